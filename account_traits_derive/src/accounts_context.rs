@@ -0,0 +1,549 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parenthesized, parse::ParseStream, Attribute, DeriveInput, Ident, LitInt,
+    LitStr, Path, Token, Variant,
+};
+
+/// An account of an instruction, parsed from its shank `#[account(...)]` attribute
+struct InstructionAccount {
+    name: Ident,
+    signer: bool,
+    writable: bool,
+    optional: bool,
+}
+
+/// The `#[accounts(...)]` attribute of an instruction
+#[derive(Default)]
+struct AccountsAttribute {
+    remaining: Option<Ident>,
+    pdas: Vec<Pda>,
+    associated_token_accounts: Vec<AssociatedTokenAccount>,
+}
+
+/// `pda(account = Type(seeds..), program = ..)`
+struct Pda {
+    account: Ident,
+    ty: Path,
+    seeds: Vec<AccountRef>,
+    program: Option<AccountRef>,
+}
+
+struct AssociatedTokenAccount {
+    account: Ident,
+    owner: AccountRef,
+    mint: AccountRef,
+}
+
+/// Another account of the instruction, or a pubkey read from it
+enum AccountRef {
+    /// `account`
+    Account(Ident),
+    /// `account.key`
+    Key(Ident),
+    /// `account.field`, a field of an account loaded by a `pda`
+    Field(Ident, Ident),
+}
+
+impl AccountRef {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let account = input.parse::<Ident>()?;
+        if !input.peek(Token![.]) {
+            return Ok(Self::Account(account));
+        }
+        input.parse::<Token![.]>()?;
+        let field = input.parse::<Ident>()?;
+        if field == "key" {
+            Ok(Self::Key(account))
+        } else {
+            Ok(Self::Field(account, field))
+        }
+    }
+
+    const fn account(&self) -> &Ident {
+        match self {
+            Self::Account(account) | Self::Key(account) | Self::Field(account, _) => account,
+        }
+    }
+
+    /// The `&AccountInfo` for `Account` and the `&Pubkey` otherwise
+    fn expand(&self) -> TokenStream2 {
+        match self {
+            Self::Account(account) => quote! { &#account },
+            Self::Key(account) => quote! { #account.key },
+            Self::Field(account, field) => quote! { &#account.data()?.#field },
+        }
+    }
+
+    /// The `&Pubkey` of the reference
+    fn expand_key(&self) -> TokenStream2 {
+        match self {
+            Self::Account(account) => quote! { #account.key },
+            _ => self.expand(),
+        }
+    }
+}
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let syn::Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "AccountsContext can only be derived for an instruction enum",
+        ));
+    };
+
+    let mut expanded = TokenStream2::new();
+    for variant in &data.variants {
+        let accounts = parse_instruction_accounts(variant)?;
+        let attribute = parse_accounts_attribute(variant)?;
+        if accounts.is_empty() {
+            if let Some(attr) = variant.attrs.iter().find(|a| a.path().is_ident("accounts")) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "`accounts` attribute on an instruction without accounts",
+                ));
+            }
+            continue;
+        }
+        expanded.extend(expand_variant(
+            &input.ident,
+            &variant.ident,
+            &accounts,
+            &attribute,
+        )?);
+    }
+
+    Ok(expanded)
+}
+
+fn parse_instruction_accounts(variant: &Variant) -> syn::Result<Vec<InstructionAccount>> {
+    let mut accounts = Vec::new();
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("account"))
+    {
+        let account = parse_instruction_account(attr)?;
+        if account.0 != accounts.len() {
+            return Err(syn::Error::new_spanned(
+                attr,
+                format!(
+                    "account index {} does not match its position {}",
+                    account.0,
+                    accounts.len()
+                ),
+            ));
+        }
+        accounts.push(account.1);
+    }
+
+    // Optional accounts are read after the required ones
+    if let Some(position) = accounts.iter().position(|a| a.optional) {
+        if let Some(required) = accounts[position..].iter().find(|a| !a.optional) {
+            return Err(syn::Error::new_spanned(
+                &required.name,
+                "required accounts shall come before optional accounts",
+            ));
+        }
+    }
+
+    Ok(accounts)
+}
+
+/// Parses a shank `#[account(index, writable, signer, optional, name = "...", desc = "...")]`
+/// attribute, accepting the same aliases as shank
+fn parse_instruction_account(attr: &Attribute) -> syn::Result<(usize, InstructionAccount)> {
+    attr.parse_args_with(|input: ParseStream| {
+        let index = input.parse::<LitInt>()?.base10_parse::<usize>()?;
+        let mut name = None;
+        let mut signer = false;
+        let mut writable = false;
+        let mut optional = false;
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            // `mut` is a keyword
+            let key = input.call(Ident::parse_any)?;
+            match key.to_string().as_str() {
+                "name" => {
+                    input.parse::<Token![=]>()?;
+                    let value = input.parse::<LitStr>()?;
+                    name = Some(Ident::new(&value.value(), value.span()));
+                }
+                "desc" | "description" | "docs" => {
+                    input.parse::<Token![=]>()?;
+                    input.parse::<LitStr>()?;
+                }
+                "signer" | "sign" | "sig" | "s" => signer = true,
+                "writable" | "write" | "writ" | "mut" | "w" => writable = true,
+                "optional" | "option" | "opt" => optional = true,
+                // The account may or may not sign, so there is nothing to check
+                "optional_signer" => {}
+                _ => return Err(syn::Error::new(key.span(), "unknown account attribute")),
+            }
+        }
+
+        let Some(name) = name else {
+            return Err(input.error("missing `name` in account attribute"));
+        };
+        Ok((
+            index,
+            InstructionAccount {
+                name,
+                signer,
+                writable,
+                optional,
+            },
+        ))
+    })
+}
+
+fn parse_accounts_attribute(variant: &Variant) -> syn::Result<AccountsAttribute> {
+    let mut attribute = AccountsAttribute::default();
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("accounts"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("remaining") {
+                attribute.remaining = Some(meta.value()?.parse::<Ident>()?);
+            } else if meta.path.is_ident("pda") {
+                let content;
+                parenthesized!(content in meta.input);
+                attribute.pdas.push(parse_pda(&content)?);
+            } else if meta.path.is_ident("associated_token_account") {
+                let content;
+                parenthesized!(content in meta.input);
+                attribute
+                    .associated_token_accounts
+                    .push(parse_associated_token_account(&content)?);
+            } else {
+                return Err(meta.error(
+                    "unknown accounts attribute, expected one of `remaining`, `pda` or `associated_token_account`",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(attribute)
+}
+
+/// Parses `account = Type(seeds..), program = program`
+fn parse_pda(input: ParseStream) -> syn::Result<Pda> {
+    let account = input.parse::<Ident>()?;
+    input.parse::<Token![=]>()?;
+    let ty = input.parse::<Path>()?;
+    let seeds = if input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in input);
+        content
+            .parse_terminated(AccountRef::parse, Token![,])?
+            .into_iter()
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let mut program = None;
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+        let key = input.parse::<Ident>()?;
+        if key != "program" {
+            return Err(syn::Error::new(
+                key.span(),
+                "unknown pda argument, expected `program`",
+            ));
+        }
+        input.parse::<Token![=]>()?;
+        program = Some(AccountRef::parse(input)?);
+    }
+
+    Ok(Pda {
+        account,
+        ty,
+        seeds,
+        program,
+    })
+}
+
+/// Parses `account, owner = owner, mint = mint`
+fn parse_associated_token_account(input: ParseStream) -> syn::Result<AssociatedTokenAccount> {
+    let account = input.parse::<Ident>()?;
+    let mut owner = None;
+    let mut mint = None;
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+        let key = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+        let value = AccountRef::parse(input)?;
+        match key.to_string().as_str() {
+            "owner" => owner = Some(value),
+            "mint" => mint = Some(value),
+            _ => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "unknown associated token account argument, expected `owner` or `mint`",
+                ))
+            }
+        }
+    }
+
+    match (owner, mint) {
+        (Some(owner), Some(mint)) => Ok(AssociatedTokenAccount {
+            account,
+            owner,
+            mint,
+        }),
+        _ => Err(syn::Error::new(
+            account.span(),
+            "associated token account requires `owner` and `mint`",
+        )),
+    }
+}
+
+/// Loads the account of a `pda` and shadows it with the typed account
+fn expand_pda(
+    core: &TokenStream2,
+    pda: &Pda,
+    instruction_account: &InstructionAccount,
+) -> TokenStream2 {
+    let Pda {
+        account,
+        ty,
+        seeds,
+        program,
+    } = pda;
+    let program = program
+        .as_ref()
+        .map_or_else(|| quote! { program_id }, AccountRef::expand);
+    let seeds = seeds.iter().map(AccountRef::expand);
+    let is_writable = instruction_account.writable;
+    let load = quote! {
+        #ty::load(#program, #account, #(#seeds,)* #is_writable)?;
+    };
+    if instruction_account.optional {
+        quote! {
+            let #account: Option<#core::accounts::Account<'_, '_, #ty>> = match #account {
+                Some(#account) => {
+                    #load
+                    Some(#core::accounts::Account::new_unchecked(#account))
+                }
+                None => None,
+            };
+        }
+    } else {
+        quote! {
+            #load
+            let #account: #core::accounts::Account<'_, '_, #ty> =
+                #core::accounts::Account::new_unchecked(#account);
+        }
+    }
+}
+
+fn expand_variant(
+    instruction: &Ident,
+    variant: &Ident,
+    accounts: &[InstructionAccount],
+    attribute: &AccountsAttribute,
+) -> syn::Result<TokenStream2> {
+    let account_info = quote! { ::solana_program::account_info::AccountInfo };
+    let program_error = quote! { ::solana_program::program_error::ProgramError };
+    let pubkey = quote! { ::solana_program::pubkey::Pubkey };
+    let core = quote! { ::jito_jsm_core };
+
+    let name = format_ident!("{}Accounts", variant);
+    let (required, optional): (Vec<_>, Vec<_>) = accounts.iter().partition(|a| !a.optional);
+    let required_len = required.len();
+    // The accounts after the optional ones have always been ignored
+    let max_len = if attribute.remaining.is_some() || !optional.is_empty() {
+        quote! { None }
+    } else {
+        let len = accounts.len();
+        quote! { Some(#len) }
+    };
+
+    let find = |ident: &Ident| -> syn::Result<&InstructionAccount> {
+        accounts.iter().find(|a| a.name.eq(ident)).ok_or_else(|| {
+            syn::Error::new(
+                ident.span(),
+                format!("`{ident}` is not an account of the instruction"),
+            )
+        })
+    };
+
+    // Checks a reference is to a required account, and that fields are read from accounts
+    // loaded by one of the first `loaded` pdas
+    let check_ref = |account_ref: &AccountRef, loaded: usize| -> syn::Result<()> {
+        let ident = account_ref.account();
+        if find(ident)?.optional {
+            return Err(syn::Error::new(
+                ident.span(),
+                "optional accounts can't be referenced",
+            ));
+        }
+        if matches!(account_ref, AccountRef::Field(..))
+            && !attribute.pdas[..loaded]
+                .iter()
+                .any(|pda| pda.account.eq(ident))
+        {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!("fields can only be read from an account loaded by an earlier `pda`, `{ident}` is not"),
+            ));
+        }
+        Ok(())
+    };
+
+    let mut fields = Vec::new();
+    let mut field_names = Vec::new();
+    let mut checks = Vec::new();
+    for account in accounts {
+        let field = &account.name;
+        let role = field.to_string();
+        let (is_signer, is_writable) = (account.signer, account.writable);
+        let check = match role.as_str() {
+            "system_program" => quote! { #core::loader::load_system_program(#field)?; },
+            "token_program" => quote! { #core::loader::load_token_program(#field)?; },
+            "associated_token_program" => {
+                quote! { #core::loader::load_associated_token_account_program(#field)?; }
+            }
+            _ if is_signer || is_writable => quote! {
+                #core::accounts::check_account(#field, #role, #is_signer, #is_writable)?;
+            },
+            _ => quote! {},
+        };
+
+        let pda = attribute.pdas.iter().find(|pda| pda.account.eq(field));
+        let ty = match pda {
+            Some(Pda { ty, .. }) => quote! { #core::accounts::Account<'a, 'info, #ty> },
+            None => quote! { &'a #account_info<'info> },
+        };
+        if account.optional {
+            fields.push(quote! { pub #field: Option<#ty> });
+            if !check.is_empty() {
+                checks.push(quote! {
+                    if let Some(#field) = #field {
+                        #check
+                    }
+                });
+            }
+        } else {
+            fields.push(quote! { pub #field: #ty });
+            checks.push(check);
+        }
+        field_names.push(field.clone());
+    }
+
+    let mut uses_program_id = !optional.is_empty();
+    for (index, pda) in attribute.pdas.iter().enumerate() {
+        let Pda {
+            account,
+            seeds,
+            program,
+            ..
+        } = pda;
+        if attribute.pdas[..index]
+            .iter()
+            .any(|other| other.account.eq(account))
+        {
+            return Err(syn::Error::new(account.span(), "account loaded twice"));
+        }
+        let instruction_account = find(account)?;
+        for account_ref in seeds.iter().chain(program) {
+            check_ref(account_ref, index)?;
+        }
+        uses_program_id |= program.is_none();
+        checks.push(expand_pda(&core, pda, instruction_account));
+    }
+
+    for ata in &attribute.associated_token_accounts {
+        let AssociatedTokenAccount {
+            account,
+            owner,
+            mint,
+        } = ata;
+        if find(account)?.optional {
+            return Err(syn::Error::new(
+                account.span(),
+                "associated token accounts can't use optional accounts",
+            ));
+        }
+        check_ref(owner, attribute.pdas.len())?;
+        check_ref(mint, attribute.pdas.len())?;
+        let role = account.to_string();
+        let (owner, mint) = (owner.expand_key(), mint.expand_key());
+        checks.push(quote! {
+            #core::loader::load_associated_token_account(&#account, #role, #owner, #mint)?;
+        });
+    }
+
+    let required_names = required.iter().map(|a| &a.name);
+    let optional_lets = optional.iter().enumerate().map(|(index, account)| {
+        let field = &account.name;
+        quote! {
+            let #field = #core::accounts::optional_account(program_id, rest, #index);
+        }
+    });
+
+    let remaining = attribute.remaining.as_ref().map(|remaining| {
+        let optional_len = optional.len();
+        fields.push(quote! { pub #remaining: &'a [#account_info<'info>] });
+        field_names.push(remaining.clone());
+        quote! {
+            let #remaining = rest.get(#optional_len..).unwrap_or_default();
+        }
+    });
+    // `rest` holds the optional and remaining accounts
+    let rest = if optional.is_empty() && remaining.is_none() {
+        quote! { .. }
+    } else {
+        quote! { rest @ .. }
+    };
+
+    // The program id is only needed to tell missing optional accounts apart and to load accounts
+    let program_id = if !uses_program_id {
+        format_ident!("_program_id")
+    } else {
+        format_ident!("program_id")
+    };
+
+    let doc = format!("The accounts of [`{instruction}::{variant}`]");
+    Ok(quote! {
+        #[doc = #doc]
+        pub struct #name<'a, 'info> {
+            #(#fields,)*
+        }
+
+        impl<'a, 'info> #core::accounts::AccountsContext<'a, 'info> for #name<'a, 'info> {
+            const REQUIRED_ACCOUNTS: usize = #required_len;
+
+            #[inline(always)]
+            fn try_from_accounts(
+                #program_id: &#pubkey,
+                accounts: &'a [#account_info<'info>],
+            ) -> Result<Self, #program_error> {
+                #core::accounts::check_accounts_len(accounts.len(), #required_len, #max_len)?;
+                let [#(#required_names,)* #rest] = accounts else {
+                    return Err(#program_error::NotEnoughAccountKeys);
+                };
+                #(#optional_lets)*
+                #remaining
+
+                #(#checks)*
+
+                Ok(Self {
+                    #(#field_names,)*
+                })
+            }
+        }
+    })
+}
@@ -1,15 +1,378 @@
+mod accounts_context;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    bracketed, parse::ParseStream, parse_macro_input, punctuated::Punctuated, DeriveInput, Expr,
+    Ident, LitByteStr, LitStr, Token,
+};
 
-#[proc_macro_derive(AccountDeserialize)]
+/// Derives `AccountDeserialize` for an account struct.
+///
+/// An optional `#[account(...)]` attribute generates the rest of the account boilerplate:
+/// * `discriminator = <expr>` - implements `Discriminator` with the given value, otherwise it shall
+///   be implemented by hand
+/// * `seeds = [...]` - generates `seeds`, `find_program_address` and `load`
+/// * `name = "..."` - the account name, defaults to the struct name. Its snake case form, e.g.
+///   `ncn_vault_ticket`, is the account role in the errors returned by `load`
+/// * `bump = field` - the `u8` field holding the PDA bump; `load` then checks the PDA with
+///   `create_program_address` instead of the much more expensive `find_program_address`
+///
+/// Each seed is one of:
+/// * `b"literal"` - a constant byte string
+/// * `ident` - a pubkey, passed as an `&AccountInfo` to `load`
+/// * `ident: Pubkey` - a pubkey, passed as a `&Pubkey` to `load`
+/// * `ident: u64` - a little-endian u64
+/// * `self.field` - a pubkey read from the account data itself in `load`
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+/// #[account(
+///     discriminator = RestakingDiscriminator::NcnVaultTicket,
+///     seeds = [b"ncn_vault_ticket", ncn, vault],
+///     name = "NCN vault ticket"
+/// )]
+/// #[repr(C)]
+/// pub struct NcnVaultTicket { ... }
+/// ```
+#[proc_macro_derive(AccountDeserialize, attributes(account))]
 pub fn derive_account_deserialize(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = &input.ident;
 
-    let expanded = quote! {
+    let account = match parse_account_attribute(&input) {
+        Ok(account) => account,
+        Err(e) => return TokenStream::from(e.to_compile_error()),
+    };
+
+    let mut expanded = quote! {
         impl AccountDeserialize for #name {}
     };
+    if let Some(account) = account {
+        expanded.extend(account.expand(name));
+    }
 
     TokenStream::from(expanded)
 }
+
+/// Derives an accounts context for every variant of an instruction enum with shank
+/// `#[account(...)]` attributes.
+///
+/// Each variant gets a `{Variant}Accounts` struct with one field per account, named after the
+/// account, implementing `jito_jsm_core::accounts::AccountsContext`. Its `try_from_accounts`
+/// checks the number of accounts and the `signer`, `writable` and `optional` flags of the shank
+/// attributes, and that the `system_program`, `token_program` and `associated_token_program`
+/// accounts are the expected programs. Optional accounts are `Option` fields.
+///
+/// An optional `#[accounts(...)]` attribute on the variant adds:
+/// * `remaining = name` - a `name` field holding the accounts after the declared ones. Without it,
+///   they are rejected, unless the instruction has optional accounts
+/// * `pda(account = Type(seeds..), program = program)` - loads the account with `Type::load`,
+///   passing the seeds after the account, and makes the field a
+///   `jito_jsm_core::accounts::Account<Type>`. `program` defaults to the program id
+/// * `associated_token_account(account, owner = owner, mint = mint)` - checks the account is the
+///   associated token account of the `owner` and `mint`
+///
+/// Seeds, programs, owners and mints are another account of the instruction: `account` passes the
+/// account, `account.key` its key and `account.field` a field of an account loaded by an earlier
+/// `pda`.
+///
+/// ```ignore
+/// #[derive(ShankInstruction, AccountsContext)]
+/// pub enum VaultInstruction {
+///     #[account(0, name = "config")]
+///     #[account(1, writable, name = "vault")]
+///     #[account(2, writable, signer, name = "depositor")]
+///     #[account(3, writable, name = "depositor_vrt_token_account")]
+///     #[account(4, name = "vrt_mint")]
+///     #[account(5, signer, optional, name = "mint_signer")]
+///     #[accounts(
+///         pda(config = Config),
+///         pda(vault = Vault),
+///         associated_token_account(
+///             depositor_vrt_token_account,
+///             owner = depositor,
+///             mint = vault.vrt_mint
+///         )
+///     )]
+///     MintTo { amount_in: u64 },
+/// }
+/// ```
+#[proc_macro_derive(AccountsContext, attributes(account, accounts))]
+pub fn derive_accounts_context(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match accounts_context::expand(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(e) => TokenStream::from(e.to_compile_error()),
+    }
+}
+
+struct AccountAttribute {
+    discriminator: Option<Expr>,
+    seeds: Option<Vec<Seed>>,
+    name: Option<LitStr>,
+    bump: Option<Ident>,
+}
+
+enum Seed {
+    /// A constant byte string
+    Literal(LitByteStr),
+    /// A pubkey passed to `load` as an account
+    Account(Ident),
+    /// A pubkey passed to `load` as a pubkey
+    Pubkey(Ident),
+    /// A little-endian u64
+    U64(Ident),
+    /// A pubkey field read from the account data in `load`
+    Field(Ident),
+}
+
+fn parse_account_attribute(input: &DeriveInput) -> syn::Result<Option<AccountAttribute>> {
+    let Some(attr) = input.attrs.iter().find(|a| a.path().is_ident("account")) else {
+        return Ok(None);
+    };
+
+    let mut discriminator = None;
+    let mut seeds = None;
+    let mut name = None;
+    let mut bump = None;
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("discriminator") {
+            discriminator = Some(meta.value()?.parse::<Expr>()?);
+        } else if meta.path.is_ident("seeds") {
+            let value = meta.value()?;
+            let content;
+            bracketed!(content in value);
+            let parsed =
+                Punctuated::<Seed, Token![,]>::parse_terminated_with(&content, parse_seed)?;
+            seeds = Some(parsed.into_iter().collect());
+        } else if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<LitStr>()?);
+        } else if meta.path.is_ident("bump") {
+            bump = Some(meta.value()?.parse::<Ident>()?);
+        } else {
+            return Err(meta.error(
+                "unknown account attribute, expected one of `discriminator`, `seeds`, `name` or `bump`",
+            ));
+        }
+        Ok(())
+    })?;
+
+    if let (Some(bump), None) = (&bump, &seeds) {
+        return Err(syn::Error::new_spanned(
+            bump,
+            "`bump` requires `seeds` in account attribute",
+        ));
+    }
+
+    Ok(Some(AccountAttribute {
+        discriminator,
+        seeds,
+        name,
+        bump,
+    }))
+}
+
+fn parse_seed(input: ParseStream) -> syn::Result<Seed> {
+    if input.peek(LitByteStr) {
+        return Ok(Seed::Literal(input.parse()?));
+    }
+    if input.peek(Token![self]) {
+        input.parse::<Token![self]>()?;
+        input.parse::<Token![.]>()?;
+        return Ok(Seed::Field(input.parse()?));
+    }
+    if input.peek(Ident) {
+        let ident: Ident = input.parse()?;
+        if !input.peek(Token![:]) {
+            return Ok(Seed::Account(ident));
+        }
+        input.parse::<Token![:]>()?;
+        let ty: Ident = input.parse()?;
+        return match ty.to_string().as_str() {
+            "Pubkey" => Ok(Seed::Pubkey(ident)),
+            "u64" => Ok(Seed::U64(ident)),
+            _ => Err(syn::Error::new(
+                ty.span(),
+                "unsupported seed type, expected `Pubkey` or `u64`",
+            )),
+        };
+    }
+    Err(input.error("expected a byte string literal, an identifier or `self.<field>`"))
+}
+
+impl AccountAttribute {
+    fn expand(&self, name: &Ident) -> TokenStream2 {
+        let mut expanded = TokenStream2::new();
+        if let Some(discriminator) = &self.discriminator {
+            expanded.extend(quote! {
+                impl ::jito_layouts::account::Discriminator for #name {
+                    const DISCRIMINATOR: u8 = #discriminator as u8;
+                }
+            });
+        }
+        if let Some(seeds) = &self.seeds {
+            let role = self.name.as_ref().map_or_else(
+                || snake_case(&name.to_string()),
+                |display_name| display_name.value().to_lowercase().replace(' ', "_"),
+            );
+            expanded.extend(expand_seeds(name, &role, seeds, self.bump.as_ref()));
+        }
+        expanded
+    }
+}
+
+/// Converts a struct name to snake case, e.g. `NcnVaultTicket` to `ncn_vault_ticket`
+fn snake_case(name: &str) -> String {
+    let mut snake = String::with_capacity(name.len().saturating_add(4));
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() && i > 0 {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+fn expand_seeds(name: &Ident, role: &str, seeds: &[Seed], bump: Option<&Ident>) -> TokenStream2 {
+    let pubkey = quote! { ::solana_program::pubkey::Pubkey };
+    let account_info = quote! { ::solana_program::account_info::AccountInfo };
+    let account_error = quote! { ::jito_errors::AccountError };
+    let loader_error = quote! { ::jito_errors::LoaderError };
+
+    let mut seed_params = Vec::new();
+    let mut seed_args = Vec::new();
+    let mut seed_bytes = Vec::new();
+    let mut load_params = Vec::new();
+    let mut load_args = Vec::new();
+    let mut load_fields = Vec::new();
+    for seed in seeds {
+        match seed {
+            Seed::Literal(lit) => {
+                seed_bytes.push(quote! { #lit.to_vec() });
+            }
+            Seed::Account(ident) => {
+                seed_params.push(quote! { #ident: &#pubkey });
+                seed_args.push(quote! { #ident });
+                seed_bytes.push(quote! { #ident.to_bytes().to_vec() });
+                load_params.push(quote! { #ident: &#account_info });
+                load_args.push(quote! { #ident.key });
+            }
+            Seed::Pubkey(ident) => {
+                seed_params.push(quote! { #ident: &#pubkey });
+                seed_args.push(quote! { #ident });
+                seed_bytes.push(quote! { #ident.to_bytes().to_vec() });
+                load_params.push(quote! { #ident: &#pubkey });
+                load_args.push(quote! { #ident });
+            }
+            Seed::U64(ident) => {
+                seed_params.push(quote! { #ident: u64 });
+                seed_args.push(quote! { #ident });
+                seed_bytes.push(quote! { #ident.to_le_bytes().to_vec() });
+                load_params.push(quote! { #ident: u64 });
+                load_args.push(quote! { #ident });
+            }
+            Seed::Field(ident) => {
+                seed_params.push(quote! { #ident: &#pubkey });
+                seed_args.push(quote! { #ident });
+                seed_bytes.push(quote! { #ident.to_bytes().to_vec() });
+                load_args.push(quote! { &#ident });
+                load_fields.push(ident.clone());
+            }
+        }
+    }
+
+    if let Some(bump) = bump {
+        load_fields.push(bump.clone());
+    }
+
+    let account = format_ident!("account");
+    // Read every field the PDA check needs with a single borrow of the account data
+    let load_data = (!load_fields.is_empty()).then(|| {
+        quote! {
+            let (#(#load_fields,)*) = {
+                let data = #account.data.borrow();
+                let loaded = <Self as ::jito_layouts::account::AccountDeserialize>::try_from_slice_unchecked(
+                    &data,
+                )
+                .map_err(|_| #account_error::new(#role, #loader_error::InvalidAccountData))?;
+                (#(loaded.#load_fields,)*)
+            };
+        }
+    });
+
+    let find_doc = format!("Finds the program address of the [`{name}`] account");
+    let load_doc = format!(
+        "Loads the account as a [`{name}`] account, returning an error if it is not at the \
+         expected PDA or is otherwise invalid."
+    );
+
+    let check_pda = bump.map_or_else(
+        || {
+            quote! {
+                let expected_pubkey = Self::find_program_address(program_id, #(#load_args),*).0;
+            }
+        },
+        |bump| {
+            quote! {
+                let mut seeds = Self::seeds(#(#load_args),*);
+                seeds.push(vec![#bump]);
+                let seed_slices: Vec<&[u8]> = seeds.iter().map(|s| s.as_slice()).collect();
+                let Ok(expected_pubkey) = #pubkey::create_program_address(&seed_slices, program_id)
+                else {
+                    return Err(#account_error::new(#role, #loader_error::InvalidAddress));
+                };
+            }
+        },
+    );
+
+    quote! {
+        impl #name {
+            /// Returns the seeds for the PDA
+            pub fn seeds(#(#seed_params),*) -> Vec<Vec<u8>> {
+                Vec::from_iter([#(#seed_bytes),*])
+            }
+
+            #[doc = #find_doc]
+            #[allow(clippy::too_many_arguments)]
+            pub fn find_program_address(
+                program_id: &#pubkey,
+                #(#seed_params),*
+            ) -> (#pubkey, u8, Vec<Vec<u8>>) {
+                let seeds = Self::seeds(#(#seed_args),*);
+                let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+                let (pda, bump) = #pubkey::find_program_address(&seeds_iter, program_id);
+                (pda, bump, seeds)
+            }
+
+            #[doc = #load_doc]
+            #[allow(clippy::too_many_arguments)]
+            pub fn load(
+                program_id: &#pubkey,
+                #account: &#account_info,
+                #(#load_params,)*
+                expect_writable: bool,
+            ) -> Result<(), #account_error> {
+                if #account.owner.ne(program_id) {
+                    return Err(#account_error::new(#role, #loader_error::InvalidAccountOwner));
+                }
+                if #account.data_is_empty() {
+                    return Err(#account_error::new(#role, #loader_error::AccountDataEmpty));
+                }
+                if expect_writable && !#account.is_writable {
+                    return Err(#account_error::new(#role, #loader_error::AccountNotWritable));
+                }
+                if #account.data.borrow()[0].ne(&<Self as ::jito_layouts::account::Discriminator>::DISCRIMINATOR) {
+                    return Err(#account_error::new(#role, #loader_error::InvalidDiscriminator));
+                }
+                #load_data
+                #check_pda
+                if #account.key.ne(&expected_pubkey) {
+                    return Err(#account_error::new(#role, #loader_error::InvalidAddress));
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use jito_jsm_core::accounts::AccountsContext;
+
+#[derive(AccountsContext)]
+pub enum TestInstruction {
+    #[account(0, name = "config")]
+    #[account(1, optional, name = "policy")]
+    #[account(2, signer, name = "admin")]
+    Update,
+}
+
+fn main() {}
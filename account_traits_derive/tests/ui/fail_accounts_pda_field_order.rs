@@ -0,0 +1,11 @@
+use jito_jsm_core::accounts::AccountsContext;
+
+#[derive(AccountsContext)]
+pub enum TestInstruction {
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[accounts(pda(ncn = Ncn, program = config.restaking_program), pda(config = Config))]
+    Warmup,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use jito_jsm_core::accounts::AccountsContext;
+
+#[derive(AccountsContext)]
+pub enum TestInstruction {
+    #[account(0, writable, name = "token_account")]
+    #[account(1, name = "owner")]
+    #[accounts(associated_token_account(token_account, owner = owner, mint = mint))]
+    Deposit,
+}
+
+fn main() {}
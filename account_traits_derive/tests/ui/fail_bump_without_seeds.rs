@@ -0,0 +1,11 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = 1, bump = bump)]
+#[repr(C)]
+pub struct TestAccount {
+    pub bump: u8,
+}
+
+fn main() {}
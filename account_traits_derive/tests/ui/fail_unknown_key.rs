@@ -0,0 +1,12 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+use solana_program::pubkey::Pubkey;
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = 1, size = 8)]
+#[repr(C)]
+pub struct TestAccount {
+    pub owner: Pubkey,
+}
+
+fn main() {}
@@ -0,0 +1,238 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::AccountDeserialize;
+use jito_errors::{AccountError, LoaderError};
+use jito_jsm_core::accounts::AccountsContext;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+use spl_associated_token_account::get_associated_token_address;
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = 1, seeds = [b"vault", self.base])]
+#[repr(C)]
+pub struct TestVault {
+    pub base: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = 2, seeds = [b"ticket", vault])]
+#[repr(C)]
+pub struct TestTicket {
+    pub amount: u64,
+}
+
+#[derive(AccountsContext)]
+pub enum TestInstruction {
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "vault")]
+    #[account(2, signer, name = "admin")]
+    #[account(3, writable, signer, optional, name = "burn_signer")]
+    Update { amount: u64 },
+
+    #[account(0, mut, name = "vault")]
+    #[account(1, name = "system_program", desc = "The system program")]
+    #[accounts(remaining = mints)]
+    SetMints,
+
+    #[account(0, name = "config")]
+    #[account(1, signer, name = "admin")]
+    SetAdmin,
+
+    #[account(0, name = "vault")]
+    #[account(1, writable, name = "ticket")]
+    #[account(2, name = "vault_token_account")]
+    #[accounts(
+        pda(vault = TestVault),
+        pda(ticket = TestTicket(vault)),
+        associated_token_account(vault_token_account, owner = vault, mint = vault.mint)
+    )]
+    Withdraw,
+
+    Noop,
+}
+
+fn account_info<'a>(
+    key: &'a Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+    lamports: &'a mut u64,
+    data: &'a mut [u8],
+) -> AccountInfo<'a> {
+    AccountInfo::new(key, is_signer, is_writable, lamports, data, key, false, 0)
+}
+
+fn main() {
+    let program_id = Pubkey::new_unique();
+    let keys = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let system_program = solana_program::system_program::id();
+    let mut lamports = [0; 6];
+    let mut data = [[0u8; 0]; 6];
+    let [l0, l1, l2, l3, l4, l5] = &mut lamports;
+    let [d0, d1, d2, d3, d4, d5] = &mut data;
+    let config = account_info(&keys[0], false, false, l0, d0);
+    let vault = account_info(&keys[1], false, true, l1, d1);
+    let admin = account_info(&keys[2], true, false, l2, d2);
+    let burn_signer = account_info(&keys[3], true, true, l3, d3);
+    let placeholder = account_info(&program_id, false, false, l4, d4);
+    let system = account_info(&system_program, false, false, l5, d5);
+
+    assert_eq!(UpdateAccounts::REQUIRED_ACCOUNTS, 3);
+
+    let accounts = [config.clone(), vault.clone(), admin.clone()];
+    let UpdateAccounts {
+        config: loaded_config,
+        vault: _,
+        admin: _,
+        burn_signer: loaded_burn_signer,
+    } = UpdateAccounts::try_from_accounts(&program_id, &accounts).unwrap();
+    assert_eq!(loaded_config.key, &keys[0]);
+    assert!(loaded_burn_signer.is_none());
+
+    let accounts = [config.clone(), vault.clone(), admin.clone(), burn_signer.clone()];
+    let loaded = UpdateAccounts::try_from_accounts(&program_id, &accounts).unwrap();
+    assert_eq!(loaded.burn_signer.map(|info| info.key), Some(&keys[3]));
+
+    // The program id is passed in place of a missing optional account
+    let accounts = [config.clone(), vault.clone(), admin.clone(), placeholder.clone()];
+    let loaded = UpdateAccounts::try_from_accounts(&program_id, &accounts).unwrap();
+    assert!(loaded.burn_signer.is_none());
+
+    let accounts = [config.clone(), vault.clone()];
+    assert!(matches!(
+        UpdateAccounts::try_from_accounts(&program_id, &accounts),
+        Err(ProgramError::NotEnoughAccountKeys)
+    ));
+
+    // The accounts after the optional ones are ignored
+    let accounts = [
+        config.clone(),
+        vault.clone(),
+        admin.clone(),
+        burn_signer.clone(),
+        config.clone(),
+    ];
+    assert!(UpdateAccounts::try_from_accounts(&program_id, &accounts).is_ok());
+
+    // Extra accounts are rejected when there are no optional accounts
+    let accounts = [config.clone(), admin.clone(), vault.clone()];
+    assert!(matches!(
+        SetAdminAccounts::try_from_accounts(&program_id, &accounts),
+        Err(ProgramError::InvalidArgument)
+    ));
+
+    // admin is not a signer
+    let accounts = [config.clone(), vault.clone(), config.clone()];
+    assert!(matches!(
+        UpdateAccounts::try_from_accounts(&program_id, &accounts),
+        Err(e) if e == AccountError::new("admin", LoaderError::AccountNotSigner).into()
+    ));
+
+    // vault is not writable
+    let accounts = [config.clone(), config.clone(), admin.clone()];
+    assert!(matches!(
+        UpdateAccounts::try_from_accounts(&program_id, &accounts),
+        Err(e) if e == AccountError::new("vault", LoaderError::AccountNotWritable).into()
+    ));
+
+    let accounts = [vault.clone(), system.clone(), config.clone(), admin.clone()];
+    let loaded = SetMintsAccounts::try_from_accounts(&program_id, &accounts).unwrap();
+    assert_eq!(loaded.mints.len(), 2);
+
+    let accounts = [vault.clone(), config.clone()];
+    assert!(matches!(
+        SetMintsAccounts::try_from_accounts(&program_id, &accounts),
+        Err(e) if e == AccountError::new("system_program", LoaderError::InvalidProgramId).into()
+    ));
+
+    test_pda(&program_id);
+}
+
+fn test_pda(program_id: &Pubkey) {
+    let base = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let token_program = spl_token::id();
+    let (vault_key, _, _) = TestVault::find_program_address(program_id, &base);
+    let (ticket_key, _, _) = TestTicket::find_program_address(program_id, &vault_key);
+    let token_account_key = get_associated_token_address(&vault_key, &mint);
+    let wrong_key = Pubkey::new_unique();
+
+    let mut vault_data = vec![0; 8 + std::mem::size_of::<TestVault>()];
+    vault_data[0] = 1;
+    vault_data[8..40].copy_from_slice(base.as_ref());
+    vault_data[40..72].copy_from_slice(mint.as_ref());
+    let mut ticket_data = vec![0; 8 + std::mem::size_of::<TestTicket>()];
+    ticket_data[0] = 2;
+    let mut token_account_data = vec![0; 165];
+    let mut wrong_data = ticket_data.clone();
+    let mut lamports = [0; 4];
+    let [l0, l1, l2, l3] = &mut lamports;
+    let vault = AccountInfo::new(
+        &vault_key,
+        false,
+        false,
+        l0,
+        &mut vault_data,
+        program_id,
+        false,
+        0,
+    );
+    let ticket = AccountInfo::new(
+        &ticket_key,
+        false,
+        true,
+        l1,
+        &mut ticket_data,
+        program_id,
+        false,
+        0,
+    );
+    let token_account = AccountInfo::new(
+        &token_account_key,
+        false,
+        false,
+        l2,
+        &mut token_account_data,
+        &token_program,
+        false,
+        0,
+    );
+    let wrong_ticket = AccountInfo::new(
+        &wrong_key,
+        false,
+        true,
+        l3,
+        &mut wrong_data,
+        program_id,
+        false,
+        0,
+    );
+
+    let accounts = [vault.clone(), ticket.clone(), token_account.clone()];
+    let WithdrawAccounts {
+        vault: loaded_vault,
+        ticket: loaded_ticket,
+        vault_token_account,
+    } = WithdrawAccounts::try_from_accounts(program_id, &accounts).unwrap();
+    assert_eq!(loaded_vault.data().unwrap().mint, mint);
+    loaded_ticket.data_mut().unwrap().amount = 7;
+    assert_eq!(loaded_ticket.data().unwrap().amount, 7);
+    assert_eq!(vault_token_account.key, &token_account_key);
+
+    // The ticket is not at the PDA of the vault
+    let accounts = [vault.clone(), wrong_ticket.clone(), token_account.clone()];
+    assert!(matches!(
+        WithdrawAccounts::try_from_accounts(program_id, &accounts),
+        Err(e) if e == AccountError::new("test_ticket", LoaderError::InvalidAddress).into()
+    ));
+
+    // The token account is not the vault's token account of the vault mint
+    let accounts = [vault.clone(), ticket.clone(), wrong_ticket.clone()];
+    assert!(matches!(
+        WithdrawAccounts::try_from_accounts(program_id, &accounts),
+        Err(e) if e == AccountError::new("vault_token_account", LoaderError::InvalidAccountOwner).into()
+    ));
+}
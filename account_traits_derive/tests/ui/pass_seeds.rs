@@ -0,0 +1,141 @@
+use bytemuck::{Pod, Zeroable};
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_errors::{AccountError, LoaderError};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TestDiscriminator {
+    Base = 1,
+    Ticket = 2,
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = TestDiscriminator::Base, seeds = [b"base", self.base])]
+#[repr(C)]
+pub struct BaseAccount {
+    pub base: Pubkey,
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(
+    discriminator = TestDiscriminator::Ticket,
+    seeds = [b"ticket", owner, mint: Pubkey, epoch: u64],
+    name = "Test ticket",
+)]
+#[repr(C)]
+pub struct TicketAccount {
+    pub owner: Pubkey,
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(discriminator = 4, seeds = [b"bump", owner], bump = bump)]
+#[repr(C)]
+pub struct BumpAccount {
+    pub owner: Pubkey,
+    pub bump: u8,
+    pub reserved: [u8; 7],
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[repr(C)]
+pub struct PlainAccount {
+    pub owner: Pubkey,
+}
+
+impl Discriminator for PlainAccount {
+    const DISCRIMINATOR: u8 = 3;
+}
+
+#[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+#[account(seeds = [b"manual", owner])]
+#[repr(C)]
+pub struct ManualDiscriminatorAccount {
+    pub owner: Pubkey,
+}
+
+impl Discriminator for ManualDiscriminatorAccount {
+    const DISCRIMINATOR: u8 = 5;
+}
+
+fn main() {
+    let program_id = Pubkey::new_unique();
+    let base = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    assert_eq!(BaseAccount::DISCRIMINATOR, 1);
+    assert_eq!(TicketAccount::DISCRIMINATOR, 2);
+    assert_eq!(ManualDiscriminatorAccount::DISCRIMINATOR, 5);
+    assert_eq!(
+        ManualDiscriminatorAccount::seeds(&base),
+        vec![b"manual".to_vec(), base.to_bytes().to_vec()]
+    );
+    assert_eq!(
+        TicketAccount::seeds(&base, &mint, 7),
+        vec![
+            b"ticket".to_vec(),
+            base.to_bytes().to_vec(),
+            mint.to_bytes().to_vec(),
+            7u64.to_le_bytes().to_vec(),
+        ]
+    );
+
+    let (pda, bump, seeds) = BaseAccount::find_program_address(&program_id, &base);
+    assert_eq!(seeds, BaseAccount::seeds(&base));
+    let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+    assert_eq!(
+        Pubkey::find_program_address(&seeds_iter, &program_id),
+        (pda, bump)
+    );
+
+    let _ = TicketAccount::load;
+    let _ = BaseAccount::load;
+
+    let owner = Pubkey::new_unique();
+    let (pda, bump, _) = BumpAccount::find_program_address(&program_id, &owner);
+    let mut data = vec![0; 8 + std::mem::size_of::<BumpAccount>()];
+    data[0] = BumpAccount::DISCRIMINATOR;
+    data[8 + 32] = bump;
+    let mut lamports = 0;
+    let mut owner_lamports = 0;
+    let mut owner_data = vec![];
+    let system_program = Pubkey::default();
+    let account = AccountInfo::new(
+        &pda,
+        false,
+        true,
+        &mut lamports,
+        &mut data,
+        &program_id,
+        false,
+        0,
+    );
+    let owner_account = AccountInfo::new(
+        &owner,
+        false,
+        false,
+        &mut owner_lamports,
+        &mut owner_data,
+        &system_program,
+        false,
+        0,
+    );
+    assert!(BumpAccount::load(&program_id, &account, &owner_account, true).is_ok());
+
+    // A wrong bump derives a different address, or none at all
+    account.data.borrow_mut()[8 + 32] = bump.wrapping_sub(1);
+    assert_eq!(
+        BumpAccount::load(&program_id, &account, &owner_account, true),
+        Err(AccountError::new("bump_account", LoaderError::InvalidAddress))
+    );
+
+    // The role of the account is the snake case form of its name
+    account.data.borrow_mut()[0] = TicketAccount::DISCRIMINATOR;
+    assert_eq!(
+        BumpAccount::load(&program_id, &account, &owner_account, true),
+        Err(AccountError::new("bump_account", LoaderError::InvalidDiscriminator))
+    );
+    assert_eq!(
+        TicketAccount::load(&program_id, &account, &owner_account, &mint, 7, true),
+        Err(AccountError::new("test_ticket", LoaderError::InvalidAddress))
+    );
+}
@@ -1,55 +1,101 @@
 //! Trait that can be used when working with Solana structs that are used as accounts.
+//!
+//! The account traits live in [`jito_layouts::account`] alongside the account structs; this crate
+//! re-exports them for the on-chain programs.
 
-pub mod types;
+pub use jito_layouts::{
+    account::{
+        layout_version, AccountDeserialize, Discriminator, MigrateLayout, ACCOUNT_HEADER_LEN,
+        LAYOUT_VERSION_OFFSET,
+    },
+    impl_int_arithmetic, impl_int_conversion, types,
+};
 
-use bytemuck::Pod;
-pub use jito_account_traits_derive::AccountDeserialize;
-use solana_program::{msg, program_error::ProgramError};
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+    use solana_program::program_error::ProgramError;
 
-pub trait Discriminator {
-    const DISCRIMINATOR: u8;
-}
+    use super::{AccountDeserialize, Discriminator, MigrateLayout, ACCOUNT_HEADER_LEN};
+    use crate::types::PodU64;
 
-pub trait AccountDeserialize: Sized + Pod + Discriminator {
-    /// Deserialize the account data into a struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
-    ///
-    /// # Arguments
-    /// * `data` - The account data to deserialize
-    ///
-    /// # Returns
-    /// * `Result<&Self, ProgramError>` - The deserialized struct as a reference or an error
-    fn try_from_slice_unchecked(data: &[u8]) -> Result<&Self, ProgramError> {
-        if data.first() != Some(&Self::DISCRIMINATOR) {
-            msg!(
-                "Discriminator is invalid; expected {}, got {}",
-                Self::DISCRIMINATOR,
-                data.first().unwrap()
-            );
-            return Err(ProgramError::InvalidAccountData);
-        }
-        bytemuck::try_from_bytes(&data[8..]).map_err(|_| ProgramError::InvalidAccountData)
-    }
-
-    /// Deserialize the account data into a mutable struct.
-    /// It assumes the first byte is the discriminator and the next seven bytes are reserved.
-    /// The rest of the data is deserialized into the struct.
-    ///
-    /// # Arguments
-    /// * `data` - The account data to deserialize
-    ///
-    /// # Returns
-    /// * `Result<&mut Self, ProgramError>` - The deserialized struct as a reference or an error
-    fn try_from_slice_unchecked_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
-        if data.first() != Some(&Self::DISCRIMINATOR) {
-            msg!(
-                "Discriminator is invalid; expected {}, got {}",
-                Self::DISCRIMINATOR,
-                data.first().unwrap()
-            );
-            return Err(ProgramError::InvalidAccountData);
+    #[derive(Clone, Copy, Pod, Zeroable, AccountDeserialize)]
+    #[repr(C)]
+    struct TestAccount {
+        value: PodU64,
+    }
+
+    impl Discriminator for TestAccount {
+        const DISCRIMINATOR: u8 = 1;
+        const LAYOUT_VERSION: u8 = 2;
+    }
+
+    impl MigrateLayout for TestAccount {
+        fn migrate_from(layout_version: u8, data: &mut [u8]) -> Result<(), ProgramError> {
+            // Version 1 stored the value halved
+            if layout_version == 1 {
+                let value = u64::from_le_bytes(data[..8].try_into().unwrap());
+                data[..8].copy_from_slice(&(value * 2).to_le_bytes());
+            }
+            Ok(())
         }
-        bytemuck::try_from_bytes_mut(&mut data[8..]).map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    #[test]
+    fn test_write_header() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        TestAccount::write_header(&mut data);
+        assert_eq!(data[..2], [1, 2]);
+        assert!(TestAccount::try_from_slice_unchecked(&data).is_ok());
+    }
+
+    #[test]
+    fn test_outdated_layout_version_fails() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        data[0] = TestAccount::DISCRIMINATOR;
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked(&data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+        assert_eq!(
+            TestAccount::try_from_slice_unchecked_mut(&mut data).err(),
+            Some(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_migrate() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        data[0] = TestAccount::DISCRIMINATOR;
+        data[1] = 1;
+        data[ACCOUNT_HEADER_LEN..].copy_from_slice(&21_u64.to_le_bytes());
+
+        assert!(TestAccount::migrate(&mut data).unwrap());
+        let account = TestAccount::try_from_slice_unchecked(&data).unwrap();
+        assert_eq!(u64::from(account.value), 42);
+
+        // Migrating the current layout is a no-op
+        assert!(!TestAccount::migrate(&mut data).unwrap());
+    }
+
+    #[test]
+    fn test_migrate_newer_layout_fails() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        data[0] = TestAccount::DISCRIMINATOR;
+        data[1] = 3;
+        assert_eq!(
+            TestAccount::migrate(&mut data),
+            Err(ProgramError::InvalidAccountData)
+        );
+    }
+
+    #[test]
+    fn test_migrate_too_small_fails() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 4];
+        data[0] = TestAccount::DISCRIMINATOR;
+        assert_eq!(
+            TestAccount::migrate(&mut data),
+            Err(ProgramError::AccountDataTooSmall)
+        );
     }
 }
@@ -4,17 +4,22 @@ use base64::{engine::general_purpose, Engine};
 use borsh::BorshDeserialize;
 use cli_config::CliConfig;
 use cli_signer::CliSigner;
+use jito_bytemuck::AccountDeserialize;
 use jito_restaking_client_common::log::PrettyDisplay;
 use log::print_base58_tx;
 use serde::Serialize;
 use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_rpc_client_api::{
+    client_error::Error as ClientError,
     config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
     filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
 };
 use solana_sdk::{
-    instruction::Instruction, pubkey::Pubkey, signers::Signers, transaction::Transaction,
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signers::Signers,
+    transaction::{Transaction, TransactionError},
 };
 
 pub mod cli_args;
@@ -35,6 +40,10 @@ pub(crate) trait CliHandler {
 
     fn print_json_with_reserves(&self) -> bool;
 
+    fn restaking_program_id(&self) -> &Pubkey;
+
+    fn vault_program_id(&self) -> &Pubkey;
+
     fn signer(&self) -> anyhow::Result<&CliSigner> {
         self.cli_config()
             .signer
@@ -86,8 +95,8 @@ pub(crate) trait CliHandler {
     /// Creates an RPC program accounts configuration for fetching accounts of type `T` with an optional public key filter.
     ///
     /// This method constructs a configuration that can be used with RPC methods to fetch program accounts
-    /// that match specific criteria. It automatically adds filters for the account data size and the discriminator
-    /// of type `T` to ensure only accounts of the expected type are returned.
+    /// that match specific criteria. It automatically adds filters for the account data size, the discriminator
+    /// and the layout version of type `T` to ensure only accounts of the expected type are returned.
     fn get_rpc_program_accounts_config<T: jito_bytemuck::Discriminator>(
         &self,
         filter_pubkey: Option<(&Pubkey, usize)>,
@@ -97,7 +106,7 @@ pub(crate) trait CliHandler {
             .ok_or_else(|| anyhow!("Failed to add"))?;
 
         let encoded_discriminator =
-            general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0]);
+            general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, T::LAYOUT_VERSION]);
         let discriminator_filter = RpcFilterType::Memcmp(Memcmp::new(
             0,
             MemcmpEncodedBytes::Base64(encoded_discriminator),
@@ -171,7 +180,15 @@ pub(crate) trait CliHandler {
         } else {
             let blockhash = rpc_client.get_latest_blockhash().await?;
             let tx = Transaction::new_signed_with_payer(ixs, Some(payer), signers, blockhash);
-            let result = rpc_client.send_and_confirm_transaction(&tx).await?;
+            let result = rpc_client
+                .send_and_confirm_transaction(&tx)
+                .await
+                .map_err(|e| {
+                    anyhow!(
+                        "Transaction failed: {}",
+                        self.describe_transaction_error(&e, &tx)
+                    )
+                })?;
 
             info!("Transaction confirmed: {:?}", result);
         }
@@ -179,6 +196,31 @@ pub(crate) trait CliHandler {
         Ok(())
     }
 
+    /// Describes why a transaction failed in simulation or on chain.
+    ///
+    /// Custom errors returned by the vault or restaking program are decoded into their name and
+    /// description, e.g. `VaultUpdateNeeded (custom program error: 0x3fa): Vault must be updated
+    /// for the current epoch first`. Any other error is printed as is.
+    fn describe_transaction_error(&self, err: &ClientError, tx: &Transaction) -> String {
+        if let Some(TransactionError::InstructionError(index, InstructionError::Custom(code))) =
+            err.get_transaction_error()
+        {
+            let decoded = match tx.message.program_id(usize::from(index)) {
+                Some(program_id) if program_id == self.vault_program_id() => {
+                    jito_vault_sdk::error::decode_program_error(program_id, code)
+                }
+                Some(program_id) if program_id == self.restaking_program_id() => {
+                    jito_restaking_sdk::error::decode_program_error(program_id, code)
+                }
+                _ => None,
+            };
+            if let Some(decoded) = decoded {
+                return format!("instruction {index} failed: {decoded}");
+            }
+        }
+        err.to_string()
+    }
+
     /// Prints a value either as JSON or using its pretty display format.
     ///
     /// This function provides flexible output formatting for any type that implements both
@@ -199,6 +241,49 @@ pub(crate) trait CliHandler {
     ) -> anyhow::Result<()>
     where
         T: ?Sized + Serialize + PrettyDisplay,
+    {
+        if self.print_json() || self.print_json_with_reserves() {
+            self.print_out_json(index, address, value)
+        } else {
+            self.print_out_pretty(address, value);
+            Ok(())
+        }
+    }
+
+    /// Prints a program account from its raw data, like [`CliHandler::print_out`].
+    ///
+    /// The JSON formats serialize the on-chain layout `T` directly, while the default format uses
+    /// the [`PrettyDisplay`] of the client account `D`.
+    fn print_account<T, D>(
+        &self,
+        index: Option<usize>,
+        address: &Pubkey,
+        data: &[u8],
+    ) -> anyhow::Result<()>
+    where
+        T: AccountDeserialize + Serialize,
+        D: BorshDeserialize + PrettyDisplay,
+    {
+        if self.print_json() || self.print_json_with_reserves() {
+            let account = T::try_from_slice_unchecked(data)?;
+            self.print_out_json(index, Some(address), account)
+        } else {
+            let account = D::deserialize(&mut &data[..])?;
+            self.print_out_pretty(Some(address), &account);
+            Ok(())
+        }
+    }
+
+    /// Prints a value in the JSON format selected by `--print-json` or
+    /// `--print-json-with-reserves`
+    fn print_out_json<T>(
+        &self,
+        index: Option<usize>,
+        address: Option<&Pubkey>,
+        value: &T,
+    ) -> anyhow::Result<()>
+    where
+        T: ?Sized + Serialize,
     {
         match (self.print_json(), self.print_json_with_reserves()) {
             (true, true) => {
@@ -232,19 +317,25 @@ pub(crate) trait CliHandler {
 
                 println!("{json_string}");
             }
-            (false, false) => {
-                let type_name = std::any::type_name::<T>();
-                let msg = address.map_or("".to_string(), |address| {
-                    format!("{type_name} at {address}")
-                });
-                info!("{msg}");
-                info!("{}", value.pretty_display());
-            }
+            (false, false) => {}
         }
 
         Ok(())
     }
 
+    /// Prints a value using its [`PrettyDisplay`] format
+    fn print_out_pretty<T>(&self, address: Option<&Pubkey>, value: &T)
+    where
+        T: ?Sized + PrettyDisplay,
+    {
+        let type_name = std::any::type_name::<T>();
+        let msg = address.map_or("".to_string(), |address| {
+            format!("{type_name} at {address}")
+        });
+        info!("{msg}");
+        info!("{}", value.pretty_display());
+    }
+
     /// Recursively removes all "reserved" fields from a JSON value
     fn remove_reserved_fields(&self, value: &mut serde_json::Value) {
         if let serde_json::Value::Object(map) = value {
@@ -43,7 +43,15 @@ pub enum NcnActions {
     /// Initialize NCN Operator State
     InitializeNcnOperatorState { ncn: String, operator: String },
     /// Warmup NCN Operator State
-    NcnWarmupOperator { ncn: String, operator: String },
+    NcnWarmupOperator {
+        ncn: String,
+        operator: String,
+
+        /// Vaults backing the NCN and delegating to the operator, counted towards the NCN policy's
+        /// minimum operator stake. Each vault shall be updated for the current epoch.
+        #[arg(long)]
+        vault: Vec<String>,
+    },
     /// NCN Cooldown Operator State
     NcnCooldownOperator { ncn: String, operator: String },
     /// Initialize NCN Vault Ticket
@@ -60,6 +68,134 @@ pub enum NcnActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// Initialize NCN Metadata
+    NcnInitializeMetadata {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The display name
+        name: String,
+
+        /// The website URL
+        #[arg(long, default_value = "")]
+        website: String,
+
+        /// The URI of the logo image
+        #[arg(long, default_value = "")]
+        logo_uri: String,
+
+        /// Contact information, such as an email address or a handle
+        #[arg(long, default_value = "")]
+        contact: String,
+
+        /// Base58-encoded hash of an off-chain description document
+        #[arg(long)]
+        description_hash: Option<String>,
+    },
+    /// Update NCN Metadata
+    NcnUpdateMetadata {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The display name
+        name: String,
+
+        /// The website URL
+        #[arg(long, default_value = "")]
+        website: String,
+
+        /// The URI of the logo image
+        #[arg(long, default_value = "")]
+        logo_uri: String,
+
+        /// Contact information, such as an email address or a handle
+        #[arg(long, default_value = "")]
+        contact: String,
+
+        /// Base58-encoded hash of an off-chain description document
+        #[arg(long)]
+        description_hash: Option<String>,
+    },
+    /// Get NCN Metadata
+    GetNcnMetadata { ncn: String },
+    /// Set the operator bond requirement of an NCN
+    NcnSetOperatorBondRequirement {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The mint operators shall bond in
+        operator_bond_mint: String,
+
+        /// The minimum bond, zero removes the requirement
+        min_operator_bond: u64,
+    },
+    /// Slash an operator bond
+    SlashOperatorBond {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The token account receiving the slashed tokens
+        destination_token_account: String,
+
+        /// The amount to slash
+        amount: u64,
+    },
+    /// Jail an operator until the start of an epoch
+    NcnJailOperator {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The epoch the jail expires at
+        jailed_until_epoch: u64,
+    },
+    /// Release a jailed operator
+    NcnUnjailOperator { ncn: String, operator: String },
+    /// Initialize NCN Policy
+    InitializeNcnPolicy { ncn: String },
+    /// Set the admission policy of an NCN
+    NcnSetPolicy {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// Allow any signer to warm up operators and vaults satisfying the policy
+        #[arg(long)]
+        permissionless_admission: bool,
+
+        /// The minimum stake delegated to an operator by vaults with an allowed mint
+        #[arg(long, default_value_t = 0)]
+        min_operator_stake: u64,
+
+        /// The maximum number of admitted operators, zero for no limit
+        #[arg(long, default_value_t = 0)]
+        max_operators: u64,
+
+        /// The minimum number of slots the operator shall be opted in to the NCN
+        #[arg(long, default_value_t = 0)]
+        min_operator_ticket_age: u64,
+
+        /// The supported mints allowed for vaults, any mint is allowed if empty
+        #[arg(long)]
+        allowed_vault_mint: Vec<String>,
+    },
+    /// Get NCN Policy
+    GetNcnPolicy { ncn: String },
+    /// Set the warmup and cooldown durations of an NCN's relationships
+    NcnSetTransitionEpochs {
+        /// The NCN pubkey
+        ncn: String,
+
+        /// The number of full epochs spent warming up
+        warmup_epochs: u16,
+
+        /// The number of full epochs spent cooling down
+        cooldown_epochs: u16,
+    },
     /// Get NCN
     Get { pubkey: String },
     /// List all NCNs
@@ -174,6 +310,112 @@ pub enum OperatorActions {
         #[arg(long)]
         should_create_token_account: bool,
     },
+    /// Initialize Operator Metadata
+    OperatorInitializeMetadata {
+        /// The Operator pubkey
+        operator: String,
+
+        /// The display name
+        name: String,
+
+        /// The website URL
+        #[arg(long, default_value = "")]
+        website: String,
+
+        /// The URI of the logo image
+        #[arg(long, default_value = "")]
+        logo_uri: String,
+
+        /// Contact information, such as an email address or a handle
+        #[arg(long, default_value = "")]
+        contact: String,
+
+        /// Base58-encoded hash of an off-chain description document
+        #[arg(long)]
+        description_hash: Option<String>,
+    },
+    /// Update Operator Metadata
+    OperatorUpdateMetadata {
+        /// The Operator pubkey
+        operator: String,
+
+        /// The display name
+        name: String,
+
+        /// The website URL
+        #[arg(long, default_value = "")]
+        website: String,
+
+        /// The URI of the logo image
+        #[arg(long, default_value = "")]
+        logo_uri: String,
+
+        /// Contact information, such as an email address or a handle
+        #[arg(long, default_value = "")]
+        contact: String,
+
+        /// Base58-encoded hash of an off-chain description document
+        #[arg(long)]
+        description_hash: Option<String>,
+    },
+    /// Get Operator Metadata
+    GetOperatorMetadata { operator: String },
+    /// Initialize Operator Bond
+    OperatorInitializeBond {
+        /// The NCN the bond is posted to
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The mint of the bond
+        mint: String,
+    },
+    /// Deposit into the Operator Bond from the signer's token account
+    OperatorDepositBond {
+        /// The NCN the bond is posted to
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The mint of the bond
+        mint: String,
+
+        /// The amount to deposit
+        amount: u64,
+    },
+    /// Unbond tokens from the Operator Bond, they can be withdrawn after the NCN's cooldown
+    OperatorUnbond {
+        /// The NCN the bond is posted to
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The mint of the bond
+        mint: String,
+
+        /// The amount to unbond
+        amount: u64,
+    },
+    /// Withdraw the unbonded tokens to the signer's token account once the cooldown is over
+    OperatorWithdrawBond {
+        /// The NCN the bond is posted to
+        ncn: String,
+
+        /// The Operator pubkey
+        operator: String,
+
+        /// The mint of the bond
+        mint: String,
+    },
+    /// Get Operator Bond
+    GetOperatorBond {
+        ncn: String,
+        operator: String,
+        mint: String,
+    },
     /// Get operator
     Get { pubkey: String },
     /// List all operators
@@ -1,29 +1,38 @@
 use std::str::FromStr;
 
 use anyhow::{anyhow, Result};
-use borsh::BorshDeserialize;
 use jito_restaking_client::{
     instructions::{
         CooldownNcnVaultTicketBuilder, CooldownOperatorVaultTicketBuilder, InitializeConfigBuilder,
-        InitializeNcnBuilder, InitializeNcnOperatorStateBuilder, InitializeNcnVaultTicketBuilder,
-        InitializeOperatorBuilder, InitializeOperatorVaultTicketBuilder,
-        NcnCooldownOperatorBuilder, NcnDelegateTokenAccountBuilder, NcnSetAdminBuilder,
-        NcnSetSecondaryAdminBuilder, NcnWarmupOperatorBuilder, OperatorCooldownNcnBuilder,
-        OperatorDelegateTokenAccountBuilder, OperatorSetAdminBuilder, OperatorSetFeeBuilder,
-        OperatorSetSecondaryAdminBuilder, OperatorWarmupNcnBuilder, SetConfigAdminBuilder,
+        InitializeNcnBuilder, InitializeNcnMetadataBuilder, InitializeNcnOperatorStateBuilder,
+        InitializeNcnPolicyBuilder, InitializeNcnVaultTicketBuilder, InitializeOperatorBondBuilder,
+        InitializeOperatorBuilder, InitializeOperatorMetadataBuilder,
+        InitializeOperatorVaultTicketBuilder, NcnCooldownOperatorBuilder,
+        NcnDelegateTokenAccountBuilder, NcnSetAdminBuilder, NcnSetOperatorBondRequirementBuilder,
+        NcnSetPolicyBuilder, NcnSetSecondaryAdminBuilder, NcnSetTransitionEpochsBuilder,
+        NcnUnjailOperatorBuilder, NcnWarmupOperatorBuilder, OperatorCooldownNcnBuilder,
+        OperatorDelegateTokenAccountBuilder, OperatorDepositBondBuilder, OperatorSetAdminBuilder,
+        OperatorSetFeeBuilder, OperatorSetSecondaryAdminBuilder, OperatorUnbondBuilder,
+        OperatorWarmupNcnBuilder, OperatorWithdrawBondBuilder, SetConfigAdminBuilder,
+        SlashOperatorBondBuilder, UpdateNcnMetadataBuilder, UpdateOperatorMetadataBuilder,
         WarmupNcnVaultTicketBuilder, WarmupOperatorVaultTicketBuilder,
     },
     types::{NcnAdminRole, OperatorAdminRole},
 };
 use jito_restaking_client_common::log::PrettyDisplay;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
-    ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    config::Config, ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+    ncn_policy::NcnPolicy, ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+    operator_bond::OperatorBond, operator_metadata::OperatorMetadata,
     operator_vault_ticket::OperatorVaultTicket,
 };
+use jito_vault_core::vault_operator_delegation::VaultOperatorDelegation;
 use log::info;
-use solana_program::pubkey::Pubkey;
-use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_program::{instruction::AccountMeta, pubkey::Pubkey};
+use solana_sdk::{
+    hash::Hash,
+    signature::{read_keypair_file, Keypair, Signer},
+};
 use spl_associated_token_account::{
     get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
@@ -71,6 +80,14 @@ impl CliHandler for RestakingCliHandler {
     fn print_json_with_reserves(&self) -> bool {
         self.print_json_with_reserves
     }
+
+    fn restaking_program_id(&self) -> &Pubkey {
+        &self.restaking_program_id
+    }
+
+    fn vault_program_id(&self) -> &Pubkey {
+        &self.vault_program_id
+    }
 }
 
 impl RestakingCliHandler {
@@ -114,8 +131,13 @@ impl RestakingCliHandler {
                 action: NcnActions::InitializeNcnOperatorState { ncn, operator },
             } => self.initialize_ncn_operator_state(ncn, operator).await,
             RestakingCommands::Ncn {
-                action: NcnActions::NcnWarmupOperator { ncn, operator },
-            } => self.ncn_warmup_operator(ncn, operator).await,
+                action:
+                    NcnActions::NcnWarmupOperator {
+                        ncn,
+                        operator,
+                        vault,
+                    },
+            } => self.ncn_warmup_operator(ncn, operator, vault).await,
             RestakingCommands::Ncn {
                 action: NcnActions::NcnCooldownOperator { ncn, operator },
             } => self.ncn_cooldown_operator(ncn, operator).await,
@@ -145,6 +167,119 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnInitializeMetadata {
+                        ncn,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                        description_hash,
+                    },
+            } => {
+                self.ncn_initialize_metadata(
+                    ncn,
+                    name,
+                    website,
+                    logo_uri,
+                    contact,
+                    description_hash,
+                )
+                .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnUpdateMetadata {
+                        ncn,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                        description_hash,
+                    },
+            } => {
+                self.ncn_update_metadata(ncn, name, website, logo_uri, contact, description_hash)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action: NcnActions::GetNcnMetadata { ncn },
+            } => self.get_ncn_metadata(ncn).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetOperatorBondRequirement {
+                        ncn,
+                        operator_bond_mint,
+                        min_operator_bond,
+                    },
+            } => {
+                self.ncn_set_operator_bond_requirement(ncn, operator_bond_mint, min_operator_bond)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::SlashOperatorBond {
+                        ncn,
+                        operator,
+                        destination_token_account,
+                        amount,
+                    },
+            } => {
+                self.slash_operator_bond(ncn, operator, destination_token_account, amount)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnJailOperator {
+                        ncn,
+                        operator,
+                        jailed_until_epoch,
+                    },
+            } => {
+                self.ncn_jail_operator(ncn, operator, jailed_until_epoch)
+                    .await
+            }
+            RestakingCommands::Ncn {
+                action: NcnActions::NcnUnjailOperator { ncn, operator },
+            } => self.ncn_unjail_operator(ncn, operator).await,
+            RestakingCommands::Ncn {
+                action: NcnActions::InitializeNcnPolicy { ncn },
+            } => self.initialize_ncn_policy(ncn).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetPolicy {
+                        ncn,
+                        permissionless_admission,
+                        min_operator_stake,
+                        max_operators,
+                        min_operator_ticket_age,
+                        allowed_vault_mint,
+                    },
+            } => {
+                self.ncn_set_policy(
+                    ncn,
+                    permissionless_admission,
+                    min_operator_stake,
+                    max_operators,
+                    min_operator_ticket_age,
+                    allowed_vault_mint,
+                )
+                .await
+            }
+            RestakingCommands::Ncn {
+                action: NcnActions::GetNcnPolicy { ncn },
+            } => self.get_ncn_policy(ncn).await,
+            RestakingCommands::Ncn {
+                action:
+                    NcnActions::NcnSetTransitionEpochs {
+                        ncn,
+                        warmup_epochs,
+                        cooldown_epochs,
+                    },
+            } => {
+                self.ncn_set_transition_epochs(ncn, warmup_epochs, cooldown_epochs)
+                    .await
+            }
             RestakingCommands::Ncn {
                 action: NcnActions::Get { pubkey },
             } => self.get_ncn(pubkey).await,
@@ -271,6 +406,96 @@ impl RestakingCliHandler {
                 )
                 .await
             }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorInitializeMetadata {
+                        operator,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                        description_hash,
+                    },
+            } => {
+                self.operator_initialize_metadata(
+                    operator,
+                    name,
+                    website,
+                    logo_uri,
+                    contact,
+                    description_hash,
+                )
+                .await
+            }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorUpdateMetadata {
+                        operator,
+                        name,
+                        website,
+                        logo_uri,
+                        contact,
+                        description_hash,
+                    },
+            } => {
+                self.operator_update_metadata(
+                    operator,
+                    name,
+                    website,
+                    logo_uri,
+                    contact,
+                    description_hash,
+                )
+                .await
+            }
+            RestakingCommands::Operator {
+                action: OperatorActions::GetOperatorMetadata { operator },
+            } => self.get_operator_metadata(operator).await,
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorInitializeBond {
+                        ncn,
+                        operator,
+                        mint,
+                    },
+            } => self.operator_initialize_bond(ncn, operator, mint).await,
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorDepositBond {
+                        ncn,
+                        operator,
+                        mint,
+                        amount,
+                    },
+            } => {
+                self.operator_deposit_bond(ncn, operator, mint, amount)
+                    .await
+            }
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorUnbond {
+                        ncn,
+                        operator,
+                        mint,
+                        amount,
+                    },
+            } => self.operator_unbond(ncn, operator, mint, amount).await,
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::OperatorWithdrawBond {
+                        ncn,
+                        operator,
+                        mint,
+                    },
+            } => self.operator_withdraw_bond(ncn, operator, mint).await,
+            RestakingCommands::Operator {
+                action:
+                    OperatorActions::GetOperatorBond {
+                        ncn,
+                        operator,
+                        mint,
+                    },
+            } => self.get_operator_bond(ncn, operator, mint).await,
             RestakingCommands::Operator {
                 action: OperatorActions::Get { pubkey },
             } => self.get_operator(pubkey).await,
@@ -724,6 +949,7 @@ impl RestakingCliHandler {
 
         let (ncn_vault_ticket, _, _) =
             NcnVaultTicket::find_program_address(&self.restaking_program_id, &ncn, &vault);
+        let ncn_policy = self.ncn_policy_address(&ncn).await?;
 
         let mut ix_builder = WarmupNcnVaultTicketBuilder::new();
         ix_builder
@@ -732,6 +958,7 @@ impl RestakingCliHandler {
             .vault(vault)
             .ncn_vault_ticket(ncn_vault_ticket)
             .admin(signer.pubkey())
+            .ncn_policy(ncn_policy)
             .instruction();
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
@@ -828,7 +1055,12 @@ impl RestakingCliHandler {
     }
 
     #[allow(clippy::future_not_send)]
-    pub async fn ncn_warmup_operator(&self, ncn: String, operator: String) -> Result<()> {
+    pub async fn ncn_warmup_operator(
+        &self,
+        ncn: String,
+        operator: String,
+        vaults: Vec<String>,
+    ) -> Result<()> {
         let signer = self
             .cli_config
             .signer
@@ -841,6 +1073,21 @@ impl RestakingCliHandler {
         let (ncn_operator_state, _, _) =
             NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
 
+        // The operator bond is only required when the NCN has a bond requirement
+        let ncn_account = self
+            .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+            .await?;
+        let operator_bond = (ncn_account.min_operator_bond > 0).then(|| {
+            OperatorBond::find_program_address(
+                &self.restaking_program_id,
+                &ncn,
+                &operator,
+                &ncn_account.operator_bond_mint,
+            )
+            .0
+        });
+        let ncn_policy = self.ncn_policy_address(&ncn).await?;
+
         let mut ix_builder = NcnWarmupOperatorBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
@@ -848,7 +1095,25 @@ impl RestakingCliHandler {
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
             .admin(signer.pubkey())
-            .instruction();
+            .operator_bond(operator_bond)
+            .ncn_policy(ncn_policy);
+        // The NCN policy counts the stake delegated by the
+        // (vault, ncn_vault_ticket, vault_operator_delegation) groups
+        for vault in vaults {
+            let vault = Pubkey::from_str(&vault)?;
+            let ncn_vault_ticket =
+                NcnVaultTicket::find_program_address(&self.restaking_program_id, &ncn, &vault).0;
+            let vault_operator_delegation = VaultOperatorDelegation::find_program_address(
+                &self.vault_program_id,
+                &vault,
+                &operator,
+            )
+            .0;
+            ix_builder
+                .add_remaining_account(AccountMeta::new_readonly(vault, false))
+                .add_remaining_account(AccountMeta::new_readonly(ncn_vault_ticket, false))
+                .add_remaining_account(AccountMeta::new_readonly(vault_operator_delegation, false));
+        }
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
 
@@ -883,6 +1148,8 @@ impl RestakingCliHandler {
         let (ncn_operator_state, _, _) =
             NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
 
+        let ncn_policy = self.ncn_policy_address(&ncn).await?;
+
         let mut ix_builder = NcnCooldownOperatorBuilder::new();
         ix_builder
             .config(Config::find_program_address(&self.restaking_program_id).0)
@@ -890,6 +1157,7 @@ impl RestakingCliHandler {
             .operator(operator)
             .ncn_operator_state(ncn_operator_state)
             .admin(signer.pubkey())
+            .ncn_policy(ncn_policy)
             .instruction();
         let mut ix = ix_builder.instruction();
         ix.program_id = self.restaking_program_id;
@@ -1264,10 +1532,11 @@ impl RestakingCliHandler {
         let config_address = Config::find_program_address(&self.restaking_program_id).0;
 
         let account = rpc_client.get_account(&config_address).await?;
-        let config =
-            jito_restaking_client::accounts::Config::deserialize(&mut account.data.as_slice())?;
-
-        self.print_out(None, Some(&config_address), &config)?;
+        self.print_account::<Config, jito_restaking_client::accounts::Config>(
+            None,
+            &config_address,
+            &account.data,
+        )?;
 
         Ok(())
     }
@@ -1276,9 +1545,11 @@ impl RestakingCliHandler {
     pub async fn get_ncn(&self, pubkey: String) -> Result<()> {
         let pubkey = Pubkey::from_str(&pubkey)?;
         let account = self.get_rpc_client().get_account(&pubkey).await?;
-        let ncn = jito_restaking_client::accounts::Ncn::deserialize(&mut account.data.as_slice())?;
-
-        self.print_out(None, Some(&pubkey), &ncn)?;
+        self.print_account::<Ncn, jito_restaking_client::accounts::Ncn>(
+            None,
+            &pubkey,
+            &account.data,
+        )?;
 
         Ok(())
     }
@@ -1292,9 +1563,11 @@ impl RestakingCliHandler {
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
         for (index, (ncn_pubkey, ncn)) in accounts.iter().enumerate() {
-            let ncn = jito_restaking_client::accounts::Ncn::deserialize(&mut ncn.data.as_slice())?;
-
-            self.print_out(Some(index), Some(ncn_pubkey), &ncn)?;
+            self.print_account::<Ncn, jito_restaking_client::accounts::Ncn>(
+                Some(index),
+                ncn_pubkey,
+                &ncn.data,
+            )?;
         }
         Ok(())
     }
@@ -1322,15 +1595,10 @@ impl RestakingCliHandler {
             .await?;
         for (index, (ncn_operator_state_pubkey, ncn_operator_state)) in accounts.iter().enumerate()
         {
-            let ncn_operator_state =
-                jito_restaking_client::accounts::NcnOperatorState::deserialize(
-                    &mut ncn_operator_state.data.as_slice(),
-                )?;
-            self.print_out(
-                Some(index),
-                Some(ncn_operator_state_pubkey),
-                &ncn_operator_state,
-            )?;
+            self.print_account::<
+                NcnOperatorState,
+                jito_restaking_client::accounts::NcnOperatorState,
+            >(Some(index), ncn_operator_state_pubkey, &ncn_operator_state.data)?;
         }
         Ok(())
     }
@@ -1345,10 +1613,11 @@ impl RestakingCliHandler {
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
         for (index, (ticket_pubkey, ticket)) in accounts.iter().enumerate() {
-            let ticket = jito_restaking_client::accounts::NcnVaultTicket::deserialize(
-                &mut ticket.data.as_slice(),
+            self.print_account::<NcnVaultTicket, jito_restaking_client::accounts::NcnVaultTicket>(
+                Some(index),
+                ticket_pubkey,
+                &ticket.data,
             )?;
-            self.print_out(Some(index), Some(ticket_pubkey), &ticket)?;
         }
         Ok(())
     }
@@ -1357,9 +1626,11 @@ impl RestakingCliHandler {
     pub async fn get_operator(&self, pubkey: String) -> Result<()> {
         let pubkey = Pubkey::from_str(&pubkey)?;
         let account = self.get_rpc_client().get_account(&pubkey).await?;
-        let operator =
-            jito_restaking_client::accounts::Operator::deserialize(&mut account.data.as_slice())?;
-        self.print_out(None, Some(&pubkey), &operator)?;
+        self.print_account::<Operator, jito_restaking_client::accounts::Operator>(
+            None,
+            &pubkey,
+            &account.data,
+        )?;
 
         Ok(())
     }
@@ -1372,10 +1643,11 @@ impl RestakingCliHandler {
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
         for (index, (operator_pubkey, operator)) in accounts.iter().enumerate() {
-            let operator = jito_restaking_client::accounts::Operator::deserialize(
-                &mut operator.data.as_slice(),
+            self.print_account::<Operator, jito_restaking_client::accounts::Operator>(
+                Some(index),
+                operator_pubkey,
+                &operator.data,
             )?;
-            self.print_out(Some(index), Some(operator_pubkey), &operator)?;
         }
         Ok(())
     }
@@ -1389,10 +1661,10 @@ impl RestakingCliHandler {
             .get_program_accounts_with_config(&self.restaking_program_id, config)
             .await?;
         for (index, (ticket_pubkey, ticket)) in accounts.iter().enumerate() {
-            let ticket = jito_restaking_client::accounts::OperatorVaultTicket::deserialize(
-                &mut ticket.data.as_slice(),
-            )?;
-            self.print_out(Some(index), Some(ticket_pubkey), &ticket)?;
+            self.print_account::<
+                OperatorVaultTicket,
+                jito_restaking_client::accounts::OperatorVaultTicket,
+            >(Some(index), ticket_pubkey, &ticket.data)?;
         }
         Ok(())
     }
@@ -1430,4 +1702,831 @@ impl RestakingCliHandler {
 
         Ok(())
     }
+
+    #[allow(clippy::too_many_arguments, clippy::future_not_send)]
+    pub async fn ncn_initialize_metadata(
+        &self,
+        ncn: String,
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_metadata = NcnMetadata::find_program_address(&self.restaking_program_id, &ncn).0;
+
+        let mut ix_builder = InitializeNcnMetadataBuilder::new();
+        ix_builder
+            .config(config_address)
+            .ncn(ncn)
+            .ncn_metadata(ncn_metadata)
+            .metadata_admin(signer.pubkey())
+            .payer(signer.pubkey())
+            .name(name)
+            .website(website)
+            .logo_uri(logo_uri)
+            .contact(contact);
+        if let Some(description_hash) = description_hash {
+            ix_builder.description_hash(Hash::from_str(&description_hash)?.to_bytes());
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Initializing Ncn Metadata {} for Ncn {}", ncn_metadata, ncn);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnMetadata>(&ncn_metadata)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::future_not_send)]
+    pub async fn ncn_update_metadata(
+        &self,
+        ncn: String,
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_metadata = NcnMetadata::find_program_address(&self.restaking_program_id, &ncn).0;
+
+        let mut ix_builder = UpdateNcnMetadataBuilder::new();
+        ix_builder
+            .config(config_address)
+            .ncn(ncn)
+            .ncn_metadata(ncn_metadata)
+            .metadata_admin(signer.pubkey())
+            .name(name)
+            .website(website)
+            .logo_uri(logo_uri)
+            .contact(contact);
+        if let Some(description_hash) = description_hash {
+            ix_builder.description_hash(Hash::from_str(&description_hash)?.to_bytes());
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Updating Ncn Metadata {} for Ncn {}", ncn_metadata, ncn);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnMetadata>(&ncn_metadata)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn get_ncn_metadata(&self, ncn: String) -> Result<()> {
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_metadata = NcnMetadata::find_program_address(&self.restaking_program_id, &ncn).0;
+        let account = self.get_rpc_client().get_account(&ncn_metadata).await?;
+        self.print_account::<NcnMetadata, jito_restaking_client::accounts::NcnMetadata>(
+            None,
+            &ncn_metadata,
+            &account.data,
+        )?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::future_not_send)]
+    pub async fn operator_initialize_metadata(
+        &self,
+        operator: String,
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let operator = Pubkey::from_str(&operator)?;
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&self.restaking_program_id, &operator).0;
+
+        let mut ix_builder = InitializeOperatorMetadataBuilder::new();
+        ix_builder
+            .config(config_address)
+            .operator(operator)
+            .operator_metadata(operator_metadata)
+            .metadata_admin(signer.pubkey())
+            .payer(signer.pubkey())
+            .name(name)
+            .website(website)
+            .logo_uri(logo_uri)
+            .contact(contact);
+        if let Some(description_hash) = description_hash {
+            ix_builder.description_hash(Hash::from_str(&description_hash)?.to_bytes());
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Initializing Operator Metadata {} for Operator {}",
+            operator_metadata, operator
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorMetadata>(
+                    &operator_metadata,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::future_not_send)]
+    pub async fn operator_update_metadata(
+        &self,
+        operator: String,
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+        let operator = Pubkey::from_str(&operator)?;
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&self.restaking_program_id, &operator).0;
+
+        let mut ix_builder = UpdateOperatorMetadataBuilder::new();
+        ix_builder
+            .config(config_address)
+            .operator(operator)
+            .operator_metadata(operator_metadata)
+            .metadata_admin(signer.pubkey())
+            .name(name)
+            .website(website)
+            .logo_uri(logo_uri)
+            .contact(contact);
+        if let Some(description_hash) = description_hash {
+            ix_builder.description_hash(Hash::from_str(&description_hash)?.to_bytes());
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Updating Operator Metadata {} for Operator {}",
+            operator_metadata, operator
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorMetadata>(
+                    &operator_metadata,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn get_operator_metadata(&self, operator: String) -> Result<()> {
+        let operator = Pubkey::from_str(&operator)?;
+        let operator_metadata =
+            OperatorMetadata::find_program_address(&self.restaking_program_id, &operator).0;
+        let account = self
+            .get_rpc_client()
+            .get_account(&operator_metadata)
+            .await?;
+        self.print_account::<OperatorMetadata, jito_restaking_client::accounts::OperatorMetadata>(
+            None,
+            &operator_metadata,
+            &account.data,
+        )?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_set_operator_bond_requirement(
+        &self,
+        ncn: String,
+        operator_bond_mint: String,
+        min_operator_bond: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator_bond_mint = Pubkey::from_str(&operator_bond_mint)?;
+
+        let mut ix_builder = NcnSetOperatorBondRequirementBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator_bond_mint(operator_bond_mint)
+            .admin(signer.pubkey())
+            .min_operator_bond(min_operator_bond);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Setting operator bond requirement of Ncn {} to {} of mint {}",
+            ncn, min_operator_bond, operator_bond_mint
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn slash_operator_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        destination_token_account: String,
+        amount: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let destination_token_account = Pubkey::from_str(&destination_token_account)?;
+
+        let ncn_account = self
+            .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+            .await?;
+        let mint = ncn_account.operator_bond_mint;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = SlashOperatorBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .operator_bond(operator_bond)
+            .operator_bond_token_account(get_associated_token_address(&operator_bond, &mint))
+            .slasher_admin(signer.pubkey())
+            .destination_token_account(destination_token_account)
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Slashing {} from the bond of Operator {}", amount, operator);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorBond>(&operator_bond)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn operator_initialize_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        mint: String,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let mint = Pubkey::from_str(&mint)?;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+
+        let mut ix_builder = InitializeOperatorBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_bond(operator_bond)
+            .mint(mint)
+            .admin(signer.pubkey())
+            .payer(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        // The bond holds its tokens in its associated token account
+        let create_ata_ix = create_associated_token_account_idempotent(
+            &signer.pubkey(),
+            &operator_bond,
+            &mint,
+            &spl_token::id(),
+        );
+
+        info!(
+            "Initializing Operator Bond {} for Operator {} to NCN {}",
+            operator_bond, operator, ncn
+        );
+
+        self.process_transaction(&[ix, create_ata_ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorBond>(&operator_bond)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn operator_deposit_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        mint: String,
+        amount: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let mint = Pubkey::from_str(&mint)?;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+
+        let mut ix_builder = OperatorDepositBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_bond(operator_bond)
+            .mint(mint)
+            .admin(signer.pubkey())
+            .admin_token_account(get_associated_token_address(&signer.pubkey(), &mint))
+            .operator_bond_token_account(get_associated_token_address(&operator_bond, &mint))
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Depositing {} into Operator Bond {}", amount, operator_bond);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorBond>(&operator_bond)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn operator_unbond(
+        &self,
+        ncn: String,
+        operator: String,
+        mint: String,
+        amount: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let mint = Pubkey::from_str(&mint)?;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+
+        let mut ix_builder = OperatorUnbondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_bond(operator_bond)
+            .mint(mint)
+            .admin(signer.pubkey())
+            .amount(amount);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Unbonding {} from Operator Bond {}", amount, operator_bond);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorBond>(&operator_bond)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn operator_withdraw_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        mint: String,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let mint = Pubkey::from_str(&mint)?;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+
+        let mut ix_builder = OperatorWithdrawBondBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .operator_bond(operator_bond)
+            .mint(mint)
+            .admin(signer.pubkey())
+            .admin_token_account(get_associated_token_address(&signer.pubkey(), &mint))
+            .operator_bond_token_account(get_associated_token_address(&operator_bond, &mint));
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Withdrawing unbonded tokens from Operator Bond {}",
+            operator_bond
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::OperatorBond>(&operator_bond)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn get_operator_bond(
+        &self,
+        ncn: String,
+        operator: String,
+        mint: String,
+    ) -> Result<()> {
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+        let mint = Pubkey::from_str(&mint)?;
+        let operator_bond =
+            OperatorBond::find_program_address(&self.restaking_program_id, &ncn, &operator, &mint)
+                .0;
+        let account = self.get_rpc_client().get_account(&operator_bond).await?;
+        self.print_account::<OperatorBond, jito_restaking_client::accounts::OperatorBond>(
+            None,
+            &operator_bond,
+            &account.data,
+        )?;
+
+        Ok(())
+    }
+
+    /// Returns the NCN policy address if the NCN has a policy
+    #[allow(clippy::future_not_send)]
+    async fn ncn_policy_address(&self, ncn: &Pubkey) -> Result<Option<Pubkey>> {
+        let ncn_account = self
+            .get_account::<jito_restaking_client::accounts::Ncn>(ncn)
+            .await?;
+        Ok(ncn_account
+            .has_policy
+            .then(|| NcnPolicy::find_program_address(&self.restaking_program_id, ncn).0))
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn initialize_ncn_policy(&self, ncn: String) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_policy = NcnPolicy::find_program_address(&self.restaking_program_id, &ncn).0;
+
+        let mut ix_builder = InitializeNcnPolicyBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .ncn_policy(ncn_policy)
+            .admin(signer.pubkey())
+            .payer(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Initializing NCN Policy {} for Ncn {}", ncn_policy, ncn);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnPolicy>(&ncn_policy)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ncn_set_policy(
+        &self,
+        ncn: String,
+        permissionless_admission: bool,
+        min_operator_stake: u64,
+        max_operators: u64,
+        min_operator_ticket_age: u64,
+        allowed_vault_mints: Vec<String>,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_policy = NcnPolicy::find_program_address(&self.restaking_program_id, &ncn).0;
+
+        let mut ix_builder = NcnSetPolicyBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .ncn_policy(ncn_policy)
+            .admin(signer.pubkey())
+            .permissionless_admission(permissionless_admission)
+            .min_operator_stake(min_operator_stake)
+            .max_operators(max_operators)
+            .min_operator_ticket_age(min_operator_ticket_age);
+        for mint in allowed_vault_mints {
+            ix_builder
+                .add_remaining_account(AccountMeta::new_readonly(Pubkey::from_str(&mint)?, false));
+        }
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Setting NCN Policy {} for Ncn {}", ncn_policy, ncn);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnPolicy>(&ncn_policy)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn get_ncn_policy(&self, ncn: String) -> Result<()> {
+        let ncn = Pubkey::from_str(&ncn)?;
+        let ncn_policy = NcnPolicy::find_program_address(&self.restaking_program_id, &ncn).0;
+        let account = self.get_rpc_client().get_account(&ncn_policy).await?;
+        self.print_account::<NcnPolicy, jito_restaking_client::accounts::NcnPolicy>(
+            None,
+            &ncn_policy,
+            &account.data,
+        )?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_jail_operator(
+        &self,
+        ncn: String,
+        operator: String,
+        jailed_until_epoch: u64,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = NcnJailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .admin(signer.pubkey())
+            .jailed_until_epoch(jailed_until_epoch);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Jailing Operator {} in Ncn {} until epoch {}",
+            operator, ncn, jailed_until_epoch
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnOperatorState>(
+                    &ncn_operator_state,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    #[allow(clippy::future_not_send)]
+    pub async fn ncn_unjail_operator(&self, ncn: String, operator: String) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+        let operator = Pubkey::from_str(&operator)?;
+
+        let (ncn_operator_state, _, _) =
+            NcnOperatorState::find_program_address(&self.restaking_program_id, &ncn, &operator);
+
+        let mut ix_builder = NcnUnjailOperatorBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .operator(operator)
+            .ncn_operator_state(ncn_operator_state)
+            .admin(signer.pubkey());
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!("Releasing Operator {} from jail in Ncn {}", operator, ncn);
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::NcnOperatorState>(
+                    &ncn_operator_state,
+                )
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
+
+    pub async fn ncn_set_transition_epochs(
+        &self,
+        ncn: String,
+        warmup_epochs: u16,
+        cooldown_epochs: u16,
+    ) -> Result<()> {
+        let signer = self
+            .cli_config
+            .signer
+            .as_ref()
+            .ok_or_else(|| anyhow!("No signer"))?;
+
+        let ncn = Pubkey::from_str(&ncn)?;
+
+        let mut ix_builder = NcnSetTransitionEpochsBuilder::new();
+        ix_builder
+            .config(Config::find_program_address(&self.restaking_program_id).0)
+            .ncn(ncn)
+            .admin(signer.pubkey())
+            .warmup_epochs(warmup_epochs)
+            .cooldown_epochs(cooldown_epochs);
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.restaking_program_id;
+
+        info!(
+            "Setting transition epochs of Ncn {} to {} warmup and {} cooldown",
+            ncn, warmup_epochs, cooldown_epochs
+        );
+
+        self.process_transaction(&[ix], &signer.pubkey(), &[signer])
+            .await?;
+
+        if !self.print_tx {
+            let account = self
+                .get_account::<jito_restaking_client::accounts::Ncn>(&ncn)
+                .await?;
+            info!("{}", account.pretty_display());
+        }
+
+        Ok(())
+    }
 }
@@ -85,6 +85,14 @@ impl CliHandler for VaultCliHandler {
     fn print_json_with_reserves(&self) -> bool {
         self.print_json_with_reserves
     }
+
+    fn restaking_program_id(&self) -> &Pubkey {
+        &self.restaking_program_id
+    }
+
+    fn vault_program_id(&self) -> &Pubkey {
+        &self.vault_program_id
+    }
 }
 
 impl VaultCliHandler {
@@ -601,7 +609,7 @@ impl VaultCliHandler {
         rpc_client
             .send_and_confirm_transaction(&tx)
             .await
-            .map_err(|e| anyhow!(e.to_string()))?;
+            .map_err(|e| anyhow!(self.describe_transaction_error(&e, &tx)))?;
         info!("Transaction confirmed: {:?}", tx.get_signature());
 
         Ok(())
@@ -656,8 +664,11 @@ impl VaultCliHandler {
 
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -736,8 +747,11 @@ impl VaultCliHandler {
         );
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -800,8 +814,11 @@ impl VaultCliHandler {
         );
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
         info!("Transaction confirmed: {:?}", tx.get_signature());
 
@@ -890,8 +907,11 @@ impl VaultCliHandler {
         info!("Mint to transaction: {:?}", tx.get_signature());
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -1225,8 +1245,11 @@ impl VaultCliHandler {
         info!("Delegating token account: {:?}", tx.get_signature());
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -1272,8 +1295,11 @@ impl VaultCliHandler {
         info!("Delegating token transfer: {:?}", tx.get_signature());
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -1479,8 +1505,11 @@ impl VaultCliHandler {
         );
         let result = rpc_client.send_and_confirm_transaction(&tx).await;
 
-        if result.is_err() {
-            return Err(anyhow::anyhow!("Transaction failed: {:?}", result.err()));
+        if let Err(e) = result {
+            return Err(anyhow::anyhow!(
+                "Transaction failed: {}",
+                self.describe_transaction_error(&e, &tx)
+            ));
         }
 
         info!("Transaction confirmed: {:?}", tx.get_signature());
@@ -1508,7 +1537,11 @@ impl VaultCliHandler {
         )
         .0;
 
-        self.print_out(None, Some(&pubkey), &vault)?;
+        self.print_account::<Vault, jito_vault_client::accounts::Vault>(
+            None,
+            &pubkey,
+            &vault_account.data,
+        )?;
 
         if let Ok(metadata) = self
             .get_account::<jito_vault_client::log::metadata::Metadata>(&metadata_pubkey)
@@ -1529,9 +1562,10 @@ impl VaultCliHandler {
             .await
             .unwrap();
         log::info!("{:?}", accounts);
-        for (index, (vault_pubkey, vault)) in accounts.iter().enumerate() {
-            let vault =
-                jito_vault_client::accounts::Vault::deserialize(&mut vault.data.as_slice())?;
+        for (index, (vault_pubkey, vault_account)) in accounts.iter().enumerate() {
+            let vault = jito_vault_client::accounts::Vault::deserialize(
+                &mut vault_account.data.as_slice(),
+            )?;
 
             let metadata_pubkey = Pubkey::find_program_address(
                 &[
@@ -1543,7 +1577,11 @@ impl VaultCliHandler {
             )
             .0;
 
-            self.print_out(Some(index), Some(vault_pubkey), &vault)?;
+            self.print_account::<Vault, jito_vault_client::accounts::Vault>(
+                Some(index),
+                vault_pubkey,
+                &vault_account.data,
+            )?;
 
             if let Ok(metadata) = self
                 .get_account::<jito_vault_client::log::metadata::Metadata>(&metadata_pubkey)
@@ -1566,9 +1604,11 @@ impl VaultCliHandler {
         );
 
         let account = rpc_client.get_account(&config_address).await?;
-        let config =
-            jito_vault_client::accounts::Config::deserialize(&mut account.data.as_slice())?;
-        self.print_out(None, Some(&config_address), &config)?;
+        self.print_account::<Config, jito_vault_client::accounts::Config>(
+            None,
+            &config_address,
+            &account.data,
+        )?;
         Ok(())
     }
 
@@ -1592,10 +1632,10 @@ impl VaultCliHandler {
         )
         .0;
         let account = rpc_client.get_account(&vault_update_state_tracker).await?;
-        let state_tracker = jito_vault_client::accounts::VaultUpdateStateTracker::deserialize(
-            &mut account.data.as_slice(),
-        )?;
-        self.print_out(None, Some(&vault_update_state_tracker), &state_tracker)?;
+        self.print_account::<
+            VaultUpdateStateTracker,
+            jito_vault_client::accounts::VaultUpdateStateTracker,
+        >(None, &vault_update_state_tracker, &account.data)?;
         Ok(())
     }
 
@@ -1622,11 +1662,10 @@ impl VaultCliHandler {
                 .0;
                 let account = rpc_client.get_account(&vault_operator_delegation).await?;
 
-                let delegation = jito_vault_client::accounts::VaultOperatorDelegation::deserialize(
-                    &mut account.data.as_slice(),
-                )?;
-
-                self.print_out(None, Some(&vault_operator_delegation), &delegation)?;
+                self.print_account::<
+                    VaultOperatorDelegation,
+                    jito_vault_client::accounts::VaultOperatorDelegation,
+                >(None, &vault_operator_delegation, &account.data)?;
             }
             None => {
                 let config = self.get_rpc_program_accounts_config::<VaultOperatorDelegation>(
@@ -1637,12 +1676,10 @@ impl VaultCliHandler {
                     .await?;
 
                 for (index, (pubkey, account)) in accounts.iter().enumerate() {
-                    let vault_operator_delegation =
-                        jito_vault_client::accounts::VaultOperatorDelegation::deserialize(
-                            &mut account.data.as_slice(),
-                        )?;
-
-                    self.print_out(Some(index), Some(pubkey), &vault_operator_delegation)?;
+                    self.print_account::<
+                        VaultOperatorDelegation,
+                        jito_vault_client::accounts::VaultOperatorDelegation,
+                    >(Some(index), pubkey, &account.data)?;
                 }
             }
         }
@@ -1673,10 +1710,10 @@ impl VaultCliHandler {
         let account = rpc_client
             .get_account(&vault_staker_withdrawal_ticket)
             .await?;
-        let ticket = jito_vault_client::accounts::VaultStakerWithdrawalTicket::deserialize(
-            &mut account.data.as_slice(),
-        )?;
-        self.print_out(None, Some(&vault_staker_withdrawal_ticket), &ticket)?;
+        self.print_account::<
+            VaultStakerWithdrawalTicket,
+            jito_vault_client::accounts::VaultStakerWithdrawalTicket,
+        >(None, &vault_staker_withdrawal_ticket, &account.data)?;
 
         Ok(())
     }
@@ -8,8 +8,12 @@
 
 export * from './config';
 export * from './ncn';
+export * from './ncnMetadata';
 export * from './ncnOperatorState';
+export * from './ncnPolicy';
 export * from './ncnVaultSlasherTicket';
 export * from './ncnVaultTicket';
 export * from './operator';
+export * from './operatorBond';
+export * from './operatorMetadata';
 export * from './operatorVaultTicket';
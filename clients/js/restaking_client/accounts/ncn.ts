@@ -17,6 +17,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -34,6 +36,12 @@ import {
   type MaybeAccount,
   type MaybeEncodedAccount,
 } from '@solana/web3.js';
+import {
+  getTransitionEpochsDecoder,
+  getTransitionEpochsEncoder,
+  type TransitionEpochs,
+  type TransitionEpochsArgs,
+} from '../types';
 
 export type Ncn = {
   discriminator: bigint;
@@ -51,6 +59,10 @@ export type Ncn = {
   vaultCount: bigint;
   slasherCount: bigint;
   bump: number;
+  operatorBondMint: Address;
+  minOperatorBond: bigint;
+  hasPolicy: boolean;
+  transitionEpochs: TransitionEpochs;
   reserved: Array<number>;
 };
 
@@ -70,6 +82,10 @@ export type NcnArgs = {
   vaultCount: number | bigint;
   slasherCount: number | bigint;
   bump: number;
+  operatorBondMint: Address;
+  minOperatorBond: number | bigint;
+  hasPolicy: boolean;
+  transitionEpochs: TransitionEpochsArgs;
   reserved: Array<number>;
 };
 
@@ -90,7 +106,11 @@ export function getNcnEncoder(): Encoder<NcnArgs> {
     ['vaultCount', getU64Encoder()],
     ['slasherCount', getU64Encoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['operatorBondMint', getAddressEncoder()],
+    ['minOperatorBond', getU64Encoder()],
+    ['hasPolicy', getBooleanEncoder()],
+    ['transitionEpochs', getTransitionEpochsEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 217 })],
   ]);
 }
 
@@ -111,7 +131,11 @@ export function getNcnDecoder(): Decoder<Ncn> {
     ['vaultCount', getU64Decoder()],
     ['slasherCount', getU64Decoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['operatorBondMint', getAddressDecoder()],
+    ['minOperatorBond', getU64Decoder()],
+    ['hasPolicy', getBooleanDecoder()],
+    ['transitionEpochs', getTransitionEpochsDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 217 })],
   ]);
 }
 
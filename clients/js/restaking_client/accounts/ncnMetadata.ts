@@ -0,0 +1,135 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  getMetadataDecoder,
+  getMetadataEncoder,
+  type Metadata,
+  type MetadataArgs,
+} from '../types';
+
+export type NcnMetadata = {
+  discriminator: bigint;
+  ncn: Address;
+  metadata: Metadata;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type NcnMetadataArgs = {
+  discriminator: number | bigint;
+  ncn: Address;
+  metadata: MetadataArgs;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getNcnMetadataEncoder(): Encoder<NcnMetadataArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['ncn', getAddressEncoder()],
+    ['metadata', getMetadataEncoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getNcnMetadataDecoder(): Decoder<NcnMetadata> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['ncn', getAddressDecoder()],
+    ['metadata', getMetadataDecoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getNcnMetadataCodec(): Codec<NcnMetadataArgs, NcnMetadata> {
+  return combineCodec(getNcnMetadataEncoder(), getNcnMetadataDecoder());
+}
+
+export function decodeNcnMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<NcnMetadata, TAddress>;
+export function decodeNcnMetadata<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<NcnMetadata, TAddress>;
+export function decodeNcnMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<NcnMetadata, TAddress> | MaybeAccount<NcnMetadata, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getNcnMetadataDecoder()
+  );
+}
+
+export async function fetchNcnMetadata<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<NcnMetadata, TAddress>> {
+  const maybeAccount = await fetchMaybeNcnMetadata(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnMetadata<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<NcnMetadata, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeNcnMetadata(maybeAccount);
+}
+
+export async function fetchAllNcnMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<NcnMetadata>[]> {
+  const maybeAccounts = await fetchAllMaybeNcnMetadata(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeNcnMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<NcnMetadata>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeNcnMetadata(maybeAccount));
+}
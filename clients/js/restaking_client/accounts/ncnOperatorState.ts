@@ -17,6 +17,8 @@ import {
   getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -49,6 +51,8 @@ export type NcnOperatorState = {
   ncnOptInState: SlotToggle;
   operatorOptInState: SlotToggle;
   bump: number;
+  policyAdmitted: boolean;
+  jailedUntilEpoch: bigint;
   reserved: Array<number>;
 };
 
@@ -60,6 +64,8 @@ export type NcnOperatorStateArgs = {
   ncnOptInState: SlotToggleArgs;
   operatorOptInState: SlotToggleArgs;
   bump: number;
+  policyAdmitted: boolean;
+  jailedUntilEpoch: number | bigint;
   reserved: Array<number>;
 };
 
@@ -72,7 +78,9 @@ export function getNcnOperatorStateEncoder(): Encoder<NcnOperatorStateArgs> {
     ['ncnOptInState', getSlotToggleEncoder()],
     ['operatorOptInState', getSlotToggleEncoder()],
     ['bump', getU8Encoder()],
-    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+    ['policyAdmitted', getBooleanEncoder()],
+    ['jailedUntilEpoch', getU64Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 254 })],
   ]);
 }
 
@@ -85,7 +93,9 @@ export function getNcnOperatorStateDecoder(): Decoder<NcnOperatorState> {
     ['ncnOptInState', getSlotToggleDecoder()],
     ['operatorOptInState', getSlotToggleDecoder()],
     ['bump', getU8Decoder()],
-    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+    ['policyAdmitted', getBooleanDecoder()],
+    ['jailedUntilEpoch', getU64Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 254 })],
   ]);
 }
 
@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type NcnPolicy = {
+  discriminator: bigint;
+  ncn: Address;
+  allowedVaultMints: Array<Address>;
+  minOperatorStake: bigint;
+  maxOperators: bigint;
+  admittedOperatorCount: bigint;
+  minOperatorTicketAge: bigint;
+  permissionlessAdmission: boolean;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type NcnPolicyArgs = {
+  discriminator: number | bigint;
+  ncn: Address;
+  allowedVaultMints: Array<Address>;
+  minOperatorStake: number | bigint;
+  maxOperators: number | bigint;
+  admittedOperatorCount: number | bigint;
+  minOperatorTicketAge: number | bigint;
+  permissionlessAdmission: boolean;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getNcnPolicyEncoder(): Encoder<NcnPolicyArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['ncn', getAddressEncoder()],
+    ['allowedVaultMints', getArrayEncoder(getAddressEncoder(), { size: 8 })],
+    ['minOperatorStake', getU64Encoder()],
+    ['maxOperators', getU64Encoder()],
+    ['admittedOperatorCount', getU64Encoder()],
+    ['minOperatorTicketAge', getU64Encoder()],
+    ['permissionlessAdmission', getBooleanEncoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getNcnPolicyDecoder(): Decoder<NcnPolicy> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['ncn', getAddressDecoder()],
+    ['allowedVaultMints', getArrayDecoder(getAddressDecoder(), { size: 8 })],
+    ['minOperatorStake', getU64Decoder()],
+    ['maxOperators', getU64Decoder()],
+    ['admittedOperatorCount', getU64Decoder()],
+    ['minOperatorTicketAge', getU64Decoder()],
+    ['permissionlessAdmission', getBooleanDecoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getNcnPolicyCodec(): Codec<NcnPolicyArgs, NcnPolicy> {
+  return combineCodec(getNcnPolicyEncoder(), getNcnPolicyDecoder());
+}
+
+export function decodeNcnPolicy<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<NcnPolicy, TAddress>;
+export function decodeNcnPolicy<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<NcnPolicy, TAddress>;
+export function decodeNcnPolicy<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<NcnPolicy, TAddress> | MaybeAccount<NcnPolicy, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getNcnPolicyDecoder()
+  );
+}
+
+export async function fetchNcnPolicy<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<NcnPolicy, TAddress>> {
+  const maybeAccount = await fetchMaybeNcnPolicy(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeNcnPolicy<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<NcnPolicy, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeNcnPolicy(maybeAccount);
+}
+
+export async function fetchAllNcnPolicy(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<NcnPolicy>[]> {
+  const maybeAccounts = await fetchAllMaybeNcnPolicy(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeNcnPolicy(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<NcnPolicy>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeNcnPolicy(maybeAccount));
+}
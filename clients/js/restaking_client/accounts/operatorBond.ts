@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+
+export type OperatorBond = {
+  discriminator: bigint;
+  ncn: Address;
+  operator: Address;
+  mint: Address;
+  amount: bigint;
+  slashed: bigint;
+  unbondingAmount: bigint;
+  withdrawableEpoch: bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type OperatorBondArgs = {
+  discriminator: number | bigint;
+  ncn: Address;
+  operator: Address;
+  mint: Address;
+  amount: number | bigint;
+  slashed: number | bigint;
+  unbondingAmount: number | bigint;
+  withdrawableEpoch: number | bigint;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getOperatorBondEncoder(): Encoder<OperatorBondArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['ncn', getAddressEncoder()],
+    ['operator', getAddressEncoder()],
+    ['mint', getAddressEncoder()],
+    ['amount', getU64Encoder()],
+    ['slashed', getU64Encoder()],
+    ['unbondingAmount', getU64Encoder()],
+    ['withdrawableEpoch', getU64Encoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 215 })],
+  ]);
+}
+
+export function getOperatorBondDecoder(): Decoder<OperatorBond> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['ncn', getAddressDecoder()],
+    ['operator', getAddressDecoder()],
+    ['mint', getAddressDecoder()],
+    ['amount', getU64Decoder()],
+    ['slashed', getU64Decoder()],
+    ['unbondingAmount', getU64Decoder()],
+    ['withdrawableEpoch', getU64Decoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 215 })],
+  ]);
+}
+
+export function getOperatorBondCodec(): Codec<OperatorBondArgs, OperatorBond> {
+  return combineCodec(getOperatorBondEncoder(), getOperatorBondDecoder());
+}
+
+export function decodeOperatorBond<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<OperatorBond, TAddress>;
+export function decodeOperatorBond<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<OperatorBond, TAddress>;
+export function decodeOperatorBond<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+): Account<OperatorBond, TAddress> | MaybeAccount<OperatorBond, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getOperatorBondDecoder()
+  );
+}
+
+export async function fetchOperatorBond<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<OperatorBond, TAddress>> {
+  const maybeAccount = await fetchMaybeOperatorBond(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorBond<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<OperatorBond, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeOperatorBond(maybeAccount);
+}
+
+export async function fetchAllOperatorBond(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<OperatorBond>[]> {
+  const maybeAccounts = await fetchAllMaybeOperatorBond(rpc, addresses, config);
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeOperatorBond(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<OperatorBond>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) => decodeOperatorBond(maybeAccount));
+}
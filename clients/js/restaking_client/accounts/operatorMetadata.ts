@@ -0,0 +1,151 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  assertAccountExists,
+  assertAccountsExist,
+  combineCodec,
+  decodeAccount,
+  fetchEncodedAccount,
+  fetchEncodedAccounts,
+  getAddressDecoder,
+  getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Account,
+  type Address,
+  type Codec,
+  type Decoder,
+  type EncodedAccount,
+  type Encoder,
+  type FetchAccountConfig,
+  type FetchAccountsConfig,
+  type MaybeAccount,
+  type MaybeEncodedAccount,
+} from '@solana/web3.js';
+import {
+  getMetadataDecoder,
+  getMetadataEncoder,
+  type Metadata,
+  type MetadataArgs,
+} from '../types';
+
+export type OperatorMetadata = {
+  discriminator: bigint;
+  operator: Address;
+  metadata: Metadata;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export type OperatorMetadataArgs = {
+  discriminator: number | bigint;
+  operator: Address;
+  metadata: MetadataArgs;
+  bump: number;
+  reserved: Array<number>;
+};
+
+export function getOperatorMetadataEncoder(): Encoder<OperatorMetadataArgs> {
+  return getStructEncoder([
+    ['discriminator', getU64Encoder()],
+    ['operator', getAddressEncoder()],
+    ['metadata', getMetadataEncoder()],
+    ['bump', getU8Encoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 263 })],
+  ]);
+}
+
+export function getOperatorMetadataDecoder(): Decoder<OperatorMetadata> {
+  return getStructDecoder([
+    ['discriminator', getU64Decoder()],
+    ['operator', getAddressDecoder()],
+    ['metadata', getMetadataDecoder()],
+    ['bump', getU8Decoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 263 })],
+  ]);
+}
+
+export function getOperatorMetadataCodec(): Codec<
+  OperatorMetadataArgs,
+  OperatorMetadata
+> {
+  return combineCodec(
+    getOperatorMetadataEncoder(),
+    getOperatorMetadataDecoder()
+  );
+}
+
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress>
+): Account<OperatorMetadata, TAddress>;
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: MaybeEncodedAccount<TAddress>
+): MaybeAccount<OperatorMetadata, TAddress>;
+export function decodeOperatorMetadata<TAddress extends string = string>(
+  encodedAccount: EncodedAccount<TAddress> | MaybeEncodedAccount<TAddress>
+):
+  | Account<OperatorMetadata, TAddress>
+  | MaybeAccount<OperatorMetadata, TAddress> {
+  return decodeAccount(
+    encodedAccount as MaybeEncodedAccount<TAddress>,
+    getOperatorMetadataDecoder()
+  );
+}
+
+export async function fetchOperatorMetadata<TAddress extends string = string>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<Account<OperatorMetadata, TAddress>> {
+  const maybeAccount = await fetchMaybeOperatorMetadata(rpc, address, config);
+  assertAccountExists(maybeAccount);
+  return maybeAccount;
+}
+
+export async function fetchMaybeOperatorMetadata<
+  TAddress extends string = string,
+>(
+  rpc: Parameters<typeof fetchEncodedAccount>[0],
+  address: Address<TAddress>,
+  config?: FetchAccountConfig
+): Promise<MaybeAccount<OperatorMetadata, TAddress>> {
+  const maybeAccount = await fetchEncodedAccount(rpc, address, config);
+  return decodeOperatorMetadata(maybeAccount);
+}
+
+export async function fetchAllOperatorMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<Account<OperatorMetadata>[]> {
+  const maybeAccounts = await fetchAllMaybeOperatorMetadata(
+    rpc,
+    addresses,
+    config
+  );
+  assertAccountsExist(maybeAccounts);
+  return maybeAccounts;
+}
+
+export async function fetchAllMaybeOperatorMetadata(
+  rpc: Parameters<typeof fetchEncodedAccounts>[0],
+  addresses: Array<Address>,
+  config?: FetchAccountsConfig
+): Promise<MaybeAccount<OperatorMetadata>[]> {
+  const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
+  return maybeAccounts.map((maybeAccount) =>
+    decodeOperatorMetadata(maybeAccount)
+  );
+}
@@ -14,90 +14,189 @@ import {
 } from '@solana/web3.js';
 import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
 
+/** NcnOperatorAdminInvalid: Signer is not the NCN's operator admin */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID = 0x1388; // 5000
+/** NcnCooldownOperatorFailed: NCN operator state cannot be cooled down by the NCN in its current state */
+export const JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED = 0x1389; // 5001
+/** NcnSlasherAdminInvalid: Signer is not the NCN's slasher admin */
+export const JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID = 0x138a; // 5002
+/** NcnVaultAdminInvalid: Signer is not the NCN's vault admin */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID = 0x138b; // 5003
+/** NcnAdminInvalid: Signer is not the NCN admin */
+export const JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID = 0x138c; // 5004
+/** NcnDelegateAdminInvalid: Signer is not the NCN's delegate admin */
+export const JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID = 0x138d; // 5005
+/** NcnVaultSlasherTicketFailedCooldown: NCN vault slasher ticket cannot be cooled down in its current state */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN = 0x138e; // 5006
+/** NcnVaultTicketFailedCooldown: NCN vault ticket cannot be cooled down in its current state */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN = 0x138f; // 5007
+/** NcnWarmupOperatorFailed: NCN operator state cannot be warmed up by the NCN in its current state */
+export const JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED = 0x1390; // 5008
+/** NcnVaultSlasherTicketFailedWarmup: NCN vault slasher ticket cannot be warmed up in its current state */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP = 0x1391; // 5009
+/** NcnVaultTicketFailedWarmup: NCN vault ticket cannot be warmed up in its current state */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP = 0x1392; // 5010
+/** NcnMetadataAdminInvalid: Signer is not the NCN's metadata admin */
+export const JITO_RESTAKING_ERROR__NCN_METADATA_ADMIN_INVALID = 0x1393; // 5011
+/** NcnOperatorBondInsufficient: Operator bond is below the NCN's minimum bond */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_BOND_INSUFFICIENT = 0x1394; // 5012
+/** NcnPolicyMissing: NCN has no operator policy */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_MISSING = 0x1395; // 5013
+/** NcnPolicyTooManyVaultMints: NCN policy allows too many vault mints */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_TOO_MANY_VAULT_MINTS = 0x1396; // 5014
+/** NcnPolicyVaultMintNotAllowed: Vault mint is not allowed by the NCN policy */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_MINT_NOT_ALLOWED = 0x1397; // 5015
+/** NcnPolicyOperatorStakeInsufficient: Operator stake is below the NCN policy minimum */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_STAKE_INSUFFICIENT = 0x1398; // 5016
+/** NcnPolicyOperatorTicketTooNew: Operator ticket is too new for the NCN policy */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_TICKET_TOO_NEW = 0x1399; // 5017
+/** NcnPolicyMaxOperatorsReached: NCN policy maximum number of operators reached */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_MAX_OPERATORS_REACHED = 0x139a; // 5018
+/** NcnJailAdminInvalid: Signer is not the NCN's jail admin */
+export const JITO_RESTAKING_ERROR__NCN_JAIL_ADMIN_INVALID = 0x139b; // 5019
+/** NcnOperatorJailEpochInvalid: Jail end epoch must be after the current epoch */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_JAIL_EPOCH_INVALID = 0x139c; // 5020
+/** NcnOperatorNotJailed: Operator is not jailed by the NCN */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED = 0x139d; // 5021
+/** NcnTransitionEpochsInvalid: Warmup and cooldown epochs are invalid */
+export const JITO_RESTAKING_ERROR__NCN_TRANSITION_EPOCHS_INVALID = 0x139e; // 5022
+/** NcnOperatorJailed: Operator is jailed by the NCN */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_JAILED = 0x139f; // 5023
+/** NcnOperatorStateInactive: NCN operator state is not active */
+export const JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_INACTIVE = 0x13a0; // 5024
+/** NcnVaultTicketInactive: NCN vault ticket is not active */
+export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_INACTIVE = 0x13a1; // 5025
+/** NcnPolicyVaultUpdateNeeded: Vault is not updated for the current epoch */
+export const JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_UPDATE_NEEDED = 0x13a2; // 5026
+/** OperatorNcnAdminInvalid: Signer is not the operator's NCN admin */
+export const JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID = 0x1770; // 6000
+/** OperatorVaultAdminInvalid: Signer is not the operator's vault admin */
+export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID = 0x1771; // 6001
+/** OperatorAdminInvalid: Signer is not the operator admin */
+export const JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID = 0x1772; // 6002
+/** OperatorDelegateAdminInvalid: Signer is not the operator's delegate admin */
+export const JITO_RESTAKING_ERROR__OPERATOR_DELEGATE_ADMIN_INVALID = 0x1773; // 6003
+/** OperatorCooldownNcnFailed: NCN operator state cannot be cooled down by the operator in its current state */
+export const JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED = 0x1774; // 6004
+/** OperatorVaultTicketFailedCooldown: Operator vault ticket cannot be cooled down in its current state */
+export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN = 0x1775; // 6005
+/** OperatorVaultTicketFailedWarmup: Operator vault ticket cannot be warmed up in its current state */
+export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP = 0x1776; // 6006
+/** OperatorWarmupNcnFailed: NCN operator state cannot be warmed up by the operator in its current state */
+export const JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED = 0x1777; // 6007
+/** OperatorFeeCapExceeded: Operator fee exceeds the maximum allowed fee */
+export const JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED = 0x1778; // 6008
+/** NcnOverflow: NCN count overflowed */
+export const JITO_RESTAKING_ERROR__NCN_OVERFLOW = 0x1779; // 6009
+/** OperatorOverflow: Operator count overflowed */
+export const JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW = 0x177a; // 6010
+/** VaultOverflow: Vault count overflowed */
+export const JITO_RESTAKING_ERROR__VAULT_OVERFLOW = 0x177b; // 6011
+/** SlasherOverflow: Slasher count overflowed */
+export const JITO_RESTAKING_ERROR__SLASHER_OVERFLOW = 0x177c; // 6012
+/** InvalidEpochLength: Epoch length is invalid */
+export const JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH = 0x177d; // 6013
+/** ConfigAdminInvalid: Signer is not the config admin */
+export const JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID = 0x177e; // 6014
+/** OperatorMetadataAdminInvalid: Signer is not the operator's metadata admin */
+export const JITO_RESTAKING_ERROR__OPERATOR_METADATA_ADMIN_INVALID = 0x177f; // 6015
+/** MetadataFieldTooLong: Metadata field is too long */
+export const JITO_RESTAKING_ERROR__METADATA_FIELD_TOO_LONG = 0x1780; // 6016
+/** OperatorBondAmountZero: Bond amount must be non-zero */
+export const JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_ZERO = 0x1781; // 6017
+/** OperatorBondAmountExceeded: Amount exceeds the bonded amount */
+export const JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_EXCEEDED = 0x1782; // 6018
+/** OperatorBondCooldownActive: Unbonded tokens are still cooling down */
+export const JITO_RESTAKING_ERROR__OPERATOR_BOND_COOLDOWN_ACTIVE = 0x1783; // 6019
+/** MetadataFieldInvalid: Metadata field is not zero-padded UTF-8 or not a valid flag */
+export const JITO_RESTAKING_ERROR__METADATA_FIELD_INVALID = 0x1784; // 6020
+/** ArithmeticOverflow: Arithmetic overflow */
+export const JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW = 0x1b58; // 7000
+/** ArithmeticUnderflow: Arithmetic underflow */
+export const JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW = 0x1b59; // 7001
+/** DivisionByZero: Division by zero */
+export const JITO_RESTAKING_ERROR__DIVISION_BY_ZERO = 0x1b5a; // 7002
 /** BadEpochLength: Bad epoch length */
-export const JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH = 0x0; // 0
-/** NcnOperatorAdminInvalid: NcnOperatorAdminInvalid */
-export const JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID = 0x3e8; // 1000
-/** NcnCooldownOperatorFailed: NcnCooldownOperatorFailed */
-export const JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED = 0x3e9; // 1001
-/** NcnSlasherAdminInvalid: NcnSlasherAdminInvalid */
-export const JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID = 0x3ea; // 1002
-/** NcnVaultAdminInvalid: NcnVaultAdminInvalid */
-export const JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID = 0x3eb; // 1003
-/** NcnAdminInvalid: NcnAdminInvalid */
-export const JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID = 0x3ec; // 1004
-/** NcnDelegateAdminInvalid: NcnDelegateAdminInvalid */
-export const JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID = 0x3ed; // 1005
-/** NcnVaultSlasherTicketFailedCooldown: NcnVaultSlasherTicketFailedCooldown */
-export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN = 0x3ee; // 1006
-/** NcnVaultTicketFailedCooldown: NcnVaultTicketFailedCooldown */
-export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN = 0x3ef; // 1007
-/** NcnWarmupOperatorFailed: NcnWarmupOperatorFailed */
-export const JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED = 0x3f0; // 1008
-/** NcnVaultSlasherTicketFailedWarmup: NcnVaultSlasherTicketFailedWarmup */
-export const JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP = 0x3f1; // 1009
-/** NcnVaultTicketFailedWarmup: NcnVaultTicketFailedWarmup */
-export const JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP = 0x3f2; // 1010
-/** OperatorNcnAdminInvalid: OperatorNcnAdminInvalid */
-export const JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID = 0x7d0; // 2000
-/** OperatorVaultAdminInvalid: OperatorVaultAdminInvalid */
-export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID = 0x7d1; // 2001
-/** OperatorAdminInvalid: OperatorAdminInvalid */
-export const JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID = 0x7d2; // 2002
-/** OperatorDelegateAdminInvalid: OperatorDelegateAdminInvalid */
-export const JITO_RESTAKING_ERROR__OPERATOR_DELEGATE_ADMIN_INVALID = 0x7d3; // 2003
-/** OperatorCooldownNcnFailed: OperatorCooldownNcnFailed */
-export const JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED = 0x7d4; // 2004
-/** OperatorVaultTicketFailedCooldown: OperatorVaultTicketFailedCooldown */
-export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN = 0x7d5; // 2005
-/** OperatorVaultTicketFailedWarmup: OperatorVaultTicketFailedWarmup */
-export const JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP = 0x7d6; // 2006
-/** OperatorWarmupNcnFailed: OperatorWarmupNcnFailed */
-export const JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED = 0x7d7; // 2007
-/** OperatorFeeCapExceeded: OperatorFeeCapExceeded */
-export const JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED = 0x7d8; // 2008
-/** NcnOverflow: NcnOverflow */
-export const JITO_RESTAKING_ERROR__NCN_OVERFLOW = 0x7d9; // 2009
-/** OperatorOverflow: OperatorOverflow */
-export const JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW = 0x7da; // 2010
-/** VaultOverflow: VaultOverflow */
-export const JITO_RESTAKING_ERROR__VAULT_OVERFLOW = 0x7db; // 2011
-/** SlasherOverflow: SlasherOverflow */
-export const JITO_RESTAKING_ERROR__SLASHER_OVERFLOW = 0x7dc; // 2012
-/** InvalidEpochLength: InvalidEpochLength */
-export const JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH = 0x7dd; // 2013
-/** ConfigAdminInvalid: ConfigAdminInvalid */
-export const JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID = 0x7de; // 2014
-/** ArithmeticOverflow: ArithmeticOverflow */
-export const JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW = 0xbb8; // 3000
-/** ArithmeticUnderflow: ArithmeticUnderflow */
-export const JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW = 0xbb9; // 3001
-/** DivisionByZero: DivisionByZero */
-export const JITO_RESTAKING_ERROR__DIVISION_BY_ZERO = 0xbba; // 3002
+export const JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH = 0xfa0; // 4000
+/** AccountNotSigner: not a signer */
+export const JITO_RESTAKING_ERROR__ACCOUNT_NOT_SIGNER = 0x1004; // 4100
+/** AccountNotWritable: not writable */
+export const JITO_RESTAKING_ERROR__ACCOUNT_NOT_WRITABLE = 0x1005; // 4101
+/** InvalidAccountOwner: wrong owner */
+export const JITO_RESTAKING_ERROR__INVALID_ACCOUNT_OWNER = 0x1006; // 4102
+/** AccountDataEmpty: account data is empty */
+export const JITO_RESTAKING_ERROR__ACCOUNT_DATA_EMPTY = 0x1007; // 4103
+/** AccountAlreadyInitialized: account is already initialized */
+export const JITO_RESTAKING_ERROR__ACCOUNT_ALREADY_INITIALIZED = 0x1008; // 4104
+/** InvalidAccountData: account data is invalid */
+export const JITO_RESTAKING_ERROR__INVALID_ACCOUNT_DATA = 0x1009; // 4105
+/** InvalidDiscriminator: wrong discriminator */
+export const JITO_RESTAKING_ERROR__INVALID_DISCRIMINATOR = 0x100a; // 4106
+/** InvalidAddress: not at the expected address */
+export const JITO_RESTAKING_ERROR__INVALID_ADDRESS = 0x100b; // 4107
+/** InvalidProgramId: wrong program */
+export const JITO_RESTAKING_ERROR__INVALID_PROGRAM_ID = 0x100c; // 4108
+/** InvalidTokenOwner: wrong token account owner */
+export const JITO_RESTAKING_ERROR__INVALID_TOKEN_OWNER = 0x100d; // 4109
+/** InvalidMint: wrong mint */
+export const JITO_RESTAKING_ERROR__INVALID_MINT = 0x100e; // 4110
 
 export type JitoRestakingError =
+  | typeof JITO_RESTAKING_ERROR__ACCOUNT_ALREADY_INITIALIZED
+  | typeof JITO_RESTAKING_ERROR__ACCOUNT_DATA_EMPTY
+  | typeof JITO_RESTAKING_ERROR__ACCOUNT_NOT_SIGNER
+  | typeof JITO_RESTAKING_ERROR__ACCOUNT_NOT_WRITABLE
   | typeof JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW
   | typeof JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW
   | typeof JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH
   | typeof JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__DIVISION_BY_ZERO
+  | typeof JITO_RESTAKING_ERROR__INVALID_ACCOUNT_DATA
+  | typeof JITO_RESTAKING_ERROR__INVALID_ACCOUNT_OWNER
+  | typeof JITO_RESTAKING_ERROR__INVALID_ADDRESS
+  | typeof JITO_RESTAKING_ERROR__INVALID_DISCRIMINATOR
   | typeof JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH
+  | typeof JITO_RESTAKING_ERROR__INVALID_MINT
+  | typeof JITO_RESTAKING_ERROR__INVALID_PROGRAM_ID
+  | typeof JITO_RESTAKING_ERROR__INVALID_TOKEN_OWNER
+  | typeof JITO_RESTAKING_ERROR__METADATA_FIELD_INVALID
+  | typeof JITO_RESTAKING_ERROR__METADATA_FIELD_TOO_LONG
   | typeof JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED
   | typeof JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_JAIL_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_METADATA_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_BOND_INSUFFICIENT
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_JAILED
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_JAIL_EPOCH_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED
+  | typeof JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_INACTIVE
   | typeof JITO_RESTAKING_ERROR__NCN_OVERFLOW
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_MAX_OPERATORS_REACHED
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_MISSING
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_STAKE_INSUFFICIENT
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_TICKET_TOO_NEW
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_TOO_MANY_VAULT_MINTS
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_MINT_NOT_ALLOWED
+  | typeof JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_UPDATE_NEEDED
   | typeof JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__NCN_TRANSITION_EPOCHS_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN
   | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP
+  | typeof JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_INACTIVE
   | typeof JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED
   | typeof JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_EXCEEDED
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_ZERO
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_BOND_COOLDOWN_ACTIVE
   | typeof JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED
   | typeof JITO_RESTAKING_ERROR__OPERATOR_DELEGATE_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED
+  | typeof JITO_RESTAKING_ERROR__OPERATOR_METADATA_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID
   | typeof JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW
   | typeof JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID
@@ -110,36 +209,69 @@ export type JitoRestakingError =
 let jitoRestakingErrorMessages: Record<JitoRestakingError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   jitoRestakingErrorMessages = {
-    [JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW]: `ArithmeticOverflow`,
-    [JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW]: `ArithmeticUnderflow`,
+    [JITO_RESTAKING_ERROR__ACCOUNT_ALREADY_INITIALIZED]: `account is already initialized`,
+    [JITO_RESTAKING_ERROR__ACCOUNT_DATA_EMPTY]: `account data is empty`,
+    [JITO_RESTAKING_ERROR__ACCOUNT_NOT_SIGNER]: `not a signer`,
+    [JITO_RESTAKING_ERROR__ACCOUNT_NOT_WRITABLE]: `not writable`,
+    [JITO_RESTAKING_ERROR__ARITHMETIC_OVERFLOW]: `Arithmetic overflow`,
+    [JITO_RESTAKING_ERROR__ARITHMETIC_UNDERFLOW]: `Arithmetic underflow`,
     [JITO_RESTAKING_ERROR__BAD_EPOCH_LENGTH]: `Bad epoch length`,
-    [JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID]: `ConfigAdminInvalid`,
-    [JITO_RESTAKING_ERROR__DIVISION_BY_ZERO]: `DivisionByZero`,
-    [JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH]: `InvalidEpochLength`,
-    [JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID]: `NcnAdminInvalid`,
-    [JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED]: `NcnCooldownOperatorFailed`,
-    [JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID]: `NcnDelegateAdminInvalid`,
-    [JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID]: `NcnOperatorAdminInvalid`,
-    [JITO_RESTAKING_ERROR__NCN_OVERFLOW]: `NcnOverflow`,
-    [JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID]: `NcnSlasherAdminInvalid`,
-    [JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID]: `NcnVaultAdminInvalid`,
-    [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN]: `NcnVaultSlasherTicketFailedCooldown`,
-    [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP]: `NcnVaultSlasherTicketFailedWarmup`,
-    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN]: `NcnVaultTicketFailedCooldown`,
-    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP]: `NcnVaultTicketFailedWarmup`,
-    [JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED]: `NcnWarmupOperatorFailed`,
-    [JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID]: `OperatorAdminInvalid`,
-    [JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED]: `OperatorCooldownNcnFailed`,
-    [JITO_RESTAKING_ERROR__OPERATOR_DELEGATE_ADMIN_INVALID]: `OperatorDelegateAdminInvalid`,
-    [JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED]: `OperatorFeeCapExceeded`,
-    [JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID]: `OperatorNcnAdminInvalid`,
-    [JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
-    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID]: `OperatorVaultAdminInvalid`,
-    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN]: `OperatorVaultTicketFailedCooldown`,
-    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP]: `OperatorVaultTicketFailedWarmup`,
-    [JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED]: `OperatorWarmupNcnFailed`,
-    [JITO_RESTAKING_ERROR__SLASHER_OVERFLOW]: `SlasherOverflow`,
-    [JITO_RESTAKING_ERROR__VAULT_OVERFLOW]: `VaultOverflow`,
+    [JITO_RESTAKING_ERROR__CONFIG_ADMIN_INVALID]: `Signer is not the config admin`,
+    [JITO_RESTAKING_ERROR__DIVISION_BY_ZERO]: `Division by zero`,
+    [JITO_RESTAKING_ERROR__INVALID_ACCOUNT_DATA]: `account data is invalid`,
+    [JITO_RESTAKING_ERROR__INVALID_ACCOUNT_OWNER]: `wrong owner`,
+    [JITO_RESTAKING_ERROR__INVALID_ADDRESS]: `not at the expected address`,
+    [JITO_RESTAKING_ERROR__INVALID_DISCRIMINATOR]: `wrong discriminator`,
+    [JITO_RESTAKING_ERROR__INVALID_EPOCH_LENGTH]: `Epoch length is invalid`,
+    [JITO_RESTAKING_ERROR__INVALID_MINT]: `wrong mint`,
+    [JITO_RESTAKING_ERROR__INVALID_PROGRAM_ID]: `wrong program`,
+    [JITO_RESTAKING_ERROR__INVALID_TOKEN_OWNER]: `wrong token account owner`,
+    [JITO_RESTAKING_ERROR__METADATA_FIELD_INVALID]: `Metadata field is not zero-padded UTF-8 or not a valid flag`,
+    [JITO_RESTAKING_ERROR__METADATA_FIELD_TOO_LONG]: `Metadata field is too long`,
+    [JITO_RESTAKING_ERROR__NCN_ADMIN_INVALID]: `Signer is not the NCN admin`,
+    [JITO_RESTAKING_ERROR__NCN_COOLDOWN_OPERATOR_FAILED]: `NCN operator state cannot be cooled down by the NCN in its current state`,
+    [JITO_RESTAKING_ERROR__NCN_DELEGATE_ADMIN_INVALID]: `Signer is not the NCN's delegate admin`,
+    [JITO_RESTAKING_ERROR__NCN_JAIL_ADMIN_INVALID]: `Signer is not the NCN's jail admin`,
+    [JITO_RESTAKING_ERROR__NCN_METADATA_ADMIN_INVALID]: `Signer is not the NCN's metadata admin`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_ADMIN_INVALID]: `Signer is not the NCN's operator admin`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_BOND_INSUFFICIENT]: `Operator bond is below the NCN's minimum bond`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_JAILED]: `Operator is jailed by the NCN`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_JAIL_EPOCH_INVALID]: `Jail end epoch must be after the current epoch`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_NOT_JAILED]: `Operator is not jailed by the NCN`,
+    [JITO_RESTAKING_ERROR__NCN_OPERATOR_STATE_INACTIVE]: `NCN operator state is not active`,
+    [JITO_RESTAKING_ERROR__NCN_OVERFLOW]: `NCN count overflowed`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_MAX_OPERATORS_REACHED]: `NCN policy maximum number of operators reached`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_MISSING]: `NCN has no operator policy`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_STAKE_INSUFFICIENT]: `Operator stake is below the NCN policy minimum`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_OPERATOR_TICKET_TOO_NEW]: `Operator ticket is too new for the NCN policy`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_TOO_MANY_VAULT_MINTS]: `NCN policy allows too many vault mints`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_MINT_NOT_ALLOWED]: `Vault mint is not allowed by the NCN policy`,
+    [JITO_RESTAKING_ERROR__NCN_POLICY_VAULT_UPDATE_NEEDED]: `Vault is not updated for the current epoch`,
+    [JITO_RESTAKING_ERROR__NCN_SLASHER_ADMIN_INVALID]: `Signer is not the NCN's slasher admin`,
+    [JITO_RESTAKING_ERROR__NCN_TRANSITION_EPOCHS_INVALID]: `Warmup and cooldown epochs are invalid`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_ADMIN_INVALID]: `Signer is not the NCN's vault admin`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_COOLDOWN]: `NCN vault slasher ticket cannot be cooled down in its current state`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_SLASHER_TICKET_FAILED_WARMUP]: `NCN vault slasher ticket cannot be warmed up in its current state`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_COOLDOWN]: `NCN vault ticket cannot be cooled down in its current state`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_FAILED_WARMUP]: `NCN vault ticket cannot be warmed up in its current state`,
+    [JITO_RESTAKING_ERROR__NCN_VAULT_TICKET_INACTIVE]: `NCN vault ticket is not active`,
+    [JITO_RESTAKING_ERROR__NCN_WARMUP_OPERATOR_FAILED]: `NCN operator state cannot be warmed up by the NCN in its current state`,
+    [JITO_RESTAKING_ERROR__OPERATOR_ADMIN_INVALID]: `Signer is not the operator admin`,
+    [JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_EXCEEDED]: `Amount exceeds the bonded amount`,
+    [JITO_RESTAKING_ERROR__OPERATOR_BOND_AMOUNT_ZERO]: `Bond amount must be non-zero`,
+    [JITO_RESTAKING_ERROR__OPERATOR_BOND_COOLDOWN_ACTIVE]: `Unbonded tokens are still cooling down`,
+    [JITO_RESTAKING_ERROR__OPERATOR_COOLDOWN_NCN_FAILED]: `NCN operator state cannot be cooled down by the operator in its current state`,
+    [JITO_RESTAKING_ERROR__OPERATOR_DELEGATE_ADMIN_INVALID]: `Signer is not the operator's delegate admin`,
+    [JITO_RESTAKING_ERROR__OPERATOR_FEE_CAP_EXCEEDED]: `Operator fee exceeds the maximum allowed fee`,
+    [JITO_RESTAKING_ERROR__OPERATOR_METADATA_ADMIN_INVALID]: `Signer is not the operator's metadata admin`,
+    [JITO_RESTAKING_ERROR__OPERATOR_NCN_ADMIN_INVALID]: `Signer is not the operator's NCN admin`,
+    [JITO_RESTAKING_ERROR__OPERATOR_OVERFLOW]: `Operator count overflowed`,
+    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_ADMIN_INVALID]: `Signer is not the operator's vault admin`,
+    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_COOLDOWN]: `Operator vault ticket cannot be cooled down in its current state`,
+    [JITO_RESTAKING_ERROR__OPERATOR_VAULT_TICKET_FAILED_WARMUP]: `Operator vault ticket cannot be warmed up in its current state`,
+    [JITO_RESTAKING_ERROR__OPERATOR_WARMUP_NCN_FAILED]: `NCN operator state cannot be warmed up by the operator in its current state`,
+    [JITO_RESTAKING_ERROR__SLASHER_OVERFLOW]: `Slasher count overflowed`,
+    [JITO_RESTAKING_ERROR__VAULT_OVERFLOW]: `Vault count overflowed`,
   };
 }
 
@@ -11,23 +11,39 @@ export * from './cooldownNcnVaultTicket';
 export * from './cooldownOperatorVaultTicket';
 export * from './initializeConfig';
 export * from './initializeNcn';
+export * from './initializeNcnMetadata';
 export * from './initializeNcnOperatorState';
+export * from './initializeNcnPolicy';
 export * from './initializeNcnVaultSlasherTicket';
 export * from './initializeNcnVaultTicket';
 export * from './initializeOperator';
+export * from './initializeOperatorBond';
+export * from './initializeOperatorMetadata';
 export * from './initializeOperatorVaultTicket';
+export * from './migrateAccount';
 export * from './ncnCooldownOperator';
 export * from './ncnDelegateTokenAccount';
+export * from './ncnJailOperator';
 export * from './ncnSetAdmin';
+export * from './ncnSetOperatorBondRequirement';
+export * from './ncnSetPolicy';
 export * from './ncnSetSecondaryAdmin';
+export * from './ncnSetTransitionEpochs';
+export * from './ncnUnjailOperator';
 export * from './ncnWarmupOperator';
 export * from './operatorCooldownNcn';
 export * from './operatorDelegateTokenAccount';
+export * from './operatorDepositBond';
 export * from './operatorSetAdmin';
 export * from './operatorSetFee';
 export * from './operatorSetSecondaryAdmin';
+export * from './operatorUnbond';
 export * from './operatorWarmupNcn';
+export * from './operatorWithdrawBond';
 export * from './setConfigAdmin';
+export * from './slashOperatorBond';
+export * from './updateNcnMetadata';
+export * from './updateOperatorMetadata';
 export * from './warmupNcnVaultSlasherTicket';
 export * from './warmupNcnVaultTicket';
 export * from './warmupOperatorVaultTicket';
@@ -0,0 +1,308 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+  type WritableSignerAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const INITIALIZE_OPERATOR_METADATA_DISCRIMINATOR = 27;
+
+export function getInitializeOperatorMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(INITIALIZE_OPERATOR_METADATA_DISCRIMINATOR);
+}
+
+export type InitializeOperatorMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorMetadata extends string | IAccountMeta<string> = string,
+  TAccountMetadataAdmin extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorMetadata extends string
+        ? WritableAccount<TAccountOperatorMetadata>
+        : TAccountOperatorMetadata,
+      TAccountMetadataAdmin extends string
+        ? ReadonlySignerAccount<TAccountMetadataAdmin> &
+            IAccountSignerMeta<TAccountMetadataAdmin>
+        : TAccountMetadataAdmin,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type InitializeOperatorMetadataInstructionData = {
+  discriminator: number;
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: Option<ReadonlyUint8Array>;
+};
+
+export type InitializeOperatorMetadataInstructionDataArgs = {
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: OptionOrNullable<ReadonlyUint8Array>;
+};
+
+export function getInitializeOperatorMetadataInstructionDataEncoder(): Encoder<InitializeOperatorMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['website', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['logoUri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contact', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      [
+        'descriptionHash',
+        getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32)),
+      ],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: INITIALIZE_OPERATOR_METADATA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getInitializeOperatorMetadataInstructionDataDecoder(): Decoder<InitializeOperatorMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['website', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['logoUri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contact', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    [
+      'descriptionHash',
+      getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32)),
+    ],
+  ]);
+}
+
+export function getInitializeOperatorMetadataInstructionDataCodec(): Codec<
+  InitializeOperatorMetadataInstructionDataArgs,
+  InitializeOperatorMetadataInstructionData
+> {
+  return combineCodec(
+    getInitializeOperatorMetadataInstructionDataEncoder(),
+    getInitializeOperatorMetadataInstructionDataDecoder()
+  );
+}
+
+export type InitializeOperatorMetadataInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorMetadata extends string = string,
+  TAccountMetadataAdmin extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  operatorMetadata: Address<TAccountOperatorMetadata>;
+  metadataAdmin: TransactionSigner<TAccountMetadataAdmin>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
+  name: InitializeOperatorMetadataInstructionDataArgs['name'];
+  website: InitializeOperatorMetadataInstructionDataArgs['website'];
+  logoUri: InitializeOperatorMetadataInstructionDataArgs['logoUri'];
+  contact: InitializeOperatorMetadataInstructionDataArgs['contact'];
+  descriptionHash: InitializeOperatorMetadataInstructionDataArgs['descriptionHash'];
+};
+
+export function getInitializeOperatorMetadataInstruction<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountOperatorMetadata extends string,
+  TAccountMetadataAdmin extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: InitializeOperatorMetadataInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountOperatorMetadata,
+    TAccountMetadataAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): InitializeOperatorMetadataInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountOperator,
+  TAccountOperatorMetadata,
+  TAccountMetadataAdmin,
+  TAccountPayer,
+  TAccountSystemProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorMetadata: {
+      value: input.operatorMetadata ?? null,
+      isWritable: true,
+    },
+    metadataAdmin: { value: input.metadataAdmin ?? null, isWritable: false },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorMetadata),
+      getAccountMeta(accounts.metadataAdmin),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
+    ],
+    programAddress,
+    data: getInitializeOperatorMetadataInstructionDataEncoder().encode(
+      args as InitializeOperatorMetadataInstructionDataArgs
+    ),
+  } as InitializeOperatorMetadataInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountOperatorMetadata,
+    TAccountMetadataAdmin,
+    TAccountPayer,
+    TAccountSystemProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedInitializeOperatorMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    operatorMetadata: TAccountMetas[2];
+    metadataAdmin: TAccountMetas[3];
+    payer: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
+  };
+  data: InitializeOperatorMetadataInstructionData;
+};
+
+export function parseInitializeOperatorMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedInitializeOperatorMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      operator: getNextAccount(),
+      operatorMetadata: getNextAccount(),
+      metadataAdmin: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
+    },
+    data: getInitializeOperatorMetadataInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
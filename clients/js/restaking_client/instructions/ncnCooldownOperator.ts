@@ -43,6 +43,7 @@ export type NcnCooldownOperatorInstruction<
   TAccountOperator extends string | IAccountMeta<string> = string,
   TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
   TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNcnPolicy extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -62,6 +63,9 @@ export type NcnCooldownOperatorInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             IAccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountNcnPolicy extends string
+        ? WritableAccount<TAccountNcnPolicy>
+        : TAccountNcnPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -100,12 +104,15 @@ export type NcnCooldownOperatorInput<
   TAccountOperator extends string = string,
   TAccountNcnOperatorState extends string = string,
   TAccountAdmin extends string = string,
+  TAccountNcnPolicy extends string = string,
 > = {
   config: Address<TAccountConfig>;
   ncn: Address<TAccountNcn>;
   operator: Address<TAccountOperator>;
   ncnOperatorState: Address<TAccountNcnOperatorState>;
   admin: TransactionSigner<TAccountAdmin>;
+  /** Required if the operator was admitted under the NCN policy */
+  ncnPolicy?: Address<TAccountNcnPolicy>;
 };
 
 export function getNcnCooldownOperatorInstruction<
@@ -114,6 +121,7 @@ export function getNcnCooldownOperatorInstruction<
   TAccountOperator extends string,
   TAccountNcnOperatorState extends string,
   TAccountAdmin extends string,
+  TAccountNcnPolicy extends string,
   TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
 >(
   input: NcnCooldownOperatorInput<
@@ -121,7 +129,8 @@ export function getNcnCooldownOperatorInstruction<
     TAccountNcn,
     TAccountOperator,
     TAccountNcnOperatorState,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountNcnPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): NcnCooldownOperatorInstruction<
@@ -130,7 +139,8 @@ export function getNcnCooldownOperatorInstruction<
   TAccountNcn,
   TAccountOperator,
   TAccountNcnOperatorState,
-  TAccountAdmin
+  TAccountAdmin,
+  TAccountNcnPolicy
 > {
   // Program address.
   const programAddress =
@@ -146,6 +156,7 @@ export function getNcnCooldownOperatorInstruction<
       isWritable: true,
     },
     admin: { value: input.admin ?? null, isWritable: false },
+    ncnPolicy: { value: input.ncnPolicy ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -160,6 +171,7 @@ export function getNcnCooldownOperatorInstruction<
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.ncnOperatorState),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.ncnPolicy),
     ],
     programAddress,
     data: getNcnCooldownOperatorInstructionDataEncoder().encode({}),
@@ -169,7 +181,8 @@ export function getNcnCooldownOperatorInstruction<
     TAccountNcn,
     TAccountOperator,
     TAccountNcnOperatorState,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountNcnPolicy
   >;
 
   return instruction;
@@ -186,6 +199,8 @@ export type ParsedNcnCooldownOperatorInstruction<
     operator: TAccountMetas[2];
     ncnOperatorState: TAccountMetas[3];
     admin: TAccountMetas[4];
+    /** Required if the operator was admitted under the NCN policy */
+    ncnPolicy?: TAccountMetas[5] | undefined;
   };
   data: NcnCooldownOperatorInstructionData;
 };
@@ -198,7 +213,7 @@ export function parseNcnCooldownOperatorInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedNcnCooldownOperatorInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -208,6 +223,12 @@ export function parseNcnCooldownOperatorInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_RESTAKING_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -216,6 +237,7 @@ export function parseNcnCooldownOperatorInstruction<
       operator: getNextAccount(),
       ncnOperatorState: getNextAccount(),
       admin: getNextAccount(),
+      ncnPolicy: getNextOptionalAccount(),
     },
     data: getNcnCooldownOperatorInstructionDataDecoder().decode(
       instruction.data
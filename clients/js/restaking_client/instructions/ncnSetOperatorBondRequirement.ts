@@ -0,0 +1,229 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const NCN_SET_OPERATOR_BOND_REQUIREMENT_DISCRIMINATOR = 29;
+
+export function getNcnSetOperatorBondRequirementDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_OPERATOR_BOND_REQUIREMENT_DISCRIMINATOR);
+}
+
+export type NcnSetOperatorBondRequirementInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperatorBondMint extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? WritableAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperatorBondMint extends string
+        ? ReadonlyAccount<TAccountOperatorBondMint>
+        : TAccountOperatorBondMint,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetOperatorBondRequirementInstructionData = {
+  discriminator: number;
+  minOperatorBond: bigint;
+};
+
+export type NcnSetOperatorBondRequirementInstructionDataArgs = {
+  minOperatorBond: number | bigint;
+};
+
+export function getNcnSetOperatorBondRequirementInstructionDataEncoder(): Encoder<NcnSetOperatorBondRequirementInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['minOperatorBond', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_OPERATOR_BOND_REQUIREMENT_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetOperatorBondRequirementInstructionDataDecoder(): Decoder<NcnSetOperatorBondRequirementInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['minOperatorBond', getU64Decoder()],
+  ]);
+}
+
+export function getNcnSetOperatorBondRequirementInstructionDataCodec(): Codec<
+  NcnSetOperatorBondRequirementInstructionDataArgs,
+  NcnSetOperatorBondRequirementInstructionData
+> {
+  return combineCodec(
+    getNcnSetOperatorBondRequirementInstructionDataEncoder(),
+    getNcnSetOperatorBondRequirementInstructionDataDecoder()
+  );
+}
+
+export type NcnSetOperatorBondRequirementInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperatorBondMint extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operatorBondMint: Address<TAccountOperatorBondMint>;
+  admin: TransactionSigner<TAccountAdmin>;
+  minOperatorBond: NcnSetOperatorBondRequirementInstructionDataArgs['minOperatorBond'];
+};
+
+export function getNcnSetOperatorBondRequirementInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperatorBondMint extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: NcnSetOperatorBondRequirementInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorBondMint,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): NcnSetOperatorBondRequirementInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperatorBondMint,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    operatorBondMint: {
+      value: input.operatorBondMint ?? null,
+      isWritable: false,
+    },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operatorBondMint),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetOperatorBondRequirementInstructionDataEncoder().encode(
+      args as NcnSetOperatorBondRequirementInstructionDataArgs
+    ),
+  } as NcnSetOperatorBondRequirementInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperatorBondMint,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetOperatorBondRequirementInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operatorBondMint: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: NcnSetOperatorBondRequirementInstructionData;
+};
+
+export function parseNcnSetOperatorBondRequirementInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetOperatorBondRequirementInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operatorBondMint: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetOperatorBondRequirementInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
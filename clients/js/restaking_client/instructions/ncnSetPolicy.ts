@@ -0,0 +1,238 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const NCN_SET_POLICY_DISCRIMINATOR = 34;
+
+export function getNcnSetPolicyDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_POLICY_DISCRIMINATOR);
+}
+
+export type NcnSetPolicyInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnPolicy extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnPolicy extends string
+        ? WritableAccount<TAccountNcnPolicy>
+        : TAccountNcnPolicy,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetPolicyInstructionData = {
+  discriminator: number;
+  permissionlessAdmission: boolean;
+  minOperatorStake: bigint;
+  maxOperators: bigint;
+  minOperatorTicketAge: bigint;
+};
+
+export type NcnSetPolicyInstructionDataArgs = {
+  permissionlessAdmission: boolean;
+  minOperatorStake: number | bigint;
+  maxOperators: number | bigint;
+  minOperatorTicketAge: number | bigint;
+};
+
+export function getNcnSetPolicyInstructionDataEncoder(): Encoder<NcnSetPolicyInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['permissionlessAdmission', getBooleanEncoder()],
+      ['minOperatorStake', getU64Encoder()],
+      ['maxOperators', getU64Encoder()],
+      ['minOperatorTicketAge', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: NCN_SET_POLICY_DISCRIMINATOR })
+  );
+}
+
+export function getNcnSetPolicyInstructionDataDecoder(): Decoder<NcnSetPolicyInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['permissionlessAdmission', getBooleanDecoder()],
+    ['minOperatorStake', getU64Decoder()],
+    ['maxOperators', getU64Decoder()],
+    ['minOperatorTicketAge', getU64Decoder()],
+  ]);
+}
+
+export function getNcnSetPolicyInstructionDataCodec(): Codec<
+  NcnSetPolicyInstructionDataArgs,
+  NcnSetPolicyInstructionData
+> {
+  return combineCodec(
+    getNcnSetPolicyInstructionDataEncoder(),
+    getNcnSetPolicyInstructionDataDecoder()
+  );
+}
+
+export type NcnSetPolicyInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnPolicy extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnPolicy: Address<TAccountNcnPolicy>;
+  admin: TransactionSigner<TAccountAdmin>;
+  permissionlessAdmission: NcnSetPolicyInstructionDataArgs['permissionlessAdmission'];
+  minOperatorStake: NcnSetPolicyInstructionDataArgs['minOperatorStake'];
+  maxOperators: NcnSetPolicyInstructionDataArgs['maxOperators'];
+  minOperatorTicketAge: NcnSetPolicyInstructionDataArgs['minOperatorTicketAge'];
+};
+
+export function getNcnSetPolicyInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnPolicy extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: NcnSetPolicyInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnPolicy,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): NcnSetPolicyInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnPolicy,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnPolicy: { value: input.ncnPolicy ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnPolicy),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetPolicyInstructionDataEncoder().encode(
+      args as NcnSetPolicyInstructionDataArgs
+    ),
+  } as NcnSetPolicyInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnPolicy,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetPolicyInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnPolicy: TAccountMetas[2];
+    admin: TAccountMetas[3];
+  };
+  data: NcnSetPolicyInstructionData;
+};
+
+export function parseNcnSetPolicyInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetPolicyInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnPolicy: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetPolicyInstructionDataDecoder().decode(instruction.data),
+  };
+}
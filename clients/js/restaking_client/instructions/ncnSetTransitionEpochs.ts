@@ -0,0 +1,217 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const NCN_SET_TRANSITION_EPOCHS_DISCRIMINATOR = 37;
+
+export function getNcnSetTransitionEpochsDiscriminatorBytes() {
+  return getU8Encoder().encode(NCN_SET_TRANSITION_EPOCHS_DISCRIMINATOR);
+}
+
+export type NcnSetTransitionEpochsInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? WritableAccount<TAccountNcn> : TAccountNcn,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type NcnSetTransitionEpochsInstructionData = {
+  discriminator: number;
+  warmupEpochs: number;
+  cooldownEpochs: number;
+};
+
+export type NcnSetTransitionEpochsInstructionDataArgs = {
+  warmupEpochs: number;
+  cooldownEpochs: number;
+};
+
+export function getNcnSetTransitionEpochsInstructionDataEncoder(): Encoder<NcnSetTransitionEpochsInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['warmupEpochs', getU16Encoder()],
+      ['cooldownEpochs', getU16Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: NCN_SET_TRANSITION_EPOCHS_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getNcnSetTransitionEpochsInstructionDataDecoder(): Decoder<NcnSetTransitionEpochsInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['warmupEpochs', getU16Decoder()],
+    ['cooldownEpochs', getU16Decoder()],
+  ]);
+}
+
+export function getNcnSetTransitionEpochsInstructionDataCodec(): Codec<
+  NcnSetTransitionEpochsInstructionDataArgs,
+  NcnSetTransitionEpochsInstructionData
+> {
+  return combineCodec(
+    getNcnSetTransitionEpochsInstructionDataEncoder(),
+    getNcnSetTransitionEpochsInstructionDataDecoder()
+  );
+}
+
+export type NcnSetTransitionEpochsInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  admin: TransactionSigner<TAccountAdmin>;
+  warmupEpochs: NcnSetTransitionEpochsInstructionDataArgs['warmupEpochs'];
+  cooldownEpochs: NcnSetTransitionEpochsInstructionDataArgs['cooldownEpochs'];
+};
+
+export function getNcnSetTransitionEpochsInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: NcnSetTransitionEpochsInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): NcnSetTransitionEpochsInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: true },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getNcnSetTransitionEpochsInstructionDataEncoder().encode(
+      args as NcnSetTransitionEpochsInstructionDataArgs
+    ),
+  } as NcnSetTransitionEpochsInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedNcnSetTransitionEpochsInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    admin: TAccountMetas[2];
+  };
+  data: NcnSetTransitionEpochsInstructionData;
+};
+
+export function parseNcnSetTransitionEpochsInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedNcnSetTransitionEpochsInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 3) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getNcnSetTransitionEpochsInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
@@ -43,6 +43,8 @@ export type NcnWarmupOperatorInstruction<
   TAccountOperator extends string | IAccountMeta<string> = string,
   TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
   TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountOperatorBond extends string | IAccountMeta<string> = string,
+  TAccountNcnPolicy extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -62,6 +64,12 @@ export type NcnWarmupOperatorInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             IAccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountOperatorBond extends string
+        ? ReadonlyAccount<TAccountOperatorBond>
+        : TAccountOperatorBond,
+      TAccountNcnPolicy extends string
+        ? WritableAccount<TAccountNcnPolicy>
+        : TAccountNcnPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -97,12 +105,18 @@ export type NcnWarmupOperatorInput<
   TAccountOperator extends string = string,
   TAccountNcnOperatorState extends string = string,
   TAccountAdmin extends string = string,
+  TAccountOperatorBond extends string = string,
+  TAccountNcnPolicy extends string = string,
 > = {
   config: Address<TAccountConfig>;
   ncn: Address<TAccountNcn>;
   operator: Address<TAccountOperator>;
   ncnOperatorState: Address<TAccountNcnOperatorState>;
   admin: TransactionSigner<TAccountAdmin>;
+  /** Required if the NCN has an operator bond requirement */
+  operatorBond?: Address<TAccountOperatorBond>;
+  /** Required if the NCN has a policy */
+  ncnPolicy?: Address<TAccountNcnPolicy>;
 };
 
 export function getNcnWarmupOperatorInstruction<
@@ -111,6 +125,8 @@ export function getNcnWarmupOperatorInstruction<
   TAccountOperator extends string,
   TAccountNcnOperatorState extends string,
   TAccountAdmin extends string,
+  TAccountOperatorBond extends string,
+  TAccountNcnPolicy extends string,
   TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
 >(
   input: NcnWarmupOperatorInput<
@@ -118,7 +134,9 @@ export function getNcnWarmupOperatorInstruction<
     TAccountNcn,
     TAccountOperator,
     TAccountNcnOperatorState,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountOperatorBond,
+    TAccountNcnPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): NcnWarmupOperatorInstruction<
@@ -127,7 +145,9 @@ export function getNcnWarmupOperatorInstruction<
   TAccountNcn,
   TAccountOperator,
   TAccountNcnOperatorState,
-  TAccountAdmin
+  TAccountAdmin,
+  TAccountOperatorBond,
+  TAccountNcnPolicy
 > {
   // Program address.
   const programAddress =
@@ -143,6 +163,8 @@ export function getNcnWarmupOperatorInstruction<
       isWritable: true,
     },
     admin: { value: input.admin ?? null, isWritable: false },
+    operatorBond: { value: input.operatorBond ?? null, isWritable: false },
+    ncnPolicy: { value: input.ncnPolicy ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -157,6 +179,8 @@ export function getNcnWarmupOperatorInstruction<
       getAccountMeta(accounts.operator),
       getAccountMeta(accounts.ncnOperatorState),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.operatorBond),
+      getAccountMeta(accounts.ncnPolicy),
     ],
     programAddress,
     data: getNcnWarmupOperatorInstructionDataEncoder().encode({}),
@@ -166,7 +190,9 @@ export function getNcnWarmupOperatorInstruction<
     TAccountNcn,
     TAccountOperator,
     TAccountNcnOperatorState,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountOperatorBond,
+    TAccountNcnPolicy
   >;
 
   return instruction;
@@ -183,6 +209,10 @@ export type ParsedNcnWarmupOperatorInstruction<
     operator: TAccountMetas[2];
     ncnOperatorState: TAccountMetas[3];
     admin: TAccountMetas[4];
+    /** Required if the NCN has an operator bond requirement */
+    operatorBond?: TAccountMetas[5] | undefined;
+    /** Required if the NCN has a policy */
+    ncnPolicy?: TAccountMetas[6] | undefined;
   };
   data: NcnWarmupOperatorInstructionData;
 };
@@ -195,7 +225,7 @@ export function parseNcnWarmupOperatorInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedNcnWarmupOperatorInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -205,6 +235,12 @@ export function parseNcnWarmupOperatorInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_RESTAKING_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -213,6 +249,8 @@ export function parseNcnWarmupOperatorInstruction<
       operator: getNextAccount(),
       ncnOperatorState: getNextAccount(),
       admin: getNextAccount(),
+      operatorBond: getNextOptionalAccount(),
+      ncnPolicy: getNextOptionalAccount(),
     },
     data: getNcnWarmupOperatorInstructionDataDecoder().decode(instruction.data),
   };
@@ -0,0 +1,312 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const OPERATOR_DEPOSIT_BOND_DISCRIMINATOR = 31;
+
+export function getOperatorDepositBondDiscriminatorBytes() {
+  return getU8Encoder().encode(OPERATOR_DEPOSIT_BOND_DISCRIMINATOR);
+}
+
+export type OperatorDepositBondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorBond extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountAdminTokenAccount extends string | IAccountMeta<string> = string,
+  TAccountOperatorBondTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorBond extends string
+        ? WritableAccount<TAccountOperatorBond>
+        : TAccountOperatorBond,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      TAccountAdminTokenAccount extends string
+        ? WritableAccount<TAccountAdminTokenAccount>
+        : TAccountAdminTokenAccount,
+      TAccountOperatorBondTokenAccount extends string
+        ? WritableAccount<TAccountOperatorBondTokenAccount>
+        : TAccountOperatorBondTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type OperatorDepositBondInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type OperatorDepositBondInstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getOperatorDepositBondInstructionDataEncoder(): Encoder<OperatorDepositBondInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: OPERATOR_DEPOSIT_BOND_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getOperatorDepositBondInstructionDataDecoder(): Decoder<OperatorDepositBondInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getOperatorDepositBondInstructionDataCodec(): Codec<
+  OperatorDepositBondInstructionDataArgs,
+  OperatorDepositBondInstructionData
+> {
+  return combineCodec(
+    getOperatorDepositBondInstructionDataEncoder(),
+    getOperatorDepositBondInstructionDataDecoder()
+  );
+}
+
+export type OperatorDepositBondInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorBond extends string = string,
+  TAccountMint extends string = string,
+  TAccountAdmin extends string = string,
+  TAccountAdminTokenAccount extends string = string,
+  TAccountOperatorBondTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  operatorBond: Address<TAccountOperatorBond>;
+  mint: Address<TAccountMint>;
+  admin: TransactionSigner<TAccountAdmin>;
+  adminTokenAccount: Address<TAccountAdminTokenAccount>;
+  operatorBondTokenAccount: Address<TAccountOperatorBondTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: OperatorDepositBondInstructionDataArgs['amount'];
+};
+
+export function getOperatorDepositBondInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountOperatorBond extends string,
+  TAccountMint extends string,
+  TAccountAdmin extends string,
+  TAccountAdminTokenAccount extends string,
+  TAccountOperatorBondTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: OperatorDepositBondInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorBond,
+    TAccountMint,
+    TAccountAdmin,
+    TAccountAdminTokenAccount,
+    TAccountOperatorBondTokenAccount,
+    TAccountTokenProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): OperatorDepositBondInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountOperatorBond,
+  TAccountMint,
+  TAccountAdmin,
+  TAccountAdminTokenAccount,
+  TAccountOperatorBondTokenAccount,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorBond: { value: input.operatorBond ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    admin: { value: input.admin ?? null, isWritable: false },
+    adminTokenAccount: {
+      value: input.adminTokenAccount ?? null,
+      isWritable: true,
+    },
+    operatorBondTokenAccount: {
+      value: input.operatorBondTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorBond),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.adminTokenAccount),
+      getAccountMeta(accounts.operatorBondTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getOperatorDepositBondInstructionDataEncoder().encode(
+      args as OperatorDepositBondInstructionDataArgs
+    ),
+  } as OperatorDepositBondInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorBond,
+    TAccountMint,
+    TAccountAdmin,
+    TAccountAdminTokenAccount,
+    TAccountOperatorBondTokenAccount,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedOperatorDepositBondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    operatorBond: TAccountMetas[3];
+    mint: TAccountMetas[4];
+    admin: TAccountMetas[5];
+    adminTokenAccount: TAccountMetas[6];
+    operatorBondTokenAccount: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+  };
+  data: OperatorDepositBondInstructionData;
+};
+
+export function parseOperatorDepositBondInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedOperatorDepositBondInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      operatorBond: getNextAccount(),
+      mint: getNextAccount(),
+      admin: getNextAccount(),
+      adminTokenAccount: getNextAccount(),
+      operatorBondTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getOperatorDepositBondInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
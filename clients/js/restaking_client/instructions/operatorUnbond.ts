@@ -0,0 +1,247 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const OPERATOR_UNBOND_DISCRIMINATOR = 39;
+
+export function getOperatorUnbondDiscriminatorBytes() {
+  return getU8Encoder().encode(OPERATOR_UNBOND_DISCRIMINATOR);
+}
+
+export type OperatorUnbondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorBond extends string | IAccountMeta<string> = string,
+  TAccountMint extends string | IAccountMeta<string> = string,
+  TAccountAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorBond extends string
+        ? WritableAccount<TAccountOperatorBond>
+        : TAccountOperatorBond,
+      TAccountMint extends string
+        ? ReadonlyAccount<TAccountMint>
+        : TAccountMint,
+      TAccountAdmin extends string
+        ? ReadonlySignerAccount<TAccountAdmin> &
+            IAccountSignerMeta<TAccountAdmin>
+        : TAccountAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type OperatorUnbondInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type OperatorUnbondInstructionDataArgs = { amount: number | bigint };
+
+export function getOperatorUnbondInstructionDataEncoder(): Encoder<OperatorUnbondInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: OPERATOR_UNBOND_DISCRIMINATOR })
+  );
+}
+
+export function getOperatorUnbondInstructionDataDecoder(): Decoder<OperatorUnbondInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getOperatorUnbondInstructionDataCodec(): Codec<
+  OperatorUnbondInstructionDataArgs,
+  OperatorUnbondInstructionData
+> {
+  return combineCodec(
+    getOperatorUnbondInstructionDataEncoder(),
+    getOperatorUnbondInstructionDataDecoder()
+  );
+}
+
+export type OperatorUnbondInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorBond extends string = string,
+  TAccountMint extends string = string,
+  TAccountAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  operatorBond: Address<TAccountOperatorBond>;
+  mint: Address<TAccountMint>;
+  admin: TransactionSigner<TAccountAdmin>;
+  amount: OperatorUnbondInstructionDataArgs['amount'];
+};
+
+export function getOperatorUnbondInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountOperatorBond extends string,
+  TAccountMint extends string,
+  TAccountAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: OperatorUnbondInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorBond,
+    TAccountMint,
+    TAccountAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): OperatorUnbondInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountOperatorBond,
+  TAccountMint,
+  TAccountAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorBond: { value: input.operatorBond ?? null, isWritable: true },
+    mint: { value: input.mint ?? null, isWritable: false },
+    admin: { value: input.admin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorBond),
+      getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.admin),
+    ],
+    programAddress,
+    data: getOperatorUnbondInstructionDataEncoder().encode(
+      args as OperatorUnbondInstructionDataArgs
+    ),
+  } as OperatorUnbondInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountOperatorBond,
+    TAccountMint,
+    TAccountAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedOperatorUnbondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    operatorBond: TAccountMetas[3];
+    mint: TAccountMetas[4];
+    admin: TAccountMetas[5];
+  };
+  data: OperatorUnbondInstructionData;
+};
+
+export function parseOperatorUnbondInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedOperatorUnbondInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 6) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      operatorBond: getNextAccount(),
+      mint: getNextAccount(),
+      admin: getNextAccount(),
+    },
+    data: getOperatorUnbondInstructionDataDecoder().decode(instruction.data),
+  };
+}
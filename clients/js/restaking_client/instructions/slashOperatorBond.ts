@@ -0,0 +1,310 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getStructDecoder,
+  getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const SLASH_OPERATOR_BOND_DISCRIMINATOR = 32;
+
+export function getSlashOperatorBondDiscriminatorBytes() {
+  return getU8Encoder().encode(SLASH_OPERATOR_BOND_DISCRIMINATOR);
+}
+
+export type SlashOperatorBondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountNcnOperatorState extends string | IAccountMeta<string> = string,
+  TAccountOperatorBond extends string | IAccountMeta<string> = string,
+  TAccountOperatorBondTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountSlasherAdmin extends string | IAccountMeta<string> = string,
+  TAccountDestinationTokenAccount extends
+    | string
+    | IAccountMeta<string> = string,
+  TAccountTokenProgram extends
+    | string
+    | IAccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountNcnOperatorState extends string
+        ? ReadonlyAccount<TAccountNcnOperatorState>
+        : TAccountNcnOperatorState,
+      TAccountOperatorBond extends string
+        ? WritableAccount<TAccountOperatorBond>
+        : TAccountOperatorBond,
+      TAccountOperatorBondTokenAccount extends string
+        ? WritableAccount<TAccountOperatorBondTokenAccount>
+        : TAccountOperatorBondTokenAccount,
+      TAccountSlasherAdmin extends string
+        ? ReadonlySignerAccount<TAccountSlasherAdmin> &
+            IAccountSignerMeta<TAccountSlasherAdmin>
+        : TAccountSlasherAdmin,
+      TAccountDestinationTokenAccount extends string
+        ? WritableAccount<TAccountDestinationTokenAccount>
+        : TAccountDestinationTokenAccount,
+      TAccountTokenProgram extends string
+        ? ReadonlyAccount<TAccountTokenProgram>
+        : TAccountTokenProgram,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type SlashOperatorBondInstructionData = {
+  discriminator: number;
+  amount: bigint;
+};
+
+export type SlashOperatorBondInstructionDataArgs = { amount: number | bigint };
+
+export function getSlashOperatorBondInstructionDataEncoder(): Encoder<SlashOperatorBondInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['amount', getU64Encoder()],
+    ]),
+    (value) => ({ ...value, discriminator: SLASH_OPERATOR_BOND_DISCRIMINATOR })
+  );
+}
+
+export function getSlashOperatorBondInstructionDataDecoder(): Decoder<SlashOperatorBondInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['amount', getU64Decoder()],
+  ]);
+}
+
+export function getSlashOperatorBondInstructionDataCodec(): Codec<
+  SlashOperatorBondInstructionDataArgs,
+  SlashOperatorBondInstructionData
+> {
+  return combineCodec(
+    getSlashOperatorBondInstructionDataEncoder(),
+    getSlashOperatorBondInstructionDataDecoder()
+  );
+}
+
+export type SlashOperatorBondInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountOperator extends string = string,
+  TAccountNcnOperatorState extends string = string,
+  TAccountOperatorBond extends string = string,
+  TAccountOperatorBondTokenAccount extends string = string,
+  TAccountSlasherAdmin extends string = string,
+  TAccountDestinationTokenAccount extends string = string,
+  TAccountTokenProgram extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  operator: Address<TAccountOperator>;
+  ncnOperatorState: Address<TAccountNcnOperatorState>;
+  operatorBond: Address<TAccountOperatorBond>;
+  operatorBondTokenAccount: Address<TAccountOperatorBondTokenAccount>;
+  slasherAdmin: TransactionSigner<TAccountSlasherAdmin>;
+  destinationTokenAccount: Address<TAccountDestinationTokenAccount>;
+  tokenProgram?: Address<TAccountTokenProgram>;
+  amount: SlashOperatorBondInstructionDataArgs['amount'];
+};
+
+export function getSlashOperatorBondInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountOperator extends string,
+  TAccountNcnOperatorState extends string,
+  TAccountOperatorBond extends string,
+  TAccountOperatorBondTokenAccount extends string,
+  TAccountSlasherAdmin extends string,
+  TAccountDestinationTokenAccount extends string,
+  TAccountTokenProgram extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: SlashOperatorBondInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountOperatorBond,
+    TAccountOperatorBondTokenAccount,
+    TAccountSlasherAdmin,
+    TAccountDestinationTokenAccount,
+    TAccountTokenProgram
+  >,
+  config?: { programAddress?: TProgramAddress }
+): SlashOperatorBondInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountOperator,
+  TAccountNcnOperatorState,
+  TAccountOperatorBond,
+  TAccountOperatorBondTokenAccount,
+  TAccountSlasherAdmin,
+  TAccountDestinationTokenAccount,
+  TAccountTokenProgram
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    ncnOperatorState: {
+      value: input.ncnOperatorState ?? null,
+      isWritable: false,
+    },
+    operatorBond: { value: input.operatorBond ?? null, isWritable: true },
+    operatorBondTokenAccount: {
+      value: input.operatorBondTokenAccount ?? null,
+      isWritable: true,
+    },
+    slasherAdmin: { value: input.slasherAdmin ?? null, isWritable: false },
+    destinationTokenAccount: {
+      value: input.destinationTokenAccount ?? null,
+      isWritable: true,
+    },
+    tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  // Resolve default values.
+  if (!accounts.tokenProgram.value) {
+    accounts.tokenProgram.value =
+      'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as Address<'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA'>;
+  }
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.ncnOperatorState),
+      getAccountMeta(accounts.operatorBond),
+      getAccountMeta(accounts.operatorBondTokenAccount),
+      getAccountMeta(accounts.slasherAdmin),
+      getAccountMeta(accounts.destinationTokenAccount),
+      getAccountMeta(accounts.tokenProgram),
+    ],
+    programAddress,
+    data: getSlashOperatorBondInstructionDataEncoder().encode(
+      args as SlashOperatorBondInstructionDataArgs
+    ),
+  } as SlashOperatorBondInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountOperator,
+    TAccountNcnOperatorState,
+    TAccountOperatorBond,
+    TAccountOperatorBondTokenAccount,
+    TAccountSlasherAdmin,
+    TAccountDestinationTokenAccount,
+    TAccountTokenProgram
+  >;
+
+  return instruction;
+}
+
+export type ParsedSlashOperatorBondInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    operator: TAccountMetas[2];
+    ncnOperatorState: TAccountMetas[3];
+    operatorBond: TAccountMetas[4];
+    operatorBondTokenAccount: TAccountMetas[5];
+    slasherAdmin: TAccountMetas[6];
+    destinationTokenAccount: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+  };
+  data: SlashOperatorBondInstructionData;
+};
+
+export function parseSlashOperatorBondInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedSlashOperatorBondInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 9) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      operator: getNextAccount(),
+      ncnOperatorState: getNextAccount(),
+      operatorBond: getNextAccount(),
+      operatorBondTokenAccount: getNextAccount(),
+      slasherAdmin: getNextAccount(),
+      destinationTokenAccount: getNextAccount(),
+      tokenProgram: getNextAccount(),
+    },
+    data: getSlashOperatorBondInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,260 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const UPDATE_NCN_METADATA_DISCRIMINATOR = 26;
+
+export function getUpdateNcnMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(UPDATE_NCN_METADATA_DISCRIMINATOR);
+}
+
+export type UpdateNcnMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountNcn extends string | IAccountMeta<string> = string,
+  TAccountNcnMetadata extends string | IAccountMeta<string> = string,
+  TAccountMetadataAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountNcn extends string ? ReadonlyAccount<TAccountNcn> : TAccountNcn,
+      TAccountNcnMetadata extends string
+        ? WritableAccount<TAccountNcnMetadata>
+        : TAccountNcnMetadata,
+      TAccountMetadataAdmin extends string
+        ? ReadonlySignerAccount<TAccountMetadataAdmin> &
+            IAccountSignerMeta<TAccountMetadataAdmin>
+        : TAccountMetadataAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type UpdateNcnMetadataInstructionData = {
+  discriminator: number;
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: Option<ReadonlyUint8Array>;
+};
+
+export type UpdateNcnMetadataInstructionDataArgs = {
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: OptionOrNullable<ReadonlyUint8Array>;
+};
+
+export function getUpdateNcnMetadataInstructionDataEncoder(): Encoder<UpdateNcnMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['website', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['logoUri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contact', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      [
+        'descriptionHash',
+        getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32)),
+      ],
+    ]),
+    (value) => ({ ...value, discriminator: UPDATE_NCN_METADATA_DISCRIMINATOR })
+  );
+}
+
+export function getUpdateNcnMetadataInstructionDataDecoder(): Decoder<UpdateNcnMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['website', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['logoUri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contact', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    [
+      'descriptionHash',
+      getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32)),
+    ],
+  ]);
+}
+
+export function getUpdateNcnMetadataInstructionDataCodec(): Codec<
+  UpdateNcnMetadataInstructionDataArgs,
+  UpdateNcnMetadataInstructionData
+> {
+  return combineCodec(
+    getUpdateNcnMetadataInstructionDataEncoder(),
+    getUpdateNcnMetadataInstructionDataDecoder()
+  );
+}
+
+export type UpdateNcnMetadataInput<
+  TAccountConfig extends string = string,
+  TAccountNcn extends string = string,
+  TAccountNcnMetadata extends string = string,
+  TAccountMetadataAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  ncn: Address<TAccountNcn>;
+  ncnMetadata: Address<TAccountNcnMetadata>;
+  metadataAdmin: TransactionSigner<TAccountMetadataAdmin>;
+  name: UpdateNcnMetadataInstructionDataArgs['name'];
+  website: UpdateNcnMetadataInstructionDataArgs['website'];
+  logoUri: UpdateNcnMetadataInstructionDataArgs['logoUri'];
+  contact: UpdateNcnMetadataInstructionDataArgs['contact'];
+  descriptionHash: UpdateNcnMetadataInstructionDataArgs['descriptionHash'];
+};
+
+export function getUpdateNcnMetadataInstruction<
+  TAccountConfig extends string,
+  TAccountNcn extends string,
+  TAccountNcnMetadata extends string,
+  TAccountMetadataAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: UpdateNcnMetadataInput<
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnMetadata,
+    TAccountMetadataAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): UpdateNcnMetadataInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountNcn,
+  TAccountNcnMetadata,
+  TAccountMetadataAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    ncn: { value: input.ncn ?? null, isWritable: false },
+    ncnMetadata: { value: input.ncnMetadata ?? null, isWritable: true },
+    metadataAdmin: { value: input.metadataAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.ncn),
+      getAccountMeta(accounts.ncnMetadata),
+      getAccountMeta(accounts.metadataAdmin),
+    ],
+    programAddress,
+    data: getUpdateNcnMetadataInstructionDataEncoder().encode(
+      args as UpdateNcnMetadataInstructionDataArgs
+    ),
+  } as UpdateNcnMetadataInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountNcn,
+    TAccountNcnMetadata,
+    TAccountMetadataAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedUpdateNcnMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    ncn: TAccountMetas[1];
+    ncnMetadata: TAccountMetas[2];
+    metadataAdmin: TAccountMetas[3];
+  };
+  data: UpdateNcnMetadataInstructionData;
+};
+
+export function parseUpdateNcnMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedUpdateNcnMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      ncn: getNextAccount(),
+      ncnMetadata: getNextAccount(),
+      metadataAdmin: getNextAccount(),
+    },
+    data: getUpdateNcnMetadataInstructionDataDecoder().decode(instruction.data),
+  };
+}
@@ -0,0 +1,270 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getBytesDecoder,
+  getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  transformEncoder,
+  type Address,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type IAccountMeta,
+  type IAccountSignerMeta,
+  type IInstruction,
+  type IInstructionWithAccounts,
+  type IInstructionWithData,
+  type Option,
+  type OptionOrNullable,
+  type ReadonlyAccount,
+  type ReadonlySignerAccount,
+  type ReadonlyUint8Array,
+  type TransactionSigner,
+  type WritableAccount,
+} from '@solana/web3.js';
+import { JITO_RESTAKING_PROGRAM_ADDRESS } from '../programs';
+import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
+
+export const UPDATE_OPERATOR_METADATA_DISCRIMINATOR = 28;
+
+export function getUpdateOperatorMetadataDiscriminatorBytes() {
+  return getU8Encoder().encode(UPDATE_OPERATOR_METADATA_DISCRIMINATOR);
+}
+
+export type UpdateOperatorMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountConfig extends string | IAccountMeta<string> = string,
+  TAccountOperator extends string | IAccountMeta<string> = string,
+  TAccountOperatorMetadata extends string | IAccountMeta<string> = string,
+  TAccountMetadataAdmin extends string | IAccountMeta<string> = string,
+  TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
+> = IInstruction<TProgram> &
+  IInstructionWithData<Uint8Array> &
+  IInstructionWithAccounts<
+    [
+      TAccountConfig extends string
+        ? ReadonlyAccount<TAccountConfig>
+        : TAccountConfig,
+      TAccountOperator extends string
+        ? ReadonlyAccount<TAccountOperator>
+        : TAccountOperator,
+      TAccountOperatorMetadata extends string
+        ? WritableAccount<TAccountOperatorMetadata>
+        : TAccountOperatorMetadata,
+      TAccountMetadataAdmin extends string
+        ? ReadonlySignerAccount<TAccountMetadataAdmin> &
+            IAccountSignerMeta<TAccountMetadataAdmin>
+        : TAccountMetadataAdmin,
+      ...TRemainingAccounts,
+    ]
+  >;
+
+export type UpdateOperatorMetadataInstructionData = {
+  discriminator: number;
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: Option<ReadonlyUint8Array>;
+};
+
+export type UpdateOperatorMetadataInstructionDataArgs = {
+  name: string;
+  website: string;
+  logoUri: string;
+  contact: string;
+  descriptionHash: OptionOrNullable<ReadonlyUint8Array>;
+};
+
+export function getUpdateOperatorMetadataInstructionDataEncoder(): Encoder<UpdateOperatorMetadataInstructionDataArgs> {
+  return transformEncoder(
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['website', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['logoUri', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      ['contact', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+      [
+        'descriptionHash',
+        getOptionEncoder(fixEncoderSize(getBytesEncoder(), 32)),
+      ],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: UPDATE_OPERATOR_METADATA_DISCRIMINATOR,
+    })
+  );
+}
+
+export function getUpdateOperatorMetadataInstructionDataDecoder(): Decoder<UpdateOperatorMetadataInstructionData> {
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['website', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['logoUri', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    ['contact', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+    [
+      'descriptionHash',
+      getOptionDecoder(fixDecoderSize(getBytesDecoder(), 32)),
+    ],
+  ]);
+}
+
+export function getUpdateOperatorMetadataInstructionDataCodec(): Codec<
+  UpdateOperatorMetadataInstructionDataArgs,
+  UpdateOperatorMetadataInstructionData
+> {
+  return combineCodec(
+    getUpdateOperatorMetadataInstructionDataEncoder(),
+    getUpdateOperatorMetadataInstructionDataDecoder()
+  );
+}
+
+export type UpdateOperatorMetadataInput<
+  TAccountConfig extends string = string,
+  TAccountOperator extends string = string,
+  TAccountOperatorMetadata extends string = string,
+  TAccountMetadataAdmin extends string = string,
+> = {
+  config: Address<TAccountConfig>;
+  operator: Address<TAccountOperator>;
+  operatorMetadata: Address<TAccountOperatorMetadata>;
+  metadataAdmin: TransactionSigner<TAccountMetadataAdmin>;
+  name: UpdateOperatorMetadataInstructionDataArgs['name'];
+  website: UpdateOperatorMetadataInstructionDataArgs['website'];
+  logoUri: UpdateOperatorMetadataInstructionDataArgs['logoUri'];
+  contact: UpdateOperatorMetadataInstructionDataArgs['contact'];
+  descriptionHash: UpdateOperatorMetadataInstructionDataArgs['descriptionHash'];
+};
+
+export function getUpdateOperatorMetadataInstruction<
+  TAccountConfig extends string,
+  TAccountOperator extends string,
+  TAccountOperatorMetadata extends string,
+  TAccountMetadataAdmin extends string,
+  TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+>(
+  input: UpdateOperatorMetadataInput<
+    TAccountConfig,
+    TAccountOperator,
+    TAccountOperatorMetadata,
+    TAccountMetadataAdmin
+  >,
+  config?: { programAddress?: TProgramAddress }
+): UpdateOperatorMetadataInstruction<
+  TProgramAddress,
+  TAccountConfig,
+  TAccountOperator,
+  TAccountOperatorMetadata,
+  TAccountMetadataAdmin
+> {
+  // Program address.
+  const programAddress =
+    config?.programAddress ?? JITO_RESTAKING_PROGRAM_ADDRESS;
+
+  // Original accounts.
+  const originalAccounts = {
+    config: { value: input.config ?? null, isWritable: false },
+    operator: { value: input.operator ?? null, isWritable: false },
+    operatorMetadata: {
+      value: input.operatorMetadata ?? null,
+      isWritable: true,
+    },
+    metadataAdmin: { value: input.metadataAdmin ?? null, isWritable: false },
+  };
+  const accounts = originalAccounts as Record<
+    keyof typeof originalAccounts,
+    ResolvedAccount
+  >;
+
+  // Original args.
+  const args = { ...input };
+
+  const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
+  const instruction = {
+    accounts: [
+      getAccountMeta(accounts.config),
+      getAccountMeta(accounts.operator),
+      getAccountMeta(accounts.operatorMetadata),
+      getAccountMeta(accounts.metadataAdmin),
+    ],
+    programAddress,
+    data: getUpdateOperatorMetadataInstructionDataEncoder().encode(
+      args as UpdateOperatorMetadataInstructionDataArgs
+    ),
+  } as UpdateOperatorMetadataInstruction<
+    TProgramAddress,
+    TAccountConfig,
+    TAccountOperator,
+    TAccountOperatorMetadata,
+    TAccountMetadataAdmin
+  >;
+
+  return instruction;
+}
+
+export type ParsedUpdateOperatorMetadataInstruction<
+  TProgram extends string = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
+  TAccountMetas extends readonly IAccountMeta[] = readonly IAccountMeta[],
+> = {
+  programAddress: Address<TProgram>;
+  accounts: {
+    config: TAccountMetas[0];
+    operator: TAccountMetas[1];
+    operatorMetadata: TAccountMetas[2];
+    metadataAdmin: TAccountMetas[3];
+  };
+  data: UpdateOperatorMetadataInstructionData;
+};
+
+export function parseUpdateOperatorMetadataInstruction<
+  TProgram extends string,
+  TAccountMetas extends readonly IAccountMeta[],
+>(
+  instruction: IInstruction<TProgram> &
+    IInstructionWithAccounts<TAccountMetas> &
+    IInstructionWithData<Uint8Array>
+): ParsedUpdateOperatorMetadataInstruction<TProgram, TAccountMetas> {
+  if (instruction.accounts.length < 4) {
+    // TODO: Coded error.
+    throw new Error('Not enough accounts');
+  }
+  let accountIndex = 0;
+  const getNextAccount = () => {
+    const accountMeta = instruction.accounts![accountIndex]!;
+    accountIndex += 1;
+    return accountMeta;
+  };
+  return {
+    programAddress: instruction.programAddress,
+    accounts: {
+      config: getNextAccount(),
+      operator: getNextAccount(),
+      operatorMetadata: getNextAccount(),
+      metadataAdmin: getNextAccount(),
+    },
+    data: getUpdateOperatorMetadataInstructionDataDecoder().decode(
+      instruction.data
+    ),
+  };
+}
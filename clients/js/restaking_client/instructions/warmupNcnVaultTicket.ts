@@ -43,6 +43,7 @@ export type WarmupNcnVaultTicketInstruction<
   TAccountVault extends string | IAccountMeta<string> = string,
   TAccountNcnVaultTicket extends string | IAccountMeta<string> = string,
   TAccountAdmin extends string | IAccountMeta<string> = string,
+  TAccountNcnPolicy extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -62,6 +63,9 @@ export type WarmupNcnVaultTicketInstruction<
         ? ReadonlySignerAccount<TAccountAdmin> &
             IAccountSignerMeta<TAccountAdmin>
         : TAccountAdmin,
+      TAccountNcnPolicy extends string
+        ? ReadonlyAccount<TAccountNcnPolicy>
+        : TAccountNcnPolicy,
       ...TRemainingAccounts,
     ]
   >;
@@ -100,12 +104,15 @@ export type WarmupNcnVaultTicketInput<
   TAccountVault extends string = string,
   TAccountNcnVaultTicket extends string = string,
   TAccountAdmin extends string = string,
+  TAccountNcnPolicy extends string = string,
 > = {
   config: Address<TAccountConfig>;
   ncn: Address<TAccountNcn>;
   vault: Address<TAccountVault>;
   ncnVaultTicket: Address<TAccountNcnVaultTicket>;
   admin: TransactionSigner<TAccountAdmin>;
+  /** Required if the NCN has a policy */
+  ncnPolicy?: Address<TAccountNcnPolicy>;
 };
 
 export function getWarmupNcnVaultTicketInstruction<
@@ -114,6 +121,7 @@ export function getWarmupNcnVaultTicketInstruction<
   TAccountVault extends string,
   TAccountNcnVaultTicket extends string,
   TAccountAdmin extends string,
+  TAccountNcnPolicy extends string,
   TProgramAddress extends Address = typeof JITO_RESTAKING_PROGRAM_ADDRESS,
 >(
   input: WarmupNcnVaultTicketInput<
@@ -121,7 +129,8 @@ export function getWarmupNcnVaultTicketInstruction<
     TAccountNcn,
     TAccountVault,
     TAccountNcnVaultTicket,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountNcnPolicy
   >,
   config?: { programAddress?: TProgramAddress }
 ): WarmupNcnVaultTicketInstruction<
@@ -130,7 +139,8 @@ export function getWarmupNcnVaultTicketInstruction<
   TAccountNcn,
   TAccountVault,
   TAccountNcnVaultTicket,
-  TAccountAdmin
+  TAccountAdmin,
+  TAccountNcnPolicy
 > {
   // Program address.
   const programAddress =
@@ -143,6 +153,7 @@ export function getWarmupNcnVaultTicketInstruction<
     vault: { value: input.vault ?? null, isWritable: false },
     ncnVaultTicket: { value: input.ncnVaultTicket ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
+    ncnPolicy: { value: input.ncnPolicy ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -157,6 +168,7 @@ export function getWarmupNcnVaultTicketInstruction<
       getAccountMeta(accounts.vault),
       getAccountMeta(accounts.ncnVaultTicket),
       getAccountMeta(accounts.admin),
+      getAccountMeta(accounts.ncnPolicy),
     ],
     programAddress,
     data: getWarmupNcnVaultTicketInstructionDataEncoder().encode({}),
@@ -166,7 +178,8 @@ export function getWarmupNcnVaultTicketInstruction<
     TAccountNcn,
     TAccountVault,
     TAccountNcnVaultTicket,
-    TAccountAdmin
+    TAccountAdmin,
+    TAccountNcnPolicy
   >;
 
   return instruction;
@@ -183,6 +196,8 @@ export type ParsedWarmupNcnVaultTicketInstruction<
     vault: TAccountMetas[2];
     ncnVaultTicket: TAccountMetas[3];
     admin: TAccountMetas[4];
+    /** Required if the NCN has a policy */
+    ncnPolicy?: TAccountMetas[5] | undefined;
   };
   data: WarmupNcnVaultTicketInstructionData;
 };
@@ -195,7 +210,7 @@ export function parseWarmupNcnVaultTicketInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedWarmupNcnVaultTicketInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -205,6 +220,12 @@ export function parseWarmupNcnVaultTicketInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === JITO_RESTAKING_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -213,6 +234,7 @@ export function parseWarmupNcnVaultTicketInstruction<
       vault: getNextAccount(),
       ncnVaultTicket: getNextAccount(),
       admin: getNextAccount(),
+      ncnPolicy: getNextOptionalAccount(),
     },
     data: getWarmupNcnVaultTicketInstructionDataDecoder().decode(
       instruction.data
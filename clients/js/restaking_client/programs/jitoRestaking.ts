@@ -18,23 +18,39 @@ import {
   type ParsedCooldownOperatorVaultTicketInstruction,
   type ParsedInitializeConfigInstruction,
   type ParsedInitializeNcnInstruction,
+  type ParsedInitializeNcnMetadataInstruction,
   type ParsedInitializeNcnOperatorStateInstruction,
+  type ParsedInitializeNcnPolicyInstruction,
   type ParsedInitializeNcnVaultSlasherTicketInstruction,
   type ParsedInitializeNcnVaultTicketInstruction,
+  type ParsedInitializeOperatorBondInstruction,
   type ParsedInitializeOperatorInstruction,
+  type ParsedInitializeOperatorMetadataInstruction,
   type ParsedInitializeOperatorVaultTicketInstruction,
+  type ParsedMigrateAccountInstruction,
   type ParsedNcnCooldownOperatorInstruction,
   type ParsedNcnDelegateTokenAccountInstruction,
+  type ParsedNcnJailOperatorInstruction,
   type ParsedNcnSetAdminInstruction,
+  type ParsedNcnSetOperatorBondRequirementInstruction,
+  type ParsedNcnSetPolicyInstruction,
   type ParsedNcnSetSecondaryAdminInstruction,
+  type ParsedNcnSetTransitionEpochsInstruction,
+  type ParsedNcnUnjailOperatorInstruction,
   type ParsedNcnWarmupOperatorInstruction,
   type ParsedOperatorCooldownNcnInstruction,
   type ParsedOperatorDelegateTokenAccountInstruction,
+  type ParsedOperatorDepositBondInstruction,
   type ParsedOperatorSetAdminInstruction,
   type ParsedOperatorSetFeeInstruction,
   type ParsedOperatorSetSecondaryAdminInstruction,
+  type ParsedOperatorUnbondInstruction,
   type ParsedOperatorWarmupNcnInstruction,
+  type ParsedOperatorWithdrawBondInstruction,
   type ParsedSetConfigAdminInstruction,
+  type ParsedSlashOperatorBondInstruction,
+  type ParsedUpdateNcnMetadataInstruction,
+  type ParsedUpdateOperatorMetadataInstruction,
   type ParsedWarmupNcnVaultSlasherTicketInstruction,
   type ParsedWarmupNcnVaultTicketInstruction,
   type ParsedWarmupOperatorVaultTicketInstruction,
@@ -46,10 +62,14 @@ export const JITO_RESTAKING_PROGRAM_ADDRESS =
 export enum JitoRestakingAccount {
   Config,
   Ncn,
+  NcnMetadata,
   NcnOperatorState,
+  NcnPolicy,
   NcnVaultSlasherTicket,
   NcnVaultTicket,
   Operator,
+  OperatorBond,
+  OperatorMetadata,
   OperatorVaultTicket,
 }
 
@@ -79,6 +99,22 @@ export enum JitoRestakingInstruction {
   NcnDelegateTokenAccount,
   OperatorDelegateTokenAccount,
   SetConfigAdmin,
+  InitializeNcnMetadata,
+  UpdateNcnMetadata,
+  InitializeOperatorMetadata,
+  UpdateOperatorMetadata,
+  NcnSetOperatorBondRequirement,
+  InitializeOperatorBond,
+  OperatorDepositBond,
+  SlashOperatorBond,
+  InitializeNcnPolicy,
+  NcnSetPolicy,
+  NcnJailOperator,
+  NcnUnjailOperator,
+  NcnSetTransitionEpochs,
+  MigrateAccount,
+  OperatorUnbond,
+  OperatorWithdrawBond,
 }
 
 export function identifyJitoRestakingInstruction(
@@ -160,6 +196,54 @@ export function identifyJitoRestakingInstruction(
   if (containsBytes(data, getU8Encoder().encode(24), 0)) {
     return JitoRestakingInstruction.SetConfigAdmin;
   }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return JitoRestakingInstruction.InitializeNcnMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return JitoRestakingInstruction.UpdateNcnMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return JitoRestakingInstruction.InitializeOperatorMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return JitoRestakingInstruction.UpdateOperatorMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return JitoRestakingInstruction.NcnSetOperatorBondRequirement;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return JitoRestakingInstruction.InitializeOperatorBond;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return JitoRestakingInstruction.OperatorDepositBond;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return JitoRestakingInstruction.SlashOperatorBond;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return JitoRestakingInstruction.InitializeNcnPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return JitoRestakingInstruction.NcnSetPolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(35), 0)) {
+    return JitoRestakingInstruction.NcnJailOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(36), 0)) {
+    return JitoRestakingInstruction.NcnUnjailOperator;
+  }
+  if (containsBytes(data, getU8Encoder().encode(37), 0)) {
+    return JitoRestakingInstruction.NcnSetTransitionEpochs;
+  }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return JitoRestakingInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(39), 0)) {
+    return JitoRestakingInstruction.OperatorUnbond;
+  }
+  if (containsBytes(data, getU8Encoder().encode(40), 0)) {
+    return JitoRestakingInstruction.OperatorWithdrawBond;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoRestaking instruction.'
   );
@@ -242,4 +326,52 @@ export type ParsedJitoRestakingInstruction<
     } & ParsedOperatorDelegateTokenAccountInstruction<TProgram>)
   | ({
       instructionType: JitoRestakingInstruction.SetConfigAdmin;
-    } & ParsedSetConfigAdminInstruction<TProgram>);
+    } & ParsedSetConfigAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.InitializeNcnMetadata;
+    } & ParsedInitializeNcnMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.UpdateNcnMetadata;
+    } & ParsedUpdateNcnMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.InitializeOperatorMetadata;
+    } & ParsedInitializeOperatorMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.UpdateOperatorMetadata;
+    } & ParsedUpdateOperatorMetadataInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetOperatorBondRequirement;
+    } & ParsedNcnSetOperatorBondRequirementInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.InitializeOperatorBond;
+    } & ParsedInitializeOperatorBondInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorDepositBond;
+    } & ParsedOperatorDepositBondInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.SlashOperatorBond;
+    } & ParsedSlashOperatorBondInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.InitializeNcnPolicy;
+    } & ParsedInitializeNcnPolicyInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetPolicy;
+    } & ParsedNcnSetPolicyInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnJailOperator;
+    } & ParsedNcnJailOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnUnjailOperator;
+    } & ParsedNcnUnjailOperatorInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.NcnSetTransitionEpochs;
+    } & ParsedNcnSetTransitionEpochsInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorUnbond;
+    } & ParsedOperatorUnbondInstruction<TProgram>)
+  | ({
+      instructionType: JitoRestakingInstruction.OperatorWithdrawBond;
+    } & ParsedOperatorWithdrawBondInstruction<TProgram>);
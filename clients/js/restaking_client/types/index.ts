@@ -6,6 +6,9 @@
  * @see https://github.com/kinobi-so/kinobi
  */
 
+export * from './delegationState';
+export * from './metadata';
 export * from './ncnAdminRole';
 export * from './operatorAdminRole';
 export * from './slotToggle';
+export * from './transitionEpochs';
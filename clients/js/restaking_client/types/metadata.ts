@@ -0,0 +1,64 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
+  getArrayDecoder,
+  getArrayEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU8Decoder,
+  getU8Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+  type ReadonlyUint8Array,
+} from '@solana/web3.js';
+
+export type Metadata = {
+  name: ReadonlyUint8Array;
+  website: Array<number>;
+  logoUri: Array<number>;
+  contact: ReadonlyUint8Array;
+  descriptionHash: ReadonlyUint8Array;
+  hasDescriptionHash: boolean;
+};
+
+export type MetadataArgs = Metadata;
+
+export function getMetadataEncoder(): Encoder<MetadataArgs> {
+  return getStructEncoder([
+    ['name', fixEncoderSize(getBytesEncoder(), 64)],
+    ['website', getArrayEncoder(getU8Encoder(), { size: 128 })],
+    ['logoUri', getArrayEncoder(getU8Encoder(), { size: 128 })],
+    ['contact', fixEncoderSize(getBytesEncoder(), 64)],
+    ['descriptionHash', fixEncoderSize(getBytesEncoder(), 32)],
+    ['hasDescriptionHash', getBooleanEncoder()],
+  ]);
+}
+
+export function getMetadataDecoder(): Decoder<Metadata> {
+  return getStructDecoder([
+    ['name', fixDecoderSize(getBytesDecoder(), 64)],
+    ['website', getArrayDecoder(getU8Decoder(), { size: 128 })],
+    ['logoUri', getArrayDecoder(getU8Decoder(), { size: 128 })],
+    ['contact', fixDecoderSize(getBytesDecoder(), 64)],
+    ['descriptionHash', fixDecoderSize(getBytesDecoder(), 32)],
+    ['hasDescriptionHash', getBooleanDecoder()],
+  ]);
+}
+
+export function getMetadataCodec(): Codec<MetadataArgs, Metadata> {
+  return combineCodec(getMetadataEncoder(), getMetadataDecoder());
+}
@@ -0,0 +1,54 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/kinobi-so/kinobi
+ */
+
+import {
+  combineCodec,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getStructDecoder,
+  getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
+} from '@solana/web3.js';
+
+export type TransitionEpochs = {
+  isSet: boolean;
+  warmupEpochs: number;
+  cooldownEpochs: number;
+};
+
+export type TransitionEpochsArgs = TransitionEpochs;
+
+export function getTransitionEpochsEncoder(): Encoder<TransitionEpochsArgs> {
+  return getStructEncoder([
+    ['isSet', getBooleanEncoder()],
+    ['warmupEpochs', getU16Encoder()],
+    ['cooldownEpochs', getU16Encoder()],
+  ]);
+}
+
+export function getTransitionEpochsDecoder(): Decoder<TransitionEpochs> {
+  return getStructDecoder([
+    ['isSet', getBooleanDecoder()],
+    ['warmupEpochs', getU16Decoder()],
+    ['cooldownEpochs', getU16Decoder()],
+  ]);
+}
+
+export function getTransitionEpochsCodec(): Codec<
+  TransitionEpochsArgs,
+  TransitionEpochs
+> {
+  return combineCodec(
+    getTransitionEpochsEncoder(),
+    getTransitionEpochsDecoder()
+  );
+}
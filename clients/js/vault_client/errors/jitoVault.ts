@@ -14,145 +14,178 @@ import {
 } from '@solana/web3.js';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 
-/** BadEpochLength: Bad epoch length */
-export const JITO_VAULT_ERROR__BAD_EPOCH_LENGTH = 0x0; // 0
-/** VaultSlashUnderflow: VaultSlashUnderflow */
+/** VaultSlashUnderflow: Slash amount exceeds the security delegated to the operator */
 export const JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW = 0x3e8; // 1000
-/** VaultInitialAmountFailed: VaultInitialAmountFailed */
+/** VaultInitialAmountFailed: Initial deposit must be non-zero and mint VRT one to one */
 export const JITO_VAULT_ERROR__VAULT_INITIAL_AMOUNT_FAILED = 0x3e9; // 1001
-/** VaultInsufficientFunds: VaultInsufficientFunds */
+/** VaultInsufficientFunds: Vault does not hold enough assets for this amount */
 export const JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS = 0x3ea; // 1002
-/** VaultOverflow: VaultOverflow */
+/** VaultOverflow: Vault calculation overflowed */
 export const JITO_VAULT_ERROR__VAULT_OVERFLOW = 0x3eb; // 1003
-/** VaultOperatorAdminInvalid: VaultOperatorAdminInvalid */
+/** VaultOperatorAdminInvalid: Signer is not the vault's operator admin */
 export const JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID = 0x3ec; // 1004
-/** VaultAdminInvalid: VaultAdminInvalid */
+/** VaultAdminInvalid: Signer is not the vault admin */
 export const JITO_VAULT_ERROR__VAULT_ADMIN_INVALID = 0x3ed; // 1005
-/** VaultCapacityAdminInvalid: VaultCapacityAdminInvalid */
+/** VaultCapacityAdminInvalid: Signer is not the vault's capacity admin */
 export const JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID = 0x3ee; // 1006
-/** VaultMintBurnAdminInvalid: VaultMintBurnAdminInvalid */
+/** VaultMintBurnAdminInvalid: Signer is not the vault's mint burn admin */
 export const JITO_VAULT_ERROR__VAULT_MINT_BURN_ADMIN_INVALID = 0x3ef; // 1007
-/** VaultDelegationAdminInvalid: VaultDelegationAdminInvalid */
+/** VaultDelegationAdminInvalid: Signer is not the vault's delegation admin */
 export const JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID = 0x3f0; // 1008
-/** VaultDelegateAssetAdminInvalid: VaultDelegateAssetAdminInvalid */
+/** VaultDelegateAssetAdminInvalid: Signer is not the vault's delegate asset admin */
 export const JITO_VAULT_ERROR__VAULT_DELEGATE_ASSET_ADMIN_INVALID = 0x3f1; // 1009
-/** VaultCapacityExceeded: VaultCapacityExceeded */
+/** VaultCapacityExceeded: Deposit would exceed the vault's deposit capacity */
 export const JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED = 0x3f2; // 1010
-/** VaultSlasherAdminInvalid: VaultSlasherAdminInvalid */
+/** VaultSlasherAdminInvalid: Signer is not the vault's slasher admin */
 export const JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID = 0x3f3; // 1011
-/** VaultNcnAdminInvalid: VaultNcnAdminInvalid */
+/** VaultNcnAdminInvalid: Signer is not the vault's NCN admin */
 export const JITO_VAULT_ERROR__VAULT_NCN_ADMIN_INVALID = 0x3f4; // 1012
-/** VaultFeeAdminInvalid: VaultFeeAdminInvalid */
+/** VaultFeeAdminInvalid: Signer is not the vault's fee admin */
 export const JITO_VAULT_ERROR__VAULT_FEE_ADMIN_INVALID = 0x3f5; // 1013
-/** ConfigAdminInvalid: ConfigAdminInvalid */
+/** ConfigAdminInvalid: Signer is not the config admin */
 export const JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID = 0x3f6; // 1014
-/** ConfigFeeAdminInvalid: ConfigFeeAdminInvalid */
+/** ConfigFeeAdminInvalid: Signer is not the config's fee admin */
 export const JITO_VAULT_ERROR__CONFIG_FEE_ADMIN_INVALID = 0x3f7; // 1015
-/** VaultFeeCapExceeded: VaultFeeCapExceeded */
+/** VaultFeeCapExceeded: Fee exceeds the maximum allowed fee */
 export const JITO_VAULT_ERROR__VAULT_FEE_CAP_EXCEEDED = 0x3f8; // 1016
-/** VaultFeeChangeTooSoon: VaultFeeChangeTooSoon */
+/** VaultFeeChangeTooSoon: Fees can only be changed once per epoch */
 export const JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON = 0x3f9; // 1017
-/** VaultFeeBumpTooLarge: VaultFeeBumpTooLarge */
+/** VaultFeeBumpTooLarge: Fee increase exceeds the maximum allowed per change */
 export const JITO_VAULT_ERROR__VAULT_FEE_BUMP_TOO_LARGE = 0x3fa; // 1018
-/** VaultUnderflow: VaultUnderflow */
+/** VaultUnderflow: Vault calculation underflowed */
 export const JITO_VAULT_ERROR__VAULT_UNDERFLOW = 0x3fb; // 1019
-/** VaultUpdateNeeded: VaultUpdateNeeded */
+/** VaultUpdateNeeded: Vault must be updated for the current epoch first */
 export const JITO_VAULT_ERROR__VAULT_UPDATE_NEEDED = 0x3fc; // 1020
-/** VaultIsUpdated: VaultIsUpdated */
+/** VaultIsUpdated: Vault is already updated for the current epoch */
 export const JITO_VAULT_ERROR__VAULT_IS_UPDATED = 0x3fd; // 1021
-/** VaultOperatorDelegationUpdateNeeded: VaultOperatorDelegationUpdateNeeded */
+/** VaultOperatorDelegationUpdateNeeded: Vault operator delegation must be updated for the current epoch first */
 export const JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_UPDATE_NEEDED = 0x3fe; // 1022
-/** VaultOperatorDelegationIsUpdated: VaultOperatorDelegationIsUpdated */
+/** VaultOperatorDelegationIsUpdated: Vault operator delegation is already updated for the current epoch */
 export const JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_IS_UPDATED = 0x3ff; // 1023
-/** VaultUpdateIncorrectIndex: VaultUpdateIncorrectIndex */
+/** VaultUpdateIncorrectIndex: Operators must be updated in index order */
 export const JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX = 0x400; // 1024
-/** VaultUpdateStateNotFinishedUpdating: VaultUpdateStateNotFinishedUpdating */
+/** VaultUpdateStateNotFinishedUpdating: Not all operators have been updated yet */
 export const JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING = 0x401; // 1025
-/** VaultSecurityOverflow: VaultSecurityOverflow */
+/** VaultSecurityOverflow: Delegated security overflowed */
 export const JITO_VAULT_ERROR__VAULT_SECURITY_OVERFLOW = 0x402; // 1026
-/** VaultSlashIncomplete: VaultSlashIncomplete */
+/** VaultSlashIncomplete: Slash could not be applied in full */
 export const JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE = 0x403; // 1027
-/** VaultSecurityUnderflow: VaultSecurityUnderflow */
+/** VaultSecurityUnderflow: Delegated security underflowed */
 export const JITO_VAULT_ERROR__VAULT_SECURITY_UNDERFLOW = 0x404; // 1028
-/** SlippageError: SlippageError */
+/** SlippageError: Amount out is less than the minimum amount out */
 export const JITO_VAULT_ERROR__SLIPPAGE_ERROR = 0x405; // 1029
-/** VaultStakerWithdrawalTicketNotWithdrawable: VaultStakerWithdrawalTicketNotWithdrawable */
+/** VaultStakerWithdrawalTicketNotWithdrawable: Withdrawal ticket is still cooling down */
 export const JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE = 0x406; // 1030
-/** VaultNcnSlasherTicketFailedCooldown: VaultNcnSlasherTicketFailedCooldown */
+/** VaultNcnSlasherTicketFailedCooldown: Vault NCN slasher ticket cannot be cooled down in its current state */
 export const JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_COOLDOWN = 0x407; // 1031
-/** VaultNcnSlasherTicketFailedWarmup: VaultNcnSlasherTicketFailedWarmup */
+/** VaultNcnSlasherTicketFailedWarmup: Vault NCN slasher ticket cannot be warmed up in its current state */
 export const JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_WARMUP = 0x408; // 1032
-/** VaultNcnTicketFailedCooldown: VaultNcnTicketFailedCooldown */
+/** VaultNcnTicketFailedCooldown: Vault NCN ticket cannot be cooled down in its current state */
 export const JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN = 0x409; // 1033
-/** VaultNcnTicketFailedWarmup: VaultNcnTicketFailedWarmup */
+/** VaultNcnTicketFailedWarmup: Vault NCN ticket cannot be warmed up in its current state */
 export const JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP = 0x40a; // 1034
-/** VaultNcnTicketUnslashable: VaultNcnTicketUnslashable */
+/** VaultNcnTicketUnslashable: Vault NCN ticket is not active, so the NCN cannot slash */
 export const JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE = 0x40b; // 1035
-/** OperatorVaultTicketUnslashable: OperatorVaultTicketUnslashable */
+/** OperatorVaultTicketUnslashable: Operator vault ticket is not active, so the operator cannot be slashed */
 export const JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE = 0x40c; // 1036
-/** NcnOperatorStateUnslashable: NcnOperatorStateUnslashable */
+/** NcnOperatorStateUnslashable: NCN operator state is not active, so the operator cannot be slashed */
 export const JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE = 0x40d; // 1037
-/** VaultNcnSlasherTicketUnslashable: VaultNcnSlasherTicketUnslashable */
+/** VaultNcnSlasherTicketUnslashable: Vault NCN slasher ticket is not active, so the slasher cannot slash */
 export const JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_UNSLASHABLE = 0x40e; // 1038
-/** NcnVaultTicketUnslashable: NcnVaultTicketUnslashable */
+/** NcnVaultTicketUnslashable: NCN vault ticket is not active, so the NCN cannot slash */
 export const JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE = 0x40f; // 1039
-/** NcnVaultSlasherTicketUnslashable: NcnVaultSlasherTicketUnslashable */
+/** NcnVaultSlasherTicketUnslashable: NCN vault slasher ticket is not active, so the slasher cannot slash */
 export const JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE = 0x410; // 1040
-/** VaultMaxSlashedPerOperatorExceeded: VaultMaxSlashedPerOperatorExceeded */
+/** VaultMaxSlashedPerOperatorExceeded: Slash exceeds the maximum slashable per operator this epoch */
 export const JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED = 0x411; // 1041
-/** VaultStakerWithdrawalTicketInvalidStaker: VaultStakerWithdrawalTicketInvalidStaker */
+/** VaultStakerWithdrawalTicketInvalidStaker: Signer is not the staker of the withdrawal ticket */
 export const JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER = 0x412; // 1042
-/** SlasherOverflow: SlasherOverflow */
+/** SlasherOverflow: Vault slasher count overflowed */
 export const JITO_VAULT_ERROR__SLASHER_OVERFLOW = 0x413; // 1043
-/** NcnOverflow: NcnOverflow */
+/** NcnOverflow: Vault NCN count overflowed */
 export const JITO_VAULT_ERROR__NCN_OVERFLOW = 0x414; // 1044
-/** OperatorOverflow: OperatorOverflow */
+/** OperatorOverflow: Vault operator count overflowed */
 export const JITO_VAULT_ERROR__OPERATOR_OVERFLOW = 0x415; // 1045
-/** VaultDelegationZero: VaultDelegationZero */
+/** VaultDelegationZero: Delegation amount must be non-zero */
 export const JITO_VAULT_ERROR__VAULT_DELEGATION_ZERO = 0x416; // 1046
-/** VaultCooldownZero: VaultCooldownZero */
+/** VaultCooldownZero: Cooldown amount must be non-zero */
 export const JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO = 0x417; // 1047
-/** VaultBurnZero: VaultBurnZero */
+/** VaultBurnZero: Burn amount must be non-zero */
 export const JITO_VAULT_ERROR__VAULT_BURN_ZERO = 0x418; // 1048
-/** VaultEnqueueWithdrawalAmountZero: VaultEnqueueWithdrawalAmountZero */
+/** VaultEnqueueWithdrawalAmountZero: Withdrawal amount must be non-zero */
 export const JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO = 0x419; // 1049
-/** VaultMintZero: VaultMintZero */
+/** VaultMintZero: Mint amount must be non-zero */
 export const JITO_VAULT_ERROR__VAULT_MINT_ZERO = 0x41a; // 1050
-/** VaultIsPaused: VaultIsPaused */
+/** VaultIsPaused: Vault is paused */
 export const JITO_VAULT_ERROR__VAULT_IS_PAUSED = 0x41b; // 1051
-/** InvalidDepositor: InvalidDepositor */
+/** InvalidDepositor: Depositor cannot be the vault */
 export const JITO_VAULT_ERROR__INVALID_DEPOSITOR = 0x41c; // 1052
-/** InvalidDepositTokenAccount: InvalidDepositTokenAccount */
+/** InvalidDepositTokenAccount: Depositor token account cannot be the vault token account */
 export const JITO_VAULT_ERROR__INVALID_DEPOSIT_TOKEN_ACCOUNT = 0x41d; // 1053
-/** NoSupportedMintBalanceChange: NoSupportedMintBalanceChange */
+/** NoSupportedMintBalanceChange: Supported mint balance did not change */
 export const JITO_VAULT_ERROR__NO_SUPPORTED_MINT_BALANCE_CHANGE = 0x41e; // 1054
-/** InvalidEpochLength: InvalidEpochLength */
+/** InvalidEpochLength: Epoch length is invalid */
 export const JITO_VAULT_ERROR__INVALID_EPOCH_LENGTH = 0x41f; // 1055
-/** VaultRewardFeeDeltaTooLarge: VaultRewardFeeDeltaTooLarge */
+/** VaultRewardFeeDeltaTooLarge: Reward fee taken differs too much from the configured reward fee */
 export const JITO_VAULT_ERROR__VAULT_REWARD_FEE_DELTA_TOO_LARGE = 0x420; // 1056
-/** VaultRewardFeeIsZero: VaultRewardFeeIsZero */
+/** VaultRewardFeeIsZero: Reward fee rounded down to zero VRT */
 export const JITO_VAULT_ERROR__VAULT_REWARD_FEE_IS_ZERO = 0x421; // 1057
-/** VrtOutCannotBeZero: VrtOutCannotBeZero */
+/** VrtOutCannotBeZero: Deposit is too small to mint any VRT */
 export const JITO_VAULT_ERROR__VRT_OUT_CANNOT_BE_ZERO = 0x422; // 1058
-/** NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate: NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate */
+/** NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate: Not enough assets were cooled down to cover pending withdrawals */
 export const JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE = 0x423; // 1059
-/** ArithmeticOverflow: ArithmeticOverflow */
+/** ArithmeticOverflow: Arithmetic overflow */
 export const JITO_VAULT_ERROR__ARITHMETIC_OVERFLOW = 0xbb8; // 3000
-/** ArithmeticUnderflow: ArithmeticUnderflow */
+/** ArithmeticUnderflow: Arithmetic underflow */
 export const JITO_VAULT_ERROR__ARITHMETIC_UNDERFLOW = 0xbb9; // 3001
-/** DivisionByZero: DivisionByZero */
+/** DivisionByZero: Division by zero */
 export const JITO_VAULT_ERROR__DIVISION_BY_ZERO = 0xbba; // 3002
+/** BadEpochLength: Bad epoch length */
+export const JITO_VAULT_ERROR__BAD_EPOCH_LENGTH = 0xfa0; // 4000
+/** AccountNotSigner: not a signer */
+export const JITO_VAULT_ERROR__ACCOUNT_NOT_SIGNER = 0x1004; // 4100
+/** AccountNotWritable: not writable */
+export const JITO_VAULT_ERROR__ACCOUNT_NOT_WRITABLE = 0x1005; // 4101
+/** InvalidAccountOwner: wrong owner */
+export const JITO_VAULT_ERROR__INVALID_ACCOUNT_OWNER = 0x1006; // 4102
+/** AccountDataEmpty: account data is empty */
+export const JITO_VAULT_ERROR__ACCOUNT_DATA_EMPTY = 0x1007; // 4103
+/** AccountAlreadyInitialized: account is already initialized */
+export const JITO_VAULT_ERROR__ACCOUNT_ALREADY_INITIALIZED = 0x1008; // 4104
+/** InvalidAccountData: account data is invalid */
+export const JITO_VAULT_ERROR__INVALID_ACCOUNT_DATA = 0x1009; // 4105
+/** InvalidDiscriminator: wrong discriminator */
+export const JITO_VAULT_ERROR__INVALID_DISCRIMINATOR = 0x100a; // 4106
+/** InvalidAddress: not at the expected address */
+export const JITO_VAULT_ERROR__INVALID_ADDRESS = 0x100b; // 4107
+/** InvalidProgramId: wrong program */
+export const JITO_VAULT_ERROR__INVALID_PROGRAM_ID = 0x100c; // 4108
+/** InvalidTokenOwner: wrong token account owner */
+export const JITO_VAULT_ERROR__INVALID_TOKEN_OWNER = 0x100d; // 4109
+/** InvalidMint: wrong mint */
+export const JITO_VAULT_ERROR__INVALID_MINT = 0x100e; // 4110
 
 export type JitoVaultError =
+  | typeof JITO_VAULT_ERROR__ACCOUNT_ALREADY_INITIALIZED
+  | typeof JITO_VAULT_ERROR__ACCOUNT_DATA_EMPTY
+  | typeof JITO_VAULT_ERROR__ACCOUNT_NOT_SIGNER
+  | typeof JITO_VAULT_ERROR__ACCOUNT_NOT_WRITABLE
   | typeof JITO_VAULT_ERROR__ARITHMETIC_OVERFLOW
   | typeof JITO_VAULT_ERROR__ARITHMETIC_UNDERFLOW
   | typeof JITO_VAULT_ERROR__BAD_EPOCH_LENGTH
   | typeof JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__CONFIG_FEE_ADMIN_INVALID
   | typeof JITO_VAULT_ERROR__DIVISION_BY_ZERO
+  | typeof JITO_VAULT_ERROR__INVALID_ACCOUNT_DATA
+  | typeof JITO_VAULT_ERROR__INVALID_ACCOUNT_OWNER
+  | typeof JITO_VAULT_ERROR__INVALID_ADDRESS
   | typeof JITO_VAULT_ERROR__INVALID_DEPOSITOR
   | typeof JITO_VAULT_ERROR__INVALID_DEPOSIT_TOKEN_ACCOUNT
+  | typeof JITO_VAULT_ERROR__INVALID_DISCRIMINATOR
   | typeof JITO_VAULT_ERROR__INVALID_EPOCH_LENGTH
+  | typeof JITO_VAULT_ERROR__INVALID_MINT
+  | typeof JITO_VAULT_ERROR__INVALID_PROGRAM_ID
+  | typeof JITO_VAULT_ERROR__INVALID_TOKEN_OWNER
   | typeof JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE
   | typeof JITO_VAULT_ERROR__NCN_OVERFLOW
   | typeof JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE
@@ -212,82 +245,97 @@ export type JitoVaultError =
 let jitoVaultErrorMessages: Record<JitoVaultError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   jitoVaultErrorMessages = {
-    [JITO_VAULT_ERROR__ARITHMETIC_OVERFLOW]: `ArithmeticOverflow`,
-    [JITO_VAULT_ERROR__ARITHMETIC_UNDERFLOW]: `ArithmeticUnderflow`,
+    [JITO_VAULT_ERROR__ACCOUNT_ALREADY_INITIALIZED]: `account is already initialized`,
+    [JITO_VAULT_ERROR__ACCOUNT_DATA_EMPTY]: `account data is empty`,
+    [JITO_VAULT_ERROR__ACCOUNT_NOT_SIGNER]: `not a signer`,
+    [JITO_VAULT_ERROR__ACCOUNT_NOT_WRITABLE]: `not writable`,
+    [JITO_VAULT_ERROR__ARITHMETIC_OVERFLOW]: `Arithmetic overflow`,
+    [JITO_VAULT_ERROR__ARITHMETIC_UNDERFLOW]: `Arithmetic underflow`,
     [JITO_VAULT_ERROR__BAD_EPOCH_LENGTH]: `Bad epoch length`,
-    [JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID]: `ConfigAdminInvalid`,
-    [JITO_VAULT_ERROR__CONFIG_FEE_ADMIN_INVALID]: `ConfigFeeAdminInvalid`,
-    [JITO_VAULT_ERROR__DIVISION_BY_ZERO]: `DivisionByZero`,
-    [JITO_VAULT_ERROR__INVALID_DEPOSITOR]: `InvalidDepositor`,
-    [JITO_VAULT_ERROR__INVALID_DEPOSIT_TOKEN_ACCOUNT]: `InvalidDepositTokenAccount`,
-    [JITO_VAULT_ERROR__INVALID_EPOCH_LENGTH]: `InvalidEpochLength`,
-    [JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE]: `NcnOperatorStateUnslashable`,
-    [JITO_VAULT_ERROR__NCN_OVERFLOW]: `NcnOverflow`,
-    [JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE]: `NcnVaultSlasherTicketUnslashable`,
-    [JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE]: `NcnVaultTicketUnslashable`,
-    [JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE]: `NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate`,
-    [JITO_VAULT_ERROR__NO_SUPPORTED_MINT_BALANCE_CHANGE]: `NoSupportedMintBalanceChange`,
-    [JITO_VAULT_ERROR__OPERATOR_OVERFLOW]: `OperatorOverflow`,
-    [JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE]: `OperatorVaultTicketUnslashable`,
-    [JITO_VAULT_ERROR__SLASHER_OVERFLOW]: `SlasherOverflow`,
-    [JITO_VAULT_ERROR__SLIPPAGE_ERROR]: `SlippageError`,
-    [JITO_VAULT_ERROR__VAULT_ADMIN_INVALID]: `VaultAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_BURN_ZERO]: `VaultBurnZero`,
-    [JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID]: `VaultCapacityAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED]: `VaultCapacityExceeded`,
-    [JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO]: `VaultCooldownZero`,
-    [JITO_VAULT_ERROR__VAULT_DELEGATE_ASSET_ADMIN_INVALID]: `VaultDelegateAssetAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID]: `VaultDelegationAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_DELEGATION_ZERO]: `VaultDelegationZero`,
-    [JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO]: `VaultEnqueueWithdrawalAmountZero`,
-    [JITO_VAULT_ERROR__VAULT_FEE_ADMIN_INVALID]: `VaultFeeAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_FEE_BUMP_TOO_LARGE]: `VaultFeeBumpTooLarge`,
-    [JITO_VAULT_ERROR__VAULT_FEE_CAP_EXCEEDED]: `VaultFeeCapExceeded`,
-    [JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON]: `VaultFeeChangeTooSoon`,
-    [JITO_VAULT_ERROR__VAULT_INITIAL_AMOUNT_FAILED]: `VaultInitialAmountFailed`,
-    [JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS]: `VaultInsufficientFunds`,
-    [JITO_VAULT_ERROR__VAULT_IS_PAUSED]: `VaultIsPaused`,
-    [JITO_VAULT_ERROR__VAULT_IS_UPDATED]: `VaultIsUpdated`,
-    [JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED]: `VaultMaxSlashedPerOperatorExceeded`,
-    [JITO_VAULT_ERROR__VAULT_MINT_BURN_ADMIN_INVALID]: `VaultMintBurnAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_MINT_ZERO]: `VaultMintZero`,
-    [JITO_VAULT_ERROR__VAULT_NCN_ADMIN_INVALID]: `VaultNcnAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_COOLDOWN]: `VaultNcnSlasherTicketFailedCooldown`,
-    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_WARMUP]: `VaultNcnSlasherTicketFailedWarmup`,
-    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_UNSLASHABLE]: `VaultNcnSlasherTicketUnslashable`,
-    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN]: `VaultNcnTicketFailedCooldown`,
-    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP]: `VaultNcnTicketFailedWarmup`,
-    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE]: `VaultNcnTicketUnslashable`,
-    [JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID]: `VaultOperatorAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_IS_UPDATED]: `VaultOperatorDelegationIsUpdated`,
-    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_UPDATE_NEEDED]: `VaultOperatorDelegationUpdateNeeded`,
-    [JITO_VAULT_ERROR__VAULT_OVERFLOW]: `VaultOverflow`,
-    [JITO_VAULT_ERROR__VAULT_REWARD_FEE_DELTA_TOO_LARGE]: `VaultRewardFeeDeltaTooLarge`,
-    [JITO_VAULT_ERROR__VAULT_REWARD_FEE_IS_ZERO]: `VaultRewardFeeIsZero`,
-    [JITO_VAULT_ERROR__VAULT_SECURITY_OVERFLOW]: `VaultSecurityOverflow`,
-    [JITO_VAULT_ERROR__VAULT_SECURITY_UNDERFLOW]: `VaultSecurityUnderflow`,
-    [JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID]: `VaultSlasherAdminInvalid`,
-    [JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE]: `VaultSlashIncomplete`,
-    [JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW]: `VaultSlashUnderflow`,
-    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER]: `VaultStakerWithdrawalTicketInvalidStaker`,
-    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE]: `VaultStakerWithdrawalTicketNotWithdrawable`,
-    [JITO_VAULT_ERROR__VAULT_UNDERFLOW]: `VaultUnderflow`,
-    [JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX]: `VaultUpdateIncorrectIndex`,
-    [JITO_VAULT_ERROR__VAULT_UPDATE_NEEDED]: `VaultUpdateNeeded`,
-    [JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING]: `VaultUpdateStateNotFinishedUpdating`,
-    [JITO_VAULT_ERROR__VRT_OUT_CANNOT_BE_ZERO]: `VrtOutCannotBeZero`,
+    [JITO_VAULT_ERROR__CONFIG_ADMIN_INVALID]: `Signer is not the config admin`,
+    [JITO_VAULT_ERROR__CONFIG_FEE_ADMIN_INVALID]: `Signer is not the config's fee admin`,
+    [JITO_VAULT_ERROR__DIVISION_BY_ZERO]: `Division by zero`,
+    [JITO_VAULT_ERROR__INVALID_ACCOUNT_DATA]: `account data is invalid`,
+    [JITO_VAULT_ERROR__INVALID_ACCOUNT_OWNER]: `wrong owner`,
+    [JITO_VAULT_ERROR__INVALID_ADDRESS]: `not at the expected address`,
+    [JITO_VAULT_ERROR__INVALID_DEPOSITOR]: `Depositor cannot be the vault`,
+    [JITO_VAULT_ERROR__INVALID_DEPOSIT_TOKEN_ACCOUNT]: `Depositor token account cannot be the vault token account`,
+    [JITO_VAULT_ERROR__INVALID_DISCRIMINATOR]: `wrong discriminator`,
+    [JITO_VAULT_ERROR__INVALID_EPOCH_LENGTH]: `Epoch length is invalid`,
+    [JITO_VAULT_ERROR__INVALID_MINT]: `wrong mint`,
+    [JITO_VAULT_ERROR__INVALID_PROGRAM_ID]: `wrong program`,
+    [JITO_VAULT_ERROR__INVALID_TOKEN_OWNER]: `wrong token account owner`,
+    [JITO_VAULT_ERROR__NCN_OPERATOR_STATE_UNSLASHABLE]: `NCN operator state is not active, so the operator cannot be slashed`,
+    [JITO_VAULT_ERROR__NCN_OVERFLOW]: `Vault NCN count overflowed`,
+    [JITO_VAULT_ERROR__NCN_VAULT_SLASHER_TICKET_UNSLASHABLE]: `NCN vault slasher ticket is not active, so the slasher cannot slash`,
+    [JITO_VAULT_ERROR__NCN_VAULT_TICKET_UNSLASHABLE]: `NCN vault ticket is not active, so the NCN cannot slash`,
+    [JITO_VAULT_ERROR__NON_ZERO_ADDITIONAL_ASSETS_NEEDED_FOR_WITHDRAWAL_AT_END_OF_UPDATE]: `Not enough assets were cooled down to cover pending withdrawals`,
+    [JITO_VAULT_ERROR__NO_SUPPORTED_MINT_BALANCE_CHANGE]: `Supported mint balance did not change`,
+    [JITO_VAULT_ERROR__OPERATOR_OVERFLOW]: `Vault operator count overflowed`,
+    [JITO_VAULT_ERROR__OPERATOR_VAULT_TICKET_UNSLASHABLE]: `Operator vault ticket is not active, so the operator cannot be slashed`,
+    [JITO_VAULT_ERROR__SLASHER_OVERFLOW]: `Vault slasher count overflowed`,
+    [JITO_VAULT_ERROR__SLIPPAGE_ERROR]: `Amount out is less than the minimum amount out`,
+    [JITO_VAULT_ERROR__VAULT_ADMIN_INVALID]: `Signer is not the vault admin`,
+    [JITO_VAULT_ERROR__VAULT_BURN_ZERO]: `Burn amount must be non-zero`,
+    [JITO_VAULT_ERROR__VAULT_CAPACITY_ADMIN_INVALID]: `Signer is not the vault's capacity admin`,
+    [JITO_VAULT_ERROR__VAULT_CAPACITY_EXCEEDED]: `Deposit would exceed the vault's deposit capacity`,
+    [JITO_VAULT_ERROR__VAULT_COOLDOWN_ZERO]: `Cooldown amount must be non-zero`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATE_ASSET_ADMIN_INVALID]: `Signer is not the vault's delegate asset admin`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_ADMIN_INVALID]: `Signer is not the vault's delegation admin`,
+    [JITO_VAULT_ERROR__VAULT_DELEGATION_ZERO]: `Delegation amount must be non-zero`,
+    [JITO_VAULT_ERROR__VAULT_ENQUEUE_WITHDRAWAL_AMOUNT_ZERO]: `Withdrawal amount must be non-zero`,
+    [JITO_VAULT_ERROR__VAULT_FEE_ADMIN_INVALID]: `Signer is not the vault's fee admin`,
+    [JITO_VAULT_ERROR__VAULT_FEE_BUMP_TOO_LARGE]: `Fee increase exceeds the maximum allowed per change`,
+    [JITO_VAULT_ERROR__VAULT_FEE_CAP_EXCEEDED]: `Fee exceeds the maximum allowed fee`,
+    [JITO_VAULT_ERROR__VAULT_FEE_CHANGE_TOO_SOON]: `Fees can only be changed once per epoch`,
+    [JITO_VAULT_ERROR__VAULT_INITIAL_AMOUNT_FAILED]: `Initial deposit must be non-zero and mint VRT one to one`,
+    [JITO_VAULT_ERROR__VAULT_INSUFFICIENT_FUNDS]: `Vault does not hold enough assets for this amount`,
+    [JITO_VAULT_ERROR__VAULT_IS_PAUSED]: `Vault is paused`,
+    [JITO_VAULT_ERROR__VAULT_IS_UPDATED]: `Vault is already updated for the current epoch`,
+    [JITO_VAULT_ERROR__VAULT_MAX_SLASHED_PER_OPERATOR_EXCEEDED]: `Slash exceeds the maximum slashable per operator this epoch`,
+    [JITO_VAULT_ERROR__VAULT_MINT_BURN_ADMIN_INVALID]: `Signer is not the vault's mint burn admin`,
+    [JITO_VAULT_ERROR__VAULT_MINT_ZERO]: `Mint amount must be non-zero`,
+    [JITO_VAULT_ERROR__VAULT_NCN_ADMIN_INVALID]: `Signer is not the vault's NCN admin`,
+    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_COOLDOWN]: `Vault NCN slasher ticket cannot be cooled down in its current state`,
+    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_FAILED_WARMUP]: `Vault NCN slasher ticket cannot be warmed up in its current state`,
+    [JITO_VAULT_ERROR__VAULT_NCN_SLASHER_TICKET_UNSLASHABLE]: `Vault NCN slasher ticket is not active, so the slasher cannot slash`,
+    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_COOLDOWN]: `Vault NCN ticket cannot be cooled down in its current state`,
+    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_FAILED_WARMUP]: `Vault NCN ticket cannot be warmed up in its current state`,
+    [JITO_VAULT_ERROR__VAULT_NCN_TICKET_UNSLASHABLE]: `Vault NCN ticket is not active, so the NCN cannot slash`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_ADMIN_INVALID]: `Signer is not the vault's operator admin`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_IS_UPDATED]: `Vault operator delegation is already updated for the current epoch`,
+    [JITO_VAULT_ERROR__VAULT_OPERATOR_DELEGATION_UPDATE_NEEDED]: `Vault operator delegation must be updated for the current epoch first`,
+    [JITO_VAULT_ERROR__VAULT_OVERFLOW]: `Vault calculation overflowed`,
+    [JITO_VAULT_ERROR__VAULT_REWARD_FEE_DELTA_TOO_LARGE]: `Reward fee taken differs too much from the configured reward fee`,
+    [JITO_VAULT_ERROR__VAULT_REWARD_FEE_IS_ZERO]: `Reward fee rounded down to zero VRT`,
+    [JITO_VAULT_ERROR__VAULT_SECURITY_OVERFLOW]: `Delegated security overflowed`,
+    [JITO_VAULT_ERROR__VAULT_SECURITY_UNDERFLOW]: `Delegated security underflowed`,
+    [JITO_VAULT_ERROR__VAULT_SLASHER_ADMIN_INVALID]: `Signer is not the vault's slasher admin`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_INCOMPLETE]: `Slash could not be applied in full`,
+    [JITO_VAULT_ERROR__VAULT_SLASH_UNDERFLOW]: `Slash amount exceeds the security delegated to the operator`,
+    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_INVALID_STAKER]: `Signer is not the staker of the withdrawal ticket`,
+    [JITO_VAULT_ERROR__VAULT_STAKER_WITHDRAWAL_TICKET_NOT_WITHDRAWABLE]: `Withdrawal ticket is still cooling down`,
+    [JITO_VAULT_ERROR__VAULT_UNDERFLOW]: `Vault calculation underflowed`,
+    [JITO_VAULT_ERROR__VAULT_UPDATE_INCORRECT_INDEX]: `Operators must be updated in index order`,
+    [JITO_VAULT_ERROR__VAULT_UPDATE_NEEDED]: `Vault must be updated for the current epoch first`,
+    [JITO_VAULT_ERROR__VAULT_UPDATE_STATE_NOT_FINISHED_UPDATING]: `Not all operators have been updated yet`,
+    [JITO_VAULT_ERROR__VRT_OUT_CANNOT_BE_ZERO]: `Deposit is too small to mint any VRT`,
   };
 }
 
 export function getJitoVaultErrorMessage(code: JitoVaultError): string {
   if (process.env.NODE_ENV !== 'production') {
-    return (jitoVaultErrorMessages as Record<JitoVaultError, string>)[code];
+    return (jitoVaultErrorMessages as Record<JitoVaultError, string>)[
+      code
+    ];
   }
 
   return 'Error message not available in production bundles.';
 }
 
-export function isJitoVaultError<TProgramErrorCode extends JitoVaultError>(
+export function isJitoVaultError<
+  TProgramErrorCode extends JitoVaultError,
+>(
   error: unknown,
   transactionMessage: {
     instructions: Record<number, { programAddress: Address }>;
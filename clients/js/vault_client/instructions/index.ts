@@ -25,6 +25,7 @@ export * from './initializeVaultNcnTicket';
 export * from './initializeVaultOperatorDelegation';
 export * from './initializeVaultUpdateStateTracker';
 export * from './initializeVaultWithMint';
+export * from './migrateAccount';
 export * from './mintTo';
 export * from './revokeDelegateTokenAccount';
 export * from './setAdmin';
@@ -18,11 +18,14 @@ import {
   type Decoder,
   type Encoder,
   type IAccountMeta,
+  type IAccountSignerMeta,
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/web3.js';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -67,7 +70,8 @@ export type InitializeVaultUpdateStateTrackerInstruction<
         ? WritableAccount<TAccountVaultUpdateStateTracker>
         : TAccountVaultUpdateStateTracker,
       TAccountPayer extends string
-        ? WritableAccount<TAccountPayer>
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
         : TAccountPayer,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
@@ -125,7 +129,7 @@ export type InitializeVaultUpdateStateTrackerInput<
   config: Address<TAccountConfig>;
   vault: Address<TAccountVault>;
   vaultUpdateStateTracker: Address<TAccountVaultUpdateStateTracker>;
-  payer: Address<TAccountPayer>;
+  payer: TransactionSigner<TAccountPayer>;
   systemProgram?: Address<TAccountSystemProgram>;
   withdrawalAllocationMethod: InitializeVaultUpdateStateTrackerInstructionDataArgs['withdrawalAllocationMethod'];
 };
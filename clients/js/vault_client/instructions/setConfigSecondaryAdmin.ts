@@ -25,6 +25,7 @@ import {
   type ReadonlyAccount,
   type ReadonlySignerAccount,
   type TransactionSigner,
+  type WritableAccount,
 } from '@solana/web3.js';
 import { JITO_VAULT_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -52,7 +53,7 @@ export type SetConfigSecondaryAdminInstruction<
   IInstructionWithAccounts<
     [
       TAccountConfig extends string
-        ? ReadonlyAccount<TAccountConfig>
+        ? WritableAccount<TAccountConfig>
         : TAccountConfig,
       TAccountAdmin extends string
         ? ReadonlySignerAccount<TAccountAdmin> &
@@ -138,7 +139,7 @@ export function getSetConfigSecondaryAdminInstruction<
 
   // Original accounts.
   const originalAccounts = {
-    config: { value: input.config ?? null, isWritable: false },
+    config: { value: input.config ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
     newAdmin: { value: input.newAdmin ?? null, isWritable: false },
   };
@@ -32,6 +32,7 @@ import {
   type ParsedInitializeVaultOperatorDelegationInstruction,
   type ParsedInitializeVaultUpdateStateTrackerInstruction,
   type ParsedInitializeVaultWithMintInstruction,
+  type ParsedMigrateAccountInstruction,
   type ParsedMintToInstruction,
   type ParsedRevokeDelegateTokenAccountInstruction,
   type ParsedSetAdminInstruction,
@@ -98,6 +99,7 @@ export enum JitoVaultInstruction {
   UpdateTokenMetadata,
   SetConfigAdmin,
   SetConfigSecondaryAdmin,
+  MigrateAccount,
 }
 
 export function identifyJitoVaultInstruction(
@@ -206,6 +208,9 @@ export function identifyJitoVaultInstruction(
   if (containsBytes(data, getU8Encoder().encode(33), 0)) {
     return JitoVaultInstruction.SetConfigSecondaryAdmin;
   }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return JitoVaultInstruction.MigrateAccount;
+  }
   throw new Error(
     'The provided instruction could not be identified as a jitoVault instruction.'
   );
@@ -315,4 +320,7 @@ export type ParsedJitoVaultInstruction<
     } & ParsedSetConfigAdminInstruction<TProgram>)
   | ({
       instructionType: JitoVaultInstruction.SetConfigSecondaryAdmin;
-    } & ParsedSetConfigSecondaryAdminInstruction<TProgram>);
+    } & ParsedSetConfigSecondaryAdminInstruction<TProgram>)
+  | ({
+      instructionType: JitoVaultInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>);
@@ -11,6 +11,7 @@ export * from './createMetadataAccountArgsV3';
 export * from './dataV2';
 export * from './delegationState';
 export * from './slotToggle';
+export * from './transitionEpochs';
 export * from './updateMetadataAccountArgsV2';
 export * from './vaultAdminRole';
 export * from './withdrawalAllocationMethod';
@@ -8,37 +8,45 @@
 
 import {
   combineCodec,
-  fixDecoderSize,
-  fixEncoderSize,
-  getBytesDecoder,
-  getBytesEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   type Codec,
   type Decoder,
   type Encoder,
-  type ReadonlyUint8Array,
 } from '@solana/web3.js';
+import {
+  getTransitionEpochsDecoder,
+  getTransitionEpochsEncoder,
+  type TransitionEpochs,
+  type TransitionEpochsArgs,
+} from '.';
 
 export type SlotToggle = {
   slotAdded: bigint;
   slotRemoved: bigint;
-  reserved: ReadonlyUint8Array;
+  transitionEpochs: TransitionEpochs;
+  reserved: Array<number>;
 };
 
 export type SlotToggleArgs = {
   slotAdded: number | bigint;
   slotRemoved: number | bigint;
-  reserved: ReadonlyUint8Array;
+  transitionEpochs: TransitionEpochsArgs;
+  reserved: Array<number>;
 };
 
 export function getSlotToggleEncoder(): Encoder<SlotToggleArgs> {
   return getStructEncoder([
     ['slotAdded', getU64Encoder()],
     ['slotRemoved', getU64Encoder()],
-    ['reserved', fixEncoderSize(getBytesEncoder(), 32)],
+    ['transitionEpochs', getTransitionEpochsEncoder()],
+    ['reserved', getArrayEncoder(getU8Encoder(), { size: 27 })],
   ]);
 }
 
@@ -46,7 +54,8 @@ export function getSlotToggleDecoder(): Decoder<SlotToggle> {
   return getStructDecoder([
     ['slotAdded', getU64Decoder()],
     ['slotRemoved', getU64Decoder()],
-    ['reserved', fixDecoderSize(getBytesDecoder(), 32)],
+    ['transitionEpochs', getTransitionEpochsDecoder()],
+    ['reserved', getArrayDecoder(getU8Decoder(), { size: 27 })],
   ]);
 }
 
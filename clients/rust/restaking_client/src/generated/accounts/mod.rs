@@ -7,16 +7,24 @@
 
 pub(crate) mod r#config;
 pub(crate) mod r#ncn;
+pub(crate) mod r#ncn_metadata;
 pub(crate) mod r#ncn_operator_state;
+pub(crate) mod r#ncn_policy;
 pub(crate) mod r#ncn_vault_slasher_ticket;
 pub(crate) mod r#ncn_vault_ticket;
 pub(crate) mod r#operator;
+pub(crate) mod r#operator_bond;
+pub(crate) mod r#operator_metadata;
 pub(crate) mod r#operator_vault_ticket;
 
 pub use self::r#config::*;
 pub use self::r#ncn::*;
+pub use self::r#ncn_metadata::*;
 pub use self::r#ncn_operator_state::*;
+pub use self::r#ncn_policy::*;
 pub use self::r#ncn_vault_slasher_ticket::*;
 pub use self::r#ncn_vault_ticket::*;
 pub use self::r#operator::*;
+pub use self::r#operator_bond::*;
+pub use self::r#operator_metadata::*;
 pub use self::r#operator_vault_ticket::*;
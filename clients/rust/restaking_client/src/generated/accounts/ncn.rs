@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::TransitionEpochs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -63,8 +64,16 @@ pub struct Ncn {
     pub vault_count: u64,
     pub slasher_count: u64,
     pub bump: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_bond_mint: Pubkey,
+    pub min_operator_bond: u64,
+    pub has_policy: bool,
+    pub transition_epochs: TransitionEpochs,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 217],
 }
 
 impl Ncn {
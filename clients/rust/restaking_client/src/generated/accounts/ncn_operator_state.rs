@@ -28,8 +28,10 @@ pub struct NcnOperatorState {
     pub ncn_opt_in_state: SlotToggle,
     pub operator_opt_in_state: SlotToggle,
     pub bump: u8,
+    pub policy_admitted: bool,
+    pub jailed_until_epoch: u64,
     #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
-    pub reserved: [u8; 263],
+    pub reserved: [u8; 254],
 }
 
 impl NcnOperatorState {
@@ -10,96 +10,195 @@ use thiserror::Error;
 
 #[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum JitoRestakingError {
-    /// 0 - Bad epoch length
+    /// 5000 - Signer is not the NCN's operator admin
+    #[error("Signer is not the NCN's operator admin")]
+    NcnOperatorAdminInvalid = 0x1388,
+    /// 5001 - NCN operator state cannot be cooled down by the NCN in its current state
+    #[error("NCN operator state cannot be cooled down by the NCN in its current state")]
+    NcnCooldownOperatorFailed = 0x1389,
+    /// 5002 - Signer is not the NCN's slasher admin
+    #[error("Signer is not the NCN's slasher admin")]
+    NcnSlasherAdminInvalid = 0x138A,
+    /// 5003 - Signer is not the NCN's vault admin
+    #[error("Signer is not the NCN's vault admin")]
+    NcnVaultAdminInvalid = 0x138B,
+    /// 5004 - Signer is not the NCN admin
+    #[error("Signer is not the NCN admin")]
+    NcnAdminInvalid = 0x138C,
+    /// 5005 - Signer is not the NCN's delegate admin
+    #[error("Signer is not the NCN's delegate admin")]
+    NcnDelegateAdminInvalid = 0x138D,
+    /// 5006 - NCN vault slasher ticket cannot be cooled down in its current state
+    #[error("NCN vault slasher ticket cannot be cooled down in its current state")]
+    NcnVaultSlasherTicketFailedCooldown = 0x138E,
+    /// 5007 - NCN vault ticket cannot be cooled down in its current state
+    #[error("NCN vault ticket cannot be cooled down in its current state")]
+    NcnVaultTicketFailedCooldown = 0x138F,
+    /// 5008 - NCN operator state cannot be warmed up by the NCN in its current state
+    #[error("NCN operator state cannot be warmed up by the NCN in its current state")]
+    NcnWarmupOperatorFailed = 0x1390,
+    /// 5009 - NCN vault slasher ticket cannot be warmed up in its current state
+    #[error("NCN vault slasher ticket cannot be warmed up in its current state")]
+    NcnVaultSlasherTicketFailedWarmup = 0x1391,
+    /// 5010 - NCN vault ticket cannot be warmed up in its current state
+    #[error("NCN vault ticket cannot be warmed up in its current state")]
+    NcnVaultTicketFailedWarmup = 0x1392,
+    /// 5011 - Signer is not the NCN's metadata admin
+    #[error("Signer is not the NCN's metadata admin")]
+    NcnMetadataAdminInvalid = 0x1393,
+    /// 5012 - Operator bond is below the NCN's minimum bond
+    #[error("Operator bond is below the NCN's minimum bond")]
+    NcnOperatorBondInsufficient = 0x1394,
+    /// 5013 - NCN has no operator policy
+    #[error("NCN has no operator policy")]
+    NcnPolicyMissing = 0x1395,
+    /// 5014 - NCN policy allows too many vault mints
+    #[error("NCN policy allows too many vault mints")]
+    NcnPolicyTooManyVaultMints = 0x1396,
+    /// 5015 - Vault mint is not allowed by the NCN policy
+    #[error("Vault mint is not allowed by the NCN policy")]
+    NcnPolicyVaultMintNotAllowed = 0x1397,
+    /// 5016 - Operator stake is below the NCN policy minimum
+    #[error("Operator stake is below the NCN policy minimum")]
+    NcnPolicyOperatorStakeInsufficient = 0x1398,
+    /// 5017 - Operator ticket is too new for the NCN policy
+    #[error("Operator ticket is too new for the NCN policy")]
+    NcnPolicyOperatorTicketTooNew = 0x1399,
+    /// 5018 - NCN policy maximum number of operators reached
+    #[error("NCN policy maximum number of operators reached")]
+    NcnPolicyMaxOperatorsReached = 0x139A,
+    /// 5019 - Signer is not the NCN's jail admin
+    #[error("Signer is not the NCN's jail admin")]
+    NcnJailAdminInvalid = 0x139B,
+    /// 5020 - Jail end epoch must be after the current epoch
+    #[error("Jail end epoch must be after the current epoch")]
+    NcnOperatorJailEpochInvalid = 0x139C,
+    /// 5021 - Operator is not jailed by the NCN
+    #[error("Operator is not jailed by the NCN")]
+    NcnOperatorNotJailed = 0x139D,
+    /// 5022 - Warmup and cooldown epochs are invalid
+    #[error("Warmup and cooldown epochs are invalid")]
+    NcnTransitionEpochsInvalid = 0x139E,
+    /// 5023 - Operator is jailed by the NCN
+    #[error("Operator is jailed by the NCN")]
+    NcnOperatorJailed = 0x139F,
+    /// 5024 - NCN operator state is not active
+    #[error("NCN operator state is not active")]
+    NcnOperatorStateInactive = 0x13A0,
+    /// 5025 - NCN vault ticket is not active
+    #[error("NCN vault ticket is not active")]
+    NcnVaultTicketInactive = 0x13A1,
+    /// 5026 - Vault is not updated for the current epoch
+    #[error("Vault is not updated for the current epoch")]
+    NcnPolicyVaultUpdateNeeded = 0x13A2,
+    /// 6000 - Signer is not the operator's NCN admin
+    #[error("Signer is not the operator's NCN admin")]
+    OperatorNcnAdminInvalid = 0x1770,
+    /// 6001 - Signer is not the operator's vault admin
+    #[error("Signer is not the operator's vault admin")]
+    OperatorVaultAdminInvalid = 0x1771,
+    /// 6002 - Signer is not the operator admin
+    #[error("Signer is not the operator admin")]
+    OperatorAdminInvalid = 0x1772,
+    /// 6003 - Signer is not the operator's delegate admin
+    #[error("Signer is not the operator's delegate admin")]
+    OperatorDelegateAdminInvalid = 0x1773,
+    /// 6004 - NCN operator state cannot be cooled down by the operator in its current state
+    #[error("NCN operator state cannot be cooled down by the operator in its current state")]
+    OperatorCooldownNcnFailed = 0x1774,
+    /// 6005 - Operator vault ticket cannot be cooled down in its current state
+    #[error("Operator vault ticket cannot be cooled down in its current state")]
+    OperatorVaultTicketFailedCooldown = 0x1775,
+    /// 6006 - Operator vault ticket cannot be warmed up in its current state
+    #[error("Operator vault ticket cannot be warmed up in its current state")]
+    OperatorVaultTicketFailedWarmup = 0x1776,
+    /// 6007 - NCN operator state cannot be warmed up by the operator in its current state
+    #[error("NCN operator state cannot be warmed up by the operator in its current state")]
+    OperatorWarmupNcnFailed = 0x1777,
+    /// 6008 - Operator fee exceeds the maximum allowed fee
+    #[error("Operator fee exceeds the maximum allowed fee")]
+    OperatorFeeCapExceeded = 0x1778,
+    /// 6009 - NCN count overflowed
+    #[error("NCN count overflowed")]
+    NcnOverflow = 0x1779,
+    /// 6010 - Operator count overflowed
+    #[error("Operator count overflowed")]
+    OperatorOverflow = 0x177A,
+    /// 6011 - Vault count overflowed
+    #[error("Vault count overflowed")]
+    VaultOverflow = 0x177B,
+    /// 6012 - Slasher count overflowed
+    #[error("Slasher count overflowed")]
+    SlasherOverflow = 0x177C,
+    /// 6013 - Epoch length is invalid
+    #[error("Epoch length is invalid")]
+    InvalidEpochLength = 0x177D,
+    /// 6014 - Signer is not the config admin
+    #[error("Signer is not the config admin")]
+    ConfigAdminInvalid = 0x177E,
+    /// 6015 - Signer is not the operator's metadata admin
+    #[error("Signer is not the operator's metadata admin")]
+    OperatorMetadataAdminInvalid = 0x177F,
+    /// 6016 - Metadata field is too long
+    #[error("Metadata field is too long")]
+    MetadataFieldTooLong = 0x1780,
+    /// 6017 - Bond amount must be non-zero
+    #[error("Bond amount must be non-zero")]
+    OperatorBondAmountZero = 0x1781,
+    /// 6018 - Amount exceeds the bonded amount
+    #[error("Amount exceeds the bonded amount")]
+    OperatorBondAmountExceeded = 0x1782,
+    /// 6019 - Unbonded tokens are still cooling down
+    #[error("Unbonded tokens are still cooling down")]
+    OperatorBondCooldownActive = 0x1783,
+    /// 6020 - Metadata field is not zero-padded UTF-8 or not a valid flag
+    #[error("Metadata field is not zero-padded UTF-8 or not a valid flag")]
+    MetadataFieldInvalid = 0x1784,
+    /// 7000 - Arithmetic overflow
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow = 0x1B58,
+    /// 7001 - Arithmetic underflow
+    #[error("Arithmetic underflow")]
+    ArithmeticUnderflow = 0x1B59,
+    /// 7002 - Division by zero
+    #[error("Division by zero")]
+    DivisionByZero = 0x1B5A,
+    /// 4000 - Bad epoch length
     #[error("Bad epoch length")]
-    BadEpochLength = 0x0,
-    /// 1000 - NcnOperatorAdminInvalid
-    #[error("NcnOperatorAdminInvalid")]
-    NcnOperatorAdminInvalid = 0x3E8,
-    /// 1001 - NcnCooldownOperatorFailed
-    #[error("NcnCooldownOperatorFailed")]
-    NcnCooldownOperatorFailed = 0x3E9,
-    /// 1002 - NcnSlasherAdminInvalid
-    #[error("NcnSlasherAdminInvalid")]
-    NcnSlasherAdminInvalid = 0x3EA,
-    /// 1003 - NcnVaultAdminInvalid
-    #[error("NcnVaultAdminInvalid")]
-    NcnVaultAdminInvalid = 0x3EB,
-    /// 1004 - NcnAdminInvalid
-    #[error("NcnAdminInvalid")]
-    NcnAdminInvalid = 0x3EC,
-    /// 1005 - NcnDelegateAdminInvalid
-    #[error("NcnDelegateAdminInvalid")]
-    NcnDelegateAdminInvalid = 0x3ED,
-    /// 1006 - NcnVaultSlasherTicketFailedCooldown
-    #[error("NcnVaultSlasherTicketFailedCooldown")]
-    NcnVaultSlasherTicketFailedCooldown = 0x3EE,
-    /// 1007 - NcnVaultTicketFailedCooldown
-    #[error("NcnVaultTicketFailedCooldown")]
-    NcnVaultTicketFailedCooldown = 0x3EF,
-    /// 1008 - NcnWarmupOperatorFailed
-    #[error("NcnWarmupOperatorFailed")]
-    NcnWarmupOperatorFailed = 0x3F0,
-    /// 1009 - NcnVaultSlasherTicketFailedWarmup
-    #[error("NcnVaultSlasherTicketFailedWarmup")]
-    NcnVaultSlasherTicketFailedWarmup = 0x3F1,
-    /// 1010 - NcnVaultTicketFailedWarmup
-    #[error("NcnVaultTicketFailedWarmup")]
-    NcnVaultTicketFailedWarmup = 0x3F2,
-    /// 2000 - OperatorNcnAdminInvalid
-    #[error("OperatorNcnAdminInvalid")]
-    OperatorNcnAdminInvalid = 0x7D0,
-    /// 2001 - OperatorVaultAdminInvalid
-    #[error("OperatorVaultAdminInvalid")]
-    OperatorVaultAdminInvalid = 0x7D1,
-    /// 2002 - OperatorAdminInvalid
-    #[error("OperatorAdminInvalid")]
-    OperatorAdminInvalid = 0x7D2,
-    /// 2003 - OperatorDelegateAdminInvalid
-    #[error("OperatorDelegateAdminInvalid")]
-    OperatorDelegateAdminInvalid = 0x7D3,
-    /// 2004 - OperatorCooldownNcnFailed
-    #[error("OperatorCooldownNcnFailed")]
-    OperatorCooldownNcnFailed = 0x7D4,
-    /// 2005 - OperatorVaultTicketFailedCooldown
-    #[error("OperatorVaultTicketFailedCooldown")]
-    OperatorVaultTicketFailedCooldown = 0x7D5,
-    /// 2006 - OperatorVaultTicketFailedWarmup
-    #[error("OperatorVaultTicketFailedWarmup")]
-    OperatorVaultTicketFailedWarmup = 0x7D6,
-    /// 2007 - OperatorWarmupNcnFailed
-    #[error("OperatorWarmupNcnFailed")]
-    OperatorWarmupNcnFailed = 0x7D7,
-    /// 2008 - OperatorFeeCapExceeded
-    #[error("OperatorFeeCapExceeded")]
-    OperatorFeeCapExceeded = 0x7D8,
-    /// 2009 - NcnOverflow
-    #[error("NcnOverflow")]
-    NcnOverflow = 0x7D9,
-    /// 2010 - OperatorOverflow
-    #[error("OperatorOverflow")]
-    OperatorOverflow = 0x7DA,
-    /// 2011 - VaultOverflow
-    #[error("VaultOverflow")]
-    VaultOverflow = 0x7DB,
-    /// 2012 - SlasherOverflow
-    #[error("SlasherOverflow")]
-    SlasherOverflow = 0x7DC,
-    /// 2013 - InvalidEpochLength
-    #[error("InvalidEpochLength")]
-    InvalidEpochLength = 0x7DD,
-    /// 2014 - ConfigAdminInvalid
-    #[error("ConfigAdminInvalid")]
-    ConfigAdminInvalid = 0x7DE,
-    /// 3000 - ArithmeticOverflow
-    #[error("ArithmeticOverflow")]
-    ArithmeticOverflow = 0xBB8,
-    /// 3001 - ArithmeticUnderflow
-    #[error("ArithmeticUnderflow")]
-    ArithmeticUnderflow = 0xBB9,
-    /// 3002 - DivisionByZero
-    #[error("DivisionByZero")]
-    DivisionByZero = 0xBBA,
+    BadEpochLength = 0xFA0,
+    /// 4100 - not a signer
+    #[error("not a signer")]
+    AccountNotSigner = 0x1004,
+    /// 4101 - not writable
+    #[error("not writable")]
+    AccountNotWritable = 0x1005,
+    /// 4102 - wrong owner
+    #[error("wrong owner")]
+    InvalidAccountOwner = 0x1006,
+    /// 4103 - account data is empty
+    #[error("account data is empty")]
+    AccountDataEmpty = 0x1007,
+    /// 4104 - account is already initialized
+    #[error("account is already initialized")]
+    AccountAlreadyInitialized = 0x1008,
+    /// 4105 - account data is invalid
+    #[error("account data is invalid")]
+    InvalidAccountData = 0x1009,
+    /// 4106 - wrong discriminator
+    #[error("wrong discriminator")]
+    InvalidDiscriminator = 0x100A,
+    /// 4107 - not at the expected address
+    #[error("not at the expected address")]
+    InvalidAddress = 0x100B,
+    /// 4108 - wrong program
+    #[error("wrong program")]
+    InvalidProgramId = 0x100C,
+    /// 4109 - wrong token account owner
+    #[error("wrong token account owner")]
+    InvalidTokenOwner = 0x100D,
+    /// 4110 - wrong mint
+    #[error("wrong mint")]
+    InvalidMint = 0x100E,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoRestakingError {
@@ -10,23 +10,39 @@ pub(crate) mod r#cooldown_ncn_vault_ticket;
 pub(crate) mod r#cooldown_operator_vault_ticket;
 pub(crate) mod r#initialize_config;
 pub(crate) mod r#initialize_ncn;
+pub(crate) mod r#initialize_ncn_metadata;
 pub(crate) mod r#initialize_ncn_operator_state;
+pub(crate) mod r#initialize_ncn_policy;
 pub(crate) mod r#initialize_ncn_vault_slasher_ticket;
 pub(crate) mod r#initialize_ncn_vault_ticket;
 pub(crate) mod r#initialize_operator;
+pub(crate) mod r#initialize_operator_bond;
+pub(crate) mod r#initialize_operator_metadata;
 pub(crate) mod r#initialize_operator_vault_ticket;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#ncn_cooldown_operator;
 pub(crate) mod r#ncn_delegate_token_account;
+pub(crate) mod r#ncn_jail_operator;
 pub(crate) mod r#ncn_set_admin;
+pub(crate) mod r#ncn_set_operator_bond_requirement;
+pub(crate) mod r#ncn_set_policy;
 pub(crate) mod r#ncn_set_secondary_admin;
+pub(crate) mod r#ncn_set_transition_epochs;
+pub(crate) mod r#ncn_unjail_operator;
 pub(crate) mod r#ncn_warmup_operator;
 pub(crate) mod r#operator_cooldown_ncn;
 pub(crate) mod r#operator_delegate_token_account;
+pub(crate) mod r#operator_deposit_bond;
 pub(crate) mod r#operator_set_admin;
 pub(crate) mod r#operator_set_fee;
 pub(crate) mod r#operator_set_secondary_admin;
+pub(crate) mod r#operator_unbond;
 pub(crate) mod r#operator_warmup_ncn;
+pub(crate) mod r#operator_withdraw_bond;
 pub(crate) mod r#set_config_admin;
+pub(crate) mod r#slash_operator_bond;
+pub(crate) mod r#update_ncn_metadata;
+pub(crate) mod r#update_operator_metadata;
 pub(crate) mod r#warmup_ncn_vault_slasher_ticket;
 pub(crate) mod r#warmup_ncn_vault_ticket;
 pub(crate) mod r#warmup_operator_vault_ticket;
@@ -36,23 +52,39 @@ pub use self::r#cooldown_ncn_vault_ticket::*;
 pub use self::r#cooldown_operator_vault_ticket::*;
 pub use self::r#initialize_config::*;
 pub use self::r#initialize_ncn::*;
+pub use self::r#initialize_ncn_metadata::*;
 pub use self::r#initialize_ncn_operator_state::*;
+pub use self::r#initialize_ncn_policy::*;
 pub use self::r#initialize_ncn_vault_slasher_ticket::*;
 pub use self::r#initialize_ncn_vault_ticket::*;
 pub use self::r#initialize_operator::*;
+pub use self::r#initialize_operator_bond::*;
+pub use self::r#initialize_operator_metadata::*;
 pub use self::r#initialize_operator_vault_ticket::*;
+pub use self::r#migrate_account::*;
 pub use self::r#ncn_cooldown_operator::*;
 pub use self::r#ncn_delegate_token_account::*;
+pub use self::r#ncn_jail_operator::*;
 pub use self::r#ncn_set_admin::*;
+pub use self::r#ncn_set_operator_bond_requirement::*;
+pub use self::r#ncn_set_policy::*;
 pub use self::r#ncn_set_secondary_admin::*;
+pub use self::r#ncn_set_transition_epochs::*;
+pub use self::r#ncn_unjail_operator::*;
 pub use self::r#ncn_warmup_operator::*;
 pub use self::r#operator_cooldown_ncn::*;
 pub use self::r#operator_delegate_token_account::*;
+pub use self::r#operator_deposit_bond::*;
 pub use self::r#operator_set_admin::*;
 pub use self::r#operator_set_fee::*;
 pub use self::r#operator_set_secondary_admin::*;
+pub use self::r#operator_unbond::*;
 pub use self::r#operator_warmup_ncn::*;
+pub use self::r#operator_withdraw_bond::*;
 pub use self::r#set_config_admin::*;
+pub use self::r#slash_operator_bond::*;
+pub use self::r#update_ncn_metadata::*;
+pub use self::r#update_operator_metadata::*;
 pub use self::r#warmup_ncn_vault_slasher_ticket::*;
 pub use self::r#warmup_ncn_vault_ticket::*;
 pub use self::r#warmup_operator_vault_ticket::*;
@@ -19,6 +19,8 @@ pub struct NcnCooldownOperator {
     pub ncn_operator_state: solana_program::pubkey::Pubkey,
 
     pub admin: solana_program::pubkey::Pubkey,
+    /// Required if the operator was admitted under the NCN policy
+    pub ncn_policy: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl NcnCooldownOperator {
@@ -30,7 +32,7 @@ impl NcnCooldownOperator {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -49,6 +51,16 @@ impl NcnCooldownOperator {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.admin, true,
         ));
+        if let Some(ncn_policy) = self.ncn_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                ncn_policy, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = NcnCooldownOperatorInstructionData::new()
             .try_to_vec()
@@ -88,6 +100,7 @@ impl Default for NcnCooldownOperatorInstructionData {
 ///   2. `[]` operator
 ///   3. `[writable]` ncn_operator_state
 ///   4. `[signer]` admin
+///   5. `[writable, optional]` ncn_policy
 #[derive(Clone, Debug, Default)]
 pub struct NcnCooldownOperatorBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -95,6 +108,7 @@ pub struct NcnCooldownOperatorBuilder {
     operator: Option<solana_program::pubkey::Pubkey>,
     ncn_operator_state: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
+    ncn_policy: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -130,6 +144,13 @@ impl NcnCooldownOperatorBuilder {
         self.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required if the operator was admitted under the NCN policy
+    #[inline(always)]
+    pub fn ncn_policy(&mut self, ncn_policy: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.ncn_policy = ncn_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -158,6 +179,7 @@ impl NcnCooldownOperatorBuilder {
                 .ncn_operator_state
                 .expect("ncn_operator_state is not set"),
             admin: self.admin.expect("admin is not set"),
+            ncn_policy: self.ncn_policy,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -175,6 +197,8 @@ pub struct NcnCooldownOperatorCpiAccounts<'a, 'b> {
     pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required if the operator was admitted under the NCN policy
+    pub ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `ncn_cooldown_operator` CPI instruction.
@@ -191,6 +215,8 @@ pub struct NcnCooldownOperatorCpi<'a, 'b> {
     pub ncn_operator_state: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required if the operator was admitted under the NCN policy
+    pub ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
@@ -205,6 +231,7 @@ impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
             operator: accounts.operator,
             ncn_operator_state: accounts.ncn_operator_state,
             admin: accounts.admin,
+            ncn_policy: accounts.ncn_policy,
         }
     }
     #[inline(always)]
@@ -240,7 +267,7 @@ impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -261,6 +288,17 @@ impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
             *self.admin.key,
             true,
         ));
+        if let Some(ncn_policy) = self.ncn_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *ncn_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -277,13 +315,16 @@ impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.operator.clone());
         account_infos.push(self.ncn_operator_state.clone());
         account_infos.push(self.admin.clone());
+        if let Some(ncn_policy) = self.ncn_policy {
+            account_infos.push(ncn_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +346,7 @@ impl<'a, 'b> NcnCooldownOperatorCpi<'a, 'b> {
 ///   2. `[]` operator
 ///   3. `[writable]` ncn_operator_state
 ///   4. `[signer]` admin
+///   5. `[writable, optional]` ncn_policy
 #[derive(Clone, Debug)]
 pub struct NcnCooldownOperatorCpiBuilder<'a, 'b> {
     instruction: Box<NcnCooldownOperatorCpiBuilderInstruction<'a, 'b>>,
@@ -319,6 +361,7 @@ impl<'a, 'b> NcnCooldownOperatorCpiBuilder<'a, 'b> {
             operator: None,
             ncn_operator_state: None,
             admin: None,
+            ncn_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -357,6 +400,16 @@ impl<'a, 'b> NcnCooldownOperatorCpiBuilder<'a, 'b> {
         self.instruction.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required if the operator was admitted under the NCN policy
+    #[inline(always)]
+    pub fn ncn_policy(
+        &mut self,
+        ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.ncn_policy = ncn_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -413,6 +466,8 @@ impl<'a, 'b> NcnCooldownOperatorCpiBuilder<'a, 'b> {
                 .expect("ncn_operator_state is not set"),
 
             admin: self.instruction.admin.expect("admin is not set"),
+
+            ncn_policy: self.instruction.ncn_policy,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -429,6 +484,7 @@ struct NcnCooldownOperatorCpiBuilderInstruction<'a, 'b> {
     operator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_operator_state: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -19,6 +19,8 @@ pub struct WarmupNcnVaultTicket {
     pub ncn_vault_ticket: solana_program::pubkey::Pubkey,
 
     pub admin: solana_program::pubkey::Pubkey,
+    /// Required if the NCN has a policy
+    pub ncn_policy: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl WarmupNcnVaultTicket {
@@ -30,7 +32,7 @@ impl WarmupNcnVaultTicket {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.config,
             false,
@@ -48,6 +50,16 @@ impl WarmupNcnVaultTicket {
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.admin, true,
         ));
+        if let Some(ncn_policy) = self.ncn_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                ncn_policy, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = WarmupNcnVaultTicketInstructionData::new()
             .try_to_vec()
@@ -87,6 +99,7 @@ impl Default for WarmupNcnVaultTicketInstructionData {
 ///   2. `[]` vault
 ///   3. `[writable]` ncn_vault_ticket
 ///   4. `[signer]` admin
+///   5. `[optional]` ncn_policy
 #[derive(Clone, Debug, Default)]
 pub struct WarmupNcnVaultTicketBuilder {
     config: Option<solana_program::pubkey::Pubkey>,
@@ -94,6 +107,7 @@ pub struct WarmupNcnVaultTicketBuilder {
     vault: Option<solana_program::pubkey::Pubkey>,
     ncn_vault_ticket: Option<solana_program::pubkey::Pubkey>,
     admin: Option<solana_program::pubkey::Pubkey>,
+    ncn_policy: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -129,6 +143,13 @@ impl WarmupNcnVaultTicketBuilder {
         self.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required if the NCN has a policy
+    #[inline(always)]
+    pub fn ncn_policy(&mut self, ncn_policy: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.ncn_policy = ncn_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -155,6 +176,7 @@ impl WarmupNcnVaultTicketBuilder {
             vault: self.vault.expect("vault is not set"),
             ncn_vault_ticket: self.ncn_vault_ticket.expect("ncn_vault_ticket is not set"),
             admin: self.admin.expect("admin is not set"),
+            ncn_policy: self.ncn_policy,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -172,6 +194,8 @@ pub struct WarmupNcnVaultTicketCpiAccounts<'a, 'b> {
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required if the NCN has a policy
+    pub ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `warmup_ncn_vault_ticket` CPI instruction.
@@ -188,6 +212,8 @@ pub struct WarmupNcnVaultTicketCpi<'a, 'b> {
     pub ncn_vault_ticket: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub admin: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Required if the NCN has a policy
+    pub ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
@@ -202,6 +228,7 @@ impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
             vault: accounts.vault,
             ncn_vault_ticket: accounts.ncn_vault_ticket,
             admin: accounts.admin,
+            ncn_policy: accounts.ncn_policy,
         }
     }
     #[inline(always)]
@@ -237,7 +264,7 @@ impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.config.key,
             false,
@@ -258,6 +285,17 @@ impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
             *self.admin.key,
             true,
         ));
+        if let Some(ncn_policy) = self.ncn_policy {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *ncn_policy.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::JITO_RESTAKING_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -274,13 +312,16 @@ impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.config.clone());
         account_infos.push(self.ncn.clone());
         account_infos.push(self.vault.clone());
         account_infos.push(self.ncn_vault_ticket.clone());
         account_infos.push(self.admin.clone());
+        if let Some(ncn_policy) = self.ncn_policy {
+            account_infos.push(ncn_policy.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -302,6 +343,7 @@ impl<'a, 'b> WarmupNcnVaultTicketCpi<'a, 'b> {
 ///   2. `[]` vault
 ///   3. `[writable]` ncn_vault_ticket
 ///   4. `[signer]` admin
+///   5. `[optional]` ncn_policy
 #[derive(Clone, Debug)]
 pub struct WarmupNcnVaultTicketCpiBuilder<'a, 'b> {
     instruction: Box<WarmupNcnVaultTicketCpiBuilderInstruction<'a, 'b>>,
@@ -316,6 +358,7 @@ impl<'a, 'b> WarmupNcnVaultTicketCpiBuilder<'a, 'b> {
             vault: None,
             ncn_vault_ticket: None,
             admin: None,
+            ncn_policy: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -351,6 +394,16 @@ impl<'a, 'b> WarmupNcnVaultTicketCpiBuilder<'a, 'b> {
         self.instruction.admin = Some(admin);
         self
     }
+    /// `[optional account]`
+    /// Required if the NCN has a policy
+    #[inline(always)]
+    pub fn ncn_policy(
+        &mut self,
+        ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.ncn_policy = ncn_policy;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -407,6 +460,8 @@ impl<'a, 'b> WarmupNcnVaultTicketCpiBuilder<'a, 'b> {
                 .expect("ncn_vault_ticket is not set"),
 
             admin: self.instruction.admin.expect("admin is not set"),
+
+            ncn_policy: self.instruction.ncn_policy,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -423,6 +478,7 @@ struct WarmupNcnVaultTicketCpiBuilderInstruction<'a, 'b> {
     vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     ncn_vault_ticket: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     admin: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ncn_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -5,10 +5,16 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+pub(crate) mod r#delegation_state;
+pub(crate) mod r#metadata;
 pub(crate) mod r#ncn_admin_role;
 pub(crate) mod r#operator_admin_role;
 pub(crate) mod r#slot_toggle;
+pub(crate) mod r#transition_epochs;
 
+pub use self::r#delegation_state::*;
+pub use self::r#metadata::*;
 pub use self::r#ncn_admin_role::*;
 pub use self::r#operator_admin_role::*;
 pub use self::r#slot_toggle::*;
+pub use self::r#transition_epochs::*;
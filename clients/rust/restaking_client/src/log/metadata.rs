@@ -0,0 +1,32 @@
+use jito_restaking_client_common::log::{field, section_header};
+
+use crate::types::Metadata;
+
+/// Decodes a zero-padded byte string
+fn trimmed(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+/// Renders the metadata section shared by the NCN and operator metadata accounts
+pub(crate) fn metadata_section(metadata: &Metadata) -> String {
+    let mut output = String::new();
+
+    output.push_str(&section_header("Metadata"));
+    output.push_str(&field("Name", trimmed(&metadata.name)));
+    output.push_str(&field("Website", trimmed(&metadata.website)));
+    output.push_str(&field("Logo URI", trimmed(&metadata.logo_uri)));
+    output.push_str(&field("Contact", trimmed(&metadata.contact)));
+    if metadata.has_description_hash {
+        let description_hash: String = metadata
+            .description_hash
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        output.push_str(&field("Description Hash", description_hash));
+    } else {
+        output.push_str(&field("Description Hash", "None"));
+    }
+
+    output
+}
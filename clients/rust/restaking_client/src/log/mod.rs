@@ -1,7 +1,12 @@
 pub(crate) mod config;
+pub(crate) mod metadata;
 pub(crate) mod ncn;
+pub(crate) mod ncn_metadata;
 pub(crate) mod ncn_operator_state;
+pub(crate) mod ncn_policy;
 pub(crate) mod ncn_vault_slasher_ticket;
 pub(crate) mod ncn_vault_ticket;
 pub(crate) mod operator;
+pub(crate) mod operator_bond;
+pub(crate) mod operator_metadata;
 pub(crate) mod operator_vault_ticket;
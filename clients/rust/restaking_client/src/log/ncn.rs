@@ -28,6 +28,24 @@ impl PrettyDisplay for Ncn {
         output.push_str(&field("Vault Count", self.vault_count));
         output.push_str(&field("Slasher Count", self.slasher_count));
 
+        output.push_str(&section_header("Operator Bond Requirement"));
+        output.push_str(&field("Operator Bond Mint", self.operator_bond_mint));
+        output.push_str(&field("Min Operator Bond", self.min_operator_bond));
+
+        output.push_str(&section_header("Policy"));
+        output.push_str(&field("Has Policy", self.has_policy));
+
+        output.push_str(&section_header("Transition Epochs"));
+        output.push_str(&field("Custom", self.transition_epochs.is_set));
+        output.push_str(&field(
+            "Warmup Epochs",
+            self.transition_epochs.warmup_epochs,
+        ));
+        output.push_str(&field(
+            "Cooldown Epochs",
+            self.transition_epochs.cooldown_epochs,
+        ));
+
         output
     }
 }
@@ -37,7 +55,7 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::accounts::Ncn;
+    use crate::{accounts::Ncn, types::TransitionEpochs};
 
     #[test]
     fn test_ncn_pretty_display_structure() {
@@ -57,7 +75,15 @@ mod tests {
             vault_count: 2,
             slasher_count: 3,
             bump: 254,
-            reserved: [0; 263],
+            operator_bond_mint: Pubkey::new_unique(),
+            min_operator_bond: 1_000,
+            has_policy: true,
+            transition_epochs: TransitionEpochs {
+                is_set: true,
+                warmup_epochs: 3,
+                cooldown_epochs: 5,
+            },
+            reserved: [0; 217],
         };
 
         let output = ncn.pretty_display();
@@ -76,5 +102,10 @@ mod tests {
         assert!(output.contains(&ncn.vault_count.to_string()));
         assert!(output.contains(&ncn.slasher_count.to_string()));
         assert!(output.contains(&ncn.bump.to_string()));
+        assert!(output.contains(&ncn.operator_bond_mint.to_string()));
+        assert!(output.contains(&ncn.min_operator_bond.to_string()));
+        assert!(output.contains("Has Policy"));
+        assert!(output.contains("Warmup Epochs"));
+        assert!(output.contains("Cooldown Epochs"));
     }
 }
@@ -0,0 +1,54 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::{accounts::NcnMetadata, log::metadata::metadata_section};
+
+impl PrettyDisplay for NcnMetadata {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Ncn Metadata Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("NCN", self.ncn));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&metadata_section(&self.metadata));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::{accounts::NcnMetadata, types::Metadata};
+
+    #[test]
+    fn test_ncn_metadata_pretty_display_structure() {
+        let mut name = [0; 64];
+        name[..4].copy_from_slice(b"Jito");
+        let ncn_metadata = NcnMetadata {
+            discriminator: 12345,
+            ncn: Pubkey::new_unique(),
+            metadata: Metadata {
+                name,
+                website: [0; 128],
+                logo_uri: [0; 128],
+                contact: [0; 64],
+                description_hash: [0xab; 32],
+                has_description_hash: true,
+            },
+            bump: 2,
+            reserved: [0; 263],
+        };
+
+        let output = ncn_metadata.pretty_display();
+
+        assert!(output.contains(&ncn_metadata.ncn.to_string()));
+        assert!(output.contains("Jito"));
+        assert!(output.contains(&"ab".repeat(32)));
+        assert!(output.contains(&ncn_metadata.bump.to_string()));
+    }
+}
@@ -13,6 +13,8 @@ impl PrettyDisplay for NcnOperatorState {
         output.push_str(&field("Operator", self.operator));
         output.push_str(&field("Index", self.index));
         output.push_str(&field("Bump", self.bump));
+        output.push_str(&field("Policy Admitted", self.policy_admitted));
+        output.push_str(&field("Jailed Until Epoch", self.jailed_until_epoch));
 
         output.push_str(&section_header("NCN State"));
         output.push_str(&field("NCN Opt-In Added", self.ncn_opt_in_state.slot_added));
@@ -40,7 +42,10 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::{accounts::NcnOperatorState, types::SlotToggle};
+    use crate::{
+        accounts::NcnOperatorState,
+        types::{SlotToggle, TransitionEpochs},
+    };
 
     #[test]
     fn test_ncn_operator_state_pretty_display_structure() {
@@ -52,15 +57,27 @@ mod tests {
             ncn_opt_in_state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                transition_epochs: TransitionEpochs {
+                    is_set: false,
+                    warmup_epochs: 0,
+                    cooldown_epochs: 0,
+                },
+                reserved: [0; 27],
             },
             operator_opt_in_state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                transition_epochs: TransitionEpochs {
+                    is_set: false,
+                    warmup_epochs: 0,
+                    cooldown_epochs: 0,
+                },
+                reserved: [0; 27],
             },
             bump: 254,
-            reserved: [0; 263],
+            policy_admitted: true,
+            jailed_until_epoch: 42,
+            reserved: [0; 254],
         };
 
         let output = ncn_operator_state.pretty_display();
@@ -83,5 +100,6 @@ mod tests {
                 .to_string()
         ));
         assert!(output.contains(&ncn_operator_state.bump.to_string()));
+        assert!(output.contains(&ncn_operator_state.jailed_until_epoch.to_string()));
     }
 }
@@ -0,0 +1,83 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::NcnPolicy;
+
+impl PrettyDisplay for NcnPolicy {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("NCN Policy Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("NCN", self.ncn));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Admission"));
+        output.push_str(&field(
+            "Permissionless Admission",
+            self.permissionless_admission,
+        ));
+        output.push_str(&field("Min Operator Stake", self.min_operator_stake));
+        output.push_str(&field(
+            "Min Operator Ticket Age",
+            self.min_operator_ticket_age,
+        ));
+        output.push_str(&field("Max Operators", self.max_operators));
+        output.push_str(&field(
+            "Admitted Operator Count",
+            self.admitted_operator_count,
+        ));
+
+        output.push_str(&section_header("Allowed Vault Mints"));
+        let allowed_vault_mints: Vec<&Pubkey> = self
+            .allowed_vault_mints
+            .iter()
+            .filter(|mint| **mint != Pubkey::default())
+            .collect();
+        if allowed_vault_mints.is_empty() {
+            output.push_str(&field("Mints", "Any"));
+        }
+        for (index, mint) in allowed_vault_mints.iter().enumerate() {
+            output.push_str(&field(&format!("Mint {index}"), mint));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::NcnPolicy;
+
+    #[test]
+    fn test_ncn_policy_pretty_display_structure() {
+        let mut allowed_vault_mints = [Pubkey::default(); 8];
+        allowed_vault_mints[0] = Pubkey::new_unique();
+        let ncn_policy = NcnPolicy {
+            discriminator: 12345,
+            ncn: Pubkey::new_unique(),
+            allowed_vault_mints,
+            min_operator_stake: 10_000,
+            max_operators: 16,
+            admitted_operator_count: 3,
+            min_operator_ticket_age: 432_000,
+            permissionless_admission: true,
+            bump: 254,
+            reserved: [0; 263],
+        };
+
+        let output = ncn_policy.pretty_display();
+
+        assert!(output.contains(&ncn_policy.ncn.to_string()));
+        assert!(output.contains(&allowed_vault_mints[0].to_string()));
+        assert!(!output.contains(&Pubkey::default().to_string()));
+        assert!(output.contains(&ncn_policy.min_operator_stake.to_string()));
+        assert!(output.contains(&ncn_policy.max_operators.to_string()));
+        assert!(output.contains(&ncn_policy.min_operator_ticket_age.to_string()));
+        assert!(output.contains(&ncn_policy.bump.to_string()));
+    }
+}
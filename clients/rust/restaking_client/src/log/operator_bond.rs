@@ -0,0 +1,60 @@
+use jito_restaking_client_common::log::{account_header, field, section_header, PrettyDisplay};
+
+use crate::accounts::OperatorBond;
+
+impl PrettyDisplay for OperatorBond {
+    fn pretty_display(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&account_header("Operator Bond Account"));
+
+        output.push_str(&section_header("Basic Information"));
+        output.push_str(&field("NCN", self.ncn));
+        output.push_str(&field("Operator", self.operator));
+        output.push_str(&field("Mint", self.mint));
+        output.push_str(&field("Bump", self.bump));
+
+        output.push_str(&section_header("Bond"));
+        output.push_str(&field("Amount", self.amount));
+        output.push_str(&field("Slashed", self.slashed));
+        output.push_str(&field("Unbonding Amount", self.unbonding_amount));
+        output.push_str(&field("Withdrawable Epoch", self.withdrawable_epoch));
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anchor_lang::prelude::Pubkey;
+    use jito_restaking_client_common::log::PrettyDisplay;
+
+    use crate::accounts::OperatorBond;
+
+    #[test]
+    fn test_operator_bond_pretty_display_structure() {
+        let operator_bond = OperatorBond {
+            discriminator: 12345,
+            ncn: Pubkey::new_unique(),
+            operator: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            amount: 1_000,
+            slashed: 250,
+            unbonding_amount: 400,
+            withdrawable_epoch: 12,
+            bump: 254,
+            reserved: [0; 215],
+        };
+
+        let output = operator_bond.pretty_display();
+
+        assert!(output.contains(&operator_bond.ncn.to_string()));
+        assert!(output.contains(&operator_bond.operator.to_string()));
+        assert!(output.contains(&operator_bond.mint.to_string()));
+        assert!(output.contains(&operator_bond.amount.to_string()));
+        assert!(output.contains(&operator_bond.slashed.to_string()));
+        assert!(output.contains(&operator_bond.unbonding_amount.to_string()));
+        assert!(output.contains(&operator_bond.withdrawable_epoch.to_string()));
+        assert!(output.contains(&operator_bond.bump.to_string()));
+    }
+}
@@ -27,7 +27,10 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::{accounts::OperatorVaultTicket, types::SlotToggle};
+    use crate::{
+        accounts::OperatorVaultTicket,
+        types::{SlotToggle, TransitionEpochs},
+    };
 
     #[test]
     fn test_operator_vault_ticket_pretty_display_structure() {
@@ -39,7 +42,12 @@ mod tests {
             state: SlotToggle {
                 slot_added: 0,
                 slot_removed: 1,
-                reserved: [0; 32],
+                transition_epochs: TransitionEpochs {
+                    is_set: false,
+                    warmup_epochs: 0,
+                    cooldown_epochs: 0,
+                },
+                reserved: [0; 27],
             },
             bump: 2,
             reserved: [0; 263],
@@ -10,198 +10,231 @@ use thiserror::Error;
 
 #[derive(Clone, Debug, Eq, Error, FromPrimitive, PartialEq)]
 pub enum JitoVaultError {
-    /// 0 - Bad epoch length
-    #[error("Bad epoch length")]
-    BadEpochLength = 0x0,
-    /// 1000 - VaultSlashUnderflow
-    #[error("VaultSlashUnderflow")]
+    /// 1000 - Slash amount exceeds the security delegated to the operator
+    #[error("Slash amount exceeds the security delegated to the operator")]
     VaultSlashUnderflow = 0x3E8,
-    /// 1001 - VaultInitialAmountFailed
-    #[error("VaultInitialAmountFailed")]
+    /// 1001 - Initial deposit must be non-zero and mint VRT one to one
+    #[error("Initial deposit must be non-zero and mint VRT one to one")]
     VaultInitialAmountFailed = 0x3E9,
-    /// 1002 - VaultInsufficientFunds
-    #[error("VaultInsufficientFunds")]
+    /// 1002 - Vault does not hold enough assets for this amount
+    #[error("Vault does not hold enough assets for this amount")]
     VaultInsufficientFunds = 0x3EA,
-    /// 1003 - VaultOverflow
-    #[error("VaultOverflow")]
+    /// 1003 - Vault calculation overflowed
+    #[error("Vault calculation overflowed")]
     VaultOverflow = 0x3EB,
-    /// 1004 - VaultOperatorAdminInvalid
-    #[error("VaultOperatorAdminInvalid")]
+    /// 1004 - Signer is not the vault's operator admin
+    #[error("Signer is not the vault's operator admin")]
     VaultOperatorAdminInvalid = 0x3EC,
-    /// 1005 - VaultAdminInvalid
-    #[error("VaultAdminInvalid")]
+    /// 1005 - Signer is not the vault admin
+    #[error("Signer is not the vault admin")]
     VaultAdminInvalid = 0x3ED,
-    /// 1006 - VaultCapacityAdminInvalid
-    #[error("VaultCapacityAdminInvalid")]
+    /// 1006 - Signer is not the vault's capacity admin
+    #[error("Signer is not the vault's capacity admin")]
     VaultCapacityAdminInvalid = 0x3EE,
-    /// 1007 - VaultMintBurnAdminInvalid
-    #[error("VaultMintBurnAdminInvalid")]
+    /// 1007 - Signer is not the vault's mint burn admin
+    #[error("Signer is not the vault's mint burn admin")]
     VaultMintBurnAdminInvalid = 0x3EF,
-    /// 1008 - VaultDelegationAdminInvalid
-    #[error("VaultDelegationAdminInvalid")]
+    /// 1008 - Signer is not the vault's delegation admin
+    #[error("Signer is not the vault's delegation admin")]
     VaultDelegationAdminInvalid = 0x3F0,
-    /// 1009 - VaultDelegateAssetAdminInvalid
-    #[error("VaultDelegateAssetAdminInvalid")]
+    /// 1009 - Signer is not the vault's delegate asset admin
+    #[error("Signer is not the vault's delegate asset admin")]
     VaultDelegateAssetAdminInvalid = 0x3F1,
-    /// 1010 - VaultCapacityExceeded
-    #[error("VaultCapacityExceeded")]
+    /// 1010 - Deposit would exceed the vault's deposit capacity
+    #[error("Deposit would exceed the vault's deposit capacity")]
     VaultCapacityExceeded = 0x3F2,
-    /// 1011 - VaultSlasherAdminInvalid
-    #[error("VaultSlasherAdminInvalid")]
+    /// 1011 - Signer is not the vault's slasher admin
+    #[error("Signer is not the vault's slasher admin")]
     VaultSlasherAdminInvalid = 0x3F3,
-    /// 1012 - VaultNcnAdminInvalid
-    #[error("VaultNcnAdminInvalid")]
+    /// 1012 - Signer is not the vault's NCN admin
+    #[error("Signer is not the vault's NCN admin")]
     VaultNcnAdminInvalid = 0x3F4,
-    /// 1013 - VaultFeeAdminInvalid
-    #[error("VaultFeeAdminInvalid")]
+    /// 1013 - Signer is not the vault's fee admin
+    #[error("Signer is not the vault's fee admin")]
     VaultFeeAdminInvalid = 0x3F5,
-    /// 1014 - ConfigAdminInvalid
-    #[error("ConfigAdminInvalid")]
+    /// 1014 - Signer is not the config admin
+    #[error("Signer is not the config admin")]
     ConfigAdminInvalid = 0x3F6,
-    /// 1015 - ConfigFeeAdminInvalid
-    #[error("ConfigFeeAdminInvalid")]
+    /// 1015 - Signer is not the config's fee admin
+    #[error("Signer is not the config's fee admin")]
     ConfigFeeAdminInvalid = 0x3F7,
-    /// 1016 - VaultFeeCapExceeded
-    #[error("VaultFeeCapExceeded")]
+    /// 1016 - Fee exceeds the maximum allowed fee
+    #[error("Fee exceeds the maximum allowed fee")]
     VaultFeeCapExceeded = 0x3F8,
-    /// 1017 - VaultFeeChangeTooSoon
-    #[error("VaultFeeChangeTooSoon")]
+    /// 1017 - Fees can only be changed once per epoch
+    #[error("Fees can only be changed once per epoch")]
     VaultFeeChangeTooSoon = 0x3F9,
-    /// 1018 - VaultFeeBumpTooLarge
-    #[error("VaultFeeBumpTooLarge")]
+    /// 1018 - Fee increase exceeds the maximum allowed per change
+    #[error("Fee increase exceeds the maximum allowed per change")]
     VaultFeeBumpTooLarge = 0x3FA,
-    /// 1019 - VaultUnderflow
-    #[error("VaultUnderflow")]
+    /// 1019 - Vault calculation underflowed
+    #[error("Vault calculation underflowed")]
     VaultUnderflow = 0x3FB,
-    /// 1020 - VaultUpdateNeeded
-    #[error("VaultUpdateNeeded")]
+    /// 1020 - Vault must be updated for the current epoch first
+    #[error("Vault must be updated for the current epoch first")]
     VaultUpdateNeeded = 0x3FC,
-    /// 1021 - VaultIsUpdated
-    #[error("VaultIsUpdated")]
+    /// 1021 - Vault is already updated for the current epoch
+    #[error("Vault is already updated for the current epoch")]
     VaultIsUpdated = 0x3FD,
-    /// 1022 - VaultOperatorDelegationUpdateNeeded
-    #[error("VaultOperatorDelegationUpdateNeeded")]
+    /// 1022 - Vault operator delegation must be updated for the current epoch first
+    #[error("Vault operator delegation must be updated for the current epoch first")]
     VaultOperatorDelegationUpdateNeeded = 0x3FE,
-    /// 1023 - VaultOperatorDelegationIsUpdated
-    #[error("VaultOperatorDelegationIsUpdated")]
+    /// 1023 - Vault operator delegation is already updated for the current epoch
+    #[error("Vault operator delegation is already updated for the current epoch")]
     VaultOperatorDelegationIsUpdated = 0x3FF,
-    /// 1024 - VaultUpdateIncorrectIndex
-    #[error("VaultUpdateIncorrectIndex")]
+    /// 1024 - Operators must be updated in index order
+    #[error("Operators must be updated in index order")]
     VaultUpdateIncorrectIndex = 0x400,
-    /// 1025 - VaultUpdateStateNotFinishedUpdating
-    #[error("VaultUpdateStateNotFinishedUpdating")]
+    /// 1025 - Not all operators have been updated yet
+    #[error("Not all operators have been updated yet")]
     VaultUpdateStateNotFinishedUpdating = 0x401,
-    /// 1026 - VaultSecurityOverflow
-    #[error("VaultSecurityOverflow")]
+    /// 1026 - Delegated security overflowed
+    #[error("Delegated security overflowed")]
     VaultSecurityOverflow = 0x402,
-    /// 1027 - VaultSlashIncomplete
-    #[error("VaultSlashIncomplete")]
+    /// 1027 - Slash could not be applied in full
+    #[error("Slash could not be applied in full")]
     VaultSlashIncomplete = 0x403,
-    /// 1028 - VaultSecurityUnderflow
-    #[error("VaultSecurityUnderflow")]
+    /// 1028 - Delegated security underflowed
+    #[error("Delegated security underflowed")]
     VaultSecurityUnderflow = 0x404,
-    /// 1029 - SlippageError
-    #[error("SlippageError")]
+    /// 1029 - Amount out is less than the minimum amount out
+    #[error("Amount out is less than the minimum amount out")]
     SlippageError = 0x405,
-    /// 1030 - VaultStakerWithdrawalTicketNotWithdrawable
-    #[error("VaultStakerWithdrawalTicketNotWithdrawable")]
+    /// 1030 - Withdrawal ticket is still cooling down
+    #[error("Withdrawal ticket is still cooling down")]
     VaultStakerWithdrawalTicketNotWithdrawable = 0x406,
-    /// 1031 - VaultNcnSlasherTicketFailedCooldown
-    #[error("VaultNcnSlasherTicketFailedCooldown")]
+    /// 1031 - Vault NCN slasher ticket cannot be cooled down in its current state
+    #[error("Vault NCN slasher ticket cannot be cooled down in its current state")]
     VaultNcnSlasherTicketFailedCooldown = 0x407,
-    /// 1032 - VaultNcnSlasherTicketFailedWarmup
-    #[error("VaultNcnSlasherTicketFailedWarmup")]
+    /// 1032 - Vault NCN slasher ticket cannot be warmed up in its current state
+    #[error("Vault NCN slasher ticket cannot be warmed up in its current state")]
     VaultNcnSlasherTicketFailedWarmup = 0x408,
-    /// 1033 - VaultNcnTicketFailedCooldown
-    #[error("VaultNcnTicketFailedCooldown")]
+    /// 1033 - Vault NCN ticket cannot be cooled down in its current state
+    #[error("Vault NCN ticket cannot be cooled down in its current state")]
     VaultNcnTicketFailedCooldown = 0x409,
-    /// 1034 - VaultNcnTicketFailedWarmup
-    #[error("VaultNcnTicketFailedWarmup")]
+    /// 1034 - Vault NCN ticket cannot be warmed up in its current state
+    #[error("Vault NCN ticket cannot be warmed up in its current state")]
     VaultNcnTicketFailedWarmup = 0x40A,
-    /// 1035 - VaultNcnTicketUnslashable
-    #[error("VaultNcnTicketUnslashable")]
+    /// 1035 - Vault NCN ticket is not active, so the NCN cannot slash
+    #[error("Vault NCN ticket is not active, so the NCN cannot slash")]
     VaultNcnTicketUnslashable = 0x40B,
-    /// 1036 - OperatorVaultTicketUnslashable
-    #[error("OperatorVaultTicketUnslashable")]
+    /// 1036 - Operator vault ticket is not active, so the operator cannot be slashed
+    #[error("Operator vault ticket is not active, so the operator cannot be slashed")]
     OperatorVaultTicketUnslashable = 0x40C,
-    /// 1037 - NcnOperatorStateUnslashable
-    #[error("NcnOperatorStateUnslashable")]
+    /// 1037 - NCN operator state is not active, so the operator cannot be slashed
+    #[error("NCN operator state is not active, so the operator cannot be slashed")]
     NcnOperatorStateUnslashable = 0x40D,
-    /// 1038 - VaultNcnSlasherTicketUnslashable
-    #[error("VaultNcnSlasherTicketUnslashable")]
+    /// 1038 - Vault NCN slasher ticket is not active, so the slasher cannot slash
+    #[error("Vault NCN slasher ticket is not active, so the slasher cannot slash")]
     VaultNcnSlasherTicketUnslashable = 0x40E,
-    /// 1039 - NcnVaultTicketUnslashable
-    #[error("NcnVaultTicketUnslashable")]
+    /// 1039 - NCN vault ticket is not active, so the NCN cannot slash
+    #[error("NCN vault ticket is not active, so the NCN cannot slash")]
     NcnVaultTicketUnslashable = 0x40F,
-    /// 1040 - NcnVaultSlasherTicketUnslashable
-    #[error("NcnVaultSlasherTicketUnslashable")]
+    /// 1040 - NCN vault slasher ticket is not active, so the slasher cannot slash
+    #[error("NCN vault slasher ticket is not active, so the slasher cannot slash")]
     NcnVaultSlasherTicketUnslashable = 0x410,
-    /// 1041 - VaultMaxSlashedPerOperatorExceeded
-    #[error("VaultMaxSlashedPerOperatorExceeded")]
+    /// 1041 - Slash exceeds the maximum slashable per operator this epoch
+    #[error("Slash exceeds the maximum slashable per operator this epoch")]
     VaultMaxSlashedPerOperatorExceeded = 0x411,
-    /// 1042 - VaultStakerWithdrawalTicketInvalidStaker
-    #[error("VaultStakerWithdrawalTicketInvalidStaker")]
+    /// 1042 - Signer is not the staker of the withdrawal ticket
+    #[error("Signer is not the staker of the withdrawal ticket")]
     VaultStakerWithdrawalTicketInvalidStaker = 0x412,
-    /// 1043 - SlasherOverflow
-    #[error("SlasherOverflow")]
+    /// 1043 - Vault slasher count overflowed
+    #[error("Vault slasher count overflowed")]
     SlasherOverflow = 0x413,
-    /// 1044 - NcnOverflow
-    #[error("NcnOverflow")]
+    /// 1044 - Vault NCN count overflowed
+    #[error("Vault NCN count overflowed")]
     NcnOverflow = 0x414,
-    /// 1045 - OperatorOverflow
-    #[error("OperatorOverflow")]
+    /// 1045 - Vault operator count overflowed
+    #[error("Vault operator count overflowed")]
     OperatorOverflow = 0x415,
-    /// 1046 - VaultDelegationZero
-    #[error("VaultDelegationZero")]
+    /// 1046 - Delegation amount must be non-zero
+    #[error("Delegation amount must be non-zero")]
     VaultDelegationZero = 0x416,
-    /// 1047 - VaultCooldownZero
-    #[error("VaultCooldownZero")]
+    /// 1047 - Cooldown amount must be non-zero
+    #[error("Cooldown amount must be non-zero")]
     VaultCooldownZero = 0x417,
-    /// 1048 - VaultBurnZero
-    #[error("VaultBurnZero")]
+    /// 1048 - Burn amount must be non-zero
+    #[error("Burn amount must be non-zero")]
     VaultBurnZero = 0x418,
-    /// 1049 - VaultEnqueueWithdrawalAmountZero
-    #[error("VaultEnqueueWithdrawalAmountZero")]
+    /// 1049 - Withdrawal amount must be non-zero
+    #[error("Withdrawal amount must be non-zero")]
     VaultEnqueueWithdrawalAmountZero = 0x419,
-    /// 1050 - VaultMintZero
-    #[error("VaultMintZero")]
+    /// 1050 - Mint amount must be non-zero
+    #[error("Mint amount must be non-zero")]
     VaultMintZero = 0x41A,
-    /// 1051 - VaultIsPaused
-    #[error("VaultIsPaused")]
+    /// 1051 - Vault is paused
+    #[error("Vault is paused")]
     VaultIsPaused = 0x41B,
-    /// 1052 - InvalidDepositor
-    #[error("InvalidDepositor")]
+    /// 1052 - Depositor cannot be the vault
+    #[error("Depositor cannot be the vault")]
     InvalidDepositor = 0x41C,
-    /// 1053 - InvalidDepositTokenAccount
-    #[error("InvalidDepositTokenAccount")]
+    /// 1053 - Depositor token account cannot be the vault token account
+    #[error("Depositor token account cannot be the vault token account")]
     InvalidDepositTokenAccount = 0x41D,
-    /// 1054 - NoSupportedMintBalanceChange
-    #[error("NoSupportedMintBalanceChange")]
+    /// 1054 - Supported mint balance did not change
+    #[error("Supported mint balance did not change")]
     NoSupportedMintBalanceChange = 0x41E,
-    /// 1055 - InvalidEpochLength
-    #[error("InvalidEpochLength")]
+    /// 1055 - Epoch length is invalid
+    #[error("Epoch length is invalid")]
     InvalidEpochLength = 0x41F,
-    /// 1056 - VaultRewardFeeDeltaTooLarge
-    #[error("VaultRewardFeeDeltaTooLarge")]
+    /// 1056 - Reward fee taken differs too much from the configured reward fee
+    #[error("Reward fee taken differs too much from the configured reward fee")]
     VaultRewardFeeDeltaTooLarge = 0x420,
-    /// 1057 - VaultRewardFeeIsZero
-    #[error("VaultRewardFeeIsZero")]
+    /// 1057 - Reward fee rounded down to zero VRT
+    #[error("Reward fee rounded down to zero VRT")]
     VaultRewardFeeIsZero = 0x421,
-    /// 1058 - VrtOutCannotBeZero
-    #[error("VrtOutCannotBeZero")]
+    /// 1058 - Deposit is too small to mint any VRT
+    #[error("Deposit is too small to mint any VRT")]
     VrtOutCannotBeZero = 0x422,
-    /// 1059 - NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate
-    #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
+    /// 1059 - Not enough assets were cooled down to cover pending withdrawals
+    #[error("Not enough assets were cooled down to cover pending withdrawals")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate = 0x423,
-    /// 3000 - ArithmeticOverflow
-    #[error("ArithmeticOverflow")]
+    /// 3000 - Arithmetic overflow
+    #[error("Arithmetic overflow")]
     ArithmeticOverflow = 0xBB8,
-    /// 3001 - ArithmeticUnderflow
-    #[error("ArithmeticUnderflow")]
+    /// 3001 - Arithmetic underflow
+    #[error("Arithmetic underflow")]
     ArithmeticUnderflow = 0xBB9,
-    /// 3002 - DivisionByZero
-    #[error("DivisionByZero")]
+    /// 3002 - Division by zero
+    #[error("Division by zero")]
     DivisionByZero = 0xBBA,
+    /// 4000 - Bad epoch length
+    #[error("Bad epoch length")]
+    BadEpochLength = 0xFA0,
+    /// 4100 - not a signer
+    #[error("not a signer")]
+    AccountNotSigner = 0x1004,
+    /// 4101 - not writable
+    #[error("not writable")]
+    AccountNotWritable = 0x1005,
+    /// 4102 - wrong owner
+    #[error("wrong owner")]
+    InvalidAccountOwner = 0x1006,
+    /// 4103 - account data is empty
+    #[error("account data is empty")]
+    AccountDataEmpty = 0x1007,
+    /// 4104 - account is already initialized
+    #[error("account is already initialized")]
+    AccountAlreadyInitialized = 0x1008,
+    /// 4105 - account data is invalid
+    #[error("account data is invalid")]
+    InvalidAccountData = 0x1009,
+    /// 4106 - wrong discriminator
+    #[error("wrong discriminator")]
+    InvalidDiscriminator = 0x100A,
+    /// 4107 - not at the expected address
+    #[error("not at the expected address")]
+    InvalidAddress = 0x100B,
+    /// 4108 - wrong program
+    #[error("wrong program")]
+    InvalidProgramId = 0x100C,
+    /// 4109 - wrong token account owner
+    #[error("wrong token account owner")]
+    InvalidTokenOwner = 0x100D,
+    /// 4110 - wrong mint
+    #[error("wrong mint")]
+    InvalidMint = 0x100E,
 }
 
 impl solana_program::program_error::PrintProgramError for JitoVaultError {
@@ -48,7 +48,7 @@ impl InitializeVaultUpdateStateTracker {
             false,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
-            self.payer, false,
+            self.payer, true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.system_program,
@@ -99,7 +99,7 @@ pub struct InitializeVaultUpdateStateTrackerInstructionArgs {
 ///   0. `[]` config
 ///   1. `[writable]` vault
 ///   2. `[writable]` vault_update_state_tracker
-///   3. `[writable]` payer
+///   3. `[writable, signer]` payer
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct InitializeVaultUpdateStateTrackerBuilder {
@@ -290,7 +290,7 @@ impl<'a, 'b> InitializeVaultUpdateStateTrackerCpi<'a, 'b> {
         ));
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.payer.key,
-            false,
+            true,
         ));
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.system_program.key,
@@ -340,7 +340,7 @@ impl<'a, 'b> InitializeVaultUpdateStateTrackerCpi<'a, 'b> {
 ///   0. `[]` config
 ///   1. `[writable]` vault
 ///   2. `[writable]` vault_update_state_tracker
-///   3. `[writable]` payer
+///   3. `[writable, signer]` payer
 ///   4. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct InitializeVaultUpdateStateTrackerCpiBuilder<'a, 'b> {
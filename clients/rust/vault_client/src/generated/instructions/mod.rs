@@ -24,6 +24,7 @@ pub(crate) mod r#initialize_vault_ncn_ticket;
 pub(crate) mod r#initialize_vault_operator_delegation;
 pub(crate) mod r#initialize_vault_update_state_tracker;
 pub(crate) mod r#initialize_vault_with_mint;
+pub(crate) mod r#migrate_account;
 pub(crate) mod r#mint_to;
 pub(crate) mod r#revoke_delegate_token_account;
 pub(crate) mod r#set_admin;
@@ -59,6 +60,7 @@ pub use self::r#initialize_vault_ncn_ticket::*;
 pub use self::r#initialize_vault_operator_delegation::*;
 pub use self::r#initialize_vault_update_state_tracker::*;
 pub use self::r#initialize_vault_with_mint::*;
+pub use self::r#migrate_account::*;
 pub use self::r#mint_to::*;
 pub use self::r#revoke_delegate_token_account::*;
 pub use self::r#set_admin::*;
@@ -32,7 +32,7 @@ impl SetConfigSecondaryAdmin {
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
         let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             self.config,
             false,
         ));
@@ -85,7 +85,7 @@ pub struct SetConfigSecondaryAdminInstructionArgs {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` config
+///   0. `[writable]` config
 ///   1. `[signer]` admin
 ///   2. `[]` new_admin
 #[derive(Clone, Debug, Default)]
@@ -228,7 +228,7 @@ impl<'a, 'b> SetConfigSecondaryAdminCpi<'a, 'b> {
         )],
     ) -> solana_program::entrypoint::ProgramResult {
         let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
-        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+        accounts.push(solana_program::instruction::AccountMeta::new(
             *self.config.key,
             false,
         ));
@@ -279,7 +279,7 @@ impl<'a, 'b> SetConfigSecondaryAdminCpi<'a, 'b> {
 ///
 /// ### Accounts:
 ///
-///   0. `[]` config
+///   0. `[writable]` config
 ///   1. `[signer]` admin
 ///   2. `[]` new_admin
 #[derive(Clone, Debug)]
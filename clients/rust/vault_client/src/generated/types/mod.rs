@@ -10,6 +10,7 @@ pub(crate) mod r#create_metadata_account_args_v3;
 pub(crate) mod r#data_v2;
 pub(crate) mod r#delegation_state;
 pub(crate) mod r#slot_toggle;
+pub(crate) mod r#transition_epochs;
 pub(crate) mod r#update_metadata_account_args_v2;
 pub(crate) mod r#vault_admin_role;
 pub(crate) mod r#withdrawal_allocation_method;
@@ -19,6 +20,7 @@ pub use self::r#create_metadata_account_args_v3::*;
 pub use self::r#data_v2::*;
 pub use self::r#delegation_state::*;
 pub use self::r#slot_toggle::*;
+pub use self::r#transition_epochs::*;
 pub use self::r#update_metadata_account_args_v2::*;
 pub use self::r#vault_admin_role::*;
 pub use self::r#withdrawal_allocation_method::*;
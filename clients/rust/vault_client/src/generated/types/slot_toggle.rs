@@ -5,6 +5,7 @@
 //! <https://github.com/kinobi-so/kinobi>
 //!
 
+use crate::generated::types::TransitionEpochs;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 
@@ -13,5 +14,6 @@ use borsh::BorshSerialize;
 pub struct SlotToggle {
     pub slot_added: u64,
     pub slot_removed: u64,
-    pub reserved: [u8; 32],
+    pub transition_epochs: TransitionEpochs,
+    pub reserved: [u8; 27],
 }
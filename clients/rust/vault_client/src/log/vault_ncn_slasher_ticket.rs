@@ -32,7 +32,10 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::{accounts::VaultNcnSlasherTicket, types::SlotToggle};
+    use crate::{
+        accounts::VaultNcnSlasherTicket,
+        types::{SlotToggle, TransitionEpochs},
+    };
 
     #[test]
     fn test_vault_ncn_slasher_ticket_pretty_display_structure() {
@@ -46,7 +49,12 @@ mod tests {
             state: SlotToggle {
                 slot_added: 3,
                 slot_removed: 4,
-                reserved: [0; 32],
+                transition_epochs: TransitionEpochs {
+                    is_set: false,
+                    warmup_epochs: 0,
+                    cooldown_epochs: 0,
+                },
+                reserved: [0; 27],
             },
             bump: 5,
             reserved: [0; 263],
@@ -27,7 +27,10 @@ mod tests {
     use anchor_lang::prelude::Pubkey;
     use jito_restaking_client_common::log::PrettyDisplay;
 
-    use crate::{accounts::VaultNcnTicket, types::SlotToggle};
+    use crate::{
+        accounts::VaultNcnTicket,
+        types::{SlotToggle, TransitionEpochs},
+    };
 
     #[test]
     fn test_vault_ncn_ticket_pretty_display_structure() {
@@ -39,7 +42,12 @@ mod tests {
             state: SlotToggle {
                 slot_added: 1,
                 slot_removed: 2,
-                reserved: [0; 32],
+                transition_epochs: TransitionEpochs {
+                    is_set: false,
+                    warmup_epochs: 0,
+                    cooldown_epochs: 0,
+                },
+                reserved: [0; 27],
             },
             bump: 3,
             reserved: [0; 263],
@@ -0,0 +1,275 @@
+//! Declarative instruction accounts
+//!
+//! `#[derive(AccountsContext)]` on an instruction enum generates a `{Variant}Accounts` struct for
+//! every variant with `#[account(...)]` attributes, the same attributes shank reads to build the
+//! IDL. Each struct has one field per account, named after the account, and implements
+//! [`AccountsContext`] to validate the accounts passed to the instruction:
+//! * the number of accounts
+//! * `signer` and `writable` accounts
+//! * `optional` accounts, which are `None` when missing or when the program id is passed in their
+//!   place
+//! * `system_program`, `token_program` and `associated_token_program` are the expected programs
+//!
+//! An `#[accounts(...)]` attribute on the variant adds checks that shank can't describe:
+//! * `remaining = name` - collects the accounts after the declared ones into a `name` slice.
+//!   Without it, extra accounts are rejected, unless the instruction has optional accounts: the
+//!   accounts after those are ignored, as they always were
+//! * `pda(account = Type(seeds..), program = ..)` - loads the account with `Type::load`, which
+//!   checks the owner, discriminator and PDA, and makes it an [`Account`] of that type. The seeds
+//!   are the accounts `load` takes after the account itself
+//! * `associated_token_account(account, owner = owner, mint = mint)` - the account is the
+//!   associated token account of the owner and mint
+//!
+//! Seeds, owners and mints name another account of the instruction. `account.key` passes the key
+//! of an account where `load` takes a pubkey, and `account.field` reads a pubkey field of an
+//! account loaded by an earlier `pda`, e.g. `program = config.restaking_program`.
+//!
+//! ```ignore
+//! #[derive(BorshSerialize, BorshDeserialize, ShankInstruction, AccountsContext)]
+//! pub enum VaultInstruction {
+//!     #[account(0, name = "config")]
+//!     #[account(1, writable, name = "vault")]
+//!     #[account(2, signer, name = "admin")]
+//!     #[accounts(pda(config = Config), pda(vault = Vault))]
+//!     SetDepositCapacity { amount: u64 },
+//! }
+//!
+//! let SetDepositCapacityAccounts { config, vault, admin } =
+//!     SetDepositCapacityAccounts::try_from_accounts(program_id, accounts)?;
+//! let mut vault = vault.data_mut()?;
+//! ```
+use std::{
+    cell::{Ref, RefMut},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+pub use jito_account_traits_derive::AccountsContext;
+use jito_bytemuck::AccountDeserialize;
+use jito_errors::{AccountError, LoaderError};
+use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The validated accounts of an instruction
+pub trait AccountsContext<'a, 'info>: Sized {
+    /// The number of accounts the instruction requires, not counting optional accounts
+    const REQUIRED_ACCOUNTS: usize;
+
+    /// Splits the accounts passed to the instruction into their fields, returning an error if an
+    /// account is missing or fails a check.
+    ///
+    /// # Arguments
+    /// * `program_id` - The program id, passed in place of missing optional accounts
+    /// * `accounts` - The accounts passed to the instruction
+    fn try_from_accounts(
+        program_id: &Pubkey,
+        accounts: &'a [AccountInfo<'info>],
+    ) -> Result<Self, ProgramError>;
+}
+
+/// An account of an instruction that was loaded as a `T` account.
+///
+/// It dereferences to its [`AccountInfo`], and reads its data as a `T` without checking the
+/// discriminator again.
+pub struct Account<'a, 'info, T> {
+    info: &'a AccountInfo<'info>,
+    account: PhantomData<T>,
+}
+
+impl<'a, 'info, T> Account<'a, 'info, T> {
+    /// Wraps an account that was already loaded as a `T` account
+    ///
+    /// # Arguments
+    /// * `info` - The account, checked by the `load` function of `T`
+    #[inline(always)]
+    pub const fn new_unchecked(info: &'a AccountInfo<'info>) -> Self {
+        Self {
+            info,
+            account: PhantomData,
+        }
+    }
+
+    /// Returns the [`AccountInfo`] of the account
+    #[inline(always)]
+    pub const fn info(&self) -> &'a AccountInfo<'info> {
+        self.info
+    }
+}
+
+impl<'a, T: AccountDeserialize> Account<'a, '_, T> {
+    /// Borrows the account data as a `T`
+    ///
+    /// # Returns
+    /// * `Result<Ref<T>, ProgramError>` - The account data, or an error if it is already mutably
+    ///   borrowed
+    #[inline(always)]
+    pub fn data(&self) -> Result<Ref<'a, T>, ProgramError> {
+        let data = self
+            .info
+            .try_borrow_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+        Ref::filter_map(data, |data| T::try_from_slice_unchecked(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Mutably borrows the account data as a `T`
+    ///
+    /// # Returns
+    /// * `Result<RefMut<T>, ProgramError>` - The account data, or an error if it is already
+    ///   borrowed
+    #[inline(always)]
+    pub fn data_mut(&self) -> Result<RefMut<'a, T>, ProgramError> {
+        let data = self
+            .info
+            .try_borrow_mut_data()
+            .map_err(|_| ProgramError::AccountBorrowFailed)?;
+        RefMut::filter_map(data, |data| T::try_from_slice_unchecked_mut(data).ok())
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<'info, T> Deref for Account<'_, 'info, T> {
+    type Target = AccountInfo<'info>;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        self.info
+    }
+}
+
+/// Checks the account is a signer and writable when the instruction expects it to be.
+///
+/// # Arguments
+/// * `info` - The account to check
+/// * `role` - The name of the account in the instruction
+/// * `is_signer` - Whether the account should be a signer
+/// * `is_writable` - Whether the account should be writable
+///
+/// # Returns
+/// * `Result<(), AccountError>` - The result of the operation
+#[inline(always)]
+pub const fn check_account(
+    info: &AccountInfo,
+    role: &'static str,
+    is_signer: bool,
+    is_writable: bool,
+) -> Result<(), AccountError> {
+    if is_signer && !info.is_signer {
+        return Err(AccountError::new(role, LoaderError::AccountNotSigner));
+    }
+    if is_writable && !info.is_writable {
+        return Err(AccountError::new(role, LoaderError::AccountNotWritable));
+    }
+
+    Ok(())
+}
+
+/// Checks the number of accounts passed to an instruction.
+///
+/// # Arguments
+/// * `len` - The number of accounts passed to the instruction
+/// * `required` - The number of accounts the instruction requires
+/// * `max` - The number of accounts the instruction takes, `None` if it takes remaining accounts
+///
+/// # Returns
+/// * `Result<(), ProgramError>` - The result of the operation
+#[inline(always)]
+pub fn check_accounts_len(
+    len: usize,
+    required: usize,
+    max: Option<usize>,
+) -> Result<(), ProgramError> {
+    if len < required {
+        msg!("Expected at least {} accounts, received {}", required, len);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    if let Some(max) = max.filter(|max| len > *max) {
+        msg!("Expected at most {} accounts, received {}", max, len);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// Returns the optional account at `index`, or `None` if it was not passed or the program id was
+/// passed in its place.
+///
+/// # Arguments
+/// * `program_id` - The program id
+/// * `accounts` - The accounts passed to the instruction
+/// * `index` - The index of the optional account
+#[inline(always)]
+pub fn optional_account<'a, 'info>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'info>],
+    index: usize,
+) -> Option<&'a AccountInfo<'info>> {
+    accounts.get(index).filter(|info| info.key.ne(program_id))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn account_info<'a>(
+        key: &'a Pubkey,
+        is_signer: bool,
+        is_writable: bool,
+        lamports: &'a mut u64,
+        data: &'a mut [u8],
+    ) -> AccountInfo<'a> {
+        AccountInfo::new(key, is_signer, is_writable, lamports, data, key, false, 0)
+    }
+
+    #[test]
+    fn test_check_account() {
+        let key = Pubkey::new_unique();
+        let (mut lamports, mut data) = (0, []);
+        let info = account_info(&key, false, false, &mut lamports, &mut data);
+
+        assert_eq!(check_account(&info, "admin", false, false), Ok(()));
+        assert_eq!(
+            check_account(&info, "admin", true, false),
+            Err(AccountError::new("admin", LoaderError::AccountNotSigner))
+        );
+        assert_eq!(
+            check_account(&info, "vault", false, true),
+            Err(AccountError::new("vault", LoaderError::AccountNotWritable))
+        );
+    }
+
+    #[test]
+    fn test_check_accounts_len() {
+        assert_eq!(check_accounts_len(3, 3, Some(3)), Ok(()));
+        assert_eq!(check_accounts_len(4, 3, Some(4)), Ok(()));
+        assert_eq!(check_accounts_len(10, 3, None), Ok(()));
+        assert_eq!(
+            check_accounts_len(2, 3, None),
+            Err(ProgramError::NotEnoughAccountKeys)
+        );
+        assert_eq!(
+            check_accounts_len(5, 3, Some(4)),
+            Err(ProgramError::InvalidArgument)
+        );
+    }
+
+    #[test]
+    fn test_optional_account() {
+        let program_id = Pubkey::new_unique();
+        let key = Pubkey::new_unique();
+        let (mut lamports_a, mut data_a) = (0, []);
+        let (mut lamports_b, mut data_b) = (0, []);
+        let accounts = [
+            account_info(&program_id, false, false, &mut lamports_a, &mut data_a),
+            account_info(&key, false, false, &mut lamports_b, &mut data_b),
+        ];
+
+        assert!(optional_account(&program_id, &accounts, 0).is_none());
+        assert_eq!(
+            optional_account(&program_id, &accounts, 1).map(|info| info.key),
+            Some(&key)
+        );
+        assert!(optional_account(&program_id, &accounts, 2).is_none());
+    }
+}
@@ -1,39 +1,13 @@
-use solana_decode_error::DecodeError;
-use solana_program::program_error::ProgramError;
-use thiserror::Error;
+//! Errors returned by the core helpers. They live in `jito_errors` so the SDKs can decode them
+//! without depending on this crate.
 
-#[derive(Debug, Error, PartialEq, Eq)]
-pub enum CoreError {
-    #[error("Bad epoch length")]
-    BadEpochLength,
-}
-
-impl<T> DecodeError<T> for CoreError {
-    fn type_of() -> &'static str {
-        "jito::core"
-    }
-}
-
-impl From<CoreError> for ProgramError {
-    fn from(e: CoreError) -> Self {
-        Self::Custom(e as u32)
-    }
-}
-
-impl From<CoreError> for u64 {
-    fn from(e: CoreError) -> Self {
-        e as Self
-    }
-}
-
-impl From<CoreError> for u32 {
-    fn from(e: CoreError) -> Self {
-        e as Self
-    }
-}
+pub use jito_errors::CoreError;
 
 #[cfg(test)]
 mod tests {
+    use solana_decode_error::DecodeError;
+    use solana_program::program_error::ProgramError;
+
     use super::*;
 
     #[test]
@@ -1,7 +1,9 @@
 use error::CoreError;
+use jito_bytemuck::{MigrateLayout, ACCOUNT_HEADER_LEN};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    msg,
     program::{invoke, invoke_signed},
     program_error::ProgramError,
     pubkey::Pubkey,
@@ -9,6 +11,7 @@ use solana_program::{
     system_instruction,
 };
 
+pub mod accounts;
 pub mod error;
 pub mod loader;
 pub mod slot_toggle;
@@ -113,6 +116,15 @@ pub fn close_program_account<'a>(
     Ok(())
 }
 
+/// Resizes a program account, topping up its lamports from the payer to stay rent exempt.
+/// Any new bytes are zeroed.
+/// # Arguments
+/// * `account` - The account to resize
+/// * `new_size` - The new size of the account data
+/// * `payer` - The account that will pay for the lamports
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `ProgramResult` - The result of the operation
 pub fn realloc<'a, 'info>(
     account: &'a AccountInfo<'info>,
     new_size: usize,
@@ -122,11 +134,55 @@ pub fn realloc<'a, 'info>(
     let new_minimum_balance = rent.minimum_balance(new_size);
 
     let lamports_diff = new_minimum_balance.saturating_sub(account.lamports());
-    invoke(
-        &system_instruction::transfer(payer.key, account.key, lamports_diff),
-        &[payer.clone(), account.clone()],
-    )?;
-    account.realloc(new_size, false)?;
+    if lamports_diff > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_diff),
+            &[payer.clone(), account.clone()],
+        )?;
+    }
+    account.realloc(new_size, true)?;
+    Ok(())
+}
+
+/// Migrates a program account in place to the current layout of `T`, growing it first if the
+/// current layout is larger
+/// # Arguments
+/// * `account` - The account to migrate
+/// * `payer` - The account that will pay for the lamports
+/// * `rent` - The rent sysvar
+/// # Returns
+/// * `ProgramResult` - The result of the operation
+pub fn migrate_account<'a, 'info, T: MigrateLayout>(
+    account: &'a AccountInfo<'info>,
+    payer: &'a AccountInfo<'info>,
+    rent: &Rent,
+) -> ProgramResult {
+    let space = ACCOUNT_HEADER_LEN
+        .checked_add(std::mem::size_of::<T>())
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    if account.data_len() < space {
+        msg!(
+            "Growing account {} from {} to {} bytes",
+            account.key,
+            account.data_len(),
+            space
+        );
+        realloc(account, space, payer, rent)?;
+    }
+
+    if T::migrate(&mut account.try_borrow_mut_data()?)? {
+        msg!(
+            "Migrated account {} to layout version {}",
+            account.key,
+            T::LAYOUT_VERSION
+        );
+    } else {
+        msg!(
+            "Account {} is already at layout version {}",
+            account.key,
+            T::LAYOUT_VERSION
+        );
+    }
     Ok(())
 }
 
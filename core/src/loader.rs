@@ -1,7 +1,10 @@
 //! Loader functions for program accounts
-use solana_program::{
-    account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey, system_program,
-};
+//!
+//! The loaders return an [`AccountError`] naming the role of the account that failed, e.g.
+//! `vault_fee_token_account: wrong owner`. Converting it into a `ProgramError` logs the role and
+//! returns the [`LoaderError`] code.
+pub use jito_errors::{AccountError, LoaderError};
+use solana_program::{account_info::AccountInfo, msg, pubkey::Pubkey, system_program};
 use spl_associated_token_account::get_associated_token_address;
 use spl_token_2022::extension::StateWithExtensions;
 
@@ -10,18 +13,21 @@ use spl_token_2022::extension::StateWithExtensions;
 ///
 /// # Arguments
 /// * `info` - The account to load the signer from
+/// * `role` - The name of the account in the instruction
 /// * `expect_writable` - Whether the account should be writable
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_signer(info: &AccountInfo, expect_writable: bool) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub const fn load_signer(
+    info: &AccountInfo,
+    role: &'static str,
+    expect_writable: bool,
+) -> Result<(), AccountError> {
     if !info.is_signer {
-        msg!("Account is not a signer");
-        return Err(ProgramError::MissingRequiredSignature);
+        return Err(AccountError::new(role, LoaderError::AccountNotSigner));
     }
     if expect_writable && !info.is_writable {
-        msg!("Signer is not writable");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::AccountNotWritable));
     }
 
     Ok(())
@@ -33,27 +39,32 @@ pub fn load_signer(info: &AccountInfo, expect_writable: bool) -> Result<(), Prog
 /// * `info` - The account to load the system program from
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_system_program(info: &AccountInfo) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_system_program(info: &AccountInfo) -> Result<(), AccountError> {
     if info.key.ne(&system_program::id()) {
-        msg!("Account is not the system program");
-        return Err(ProgramError::IncorrectProgramId);
+        return Err(AccountError::new(
+            "system_program",
+            LoaderError::InvalidProgramId,
+        ));
     }
 
     Ok(())
 }
 
-/// Loads the account as the `spl_token` program, returning an error if it is not.
+/// Loads the account as the `spl_associated_token_account` program, returning an error if it is
+/// not.
 ///
 /// # Arguments
-/// * `info` - The account to load the token program from
+/// * `info` - The account to load the associated token program from
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_associated_token_account_program(info: &AccountInfo) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_associated_token_account_program(info: &AccountInfo) -> Result<(), AccountError> {
     if info.key.ne(&spl_associated_token_account::id()) {
-        msg!("Account is not the spl associated token program");
-        return Err(ProgramError::IncorrectProgramId);
+        return Err(AccountError::new(
+            "associated_token_program",
+            LoaderError::InvalidProgramId,
+        ));
     }
 
     Ok(())
@@ -65,11 +76,13 @@ pub fn load_associated_token_account_program(info: &AccountInfo) -> Result<(), P
 /// * `info` - The account to load the token program from
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_token_program(info: &AccountInfo) -> Result<(), AccountError> {
     if info.key.ne(&spl_token::id()) {
-        msg!("Account is not the spl token program");
-        return Err(ProgramError::IncorrectProgramId);
+        return Err(AccountError::new(
+            "token_program",
+            LoaderError::InvalidProgramId,
+        ));
     }
 
     Ok(())
@@ -81,11 +94,13 @@ pub fn load_token_program(info: &AccountInfo) -> Result<(), ProgramError> {
 /// * `info` - The account to load the token program from
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_token_2022_program(info: &AccountInfo) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_token_2022_program(info: &AccountInfo) -> Result<(), AccountError> {
     if info.key.ne(&spl_token_2022::id()) {
-        msg!("Account is not the spl token 2022 program");
-        return Err(ProgramError::IncorrectProgramId);
+        return Err(AccountError::new(
+            "token_program",
+            LoaderError::InvalidProgramId,
+        ));
     }
 
     Ok(())
@@ -96,24 +111,29 @@ pub fn load_token_2022_program(info: &AccountInfo) -> Result<(), ProgramError> {
 ///
 /// # Arguments
 /// * `info` - The account to load the system account from
+/// * `role` - The name of the account in the instruction
 /// * `is_writable` - Whether the account should be writable
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_system_account(info: &AccountInfo, is_writable: bool) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_system_account(
+    info: &AccountInfo,
+    role: &'static str,
+    is_writable: bool,
+) -> Result<(), AccountError> {
     if info.owner.ne(&system_program::id()) {
-        msg!("Account is not owned by the system program");
-        return Err(ProgramError::InvalidAccountOwner);
+        return Err(AccountError::new(role, LoaderError::InvalidAccountOwner));
     }
 
     if !info.data_is_empty() {
-        msg!("Account data is not empty");
-        return Err(ProgramError::AccountAlreadyInitialized);
+        return Err(AccountError::new(
+            role,
+            LoaderError::AccountAlreadyInitialized,
+        ));
     }
 
     if is_writable && !info.is_writable {
-        msg!("Account is not writable");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::AccountNotWritable));
     }
 
     Ok(())
@@ -124,30 +144,29 @@ pub fn load_system_account(info: &AccountInfo, is_writable: bool) -> Result<(),
 ///
 /// # Arguments
 /// * `token_account` - The account to load the token account from
+/// * `role` - The name of the account in the instruction
 /// * `owner` - The owner of the token account
 /// * `mint` - The mint of the token account
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
+/// * `Result<(), AccountError>` - The result of the operation
 pub fn load_associated_token_account(
     token_account: &AccountInfo,
+    role: &'static str,
     owner: &Pubkey,
     mint: &Pubkey,
-) -> Result<(), ProgramError> {
+) -> Result<(), AccountError> {
     if token_account.owner.ne(&spl_token::id()) {
-        msg!("Account is not owned by the spl token program");
-        return Err(ProgramError::InvalidAccountOwner);
+        return Err(AccountError::new(role, LoaderError::InvalidAccountOwner));
     }
 
     if token_account.data_is_empty() {
-        msg!("Account data is empty");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::AccountDataEmpty));
     }
 
     let associated_token_account = get_associated_token_address(owner, mint);
     if token_account.key.ne(&associated_token_account) {
-        msg!("Account is not the associated token account");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::InvalidAddress));
     }
 
     Ok(())
@@ -162,46 +181,46 @@ pub fn load_associated_token_account(
 ///
 /// # Arguments
 /// * `token_account` - The account to load the token account from
+/// * `role` - The name of the account in the instruction
 /// * `owner` - The owner of the token account
 /// * `mint` - The mint of the token account
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
+/// * `Result<(), AccountError>` - The result of the operation
 ///
 /// # Errors
 /// This function will return an error in the following cases:
-/// * `ProgramError::InvalidAccountOwner` - If the `token_account` is not owned by the expected SPL Token program.
-/// * `ProgramError::InvalidAccountData` - If the `token_account` data is empty or if the mint associated with the `token_account` does not match the provided `mint`.
+/// * `LoaderError::InvalidProgramId` - If the `token_program` is not the SPL Token program.
+/// * `LoaderError::InvalidAccountOwner` - If the `token_account` is not owned by the expected SPL Token program.
+/// * `LoaderError::AccountDataEmpty` / `LoaderError::InvalidAccountData` - If the `token_account` data is empty or is not a token account.
+/// * `LoaderError::InvalidTokenOwner` / `LoaderError::InvalidMint` - If the owner or mint of the `token_account` does not match the provided `owner` or `mint`.
 pub fn load_token_account(
     token_account: &AccountInfo,
+    role: &'static str,
     owner: &Pubkey,
     mint: &Pubkey,
     token_program: &AccountInfo,
-) -> Result<(), ProgramError> {
-    if token_program.key.ne(&spl_token::id()) {
-        msg!("Account is not owned by the spl token program");
-        return Err(ProgramError::IncorrectProgramId);
-    }
+) -> Result<(), AccountError> {
+    load_token_program(token_program)?;
 
     if token_account.owner.ne(&spl_token::id()) {
-        msg!("Account is not owned by the token program");
-        return Err(ProgramError::InvalidAccountOwner);
+        return Err(AccountError::new(role, LoaderError::InvalidAccountOwner));
     }
 
     if token_account.data_is_empty() {
-        msg!("Account data is empty");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::AccountDataEmpty));
     }
 
     let data = token_account.data.borrow();
-    let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)
+        .map_err(|_| AccountError::new(role, LoaderError::InvalidAccountData))?;
     if token_account.base.owner.ne(owner) {
         msg!(
             "The token_account has an incorrect owner, expected {}, received {}",
             owner,
             token_account.base.owner
         );
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::InvalidTokenOwner));
     }
 
     if token_account.base.mint.ne(mint) {
@@ -210,7 +229,7 @@ pub fn load_token_account(
             mint,
             token_account.base.mint
         );
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::InvalidMint));
     }
 
     Ok(())
@@ -220,21 +239,21 @@ pub fn load_token_account(
 ///
 /// # Arguments
 /// * `info` - The account to load the token mint from
+/// * `role` - The name of the account in the instruction
 ///
 /// # Returns
-/// * `Result<(), ProgramError>` - The result of the operation
-pub fn load_token_mint(info: &AccountInfo) -> Result<(), ProgramError> {
+/// * `Result<(), AccountError>` - The result of the operation
+pub fn load_token_mint(info: &AccountInfo, role: &'static str) -> Result<(), AccountError> {
     if info.owner.ne(&spl_token::id()) {
-        msg!("Account is not owned by the spl token program");
-        return Err(ProgramError::InvalidAccountOwner);
+        return Err(AccountError::new(role, LoaderError::InvalidAccountOwner));
     }
 
     if info.data_is_empty() {
-        msg!("Account data is empty");
-        return Err(ProgramError::InvalidAccountData);
+        return Err(AccountError::new(role, LoaderError::AccountDataEmpty));
     }
 
-    let _mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&info.data.borrow())?;
+    StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&info.data.borrow())
+        .map_err(|_| AccountError::new(role, LoaderError::InvalidAccountData))?;
 
     Ok(())
 }
@@ -0,0 +1,711 @@
+//! Audit of the restaking ticket graph
+//!
+//! NCNs, operators and vaults are connected by tickets on both programs, and each side of a
+//! relationship opts in on its own. The audit walks the tickets and reports the relationships that
+//! are half-configured or inconsistent:
+//!
+//! * Tickets warming up or cooling down for longer than expected
+//! * `NcnOperatorState` accounts opted in by only one of the NCN and the operator
+//! * Operators jailed by an NCN, whose stake doesn't count towards the NCN until the jail ends
+//! * `NcnVaultTicket` accounts without a matching `VaultNcnTicket`, and the reverse
+//! * `OperatorVaultTicket` accounts without a `VaultOperatorDelegation`
+//! * `NcnVaultSlasherTicket` accounts without a matching `VaultNcnSlasherTicket`
+use std::{collections::HashMap, fmt};
+
+use jito_jsm_core::{
+    get_epoch,
+    slot_toggle::{SlotToggle, SlotToggleState},
+};
+use jito_restaking_core::{
+    ncn_operator_state::NcnOperatorState, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_vault_core::{
+    config::Config as VaultConfig, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+};
+use log::warn;
+use solana_metrics::datapoint_warn;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{
+    prometheus_exporter::METRICS, restaking_handler::RestakingHandler, vault_handler::VaultHandler,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FindingKind {
+    /// A ticket warming up or cooling down for longer than the maximum transition epochs
+    StuckTransition,
+    /// An `NcnOperatorState` opted in by only one of the NCN and the operator
+    OneSidedNcnOperatorState,
+    /// An `NcnOperatorState` whose operator is jailed by the NCN
+    JailedOperator,
+    /// An `NcnVaultTicket` opted in while the vault did not opt in to the NCN
+    UnmatchedNcnVaultTicket,
+    /// A `VaultNcnTicket` opted in while the NCN did not opt in to the vault
+    UnmatchedVaultNcnTicket,
+    /// An `OperatorVaultTicket` opted in while the vault has no delegation to the operator
+    UnmatchedOperatorVaultTicket,
+    /// An `NcnVaultSlasherTicket` opted in while the vault did not opt in to the slasher
+    UnmatchedNcnVaultSlasherTicket,
+}
+
+impl FindingKind {
+    pub const ALL: [Self; 7] = [
+        Self::StuckTransition,
+        Self::OneSidedNcnOperatorState,
+        Self::JailedOperator,
+        Self::UnmatchedNcnVaultTicket,
+        Self::UnmatchedVaultNcnTicket,
+        Self::UnmatchedOperatorVaultTicket,
+        Self::UnmatchedNcnVaultSlasherTicket,
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::StuckTransition => "stuck-transition",
+            Self::OneSidedNcnOperatorState => "one-sided-ncn-operator-state",
+            Self::JailedOperator => "jailed-operator",
+            Self::UnmatchedNcnVaultTicket => "unmatched-ncn-vault-ticket",
+            Self::UnmatchedVaultNcnTicket => "unmatched-vault-ncn-ticket",
+            Self::UnmatchedOperatorVaultTicket => "unmatched-operator-vault-ticket",
+            Self::UnmatchedNcnVaultSlasherTicket => "unmatched-ncn-vault-slasher-ticket",
+        }
+    }
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A half-configured or inconsistent relationship found by the audit
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub kind: FindingKind,
+    /// The ticket the finding is about
+    pub account: Pubkey,
+    pub detail: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.kind, self.account, self.detail)
+    }
+}
+
+/// The tickets connecting NCNs, operators and vaults, on both programs
+pub struct TicketGraph {
+    pub restaking_epoch_length: u64,
+    pub vault_epoch_length: u64,
+    pub ncn_operator_states: Vec<(Pubkey, NcnOperatorState)>,
+    pub ncn_vault_tickets: Vec<(Pubkey, NcnVaultTicket)>,
+    pub operator_vault_tickets: Vec<(Pubkey, OperatorVaultTicket)>,
+    pub ncn_vault_slasher_tickets: Vec<(Pubkey, NcnVaultSlasherTicket)>,
+    pub vault_ncn_tickets: Vec<(Pubkey, VaultNcnTicket)>,
+    pub vault_operator_delegations: Vec<(Pubkey, VaultOperatorDelegation)>,
+    pub vault_ncn_slasher_tickets: Vec<(Pubkey, VaultNcnSlasherTicket)>,
+}
+
+impl TicketGraph {
+    /// Fetches the tickets of both programs.
+    pub async fn fetch(
+        restaking_handler: &RestakingHandler,
+        vault_handler: &VaultHandler,
+        vault_config_address: &Pubkey,
+    ) -> anyhow::Result<Self> {
+        let restaking_config = restaking_handler.get_config().await?;
+        let vault_config: VaultConfig = vault_handler
+            .get_vault_program_account(vault_config_address)
+            .await?;
+
+        Ok(Self {
+            restaking_epoch_length: restaking_config.epoch_length(),
+            vault_epoch_length: vault_config.epoch_length(),
+            ncn_operator_states: restaking_handler.get_ncn_operator_states().await?,
+            ncn_vault_tickets: restaking_handler.get_ncn_vault_tickets().await?,
+            operator_vault_tickets: restaking_handler.get_operator_vault_tickets().await?,
+            ncn_vault_slasher_tickets: restaking_handler.get_ncn_vault_slasher_tickets().await?,
+            vault_ncn_tickets: vault_handler.get_vault_ncn_tickets().await?,
+            vault_operator_delegations: vault_handler.get_vault_operator_delegations().await?,
+            vault_ncn_slasher_tickets: vault_handler.get_vault_ncn_slasher_tickets().await?,
+        })
+    }
+
+    /// Returns the findings of the audit at `slot`.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `max_transition_epochs` - The number of epochs a ticket can warm up or cool down for
+    ///   before it is reported as stuck
+    pub fn audit(&self, slot: u64, max_transition_epochs: u64) -> Vec<Finding> {
+        let restaking = ToggleCheck {
+            slot,
+            epoch_length: self.restaking_epoch_length,
+            max_transition_epochs,
+        };
+        let vault = ToggleCheck {
+            slot,
+            epoch_length: self.vault_epoch_length,
+            max_transition_epochs,
+        };
+        let mut findings = Vec::new();
+
+        for (pubkey, state) in &self.ncn_operator_states {
+            restaking.check_transition(
+                pubkey,
+                "NCN opt-in",
+                &state.ncn_opt_in_state,
+                &mut findings,
+            );
+            restaking.check_transition(
+                pubkey,
+                "operator opt-in",
+                &state.operator_opt_in_state,
+                &mut findings,
+            );
+
+            let ncn_opted_in = restaking.is_opted_in(&state.ncn_opt_in_state);
+            let operator_opted_in = restaking.is_opted_in(&state.operator_opt_in_state);
+            if ncn_opted_in != operator_opted_in {
+                let (opted_in, other) = if ncn_opted_in {
+                    (
+                        format!("NCN {}", state.ncn),
+                        format!("operator {}", state.operator),
+                    )
+                } else {
+                    (
+                        format!("operator {}", state.operator),
+                        format!("NCN {}", state.ncn),
+                    )
+                };
+                findings.push(Finding {
+                    kind: FindingKind::OneSidedNcnOperatorState,
+                    account: *pubkey,
+                    detail: format!("{opted_in} opted in, {other} did not"),
+                });
+            }
+
+            if state
+                .is_jailed(slot, self.restaking_epoch_length)
+                .unwrap_or_default()
+            {
+                findings.push(Finding {
+                    kind: FindingKind::JailedOperator,
+                    account: *pubkey,
+                    detail: format!(
+                        "Operator {} jailed by NCN {} until epoch {}",
+                        state.operator,
+                        state.ncn,
+                        state.jailed_until_epoch()
+                    ),
+                });
+            }
+        }
+
+        let vault_ncn_tickets: HashMap<(Pubkey, Pubkey), &SlotToggle> = self
+            .vault_ncn_tickets
+            .iter()
+            .map(|(_, ticket)| ((ticket.vault, ticket.ncn), &ticket.state))
+            .collect();
+        let ncn_vault_tickets: HashMap<(Pubkey, Pubkey), &SlotToggle> = self
+            .ncn_vault_tickets
+            .iter()
+            .map(|(_, ticket)| ((ticket.vault, ticket.ncn), &ticket.state))
+            .collect();
+
+        for (pubkey, ticket) in &self.ncn_vault_tickets {
+            restaking.check_transition(pubkey, "NCN vault ticket", &ticket.state, &mut findings);
+            if restaking.is_opted_in(&ticket.state) {
+                let matching = vault_ncn_tickets.get(&(ticket.vault, ticket.ncn));
+                if let Some(detail) = vault.unmatched(matching.copied(), "VaultNcnTicket") {
+                    findings.push(Finding {
+                        kind: FindingKind::UnmatchedNcnVaultTicket,
+                        account: *pubkey,
+                        detail: format!(
+                            "NCN {} opted in to vault {}, {detail}",
+                            ticket.ncn, ticket.vault
+                        ),
+                    });
+                }
+            }
+        }
+
+        for (pubkey, ticket) in &self.vault_ncn_tickets {
+            vault.check_transition(pubkey, "vault NCN ticket", &ticket.state, &mut findings);
+            if vault.is_opted_in(&ticket.state) {
+                let matching = ncn_vault_tickets.get(&(ticket.vault, ticket.ncn));
+                if let Some(detail) = restaking.unmatched(matching.copied(), "NcnVaultTicket") {
+                    findings.push(Finding {
+                        kind: FindingKind::UnmatchedVaultNcnTicket,
+                        account: *pubkey,
+                        detail: format!(
+                            "Vault {} opted in to NCN {}, {detail}",
+                            ticket.vault, ticket.ncn
+                        ),
+                    });
+                }
+            }
+        }
+
+        let delegations: Vec<(Pubkey, Pubkey)> = self
+            .vault_operator_delegations
+            .iter()
+            .map(|(_, delegation)| (delegation.vault, delegation.operator))
+            .collect();
+
+        for (pubkey, ticket) in &self.operator_vault_tickets {
+            restaking.check_transition(
+                pubkey,
+                "operator vault ticket",
+                &ticket.state,
+                &mut findings,
+            );
+            if restaking.is_opted_in(&ticket.state)
+                && !delegations.contains(&(ticket.vault, ticket.operator))
+            {
+                findings.push(Finding {
+                    kind: FindingKind::UnmatchedOperatorVaultTicket,
+                    account: *pubkey,
+                    detail: format!(
+                        "Operator {} opted in to vault {}, which has no VaultOperatorDelegation",
+                        ticket.operator, ticket.vault
+                    ),
+                });
+            }
+        }
+
+        let vault_ncn_slasher_tickets: HashMap<(Pubkey, Pubkey, Pubkey), &SlotToggle> = self
+            .vault_ncn_slasher_tickets
+            .iter()
+            .map(|(_, ticket)| ((ticket.vault, ticket.ncn, ticket.slasher), &ticket.state))
+            .collect();
+
+        for (pubkey, ticket) in &self.ncn_vault_slasher_tickets {
+            restaking.check_transition(
+                pubkey,
+                "NCN vault slasher ticket",
+                &ticket.state,
+                &mut findings,
+            );
+            if restaking.is_opted_in(&ticket.state) {
+                let matching =
+                    vault_ncn_slasher_tickets.get(&(ticket.vault, ticket.ncn, ticket.slasher));
+                if let Some(detail) = vault.unmatched(matching.copied(), "VaultNcnSlasherTicket") {
+                    findings.push(Finding {
+                        kind: FindingKind::UnmatchedNcnVaultSlasherTicket,
+                        account: *pubkey,
+                        detail: format!(
+                            "NCN {} opted in slasher {} to vault {}, {detail}",
+                            ticket.ncn, ticket.slasher, ticket.vault
+                        ),
+                    });
+                }
+            }
+        }
+
+        for (pubkey, ticket) in &self.vault_ncn_slasher_tickets {
+            vault.check_transition(
+                pubkey,
+                "vault NCN slasher ticket",
+                &ticket.state,
+                &mut findings,
+            );
+        }
+
+        findings
+    }
+}
+
+/// Logs the findings, emits a datapoint for each of them, and sets the finding metrics.
+pub fn report_findings(findings: &[Finding]) {
+    for finding in findings {
+        warn!("Restaking audit: {finding}");
+        datapoint_warn!(
+            "restaking-cranker-audit-finding",
+            "kind" => finding.kind.as_str(),
+            "account" => finding.account.to_string(),
+            "detail" => finding.detail,
+        );
+    }
+
+    for kind in FindingKind::ALL {
+        let count = findings.iter().filter(|f| f.kind == kind).count();
+        METRICS
+            .audit_findings
+            .with_label_values(&[kind.as_str()])
+            .set(count as i64);
+    }
+}
+
+/// Checks the [`SlotToggle`]s of one program at a slot
+struct ToggleCheck {
+    slot: u64,
+    epoch_length: u64,
+    max_transition_epochs: u64,
+}
+
+impl ToggleCheck {
+    fn state(&self, toggle: &SlotToggle) -> Option<SlotToggleState> {
+        toggle.state(self.slot, self.epoch_length).ok()
+    }
+
+    /// Whether the toggle is warming up or active
+    fn is_opted_in(&self, toggle: &SlotToggle) -> bool {
+        matches!(
+            self.state(toggle),
+            Some(SlotToggleState::WarmUp | SlotToggleState::Active)
+        )
+    }
+
+    /// Returns why the counterpart of an opted in ticket doesn't match it, if it doesn't.
+    fn unmatched(&self, counterpart: Option<&SlotToggle>, name: &str) -> Option<String> {
+        match counterpart {
+            None => Some(format!("which has no {name}")),
+            Some(toggle) if !self.is_opted_in(toggle) => Some(self.state(toggle).map_or_else(
+                || format!("its {name} state is invalid"),
+                |state| format!("its {name} is {state:?}"),
+            )),
+            Some(_) => None,
+        }
+    }
+
+    /// Reports the toggle if it warms up or cools down for more than the maximum transition
+    /// epochs.
+    fn check_transition(
+        &self,
+        account: &Pubkey,
+        name: &str,
+        toggle: &SlotToggle,
+        findings: &mut Vec<Finding>,
+    ) {
+        let Some(state) = self.state(toggle) else {
+            return;
+        };
+        let transition_slot = match state {
+            SlotToggleState::WarmUp => toggle.slot_added(),
+            SlotToggleState::Cooldown => toggle.slot_removed(),
+            SlotToggleState::Inactive | SlotToggleState::Active => return,
+        };
+        let (Ok(epoch), Ok(transition_epoch)) = (
+            get_epoch(self.slot, self.epoch_length),
+            get_epoch(transition_slot, self.epoch_length),
+        ) else {
+            return;
+        };
+
+        let epochs = epoch.saturating_sub(transition_epoch);
+        if epochs > self.max_transition_epochs {
+            findings.push(Finding {
+                kind: FindingKind::StuckTransition,
+                account: *account,
+                detail: format!("{name} in {state:?} for {epochs} epochs"),
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::slot_toggle::TransitionEpochs;
+
+    use super::*;
+
+    const EPOCH_LENGTH: u64 = 100;
+    const SLOT: u64 = 1_000;
+    const MAX_TRANSITION_EPOCHS: u64 = 4;
+
+    fn empty_graph() -> TicketGraph {
+        TicketGraph {
+            restaking_epoch_length: EPOCH_LENGTH,
+            vault_epoch_length: EPOCH_LENGTH,
+            ncn_operator_states: Vec::new(),
+            ncn_vault_tickets: Vec::new(),
+            operator_vault_tickets: Vec::new(),
+            ncn_vault_slasher_tickets: Vec::new(),
+            vault_ncn_tickets: Vec::new(),
+            vault_operator_delegations: Vec::new(),
+            vault_ncn_slasher_tickets: Vec::new(),
+        }
+    }
+
+    /// Activates the toggle at slot 100, so it is active at [`SLOT`]
+    fn activate(toggle: &mut SlotToggle) {
+        assert!(toggle.activate(100, EPOCH_LENGTH).unwrap());
+    }
+
+    fn kinds(findings: &[Finding]) -> Vec<FindingKind> {
+        findings.iter().map(|finding| finding.kind).collect()
+    }
+
+    #[test]
+    fn test_audit_empty_graph() {
+        assert!(empty_graph().audit(SLOT, MAX_TRANSITION_EPOCHS).is_empty());
+    }
+
+    #[test]
+    fn test_audit_stuck_transition() {
+        let mut graph = empty_graph();
+        let mut ticket = NcnVaultTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        ticket
+            .state
+            .activate_with_transition_epochs(500, EPOCH_LENGTH, TransitionEpochs::new(10, 10))
+            .unwrap();
+        let mut vault_ticket = VaultNcnTicket::new(ticket.vault, ticket.ncn, 0, 0, 0);
+        activate(&mut vault_ticket.state);
+        let pubkey = Pubkey::new_unique();
+        graph.ncn_vault_tickets.push((pubkey, ticket));
+        graph
+            .vault_ncn_tickets
+            .push((Pubkey::new_unique(), vault_ticket));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::StuckTransition]);
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            "NCN vault ticket in WarmUp for 5 epochs"
+        );
+
+        // Within the maximum transition epochs
+        assert!(graph.audit(SLOT, 5).is_empty());
+    }
+
+    #[test]
+    fn test_audit_one_sided_ncn_operator_state() {
+        let mut graph = empty_graph();
+        let mut state = NcnOperatorState::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut state.ncn_opt_in_state);
+        let pubkey = Pubkey::new_unique();
+        graph.ncn_operator_states.push((pubkey, state));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(
+            kinds(&findings),
+            vec![FindingKind::OneSidedNcnOperatorState]
+        );
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "NCN {} opted in, operator {} did not",
+                state.ncn, state.operator
+            )
+        );
+
+        // Both sides opted in
+        activate(&mut graph.ncn_operator_states[0].1.operator_opt_in_state);
+        assert!(graph.audit(SLOT, MAX_TRANSITION_EPOCHS).is_empty());
+    }
+
+    #[test]
+    fn test_audit_one_sided_ncn_operator_state_operator_side() {
+        let mut graph = empty_graph();
+        let mut state = NcnOperatorState::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut state.operator_opt_in_state);
+        graph
+            .ncn_operator_states
+            .push((Pubkey::new_unique(), state));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(
+            kinds(&findings),
+            vec![FindingKind::OneSidedNcnOperatorState]
+        );
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "operator {} opted in, NCN {} did not",
+                state.operator, state.ncn
+            )
+        );
+    }
+
+    #[test]
+    fn test_audit_jailed_operator() {
+        let mut graph = empty_graph();
+        let mut state = NcnOperatorState::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut state.ncn_opt_in_state);
+        activate(&mut state.operator_opt_in_state);
+        state.jail(12);
+        let pubkey = Pubkey::new_unique();
+        graph.ncn_operator_states.push((pubkey, state));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::JailedOperator]);
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "Operator {} jailed by NCN {} until epoch 12",
+                state.operator, state.ncn
+            )
+        );
+
+        // The jail ends on its own
+        assert!(graph
+            .audit(12 * EPOCH_LENGTH, MAX_TRANSITION_EPOCHS)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_audit_unmatched_ncn_vault_ticket() {
+        let mut graph = empty_graph();
+        let mut ticket = NcnVaultTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut ticket.state);
+        let pubkey = Pubkey::new_unique();
+        graph.ncn_vault_tickets.push((pubkey, ticket));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::UnmatchedNcnVaultTicket]);
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "NCN {} opted in to vault {}, which has no VaultNcnTicket",
+                ticket.ncn, ticket.vault
+            )
+        );
+
+        // The vault ticket exists but the vault never opted in
+        graph.vault_ncn_tickets.push((
+            Pubkey::new_unique(),
+            VaultNcnTicket::new(ticket.vault, ticket.ncn, 0, 0, 0),
+        ));
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::UnmatchedNcnVaultTicket]);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "NCN {} opted in to vault {}, its VaultNcnTicket is Inactive",
+                ticket.ncn, ticket.vault
+            )
+        );
+
+        // Both sides opted in
+        activate(&mut graph.vault_ncn_tickets[0].1.state);
+        assert!(graph.audit(SLOT, MAX_TRANSITION_EPOCHS).is_empty());
+    }
+
+    #[test]
+    fn test_audit_unmatched_vault_ncn_ticket() {
+        let mut graph = empty_graph();
+        let mut ticket = VaultNcnTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut ticket.state);
+        let pubkey = Pubkey::new_unique();
+        graph.vault_ncn_tickets.push((pubkey, ticket));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::UnmatchedVaultNcnTicket]);
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "Vault {} opted in to NCN {}, which has no NcnVaultTicket",
+                ticket.vault, ticket.ncn
+            )
+        );
+
+        // The NCN opted out and is cooling down, which doesn't count as opted in
+        let mut ncn_ticket = NcnVaultTicket::new(ticket.ncn, ticket.vault, 0, 0, 0);
+        activate(&mut ncn_ticket.state);
+        assert!(ncn_ticket.state.deactivate(SLOT, EPOCH_LENGTH).unwrap());
+        graph
+            .ncn_vault_tickets
+            .push((Pubkey::new_unique(), ncn_ticket));
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(kinds(&findings), vec![FindingKind::UnmatchedVaultNcnTicket]);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "Vault {} opted in to NCN {}, its NcnVaultTicket is Cooldown",
+                ticket.vault, ticket.ncn
+            )
+        );
+    }
+
+    #[test]
+    fn test_audit_unmatched_operator_vault_ticket() {
+        let mut graph = empty_graph();
+        let mut ticket =
+            OperatorVaultTicket::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0, 0);
+        activate(&mut ticket.state);
+        let pubkey = Pubkey::new_unique();
+        graph.operator_vault_tickets.push((pubkey, ticket));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(
+            kinds(&findings),
+            vec![FindingKind::UnmatchedOperatorVaultTicket]
+        );
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "Operator {} opted in to vault {}, which has no VaultOperatorDelegation",
+                ticket.operator, ticket.vault
+            )
+        );
+
+        // The vault added the operator
+        graph.vault_operator_delegations.push((
+            Pubkey::new_unique(),
+            VaultOperatorDelegation::new(ticket.vault, ticket.operator, 0, 0, 0),
+        ));
+        assert!(graph.audit(SLOT, MAX_TRANSITION_EPOCHS).is_empty());
+    }
+
+    #[test]
+    fn test_audit_unmatched_ncn_vault_slasher_ticket() {
+        let mut graph = empty_graph();
+        let mut ticket = NcnVaultSlasherTicket::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1_000,
+            0,
+            0,
+            0,
+        );
+        activate(&mut ticket.state);
+        let pubkey = Pubkey::new_unique();
+        graph.ncn_vault_slasher_tickets.push((pubkey, ticket));
+
+        let findings = graph.audit(SLOT, MAX_TRANSITION_EPOCHS);
+        assert_eq!(
+            kinds(&findings),
+            vec![FindingKind::UnmatchedNcnVaultSlasherTicket]
+        );
+        assert_eq!(findings[0].account, pubkey);
+        assert_eq!(
+            findings[0].detail,
+            format!(
+                "NCN {} opted in slasher {} to vault {}, which has no VaultNcnSlasherTicket",
+                ticket.ncn, ticket.slasher, ticket.vault
+            )
+        );
+
+        // A slasher ticket for another slasher doesn't match
+        let mut vault_ticket = VaultNcnSlasherTicket::new(
+            ticket.vault,
+            ticket.ncn,
+            Pubkey::new_unique(),
+            1_000,
+            0,
+            0,
+            0,
+        );
+        activate(&mut vault_ticket.state);
+        graph
+            .vault_ncn_slasher_tickets
+            .push((Pubkey::new_unique(), vault_ticket));
+        assert_eq!(
+            kinds(&graph.audit(SLOT, MAX_TRANSITION_EPOCHS)),
+            vec![FindingKind::UnmatchedNcnVaultSlasherTicket]
+        );
+
+        graph.vault_ncn_slasher_tickets[0].1.slasher = ticket.slasher;
+        assert!(graph.audit(SLOT, MAX_TRANSITION_EPOCHS).is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+//! Per-vault exponential backoff and quarantine
+//!
+//! A vault whose update fails is skipped by the following crank loops until its backoff delay
+//! elapses. The delay doubles with each consecutive failure, up to the maximum, with a random
+//! jitter so failing vaults don't retry in lockstep. After too many consecutive failures the vault
+//! is quarantined until the next epoch, so one bad vault can't use up the RPC quota of the others.
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use log::{error, warn};
+use rand::Rng;
+use solana_metrics::datapoint_error;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::prometheus_exporter::METRICS;
+
+pub struct VaultBackoff {
+    /// The delay after the first failure
+    base: Duration,
+    /// The longest delay
+    max: Duration,
+    /// The consecutive failures after which a vault is quarantined
+    quarantine_after: u32,
+    state: Mutex<BackoffState>,
+}
+
+#[derive(Default)]
+struct BackoffState {
+    epoch: u64,
+    vaults: HashMap<Pubkey, Failures>,
+}
+
+struct Failures {
+    count: u32,
+    retry_at: Instant,
+}
+
+impl VaultBackoff {
+    pub fn new(base: Duration, max: Duration, quarantine_after: u32) -> Self {
+        Self {
+            base,
+            max,
+            quarantine_after,
+            state: Mutex::new(BackoffState::default()),
+        }
+    }
+
+    /// Forgets the failures and quarantine of previous epochs once `epoch` starts.
+    pub fn start_epoch(&self, epoch: u64) {
+        let mut state = self.state.lock().unwrap();
+        if state.epoch != epoch {
+            state.epoch = epoch;
+            state.vaults.clear();
+            self.update_metrics(&state);
+        }
+        drop(state);
+    }
+
+    /// Whether `vault` can be updated now, neither backing off nor quarantined.
+    pub fn is_ready(&self, vault: &Pubkey) -> bool {
+        let state = self.state.lock().unwrap();
+        state.vaults.get(vault).is_none_or(|failures| {
+            !self.is_quarantined(failures) && failures.retry_at <= Instant::now()
+        })
+    }
+
+    pub fn record_success(&self, vault: &Pubkey) {
+        let mut state = self.state.lock().unwrap();
+        if state.vaults.remove(vault).is_some() {
+            self.update_metrics(&state);
+        }
+    }
+
+    /// Records a failed update of `vault`, backing it off or quarantining it.
+    pub fn record_failure(&self, vault: &Pubkey) {
+        let mut state = self.state.lock().unwrap();
+        let failures = state.vaults.entry(*vault).or_insert_with(|| Failures {
+            count: 0,
+            retry_at: Instant::now(),
+        });
+        failures.count = failures.count.saturating_add(1);
+
+        if self.is_quarantined(failures) {
+            error!(
+                "Vault {vault} quarantined until the next epoch after {} consecutive failures",
+                failures.count
+            );
+            datapoint_error!(
+                "restaking-cranker-vault-quarantined",
+                "vault" => vault.to_string(),
+                ("failures", failures.count as i64, i64),
+            );
+        } else {
+            let delay = backoff_delay(
+                self.base,
+                self.max,
+                failures.count,
+                rand::thread_rng().gen(),
+            );
+            failures.retry_at = Instant::now() + delay;
+            warn!(
+                "Vault {vault} failed {} times in a row, retrying in {:.0}s",
+                failures.count,
+                delay.as_secs_f64()
+            );
+        }
+
+        self.update_metrics(&state);
+        drop(state);
+    }
+
+    /// The vaults quarantined until the next epoch
+    pub fn quarantined(&self) -> Vec<Pubkey> {
+        let state = self.state.lock().unwrap();
+        state
+            .vaults
+            .iter()
+            .filter(|(_, failures)| self.is_quarantined(failures))
+            .map(|(vault, _)| *vault)
+            .collect()
+    }
+
+    const fn is_quarantined(&self, failures: &Failures) -> bool {
+        failures.count >= self.quarantine_after
+    }
+
+    fn update_metrics(&self, state: &BackoffState) {
+        METRICS.quarantined_vaults.reset();
+        let mut backing_off = 0;
+        for (vault, failures) in &state.vaults {
+            if self.is_quarantined(failures) {
+                METRICS
+                    .quarantined_vaults
+                    .with_label_values(&[&vault.to_string()])
+                    .set(1);
+            } else {
+                backing_off += 1;
+            }
+        }
+        METRICS.vaults_backing_off.set(backing_off);
+    }
+}
+
+/// Returns the delay before retrying a vault after `failures` consecutive failures.
+///
+/// The base delay is doubled for each failure after the first, up to `max`, and the second half
+/// of the delay is scaled by `jitter`, from 0 to 1.
+pub fn backoff_delay(base: Duration, max: Duration, failures: u32, jitter: f64) -> Duration {
+    let delay = base
+        .saturating_mul(2u32.saturating_pow(failures.saturating_sub(1)))
+        .min(max);
+    delay / 2 + (delay / 2).mul_f64(jitter.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: Duration = Duration::from_secs(10);
+    const MAX: Duration = Duration::from_secs(300);
+
+    #[test]
+    fn test_backoff_delay_doubles() {
+        assert_eq!(backoff_delay(BASE, MAX, 1, 1.0), Duration::from_secs(10));
+        assert_eq!(backoff_delay(BASE, MAX, 2, 1.0), Duration::from_secs(20));
+        assert_eq!(backoff_delay(BASE, MAX, 3, 1.0), Duration::from_secs(40));
+    }
+
+    #[test]
+    fn test_backoff_delay_capped() {
+        assert_eq!(backoff_delay(BASE, MAX, 6, 1.0), MAX);
+        assert_eq!(backoff_delay(BASE, MAX, u32::MAX, 1.0), MAX);
+    }
+
+    #[test]
+    fn test_backoff_delay_jitter() {
+        assert_eq!(backoff_delay(BASE, MAX, 1, 0.0), Duration::from_secs(5));
+        assert_eq!(
+            backoff_delay(BASE, MAX, 1, 0.5),
+            Duration::from_millis(7_500)
+        );
+        // Out of range jitter is clamped
+        assert_eq!(backoff_delay(BASE, MAX, 1, -1.0), Duration::from_secs(5));
+        assert_eq!(backoff_delay(BASE, MAX, 1, 2.0), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_backoff_delay_without_failures() {
+        assert_eq!(backoff_delay(BASE, MAX, 0, 1.0), BASE);
+    }
+
+    #[test]
+    fn test_vault_backoff_failure_and_success() {
+        let backoff = VaultBackoff::new(BASE, MAX, 3);
+        let vault = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        assert!(backoff.is_ready(&vault));
+
+        backoff.record_failure(&vault);
+        assert!(!backoff.is_ready(&vault));
+        assert!(backoff.is_ready(&other));
+        assert!(backoff.quarantined().is_empty());
+
+        backoff.record_success(&vault);
+        assert!(backoff.is_ready(&vault));
+    }
+
+    #[test]
+    fn test_vault_backoff_ready_after_delay() {
+        let backoff = VaultBackoff::new(Duration::ZERO, Duration::ZERO, 3);
+        let vault = Pubkey::new_unique();
+
+        backoff.record_failure(&vault);
+        assert!(backoff.is_ready(&vault));
+    }
+
+    #[test]
+    fn test_vault_backoff_quarantine() {
+        let backoff = VaultBackoff::new(Duration::ZERO, Duration::ZERO, 2);
+        let vault = Pubkey::new_unique();
+        backoff.start_epoch(1);
+
+        backoff.record_failure(&vault);
+        assert!(backoff.quarantined().is_empty());
+        backoff.record_failure(&vault);
+        assert_eq!(backoff.quarantined(), vec![vault]);
+        // Quarantined vaults stay skipped even without a delay
+        assert!(!backoff.is_ready(&vault));
+
+        // The same epoch keeps the quarantine
+        backoff.start_epoch(1);
+        assert!(!backoff.is_ready(&vault));
+
+        backoff.start_epoch(2);
+        assert!(backoff.is_ready(&vault));
+        assert!(backoff.quarantined().is_empty());
+    }
+}
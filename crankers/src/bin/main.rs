@@ -1,8 +1,10 @@
 use std::{
     collections::HashMap,
     fmt,
+    net::SocketAddr,
     path::PathBuf,
     process::Command,
+    str::FromStr,
     sync::Arc,
     time::{Duration, Instant},
 };
@@ -12,13 +14,33 @@ use clap::{arg, Parser, ValueEnum};
 use dotenvy::dotenv;
 use jito_jsm_core::get_epoch;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
-use jito_vault_cranker::{metrics::emit_vault_metrics, vault_handler::VaultHandler};
-use log::{error, info};
+use jito_vault_cranker::{
+    audit::{report_findings, TicketGraph},
+    backoff::VaultBackoff,
+    metrics::emit_vault_metrics,
+    payer_pool::{PayerPool, PayerSelection},
+    priority_fees::{PriorityFeeStrategy, PriorityFees},
+    prometheus_exporter::{serve, Health, METRICS},
+    rate_limiter::{new_rpc_client, RateLimiter},
+    restaking_handler::RestakingHandler,
+    state::{StateStore, UpdateStage},
+    subscriptions::Subscriptions,
+    vault_handler::VaultHandler,
+};
+use log::{error, info, warn};
 use solana_metrics::set_host_id;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_sdk::{pubkey::Pubkey, signature::read_keypair_file};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    pubkey::{ParsePubkeyError, Pubkey},
+    signature::{read_keypair_file, Keypair},
+};
+use tokio::sync::Semaphore;
 
 #[derive(Parser)]
 struct Args {
@@ -34,9 +56,18 @@ struct Args {
     #[arg(long, env, default_value = "local")]
     region: String,
 
-    /// Path to keypair used to pay
-    #[arg(short, long, env)]
-    keypair_path: PathBuf,
+    /// Paths to the keypairs paying for the cranks (comma separated). The first one is the primary
+    /// payer, which owns the address lookup tables.
+    #[arg(short, long, env, value_delimiter = ',', required = true)]
+    keypair_path: Vec<PathBuf>,
+
+    /// How each vault update picks its payer from the keypairs
+    #[arg(long, env, value_enum, default_value_t = PayerSelection::RoundRobin)]
+    payer_selection: PayerSelection,
+
+    /// Balance in lamports under which a payer is skipped and alerted on
+    #[arg(long, env, default_value_t = 100_000_000)]
+    min_payer_balance: u64,
 
     /// Vault program ID (Pubkey as base58 string)
     #[arg(
@@ -58,13 +89,190 @@ struct Args {
     #[arg(long, env, default_value = "300")]
     crank_interval: u64,
 
+    /// Websocket URL of the RPC. When set, the cranker starts as soon as the epoch flips and
+    /// caches the vault program accounts instead of polling them
+    #[arg(long, env)]
+    websocket_url: Option<String>,
+
     /// Interval in seconds between metrics emission (default: 5 minutes)
     #[arg(long, env, default_value = "300")]
     metrics_interval: u64,
 
-    /// Priority fees (in microlamports per compute unit)
+    /// Priority fees (in microlamports per compute unit), the fallback of the dynamic strategy
     #[arg(long, env, default_value = "10000")]
     priority_fees: u64,
+
+    /// How the priority fee of transactions is set
+    #[arg(long, env, value_enum, default_value = "fixed")]
+    priority_fee_strategy: PriorityFeeStrategy,
+
+    /// Percentile of the recent prioritization fees used by the dynamic strategy
+    #[arg(long, env, default_value = "75", value_parser = clap::value_parser!(u8).range(0..=100))]
+    priority_fee_percentile: u8,
+
+    /// Lowest priority fee of the dynamic strategy (in microlamports per compute unit)
+    #[arg(long, env, default_value = "0")]
+    min_priority_fee: u64,
+
+    /// Highest priority fee of the dynamic strategy and of retries (in microlamports per compute
+    /// unit)
+    #[arg(long, env, default_value = "1000000")]
+    max_priority_fee: u64,
+
+    /// Increase of the priority fee on each retry of a transaction, in percent
+    #[arg(long, env, default_value = "0")]
+    priority_fee_escalation: u64,
+
+    /// Only crank these vaults (comma separated Pubkeys, all vaults when empty)
+    #[arg(long = "vault", env = "VAULTS", default_value = "", value_parser = parse_pubkeys)]
+    vaults: Pubkeys,
+
+    /// Never crank these vaults (comma separated Pubkeys)
+    #[arg(
+        long = "exclude-vault",
+        env = "EXCLUDE_VAULTS",
+        default_value = "",
+        value_parser = parse_pubkeys
+    )]
+    exclude_vaults: Pubkeys,
+
+    /// Only crank vaults with one of these supported mints (comma separated Pubkeys, all mints when
+    /// empty)
+    #[arg(
+        long = "supported-mint",
+        env = "SUPPORTED_MINTS",
+        default_value = "",
+        value_parser = parse_pubkeys
+    )]
+    supported_mints: Pubkeys,
+
+    /// Simulate the update of each vault that needs one, print the plans and exit without sending
+    /// any transaction
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Audit the restaking ticket graph, print the half-configured or inconsistent relationships
+    /// and exit
+    #[arg(long, conflicts_with = "dry_run")]
+    audit: bool,
+
+    /// Interval in seconds between restaking audits while cranking, 0 to disable them
+    #[arg(long, env, default_value = "0")]
+    audit_interval: u64,
+
+    /// Number of epochs a ticket can warm up or cool down for before the audit reports it as stuck
+    #[arg(long, env, default_value = "2")]
+    audit_max_transition_epochs: u64,
+
+    /// Vaults to call `UpdateVaultBalance` on after each full update (comma separated Pubkeys)
+    #[arg(long, env, default_value = "", value_parser = parse_pubkeys)]
+    update_balance_vaults: Pubkeys,
+
+    /// Vaults whose withdrawable tickets are burned for their stakers (comma separated Pubkeys)
+    #[arg(long, env, default_value = "", value_parser = parse_pubkeys)]
+    burn_ticket_vaults: Pubkeys,
+
+    /// Send crank transactions as legacy transactions only, without address lookup tables
+    #[arg(long, env)]
+    disable_lookup_tables: bool,
+
+    /// Maximum number of vaults updated at the same time
+    #[arg(long, env, default_value = "8", value_parser = clap::value_parser!(u64).range(1..))]
+    max_concurrent_vaults: u64,
+
+    /// Maximum RPC requests per second over all the requests of the cranker, 0 for no limit
+    #[arg(long, env, default_value = "0")]
+    rpc_rate_limit: u32,
+
+    /// Delay in seconds before retrying a vault after its first failed update, doubled after each
+    /// consecutive failure
+    #[arg(long, env, default_value = "60")]
+    vault_backoff_base: u64,
+
+    /// Longest delay in seconds before retrying a vault
+    #[arg(long, env, default_value = "3600")]
+    vault_backoff_max: u64,
+
+    /// Consecutive failed updates after which a vault is quarantined until the next epoch
+    #[arg(long, env, default_value = "5", value_parser = clap::value_parser!(u32).range(1..))]
+    quarantine_after_failures: u32,
+
+    /// Path to the local state store, recording the progress of vault updates across restarts
+    #[arg(long, env)]
+    state_path: Option<PathBuf>,
+
+    /// Print the recorded vault update history from the state store as JSON and exit
+    #[arg(long, requires = "state_path")]
+    print_state: bool,
+
+    /// Metrics exporters to enable (comma separated)
+    #[arg(
+        long,
+        env,
+        value_enum,
+        value_delimiter = ',',
+        default_value = "solana-metrics"
+    )]
+    metrics_exporters: Vec<MetricsExporter>,
+
+    /// Address serving the health checks, and the metrics of the Prometheus exporter
+    #[arg(long, env, default_value = "0.0.0.0:9090")]
+    http_listen_addr: SocketAddr,
+}
+
+impl Args {
+    /// Whether the vault filters select `vault`
+    fn is_vault_selected(&self, pubkey: &Pubkey, vault: &Vault) -> bool {
+        (self.vaults.is_empty() || self.vaults.contains(pubkey))
+            && !self.exclude_vaults.contains(pubkey)
+            && (self.supported_mints.is_empty()
+                || self.supported_mints.contains(&vault.supported_mint))
+    }
+
+    /// The vault filters, identifying the scans of the state store made with them
+    fn vault_filter(&self) -> String {
+        format!(
+            "vaults={};exclude_vaults={};supported_mints={}",
+            self.vaults.to_sorted_string(),
+            self.exclude_vaults.to_sorted_string(),
+            self.supported_mints.to_sorted_string()
+        )
+    }
+}
+
+/// Number of epochs of history kept in the state store
+const STATE_RETENTION_EPOCHS: u64 = 30;
+
+/// A comma separated list of pubkeys, such as the vaults opted in to a cranker stage. Empty entries
+/// are ignored, so an empty environment variable gives an empty list.
+#[derive(Clone, Debug, Default)]
+struct Pubkeys(Vec<Pubkey>);
+
+impl Pubkeys {
+    fn contains(&self, vault: &Pubkey) -> bool {
+        self.0.contains(vault)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// The pubkeys sorted and comma separated, so that the order they were given in doesn't
+    /// matter
+    fn to_sorted_string(&self) -> String {
+        let mut pubkeys: Vec<String> = self.0.iter().map(Pubkey::to_string).collect();
+        pubkeys.sort_unstable();
+        pubkeys.join(",")
+    }
+}
+
+fn parse_pubkeys(s: &str) -> Result<Pubkeys, ParsePubkeyError> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|pubkey| !pubkey.is_empty())
+        .map(Pubkey::from_str)
+        .collect::<Result<_, _>>()
+        .map(Pubkeys)
 }
 
 impl fmt::Display for Args {
@@ -74,20 +282,66 @@ impl fmt::Display for Args {
             "Jito Vault Cranker Configuration:\n\
             -------------------------------\n\
             RPC URL: {}\n\
-            Keypair Path: {:?}\n\
+            Keypair Paths: {:?}\n\
+            Payer Selection: {:?}\n\
+            Min Payer Balance: {} lamports\n\
             Vault Program ID: {}\n\
             Restaking Program ID: {}\n\
             Crank Interval: {} seconds\n\
+            Websocket URL: {:?}\n\
             Metrics Interval: {} seconds\n\
             Priority Fees: {} microlamports\n\
+            Priority Fee Strategy: {:?}\n\
+            Priority Fee Percentile: {}\n\
+            Priority Fee Bounds: {}-{} microlamports\n\
+            Priority Fee Escalation: {}%\n\
+            Vaults: {:?}\n\
+            Excluded Vaults: {:?}\n\
+            Supported Mints: {:?}\n\
+            Update Balance Vaults: {:?}\n\
+            Burn Ticket Vaults: {:?}\n\
+            Lookup Tables: {}\n\
+            Max Concurrent Vaults: {}\n\
+            RPC Rate Limit: {} requests/s\n\
+            Vault Backoff: {}-{} seconds\n\
+            Quarantine After Failures: {}\n\
+            Audit Interval: {} seconds\n\
+            Audit Max Transition Epochs: {}\n\
+            State Path: {:?}\n\
+            Metrics Exporters: {:?}\n\
+            HTTP Listen Address: {}\n\
             -------------------------------",
             self.rpc_url,
             self.keypair_path,
+            self.payer_selection,
+            self.min_payer_balance,
             self.vault_program_id,
             self.restaking_program_id,
             self.crank_interval,
+            self.websocket_url,
             self.metrics_interval,
             self.priority_fees,
+            self.priority_fee_strategy,
+            self.priority_fee_percentile,
+            self.min_priority_fee,
+            self.max_priority_fee,
+            self.priority_fee_escalation,
+            self.vaults.0,
+            self.exclude_vaults.0,
+            self.supported_mints.0,
+            self.update_balance_vaults.0,
+            self.burn_ticket_vaults.0,
+            !self.disable_lookup_tables,
+            self.max_concurrent_vaults,
+            self.rpc_rate_limit,
+            self.vault_backoff_base,
+            self.vault_backoff_max,
+            self.quarantine_after_failures,
+            self.audit_interval,
+            self.audit_max_transition_epochs,
+            self.state_path,
+            self.metrics_exporters,
+            self.http_listen_addr,
         )
     }
 }
@@ -99,6 +353,14 @@ pub enum Cluster {
     Localnet,
 }
 
+#[derive(ValueEnum, Debug, Clone, PartialEq, Eq)]
+pub enum MetricsExporter {
+    /// Pushes vault metrics to the InfluxDB configured by `SOLANA_METRICS_CONFIG`
+    SolanaMetrics,
+    /// Serves cranker metrics on `/metrics` of the HTTP listen address
+    Prometheus,
+}
+
 impl fmt::Display for Cluster {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -117,6 +379,23 @@ async fn main() -> anyhow::Result<(), anyhow::Error> {
 
     let args = Args::parse();
 
+    // A dry run leaves the state store to the running cranker
+    let state = args
+        .state_path
+        .as_ref()
+        .filter(|_| !args.dry_run)
+        .map(StateStore::open)
+        .transpose()
+        .context("open state store")?
+        .map(Arc::new);
+
+    if args.print_state {
+        if let Some(state) = &state {
+            println!("{}", serde_json::to_string_pretty(&state.records()?)?);
+        }
+        return Ok(());
+    }
+
     info!("{}", args);
 
     let hostname_cmd = Command::new("hostname")
@@ -132,120 +411,634 @@ async fn main() -> anyhow::Result<(), anyhow::Error> {
         args.region, args.cluster, hostname
     ));
 
-    let rpc_client = RpcClient::new_with_timeout(args.rpc_url.clone(), Duration::from_secs(60));
+    let rate_limiter =
+        (args.rpc_rate_limit > 0).then(|| Arc::new(RateLimiter::new(args.rpc_rate_limit)));
+    let rpc_client = new_rpc_client(
+        &args.rpc_url,
+        Duration::from_secs(60),
+        CommitmentConfig::default(),
+        rate_limiter.clone(),
+    );
     let config_address = Config::find_program_address(&args.vault_program_id).0;
 
+    let keypairs = args
+        .keypair_path
+        .iter()
+        .map(|path| {
+            read_keypair_file(path)
+                .map_err(|e| anyhow!("Failed to read keypair file {}: {}", path.display(), e))
+        })
+        .collect::<anyhow::Result<Vec<Keypair>>>()?;
+    let payer_pool = Arc::new(PayerPool::new(
+        keypairs,
+        args.payer_selection,
+        args.min_payer_balance,
+    )?);
+    info!("Payers: {:?}", payer_pool.pubkeys());
+
+    // The lookup table of each vault is remembered by the state store
+    if !args.disable_lookup_tables && state.is_none() {
+        warn!("Address lookup tables need --state-path, sending legacy transactions only");
+    }
+    let lookup_table_authority =
+        (!args.disable_lookup_tables && state.is_some()).then(|| payer_pool.primary());
+
     let vault_handler = Arc::new(VaultHandler::new(
         &args.rpc_url,
         args.vault_program_id,
         config_address,
-        args.priority_fees,
+        PriorityFees {
+            strategy: args.priority_fee_strategy,
+            fee: args.priority_fees,
+            percentile: args.priority_fee_percentile,
+            min_fee: args.min_priority_fee,
+            max_fee: args.max_priority_fee,
+            escalation_percent: args.priority_fee_escalation,
+        },
+        state.clone(),
+        lookup_table_authority.clone(),
+        rate_limiter.clone(),
     ));
 
-    // Track vault metrics in separate thread
+    let restaking_handler = Arc::new(RestakingHandler::new(
+        &args.rpc_url,
+        args.restaking_program_id,
+        rate_limiter.clone(),
+    ));
+
+    if args.audit {
+        return audit(
+            &args,
+            &rpc_client,
+            &restaking_handler,
+            &vault_handler,
+            config_address,
+        )
+        .await;
+    }
+
+    if args.dry_run {
+        return dry_run(
+            &args,
+            &rpc_client,
+            &vault_handler,
+            config_address,
+            &payer_pool.primary(),
+        )
+        .await;
+    }
+
+    // A crank loop can take several minutes when many vaults need an update
+    let health = Arc::new(Health::new(Duration::from_secs(
+        args.crank_interval.saturating_mul(3).max(600),
+    )));
+
+    // Serve health checks and Prometheus metrics in separate thread
     tokio::spawn({
-        let config: Config = vault_handler
-            .get_vault_program_account(&config_address)
-            .await?;
-        let epoch_length = config.epoch_length();
+        let health = health.clone();
+        let export_metrics = args
+            .metrics_exporters
+            .contains(&MetricsExporter::Prometheus);
         async move {
-            let metrics_client = RpcClient::new_with_timeout(args.rpc_url, Duration::from_secs(60));
-            loop {
-                if let Err(e) =
-                    emit_vault_metrics(&metrics_client, epoch_length, &args.cluster.to_string())
+            if let Err(e) = serve(args.http_listen_addr, health, export_metrics).await {
+                error!("Failed to serve health checks: {}", e);
+            }
+        }
+    });
+
+    // Track vault metrics in separate thread
+    if args
+        .metrics_exporters
+        .contains(&MetricsExporter::SolanaMetrics)
+    {
+        tokio::spawn({
+            let config: Config = vault_handler
+                .get_vault_program_account(&config_address)
+                .await?;
+            let epoch_length = config.epoch_length();
+            let rpc_url = args.rpc_url.clone();
+            let cluster = args.cluster.to_string();
+            let rate_limiter = rate_limiter.clone();
+            async move {
+                let metrics_client = new_rpc_client(
+                    &rpc_url,
+                    Duration::from_secs(60),
+                    CommitmentConfig::default(),
+                    rate_limiter.clone(),
+                );
+                loop {
+                    if let Err(e) = emit_vault_metrics(
+                        &metrics_client,
+                        epoch_length,
+                        &cluster,
+                        rate_limiter.clone(),
+                    )
+                    .await
+                    {
+                        error!("Failed to emit metrics: {}", e);
+                    }
+                    tokio::time::sleep(Duration::from_secs(args.metrics_interval)).await;
+                }
+            }
+        });
+    }
+
+    // Audit the restaking ticket graph in separate thread
+    if args.audit_interval > 0 {
+        tokio::spawn({
+            let restaking_handler = restaking_handler.clone();
+            let vault_handler = vault_handler.clone();
+            let rpc_url = args.rpc_url.clone();
+            let rate_limiter = rate_limiter.clone();
+            async move {
+                let audit_client = new_rpc_client(
+                    &rpc_url,
+                    Duration::from_secs(60),
+                    CommitmentConfig::default(),
+                    rate_limiter,
+                );
+                loop {
+                    match TicketGraph::fetch(&restaking_handler, &vault_handler, &config_address)
                         .await
-                {
-                    error!("Failed to emit metrics: {}", e);
+                    {
+                        Ok(graph) => match audit_client.get_slot().await {
+                            Ok(slot) => report_findings(
+                                &graph.audit(slot, args.audit_max_transition_epochs),
+                            ),
+                            Err(e) => error!("Failed to get slot for the restaking audit: {e}"),
+                        },
+                        Err(e) => error!("Failed to fetch the restaking ticket graph: {e}"),
+                    }
+                    tokio::time::sleep(Duration::from_secs(args.audit_interval)).await;
                 }
-                tokio::time::sleep(Duration::from_secs(args.metrics_interval)).await;
             }
+        });
+    }
+
+    // Subscribe to slots and vault program accounts in separate thread
+    // Compose passes an empty URL when it is not configured
+    let websocket_url = args.websocket_url.as_deref().filter(|url| !url.is_empty());
+    let subscriptions = match websocket_url {
+        Some(websocket_url) => {
+            let subscriptions = Arc::new(Subscriptions::default());
+            let config: Config = vault_handler
+                .get_vault_program_account(&config_address)
+                .await?;
+            tokio::spawn({
+                let subscriptions = subscriptions.clone();
+                let vault_handler = vault_handler.clone();
+                let websocket_url = websocket_url.to_string();
+                let vault_program_id = args.vault_program_id;
+                async move {
+                    subscriptions
+                        .run(
+                            &websocket_url,
+                            &vault_handler,
+                            &vault_program_id,
+                            config.epoch_length(),
+                        )
+                        .await
+                }
+            });
+            Some(subscriptions)
         }
-    });
+        None => None,
+    };
+
+    let workers = Arc::new(Semaphore::new(args.max_concurrent_vaults as usize));
+    let vault_backoff = Arc::new(VaultBackoff::new(
+        Duration::from_secs(args.vault_backoff_base),
+        Duration::from_secs(args.vault_backoff_max),
+        args.quarantine_after_failures,
+    ));
+    let vault_filter = args.vault_filter();
 
     loop {
         let config: Config = vault_handler
             .get_vault_program_account(&config_address)
             .await?;
 
-        let slot = rpc_client.get_slot().await.context("get slot")?;
+        let slot = match subscriptions.as_ref().and_then(|s| s.slot()) {
+            Some(slot) => slot,
+            None => rpc_client.get_slot().await.context("get slot")?,
+        };
         let epoch = get_epoch(slot, config.epoch_length()).unwrap();
 
         info!("Checking for vaults to update. Slot: {slot}, Current Epoch: {epoch}");
 
-        let vaults = vault_handler.get_vaults().await?;
-        let delegations = vault_handler.get_vault_operator_delegations().await?;
+        METRICS.epoch.set(epoch as i64);
+        METRICS.epoch_progress.set(
+            slot.checked_rem(config.epoch_length()).unwrap_or_default() as f64
+                / config.epoch_length() as f64,
+        );
+        payer_pool.refresh_balances(&rpc_client).await;
+        vault_backoff.start_epoch(epoch);
 
-        let vaults_need_update: Vec<(Pubkey, Vault)> = vaults
-            .into_iter()
-            .filter(|(_pubkey, vault)| {
-                vault
-                    .is_update_needed(slot, config.epoch_length())
-                    .expect("Config epoch length is 0")
-            })
-            .collect();
+        let start = Instant::now();
 
-        // All delegations are passed along. Delegation filtering logic is handled in `VaultHandler::crank`
-        let mut grouped_delegations: HashMap<Pubkey, Vec<(Pubkey, VaultOperatorDelegation)>> =
-            HashMap::from_iter(vaults_need_update.iter().map(|(vault, _)| (*vault, vec![])));
-        for (pubkey, delegation) in delegations {
-            if vaults_need_update
-                .iter()
-                .any(|(vault_pubkey, _)| *vault_pubkey == delegation.vault)
+        // Every vault found by the first scan of the epoch with the same filters is updated, no
+        // need to scan again
+        let epoch_completed = match &state {
+            Some(state) => state.is_epoch_completed(epoch, &vault_filter)?,
+            None => false,
+        };
+        if epoch_completed {
+            info!("All vaults updated for epoch {epoch}");
+            METRICS.vaults_need_update.set(0);
+        } else {
+            let (vaults, delegations) = match subscriptions.as_ref().filter(|s| s.cache.is_loaded())
             {
-                grouped_delegations
-                    .entry(delegation.vault)
-                    .or_default()
-                    .push((pubkey, delegation));
+                Some(subscriptions) => (
+                    subscriptions.cache.vaults(),
+                    subscriptions.cache.vault_operator_delegations(),
+                ),
+                None => (
+                    vault_handler.get_vaults().await?,
+                    vault_handler.get_vault_operator_delegations().await?,
+                ),
+            };
+            let vaults: Vec<(Pubkey, Vault)> = vaults
+                .into_iter()
+                .filter(|(pubkey, vault)| args.is_vault_selected(pubkey, vault))
+                .collect();
+            METRICS.vaults.set(vaults.len() as i64);
+
+            let (vaults_need_update, updated_vaults): (Vec<_>, Vec<_>) =
+                vaults.into_iter().partition(|(_pubkey, vault)| {
+                    vault
+                        .is_update_needed(slot, config.epoch_length())
+                        .expect("Config epoch length is 0")
+                });
+            METRICS
+                .vaults_need_update
+                .set(vaults_need_update.len() as i64);
+
+            if let Some(state) = &state {
+                record_scan(
+                    state,
+                    epoch,
+                    &vault_filter,
+                    &vaults_need_update,
+                    &updated_vaults,
+                )?;
             }
-        }
 
-        info!("Updating {} vaults", vaults_need_update.len());
+            let (grouped_operators, backing_off): (HashMap<_, _>, HashMap<_, _>) =
+                group_operators(&vaults_need_update, delegations)
+                    .into_iter()
+                    .partition(|(vault, _)| vault_backoff.is_ready(vault));
 
-        let start = Instant::now();
+            info!(
+                "Updating {} vaults, {} backing off or quarantined",
+                grouped_operators.len(),
+                backing_off.len()
+            );
+
+            let tasks: Vec<_> = grouped_operators
+                .into_iter()
+                .map(|(vault, operators)| {
+                    let update_balance = args.update_balance_vaults.contains(&vault);
 
-        let tasks: Vec<_> = grouped_delegations
-            .into_iter()
-            .map(|(vault, mut delegations)| {
-                // Sort by VaultOperatorDelegation index for correct cranking order
-                delegations.sort_by_key(|(_pubkey, delegation)| delegation.index());
-                let operators: Vec<Pubkey> = delegations
-                    .iter()
-                    .map(|(_pubkey, delegation)| delegation.operator)
-                    .collect();
-
-                // Spawn each vault update as a separate task
-                tokio::spawn({
-                    let vault_handler = vault_handler.clone();
-                    let payer = read_keypair_file(&args.keypair_path)
-                        .map_err(|e| anyhow!("Failed to read keypair file: {}", e))
-                        .unwrap();
-                    async move {
-                        match vault_handler
-                            .do_vault_update(slot, &config, &payer, &vault, &operators)
-                            .await
-                        {
-                            Ok(_) => {
-                                info!("Successfully updated vault: {vault}");
+                    // Spawn each vault update as a separate task, run by one of the workers
+                    tokio::spawn({
+                        let vault_handler = vault_handler.clone();
+                        let payer_pool = payer_pool.clone();
+                        let workers = workers.clone();
+                        let vault_backoff = vault_backoff.clone();
+                        async move {
+                            let _worker = workers
+                                .acquire_owned()
+                                .await
+                                .expect("the worker semaphore is never closed");
+                            let payer = payer_pool.lease();
+                            let start = Instant::now();
+                            match vault_handler
+                                .do_vault_update(slot, &config, &payer, &vault, &operators)
+                                .await
+                            {
+                                Ok(_) => {
+                                    info!("Successfully updated vault: {vault}");
+                                    vault_backoff.record_success(&vault);
+                                    METRICS.vaults_updated.inc();
+                                    METRICS
+                                        .vault_update_duration
+                                        .observe(start.elapsed().as_secs_f64());
+                                }
+                                Err(e) => {
+                                    error!("Failed to update vault: {vault}, error: {e}");
+                                    vault_backoff.record_failure(&vault);
+                                    METRICS.vault_update_failures.inc();
+                                    return;
+                                }
                             }
-                            Err(e) => {
-                                error!("Failed to update vault: {vault}, error: {e}");
+
+                            if update_balance {
+                                if let Err(e) =
+                                    update_vault_balance(&vault_handler, &payer, &vault).await
+                                {
+                                    error!("Failed to update vault balance: {vault}, error: {e}");
+                                }
                             }
                         }
-                    }
+                    })
                 })
-            })
-            .collect();
+                .collect();
 
-        for task in tasks {
-            if let Err(e) = task.await {
-                error!("Task failed to complete: {}", e);
+            for task in tasks {
+                if let Err(e) = task.await {
+                    error!("Task failed to complete: {}", e);
+                }
+            }
+
+            if let Some(state) = &state {
+                let progress = state.epoch_progress(epoch)?;
+                let completed = progress.iter().filter(|(_, p)| p.is_completed()).count();
+                let failures: u64 = progress.iter().map(|(_, p)| p.failure_count).sum();
+                info!(
+                    "Epoch {epoch}: {completed}/{} vaults updated, {failures} failures",
+                    progress.len()
+                );
             }
         }
 
+        if !args.burn_ticket_vaults.is_empty() {
+            let payer = payer_pool.lease();
+            if let Err(e) = burn_withdrawable_tickets(
+                &vault_handler,
+                slot,
+                &config,
+                &payer,
+                &args.burn_ticket_vaults,
+            )
+            .await
+            {
+                error!("Failed to burn withdrawal tickets: {e}");
+            }
+        }
+
+        if let Some(authority) = &lookup_table_authority {
+            if let Err(e) = vault_handler
+                .reclaim_lookup_tables(authority, slot, epoch)
+                .await
+            {
+                error!("Failed to reclaim lookup tables: {e:#}");
+            }
+        }
+
+        if let Err(e) = report_tracker_rent(&vault_handler, epoch).await {
+            error!("Failed to report tracker rent: {e}");
+        }
+
         log::info!("Time elapsed: {:.2}s", start.elapsed().as_secs_f64());
+        METRICS
+            .crank_duration
+            .observe(start.elapsed().as_secs_f64());
+        health.loop_completed();
 
         info!("Sleeping for {} seconds", args.crank_interval);
         // ---------- SLEEP (crank_interval)----------
-        tokio::time::sleep(Duration::from_secs(args.crank_interval)).await;
+        match &subscriptions {
+            // Wake up early to crank as soon as the epoch flips
+            Some(subscriptions) => {
+                if subscriptions
+                    .wait_for_epoch_flip(Duration::from_secs(args.crank_interval))
+                    .await
+                {
+                    info!("Epoch flipped, cranking");
+                }
+            }
+            None => tokio::time::sleep(Duration::from_secs(args.crank_interval)).await,
+        }
     }
 }
+
+/// Reports the rent held by the `VaultUpdateStateTracker` accounts not closed yet. The rent of a
+/// tracker is refunded when it is closed, which the trackers of previous epochs still can be.
+async fn report_tracker_rent(vault_handler: &VaultHandler, epoch: u64) -> anyhow::Result<()> {
+    let trackers = vault_handler.get_vault_update_state_trackers().await?;
+    let rent: u64 = trackers.iter().map(|(_, _, lamports)| lamports).sum();
+    let (stale_trackers, stale_rent) = trackers
+        .iter()
+        .filter(|(_, tracker, _)| tracker.ncn_epoch() < epoch)
+        .fold((0usize, 0u64), |(count, rent), (_, _, lamports)| {
+            (count.saturating_add(1), rent.saturating_add(*lamports))
+        });
+
+    info!(
+        "{} unclosed trackers hold {rent} lamports of rent, {stale_trackers} from previous epochs hold {stale_rent} lamports",
+        trackers.len()
+    );
+    METRICS.unclosed_trackers.set(trackers.len() as i64);
+    METRICS.tracker_rent.set(rent as i64);
+
+    Ok(())
+}
+
+/// Calls `UpdateVaultBalance` with the vault as it is after its update.
+async fn update_vault_balance(
+    vault_handler: &VaultHandler,
+    payer: &Keypair,
+    vault_pubkey: &Pubkey,
+) -> anyhow::Result<()> {
+    let vault: Vault = vault_handler
+        .get_vault_program_account(vault_pubkey)
+        .await?;
+    vault_handler
+        .update_vault_balance(payer, vault_pubkey, &vault)
+        .await?;
+
+    info!("Updated vault balance: {vault_pubkey}");
+
+    Ok(())
+}
+
+/// Burns the withdrawable tickets of the opted-in vaults. Vaults that still need an update for
+/// the current epoch are skipped, since burning requires an up to date vault.
+async fn burn_withdrawable_tickets(
+    vault_handler: &VaultHandler,
+    slot: u64,
+    config: &Config,
+    payer: &Keypair,
+    vaults: &Pubkeys,
+) -> anyhow::Result<()> {
+    let mut grouped_tickets: HashMap<Pubkey, Vec<(Pubkey, VaultStakerWithdrawalTicket)>> =
+        HashMap::new();
+    for (pubkey, ticket) in vault_handler.get_vault_staker_withdrawal_tickets().await? {
+        if vaults.contains(&ticket.vault) {
+            grouped_tickets
+                .entry(ticket.vault)
+                .or_default()
+                .push((pubkey, ticket));
+        }
+    }
+
+    for (vault_pubkey, tickets) in grouped_tickets {
+        let vault: Vault = vault_handler
+            .get_vault_program_account(&vault_pubkey)
+            .await?;
+        if vault.is_update_needed(slot, config.epoch_length())? {
+            info!("Skipping withdrawal tickets of vault {vault_pubkey}, vault is not updated");
+            continue;
+        }
+
+        match vault_handler
+            .burn_withdrawable_tickets(slot, config, payer, &vault_pubkey, &vault, &tickets)
+            .await
+        {
+            Ok(burned) => {
+                info!("Burned {burned} withdrawal tickets for vault: {vault_pubkey}");
+            }
+            Err(e) => {
+                error!("Failed to burn withdrawal tickets for vault: {vault_pubkey}, error: {e:#}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the vaults that need an update in the state store. The vaults of the first scan of the
+/// epoch that are now updated were updated by another cranker, so they are marked completed.
+///
+/// A scan without any vault that needs an update isn't recorded, since an epoch scanned before the
+/// vault updates were visible, e.g. from an empty cache, would otherwise be completed for good.
+fn record_scan(
+    state: &StateStore,
+    epoch: u64,
+    vault_filter: &str,
+    vaults_need_update: &[(Pubkey, Vault)],
+    updated_vaults: &[(Pubkey, Vault)],
+) -> anyhow::Result<()> {
+    if !vaults_need_update.is_empty() {
+        let vaults: Vec<Pubkey> = vaults_need_update
+            .iter()
+            .map(|(pubkey, _)| *pubkey)
+            .collect();
+        if state.record_scan(epoch, vault_filter, &vaults)? {
+            state.prune(epoch.saturating_sub(STATE_RETENTION_EPOCHS))?;
+            return Ok(());
+        }
+    }
+
+    if let Some(scan) = state.scan(epoch, vault_filter)? {
+        for vault in scan.vaults {
+            let vault = Pubkey::from_str(&vault)?;
+            if updated_vaults.iter().any(|(pubkey, _)| *pubkey == vault) {
+                state.record_stage(&vault, epoch, UpdateStage::Completed, &[])?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Groups the operators of the vaults that need an update by vault, in cranking order. All
+/// delegations are passed along, delegation filtering logic is handled in `VaultHandler::crank`.
+fn group_operators(
+    vaults_need_update: &[(Pubkey, Vault)],
+    delegations: Vec<(Pubkey, VaultOperatorDelegation)>,
+) -> HashMap<Pubkey, Vec<Pubkey>> {
+    let mut grouped_delegations: HashMap<Pubkey, Vec<VaultOperatorDelegation>> =
+        HashMap::from_iter(vaults_need_update.iter().map(|(vault, _)| (*vault, vec![])));
+    for (_pubkey, delegation) in delegations {
+        if let Some(delegations) = grouped_delegations.get_mut(&delegation.vault) {
+            delegations.push(delegation);
+        }
+    }
+
+    grouped_delegations
+        .into_iter()
+        .map(|(vault, mut delegations)| {
+            // Sort by VaultOperatorDelegation index for correct cranking order
+            delegations.sort_by_key(|delegation| delegation.index());
+            let operators = delegations
+                .iter()
+                .map(|delegation| delegation.operator)
+                .collect();
+            (vault, operators)
+        })
+        .collect()
+}
+
+/// Prints the update plan of every selected vault that needs an update, with the simulated compute
+/// units, without sending any transaction.
+async fn dry_run(
+    args: &Args,
+    rpc_client: &RpcClient,
+    vault_handler: &VaultHandler,
+    config_address: Pubkey,
+    payer: &Keypair,
+) -> anyhow::Result<()> {
+    let config: Config = vault_handler
+        .get_vault_program_account(&config_address)
+        .await?;
+    let slot = rpc_client.get_slot().await.context("get slot")?;
+    let epoch = get_epoch(slot, config.epoch_length())?;
+
+    let mut vaults_need_update = Vec::new();
+    for (pubkey, vault) in vault_handler.get_vaults().await? {
+        if args.is_vault_selected(&pubkey, &vault)
+            && vault.is_update_needed(slot, config.epoch_length())?
+        {
+            vaults_need_update.push((pubkey, vault));
+        }
+    }
+    let delegations = vault_handler.get_vault_operator_delegations().await?;
+    let mut grouped_operators: Vec<_> = group_operators(&vaults_need_update, delegations)
+        .into_iter()
+        .collect();
+    grouped_operators.sort_by_key(|(vault, _)| *vault);
+
+    println!(
+        "Dry run at slot {slot}, epoch {epoch}: {} vaults need an update",
+        grouped_operators.len()
+    );
+
+    let mut compute_units: u64 = 0;
+    let mut transactions: usize = 0;
+    for (vault, operators) in grouped_operators {
+        match vault_handler
+            .plan_vault_update(slot, &config, payer, &vault, &operators)
+            .await
+        {
+            Ok(plan) => {
+                compute_units =
+                    compute_units.saturating_add(plan.compute_units.unwrap_or_default());
+                transactions = transactions.saturating_add(plan.transactions);
+                println!("{plan}");
+            }
+            Err(e) => println!("Vault {vault}: failed to plan update: {e:#}"),
+        }
+    }
+
+    println!("Total: {transactions} transactions, {compute_units} CU simulated");
+
+    Ok(())
+}
+
+/// Prints the findings of an audit of the restaking ticket graph.
+async fn audit(
+    args: &Args,
+    rpc_client: &RpcClient,
+    restaking_handler: &RestakingHandler,
+    vault_handler: &VaultHandler,
+    config_address: Pubkey,
+) -> anyhow::Result<()> {
+    let graph = TicketGraph::fetch(restaking_handler, vault_handler, &config_address).await?;
+    let slot = rpc_client.get_slot().await.context("get slot")?;
+    let findings = graph.audit(slot, args.audit_max_transition_epochs);
+
+    println!(
+        "Audit at slot {slot}: {} NCN operator states, {} NCN vault tickets, {} operator vault tickets, {} NCN vault slasher tickets",
+        graph.ncn_operator_states.len(),
+        graph.ncn_vault_tickets.len(),
+        graph.operator_vault_tickets.len(),
+        graph.ncn_vault_slasher_tickets.len()
+    );
+    for finding in &findings {
+        println!("{finding}");
+    }
+    println!("Total: {} findings", findings.len());
+
+    Ok(())
+}
@@ -1,9 +1,21 @@
 use std::{future::Future, sync::Arc};
 
+use base64::{engine::general_purpose, Engine};
+use jito_bytemuck::Discriminator;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_rpc_client_api::client_error::Error as ClientError;
+use solana_rpc_client_api::{
+    client_error::Error as ClientError,
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+};
 use solana_sdk::{
-    account::Account, commitment_config::CommitmentConfig, hash::Hash, pubkey::Pubkey,
+    account::Account,
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    transaction::{TransactionError, VersionedTransaction},
 };
 use tokio::task;
 
@@ -71,3 +83,82 @@ pub async fn get_multiple_accounts_batched(
     }
     Ok(accounts_result)
 }
+
+/// Describes why a transaction failed in simulation or on chain. Custom errors returned by the
+/// vault program are decoded into their name and description.
+pub fn describe_transaction_error(
+    err: &ClientError,
+    tx: &VersionedTransaction,
+    vault_program_id: &Pubkey,
+) -> String {
+    if let Some(TransactionError::InstructionError(index, InstructionError::Custom(code))) =
+        err.get_transaction_error()
+    {
+        let program_id = tx
+            .message
+            .instructions()
+            .get(usize::from(index))
+            .map(|ix| ix.program_id(tx.message.static_account_keys()));
+        if program_id == Some(vault_program_id) {
+            if let Some(decoded) =
+                jito_vault_sdk::error::decode_program_error(vault_program_id, code)
+            {
+                return format!("instruction {index} failed: {decoded}");
+            }
+        }
+    }
+    err.to_string()
+}
+
+/// Returns a short label for why a transaction failed: the code of a custom program error, the
+/// name of the instruction or transaction error, or `rpc` when the transaction was not processed.
+pub fn transaction_error_code(err: &ClientError) -> String {
+    let name = match err.get_transaction_error() {
+        Some(TransactionError::InstructionError(_, InstructionError::Custom(code))) => {
+            return code.to_string();
+        }
+        Some(TransactionError::InstructionError(_, err)) => format!("{err:?}"),
+        Some(err) => format!("{err:?}"),
+        None => return "rpc".to_string(),
+    };
+    // Drop the fields of the variant
+    name.split(['(', ' '])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Constructs an `RpcProgramAccountsConfig` for querying accounts of a given type `T`.
+///
+/// # Returns
+/// - `Ok(RpcProgramAccountsConfig)`: A valid configuration for filtering accounts in
+///   Solana's RPC API.
+/// - `Err(anyhow::Error)`: If the data size calculation fails (e.g., due to overflow).
+pub fn get_rpc_program_accounts_config<T: Discriminator>(
+) -> anyhow::Result<RpcProgramAccountsConfig> {
+    let data_size = std::mem::size_of::<T>()
+        .checked_add(8)
+        .ok_or_else(|| anyhow::anyhow!("Failed to add"))?;
+    let encoded_discriminator =
+        general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, T::LAYOUT_VERSION]);
+    let memcmp = RpcFilterType::Memcmp(Memcmp::new(
+        0,
+        MemcmpEncodedBytes::Base64(encoded_discriminator),
+    ));
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::DataSize(data_size as u64), memcmp]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            data_slice: Some(UiDataSliceConfig {
+                offset: 0,
+                length: data_size,
+            }),
+            commitment: None,
+            min_context_slot: None,
+        },
+        with_context: Some(false),
+        sort_results: Some(false),
+    };
+
+    Ok(config)
+}
@@ -1,5 +1,14 @@
 #![allow(clippy::arithmetic_side_effects, clippy::integer_division)]
+pub mod audit;
+pub mod backoff;
 pub mod core;
 pub mod metrics;
+pub mod payer_pool;
+pub mod plan;
+pub mod priority_fees;
+pub mod prometheus_exporter;
+pub mod rate_limiter;
 pub mod restaking_handler;
+pub mod state;
+pub mod subscriptions;
 pub mod vault_handler;
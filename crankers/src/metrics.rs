@@ -1,7 +1,7 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, sync::Arc};
 
 use jito_jsm_core::get_epoch;
-use jito_vault_core::config::Config;
+use jito_vault_core::{config::Config, vault::VaultExt};
 use log::error;
 use solana_metrics::datapoint_info;
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
@@ -9,12 +9,13 @@ use solana_sdk::{program_pack::Pack, pubkey::Pubkey};
 use spl_associated_token_account::get_associated_token_address;
 use spl_token::state::{Account as TokenAccount, Mint};
 
-use crate::vault_handler::VaultHandler;
+use crate::{priority_fees::PriorityFees, rate_limiter::RateLimiter, vault_handler::VaultHandler};
 
 pub async fn emit_vault_metrics(
     rpc_client: &RpcClient,
     config_epoch_length: u64,
     cluster_name: &str,
+    rate_limiter: Option<Arc<RateLimiter>>,
 ) -> anyhow::Result<()> {
     let slot = rpc_client.get_slot().await?;
     let epoch = slot / config_epoch_length;
@@ -26,7 +27,10 @@ pub async fn emit_vault_metrics(
         rpc_client.url().as_str(),
         jito_vault_client::programs::JITO_VAULT_ID,
         config_address,
-        0,
+        PriorityFees::fixed(0),
+        None,
+        None,
+        rate_limiter,
     );
 
     // Get all vaults
@@ -0,0 +1,181 @@
+//! A pool of fee payers
+//!
+//! Each vault update leases a payer from the pool, which pays its transaction fees and the rent of
+//! its `VaultUpdateStateTracker` until the tracker is closed. Payers whose last known balance is
+//! below the minimum balance are skipped, and alerted on when balances are refreshed.
+use std::{
+    ops::Deref,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use clap::ValueEnum;
+use log::{error, warn};
+use solana_metrics::datapoint_error;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::prometheus_exporter::METRICS;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PayerSelection {
+    /// Each payer in turn
+    RoundRobin,
+    /// The payer with the fewest vault updates in progress
+    LeastUsed,
+}
+
+struct Payer {
+    keypair: Arc<Keypair>,
+    /// Leases not yet dropped
+    leases: Arc<AtomicUsize>,
+    /// The last known balance in lamports, `u64::MAX` before the first refresh
+    balance: AtomicU64,
+}
+
+pub struct PayerPool {
+    payers: Vec<Payer>,
+    selection: PayerSelection,
+    /// The balance in lamports under which a payer is skipped and alerted on
+    min_balance: u64,
+    next: AtomicUsize,
+}
+
+/// A payer leased from a [`PayerPool`], returned to the pool when dropped
+pub struct PayerLease {
+    keypair: Arc<Keypair>,
+    leases: Arc<AtomicUsize>,
+}
+
+impl Deref for PayerLease {
+    type Target = Keypair;
+
+    fn deref(&self) -> &Keypair {
+        &self.keypair
+    }
+}
+
+impl Drop for PayerLease {
+    fn drop(&mut self) {
+        self.leases.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl PayerPool {
+    /// Creates a pool of `keypairs`. The first keypair is the primary payer.
+    pub fn new(
+        keypairs: Vec<Keypair>,
+        selection: PayerSelection,
+        min_balance: u64,
+    ) -> anyhow::Result<Self> {
+        if keypairs.is_empty() {
+            return Err(anyhow::anyhow!("The payer pool needs at least one keypair"));
+        }
+
+        Ok(Self {
+            payers: keypairs
+                .into_iter()
+                .map(|keypair| Payer {
+                    keypair: Arc::new(keypair),
+                    leases: Arc::new(AtomicUsize::new(0)),
+                    balance: AtomicU64::new(u64::MAX),
+                })
+                .collect(),
+            selection,
+            min_balance,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// The primary payer, which owns the address lookup tables
+    pub fn primary(&self) -> Arc<Keypair> {
+        self.payers[0].keypair.clone()
+    }
+
+    pub fn pubkeys(&self) -> Vec<Pubkey> {
+        self.payers
+            .iter()
+            .map(|payer| payer.keypair.pubkey())
+            .collect()
+    }
+
+    /// Leases a payer with at least the minimum balance, or any payer if none has it.
+    pub fn lease(&self) -> PayerLease {
+        let funded: Vec<&Payer> = self
+            .payers
+            .iter()
+            .filter(|payer| payer.balance.load(Ordering::Relaxed) >= self.min_balance)
+            .collect();
+        let candidates = if funded.is_empty() {
+            warn!(
+                "No payer has the minimum balance of {} lamports",
+                self.min_balance
+            );
+            self.payers.iter().collect()
+        } else {
+            funded
+        };
+
+        let payer = match self.selection {
+            PayerSelection::RoundRobin => {
+                candidates[self.next.fetch_add(1, Ordering::Relaxed) % candidates.len()]
+            }
+            // Ties go to the richest payer
+            PayerSelection::LeastUsed => candidates
+                .iter()
+                .min_by_key(|payer| {
+                    (
+                        payer.leases.load(Ordering::Relaxed),
+                        u64::MAX - payer.balance.load(Ordering::Relaxed),
+                    )
+                })
+                .copied()
+                .expect("the pool has at least one payer"),
+        };
+
+        payer.leases.fetch_add(1, Ordering::Relaxed);
+        PayerLease {
+            keypair: payer.keypair.clone(),
+            leases: payer.leases.clone(),
+        }
+    }
+
+    /// Fetches the balance of every payer, alerting on the ones below the minimum balance.
+    pub async fn refresh_balances(&self, rpc_client: &RpcClient) {
+        let mut below_min_balance = 0;
+        for payer in &self.payers {
+            let pubkey = payer.keypair.pubkey();
+            let balance = match rpc_client.get_balance(&pubkey).await {
+                Ok(balance) => balance,
+                Err(e) => {
+                    error!("Failed to get payer balance: {pubkey}, error: {e}");
+                    continue;
+                }
+            };
+
+            payer.balance.store(balance, Ordering::Relaxed);
+            METRICS
+                .payer_balance
+                .with_label_values(&[&pubkey.to_string()])
+                .set(balance as i64);
+
+            if balance < self.min_balance {
+                below_min_balance += 1;
+                error!(
+                    "Payer {pubkey} balance {balance} lamports is below the minimum of {} lamports",
+                    self.min_balance
+                );
+                datapoint_error!(
+                    "restaking-cranker-payer-low-balance",
+                    "payer" => pubkey.to_string(),
+                    ("balance", balance as i64, i64),
+                    ("min_balance", self.min_balance as i64, i64),
+                );
+            }
+        }
+
+        METRICS.payers_below_min_balance.set(below_min_balance);
+    }
+}
@@ -0,0 +1,176 @@
+//! Vault update plans, printed by `--dry-run`
+//!
+//! A plan lists the instructions the cranker would send to update a vault, in order, with the
+//! compute units each one consumed in simulation. Simulations run against the current state of the
+//! cluster, so only the first transaction of a vault update can be simulated: the instructions of
+//! later transactions depend on the ones before them landing.
+use std::fmt;
+
+use solana_sdk::pubkey::Pubkey;
+
+/// An instruction the cranker would send
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedInstruction {
+    /// The name of the vault program instruction
+    pub name: &'static str,
+    /// The operator cranked, for `CrankVaultUpdateStateTracker`
+    pub operator: Option<Pubkey>,
+    /// The compute units consumed in simulation, `None` if the instruction was not simulated
+    pub compute_units: Option<u64>,
+}
+
+/// The instructions the cranker would send to update a vault for an epoch
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VaultUpdatePlan {
+    pub vault: Pubkey,
+    pub epoch: u64,
+    pub instructions: Vec<PlannedInstruction>,
+    /// The number of transactions the instructions are packed in
+    pub transactions: usize,
+    /// The compute unit price of the first transaction, in microlamports
+    pub compute_unit_price: u64,
+    /// The compute units consumed by the simulation of the first transaction
+    pub compute_units: Option<u64>,
+    /// The error of the simulation, if it failed
+    pub error: Option<String>,
+}
+
+impl fmt::Display for VaultUpdatePlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Vault {} (epoch {}): {} instructions in {} transactions",
+            self.vault,
+            self.epoch,
+            self.instructions.len(),
+            self.transactions
+        )?;
+        if let Some(compute_units) = self.compute_units {
+            write!(f, ", first transaction: {compute_units} CU")?;
+        }
+        if self.transactions > 0 {
+            write!(
+                f,
+                ", priority fee: {} microlamports/CU",
+                self.compute_unit_price
+            )?;
+        }
+
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            write!(f, "\n  {}. {}", i + 1, instruction.name)?;
+            if let Some(operator) = instruction.operator {
+                write!(f, " (operator {operator})")?;
+            }
+            match instruction.compute_units {
+                Some(compute_units) => write!(f, ": {compute_units} CU")?,
+                None => write!(f, ": not simulated")?,
+            }
+        }
+
+        if let Some(error) = &self.error {
+            write!(f, "\n  Simulation failed: {error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the compute units consumed by each top level instruction of a transaction, from its
+/// logs. Builtin programs don't log their compute units, so theirs are `None`.
+pub fn instruction_compute_units(logs: &[String]) -> Vec<Option<u64>> {
+    let mut compute_units = Vec::new();
+    let mut depth = 0;
+
+    for log in logs {
+        // Program messages can contain anything
+        let Some(log) = log
+            .strip_prefix("Program ")
+            .filter(|log| !log.starts_with("log: ") && !log.starts_with("data: "))
+        else {
+            continue;
+        };
+        if let Some((_, invoke)) = log.split_once(" invoke [") {
+            depth = invoke.trim_end_matches(']').parse().unwrap_or(depth + 1);
+            if depth == 1 {
+                compute_units.push(None);
+            }
+        } else if log.ends_with(" success") || log.contains(" failed: ") {
+            depth -= 1;
+        } else if let Some((_, consumed)) = log.split_once(" consumed ") {
+            if depth == 1 {
+                if let Some(last) = compute_units.last_mut() {
+                    *last = consumed
+                        .split_whitespace()
+                        .next()
+                        .and_then(|units| units.parse().ok());
+                }
+            }
+        }
+    }
+
+    compute_units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn logs(lines: &[&str]) -> Vec<String> {
+        lines.iter().map(|line| line.to_string()).collect()
+    }
+
+    #[test]
+    fn test_instruction_compute_units() {
+        let logs = logs(&[
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+            "Program ComputeBudget111111111111111111111111111111 success",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 invoke [1]",
+            "Program log: Instruction: UpdateVaultBalance",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 consumed 12345 of 200000 compute units",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 success",
+        ]);
+        assert_eq!(instruction_compute_units(&logs), vec![None, Some(12_345)]);
+    }
+
+    #[test]
+    fn test_instruction_compute_units_skips_inner_instructions() {
+        let logs = logs(&[
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 invoke [1]",
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA invoke [2]",
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA consumed 4500 of 180000 compute units",
+            "Program TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA success",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 consumed 30000 of 200000 compute units",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 success",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 invoke [1]",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 consumed 9000 of 170000 compute units",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 success",
+        ]);
+        assert_eq!(
+            instruction_compute_units(&logs),
+            vec![Some(30_000), Some(9_000)]
+        );
+    }
+
+    #[test]
+    fn test_instruction_compute_units_ignores_program_messages() {
+        let logs = logs(&[
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 invoke [1]",
+            "Program log: Program X invoke [1]",
+            "Program log: Program X consumed 1 of 2 compute units",
+            "Program data: AQID",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 consumed 8000 of 200000 compute units",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 success",
+        ]);
+        assert_eq!(instruction_compute_units(&logs), vec![Some(8_000)]);
+    }
+
+    #[test]
+    fn test_instruction_compute_units_failed_instruction() {
+        let logs = logs(&[
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 invoke [1]",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 consumed 2500 of 200000 compute units",
+            "Program Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8 failed: custom program error: 0x3e8",
+        ]);
+        assert_eq!(instruction_compute_units(&logs), vec![Some(2_500)]);
+    }
+}
@@ -0,0 +1,260 @@
+//! Priority fees and compute unit limits of cranker transactions
+//!
+//! Every transaction starts with a compute unit price, from the fee strategy, and a compute unit
+//! limit, from a simulation of the transaction. The price increases on each retry of the
+//! transaction, up to the maximum fee.
+use clap::ValueEnum;
+use log::warn;
+use solana_rpc_client::nonblocking::rpc_client::RpcClient;
+use solana_rpc_client_api::config::RpcSimulateTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction, pubkey::Pubkey, transaction::VersionedTransaction,
+};
+
+use crate::prometheus_exporter::METRICS;
+
+/// The compute unit limit of a transaction before it is simulated
+pub const MAX_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Compute units added to the simulated ones, in percent, since the state can change before the
+/// transaction lands
+const COMPUTE_UNIT_LIMIT_MARGIN_PERCENT: u64 = 10;
+
+/// The smallest increase of an escalated fee, in microlamports per compute unit, so that fees of
+/// zero or too small for the escalation percentage to round up still increase on retries
+const MIN_ESCALATION_STEP: u64 = 1_000;
+
+/// The number of accounts `getRecentPrioritizationFees` accepts
+const MAX_PRIORITIZATION_FEE_ACCOUNTS: usize = 128;
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriorityFeeStrategy {
+    /// The `--priority-fees` compute unit price
+    Fixed,
+    /// A percentile of the recent prioritization fees of the accounts the transaction writes to
+    Dynamic,
+}
+
+/// How the compute unit price of transactions is set, in microlamports per compute unit
+#[derive(Clone, Debug)]
+pub struct PriorityFees {
+    pub strategy: PriorityFeeStrategy,
+    /// The fee of the fixed strategy, and of the dynamic strategy when the recent fees can't be
+    /// fetched
+    pub fee: u64,
+    /// The percentile of the recent fees used by the dynamic strategy, from 0 to 100
+    pub percentile: u8,
+    /// The lowest fee of the dynamic strategy
+    pub min_fee: u64,
+    /// The highest fee of the dynamic strategy, and of escalated fees
+    pub max_fee: u64,
+    /// The increase of the fee on each retry, in percent
+    pub escalation_percent: u64,
+}
+
+impl PriorityFees {
+    /// A fixed fee, without escalation
+    pub const fn fixed(fee: u64) -> Self {
+        Self {
+            strategy: PriorityFeeStrategy::Fixed,
+            fee,
+            percentile: 0,
+            min_fee: fee,
+            max_fee: fee,
+            escalation_percent: 0,
+        }
+    }
+
+    /// Returns the compute unit price of a transaction of `instructions`.
+    ///
+    /// # Arguments
+    /// * `rpc_client` - The client fetching the recent fees of the dynamic strategy
+    /// * `instructions` - The instructions of the transaction, without compute budget instructions
+    /// * `attempt` - The number of times the transaction was already sent
+    pub async fn compute_unit_price(
+        &self,
+        rpc_client: &RpcClient,
+        instructions: &[Instruction],
+        attempt: u8,
+    ) -> u64 {
+        let fee = match self.strategy {
+            PriorityFeeStrategy::Fixed => self.fee,
+            PriorityFeeStrategy::Dynamic => {
+                match recent_fee(rpc_client, instructions, self.percentile).await {
+                    Ok(fee) => fee.max(self.min_fee).min(self.max_fee),
+                    Err(e) => {
+                        warn!(
+                            "Failed to get recent prioritization fees, using {}: {e}",
+                            self.fee
+                        );
+                        self.fee
+                    }
+                }
+            }
+        };
+
+        // Escalation never lowers a fee above the maximum
+        let compute_unit_price = escalate_fee(fee, self.escalation_percent, attempt)
+            .min(self.max_fee)
+            .max(fee);
+        METRICS.priority_fee.set(compute_unit_price as i64);
+
+        compute_unit_price
+    }
+}
+
+/// Returns the `percentile` of the recent prioritization fees of the accounts `instructions` write
+/// to.
+async fn recent_fee(
+    rpc_client: &RpcClient,
+    instructions: &[Instruction],
+    percentile: u8,
+) -> anyhow::Result<u64> {
+    let mut accounts: Vec<Pubkey> = Vec::new();
+    for account in instructions.iter().flat_map(|ix| ix.accounts.iter()) {
+        if account.is_writable && !accounts.contains(&account.pubkey) {
+            accounts.push(account.pubkey);
+        }
+    }
+    accounts.truncate(MAX_PRIORITIZATION_FEE_ACCOUNTS);
+
+    let fees: Vec<u64> = rpc_client
+        .get_recent_prioritization_fees(&accounts)
+        .await?
+        .into_iter()
+        .map(|fee| fee.prioritization_fee)
+        .collect();
+
+    Ok(percentile_fee(fees, percentile))
+}
+
+/// Returns the `percentile` of `fees`, or zero if there are none.
+fn percentile_fee(mut fees: Vec<u64>, percentile: u8) -> u64 {
+    fees.sort_unstable();
+
+    let index = (fees.len().saturating_sub(1) * usize::from(percentile.min(100))) / 100;
+    fees.get(index).copied().unwrap_or_default()
+}
+
+/// Returns `fee` raised by `escalation_percent` for each of the `attempt` previous sends of the
+/// transaction, by at least [`MIN_ESCALATION_STEP`] each time. An escalation of zero keeps the
+/// fee.
+fn escalate_fee(fee: u64, escalation_percent: u64, attempt: u8) -> u64 {
+    if escalation_percent == 0 {
+        return fee;
+    }
+
+    (0..attempt).fold(fee, |escalated, _| {
+        let step = escalated.saturating_mul(escalation_percent) / 100;
+        escalated.saturating_add(step.max(MIN_ESCALATION_STEP))
+    })
+}
+
+/// Returns the compute budget instructions followed by `instructions`.
+pub fn with_compute_budget(
+    instructions: &[Instruction],
+    compute_unit_limit: u32,
+    compute_unit_price: u64,
+) -> Vec<Instruction> {
+    let mut with_compute_budget = vec![
+        ComputeBudgetInstruction::set_compute_unit_limit(compute_unit_limit),
+        ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price),
+    ];
+    with_compute_budget.extend_from_slice(instructions);
+    with_compute_budget
+}
+
+/// Returns the compute unit limit of `tx` from a simulation, with a margin. Returns `None` if the
+/// simulation fails, leaving the transaction to fail when sent.
+pub async fn simulate_compute_unit_limit(
+    rpc_client: &RpcClient,
+    tx: &VersionedTransaction,
+) -> Option<u32> {
+    let result = rpc_client
+        .simulate_transaction_with_config(
+            tx,
+            RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                commitment: Some(CommitmentConfig::confirmed()),
+                ..RpcSimulateTransactionConfig::default()
+            },
+        )
+        .await
+        .inspect_err(|e| warn!("Failed to simulate transaction: {e}"))
+        .ok()?
+        .value;
+    if result.err.is_some() {
+        return None;
+    }
+
+    let units = result.units_consumed?;
+    let limit = units.saturating_mul(100 + COMPUTE_UNIT_LIMIT_MARGIN_PERCENT) / 100;
+    Some(limit.min(u64::from(MAX_COMPUTE_UNIT_LIMIT)) as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escalate_fee_first_attempt() {
+        assert_eq!(escalate_fee(5_000, 10, 0), 5_000);
+    }
+
+    #[test]
+    fn test_escalate_fee_by_percent() {
+        assert_eq!(escalate_fee(100_000, 10, 1), 110_000);
+        assert_eq!(escalate_fee(100_000, 10, 2), 121_000);
+    }
+
+    #[test]
+    fn test_escalate_fee_from_zero() {
+        assert_eq!(escalate_fee(0, 10, 1), MIN_ESCALATION_STEP);
+        assert_eq!(escalate_fee(0, 10, 3), 3 * MIN_ESCALATION_STEP);
+    }
+
+    #[test]
+    fn test_escalate_small_fee() {
+        // 10% of 5 rounds down to zero
+        assert_eq!(escalate_fee(5, 10, 1), 5 + MIN_ESCALATION_STEP);
+    }
+
+    #[test]
+    fn test_escalate_fee_without_escalation() {
+        assert_eq!(escalate_fee(0, 0, 5), 0);
+        assert_eq!(escalate_fee(5_000, 0, 5), 5_000);
+    }
+
+    #[test]
+    fn test_escalate_fee_saturates() {
+        assert_eq!(escalate_fee(u64::MAX, 10, 3), u64::MAX);
+    }
+
+    #[test]
+    fn test_percentile_fee() {
+        let fees = vec![50, 10, 40, 20, 30];
+        assert_eq!(percentile_fee(fees.clone(), 0), 10);
+        assert_eq!(percentile_fee(fees.clone(), 50), 30);
+        assert_eq!(percentile_fee(fees.clone(), 75), 40);
+        assert_eq!(percentile_fee(fees, 100), 50);
+    }
+
+    #[test]
+    fn test_percentile_fee_rounds_down() {
+        assert_eq!(percentile_fee(vec![10, 20], 99), 10);
+        assert_eq!(percentile_fee(vec![10, 20], 100), 20);
+    }
+
+    #[test]
+    fn test_percentile_fee_clamps_percentile() {
+        assert_eq!(percentile_fee(vec![10, 20, 30], 200), 30);
+    }
+
+    #[test]
+    fn test_percentile_fee_without_fees() {
+        assert_eq!(percentile_fee(Vec::new(), 50), 0);
+        assert_eq!(percentile_fee(vec![7], 50), 7);
+    }
+}
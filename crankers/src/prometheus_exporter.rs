@@ -0,0 +1,311 @@
+//! Prometheus metrics and health checks, served over HTTP
+//!
+//! * `/metrics` - the cranker metrics in the Prometheus text format, when the Prometheus exporter
+//!   is enabled
+//! * `/healthz` - liveness, `200` while the cranker is running
+//! * `/readyz` - readiness, `200` once a crank loop completed and the last one is recent
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, LazyLock,
+    },
+    time::{Duration, Instant},
+};
+
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use log::{error, info};
+use prometheus::{
+    Encoder, Gauge, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+use solana_rpc_client_api::client_error::Error as ClientError;
+
+use crate::core::transaction_error_code;
+
+/// The cranker metrics, registered on first use
+pub static METRICS: LazyLock<CrankerMetrics> = LazyLock::new(CrankerMetrics::new);
+
+const NAMESPACE: &str = "jito_vault_cranker";
+
+pub struct CrankerMetrics {
+    registry: Registry,
+    /// Vaults fully updated for their epoch
+    pub vaults_updated: IntCounter,
+    /// Vault updates that failed
+    pub vault_update_failures: IntCounter,
+    /// Time to update one vault
+    pub vault_update_duration: Histogram,
+    /// Time of one crank loop, over all vaults
+    pub crank_duration: Histogram,
+    /// Failed transactions, by error code
+    pub transaction_failures: IntCounterVec,
+    /// Balance of each fee payer, by payer
+    pub payer_balance: IntGaugeVec,
+    /// Fee payers below the minimum balance
+    pub payers_below_min_balance: IntGauge,
+    /// Compute unit price of the last transaction, in microlamports
+    pub priority_fee: IntGauge,
+    /// Current epoch, as defined by the vault program config
+    pub epoch: IntGauge,
+    /// Fraction of the current epoch elapsed, from 0 to 1
+    pub epoch_progress: Gauge,
+    /// Vaults of the vault program selected by the vault filters
+    pub vaults: IntGauge,
+    /// Vaults that need an update for the current epoch
+    pub vaults_need_update: IntGauge,
+    /// `VaultUpdateStateTracker` accounts not closed yet
+    pub unclosed_trackers: IntGauge,
+    /// Rent held by the unclosed trackers
+    pub tracker_rent: IntGauge,
+    /// Findings of the last restaking audit, by kind
+    pub audit_findings: IntGaugeVec,
+    /// RPC requests delayed by the rate limiter
+    pub rpc_requests_throttled: IntCounter,
+    /// Vaults skipped until their backoff delay elapses
+    pub vaults_backing_off: IntGauge,
+    /// Vaults quarantined until the next epoch, by vault
+    pub quarantined_vaults: IntGaugeVec,
+}
+
+impl CrankerMetrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some(NAMESPACE.to_string()), None)
+            .expect("valid metrics namespace");
+
+        let metrics = Self {
+            vaults_updated: IntCounter::new("vaults_updated_total", "Vaults updated").unwrap(),
+            vault_update_failures: IntCounter::new(
+                "vault_update_failures_total",
+                "Vault updates that failed",
+            )
+            .unwrap(),
+            vault_update_duration: Histogram::with_opts(
+                HistogramOpts::new("vault_update_duration_seconds", "Time to update one vault")
+                    .buckets(vec![1.0, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0]),
+            )
+            .unwrap(),
+            crank_duration: Histogram::with_opts(
+                HistogramOpts::new("crank_duration_seconds", "Time of one crank loop")
+                    .buckets(vec![1.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0, 1800.0]),
+            )
+            .unwrap(),
+            transaction_failures: IntCounterVec::new(
+                Opts::new(
+                    "transaction_failures_total",
+                    "Failed transactions, by error code",
+                ),
+                &["code"],
+            )
+            .unwrap(),
+            payer_balance: IntGaugeVec::new(
+                Opts::new("payer_balance_lamports", "Balance of each fee payer"),
+                &["payer"],
+            )
+            .unwrap(),
+            payers_below_min_balance: IntGauge::new(
+                "payers_below_min_balance",
+                "Fee payers below the minimum balance",
+            )
+            .unwrap(),
+            priority_fee: IntGauge::new(
+                "priority_fee_microlamports",
+                "Compute unit price of the last transaction",
+            )
+            .unwrap(),
+            epoch: IntGauge::new("epoch", "Current epoch of the vault program").unwrap(),
+            epoch_progress: Gauge::new(
+                "epoch_progress_ratio",
+                "Fraction of the current epoch elapsed",
+            )
+            .unwrap(),
+            vaults: IntGauge::new("vaults", "Vaults selected by the vault filters").unwrap(),
+            vaults_need_update: IntGauge::new(
+                "vaults_need_update",
+                "Vaults that need an update for the current epoch",
+            )
+            .unwrap(),
+            unclosed_trackers: IntGauge::new(
+                "unclosed_trackers",
+                "VaultUpdateStateTracker accounts not closed yet",
+            )
+            .unwrap(),
+            tracker_rent: IntGauge::new(
+                "tracker_rent_lamports",
+                "Rent held by the unclosed trackers",
+            )
+            .unwrap(),
+            audit_findings: IntGaugeVec::new(
+                Opts::new("audit_findings", "Findings of the last restaking audit"),
+                &["kind"],
+            )
+            .unwrap(),
+            rpc_requests_throttled: IntCounter::new(
+                "rpc_requests_throttled_total",
+                "RPC requests delayed by the rate limiter",
+            )
+            .unwrap(),
+            vaults_backing_off: IntGauge::new(
+                "vaults_backing_off",
+                "Vaults skipped until their backoff delay elapses",
+            )
+            .unwrap(),
+            quarantined_vaults: IntGaugeVec::new(
+                Opts::new(
+                    "quarantined_vaults",
+                    "Vaults quarantined until the next epoch",
+                ),
+                &["vault"],
+            )
+            .unwrap(),
+            registry,
+        };
+
+        let collectors: [Box<dyn prometheus::core::Collector>; 18] = [
+            Box::new(metrics.vaults_updated.clone()),
+            Box::new(metrics.vault_update_failures.clone()),
+            Box::new(metrics.vault_update_duration.clone()),
+            Box::new(metrics.crank_duration.clone()),
+            Box::new(metrics.transaction_failures.clone()),
+            Box::new(metrics.payer_balance.clone()),
+            Box::new(metrics.payers_below_min_balance.clone()),
+            Box::new(metrics.priority_fee.clone()),
+            Box::new(metrics.epoch.clone()),
+            Box::new(metrics.epoch_progress.clone()),
+            Box::new(metrics.vaults.clone()),
+            Box::new(metrics.vaults_need_update.clone()),
+            Box::new(metrics.unclosed_trackers.clone()),
+            Box::new(metrics.tracker_rent.clone()),
+            Box::new(metrics.audit_findings.clone()),
+            Box::new(metrics.rpc_requests_throttled.clone()),
+            Box::new(metrics.vaults_backing_off.clone()),
+            Box::new(metrics.quarantined_vaults.clone()),
+        ];
+        for collector in collectors {
+            metrics
+                .registry
+                .register(collector)
+                .expect("metrics have unique names");
+        }
+
+        metrics
+    }
+
+    /// Counts a failed transaction under its error code
+    pub fn record_transaction_failure(&self, err: &ClientError) {
+        self.transaction_failures
+            .with_label_values(&[&transaction_error_code(err)])
+            .inc();
+    }
+
+    /// Encodes the metrics in the Prometheus text format
+    fn encode(&self) -> anyhow::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Tracks the crank loop for the readiness check
+pub struct Health {
+    started: Instant,
+    /// Milliseconds from `started` to the end of the last crank loop, `u64::MAX` before the first
+    last_loop: AtomicU64,
+    /// How long after the last crank loop the cranker stays ready
+    max_loop_age: Duration,
+}
+
+impl Health {
+    pub fn new(max_loop_age: Duration) -> Self {
+        Self {
+            started: Instant::now(),
+            last_loop: AtomicU64::new(u64::MAX),
+            max_loop_age,
+        }
+    }
+
+    /// Records the end of a crank loop
+    pub fn loop_completed(&self) {
+        let elapsed = self.started.elapsed().as_millis() as u64;
+        self.last_loop.store(elapsed, Ordering::Relaxed);
+    }
+
+    /// Whether a crank loop completed within `max_loop_age`
+    pub fn is_ready(&self) -> bool {
+        let last_loop = self.last_loop.load(Ordering::Relaxed);
+        if last_loop == u64::MAX {
+            return false;
+        }
+        let age = self
+            .started
+            .elapsed()
+            .saturating_sub(Duration::from_millis(last_loop));
+        age <= self.max_loop_age
+    }
+}
+
+/// Serves the metrics and health checks on `addr` until the server fails.
+///
+/// # Arguments
+/// * `addr` - The address to listen on
+/// * `health` - The crank loop health
+/// * `export_metrics` - Whether to serve `/metrics`
+pub async fn serve(
+    addr: SocketAddr,
+    health: Arc<Health>,
+    export_metrics: bool,
+) -> anyhow::Result<()> {
+    let make_service = make_service_fn(move |_| {
+        let health = health.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let response = handle(&request, &health, export_metrics);
+                async move { Ok::<_, Infallible>(response) }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)?.serve(make_service);
+    info!("Serving health checks on {addr}");
+    server.await?;
+
+    Ok(())
+}
+
+fn handle(request: &Request<Body>, health: &Health, export_metrics: bool) -> Response<Body> {
+    if request.method() != Method::GET {
+        return response(StatusCode::METHOD_NOT_ALLOWED, "method not allowed");
+    }
+
+    match request.uri().path() {
+        "/metrics" if export_metrics => match METRICS.encode() {
+            Ok(body) => Response::builder()
+                .header(CONTENT_TYPE, TextEncoder::new().format_type())
+                .body(Body::from(body))
+                .unwrap(),
+            Err(e) => {
+                error!("Failed to encode metrics: {e}");
+                response(
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "failed to encode metrics",
+                )
+            }
+        },
+        "/healthz" => response(StatusCode::OK, "ok"),
+        "/readyz" if health.is_ready() => response(StatusCode::OK, "ready"),
+        "/readyz" => response(StatusCode::SERVICE_UNAVAILABLE, "not ready"),
+        _ => response(StatusCode::NOT_FOUND, "not found"),
+    }
+}
+
+fn response(status: StatusCode, body: &'static str) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .body(Body::from(body))
+        .unwrap()
+}
@@ -0,0 +1,131 @@
+//! RPC request rate limiting
+//!
+//! Every RPC client of the cranker shares one [`RateLimiter`], a token bucket refilled at the
+//! configured number of requests per second, so concurrent vault updates can't exceed the RPC
+//! quota together.
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use solana_rpc_client::{
+    http_sender::HttpSender,
+    nonblocking::rpc_client::RpcClient,
+    rpc_client::RpcClientConfig,
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use solana_rpc_client_api::{client_error::Result as ClientResult, request::RpcRequest};
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::time::sleep;
+
+use crate::prometheus_exporter::METRICS;
+
+/// The request timeout of the clients of the vault and restaking handlers
+pub const RPC_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A token bucket of RPC requests
+pub struct RateLimiter {
+    /// Requests per second, and the size of the bucket
+    rate: f64,
+    bucket: Mutex<Bucket>,
+}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a limiter of `requests_per_second`, starting with a full bucket.
+    pub fn new(requests_per_second: u32) -> Self {
+        let rate = f64::from(requests_per_second.max(1));
+        Self {
+            rate,
+            bucket: Mutex::new(Bucket {
+                tokens: rate,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request can be sent.
+    pub async fn acquire(&self) {
+        let mut throttled = false;
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+                let refill = now.duration_since(bucket.refilled_at).as_secs_f64() * self.rate;
+                bucket.tokens = (bucket.tokens + refill).min(self.rate);
+                bucket.refilled_at = now;
+
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate)
+            };
+
+            if !throttled {
+                throttled = true;
+                METRICS.rpc_requests_throttled.inc();
+            }
+            sleep(wait).await;
+        }
+    }
+}
+
+/// An HTTP sender waiting on a [`RateLimiter`] before each request
+struct RateLimitedSender {
+    sender: HttpSender,
+    rate_limiter: Arc<RateLimiter>,
+}
+
+#[async_trait]
+impl RpcSender for RateLimitedSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> ClientResult<serde_json::Value> {
+        self.rate_limiter.acquire().await;
+        self.sender.send(request, params).await
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.sender.get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.sender.url()
+    }
+}
+
+/// Creates an `RpcClient`, limited by `rate_limiter` when there is one.
+///
+/// # Arguments
+/// * `url` - The RPC URL
+/// * `timeout` - The timeout of each request
+/// * `commitment` - The default commitment of the client
+/// * `rate_limiter` - The limiter shared by the clients of the cranker, `None` for no limit
+pub fn new_rpc_client(
+    url: &str,
+    timeout: Duration,
+    commitment: CommitmentConfig,
+    rate_limiter: Option<Arc<RateLimiter>>,
+) -> RpcClient {
+    let sender = HttpSender::new_with_timeout(url, timeout);
+    let config = RpcClientConfig::with_commitment(commitment);
+
+    match rate_limiter {
+        Some(rate_limiter) => RpcClient::new_sender(
+            RateLimitedSender {
+                sender,
+                rate_limiter,
+            },
+            config,
+        ),
+        None => RpcClient::new_sender(sender, config),
+    }
+}
@@ -1,20 +1,36 @@
 use std::sync::Arc;
 
-use jito_bytemuck::AccountDeserialize;
-use jito_restaking_core::operator::Operator;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_restaking_core::{
+    config::Config, ncn_operator_state::NcnOperatorState,
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
 
-use crate::core::get_multiple_accounts_batched;
+use crate::{
+    core::{get_multiple_accounts_batched, get_rpc_program_accounts_config},
+    rate_limiter::{new_rpc_client, RateLimiter, RPC_TIMEOUT},
+};
 
 pub struct RestakingHandler {
     rpc_url: String,
+    restaking_program_id: Pubkey,
+    /// The RPC request limiter shared with the rest of the cranker, `None` for no limit
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl RestakingHandler {
-    pub fn new(rpc_url: &str) -> Self {
+    pub fn new(
+        rpc_url: &str,
+        restaking_program_id: Pubkey,
+        rate_limiter: Option<Arc<RateLimiter>>,
+    ) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
+            restaking_program_id,
+            rate_limiter,
         }
     }
 
@@ -25,7 +41,12 @@ impl RestakingHandler {
     /// An `RpcClient` instance configured to use the stored `rpc_url` and the
     /// `confirmed` commitment level for interactions with the Solana blockchain.
     fn get_rpc_client(&self) -> RpcClient {
-        RpcClient::new_with_commitment(self.rpc_url.clone(), CommitmentConfig::confirmed())
+        new_rpc_client(
+            &self.rpc_url,
+            RPC_TIMEOUT,
+            CommitmentConfig::confirmed(),
+            self.rate_limiter.clone(),
+        )
     }
 
     /// Fetches a list of operator public keys from the provided array of public keys.
@@ -68,4 +89,61 @@ impl RestakingHandler {
 
         Ok(operators)
     }
+
+    /// Fetches the restaking program [`Config`].
+    pub async fn get_config(&self) -> anyhow::Result<Config> {
+        let rpc_client = self.get_rpc_client();
+        let config_address = Config::find_program_address(&self.restaking_program_id).0;
+
+        let account = rpc_client.get_account(&config_address).await?;
+        let config = Config::try_from_slice_unchecked(&account.data)?;
+
+        Ok(*config)
+    }
+
+    /// Retrieves all existing `NcnOperatorState` accounts associated with the program.
+    pub async fn get_ncn_operator_states(&self) -> anyhow::Result<Vec<(Pubkey, NcnOperatorState)>> {
+        self.get_program_accounts().await
+    }
+
+    /// Retrieves all existing `NcnVaultTicket` accounts associated with the program.
+    pub async fn get_ncn_vault_tickets(&self) -> anyhow::Result<Vec<(Pubkey, NcnVaultTicket)>> {
+        self.get_program_accounts().await
+    }
+
+    /// Retrieves all existing `OperatorVaultTicket` accounts associated with the program.
+    pub async fn get_operator_vault_tickets(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, OperatorVaultTicket)>> {
+        self.get_program_accounts().await
+    }
+
+    /// Retrieves all existing `NcnVaultSlasherTicket` accounts associated with the program.
+    pub async fn get_ncn_vault_slasher_tickets(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, NcnVaultSlasherTicket)>> {
+        self.get_program_accounts().await
+    }
+
+    /// Retrieves all the accounts of type `T` of the restaking program, skipping the ones that
+    /// can't be deserialized.
+    async fn get_program_accounts<T: AccountDeserialize + Discriminator + Copy>(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, T)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = get_rpc_program_accounts_config::<T>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.restaking_program_id, config)
+            .await?;
+
+        let accounts: Vec<(Pubkey, T)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                T::try_from_slice_unchecked(&acc.data).map_or(None, |v| Some((pubkey, *v)))
+            })
+            .collect();
+
+        Ok(accounts)
+    }
 }
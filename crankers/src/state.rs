@@ -0,0 +1,495 @@
+//! Local cranker state, persisted across restarts
+//!
+//! The store records the progress of every vault update per epoch, the signatures of the
+//! transactions that made progress, and the failures along the way. On restart the cranker resumes
+//! each vault from its last recorded stage, and skips the vaults it already finished for the epoch
+//! without any RPC call.
+//!
+//! The store also remembers the address lookup table of each vault, and the tables retired to be
+//! closed, so tables are found again without scanning the lookup table program.
+//!
+//! Scans are recorded per vault filter, since a cranker restarted with other `--vault`,
+//! `--exclude-vault` or `--supported-mint` filters sees other vaults.
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use solana_sdk::{pubkey::Pubkey, signature::Signature};
+
+/// Number of failures kept per vault and epoch
+const MAX_FAILURES: usize = 10;
+
+/// The stages of a vault update, in order
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum UpdateStage {
+    /// The vault needs an update, nothing was sent yet
+    #[default]
+    Pending,
+    /// The `VaultUpdateStateTracker` is initialized
+    TrackerInitialized,
+    /// All operators are cranked
+    Cranked,
+    /// The `VaultUpdateStateTracker` is closed, the vault is updated for the epoch
+    Completed,
+}
+
+/// A failed attempt at a stage of a vault update
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Failure {
+    /// The stage that failed
+    pub stage: UpdateStage,
+    /// The error, as logged
+    pub error: String,
+    /// Unix timestamp of the failure
+    pub timestamp: u64,
+}
+
+/// The progress of a vault update for one epoch
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultProgress {
+    /// The last stage reached
+    pub stage: UpdateStage,
+    /// The signatures of the successful transactions, in order
+    pub signatures: Vec<String>,
+    /// The last failures, oldest first
+    pub failures: Vec<Failure>,
+    /// The total number of failures
+    pub failure_count: u64,
+    /// Unix timestamp of the last change
+    pub updated_at: u64,
+}
+
+impl VaultProgress {
+    pub fn is_completed(&self) -> bool {
+        self.stage == UpdateStage::Completed
+    }
+}
+
+/// A [`VaultProgress`] with its vault and epoch, as printed by `--print-state`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VaultProgressRecord {
+    pub vault: String,
+    pub epoch: u64,
+    #[serde(flatten)]
+    pub progress: VaultProgress,
+}
+
+/// The vaults found to need an update when an epoch was first scanned with a vault filter
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EpochScan {
+    pub vaults: Vec<String>,
+    /// Unix timestamp of the scan
+    pub scanned_at: u64,
+}
+
+/// The address lookup table of a vault
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LookupTableRecord {
+    pub address: String,
+    /// The last epoch the table was used to crank the vault
+    pub last_used_epoch: u64,
+}
+
+/// Persistent cranker state, backed by a sled database
+pub struct StateStore {
+    db: sled::Db,
+    /// `vault || epoch` -> [`VaultProgress`]
+    progress: sled::Tree,
+    /// `epoch || filter` -> [`EpochScan`]
+    scans: sled::Tree,
+    /// `vault` -> [`LookupTableRecord`]
+    lookup_tables: sled::Tree,
+    /// `lookup table` -> the vault it belonged to, for the deactivated tables to close
+    retired_lookup_tables: sled::Tree,
+}
+
+impl StateStore {
+    /// Opens the store at `path`, creating it if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        Self::from_db(sled::open(path)?)
+    }
+
+    fn from_db(db: sled::Db) -> anyhow::Result<Self> {
+        let progress = db.open_tree("vault_progress")?;
+        let scans = db.open_tree("epoch_scans")?;
+        let lookup_tables = db.open_tree("lookup_tables")?;
+        let retired_lookup_tables = db.open_tree("retired_lookup_tables")?;
+
+        Ok(Self {
+            db,
+            progress,
+            scans,
+            lookup_tables,
+            retired_lookup_tables,
+        })
+    }
+
+    /// Returns the progress of the update of `vault` for `epoch`, if any was recorded.
+    pub fn progress(&self, vault: &Pubkey, epoch: u64) -> anyhow::Result<Option<VaultProgress>> {
+        get(&self.progress, progress_key(vault, epoch))
+    }
+
+    /// Returns the stage the update of `vault` for `epoch` reached.
+    pub fn stage(&self, vault: &Pubkey, epoch: u64) -> anyhow::Result<UpdateStage> {
+        Ok(self
+            .progress(vault, epoch)?
+            .map(|progress| progress.stage)
+            .unwrap_or_default())
+    }
+
+    /// Returns whether the update of `vault` for `epoch` is completed.
+    pub fn is_completed(&self, vault: &Pubkey, epoch: u64) -> anyhow::Result<bool> {
+        Ok(self.stage(vault, epoch)? == UpdateStage::Completed)
+    }
+
+    /// Records that the update of `vault` for `epoch` reached `stage`, with the signatures of the
+    /// transactions sent for it. A stage never moves backwards.
+    pub fn record_stage(
+        &self,
+        vault: &Pubkey,
+        epoch: u64,
+        stage: UpdateStage,
+        signatures: &[Signature],
+    ) -> anyhow::Result<()> {
+        self.update(vault, epoch, |progress| {
+            progress.stage = progress.stage.max(stage);
+            progress
+                .signatures
+                .extend(signatures.iter().map(Signature::to_string));
+        })
+    }
+
+    /// Records a failed attempt at `stage` of the update of `vault` for `epoch`.
+    pub fn record_failure(
+        &self,
+        vault: &Pubkey,
+        epoch: u64,
+        stage: UpdateStage,
+        error: &str,
+    ) -> anyhow::Result<()> {
+        self.update(vault, epoch, |progress| {
+            if progress.failures.len() >= MAX_FAILURES {
+                progress.failures.remove(0);
+            }
+            progress.failures.push(Failure {
+                stage,
+                error: error.to_string(),
+                timestamp: now(),
+            });
+            progress.failure_count = progress.failure_count.saturating_add(1);
+        })
+    }
+
+    /// Records the vaults that need an update for `epoch`, unless the epoch was already scanned
+    /// with the same vault filter.
+    ///
+    /// # Arguments
+    /// * `epoch` - The scanned epoch
+    /// * `filter` - The vault filters of the scan, as formatted by the cranker
+    /// * `vaults` - The vaults that need an update
+    ///
+    /// # Returns
+    /// Returns `true` if this is the first scan of the epoch with `filter`
+    pub fn record_scan(&self, epoch: u64, filter: &str, vaults: &[Pubkey]) -> anyhow::Result<bool> {
+        let scan = EpochScan {
+            vaults: vaults.iter().map(Pubkey::to_string).collect(),
+            scanned_at: now(),
+        };
+        let first_scan = self
+            .scans
+            .compare_and_swap(
+                scan_key(epoch, filter),
+                None as Option<&[u8]>,
+                Some(serde_json::to_vec(&scan)?),
+            )?
+            .is_ok();
+        self.db.flush()?;
+
+        Ok(first_scan)
+    }
+
+    /// Returns the vaults that needed an update when `epoch` was first scanned with `filter`.
+    pub fn scan(&self, epoch: u64, filter: &str) -> anyhow::Result<Option<EpochScan>> {
+        get(&self.scans, scan_key(epoch, filter))
+    }
+
+    /// Returns whether every vault found by the scan of `epoch` with `filter` is updated, in which
+    /// case there is nothing left to crank until the next epoch.
+    pub fn is_epoch_completed(&self, epoch: u64, filter: &str) -> anyhow::Result<bool> {
+        let Some(scan) = self.scan(epoch, filter)? else {
+            return Ok(false);
+        };
+        for vault in scan.vaults {
+            if !self.is_completed(&vault.parse()?, epoch)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns the lookup table of `vault`, if it has one.
+    pub fn lookup_table(&self, vault: &Pubkey) -> anyhow::Result<Option<LookupTableRecord>> {
+        get(&self.lookup_tables, vault)
+    }
+
+    /// Returns the lookup table of every vault.
+    pub fn lookup_tables(&self) -> anyhow::Result<Vec<(Pubkey, LookupTableRecord)>> {
+        self.lookup_tables
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((vault_of(&key)?, serde_json::from_slice(&value)?))
+            })
+            .collect()
+    }
+
+    /// Records that the lookup table at `address` of `vault` was used in `epoch`.
+    pub fn record_lookup_table(
+        &self,
+        vault: &Pubkey,
+        address: &Pubkey,
+        epoch: u64,
+    ) -> anyhow::Result<()> {
+        let record = LookupTableRecord {
+            address: address.to_string(),
+            last_used_epoch: epoch,
+        };
+        self.lookup_tables
+            .insert(vault, serde_json::to_vec(&record)?)?;
+        self.db.flush()?;
+
+        Ok(())
+    }
+
+    /// Forgets the lookup table of `vault`, keeping its `address` to close it once deactivated.
+    pub fn retire_lookup_table(&self, vault: &Pubkey, address: &Pubkey) -> anyhow::Result<()> {
+        self.retired_lookup_tables.insert(address, vault.as_ref())?;
+        self.lookup_tables.remove(vault)?;
+        self.db.flush()?;
+
+        Ok(())
+    }
+
+    /// Returns the retired lookup tables left to close, with the vault each belonged to.
+    pub fn retired_lookup_tables(&self) -> anyhow::Result<Vec<(Pubkey, Pubkey)>> {
+        self.retired_lookup_tables
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok((
+                    Pubkey::try_from(key.as_ref())?,
+                    Pubkey::try_from(value.as_ref())?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Forgets the retired lookup table at `address`, once closed.
+    pub fn remove_retired_lookup_table(&self, address: &Pubkey) -> anyhow::Result<()> {
+        self.retired_lookup_tables.remove(address)?;
+        self.db.flush()?;
+
+        Ok(())
+    }
+
+    /// Returns every recorded progress, by vault then epoch.
+    pub fn records(&self) -> anyhow::Result<Vec<VaultProgressRecord>> {
+        self.progress
+            .iter()
+            .map(|entry| {
+                let (key, value) = entry?;
+                Ok(VaultProgressRecord {
+                    vault: vault_of(&key)?.to_string(),
+                    epoch: epoch_of(&key)?,
+                    progress: serde_json::from_slice(&value)?,
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the recorded progress of every vault for `epoch`.
+    pub fn epoch_progress(&self, epoch: u64) -> anyhow::Result<Vec<(Pubkey, VaultProgress)>> {
+        let mut progress = Vec::new();
+        for entry in self.progress.iter() {
+            let (key, value) = entry?;
+            if epoch_of(&key)? == epoch {
+                progress.push((vault_of(&key)?, serde_json::from_slice(&value)?));
+            }
+        }
+
+        Ok(progress)
+    }
+
+    /// Deletes the records of epochs before `epoch`.
+    pub fn prune(&self, epoch: u64) -> anyhow::Result<()> {
+        for entry in self.progress.iter() {
+            let (key, _) = entry?;
+            if epoch_of(&key)? < epoch {
+                self.progress.remove(key)?;
+            }
+        }
+        for entry in self.scans.range(..epoch.to_be_bytes()) {
+            let (key, _) = entry?;
+            self.scans.remove(key)?;
+        }
+        self.db.flush()?;
+
+        Ok(())
+    }
+
+    fn update(
+        &self,
+        vault: &Pubkey,
+        epoch: u64,
+        f: impl Fn(&mut VaultProgress),
+    ) -> anyhow::Result<()> {
+        let mut result = Ok(());
+        self.progress
+            .update_and_fetch(progress_key(vault, epoch), |value| {
+                let mut progress = value
+                    .and_then(|value| serde_json::from_slice(value).ok())
+                    .unwrap_or_default();
+                f(&mut progress);
+                progress.updated_at = now();
+                match serde_json::to_vec(&progress) {
+                    Ok(value) => Some(value),
+                    Err(e) => {
+                        result = Err(e);
+                        value.map(<[u8]>::to_vec)
+                    }
+                }
+            })?;
+        result?;
+        self.db.flush()?;
+
+        Ok(())
+    }
+}
+
+fn get<T: DeserializeOwned>(tree: &sled::Tree, key: impl AsRef<[u8]>) -> anyhow::Result<Option<T>> {
+    tree.get(key)?
+        .map(|value| serde_json::from_slice(&value))
+        .transpose()
+        .map_err(Into::into)
+}
+
+/// Keys sort by vault, then by epoch
+fn progress_key(vault: &Pubkey, epoch: u64) -> [u8; 40] {
+    let mut key = [0; 40];
+    key[..32].copy_from_slice(vault.as_ref());
+    key[32..].copy_from_slice(&epoch.to_be_bytes());
+    key
+}
+
+/// Keys sort by epoch, then by filter
+fn scan_key(epoch: u64, filter: &str) -> Vec<u8> {
+    let mut key = epoch.to_be_bytes().to_vec();
+    key.extend_from_slice(filter.as_bytes());
+    key
+}
+
+fn vault_of(key: &[u8]) -> anyhow::Result<Pubkey> {
+    Ok(Pubkey::try_from(&key[..32])?)
+}
+
+fn epoch_of(key: &[u8]) -> anyhow::Result<u64> {
+    Ok(u64::from_be_bytes(key[32..40].try_into()?))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILTER: &str = "vaults=;exclude_vaults=;supported_mints=";
+
+    fn open_temporary() -> StateStore {
+        StateStore::from_db(sled::Config::new().temporary(true).open().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_epoch_not_completed_before_scan() {
+        let state = open_temporary();
+        assert!(!state.is_epoch_completed(1, FILTER).unwrap());
+    }
+
+    #[test]
+    fn test_epoch_completed_once_scanned_vaults_complete() {
+        let state = open_temporary();
+        let vaults = [Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(state.record_scan(1, FILTER, &vaults).unwrap());
+        assert!(!state.record_scan(1, FILTER, &vaults[..1]).unwrap());
+
+        state
+            .record_stage(&vaults[0], 1, UpdateStage::Completed, &[])
+            .unwrap();
+        assert!(!state.is_epoch_completed(1, FILTER).unwrap());
+
+        state
+            .record_stage(&vaults[1], 1, UpdateStage::Completed, &[])
+            .unwrap();
+        assert!(state.is_epoch_completed(1, FILTER).unwrap());
+        assert!(!state.is_epoch_completed(2, FILTER).unwrap());
+    }
+
+    #[test]
+    fn test_scans_are_kept_per_filter() {
+        let state = open_temporary();
+        let vault = Pubkey::new_unique();
+        let other_filter = format!("vaults={vault};exclude_vaults=;supported_mints=");
+        assert!(state.record_scan(1, &other_filter, &[vault]).unwrap());
+        state
+            .record_stage(&vault, 1, UpdateStage::Completed, &[])
+            .unwrap();
+
+        assert!(state.is_epoch_completed(1, &other_filter).unwrap());
+        assert!(!state.is_epoch_completed(1, FILTER).unwrap());
+        assert!(state
+            .record_scan(1, FILTER, &[Pubkey::new_unique()])
+            .unwrap());
+    }
+
+    #[test]
+    fn test_lookup_table_lifecycle() {
+        let state = open_temporary();
+        let vault = Pubkey::new_unique();
+        let table = Pubkey::new_unique();
+        assert_eq!(state.lookup_table(&vault).unwrap(), None);
+
+        state.record_lookup_table(&vault, &table, 3).unwrap();
+        state.record_lookup_table(&vault, &table, 4).unwrap();
+        let record = LookupTableRecord {
+            address: table.to_string(),
+            last_used_epoch: 4,
+        };
+        assert_eq!(state.lookup_table(&vault).unwrap(), Some(record.clone()));
+        assert_eq!(state.lookup_tables().unwrap(), vec![(vault, record)]);
+
+        state.retire_lookup_table(&vault, &table).unwrap();
+        assert_eq!(state.lookup_table(&vault).unwrap(), None);
+        assert_eq!(state.retired_lookup_tables().unwrap(), vec![(table, vault)]);
+
+        state.remove_retired_lookup_table(&table).unwrap();
+        assert!(state.retired_lookup_tables().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_prune_keeps_current_epoch_scans() {
+        let state = open_temporary();
+        let vault = Pubkey::new_unique();
+        state.record_scan(1, FILTER, &[vault]).unwrap();
+        state.record_scan(2, FILTER, &[vault]).unwrap();
+
+        state.prune(2).unwrap();
+        assert!(state.scan(1, FILTER).unwrap().is_none());
+        assert!(state.scan(2, FILTER).unwrap().is_some());
+    }
+}
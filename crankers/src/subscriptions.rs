@@ -0,0 +1,238 @@
+//! Websocket subscriptions of the event-driven mode
+//!
+//! * `slotSubscribe` tracks the current slot, and wakes the crank loop as soon as the epoch of the
+//!   vault program flips
+//! * `programSubscribe` on the vault program keeps an [`AccountCache`] of the `Vault` and
+//!   `VaultOperatorDelegation` accounts up to date, replacing the `getProgramAccounts` calls of
+//!   each crank loop
+//!
+//! Subscriptions reconnect after an error. Until they are back, the slot and the cache are reset
+//! so that the crank loop falls back to `getSlot` and `getProgramAccounts` instead of using stale
+//! values. The cache is reloaded with `getProgramAccounts` on every connection, since updates may
+//! have been missed while disconnected.
+use std::{
+    collections::HashMap,
+    mem::size_of,
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        RwLock,
+    },
+    time::Duration,
+};
+
+use futures::StreamExt;
+use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_jsm_core::get_epoch;
+use jito_vault_core::{vault::Vault, vault_operator_delegation::VaultOperatorDelegation};
+use log::{error, info};
+use solana_account_decoder::UiAccountEncoding;
+use solana_pubsub_client::nonblocking::pubsub_client::PubsubClient;
+use solana_rpc_client_api::{
+    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    response::RpcKeyedAccount,
+};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use tokio::{sync::Notify, time::sleep};
+
+use crate::vault_handler::VaultHandler;
+
+/// Delay before reconnecting after the subscriptions fail
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// The `Vault` and `VaultOperatorDelegation` accounts of the vault program, kept up to date by
+/// `programSubscribe`
+#[derive(Default)]
+pub struct AccountCache {
+    vaults: RwLock<HashMap<Pubkey, Vault>>,
+    delegations: RwLock<HashMap<Pubkey, VaultOperatorDelegation>>,
+    loaded: AtomicBool,
+}
+
+impl AccountCache {
+    /// Whether the cache was loaded, and can replace `getProgramAccounts`
+    pub fn is_loaded(&self) -> bool {
+        self.loaded.load(Ordering::Relaxed)
+    }
+
+    pub fn vaults(&self) -> Vec<(Pubkey, Vault)> {
+        self.vaults
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(pubkey, vault)| (*pubkey, *vault))
+            .collect()
+    }
+
+    pub fn vault_operator_delegations(&self) -> Vec<(Pubkey, VaultOperatorDelegation)> {
+        self.delegations
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(pubkey, delegation)| (*pubkey, *delegation))
+            .collect()
+    }
+
+    /// Marks the cache as stale until it is loaded again.
+    fn invalidate(&self) {
+        self.loaded.store(false, Ordering::Relaxed);
+    }
+
+    /// Replaces the cached accounts with the ones fetched with `getProgramAccounts`.
+    async fn load(&self, vault_handler: &VaultHandler) -> anyhow::Result<()> {
+        let vaults = vault_handler.get_vaults().await?;
+        let delegations = vault_handler.get_vault_operator_delegations().await?;
+
+        *self.vaults.write().unwrap() = vaults.into_iter().collect();
+        *self.delegations.write().unwrap() = delegations.into_iter().collect();
+        self.loaded.store(true, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Updates the cache with an account notified by `programSubscribe`. Accounts of other types
+    /// are ignored.
+    fn update(&self, keyed_account: &RpcKeyedAccount) {
+        let Ok(pubkey) = Pubkey::from_str(&keyed_account.pubkey) else {
+            return;
+        };
+        let Some(data) = keyed_account.account.data.decode() else {
+            return;
+        };
+
+        match data.first() {
+            Some(&Vault::DISCRIMINATOR) => {
+                if let Some(vault) = deserialize::<Vault>(&data) {
+                    self.vaults.write().unwrap().insert(pubkey, vault);
+                }
+            }
+            Some(&VaultOperatorDelegation::DISCRIMINATOR) => {
+                if let Some(delegation) = deserialize::<VaultOperatorDelegation>(&data) {
+                    self.delegations.write().unwrap().insert(pubkey, delegation);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Deserializes an account, ignoring any data after the account struct as `getProgramAccounts`
+/// does with its data slice.
+fn deserialize<T: AccountDeserialize + Copy>(data: &[u8]) -> Option<T> {
+    let data = data.get(..size_of::<T>().checked_add(8)?)?;
+    T::try_from_slice_unchecked(data).ok().copied()
+}
+
+/// The slot and account subscriptions of the event-driven mode
+#[derive(Default)]
+pub struct Subscriptions {
+    pub cache: AccountCache,
+    /// The last slot notified, 0 before the first notification
+    slot: AtomicU64,
+    epoch_flipped: Notify,
+}
+
+impl Subscriptions {
+    /// Returns the last slot notified, if any.
+    pub fn slot(&self) -> Option<u64> {
+        Some(self.slot.load(Ordering::Relaxed)).filter(|slot| *slot != 0)
+    }
+
+    /// Waits until the epoch flips, or until `timeout` elapses.
+    ///
+    /// # Returns
+    /// Returns `true` if the epoch flipped
+    pub async fn wait_for_epoch_flip(&self, timeout: Duration) -> bool {
+        tokio::time::timeout(timeout, self.epoch_flipped.notified())
+            .await
+            .is_ok()
+    }
+
+    /// Runs the subscriptions forever, reconnecting after each failure.
+    ///
+    /// # Arguments
+    /// * `ws_url` - The websocket URL of the RPC
+    /// * `vault_handler` - The handler loading the cache on each connection
+    /// * `vault_program_id` - The vault program
+    /// * `epoch_length` - The epoch length of the vault program config
+    pub async fn run(
+        &self,
+        ws_url: &str,
+        vault_handler: &VaultHandler,
+        vault_program_id: &Pubkey,
+        epoch_length: u64,
+    ) {
+        loop {
+            if let Err(e) = self
+                .subscribe(ws_url, vault_handler, vault_program_id, epoch_length)
+                .await
+            {
+                error!("Websocket subscriptions failed, reconnecting: {e:#}");
+            }
+            self.reset();
+            sleep(RECONNECT_DELAY).await;
+        }
+    }
+
+    async fn subscribe(
+        &self,
+        ws_url: &str,
+        vault_handler: &VaultHandler,
+        vault_program_id: &Pubkey,
+        epoch_length: u64,
+    ) -> anyhow::Result<()> {
+        let client = PubsubClient::new(ws_url).await?;
+        let (mut slots, _slot_unsubscribe) = client.slot_subscribe().await?;
+        let config = RpcProgramAccountsConfig {
+            filters: None,
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: None,
+                commitment: Some(CommitmentConfig::confirmed()),
+                min_context_slot: None,
+            },
+            with_context: Some(false),
+            sort_results: None,
+        };
+        let (mut accounts, _accounts_unsubscribe) = client
+            .program_subscribe(vault_program_id, Some(config))
+            .await?;
+
+        // Loaded after subscribing, so no update is missed in between
+        self.cache.load(vault_handler).await?;
+        info!("Subscribed to slots and vault program accounts on {ws_url}");
+
+        loop {
+            tokio::select! {
+                Some(slot_info) = slots.next() => self.update_slot(slot_info.slot, epoch_length),
+                Some(response) = accounts.next() => self.cache.update(&response.value),
+                else => return Err(anyhow::anyhow!("Subscriptions closed")),
+            }
+        }
+    }
+
+    /// Forgets the last slot notified and invalidates the cache, which are no longer updated
+    /// once the subscriptions fail.
+    fn reset(&self) {
+        self.slot.store(0, Ordering::Relaxed);
+        self.cache.invalidate();
+    }
+
+    /// Records the notified slot, waking the crank loop if the epoch flipped.
+    fn update_slot(&self, slot: u64, epoch_length: u64) {
+        let previous_slot = self.slot.swap(slot, Ordering::Relaxed);
+        if previous_slot == 0 {
+            return;
+        }
+
+        if let (Ok(previous_epoch), Ok(epoch)) = (
+            get_epoch(previous_slot, epoch_length),
+            get_epoch(slot, epoch_length),
+        ) {
+            if epoch > previous_epoch {
+                info!("Epoch flipped to {epoch} at slot {slot}");
+                self.epoch_flipped.notify_one();
+            }
+        }
+    }
+}
@@ -1,43 +1,85 @@
-use std::time::Duration;
+use std::{str::FromStr, sync::Arc, time::Duration};
 
 use anyhow::Context;
-use base64::{engine::general_purpose, Engine};
 use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::get_epoch;
 use jito_vault_client::{
     instructions::{
-        CloseVaultUpdateStateTrackerBuilder, CrankVaultUpdateStateTrackerBuilder,
-        InitializeVaultUpdateStateTrackerBuilder,
+        BurnWithdrawalTicketBuilder, CloseVaultUpdateStateTrackerBuilder,
+        CrankVaultUpdateStateTrackerBuilder, InitializeVaultUpdateStateTrackerBuilder,
+        UpdateVaultBalanceBuilder,
     },
     types::WithdrawalAllocationMethod,
 };
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
-    vault_update_state_tracker::VaultUpdateStateTracker,
+    config::Config,
+    vault::Vault,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::{VaultOperatorDelegation, VaultOperatorDelegationExt},
+    vault_staker_withdrawal_ticket::{VaultStakerWithdrawalTicket, VaultStakerWithdrawalTicketExt},
+    vault_update_state_tracker::{VaultUpdateStateTracker, VaultUpdateStateTrackerExt},
 };
-use log::{error, info};
-use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use log::{error, info, warn};
 use solana_rpc_client::nonblocking::rpc_client::RpcClient;
-use solana_rpc_client_api::{
-    config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
-    filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
-};
+use solana_rpc_client_api::config::RpcSimulateTransactionConfig;
 use solana_sdk::{
-    commitment_config::CommitmentConfig, compute_budget::ComputeBudgetInstruction,
-    instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer,
-    transaction::Transaction,
+    address_lookup_table::{
+        instruction::{
+            close_lookup_table, create_lookup_table, deactivate_lookup_table, extend_lookup_table,
+        },
+        state::{AddressLookupTable, LOOKUP_TABLE_MAX_ADDRESSES},
+        AddressLookupTableAccount,
+    },
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::Instruction,
+    message::{v0, Message, VersionedMessage},
+    packet::PACKET_DATA_SIZE,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    slot_hashes::MAX_ENTRIES as SLOT_HASHES_MAX_ENTRIES,
+    transaction::VersionedTransaction,
+};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account_idempotent,
 };
 use tokio::time::sleep;
 
-use crate::core::get_latest_blockhash_with_retry;
+use crate::{
+    core::{
+        describe_transaction_error, get_latest_blockhash_with_retry, get_multiple_accounts_batched,
+        get_rpc_program_accounts_config,
+    },
+    plan::{instruction_compute_units, PlannedInstruction, VaultUpdatePlan},
+    priority_fees::{
+        simulate_compute_unit_limit, with_compute_budget, PriorityFees, MAX_COMPUTE_UNIT_LIMIT,
+    },
+    prometheus_exporter::METRICS,
+    rate_limiter::{new_rpc_client, RateLimiter, RPC_TIMEOUT},
+    state::{StateStore, UpdateStage},
+};
 
 const MAX_RETRIES: u8 = 10;
 
+/// Epochs a vault lookup table can go unused before it is deactivated, to reclaim its rent
+const LOOKUP_TABLE_IDLE_EPOCHS: u64 = 3;
+
+/// Addresses added per `ExtendLookupTable` transaction, within the transaction size limit
+const MAX_EXTEND_LOOKUP_TABLE_ADDRESSES: usize = 20;
+
 pub struct VaultHandler {
     rpc_url: String,
     vault_program_id: Pubkey,
     config_address: Pubkey,
-    priority_fees: u64,
+    priority_fees: PriorityFees,
+    state: Option<Arc<StateStore>>,
+    /// The payer owning the address lookup tables of large vaults, `None` to not use lookup
+    /// tables
+    lookup_table_authority: Option<Arc<Keypair>>,
+    /// The RPC request limiter shared with the rest of the cranker, `None` for no limit
+    rate_limiter: Option<Arc<RateLimiter>>,
 }
 
 impl VaultHandler {
@@ -45,13 +87,19 @@ impl VaultHandler {
         rpc_url: &str,
         vault_program_id: Pubkey,
         config_address: Pubkey,
-        priority_fees: u64,
+        priority_fees: PriorityFees,
+        state: Option<Arc<StateStore>>,
+        lookup_table_authority: Option<Arc<Keypair>>,
+        rate_limiter: Option<Arc<RateLimiter>>,
     ) -> Self {
         Self {
             rpc_url: rpc_url.to_string(),
             vault_program_id,
             config_address,
             priority_fees,
+            state,
+            lookup_table_authority,
+            rate_limiter,
         }
     }
 
@@ -62,74 +110,38 @@ impl VaultHandler {
     /// An `RpcClient` instance configured to use the stored `rpc_url` and the
     /// `confirmed` commitment level for interactions with the Solana blockchain.
     fn get_rpc_client(&self) -> RpcClient {
-        RpcClient::new_with_commitment(self.rpc_url.clone(), CommitmentConfig::confirmed())
-    }
-
-    /// Constructs an `RpcProgramAccountsConfig` for querying accounts of a given type `T`.
-    ///
-    /// # Returns
-    /// - `Ok(RpcProgramAccountsConfig)`: A valid configuration for filtering accounts in
-    ///   Solana's RPC API.
-    /// - `Err(anyhow::Error)`: If the data size calculation fails (e.g., due to overflow).
-    fn get_rpc_program_accounts_config<T: jito_bytemuck::Discriminator>(
-        &self,
-    ) -> anyhow::Result<RpcProgramAccountsConfig> {
-        let data_size = std::mem::size_of::<T>()
-            .checked_add(8)
-            .ok_or_else(|| anyhow::anyhow!("Failed to add"))?;
-        let encoded_discriminator =
-            general_purpose::STANDARD.encode(vec![T::DISCRIMINATOR, 0, 0, 0, 0, 0, 0, 0]);
-        let memcmp = RpcFilterType::Memcmp(Memcmp::new(
-            0,
-            MemcmpEncodedBytes::Base64(encoded_discriminator),
-        ));
-        let config = RpcProgramAccountsConfig {
-            filters: Some(vec![RpcFilterType::DataSize(data_size as u64), memcmp]),
-            account_config: RpcAccountInfoConfig {
-                encoding: Some(UiAccountEncoding::Base64),
-                data_slice: Some(UiDataSliceConfig {
-                    offset: 0,
-                    length: data_size,
-                }),
-                commitment: None,
-                min_context_slot: None,
-            },
-            with_context: Some(false),
-            sort_results: Some(false),
-        };
-
-        Ok(config)
+        new_rpc_client(
+            &self.rpc_url,
+            RPC_TIMEOUT,
+            CommitmentConfig::confirmed(),
+            self.rate_limiter.clone(),
+        )
     }
 
-    /// Sends and confirms a transaction with retries, priority fees, and blockhash refresh
+    /// Sends and confirms a transaction with retries, escalating priority fees, and blockhash refresh
     ///
     /// # Arguments
     /// * `payer` - Keypair of payer
     /// * `instructions` - Vector of instructions to include in the transaction
     ///
     /// # Returns
-    /// Returns `anyhow::Result<()>` indicating success or failure
+    /// Returns the signature of the confirmed transaction
     async fn send_and_confirm_transaction_with_retry(
         &self,
         payer: &Keypair,
-        mut instructions: Vec<Instruction>,
-    ) -> anyhow::Result<()> {
+        instructions: Vec<Instruction>,
+    ) -> anyhow::Result<Signature> {
         let rpc_client = self.get_rpc_client();
         let mut retries = 0;
 
-        instructions.insert(
-            0,
-            ComputeBudgetInstruction::set_compute_unit_price(self.priority_fees),
-        );
         while retries < MAX_RETRIES {
-            let blockhash = get_latest_blockhash_with_retry(&rpc_client).await?;
-
-            let tx = Transaction::new_signed_with_payer(
-                &instructions,
-                Some(&payer.pubkey()),
-                &[payer],
-                blockhash,
-            );
+            let compute_unit_price = self
+                .priority_fees
+                .compute_unit_price(&rpc_client, &instructions, retries)
+                .await;
+            let tx = self
+                .build_transaction(payer, &instructions, None, compute_unit_price)
+                .await?;
 
             let err = match rpc_client
                 .send_and_confirm_transaction_with_spinner_and_commitment(
@@ -138,21 +150,19 @@ impl VaultHandler {
                 )
                 .await
             {
-                Ok(_) => return Ok(()),
+                Ok(signature) => return Ok(signature),
                 Err(err) => {
+                    METRICS.record_transaction_failure(&err);
                     retries += 1;
                     if retries < MAX_RETRIES {
                         sleep(Duration::from_secs(1)).await;
                     }
-                    err
+                    describe_transaction_error(&err, &tx, &self.vault_program_id)
                 }
             };
 
             if retries >= MAX_RETRIES {
-                error!(
-                    "Transaction failed after {} retries: {:?}",
-                    MAX_RETRIES, err
-                );
+                error!("Transaction failed after {} retries: {}", MAX_RETRIES, err);
             }
         }
 
@@ -162,77 +172,80 @@ impl VaultHandler {
         ))
     }
 
-    /// Splits a vector of instructions into multiple transactions to stay within Solana's
-    /// transaction size limit of 1232 bytes.
+    /// Splits a vector of instructions into batches that each fit in a transaction within
+    /// Solana's transaction size limit of 1232 bytes.
     ///
     /// This function dynamically batches instructions by testing the actual transaction size
-    /// rather than using fixed batch sizes. Each transaction will include a compute budget
-    /// instruction at the beginning.
-    async fn split_instructions_by_size(
+    /// rather than using fixed batch sizes, counting the compute budget instructions each
+    /// transaction starts with. With a lookup table, the size is the size of a v0 transaction,
+    /// referencing the accounts in the table by index.
+    fn split_instructions_by_size(
         &self,
         instructions: &[Instruction],
         payer: &Keypair,
         max_size: usize,
-    ) -> anyhow::Result<Vec<Transaction>> {
-        let mut transactions = Vec::new();
-        let mut current_batch = Vec::new();
+        lookup_table: Option<&AddressLookupTableAccount>,
+    ) -> anyhow::Result<Vec<Vec<Instruction>>> {
+        // Compute budget instructions have the same size whatever their values
+        let transaction_size = |batch: &[Instruction]| -> anyhow::Result<usize> {
+            let instructions = with_compute_budget(batch, MAX_COMPUTE_UNIT_LIMIT, 0);
+            let tx = compile_transaction(payer, &instructions, lookup_table, Hash::default())?;
+            Ok(transaction_size(&tx))
+        };
 
-        let compute_budget_ix =
-            ComputeBudgetInstruction::set_compute_unit_price(self.priority_fees);
+        let mut batches = Vec::new();
+        let mut current_batch = Vec::new();
 
         for instruction in instructions {
-            // Create a test transaction with current batch + new instruction
-            let mut test_batch = vec![compute_budget_ix.clone()];
-            test_batch.extend(current_batch.clone());
-            test_batch.push(instruction.clone());
-
-            let blockhash = get_latest_blockhash_with_retry(&self.get_rpc_client()).await?;
-            let test_tx = Transaction::new_signed_with_payer(
-                &test_batch,
-                Some(&payer.pubkey()),
-                &[payer],
-                blockhash,
-            );
+            // Test the current batch with the new instruction
+            current_batch.push(instruction.clone());
 
-            let tx_size = test_tx.signatures.len() + test_tx.message_data().len();
-
-            if tx_size > max_size && !current_batch.is_empty() {
-                // Finalize current batch
-                let mut final_batch = vec![compute_budget_ix.clone()];
-                final_batch.extend(current_batch.clone());
-
-                let blockhash = get_latest_blockhash_with_retry(&self.get_rpc_client()).await?;
-                let tx = Transaction::new_signed_with_payer(
-                    &final_batch,
-                    Some(&payer.pubkey()),
-                    &[payer],
-                    blockhash,
-                );
-                transactions.push(tx);
+            if transaction_size(&current_batch)? > max_size && current_batch.len() > 1 {
+                // Finalize current batch without the new instruction
+                current_batch.pop();
+                batches.push(current_batch);
 
                 // Start new batch with current instruction
                 current_batch = vec![instruction.clone()];
-            } else {
-                current_batch.push(instruction.clone());
             }
         }
 
         // Handle remaining instructions
         if !current_batch.is_empty() {
-            let mut final_batch = vec![compute_budget_ix];
-            final_batch.extend(current_batch);
-
-            let blockhash = get_latest_blockhash_with_retry(&self.get_rpc_client()).await?;
-            let tx = Transaction::new_signed_with_payer(
-                &final_batch,
-                Some(&payer.pubkey()),
-                &[payer],
-                blockhash,
-            );
-            transactions.push(tx);
+            batches.push(current_batch);
         }
 
-        Ok(transactions)
+        Ok(batches)
+    }
+
+    /// Builds a transaction of `instructions` after the compute budget instructions, with the
+    /// compute unit limit from a simulation of the transaction.
+    async fn build_transaction(
+        &self,
+        payer: &Keypair,
+        instructions: &[Instruction],
+        lookup_table: Option<&AddressLookupTableAccount>,
+        compute_unit_price: u64,
+    ) -> anyhow::Result<VersionedTransaction> {
+        let rpc_client = self.get_rpc_client();
+        let blockhash = get_latest_blockhash_with_retry(&rpc_client).await?;
+
+        let tx = compile_transaction(
+            payer,
+            &with_compute_budget(instructions, MAX_COMPUTE_UNIT_LIMIT, compute_unit_price),
+            lookup_table,
+            blockhash,
+        )?;
+        let Some(compute_unit_limit) = simulate_compute_unit_limit(&rpc_client, &tx).await else {
+            return Ok(tx);
+        };
+
+        compile_transaction(
+            payer,
+            &with_compute_budget(instructions, compute_unit_limit, compute_unit_price),
+            lookup_table,
+            blockhash,
+        )
     }
 
     /// Retrieves Jito Vault Program account
@@ -268,7 +281,7 @@ impl VaultHandler {
     /// - `Vault`: The deserialized vault data from the account.
     pub async fn get_vaults(&self) -> anyhow::Result<Vec<(Pubkey, Vault)>> {
         let rpc_client = self.get_rpc_client();
-        let config = self.get_rpc_program_accounts_config::<Vault>()?;
+        let config = get_rpc_program_accounts_config::<Vault>()?;
 
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.vault_program_id, config)
@@ -296,7 +309,7 @@ impl VaultHandler {
         &self,
     ) -> anyhow::Result<Vec<(Pubkey, VaultOperatorDelegation)>> {
         let rpc_client = self.get_rpc_client();
-        let config = self.get_rpc_program_accounts_config::<VaultOperatorDelegation>()?;
+        let config = get_rpc_program_accounts_config::<VaultOperatorDelegation>()?;
 
         let accounts = rpc_client
             .get_program_accounts_with_config(&self.vault_program_id, config)
@@ -313,6 +326,121 @@ impl VaultHandler {
         Ok(delegations)
     }
 
+    /// Retrieves all existing `VaultStakerWithdrawalTicket` accounts associated with the program.
+    ///
+    /// # Returns
+    ///
+    /// An `anyhow::Result` containing a vector of `(Pubkey, VaultStakerWithdrawalTicket)` tuples.
+    /// Each tuple represents a withdrawal ticket account and includes:
+    /// - `Pubkey`: The public key of the withdrawal ticket account.
+    /// - `VaultStakerWithdrawalTicket`: The deserialized withdrawal ticket data.
+    pub async fn get_vault_staker_withdrawal_tickets(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, VaultStakerWithdrawalTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = get_rpc_program_accounts_config::<VaultStakerWithdrawalTicket>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let tickets: Vec<(Pubkey, VaultStakerWithdrawalTicket)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                VaultStakerWithdrawalTicket::try_from_slice_unchecked(&acc.data)
+                    .map_or(None, |v| Some((pubkey, *v)))
+            })
+            .collect();
+
+        Ok(tickets)
+    }
+
+    /// Retrieves all existing `VaultNcnTicket` accounts associated with the program.
+    ///
+    /// # Returns
+    ///
+    /// An `anyhow::Result` containing a vector of `(Pubkey, VaultNcnTicket)` tuples. Each tuple
+    /// represents a vault NCN ticket account and includes:
+    /// - `Pubkey`: The public key of the vault NCN ticket account.
+    /// - `VaultNcnTicket`: The deserialized vault NCN ticket data.
+    pub async fn get_vault_ncn_tickets(&self) -> anyhow::Result<Vec<(Pubkey, VaultNcnTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = get_rpc_program_accounts_config::<VaultNcnTicket>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let tickets: Vec<(Pubkey, VaultNcnTicket)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                VaultNcnTicket::try_from_slice_unchecked(&acc.data)
+                    .map_or(None, |v| Some((pubkey, *v)))
+            })
+            .collect();
+
+        Ok(tickets)
+    }
+
+    /// Retrieves all existing `VaultNcnSlasherTicket` accounts associated with the program.
+    ///
+    /// # Returns
+    ///
+    /// An `anyhow::Result` containing a vector of `(Pubkey, VaultNcnSlasherTicket)` tuples. Each tuple
+    /// represents a vault NCN slasher ticket account and includes:
+    /// - `Pubkey`: The public key of the vault NCN slasher ticket account.
+    /// - `VaultNcnSlasherTicket`: The deserialized vault NCN slasher ticket data.
+    pub async fn get_vault_ncn_slasher_tickets(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, VaultNcnSlasherTicket)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = get_rpc_program_accounts_config::<VaultNcnSlasherTicket>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let tickets: Vec<(Pubkey, VaultNcnSlasherTicket)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                VaultNcnSlasherTicket::try_from_slice_unchecked(&acc.data)
+                    .map_or(None, |v| Some((pubkey, *v)))
+            })
+            .collect();
+
+        Ok(tickets)
+    }
+
+    /// Retrieves all existing `VaultUpdateStateTracker` accounts associated with the program.
+    ///
+    /// # Returns
+    ///
+    /// An `anyhow::Result` containing a vector of `(Pubkey, VaultUpdateStateTracker, u64)` tuples.
+    /// Each tuple represents a tracker account not yet closed and includes:
+    /// - `Pubkey`: The public key of the tracker account.
+    /// - `VaultUpdateStateTracker`: The deserialized tracker data.
+    /// - `u64`: The lamports of the tracker account, refunded to the payer when it is closed.
+    pub async fn get_vault_update_state_trackers(
+        &self,
+    ) -> anyhow::Result<Vec<(Pubkey, VaultUpdateStateTracker, u64)>> {
+        let rpc_client = self.get_rpc_client();
+        let config = get_rpc_program_accounts_config::<VaultUpdateStateTracker>()?;
+
+        let accounts = rpc_client
+            .get_program_accounts_with_config(&self.vault_program_id, config)
+            .await?;
+
+        let trackers: Vec<(Pubkey, VaultUpdateStateTracker, u64)> = accounts
+            .into_iter()
+            .filter_map(|(pubkey, acc)| {
+                VaultUpdateStateTracker::try_from_slice_unchecked(&acc.data)
+                    .map_or(None, |v| Some((pubkey, *v, acc.lamports)))
+            })
+            .collect();
+
+        Ok(trackers)
+    }
+
     /// Retrieves the `VaultUpdateStateTracker` for a specific vault and epoch.
     ///
     /// # Returns
@@ -349,6 +477,9 @@ impl VaultHandler {
 
     /// Performs a complete vault update cycle: initializes tracker, cranks it, and closes it.
     ///
+    /// With a [`StateStore`], each stage is recorded as it completes, so that a restarted cranker
+    /// resumes from the last recorded stage and skips vaults it already updated this epoch.
+    ///
     /// # Returns
     ///
     /// Returns `anyhow::Result<()>` indicating success or failure of the update operation.
@@ -364,33 +495,60 @@ impl VaultHandler {
         let tracker_pubkey =
             VaultUpdateStateTracker::find_program_address(&self.vault_program_id, vault, epoch).0;
 
-        log::info!("Updating vault: {vault}");
+        let stage = match &self.state {
+            Some(state) => state.stage(vault, epoch)?,
+            None => UpdateStage::Pending,
+        };
+        if stage == UpdateStage::Completed {
+            log::info!("Vault already updated for epoch {epoch}: {vault}");
+            return Ok(());
+        }
+
+        log::info!("Updating vault: {vault}, from stage: {stage:?}");
 
         // Initialize
-        if let Err(e) = self.get_update_state_tracker(vault, epoch).await {
-            log::info!("Get tracker failed, initializing. Expecting AccountNotFound: {e}");
-            self.initialize_vault_update_state_tracker(payer, vault, tracker_pubkey)
-                .await?;
-        }
+        if stage < UpdateStage::TrackerInitialized {
+            let mut signatures = Vec::new();
+            if let Err(e) = self.get_update_state_tracker(vault, epoch).await {
+                log::info!("Get tracker failed, initializing. Expecting AccountNotFound: {e}");
+                let signature = self
+                    .initialize_vault_update_state_tracker(payer, vault, tracker_pubkey)
+                    .await
+                    .inspect_err(|e| {
+                        self.record_failure(vault, epoch, UpdateStage::TrackerInitialized, e)
+                    })?;
+                signatures.push(signature);
+            }
+            self.record_stage(vault, epoch, UpdateStage::TrackerInitialized, &signatures)?;
 
-        log::info!("Initialized tracker for vault: {vault}, tracker: {tracker_pubkey}");
+            log::info!("Initialized tracker for vault: {vault}, tracker: {tracker_pubkey}");
+        }
 
         // Crank
-        self.crank(slot, config, payer, vault, operators, tracker_pubkey)
-            .await?;
+        if stage < UpdateStage::Cranked {
+            self.crank(slot, config, payer, vault, operators, tracker_pubkey)
+                .await
+                .inspect_err(|e| self.record_failure(vault, epoch, UpdateStage::Cranked, e))?;
+            self.record_stage(vault, epoch, UpdateStage::Cranked, &[])?;
 
-        log::info!("Cranked vault: {vault}");
+            log::info!("Cranked vault: {vault}");
+        }
 
         // Close
         let tracker = self.get_update_state_tracker(vault, epoch).await?;
         if operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)? {
-            self.close_vault_update_state_tracker(payer, vault, epoch, tracker_pubkey)
-                .await?;
+            let signature = self
+                .close_vault_update_state_tracker(payer, vault, epoch, tracker_pubkey)
+                .await
+                .inspect_err(|e| self.record_failure(vault, epoch, UpdateStage::Completed, e))?;
+            self.record_stage(vault, epoch, UpdateStage::Completed, &[signature])?;
         } else {
             let context = format!(
                 "Cranking failed to update all operators for vault: {vault}, tracker: {tracker_pubkey}"
             );
-            return Err(anyhow::anyhow!(context));
+            let err = anyhow::anyhow!(context);
+            self.record_failure(vault, epoch, UpdateStage::Cranked, &err);
+            return Err(err);
         }
 
         log::info!("Closed tracker for vault: {vault}");
@@ -398,30 +556,199 @@ impl VaultHandler {
         Ok(())
     }
 
+    /// Plans the update of a vault without sending anything, simulating the first transaction.
+    ///
+    /// The plan is built from the state of the cluster, not from the [`StateStore`]: the tracker
+    /// is initialized if it does not exist, the operators not updated yet are cranked, then the
+    /// tracker is closed.
+    ///
+    /// # Returns
+    ///
+    /// Returns the planned instructions, with the compute units of the simulated ones. A failed
+    /// simulation is reported in the plan rather than as an error.
+    pub async fn plan_vault_update(
+        &self,
+        slot: u64,
+        config: &Config,
+        payer: &Keypair,
+        vault: &Pubkey,
+        operators: &[Pubkey],
+    ) -> anyhow::Result<VaultUpdatePlan> {
+        let epoch = get_epoch(slot, config.epoch_length())?;
+        let tracker_pubkey =
+            VaultUpdateStateTracker::find_program_address(&self.vault_program_id, vault, epoch).0;
+        let tracker = self.get_update_state_tracker(vault, epoch).await.ok();
+
+        let mut planned = Vec::new();
+        let mut instructions = Vec::new();
+
+        if tracker.is_none() {
+            planned.push(PlannedInstruction {
+                name: "InitializeVaultUpdateStateTracker",
+                operator: None,
+                compute_units: None,
+            });
+            instructions.push(self.initialize_vault_update_state_tracker_ix(
+                &payer.pubkey(),
+                vault,
+                tracker_pubkey,
+            ));
+        }
+
+        let all_operators_updated = match &tracker {
+            Some(tracker) => {
+                operators.is_empty() || tracker.all_operators_updated(operators.len() as u64)?
+            }
+            None => operators.is_empty(),
+        };
+        if !all_operators_updated {
+            let last_updated_index = tracker
+                .as_ref()
+                .map_or(u64::MAX, |tracker| tracker.last_updated_index());
+            let operators_iter = operators_to_crank(operators, epoch, last_updated_index)?;
+            let crank_ixs = self
+                .retrieve_non_updated_operators(
+                    &operators_iter,
+                    slot,
+                    config,
+                    vault,
+                    tracker_pubkey,
+                )
+                .await?;
+            for ix in crank_ixs {
+                planned.push(PlannedInstruction {
+                    name: "CrankVaultUpdateStateTracker",
+                    // The operator is the third account of the instruction
+                    operator: ix.accounts.get(2).map(|account| account.pubkey),
+                    compute_units: None,
+                });
+                instructions.push(ix);
+            }
+        }
+
+        planned.push(PlannedInstruction {
+            name: "CloseVaultUpdateStateTracker",
+            operator: None,
+            compute_units: None,
+        });
+        instructions.push(self.close_vault_update_state_tracker_ix(
+            &payer.pubkey(),
+            vault,
+            epoch,
+            tracker_pubkey,
+        ));
+
+        let batches =
+            self.split_instructions_by_size(&instructions, payer, PACKET_DATA_SIZE, None)?;
+
+        let mut plan = VaultUpdatePlan {
+            vault: *vault,
+            epoch,
+            instructions: planned,
+            transactions: batches.len(),
+            compute_unit_price: 0,
+            compute_units: None,
+            error: None,
+        };
+
+        // Later transactions depend on the first one landing, so they can't be simulated
+        let Some(batch) = batches.first() else {
+            return Ok(plan);
+        };
+        let rpc_client = self.get_rpc_client();
+        plan.compute_unit_price = self
+            .priority_fees
+            .compute_unit_price(&rpc_client, batch, 0)
+            .await;
+        let tx = self
+            .build_transaction(payer, batch, None, plan.compute_unit_price)
+            .await?;
+        let result = rpc_client
+            .simulate_transaction_with_config(
+                &tx,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    ..RpcSimulateTransactionConfig::default()
+                },
+            )
+            .await?
+            .value;
+
+        plan.compute_units = result.units_consumed;
+        plan.error = result.err.map(|err| err.to_string());
+        // Skip the compute budget instructions
+        let compute_units = instruction_compute_units(result.logs.as_deref().unwrap_or_default());
+        for (instruction, compute_units) in plan
+            .instructions
+            .iter_mut()
+            .zip(compute_units.into_iter().skip(2))
+        {
+            instruction.compute_units = compute_units;
+        }
+
+        Ok(plan)
+    }
+
+    /// Records that the update of `vault` reached `stage`, if there is a [`StateStore`].
+    fn record_stage(
+        &self,
+        vault: &Pubkey,
+        epoch: u64,
+        stage: UpdateStage,
+        signatures: &[Signature],
+    ) -> anyhow::Result<()> {
+        if let Some(state) = &self.state {
+            state.record_stage(vault, epoch, stage, signatures)?;
+        }
+        Ok(())
+    }
+
+    /// Records a failure of the update of `vault`, if there is a [`StateStore`]. The failure is
+    /// already being returned, so an error writing it is only logged.
+    fn record_failure(&self, vault: &Pubkey, epoch: u64, stage: UpdateStage, err: &anyhow::Error) {
+        if let Some(state) = &self.state {
+            if let Err(e) = state.record_failure(vault, epoch, stage, &format!("{err:#}")) {
+                error!("Failed to record failure for vault: {vault}, error: {e}");
+            }
+        }
+    }
+
     /// Initializes a vault update state tracker for a given epoch and vault.
     ///
     /// # Returns
     ///
-    /// Returns `anyhow::Result<()>` indicating success or failure of initialization.
+    /// Returns the signature of the initialization transaction.
     pub async fn initialize_vault_update_state_tracker(
         &self,
         payer: &Keypair,
         vault: &Pubkey,
         tracker_pubkey: Pubkey,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Signature> {
+        let init_ix =
+            self.initialize_vault_update_state_tracker_ix(&payer.pubkey(), vault, tracker_pubkey);
+
+        self.send_and_confirm_transaction_with_retry(payer, vec![init_ix])
+            .await
+    }
+
+    fn initialize_vault_update_state_tracker_ix(
+        &self,
+        payer: &Pubkey,
+        vault: &Pubkey,
+        tracker_pubkey: Pubkey,
+    ) -> Instruction {
         let mut init_ix_builder = InitializeVaultUpdateStateTrackerBuilder::new();
         init_ix_builder
             .config(self.config_address)
             .vault(*vault)
             .vault_update_state_tracker(tracker_pubkey)
-            .payer(payer.pubkey())
+            .payer(*payer)
             .withdrawal_allocation_method(WithdrawalAllocationMethod::Greedy);
         let mut init_ix = init_ix_builder.instruction();
         init_ix.program_id = self.vault_program_id;
-
-        self.send_and_confirm_transaction_with_retry(payer, vec![init_ix])
-            .await?;
-        Ok(())
+        init_ix
     }
 
     /// Retrieves operators that need to be updated and builds their crank instructions.
@@ -485,6 +812,7 @@ impl VaultHandler {
     /// - Try to crank maximum 10 times
     /// - Batch multiple operator cranks per one transaction
     /// - Cycle send transaction, check `is_already_updated`, then retry
+    /// - Record the signature of each confirmed transaction in the [`StateStore`]
     ///
     /// # Returns
     ///
@@ -499,7 +827,6 @@ impl VaultHandler {
         operators: &[Pubkey],
         tracker_pubkey: Pubkey,
     ) -> anyhow::Result<()> {
-        let rpc_client = self.get_rpc_client();
         let epoch = get_epoch(slot, config.epoch_length())?;
         let tracker = self.get_update_state_tracker(vault, epoch).await?;
 
@@ -507,32 +834,7 @@ impl VaultHandler {
             return Ok(());
         }
 
-        let end_index = (epoch as usize)
-            .checked_rem(operators.len())
-            .context("No operators to crank")?;
-
-        // Skip updated operators if cranking has already started
-        let start_index = if tracker.last_updated_index() == u64::MAX {
-            end_index
-        } else {
-            tracker.last_updated_index() as usize
-        };
-
-        let operators_iter = if start_index < end_index {
-            // Crank from start index to end index
-            operators
-                .iter()
-                .take(end_index)
-                .skip(start_index)
-                .collect::<Vec<_>>()
-        } else {
-            // Crank through operators from start index to operators.len() and then 0 to end_index
-            operators
-                .iter()
-                .skip(start_index)
-                .chain(operators.iter().take(end_index))
-                .collect::<Vec<_>>()
-        };
+        let operators_iter = operators_to_crank(operators, epoch, tracker.last_updated_index())?;
 
         // Need to send each transaction in serial since strict sequence is required
         let instructions = self
@@ -543,44 +845,159 @@ impl VaultHandler {
             return Ok(());
         }
 
-        let txs = self
-            .split_instructions_by_size(&instructions, payer, 1232)
+        let mut batches =
+            self.split_instructions_by_size(&instructions, payer, PACKET_DATA_SIZE, None)?;
+
+        // A lookup table fits more operators per transaction, for vaults that need several
+        if let Some(authority) = self
+            .lookup_table_authority
+            .as_deref()
+            .filter(|_| batches.len() > 1)
+        {
+            match self
+                .crank_with_lookup_table(
+                    payer,
+                    authority,
+                    vault,
+                    epoch,
+                    tracker_pubkey,
+                    &instructions,
+                )
+                .await
+            {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    warn!("Failed to crank vault {vault} with v0 transactions, falling back to legacy transactions: {e:#}");
+                }
+            }
+
+            // Skip the operators cranked by the v0 transactions that landed
+            let instructions = self
+                .retrieve_non_updated_operators(
+                    &operators_iter,
+                    slot,
+                    config,
+                    vault,
+                    tracker_pubkey,
+                )
+                .await?;
+            batches =
+                self.split_instructions_by_size(&instructions, payer, PACKET_DATA_SIZE, None)?;
+        }
+
+        self.send_crank_transactions(payer, vault, epoch, &batches, None)
+            .await
+    }
+
+    /// Cranks the operators of a large vault with v0 transactions, using the vault lookup table.
+    /// The table is created and extended by its `authority`, the transactions are paid by `payer`.
+    async fn crank_with_lookup_table(
+        &self,
+        payer: &Keypair,
+        authority: &Keypair,
+        vault: &Pubkey,
+        epoch: u64,
+        tracker_pubkey: Pubkey,
+        instructions: &[Instruction],
+    ) -> anyhow::Result<()> {
+        // The tracker changes every epoch, so it is not worth a slot in the table
+        let mut addresses = vec![*vault];
+        for account in instructions.iter().flat_map(|ix| ix.accounts.iter()) {
+            if !account.is_signer
+                && account.pubkey != tracker_pubkey
+                && !addresses.contains(&account.pubkey)
+            {
+                addresses.push(account.pubkey);
+            }
+        }
+
+        let lookup_table = self
+            .get_or_create_lookup_table(authority, vault, epoch, &addresses)
             .await?;
+        let batches = self.split_instructions_by_size(
+            instructions,
+            payer,
+            PACKET_DATA_SIZE,
+            Some(&lookup_table),
+        )?;
+
+        info!(
+            "Cranking vault {vault} with {} v0 transactions, lookup table: {}",
+            batches.len(),
+            lookup_table.key
+        );
+
+        self.send_crank_transactions(payer, vault, epoch, &batches, Some(&lookup_table))
+            .await
+    }
 
-        for (i, tx) in txs.iter().enumerate() {
+    /// Sends a crank transaction per batch of instructions, one at a time, in order, retrying each
+    /// up to `MAX_RETRIES` times with an escalating priority fee.
+    async fn send_crank_transactions(
+        &self,
+        payer: &Keypair,
+        vault: &Pubkey,
+        epoch: u64,
+        batches: &[Vec<Instruction>],
+        lookup_table: Option<&AddressLookupTableAccount>,
+    ) -> anyhow::Result<()> {
+        let rpc_client = self.get_rpc_client();
+
+        for (i, batch) in batches.iter().enumerate() {
             let mut retries = 0;
 
             // Retry loop for current transaction
             loop {
+                let compute_unit_price = self
+                    .priority_fees
+                    .compute_unit_price(&rpc_client, batch, retries)
+                    .await;
+                let tx = self
+                    .build_transaction(payer, batch, lookup_table, compute_unit_price)
+                    .await?;
+
                 match rpc_client
                     .send_and_confirm_transaction_with_spinner_and_commitment(
-                        tx,
+                        &tx,
                         CommitmentConfig::confirmed(),
                     )
                     .await
                 {
-                    Ok(_) => {
+                    Ok(signature) => {
                         info!(
                             "✅ Transaction {}/{} completed successfully",
                             i + 1,
-                            txs.len()
+                            batches.len()
                         );
+                        // Progress is also tracked on chain by the tracker, this keeps the history
+                        self.record_stage(
+                            vault,
+                            epoch,
+                            UpdateStage::TrackerInitialized,
+                            &[signature],
+                        )?;
                         break; // Success - move to next transaction
                     }
                     Err(err) => {
+                        METRICS.record_transaction_failure(&err);
+                        let err = describe_transaction_error(&err, &tx, &self.vault_program_id);
                         retries += 1;
 
                         if retries <= MAX_RETRIES {
                             info!(
-                            "⚠️  Transaction {}/{} failed (attempt {}/{}), retrying in 1s: {:?}",
-                            i + 1, txs.len(), retries, MAX_RETRIES, err
-                        );
+                                "⚠️  Transaction {}/{} failed (attempt {}/{}), retrying in 1s: {}",
+                                i + 1,
+                                batches.len(),
+                                retries,
+                                MAX_RETRIES,
+                                err
+                            );
                             sleep(Duration::from_secs(1)).await;
                         } else {
                             error!(
-                                "❌ Transaction {}/{} failed permanently after {} retries: {:?}",
+                                "❌ Transaction {}/{} failed permanently after {} retries: {}",
                                 i + 1,
-                                txs.len(),
+                                batches.len(),
                                 MAX_RETRIES,
                                 err
                             );
@@ -598,35 +1015,504 @@ impl VaultHandler {
 
         info!(
             "🎉 All {} transactions completed successfully for vault cranking!",
-            txs.len()
+            batches.len()
         );
         Ok(())
     }
 
+    /// Returns the lookup table of the vault owned by the `authority`, with all the `addresses`.
+    /// The table is created or extended as needed, paid by the authority. Its address is kept in
+    /// the state store, which is how the table is found again.
+    async fn get_or_create_lookup_table(
+        &self,
+        authority: &Keypair,
+        vault: &Pubkey,
+        epoch: u64,
+        addresses: &[Pubkey],
+    ) -> anyhow::Result<AddressLookupTableAccount> {
+        let state = self
+            .state
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Lookup tables need the state store"))?;
+        let rpc_client = self.get_rpc_client();
+
+        let lookup_table = match self.get_lookup_table(state, authority, vault).await? {
+            Some(lookup_table) => lookup_table,
+            None => {
+                let recent_slot = rpc_client
+                    .get_slot_with_commitment(CommitmentConfig::finalized())
+                    .await?;
+                let (create_ix, key) =
+                    create_lookup_table(authority.pubkey(), authority.pubkey(), recent_slot);
+                let extend_ix = extend_lookup_table(
+                    key,
+                    authority.pubkey(),
+                    Some(authority.pubkey()),
+                    vec![*vault],
+                );
+                self.send_and_confirm_transaction_with_retry(authority, vec![create_ix, extend_ix])
+                    .await?;
+
+                info!("Created lookup table {key} for vault {vault}");
+
+                AddressLookupTableAccount {
+                    key,
+                    addresses: vec![*vault],
+                }
+            }
+        };
+        state.record_lookup_table(vault, &lookup_table.key, epoch)?;
+
+        let missing: Vec<Pubkey> = addresses
+            .iter()
+            .filter(|address| !lookup_table.addresses.contains(address))
+            .copied()
+            .collect();
+        if missing.is_empty() {
+            return Ok(lookup_table);
+        }
+        if lookup_table.addresses.len() + missing.len() > LOOKUP_TABLE_MAX_ADDRESSES {
+            return Err(anyhow::anyhow!(
+                "Lookup table {} of vault {vault} is full",
+                lookup_table.key
+            ));
+        }
+
+        for chunk in missing.chunks(MAX_EXTEND_LOOKUP_TABLE_ADDRESSES) {
+            let extend_ix = extend_lookup_table(
+                lookup_table.key,
+                authority.pubkey(),
+                Some(authority.pubkey()),
+                chunk.to_vec(),
+            );
+            self.send_and_confirm_transaction_with_retry(authority, vec![extend_ix])
+                .await?;
+        }
+
+        info!(
+            "Extended lookup table {} of vault {vault} with {} addresses",
+            lookup_table.key,
+            missing.len()
+        );
+
+        // Addresses can be looked up from the slot after they were added
+        let extended_slot = rpc_client.get_slot().await?;
+        while rpc_client.get_slot().await? <= extended_slot {
+            sleep(Duration::from_millis(400)).await;
+        }
+
+        let mut addresses = lookup_table.addresses;
+        addresses.extend(missing);
+        Ok(AddressLookupTableAccount {
+            key: lookup_table.key,
+            addresses,
+        })
+    }
+
+    /// Fetches the lookup table of the vault recorded in the state store. A table that was closed,
+    /// deactivated or is no longer owned by `authority` is forgotten, and `None` is returned so a
+    /// new one is created.
+    async fn get_lookup_table(
+        &self,
+        state: &StateStore,
+        authority: &Keypair,
+        vault: &Pubkey,
+    ) -> anyhow::Result<Option<AddressLookupTableAccount>> {
+        let Some(record) = state.lookup_table(vault)? else {
+            return Ok(None);
+        };
+        let key = Pubkey::from_str(&record.address)?;
+
+        let account = self
+            .get_rpc_client()
+            .get_account_with_commitment(&key, CommitmentConfig::confirmed())
+            .await?
+            .value;
+        let lookup_table = account
+            .as_ref()
+            .and_then(|account| AddressLookupTable::deserialize(&account.data).ok());
+        match lookup_table {
+            Some(lookup_table)
+                if lookup_table.meta.deactivation_slot == u64::MAX
+                    && lookup_table.meta.authority == Some(authority.pubkey()) =>
+            {
+                Ok(Some(AddressLookupTableAccount {
+                    key,
+                    addresses: lookup_table.addresses.to_vec(),
+                }))
+            }
+            _ => {
+                warn!(
+                    "Lookup table {key} of vault {vault} can't be used anymore, creating a new one"
+                );
+                // Closed once deactivated, or forgotten if it is already closed
+                state.retire_lookup_table(vault, &key)?;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Reclaims the rent of the lookup tables no longer used, refunding it to the `authority`.
+    ///
+    /// Tables of vaults that weren't cranked with them for `LOOKUP_TABLE_IDLE_EPOCHS` epochs are
+    /// retired along with the tables that can't be used anymore. Retired tables are deactivated,
+    /// then closed once the deactivation cooled down.
+    pub async fn reclaim_lookup_tables(
+        &self,
+        authority: &Keypair,
+        slot: u64,
+        epoch: u64,
+    ) -> anyhow::Result<()> {
+        let Some(state) = &self.state else {
+            return Ok(());
+        };
+
+        for (vault, record) in state.lookup_tables()? {
+            if record
+                .last_used_epoch
+                .saturating_add(LOOKUP_TABLE_IDLE_EPOCHS)
+                >= epoch
+            {
+                continue;
+            }
+
+            let key = Pubkey::from_str(&record.address)?;
+            state.retire_lookup_table(&vault, &key)?;
+
+            info!(
+                "Retiring lookup table {key} of vault {vault}, unused since epoch {}",
+                record.last_used_epoch
+            );
+        }
+
+        let rpc_client = self.get_rpc_client();
+        for (key, vault) in state.retired_lookup_tables()? {
+            let Some(account) = rpc_client
+                .get_account_with_commitment(&key, CommitmentConfig::confirmed())
+                .await?
+                .value
+            else {
+                state.remove_retired_lookup_table(&key)?;
+                continue;
+            };
+            let meta = AddressLookupTable::deserialize(&account.data)?.meta;
+            if meta.authority != Some(authority.pubkey()) {
+                warn!(
+                    "Lookup table {key} of vault {vault} isn't owned by {}, forgetting it",
+                    authority.pubkey()
+                );
+                state.remove_retired_lookup_table(&key)?;
+                continue;
+            }
+
+            if meta.deactivation_slot == u64::MAX {
+                let deactivate_ix = deactivate_lookup_table(key, authority.pubkey());
+                self.send_and_confirm_transaction_with_retry(authority, vec![deactivate_ix])
+                    .await
+                    .with_context(|| format!("Failed to deactivate lookup table {key}"))?;
+                info!("Deactivated lookup table {key} of vault {vault}");
+                continue;
+            }
+
+            // A table can be closed once its deactivation slot left the slot hashes
+            if meta
+                .deactivation_slot
+                .saturating_add(SLOT_HASHES_MAX_ENTRIES as u64)
+                >= slot
+            {
+                continue;
+            }
+
+            let close_ix = close_lookup_table(key, authority.pubkey(), authority.pubkey());
+            self.send_and_confirm_transaction_with_retry(authority, vec![close_ix])
+                .await
+                .with_context(|| format!("Failed to close lookup table {key}"))?;
+            state.remove_retired_lookup_table(&key)?;
+
+            info!(
+                "Closed lookup table {key} of vault {vault}, reclaiming {} lamports",
+                account.lamports
+            );
+        }
+
+        Ok(())
+    }
+
     /// Closes a vault update state tracker for a given epoch and vault.
     ///
     /// # Returns
     ///
-    /// Returns `anyhow::Result<()>` indicating success or failure of closing.
+    /// Returns the signature of the closing transaction.
     pub async fn close_vault_update_state_tracker(
         &self,
         payer: &Keypair,
         vault: &Pubkey,
         epoch: u64,
         tracker_pubkey: Pubkey,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Signature> {
+        let close_ix =
+            self.close_vault_update_state_tracker_ix(&payer.pubkey(), vault, epoch, tracker_pubkey);
+
+        self.send_and_confirm_transaction_with_retry(payer, vec![close_ix])
+            .await
+    }
+
+    fn close_vault_update_state_tracker_ix(
+        &self,
+        payer: &Pubkey,
+        vault: &Pubkey,
+        epoch: u64,
+        tracker_pubkey: Pubkey,
+    ) -> Instruction {
         let mut close_ix_builder = CloseVaultUpdateStateTrackerBuilder::new();
         close_ix_builder
             .config(self.config_address)
             .vault(*vault)
-            .payer(payer.pubkey())
+            .payer(*payer)
             .vault_update_state_tracker(tracker_pubkey)
             .ncn_epoch(epoch);
         let mut close_ix = close_ix_builder.instruction();
         close_ix.program_id = self.vault_program_id;
+        close_ix
+    }
 
-        self.send_and_confirm_transaction_with_retry(payer, vec![close_ix])
+    /// Updates the vault's tracked token balance, minting the reward fee to the vault fee wallet.
+    /// Run after a full vault update, since `UpdateVaultBalance` requires the vault to be updated
+    /// for the current epoch.
+    ///
+    /// # Returns
+    ///
+    /// Returns `anyhow::Result<()>` indicating success or failure of the balance update.
+    pub async fn update_vault_balance(
+        &self,
+        payer: &Keypair,
+        vault_pubkey: &Pubkey,
+        vault: &Vault,
+    ) -> anyhow::Result<()> {
+        let mut ix_builder = UpdateVaultBalanceBuilder::new();
+        ix_builder
+            .config(self.config_address)
+            .vault(*vault_pubkey)
+            .vault_token_account(get_associated_token_address(
+                vault_pubkey,
+                &vault.supported_mint,
+            ))
+            .vrt_mint(vault.vrt_mint)
+            .vault_fee_token_account(get_associated_token_address(
+                &vault.fee_wallet,
+                &vault.vrt_mint,
+            ));
+        let mut ix = ix_builder.instruction();
+        ix.program_id = self.vault_program_id;
+
+        self.send_and_confirm_transaction_with_retry(payer, vec![ix])
             .await?;
         Ok(())
     }
+
+    /// Burns the withdrawal tickets of a vault that are ready to be withdrawn, sending the
+    /// withdrawn tokens to each staker.
+    ///
+    /// - Tickets are skipped until they are withdrawable
+    /// - Vaults with a `mint_burn_admin` are skipped, since burning requires its signature
+    /// - Tickets whose staker has no token account of the supported mint are skipped. The
+    ///   cranker doesn't create it, since stakers could close it to take its rent
+    /// - The fee token accounts are created if they don't exist
+    ///
+    /// A ticket that fails to burn is logged and doesn't stop the burn of the other tickets.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of burned tickets.
+    pub async fn burn_withdrawable_tickets(
+        &self,
+        slot: u64,
+        config: &Config,
+        payer: &Keypair,
+        vault_pubkey: &Pubkey,
+        vault: &Vault,
+        tickets: &[(Pubkey, VaultStakerWithdrawalTicket)],
+    ) -> anyhow::Result<usize> {
+        if vault.mint_burn_admin.ne(&Pubkey::default()) {
+            info!("Skipping withdrawal tickets of vault {vault_pubkey}, burning requires the mint burn admin");
+            return Ok(0);
+        }
+
+        let mut withdrawable = Vec::new();
+        for (ticket_pubkey, ticket) in tickets {
+            if ticket.is_withdrawable(slot, config.epoch_length())? {
+                withdrawable.push((ticket_pubkey, ticket));
+            }
+        }
+
+        let staker_token_accounts: Vec<Pubkey> = withdrawable
+            .iter()
+            .map(|(_, ticket)| get_associated_token_address(&ticket.staker, &vault.supported_mint))
+            .collect();
+        let staker_token_accounts =
+            get_multiple_accounts_batched(&staker_token_accounts, &Arc::new(self.get_rpc_client()))
+                .await?;
+
+        let mut burned = 0;
+        for ((ticket_pubkey, ticket), staker_token_account) in
+            withdrawable.into_iter().zip(staker_token_accounts)
+        {
+            if staker_token_account.is_none() {
+                info!(
+                    "Skipping withdrawal ticket {ticket_pubkey} of vault {vault_pubkey}, staker {} has no token account",
+                    ticket.staker
+                );
+                continue;
+            }
+
+            match self
+                .burn_withdrawal_ticket(payer, config, vault_pubkey, vault, ticket_pubkey, ticket)
+                .await
+            {
+                Ok(()) => {
+                    burned += 1;
+                    info!("Burned withdrawal ticket: {ticket_pubkey}, vault: {vault_pubkey}");
+                }
+                Err(e) => {
+                    error!("Failed to burn withdrawal ticket {ticket_pubkey} of vault {vault_pubkey}: {e:#}");
+                }
+            }
+        }
+
+        Ok(burned)
+    }
+
+    /// Burns a withdrawal ticket on behalf of its staker, whose token account shall exist.
+    ///
+    /// # Returns
+    ///
+    /// Returns `anyhow::Result<()>` indicating success or failure of the burn.
+    async fn burn_withdrawal_ticket(
+        &self,
+        payer: &Keypair,
+        config: &Config,
+        vault_pubkey: &Pubkey,
+        vault: &Vault,
+        ticket_pubkey: &Pubkey,
+        ticket: &VaultStakerWithdrawalTicket,
+    ) -> anyhow::Result<()> {
+        let staker_token_account =
+            get_associated_token_address(&ticket.staker, &vault.supported_mint);
+        let vault_fee_token_account =
+            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
+        let program_fee_token_account =
+            get_associated_token_address(&config.program_fee_wallet, &vault.vrt_mint);
+
+        let create_token_account_ixs = [
+            (&vault.fee_wallet, &vault.vrt_mint),
+            (&config.program_fee_wallet, &vault.vrt_mint),
+        ]
+        .into_iter()
+        .map(|(owner, mint)| {
+            create_associated_token_account_idempotent(
+                &payer.pubkey(),
+                owner,
+                mint,
+                &spl_token::id(),
+            )
+        });
+
+        let mut ix_builder = BurnWithdrawalTicketBuilder::new();
+        ix_builder
+            .config(self.config_address)
+            .vault(*vault_pubkey)
+            .vault_token_account(get_associated_token_address(
+                vault_pubkey,
+                &vault.supported_mint,
+            ))
+            .vrt_mint(vault.vrt_mint)
+            .staker(ticket.staker)
+            .staker_token_account(staker_token_account)
+            .vault_staker_withdrawal_ticket(*ticket_pubkey)
+            .vault_staker_withdrawal_ticket_token_account(get_associated_token_address(
+                ticket_pubkey,
+                &vault.vrt_mint,
+            ))
+            .vault_fee_token_account(vault_fee_token_account)
+            .program_fee_token_account(program_fee_token_account);
+        let mut burn_ix = ix_builder.instruction();
+        burn_ix.program_id = self.vault_program_id;
+
+        let instructions = create_token_account_ixs.chain([burn_ix]).collect();
+        self.send_and_confirm_transaction_with_retry(payer, instructions)
+            .await?;
+        Ok(())
+    }
+}
+
+/// Compiles and signs a transaction, as a v0 transaction with a lookup table and a legacy one
+/// without.
+fn compile_transaction(
+    payer: &Keypair,
+    instructions: &[Instruction],
+    lookup_table: Option<&AddressLookupTableAccount>,
+    blockhash: Hash,
+) -> anyhow::Result<VersionedTransaction> {
+    let message = match lookup_table {
+        Some(lookup_table) => VersionedMessage::V0(v0::Message::try_compile(
+            &payer.pubkey(),
+            instructions,
+            std::slice::from_ref(lookup_table),
+            blockhash,
+        )?),
+        None => VersionedMessage::Legacy(Message::new_with_blockhash(
+            instructions,
+            Some(&payer.pubkey()),
+            &blockhash,
+        )),
+    };
+    Ok(VersionedTransaction::try_new(message, &[payer])?)
+}
+
+/// Returns the size of a serialized transaction
+fn transaction_size(tx: &VersionedTransaction) -> usize {
+    // Compact array length of the signatures, which is 1 byte below 128 signatures
+    1 + tx.signatures.len() * std::mem::size_of::<Signature>() + tx.message.serialize().len()
+}
+
+/// Returns the operators left to crank, in cranking order. Cranking starts at the operator at
+/// index `epoch % operators.len()` and wraps around.
+///
+/// # Arguments
+/// * `operators` - The operators of the vault, sorted by `VaultOperatorDelegation` index
+/// * `epoch` - The epoch of the update
+/// * `last_updated_index` - The index of the last operator cranked, `u64::MAX` if none was
+fn operators_to_crank(
+    operators: &[Pubkey],
+    epoch: u64,
+    last_updated_index: u64,
+) -> anyhow::Result<Vec<&Pubkey>> {
+    let end_index = (epoch as usize)
+        .checked_rem(operators.len())
+        .context("No operators to crank")?;
+
+    // Skip updated operators if cranking has already started
+    let start_index = if last_updated_index == u64::MAX {
+        end_index
+    } else {
+        last_updated_index as usize
+    };
+
+    let operators_iter = if start_index < end_index {
+        // Crank from start index to end index
+        operators
+            .iter()
+            .take(end_index)
+            .skip(start_index)
+            .collect::<Vec<_>>()
+    } else {
+        // Crank through operators from start index to operators.len() and then 0 to end_index
+        operators
+            .iter()
+            .skip(start_index)
+            .chain(operators.iter().take(end_index))
+            .collect::<Vec<_>>()
+    };
+
+    Ok(operators_iter)
 }
@@ -0,0 +1,44 @@
+use std::ops::Range;
+
+use num_derive::FromPrimitive;
+use solana_decode_error::DecodeError;
+use solana_program::program_error::ProgramError;
+use thiserror::Error;
+
+use crate::ErrorCode;
+
+/// Errors returned by `jito_jsm_core`, shared by the vault and restaking programs
+#[derive(Debug, Error, PartialEq, Eq, FromPrimitive)]
+pub enum CoreError {
+    #[error("Bad epoch length")]
+    BadEpochLength = 4000,
+}
+
+impl ErrorCode for CoreError {
+    const SOURCE: &'static str = "jito::core";
+    const CODES: Range<u32> = 4000..4100;
+}
+
+impl<T> DecodeError<T> for CoreError {
+    fn type_of() -> &'static str {
+        Self::SOURCE
+    }
+}
+
+impl From<CoreError> for ProgramError {
+    fn from(e: CoreError) -> Self {
+        Self::Custom(e as u32)
+    }
+}
+
+impl From<CoreError> for u64 {
+    fn from(e: CoreError) -> Self {
+        e as Self
+    }
+}
+
+impl From<CoreError> for u32 {
+    fn from(e: CoreError) -> Self {
+        e as Self
+    }
+}
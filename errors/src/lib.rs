@@ -0,0 +1,174 @@
+//! Registry of the custom program error codes returned by the vault and restaking programs.
+//!
+//! Each program returns `ProgramError::Custom(code)` for its own errors. The codes are split
+//! into ranges that never overlap, so a code identifies its error regardless of the program:
+//!
+//! | Range      | Errors                                                      |
+//! |------------|-------------------------------------------------------------|
+//! | 0..1000    | Errors of programs called through CPI, e.g. SPL Token       |
+//! | 1000..4000 | `VaultError`                                                |
+//! | 4000..5000 | [`CoreError`] and [`LoaderError`], shared by both programs  |
+//! | 5000..8000 | `RestakingError`                                            |
+//!
+//! The SDKs wrap [`decode_program_error`] for their program.
+
+use std::{fmt, ops::Range};
+
+use num_traits::FromPrimitive;
+use solana_program::pubkey::Pubkey;
+
+mod core;
+mod loader;
+
+pub use crate::{
+    core::CoreError,
+    loader::{AccountError, LoaderError},
+};
+
+/// The codes of errors returned by programs called through CPI, which are passed through as is
+pub const CPI_ERROR_CODES: Range<u32> = 0..1000;
+
+/// The codes of the vault program's own errors
+pub const VAULT_ERROR_CODES: Range<u32> = 1000..4000;
+
+/// The codes of [`CoreError`] and [`LoaderError`]
+pub const CORE_ERROR_CODES: Range<u32> = 4000..5000;
+
+/// The codes of the restaking program's own errors
+pub const RESTAKING_ERROR_CODES: Range<u32> = 5000..8000;
+
+/// An error enum whose discriminants are custom program error codes
+pub trait ErrorCode: fmt::Debug + fmt::Display + FromPrimitive {
+    /// The name of the error source, as returned by `DecodeError::type_of`
+    const SOURCE: &'static str;
+
+    /// The registered range the codes fall in
+    const CODES: Range<u32>;
+
+    /// Returns the error with the given code, if there is one
+    fn from_code(code: u32) -> Option<Self> {
+        if Self::CODES.contains(&code) {
+            Self::from_u32(code)
+        } else {
+            None
+        }
+    }
+}
+
+/// A custom program error code decoded into its name and description
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedError {
+    /// The program that returned the error
+    pub program_id: Pubkey,
+    /// The error source, e.g. `jito::vault`
+    pub source: &'static str,
+    /// The custom program error code
+    pub code: u32,
+    /// The name of the error variant, e.g. `VaultUpdateNeeded`
+    pub name: String,
+    /// A human-readable description of the error
+    pub description: String,
+}
+
+impl DecodedError {
+    fn new<E: ErrorCode>(program_id: &Pubkey, code: u32, error: &E) -> Self {
+        Self {
+            program_id: *program_id,
+            source: E::SOURCE,
+            code,
+            name: format!("{error:?}"),
+            description: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DecodedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (custom program error: {:#x}): {}",
+            self.name, self.code, self.description
+        )
+    }
+}
+
+/// Decodes a custom error code returned by the program at `program_id`, whose own errors are
+/// `E`. Returns `None` for codes the program doesn't define, including errors of programs it
+/// calls through CPI.
+pub fn decode_program_error<E: ErrorCode>(program_id: &Pubkey, code: u32) -> Option<DecodedError> {
+    if let Some(error) = E::from_code(code) {
+        return Some(DecodedError::new(program_id, code, &error));
+    }
+    if let Some(error) = CoreError::from_code(code) {
+        return Some(DecodedError::new(program_id, code, &error));
+    }
+    LoaderError::from_code(code).map(|error| DecodedError::new(program_id, code, &error))
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_error::ProgramError;
+
+    use super::*;
+
+    #[test]
+    fn test_ranges_do_not_overlap() {
+        assert_eq!(CPI_ERROR_CODES.end, VAULT_ERROR_CODES.start);
+        assert_eq!(VAULT_ERROR_CODES.end, CORE_ERROR_CODES.start);
+        assert_eq!(CORE_ERROR_CODES.end, RESTAKING_ERROR_CODES.start);
+        for code in 0..u16::MAX.into() {
+            if CoreError::from_u32(code).is_some() {
+                assert!(CoreError::CODES.contains(&code));
+                assert!(CORE_ERROR_CODES.contains(&code));
+            }
+            if LoaderError::from_u32(code).is_some() {
+                assert!(LoaderError::CODES.contains(&code));
+                assert!(CORE_ERROR_CODES.contains(&code));
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_loader_error() {
+        let program_id = Pubkey::new_unique();
+        let decoded =
+            decode_program_error::<CoreError>(&program_id, LoaderError::InvalidAccountOwner as u32)
+                .unwrap();
+        assert_eq!(decoded.source, "jito::loader");
+        assert_eq!(decoded.name, "InvalidAccountOwner");
+
+        let error = AccountError::new("vault_fee_token_account", LoaderError::InvalidAccountOwner);
+        assert_eq!(error.to_string(), "vault_fee_token_account: wrong owner");
+        assert_eq!(
+            ProgramError::from(error),
+            ProgramError::Custom(LoaderError::InvalidAccountOwner as u32)
+        );
+
+        // The role is only logged, so another account failing the same way returns the same code
+        let other = AccountError::new("token_program", LoaderError::InvalidAccountOwner);
+        assert_eq!(ProgramError::from(other), ProgramError::from(error));
+    }
+
+    #[test]
+    fn test_decode_core_error() {
+        let program_id = Pubkey::new_unique();
+        let decoded =
+            decode_program_error::<CoreError>(&program_id, CoreError::BadEpochLength as u32)
+                .unwrap();
+        assert_eq!(decoded.program_id, program_id);
+        assert_eq!(decoded.source, "jito::core");
+        assert_eq!(decoded.name, "BadEpochLength");
+        assert_eq!(
+            decoded.to_string(),
+            "BadEpochLength (custom program error: 0xfa0): Bad epoch length"
+        );
+    }
+
+    #[test]
+    fn test_decode_unknown_code() {
+        let program_id = Pubkey::new_unique();
+        // SPL Token's NotRentExempt, passed through from a CPI
+        assert_eq!(decode_program_error::<CoreError>(&program_id, 0), None);
+        assert_eq!(decode_program_error::<CoreError>(&program_id, 4999), None);
+    }
+}
@@ -0,0 +1,102 @@
+use std::{fmt, ops::Range};
+
+use num_derive::FromPrimitive;
+use solana_decode_error::DecodeError;
+use solana_program::{msg, program_error::ProgramError};
+use thiserror::Error;
+
+use crate::ErrorCode;
+
+/// Errors returned when an account passed to an instruction fails to load.
+///
+/// The code only says why an account failed to load, not which account it was. The role of the
+/// account is logged by [`AccountError`] and is not part of the code, so clients that need it
+/// have to read the transaction logs.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum LoaderError {
+    #[error("not a signer")]
+    AccountNotSigner = 4100,
+    #[error("not writable")]
+    AccountNotWritable,
+    #[error("wrong owner")]
+    InvalidAccountOwner,
+    #[error("account data is empty")]
+    AccountDataEmpty,
+    #[error("account is already initialized")]
+    AccountAlreadyInitialized,
+    #[error("account data is invalid")]
+    InvalidAccountData,
+    #[error("wrong discriminator")]
+    InvalidDiscriminator,
+    #[error("not at the expected address")]
+    InvalidAddress,
+    #[error("wrong program")]
+    InvalidProgramId,
+    #[error("wrong token account owner")]
+    InvalidTokenOwner,
+    #[error("wrong mint")]
+    InvalidMint,
+}
+
+impl ErrorCode for LoaderError {
+    const SOURCE: &'static str = "jito::loader";
+    const CODES: Range<u32> = 4100..4200;
+}
+
+impl<T> DecodeError<T> for LoaderError {
+    fn type_of() -> &'static str {
+        Self::SOURCE
+    }
+}
+
+impl From<LoaderError> for ProgramError {
+    fn from(e: LoaderError) -> Self {
+        Self::Custom(e as u32)
+    }
+}
+
+impl From<LoaderError> for u64 {
+    fn from(e: LoaderError) -> Self {
+        e as Self
+    }
+}
+
+impl From<LoaderError> for u32 {
+    fn from(e: LoaderError) -> Self {
+        e as Self
+    }
+}
+
+/// A [`LoaderError`] along with the role of the account that failed to load, which is the name
+/// of the account in the instruction, e.g. `vault_fee_token_account: wrong owner`.
+///
+/// Converting into a `ProgramError` logs the role and returns the [`LoaderError`] code. The role
+/// is log-only: two accounts that fail with the same [`LoaderError`] return the same code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountError {
+    /// The name of the account in the instruction
+    pub role: &'static str,
+    /// Why the account failed to load
+    pub error: LoaderError,
+}
+
+impl AccountError {
+    pub const fn new(role: &'static str, error: LoaderError) -> Self {
+        Self { role, error }
+    }
+}
+
+impl fmt::Display for AccountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.role, self.error)
+    }
+}
+
+impl std::error::Error for AccountError {}
+
+impl From<AccountError> for ProgramError {
+    fn from(e: AccountError) -> Self {
+        msg!("{}", e);
+        e.error.into()
+    }
+}
@@ -0,0 +1,165 @@
+//! Records the compute units consumed by each vault and restaking instruction when the tests run
+//! against the SBF build of the programs (`SBF_OUT_DIR` is set), and fails the test that ran it
+//! when the instruction has no entry in the checked-in baseline or regresses against it. The
+//! native builtins only meter their CPIs, so native runs are not recorded.
+//!
+//! The baseline lives in `compute_units/sbf.txt`. While that file does not exist the units are
+//! only logged. Running the SBF tests with `UPDATE_CU_BASELINE=1` adds missing instructions and
+//! raises the baseline to the highest units consumed by each instruction; delete the file first if
+//! instructions got cheaper. The update has to run under `cargo test` (see the README), as
+//! `cargo nextest` runs every test in its own process, which would race on the file.
+
+use std::{
+    any::type_name,
+    collections::BTreeMap,
+    fmt::Debug,
+    fs,
+    path::PathBuf,
+    sync::{Mutex, OnceLock},
+};
+
+use borsh::BorshDeserialize;
+use log::warn;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::transaction::Transaction;
+
+/// How much an instruction may exceed its baseline, in percent, before the test fails. The
+/// baseline is the highest units consumed across runs, so it already covers the PDA bump searches
+/// over randomly generated keys.
+const REGRESSION_THRESHOLD_PERCENT: u64 = 10;
+
+/// The highest units consumed by each instruction in this run, used to update the baseline
+static RECORDED: Mutex<BTreeMap<String, u64>> = Mutex::new(BTreeMap::new());
+
+/// The checked-in baseline, or `None` if there is no baseline file yet
+static BASELINE: OnceLock<Option<BTreeMap<String, u64>>> = OnceLock::new();
+
+fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("compute_units")
+        .join("sbf.txt")
+}
+
+fn sbf() -> bool {
+    std::env::var_os("SBF_OUT_DIR").is_some()
+}
+
+fn update_baseline() -> bool {
+    std::env::var_os("UPDATE_CU_BASELINE").is_some()
+}
+
+/// Reads the baseline file, one `<instruction> <compute units>` pair per line
+fn read_baseline() -> Option<BTreeMap<String, u64>> {
+    let contents = fs::read_to_string(baseline_path()).ok()?;
+    let baseline = contents
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (instruction, units) = line
+                .split_once(' ')
+                .unwrap_or_else(|| panic!("invalid compute unit baseline line: {line}"));
+            let units = units
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid compute unit baseline line: {line}"));
+            (instruction.to_string(), units)
+        })
+        .collect();
+    Some(baseline)
+}
+
+fn write_baseline(recorded: &BTreeMap<String, u64>) {
+    let mut baseline = read_baseline().unwrap_or_default();
+    for (instruction, units) in recorded {
+        let entry = baseline.entry(instruction.clone()).or_default();
+        *entry = (*entry).max(*units);
+    }
+
+    let mut contents = String::from("# instruction compute_units\n");
+    for (instruction, units) in baseline {
+        contents.push_str(&format!("{instruction} {units}\n"));
+    }
+    let path = baseline_path();
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// Records the compute units consumed by an instruction, panicking if it has no baseline or
+/// regressed by more than the threshold over it. Only logs the units if there is no baseline file.
+pub fn record_compute_units(instruction: &str, units: u64) {
+    if update_baseline() {
+        let mut recorded = RECORDED.lock().unwrap();
+        let entry = recorded.entry(instruction.to_string()).or_default();
+        if units > *entry {
+            *entry = units;
+            write_baseline(&recorded);
+        }
+        return;
+    }
+
+    let Some(baseline) = BASELINE.get_or_init(|| {
+        let baseline = read_baseline();
+        if baseline.is_none() {
+            warn!(
+                "No compute unit baseline in {}, only logging compute units",
+                baseline_path().display()
+            );
+        }
+        baseline
+    }) else {
+        warn!("{instruction} consumed {units} compute units");
+        return;
+    };
+    let Some(&baseline) = baseline.get(instruction) else {
+        panic!(
+            "{instruction} has no compute unit baseline in {}. Rerun the tests with \
+             UPDATE_CU_BASELINE=1 to add it.",
+            baseline_path().display()
+        );
+    };
+    let threshold = baseline * REGRESSION_THRESHOLD_PERCENT / 100;
+    assert!(
+        units <= baseline + threshold,
+        "{instruction} consumed {units} compute units, more than {threshold} over its baseline \
+         of {baseline}. Rerun the tests with UPDATE_CU_BASELINE=1 if the increase is expected."
+    );
+}
+
+/// Returns the name of a Borsh-encoded instruction, e.g. `VaultInstruction::MintTo`
+fn instruction_name<T: BorshDeserialize + Debug>(data: &[u8]) -> Option<String> {
+    let instruction = T::try_from_slice(data).ok()?;
+    let variant = format!("{instruction:?}");
+    let variant = variant
+        .split(|c: char| !c.is_alphanumeric())
+        .next()
+        .unwrap_or_default();
+    let enum_name = type_name::<T>().rsplit("::").next().unwrap_or_default();
+    Some(format!("{enum_name}::{variant}"))
+}
+
+/// Records the compute units consumed by a transaction holding a single instruction of the given
+/// program when running against the SBF build. Transactions holding several instructions are not
+/// recorded, as their units can't be attributed to one instruction.
+pub fn record_transaction_compute_units<T: BorshDeserialize + Debug>(
+    tx: &Transaction,
+    program_id: &Pubkey,
+    units: u64,
+) {
+    if !sbf() {
+        return;
+    }
+    let [instruction] = tx.message.instructions.as_slice() else {
+        return;
+    };
+    if tx
+        .message
+        .account_keys
+        .get(instruction.program_id_index as usize)
+        != Some(program_id)
+    {
+        return;
+    }
+    if let Some(name) = instruction_name::<T>(&instruction.data) {
+        record_compute_units(&name, units);
+    }
+}
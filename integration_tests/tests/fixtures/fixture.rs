@@ -6,6 +6,7 @@ use solana_program::{
 };
 use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
 use solana_sdk::{
+    account::{Account, AccountSharedData},
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
     transaction::Transaction,
@@ -367,6 +368,19 @@ impl TestBuilder {
         Ok(())
     }
 
+    pub async fn get_account(&mut self, address: &Pubkey) -> Result<Account, BanksClientError> {
+        self.context
+            .banks_client
+            .get_account(*address)
+            .await?
+            .ok_or(BanksClientError::ClientError("account not found"))
+    }
+
+    pub fn set_account(&mut self, address: &Pubkey, account: Account) {
+        self.context
+            .set_account(address, &AccountSharedData::from(account));
+    }
+
     pub async fn get_current_slot(&mut self) -> Result<u64, BanksClientError> {
         let clock: Clock = self.context.banks_client.get_sysvar().await?;
         Ok(clock.slot)
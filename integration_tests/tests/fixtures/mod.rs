@@ -1,8 +1,10 @@
+use jito_jsm_core::loader::LoaderError;
 use solana_program::{instruction::InstructionError, program_error::ProgramError};
 use solana_program_test::BanksClientError;
 use solana_sdk::transaction::TransactionError;
 use thiserror::Error;
 
+pub mod compute_units;
 pub mod fixture;
 pub mod restaking_client;
 pub mod vault_client;
@@ -28,6 +30,14 @@ impl TestError {
             TestError::ProgramError(_) => None,
         }
     }
+
+    /// Returns the program logs of a transaction that failed in simulation
+    pub fn logs(&self) -> &[String] {
+        match self {
+            TestError::BanksClientError(BanksClientError::SimulationError { logs, .. }) => logs,
+            _ => &[],
+        }
+    }
 }
 
 #[inline(always)]
@@ -39,3 +49,25 @@ pub fn assert_ix_error<T>(test_error: Result<T, TestError>, ix_error: Instructio
         TransactionError::InstructionError(0, ix_error)
     );
 }
+
+/// Asserts that the transaction failed with the given [`LoaderError`] and, where the logs are
+/// recorded, that the account with the given role is the one that failed to load.
+///
+/// The code does not carry the role, which is only logged by the SBF build of the programs (`SBF_OUT_DIR` is set), as the native
+/// builtins print their logs to stdout instead of recording them.
+#[track_caller]
+pub fn assert_account_error<T>(test_error: Result<T, TestError>, role: &str, error: LoaderError) {
+    let test_error = test_error.err().unwrap();
+    assert_eq!(
+        test_error.to_transaction_error().unwrap(),
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+    if std::env::var_os("SBF_OUT_DIR").is_some() {
+        let message = format!("Program log: {role}: {error}");
+        assert!(
+            test_error.logs().contains(&message),
+            "expected log {message:?} in {:?}",
+            test_error.logs()
+        );
+    }
+}
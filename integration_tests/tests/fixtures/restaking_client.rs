@@ -1,19 +1,24 @@
 use jito_bytemuck::AccountDeserialize;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState,
-    ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
-    operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+    config::Config, ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+    ncn_policy::NcnPolicy, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator, operator_bond::OperatorBond,
+    operator_metadata::OperatorMetadata, operator_vault_ticket::OperatorVaultTicket,
 };
 use jito_restaking_sdk::{
     error::RestakingError,
-    instruction::OperatorAdminRole,
+    instruction::{NcnAdminRole, OperatorAdminRole, RestakingInstruction},
     sdk::{
-        cooldown_ncn_vault_ticket, initialize_config, initialize_ncn,
-        initialize_ncn_operator_state, initialize_ncn_vault_slasher_ticket,
-        initialize_ncn_vault_ticket, initialize_operator, initialize_operator_vault_ticket,
-        ncn_cooldown_operator, ncn_set_admin, ncn_warmup_operator, operator_cooldown_ncn,
-        operator_set_admin, operator_set_fee, operator_set_secondary_admin, operator_warmup_ncn,
-        set_config_admin, warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket,
+        cooldown_ncn_vault_ticket, initialize_config, initialize_ncn, initialize_ncn_metadata,
+        initialize_ncn_operator_state, initialize_ncn_policy, initialize_ncn_vault_slasher_ticket,
+        initialize_ncn_vault_ticket, initialize_operator, initialize_operator_bond,
+        initialize_operator_metadata, initialize_operator_vault_ticket, migrate_account,
+        ncn_cooldown_operator, ncn_jail_operator, ncn_set_admin, ncn_set_operator_bond_requirement,
+        ncn_set_policy, ncn_set_secondary_admin, ncn_set_transition_epochs, ncn_unjail_operator,
+        ncn_warmup_operator, operator_cooldown_ncn, operator_deposit_bond, operator_set_admin,
+        operator_set_fee, operator_set_secondary_admin, operator_unbond, operator_warmup_ncn,
+        operator_withdraw_bond, set_config_admin, slash_operator_bond, update_ncn_metadata,
+        update_operator_metadata, warmup_ncn_vault_slasher_ticket, warmup_ncn_vault_ticket,
         warmup_operator_vault_ticket,
     },
 };
@@ -21,14 +26,16 @@ use solana_program::{
     instruction::InstructionError, native_token::sol_to_lamports, pubkey::Pubkey,
     system_instruction::transfer,
 };
-use solana_program_test::BanksClient;
+use solana_program_test::{BanksClient, BanksClientError};
 use solana_sdk::{
     commitment_config::CommitmentLevel,
     signature::{Keypair, Signer},
     transaction::{Transaction, TransactionError},
 };
+use spl_associated_token_account::get_associated_token_address;
+use tarpc::context;
 
-use crate::fixtures::{TestError, TestResult};
+use crate::fixtures::{compute_units::record_transaction_compute_units, TestError, TestResult};
 
 #[derive(Debug)]
 pub struct NcnRoot {
@@ -153,6 +160,49 @@ impl RestakingProgramClient {
         )?)
     }
 
+    pub async fn get_ncn_metadata(&mut self, ncn: &Pubkey) -> TestResult<NcnMetadata> {
+        let account = NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*NcnMetadata::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+    ) -> TestResult<OperatorMetadata> {
+        let account =
+            OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*OperatorMetadata::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_ncn_policy(&mut self, ncn: &Pubkey) -> TestResult<NcnPolicy> {
+        let account = NcnPolicy::find_program_address(&jito_restaking_program::id(), ncn).0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*NcnPolicy::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
+    pub async fn get_operator_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator: &Pubkey,
+        mint: &Pubkey,
+    ) -> TestResult<OperatorBond> {
+        let account =
+            OperatorBond::find_program_address(&jito_restaking_program::id(), ncn, operator, mint)
+                .0;
+        let account = self.banks_client.get_account(account).await?.unwrap();
+        Ok(*OperatorBond::try_from_slice_unchecked(
+            account.data.as_slice(),
+        )?)
+    }
+
     pub async fn do_initialize_config(&mut self) -> TestResult<Keypair> {
         let restaking_config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
         let restaking_config_admin = Keypair::new();
@@ -339,6 +389,7 @@ impl RestakingProgramClient {
             vault,
             &ncn_vault_ticket,
             &ncn_root.ncn_admin,
+            None,
         )
         .await
     }
@@ -350,6 +401,7 @@ impl RestakingProgramClient {
         vault: &Pubkey,
         ncn_vault_ticket: &Pubkey,
         admin: &Keypair,
+        ncn_policy: Option<&Pubkey>,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -361,6 +413,7 @@ impl RestakingProgramClient {
                 vault,
                 ncn_vault_ticket,
                 &admin.pubkey(),
+                ncn_policy,
             )],
             Some(&self.payer.pubkey()),
             &[admin, &self.payer],
@@ -434,6 +487,9 @@ impl RestakingProgramClient {
             )
             .0,
             &ncn_root.ncn_admin,
+            None,
+            None,
+            &[],
         )
         .await
     }
@@ -454,6 +510,7 @@ impl RestakingProgramClient {
             )
             .0,
             &ncn_root.ncn_admin,
+            None,
         )
         .await
     }
@@ -465,6 +522,7 @@ impl RestakingProgramClient {
         operator_pubkey: &Pubkey,
         ncn_operator_state: &Pubkey,
         admin: &Keypair,
+        ncn_policy: Option<&Pubkey>,
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -476,6 +534,7 @@ impl RestakingProgramClient {
                 operator_pubkey,
                 ncn_operator_state,
                 &admin.pubkey(),
+                ncn_policy,
             )],
             Some(&self.payer.pubkey()),
             &[admin, &self.payer],
@@ -484,6 +543,7 @@ impl RestakingProgramClient {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub async fn ncn_warmup_operator(
         &mut self,
         config: &Pubkey,
@@ -491,6 +551,9 @@ impl RestakingProgramClient {
         operator_pubkey: &Pubkey,
         ncn_operator_state: &Pubkey,
         admin: &Keypair,
+        operator_bond: Option<&Pubkey>,
+        ncn_policy: Option<&Pubkey>,
+        vault_operator_delegations: &[(Pubkey, Pubkey, Pubkey)],
     ) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -502,6 +565,9 @@ impl RestakingProgramClient {
                 operator_pubkey,
                 ncn_operator_state,
                 &admin.pubkey(),
+                operator_bond,
+                ncn_policy,
+                vault_operator_delegations,
             )],
             Some(&self.payer.pubkey()),
             &[admin, &self.payer],
@@ -1036,14 +1102,552 @@ impl RestakingProgramClient {
         .await
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_ncn_metadata(
+        &mut self,
+        ncn: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[initialize_ncn_metadata(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0,
+                &metadata_admin.pubkey(),
+                &self.payer.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+                description_hash,
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_ncn_metadata(
+        &mut self,
+        ncn: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[update_ncn_metadata(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &NcnMetadata::find_program_address(&jito_restaking_program::id(), ncn).0,
+                &metadata_admin.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+                description_hash,
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn initialize_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[initialize_operator_metadata(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                operator,
+                &OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0,
+                &metadata_admin.pubkey(),
+                &self.payer.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+                description_hash,
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_operator_metadata(
+        &mut self,
+        operator: &Pubkey,
+        metadata_admin: &Keypair,
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[update_operator_metadata(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                operator,
+                &OperatorMetadata::find_program_address(&jito_restaking_program::id(), operator).0,
+                &metadata_admin.pubkey(),
+                name.to_string(),
+                website.to_string(),
+                logo_uri.to_string(),
+                contact.to_string(),
+                description_hash,
+            )],
+            Some(&self.payer.pubkey()),
+            &[metadata_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_operator_bond_requirement(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_bond_mint: &Pubkey,
+        min_operator_bond: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_operator_bond_requirement(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_bond_mint,
+                &ncn_root.ncn_admin.pubkey(),
+                min_operator_bond,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_operator_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
+        mint: &Pubkey,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[initialize_operator_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &operator_root.operator_pubkey,
+                &OperatorBond::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    &operator_root.operator_pubkey,
+                    mint,
+                )
+                .0,
+                mint,
+                &operator_root.operator_admin.pubkey(),
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Deposits from the associated token account of the operator admin
+    pub async fn operator_deposit_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            ncn,
+            &operator_root.operator_pubkey,
+            mint,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_deposit_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &operator_root.operator_pubkey,
+                &operator_bond,
+                mint,
+                &operator_root.operator_admin.pubkey(),
+                &get_associated_token_address(&operator_root.operator_admin.pubkey(), mint),
+                &get_associated_token_address(&operator_bond, mint),
+                &spl_token::id(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn slash_operator_bond(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+        mint: &Pubkey,
+        destination_token_account: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            operator_pubkey,
+            mint,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[slash_operator_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &operator_bond,
+                &get_associated_token_address(&operator_bond, mint),
+                &ncn_root.ncn_admin.pubkey(),
+                destination_token_account,
+                &spl_token::id(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn operator_unbond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
+        mint: &Pubkey,
+        amount: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_unbond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &operator_root.operator_pubkey,
+                &OperatorBond::find_program_address(
+                    &jito_restaking_program::id(),
+                    ncn,
+                    &operator_root.operator_pubkey,
+                    mint,
+                )
+                .0,
+                mint,
+                &operator_root.operator_admin.pubkey(),
+                amount,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    /// Withdraws to the associated token account of the operator admin
+    pub async fn operator_withdraw_bond(
+        &mut self,
+        ncn: &Pubkey,
+        operator_root: &OperatorRoot,
+        mint: &Pubkey,
+    ) -> TestResult<()> {
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            ncn,
+            &operator_root.operator_pubkey,
+            mint,
+        )
+        .0;
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[operator_withdraw_bond(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                ncn,
+                &operator_root.operator_pubkey,
+                &operator_bond,
+                mint,
+                &operator_root.operator_admin.pubkey(),
+                &get_associated_token_address(&operator_root.operator_admin.pubkey(), mint),
+                &get_associated_token_address(&operator_bond, mint),
+                &spl_token::id(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&operator_root.operator_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn initialize_ncn_policy(&mut self, ncn_root: &NcnRoot) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[initialize_ncn_policy(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &NcnPolicy::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn ncn_set_policy(
+        &mut self,
+        ncn_root: &NcnRoot,
+        allowed_vault_mints: &[Pubkey],
+        permissionless_admission: bool,
+        min_operator_stake: u64,
+        max_operators: u64,
+        min_operator_ticket_age: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_policy(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &NcnPolicy::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin.pubkey(),
+                allowed_vault_mints,
+                permissionless_admission,
+                min_operator_stake,
+                max_operators,
+                min_operator_ticket_age,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_secondary_admin(
+        &mut self,
+        ncn_root: &NcnRoot,
+        new_admin: &Pubkey,
+        role: NcnAdminRole,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_secondary_admin(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin.pubkey(),
+                new_admin,
+                role,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_jail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+        admin: &Keypair,
+        jailed_until_epoch: u64,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_jail_operator(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &admin.pubkey(),
+                jailed_until_epoch,
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_set_transition_epochs(
+        &mut self,
+        ncn_root: &NcnRoot,
+        warmup_epochs: u16,
+        cooldown_epochs: u16,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_set_transition_epochs(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin.pubkey(),
+                warmup_epochs,
+                cooldown_epochs,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&ncn_root.ncn_admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[migrate_account(
+                &jito_restaking_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
+    pub async fn ncn_unjail_operator(
+        &mut self,
+        ncn_root: &NcnRoot,
+        operator_pubkey: &Pubkey,
+        admin: &Keypair,
+    ) -> TestResult<()> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self.process_transaction(&Transaction::new_signed_with_payer(
+            &[ncn_unjail_operator(
+                &jito_restaking_program::id(),
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    operator_pubkey,
+                )
+                .0,
+                &admin.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[admin, &self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn process_transaction(&mut self, tx: &Transaction) -> TestResult<()> {
-        self.banks_client
-            .process_transaction_with_preflight_and_commitment(
+        self._process_transaction_with_compute_units(tx).await?;
+        Ok(())
+    }
+
+    /// Processes the transaction, returning the compute units it consumed. The units are recorded
+    /// against the compute unit baseline when the transaction holds a single RestakingInstruction.
+    async fn _process_transaction_with_compute_units(
+        &mut self,
+        tx: &Transaction,
+    ) -> TestResult<u64> {
+        let result = self
+            .banks_client
+            .process_transaction_with_preflight_and_commitment_and_context(
+                context::current(),
                 tx.clone(),
                 CommitmentLevel::Processed,
             )
             .await?;
-        Ok(())
+        let units_consumed = match (result.result, result.simulation_details) {
+            (Some(Ok(())), Some(details)) => details.units_consumed,
+            (Some(Err(err)), Some(details)) => {
+                return Err(BanksClientError::SimulationError {
+                    err,
+                    logs: details.logs,
+                    units_consumed: details.units_consumed,
+                    return_data: details.return_data,
+                }
+                .into());
+            }
+            (Some(Err(err)), None) => return Err(BanksClientError::TransactionError(err).into()),
+            _ => {
+                return Err(BanksClientError::ClientError("invalid blockhash or fee-payer").into());
+            }
+        };
+        record_transaction_compute_units::<RestakingInstruction>(
+            tx,
+            &jito_restaking_program::id(),
+            units_consumed,
+        );
+        Ok(units_consumed)
     }
 
     pub async fn _airdrop(&mut self, to: &Pubkey, sol: f64) -> TestResult<()> {
@@ -17,7 +17,7 @@ use jito_vault_core::{
 use jito_vault_sdk::{
     error::VaultError,
     inline_mpl_token_metadata,
-    instruction::{ConfigAdminRole, VaultAdminRole, WithdrawalAllocationMethod},
+    instruction::{ConfigAdminRole, VaultAdminRole, VaultInstruction, WithdrawalAllocationMethod},
     sdk::{
         add_delegation, cooldown_delegation, cooldown_vault_ncn_ticket, initialize_config,
         initialize_vault, set_deposit_capacity, warmup_vault_ncn_slasher_ticket,
@@ -45,9 +45,10 @@ use spl_associated_token_account::{
 };
 use spl_token::state::Account as SPLTokenAccount;
 use spl_token_2022::extension::ExtensionType;
+use tarpc::context;
 
 use super::fixture::TestBuilder;
-use crate::fixtures::{TestError, TestResult};
+use crate::fixtures::{compute_units::record_transaction_compute_units, TestError, TestResult};
 
 pub struct VaultRoot {
     pub vault_pubkey: Pubkey,
@@ -1194,6 +1195,32 @@ impl VaultProgramClient {
         Ok(())
     }
 
+    /// Cranks the vault update state tracker, returning the compute units consumed
+    pub async fn crank_vault_update_state_tracker_compute_units(
+        &mut self,
+        vault: &Pubkey,
+        operator: &Pubkey,
+        vault_operator_delegation: &Pubkey,
+        vault_update_state_tracker: &Pubkey,
+    ) -> TestResult<u64> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+
+        self._process_transaction_with_compute_units(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::crank_vault_update_state_tracker(
+                &jito_vault_program::id(),
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                vault,
+                operator,
+                vault_operator_delegation,
+                vault_update_state_tracker,
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn update_vault_balance(&mut self, vault_pubkey: &Pubkey) -> TestResult<()> {
         let blockhash = self.banks_client.get_latest_blockhash().await?;
 
@@ -1382,6 +1409,51 @@ impl VaultProgramClient {
         .await
     }
 
+    /// Burns the withdrawal ticket, returning the compute units consumed
+    #[allow(clippy::too_many_arguments)]
+    pub async fn burn_withdrawal_ticket_compute_units(
+        &mut self,
+        config: &Pubkey,
+        vault: &Pubkey,
+        vault_token_account: &Pubkey,
+        vrt_mint: &Pubkey,
+        staker: &Pubkey,
+        staker_token_account: &Pubkey,
+        vault_staker_withdrawal_ticket: &Pubkey,
+        vault_staker_withdrawal_ticket_token_account: &Pubkey,
+        vault_fee_token_account: &Pubkey,
+        program_fee_vrt_token_account: &Pubkey,
+        mint_burn_admin: Option<&Keypair>,
+    ) -> TestResult<u64> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        let mut signers = vec![&self.payer];
+
+        if let Some(admin) = mint_burn_admin {
+            signers.push(admin);
+        };
+
+        self._process_transaction_with_compute_units(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::burn_withdrawal_ticket(
+                &jito_vault_program::id(),
+                config,
+                vault,
+                vault_token_account,
+                vrt_mint,
+                staker,
+                staker_token_account,
+                vault_staker_withdrawal_ticket,
+                vault_staker_withdrawal_ticket_token_account,
+                vault_fee_token_account,
+                program_fee_vrt_token_account,
+                mint_burn_admin.map(|s| s.pubkey()).as_ref(),
+            )],
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        ))
+        .await
+    }
+
     pub async fn add_delegation(
         &mut self,
         config: &Pubkey,
@@ -1599,13 +1671,46 @@ impl VaultProgramClient {
     }
 
     async fn _process_transaction(&mut self, tx: &Transaction) -> Result<(), TestError> {
-        self.banks_client
-            .process_transaction_with_preflight_and_commitment(
+        self._process_transaction_with_compute_units(tx).await?;
+        Ok(())
+    }
+
+    /// Processes the transaction, returning the compute units it consumed. The units are recorded
+    /// against the compute unit baseline when the transaction holds a single VaultInstruction.
+    async fn _process_transaction_with_compute_units(
+        &mut self,
+        tx: &Transaction,
+    ) -> TestResult<u64> {
+        let result = self
+            .banks_client
+            .process_transaction_with_preflight_and_commitment_and_context(
+                context::current(),
                 tx.clone(),
                 CommitmentLevel::Processed,
             )
             .await?;
-        Ok(())
+        let units_consumed = match (result.result, result.simulation_details) {
+            (Some(Ok(())), Some(details)) => details.units_consumed,
+            (Some(Err(err)), Some(details)) => {
+                return Err(BanksClientError::SimulationError {
+                    err,
+                    logs: details.logs,
+                    units_consumed: details.units_consumed,
+                    return_data: details.return_data,
+                }
+                .into());
+            }
+            (Some(Err(err)), None) => return Err(BanksClientError::TransactionError(err).into()),
+            _ => {
+                return Err(BanksClientError::ClientError("invalid blockhash or fee-payer").into());
+            }
+        };
+        record_transaction_compute_units::<VaultInstruction>(
+            tx,
+            &jito_vault_program::id(),
+            units_consumed,
+        );
+        Ok(units_consumed)
     }
 
     pub async fn airdrop(&mut self, to: &Pubkey, sol: f64) -> Result<(), TestError> {
@@ -1981,6 +2086,21 @@ impl VaultProgramClient {
         ))
         .await
     }
+
+    pub async fn migrate_account(&mut self, account: &Pubkey) -> Result<(), TestError> {
+        let blockhash = self.banks_client.get_latest_blockhash().await?;
+        self._process_transaction(&Transaction::new_signed_with_payer(
+            &[jito_vault_sdk::sdk::migrate_account(
+                &jito_vault_program::id(),
+                account,
+                &self.payer.pubkey(),
+            )],
+            Some(&self.payer.pubkey()),
+            &[&self.payer],
+            blockhash,
+        ))
+        .await
+    }
 }
 
 #[inline(always)]
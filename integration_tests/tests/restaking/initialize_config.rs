@@ -1,12 +1,13 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_restaking_core::config::Config;
     use solana_program::{
         clock::DEFAULT_SLOTS_PER_EPOCH, instruction::InstructionError, pubkey::Pubkey,
     };
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{assert_account_error, assert_ix_error, fixture::TestBuilder};
 
     #[tokio::test]
     async fn test_initialize_config_ok() {
@@ -61,7 +62,11 @@ mod tests {
             .initialize_config(&config, &config_admin)
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountOwner);
+        assert_account_error(
+            transaction_error,
+            "config",
+            LoaderError::InvalidAccountOwner,
+        );
     }
 
     /// Test that initializing the config is at the canonical PDA
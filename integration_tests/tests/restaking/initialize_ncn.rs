@@ -1,10 +1,11 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_restaking_core::{config::Config, ncn::Ncn};
     use solana_program::{instruction::InstructionError, pubkey::Pubkey};
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{assert_account_error, assert_ix_error, fixture::TestBuilder};
 
     #[tokio::test]
     async fn test_initialize_ncn_ok() {
@@ -103,7 +104,7 @@ mod tests {
             .await;
 
         // expected ncn is system program during initialization
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountOwner);
+        assert_account_error(transaction_error, "ncn", LoaderError::InvalidAccountOwner);
     }
 
     #[tokio::test]
@@ -114,7 +115,7 @@ mod tests {
         let ncn_root = restaking_program_client.do_initialize_ncn().await;
 
         // config isn't initialized, so owned by system program
-        assert_ix_error(ncn_root, InstructionError::InvalidAccountOwner);
+        assert_account_error(ncn_root, "config", LoaderError::InvalidAccountOwner);
     }
 
     #[tokio::test]
@@ -0,0 +1,148 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use jito_restaking_core::metadata::MAX_NAME_LEN;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (RestakingProgramClient, NcnRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        (restaking_program_client, ncn_root)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_metadata_ok() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito",
+                "https://jito.network",
+                "https://jito.network/logo.png",
+                "team@jito.network",
+                Some([7; 32]),
+            )
+            .await
+            .unwrap();
+
+        let ncn_metadata = restaking_program_client
+            .get_ncn_metadata(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_metadata.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(ncn_metadata.metadata.name(), "Jito");
+        assert_eq!(ncn_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(
+            ncn_metadata.metadata.logo_uri(),
+            "https://jito.network/logo.png"
+        );
+        assert_eq!(ncn_metadata.metadata.contact(), "team@jito.network");
+        assert_eq!(ncn_metadata.metadata.description_hash(), Some([7; 32]));
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_metadata_wrong_admin_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &Keypair::new(),
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::NcnMetadataAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_metadata_name_too_long_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                &"a".repeat(MAX_NAME_LEN + 1),
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::MetadataFieldTooLong);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_metadata_twice_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito 2",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(result, "ncn_metadata", LoaderError::InvalidAccountOwner);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_metadata_unknown_ncn_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_ncn_metadata(
+                &Pubkey::new_unique(),
+                &ncn_root.ncn_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(result, "ncn", LoaderError::InvalidAccountOwner);
+    }
+}
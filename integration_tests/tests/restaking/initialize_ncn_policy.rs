@@ -0,0 +1,69 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::ncn_policy::NcnPolicy;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_initialize_ncn_policy_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+
+        let ncn_policy = restaking_program_client
+            .get_ncn_policy(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_policy.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(
+            ncn_policy.bump,
+            NcnPolicy::find_program_address(&jito_restaking_program::id(), &ncn_root.ncn_pubkey).1
+        );
+        assert!(!ncn_policy.permissionless_admission());
+        assert_eq!(ncn_policy.min_operator_stake(), 0);
+        assert_eq!(ncn_policy.max_operators(), 0);
+        assert_eq!(ncn_policy.admitted_operator_count(), 0);
+        assert!(ncn_policy.allowed_vault_mints().is_empty());
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert!(ncn.has_policy());
+    }
+
+    #[tokio::test]
+    async fn test_initialize_ncn_policy_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .initialize_ncn_policy(&NcnRoot {
+                ncn_pubkey: ncn_root.ncn_pubkey,
+                ncn_admin: Keypair::new(),
+            })
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+}
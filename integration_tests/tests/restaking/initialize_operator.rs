@@ -1,11 +1,12 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_restaking_core::{config::Config, operator::Operator, MAX_FEE_BPS};
     use jito_restaking_sdk::error::RestakingError;
     use solana_program::{instruction::InstructionError, pubkey::Pubkey};
     use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+    use crate::fixtures::{assert_account_error, assert_ix_error, fixture::TestBuilder};
 
     #[tokio::test]
     async fn test_initialize_operator_ok() {
@@ -119,7 +120,11 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountOwner);
+        assert_account_error(
+            transaction_error,
+            "operator",
+            LoaderError::InvalidAccountOwner,
+        );
     }
 
     #[tokio::test]
@@ -129,7 +134,11 @@ mod tests {
 
         let transaction_error = restaking_program_client.do_initialize_operator().await;
 
-        assert_ix_error(transaction_error, InstructionError::InvalidAccountOwner);
+        assert_account_error(
+            transaction_error,
+            "config",
+            LoaderError::InvalidAccountOwner,
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::operator_bond::OperatorBond;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot},
+    };
+
+    #[tokio::test]
+    async fn test_initialize_operator_bond_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .initialize_operator_bond(&ncn_root.ncn_pubkey, &operator_root, &mint.pubkey())
+            .await
+            .unwrap();
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &mint.pubkey(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.ncn, ncn_root.ncn_pubkey);
+        assert_eq!(operator_bond.operator, operator_root.operator_pubkey);
+        assert_eq!(operator_bond.mint, mint.pubkey());
+        assert_eq!(operator_bond.amount(), 0);
+        assert_eq!(operator_bond.slashed(), 0);
+        assert_eq!(operator_bond.unbonding_amount(), 0);
+        assert_eq!(
+            operator_bond.bump,
+            OperatorBond::find_program_address(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &mint.pubkey()
+            )
+            .1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_bond_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+
+        let wrong_operator_root = OperatorRoot {
+            operator_pubkey: operator_root.operator_pubkey,
+            operator_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .initialize_operator_bond(&ncn_root.ncn_pubkey, &wrong_operator_root, &mint.pubkey())
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+    }
+}
@@ -0,0 +1,155 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use jito_restaking_core::metadata::MAX_NAME_LEN;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (RestakingProgramClient, OperatorRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        (restaking_program_client, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_metadata_ok() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "https://jito.network",
+                "https://jito.network/logo.png",
+                "team@jito.network",
+                Some([7; 32]),
+            )
+            .await
+            .unwrap();
+
+        let operator_metadata = restaking_program_client
+            .get_operator_metadata(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator_metadata.operator, operator_root.operator_pubkey);
+        assert_eq!(operator_metadata.metadata.name(), "Jito");
+        assert_eq!(operator_metadata.metadata.website(), "https://jito.network");
+        assert_eq!(
+            operator_metadata.metadata.logo_uri(),
+            "https://jito.network/logo.png"
+        );
+        assert_eq!(operator_metadata.metadata.contact(), "team@jito.network");
+        assert_eq!(operator_metadata.metadata.description_hash(), Some([7; 32]));
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_metadata_wrong_admin_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &Keypair::new(),
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::OperatorMetadataAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_metadata_name_too_long_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                &"a".repeat(MAX_NAME_LEN + 1),
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::MetadataFieldTooLong);
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_metadata_twice_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito 2",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(
+            result,
+            "operator_metadata",
+            LoaderError::InvalidAccountOwner,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_initialize_operator_metadata_unknown_operator_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        let result = restaking_program_client
+            .initialize_operator_metadata(
+                &Pubkey::new_unique(),
+                &operator_root.operator_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(result, "operator", LoaderError::InvalidAccountOwner);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use jito_bytemuck::{AccountDeserialize, ACCOUNT_HEADER_LEN, LAYOUT_VERSION_OFFSET};
+    use jito_restaking_core::ncn::Ncn;
+    use solana_program::{instruction::InstructionError, pubkey::Pubkey, rent::Rent};
+
+    use crate::fixtures::{assert_ix_error, fixture::TestBuilder};
+
+    #[tokio::test]
+    async fn test_migrate_account_current_layout_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let account_before = fixture.get_account(&ncn_root.ncn_pubkey).await.unwrap();
+
+        restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let account_after = fixture.get_account(&ncn_root.ncn_pubkey).await.unwrap();
+        assert_eq!(account_after, account_before);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_grows_account() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        // Simulate an account written with an older layout lacking trailing reserved space
+        let mut account = fixture.get_account(&ncn_root.ncn_pubkey).await.unwrap();
+        let old_len = account.data.len() - 64;
+        account.data.truncate(old_len);
+        account.lamports = Rent::default().minimum_balance(old_len);
+        fixture.set_account(&ncn_root.ncn_pubkey, account);
+        assert!(restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .is_err());
+
+        restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let account = fixture.get_account(&ncn_root.ncn_pubkey).await.unwrap();
+        let space = ACCOUNT_HEADER_LEN + size_of::<Ncn>();
+        assert_eq!(account.data.len(), space);
+        assert!(account.lamports >= Rent::default().minimum_balance(space));
+        assert_eq!(*Ncn::try_from_slice_unchecked(&account.data).unwrap(), ncn);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_newer_layout_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let mut account = fixture.get_account(&ncn_root.ncn_pubkey).await.unwrap();
+        account.data[LAYOUT_VERSION_OFFSET] = 1;
+        fixture.set_account(&ncn_root.ncn_pubkey, account);
+
+        let result = restaking_program_client
+            .migrate_account(&ncn_root.ncn_pubkey)
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountData);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_invalid_owner_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        let result = restaking_program_client
+            .migrate_account(&Pubkey::new_unique())
+            .await;
+        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+    }
+}
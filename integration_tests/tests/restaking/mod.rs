@@ -1,18 +1,35 @@
 mod initialize_config;
 mod initialize_ncn;
+mod initialize_ncn_metadata;
 mod initialize_ncn_operator_state;
+mod initialize_ncn_policy;
 mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
+mod initialize_operator_bond;
+mod initialize_operator_metadata;
 mod initialize_operator_vault_ticket;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
+mod ncn_jail_operator;
 mod ncn_set_admin;
+mod ncn_set_operator_bond_requirement;
+mod ncn_set_policy;
+mod ncn_set_transition_epochs;
+mod ncn_unjail_operator;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_delegate_token_account;
+mod operator_deposit_bond;
 mod operator_set_admin;
 mod operator_set_fee;
 mod operator_set_secondary_admin;
+mod operator_unbond;
 mod operator_warmup_ncn;
+mod operator_withdraw_bond;
 mod set_config_admin;
+mod slash_operator_bond;
+mod update_ncn_metadata;
+mod update_operator_metadata;
+mod warmup_ncn_vault_ticket;
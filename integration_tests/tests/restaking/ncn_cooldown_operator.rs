@@ -1,7 +1,9 @@
 #[cfg(test)]
 mod tests {
     use jito_jsm_core::slot_toggle::SlotToggleState;
-    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_core::{
+        config::Config, ncn_operator_state::NcnOperatorState, ncn_policy::NcnPolicy,
+    };
     use jito_restaking_sdk::error::RestakingError;
     use solana_sdk::signature::Keypair;
 
@@ -109,6 +111,7 @@ mod tests {
                 )
                 .0,
                 &Keypair::new(),
+                None,
             )
             .await;
         assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
@@ -218,4 +221,94 @@ mod tests {
             .await;
         assert_restaking_error(result, RestakingError::NcnCooldownOperatorFailed);
     }
+
+    #[tokio::test]
+    async fn test_ncn_cooldown_operator_releases_ncn_policy_admission() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], false, 0, 1, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        let ncn_operator_state_pubkey = NcnOperatorState::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+        )
+        .0;
+        let ncn_policy_pubkey =
+            NcnPolicy::find_program_address(&jito_restaking_program::id(), &ncn_root.ncn_pubkey).0;
+        restaking_program_client
+            .ncn_warmup_operator(
+                &config_pubkey,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state_pubkey,
+                &ncn_root.ncn_admin,
+                None,
+                Some(&ncn_policy_pubkey),
+                &[],
+            )
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        // The admission can only be released with the NCN policy
+        let result = restaking_program_client
+            .do_ncn_cooldown_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyMissing);
+
+        restaking_program_client
+            .ncn_cooldown_operator(
+                &config_pubkey,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &ncn_operator_state_pubkey,
+                &ncn_root.ncn_admin,
+                Some(&ncn_policy_pubkey),
+            )
+            .await
+            .unwrap();
+
+        let ncn_policy = restaking_program_client
+            .get_ncn_policy(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_policy.admitted_operator_count(), 0);
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert!(!ncn_operator_state.policy_admitted());
+    }
 }
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::{error::RestakingError, instruction::NcnAdminRole};
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an NCN and an operator that are both active, returning the current epoch
+    async fn setup() -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        OperatorRoot,
+        u64,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            epoch,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_ok() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch + 2,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_operator_state.jailed_until_epoch(), epoch + 2);
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(ncn_operator_state
+            .is_jailed(slot, config.epoch_length())
+            .unwrap());
+        assert!(!ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+
+        // The jail expires on its own at the jailed-until epoch
+        fixture
+            .warp_to_slot((epoch + 2) * config.epoch_length())
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        assert!(!ncn_operator_state
+            .is_jailed(slot, config.epoch_length())
+            .unwrap());
+        assert!(ncn_operator_state
+            .is_active(slot, config.epoch_length())
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_by_slasher_admin_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        let slasher_admin = Keypair::new();
+        restaking_program_client
+            .ncn_set_secondary_admin(
+                &ncn_root,
+                &slasher_admin.pubkey(),
+                NcnAdminRole::SlasherAdmin,
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &slasher_admin,
+                epoch + 1,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_operator_state.jailed_until_epoch(), epoch + 1);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_wrong_admin_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        let result = restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &Keypair::new(),
+                epoch + 1,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnJailAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_jail_operator_current_epoch_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        let result = restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorJailEpochInvalid);
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_operator_bond_requirement_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_set_operator_bond_requirement(&ncn_root, &mint.pubkey(), 1_000)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.operator_bond_mint, mint.pubkey());
+        assert_eq!(ncn.min_operator_bond(), 1_000);
+
+        // Setting the minimum to zero removes the requirement
+        restaking_program_client
+            .ncn_set_operator_bond_requirement(&ncn_root, &mint.pubkey(), 0)
+            .await
+            .unwrap();
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.min_operator_bond(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_operator_bond_requirement_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .ncn_set_operator_bond_requirement(&wrong_ncn_root, &mint.pubkey(), 1_000)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_operator_bond_requirement_invalid_mint_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_operator_bond_requirement(&ncn_root, &Pubkey::new_unique(), 1_000)
+            .await;
+        assert_account_error(
+            result,
+            "operator_bond_mint",
+            LoaderError::InvalidAccountOwner,
+        );
+    }
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::ncn_policy::MAX_ALLOWED_VAULT_MINTS;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_policy_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[mint.pubkey()], true, 1_000, 10, 100)
+            .await
+            .unwrap();
+
+        let ncn_policy = restaking_program_client
+            .get_ncn_policy(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert!(ncn_policy.permissionless_admission());
+        assert_eq!(ncn_policy.min_operator_stake(), 1_000);
+        assert_eq!(ncn_policy.max_operators(), 10);
+        assert_eq!(ncn_policy.min_operator_ticket_age(), 100);
+        assert_eq!(ncn_policy.allowed_vault_mints(), vec![mint.pubkey()]);
+
+        // Setting the policy again replaces the allowed vault mints
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], false, 0, 0, 0)
+            .await
+            .unwrap();
+        let ncn_policy = restaking_program_client
+            .get_ncn_policy(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert!(!ncn_policy.permissionless_admission());
+        assert!(ncn_policy.allowed_vault_mints().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_policy_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_policy(
+                &NcnRoot {
+                    ncn_pubkey: ncn_root.ncn_pubkey,
+                    ncn_admin: Keypair::new(),
+                },
+                &[],
+                true,
+                0,
+                0,
+                0,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_policy_too_many_vault_mints_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+
+        let mut mints = Vec::with_capacity(MAX_ALLOWED_VAULT_MINTS + 1);
+        for _ in 0..=MAX_ALLOWED_VAULT_MINTS {
+            let mint = Keypair::new();
+            vault_program_client
+                .create_token_mint(&mint, &spl_token::id())
+                .await
+                .unwrap();
+            mints.push(mint.pubkey());
+        }
+
+        let result = restaking_program_client
+            .ncn_set_policy(&ncn_root, &mints, false, 0, 0, 0)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyTooManyVaultMints);
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::slot_toggle::{SlotToggleState, TransitionEpochs};
+    use jito_restaking_core::{config::Config, ncn::MAX_TRANSITION_EPOCHS};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot},
+    };
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_ok() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.transition_epochs(), TransitionEpochs::default());
+
+        restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root, 0, 3)
+            .await
+            .unwrap();
+
+        let ncn = restaking_program_client
+            .get_ncn(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn.transition_epochs(), TransitionEpochs::new(0, 3));
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_wrong_admin_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .ncn_set_transition_epochs(&wrong_ncn_root, 0, 3)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_set_transition_epochs_too_long_fails() {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        let result = restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root, 1, MAX_TRANSITION_EPOCHS + 1)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnTransitionEpochsInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_transition_epochs_apply_to_operator() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_transition_epochs(&ncn_root, 0, 3)
+            .await
+            .unwrap();
+        let epoch_length = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap()
+            .epoch_length();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        // Without warmup epochs, both sides are active from the next epoch
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state.ncn_opt_in_state.transition_epochs(),
+            TransitionEpochs::new(0, 3)
+        );
+        assert_eq!(
+            ncn_operator_state.operator_opt_in_state.transition_epochs(),
+            TransitionEpochs::new(0, 3)
+        );
+        assert!(ncn_operator_state.is_active(slot, epoch_length).unwrap());
+
+        // The cooldown takes three full epochs
+        restaking_program_client
+            .do_ncn_cooldown_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(3 * epoch_length)
+            .await
+            .unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::Cooldown
+        );
+
+        fixture.warp_slot_incremental(epoch_length).await.unwrap();
+        let slot = fixture.get_current_slot().await.unwrap();
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot, epoch_length)
+                .unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::config::Config;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an NCN and an operator, returning the current epoch
+    async fn setup() -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        OperatorRoot,
+        u64,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            epoch,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_ncn_unjail_operator_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch + 10,
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_unjail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+            )
+            .await
+            .unwrap();
+
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_operator_state.jailed_until_epoch(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_unjail_operator_not_jailed_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, _epoch) =
+            setup().await;
+
+        let result = restaking_program_client
+            .ncn_unjail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorNotJailed);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_unjail_operator_wrong_admin_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, epoch) =
+            setup().await;
+
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch + 10,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .ncn_unjail_operator(&ncn_root, &operator_root.operator_pubkey, &Keypair::new())
+            .await;
+        assert_restaking_error(result, RestakingError::NcnJailAdminInvalid);
+    }
+}
@@ -1,11 +1,201 @@
 #[cfg(test)]
 mod tests {
     use jito_jsm_core::slot_toggle::SlotToggleState;
-    use jito_restaking_core::{config::Config, ncn_operator_state::NcnOperatorState};
+    use jito_restaking_core::{
+        config::Config, ncn_operator_state::NcnOperatorState, ncn_policy::NcnPolicy,
+        ncn_vault_ticket::NcnVaultTicket, operator_bond::OperatorBond,
+    };
     use jito_restaking_sdk::error::RestakingError;
-    use solana_sdk::signature::Keypair;
+    use jito_vault_core::vault_operator_delegation::VaultOperatorDelegation;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
 
-    use crate::fixtures::{fixture::TestBuilder, restaking_client::assert_restaking_error};
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+        TestResult,
+    };
+
+    /// Sets up an NCN requiring `min_operator_bond` and an operator that bonded `bond_amount`
+    async fn setup_with_operator_bond(
+        min_operator_bond: u64,
+        bond_amount: u64,
+    ) -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        OperatorRoot,
+        Pubkey,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_operator_bond_requirement(&ncn_root, &mint.pubkey(), min_operator_bond)
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .initialize_operator_bond(&ncn_root.ncn_pubkey, &operator_root, &mint.pubkey())
+            .await
+            .unwrap();
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+            &mint.pubkey(),
+        )
+        .0;
+        fixture
+            .create_ata(&mint.pubkey(), &operator_bond)
+            .await
+            .unwrap();
+        if bond_amount > 0 {
+            fixture
+                .mint_spl_to(
+                    &mint.pubkey(),
+                    &operator_root.operator_admin.pubkey(),
+                    bond_amount,
+                    &spl_token::id(),
+                )
+                .await
+                .unwrap();
+            restaking_program_client
+                .operator_deposit_bond(
+                    &ncn_root.ncn_pubkey,
+                    &operator_root,
+                    &mint.pubkey(),
+                    bond_amount,
+                )
+                .await
+                .unwrap();
+        }
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            operator_bond,
+        )
+    }
+
+    async fn ncn_warmup_operator_with_bond(
+        restaking_program_client: &mut RestakingProgramClient,
+        ncn_root: &NcnRoot,
+        operator_root: &OperatorRoot,
+        operator_bond: Option<&Pubkey>,
+    ) -> TestResult<()> {
+        restaking_program_client
+            .ncn_warmup_operator(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin,
+                operator_bond,
+                None,
+                &[],
+            )
+            .await
+    }
+
+    /// Sets up an NCN with a default policy and `num_operators` operators with an NcnOperatorState
+    async fn setup_with_ncn_policy(
+        num_operators: usize,
+    ) -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        Vec<OperatorRoot>,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+
+        let mut operator_roots = Vec::with_capacity(num_operators);
+        for _ in 0..num_operators {
+            let operator_root = restaking_program_client
+                .do_initialize_operator()
+                .await
+                .unwrap();
+            restaking_program_client
+                .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+                .await
+                .unwrap();
+            operator_roots.push(operator_root);
+        }
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        (fixture, restaking_program_client, ncn_root, operator_roots)
+    }
+
+    async fn ncn_warmup_operator_with_policy(
+        restaking_program_client: &mut RestakingProgramClient,
+        ncn_root: &NcnRoot,
+        operator_root: &OperatorRoot,
+        admin: &Keypair,
+        vault_operator_delegations: &[(Pubkey, Pubkey, Pubkey)],
+    ) -> TestResult<()> {
+        restaking_program_client
+            .ncn_warmup_operator(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .0,
+                admin,
+                None,
+                Some(
+                    &NcnPolicy::find_program_address(
+                        &jito_restaking_program::id(),
+                        &ncn_root.ncn_pubkey,
+                    )
+                    .0,
+                ),
+                vault_operator_delegations,
+            )
+            .await
+    }
 
     #[tokio::test]
     async fn test_ncn_warmup_operator_ok() {
@@ -82,11 +272,65 @@ mod tests {
                 )
                 .0,
                 &Keypair::new(),
+                None,
+                None,
+                &[],
             )
             .await;
         assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
     }
 
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_jailed_fails() {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch + 1,
+            )
+            .await
+            .unwrap();
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        let result = restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorJailed);
+
+        // The operator can be warmed up once the jail ends
+        fixture
+            .warp_to_slot((epoch + 1) * config.epoch_length())
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+    }
+
     #[tokio::test]
     async fn test_ncn_warmup_operator_warming_up_fails() {
         let mut fixture = TestBuilder::new().await;
@@ -208,4 +452,403 @@ mod tests {
             .await;
         assert_restaking_error(result, RestakingError::NcnWarmupOperatorFailed);
     }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_with_operator_bond_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, operator_bond) =
+            setup_with_operator_bond(1_000, 1_000).await;
+
+        ncn_warmup_operator_with_bond(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_root,
+            Some(&operator_bond),
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_below_min_operator_bond_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, operator_bond) =
+            setup_with_operator_bond(1_000, 999).await;
+
+        let result = ncn_warmup_operator_with_bond(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_root,
+            Some(&operator_bond),
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_missing_operator_bond_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, _operator_bond) =
+            setup_with_operator_bond(1_000, 1_000).await;
+
+        let result = ncn_warmup_operator_with_bond(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_root,
+            None,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorBondInsufficient);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_with_ncn_policy_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(1).await;
+
+        ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let ncn_policy = restaking_program_client
+            .get_ncn_policy(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_policy.admitted_operator_count(), 1);
+        let ncn_operator_state = restaking_program_client
+            .get_ncn_operator_state(&ncn_root.ncn_pubkey, &operator_roots[0].operator_pubkey)
+            .await
+            .unwrap();
+        assert!(ncn_operator_state.policy_admitted());
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_missing_ncn_policy_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(1).await;
+
+        let result = restaking_program_client
+            .do_ncn_warmup_operator(&ncn_root, &operator_roots[0].operator_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyMissing);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_permissionless_admission_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(1).await;
+
+        // Without permissionless admission, only the operator admin can warm up the operator
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &Keypair::new(),
+            &[],
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorAdminInvalid);
+
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], true, 0, 0, 0)
+            .await
+            .unwrap();
+        ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &Keypair::new(),
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_max_operators_reached_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(2).await;
+
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], false, 0, 1, 0)
+            .await
+            .unwrap();
+        ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await
+        .unwrap();
+
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[1],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyMaxOperatorsReached);
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_ticket_too_new_fails() {
+        const MIN_OPERATOR_TICKET_AGE: u64 = 100;
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(1).await;
+
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], false, 0, 0, MIN_OPERATOR_TICKET_AGE)
+            .await
+            .unwrap();
+
+        // The operator hasn't opted in to the NCN yet
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyOperatorTicketTooNew);
+
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_roots[0], &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(MIN_OPERATOR_TICKET_AGE - 1)
+            .await
+            .unwrap();
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyOperatorTicketTooNew);
+
+        fixture.warp_slot_incremental(1).await.unwrap();
+        ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &operator_roots[0],
+            &ncn_root.ncn_admin,
+            &[],
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_ncn_warmup_operator_min_operator_stake() {
+        const MIN_OPERATOR_STAKE: u64 = 10_000;
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_roots) =
+            setup_with_ncn_policy(1).await;
+        let mut vault_program_client = fixture.vault_program_client();
+        let operator_root = &operator_roots[0];
+
+        // Delegate stake from a vault to the operator
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_operator_vault_ticket(operator_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_initialize_vault_operator_delegation(&vault_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MIN_OPERATOR_STAKE)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(
+                &vault_root,
+                &depositor,
+                MIN_OPERATOR_STAKE,
+                MIN_OPERATOR_STAKE,
+            )
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(
+                &vault_root,
+                &operator_root.operator_pubkey,
+                MIN_OPERATOR_STAKE - 1,
+            )
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        let vault_operator_delegations = [(
+            vault_root.vault_pubkey,
+            NcnVaultTicket::find_program_address(
+                &jito_restaking_program::id(),
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+            )
+            .0,
+            VaultOperatorDelegation::find_program_address(
+                &jito_vault_program::id(),
+                &vault_root.vault_pubkey,
+                &operator_root.operator_pubkey,
+            )
+            .0,
+        )];
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // Stake from vaults that don't back the NCN isn't counted
+        restaking_program_client
+            .ncn_set_policy(
+                &ncn_root,
+                &[vault.supported_mint],
+                false,
+                MIN_OPERATOR_STAKE - 1,
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            operator_root,
+            &ncn_root.ncn_admin,
+            &vault_operator_delegations,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnVaultTicketInactive);
+
+        // Stake from vaults that aren't updated for the current epoch isn't counted
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .warmup_ncn_vault_ticket(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &vault_operator_delegations[0].1,
+                &ncn_root.ncn_admin,
+                Some(
+                    &NcnPolicy::find_program_address(
+                        &jito_restaking_program::id(),
+                        &ncn_root.ncn_pubkey,
+                    )
+                    .0,
+                ),
+            )
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            operator_root,
+            &ncn_root.ncn_admin,
+            &vault_operator_delegations,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyVaultUpdateNeeded);
+
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator_root.operator_pubkey])
+            .await
+            .unwrap();
+
+        // Stake from vaults with a mint the policy doesn't allow isn't counted
+        let other_mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&other_mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_policy(
+                &ncn_root,
+                &[other_mint.pubkey()],
+                false,
+                MIN_OPERATOR_STAKE - 1,
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            operator_root,
+            &ncn_root.ncn_admin,
+            &vault_operator_delegations,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyOperatorStakeInsufficient);
+
+        restaking_program_client
+            .ncn_set_policy(
+                &ncn_root,
+                &[vault.supported_mint],
+                false,
+                MIN_OPERATOR_STAKE,
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+        let result = ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            operator_root,
+            &ncn_root.ncn_admin,
+            &vault_operator_delegations,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyOperatorStakeInsufficient);
+
+        restaking_program_client
+            .ncn_set_policy(
+                &ncn_root,
+                &[vault.supported_mint],
+                false,
+                MIN_OPERATOR_STAKE - 1,
+                0,
+                0,
+            )
+            .await
+            .unwrap();
+        ncn_warmup_operator_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            operator_root,
+            &ncn_root.ncn_admin,
+            &vault_operator_delegations,
+        )
+        .await
+        .unwrap();
+    }
 }
@@ -0,0 +1,145 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{config::Config, operator_bond::OperatorBond};
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an operator bond of 5,000 tokens to an NCN, returning the NCN and the bond mint
+    async fn setup() -> (
+        TestBuilder,
+        RestakingProgramClient,
+        Pubkey,
+        OperatorRoot,
+        Pubkey,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+        fixture
+            .mint_spl_to(
+                &mint.pubkey(),
+                &operator_root.operator_admin.pubkey(),
+                10_000,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .initialize_operator_bond(&ncn_root.ncn_pubkey, &operator_root, &mint.pubkey())
+            .await
+            .unwrap();
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+            &mint.pubkey(),
+        )
+        .0;
+        fixture
+            .create_ata(&mint.pubkey(), &operator_bond)
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_deposit_bond(&ncn_root.ncn_pubkey, &operator_root, &mint.pubkey(), 5_000)
+            .await
+            .unwrap();
+
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root.ncn_pubkey,
+            operator_root,
+            mint.pubkey(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_operator_unbond_ok() {
+        let (mut fixture, mut restaking_program_client, ncn, operator_root, mint) = setup().await;
+
+        restaking_program_client
+            .operator_unbond(&ncn, &operator_root, &mint, 2_000)
+            .await
+            .unwrap();
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        let cooldown_epochs = restaking_program_client
+            .get_ncn(&ncn)
+            .await
+            .unwrap()
+            .transition_epochs()
+            .cooldown_epochs();
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(&ncn, &operator_root.operator_pubkey, &mint)
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.amount(), 3_000);
+        assert_eq!(operator_bond.unbonding_amount(), 2_000);
+        assert_eq!(
+            operator_bond.withdrawable_epoch(),
+            epoch + u64::from(cooldown_epochs) + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_operator_unbond_exceeding_bond_fails() {
+        let (_fixture, mut restaking_program_client, ncn, operator_root, mint) = setup().await;
+
+        let result = restaking_program_client
+            .operator_unbond(&ncn, &operator_root, &mint, 5_001)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorBondAmountExceeded);
+    }
+
+    #[tokio::test]
+    async fn test_operator_unbond_zero_fails() {
+        let (_fixture, mut restaking_program_client, ncn, operator_root, mint) = setup().await;
+
+        let result = restaking_program_client
+            .operator_unbond(&ncn, &operator_root, &mint, 0)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorBondAmountZero);
+    }
+
+    #[tokio::test]
+    async fn test_operator_unbond_wrong_admin_fails() {
+        let (_fixture, mut restaking_program_client, ncn, operator_root, mint) = setup().await;
+
+        let wrong_operator_root = OperatorRoot {
+            operator_pubkey: operator_root.operator_pubkey,
+            operator_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .operator_unbond(&ncn, &wrong_operator_root, &mint, 1_000)
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorAdminInvalid);
+    }
+}
@@ -0,0 +1,360 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{
+        config::Config, ncn_operator_state::NcnOperatorState, operator_bond::OperatorBond,
+    };
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, OperatorRoot, RestakingProgramClient},
+    };
+
+    /// Sets up an NCN and an operator with a bond of `bond_amount` that are both active
+    async fn setup(
+        bond_amount: u64,
+    ) -> (
+        TestBuilder,
+        RestakingProgramClient,
+        NcnRoot,
+        OperatorRoot,
+        Pubkey,
+        Pubkey,
+    ) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+
+        let mint = Keypair::new();
+        vault_program_client
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_operator_bond_requirement(&ncn_root, &mint.pubkey(), bond_amount)
+            .await
+            .unwrap();
+
+        fixture
+            .mint_spl_to(
+                &mint.pubkey(),
+                &operator_root.operator_admin.pubkey(),
+                bond_amount,
+                &spl_token::id(),
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .initialize_operator_bond(&ncn_root.ncn_pubkey, &operator_root, &mint.pubkey())
+            .await
+            .unwrap();
+        let operator_bond = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+            &mint.pubkey(),
+        )
+        .0;
+        fixture
+            .create_ata(&mint.pubkey(), &operator_bond)
+            .await
+            .unwrap();
+        restaking_program_client
+            .operator_deposit_bond(
+                &ncn_root.ncn_pubkey,
+                &operator_root,
+                &mint.pubkey(),
+                bond_amount,
+            )
+            .await
+            .unwrap();
+
+        let config_pubkey = Config::find_program_address(&jito_restaking_program::id()).0;
+        fixture.warp_slot_incremental(1).await.unwrap();
+        restaking_program_client
+            .ncn_warmup_operator(
+                &config_pubkey,
+                &ncn_root.ncn_pubkey,
+                &operator_root.operator_pubkey,
+                &NcnOperatorState::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &operator_root.operator_pubkey,
+                )
+                .0,
+                &ncn_root.ncn_admin,
+                Some(&operator_bond),
+                None,
+                &[],
+            )
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_operator_warmup_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        let config = restaking_program_client
+            .get_config(&config_pubkey)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(2 * config.epoch_length())
+            .await
+            .unwrap();
+
+        let destination = Pubkey::new_unique();
+        fixture
+            .create_ata(&mint.pubkey(), &destination)
+            .await
+            .unwrap();
+        let destination_token_account = get_associated_token_address(&destination, &mint.pubkey());
+
+        (
+            fixture,
+            restaking_program_client,
+            ncn_root,
+            operator_root,
+            mint.pubkey(),
+            destination_token_account,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_ok() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                3_000,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey, &mint)
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.amount(), 2_000);
+        assert_eq!(operator_bond.slashed(), 3_000);
+
+        let destination_token_account = fixture.get_token_account(&destination).await.unwrap();
+        assert_eq!(destination_token_account.amount, 3_000);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_exceeding_bond_takes_entire_bond() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        // Only the bonded amount can be taken, the remainder falls to the vault delegations
+        restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                8_000,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey, &mint)
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.amount(), 0);
+        assert_eq!(operator_bond.slashed(), 5_000);
+
+        let destination_token_account = fixture.get_token_account(&destination).await.unwrap();
+        assert_eq!(destination_token_account.amount, 5_000);
+
+        let operator_bond_pubkey = OperatorBond::find_program_address(
+            &jito_restaking_program::id(),
+            &ncn_root.ncn_pubkey,
+            &operator_root.operator_pubkey,
+            &mint,
+        )
+        .0;
+        let bond_token_account = fixture
+            .get_token_account(&get_associated_token_address(&operator_bond_pubkey, &mint))
+            .await
+            .unwrap();
+        assert_eq!(bond_token_account.amount, 0);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_wrong_slasher_admin_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        let wrong_ncn_root = NcnRoot {
+            ncn_pubkey: ncn_root.ncn_pubkey,
+            ncn_admin: Keypair::new(),
+        };
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &wrong_ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                1_000,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnSlasherAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_zero_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                0,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::OperatorBondAmountZero);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_operator_cooling_down_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        restaking_program_client
+            .do_operator_cooldown_ncn(&operator_root, &ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                1_000,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorStateInactive);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_jailed_operator_fails() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        let config = restaking_program_client
+            .get_config(&Config::find_program_address(&jito_restaking_program::id()).0)
+            .await
+            .unwrap();
+        let epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        restaking_program_client
+            .ncn_jail_operator(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &ncn_root.ncn_admin,
+                epoch + 1,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                1_000,
+            )
+            .await;
+        assert_restaking_error(result, RestakingError::NcnOperatorStateInactive);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_takes_unbonding_tokens() {
+        let (mut fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        // Unbonded tokens stay slashable until they are withdrawn
+        restaking_program_client
+            .operator_unbond(&ncn_root.ncn_pubkey, &operator_root, &mint, 4_000)
+            .await
+            .unwrap();
+        restaking_program_client
+            .slash_operator_bond(
+                &ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                3_000,
+            )
+            .await
+            .unwrap();
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey, &mint)
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.amount(), 0);
+        assert_eq!(operator_bond.unbonding_amount(), 2_000);
+        assert_eq!(operator_bond.slashed(), 3_000);
+
+        let destination_token_account = fixture.get_token_account(&destination).await.unwrap();
+        assert_eq!(destination_token_account.amount, 3_000);
+    }
+
+    #[tokio::test]
+    async fn test_slash_operator_bond_to_other_ncn_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, operator_root, mint, destination) =
+            setup(5_000).await;
+
+        // The bond only backs the NCN it was posted to
+        let other_ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .do_initialize_ncn_operator_state(&other_ncn_root, &operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        let result = restaking_program_client
+            .slash_operator_bond(
+                &other_ncn_root,
+                &operator_root.operator_pubkey,
+                &mint,
+                &destination,
+                1_000,
+            )
+            .await;
+        assert!(result.is_err());
+
+        let operator_bond = restaking_program_client
+            .get_operator_bond(&ncn_root.ncn_pubkey, &operator_root.operator_pubkey, &mint)
+            .await
+            .unwrap();
+        assert_eq!(operator_bond.amount(), 5_000);
+    }
+}
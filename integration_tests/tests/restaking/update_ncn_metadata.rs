@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (RestakingProgramClient, NcnRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+
+        (restaking_program_client, ncn_root)
+    }
+
+    #[tokio::test]
+    async fn test_update_ncn_metadata_ok() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito",
+                "https://jito.network",
+                "",
+                "",
+                Some([7; 32]),
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .update_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito Labs",
+                "https://jito.wtf",
+                "https://jito.wtf/logo.png",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let ncn_metadata = restaking_program_client
+            .get_ncn_metadata(&ncn_root.ncn_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(ncn_metadata.metadata.name(), "Jito Labs");
+        assert_eq!(ncn_metadata.metadata.website(), "https://jito.wtf");
+        assert_eq!(
+            ncn_metadata.metadata.logo_uri(),
+            "https://jito.wtf/logo.png"
+        );
+        assert_eq!(ncn_metadata.metadata.contact(), "");
+        assert_eq!(ncn_metadata.metadata.description_hash(), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_ncn_metadata_wrong_admin_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        restaking_program_client
+            .initialize_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .update_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &Keypair::new(),
+                "Jito Labs",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::NcnMetadataAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_update_ncn_metadata_not_initialized_fails() {
+        let (mut restaking_program_client, ncn_root) = setup().await;
+
+        let result = restaking_program_client
+            .update_ncn_metadata(
+                &ncn_root.ncn_pubkey,
+                &ncn_root.ncn_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(result, "ncn_metadata", LoaderError::InvalidAccountOwner);
+    }
+}
@@ -0,0 +1,159 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::Keypair;
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, OperatorRoot, RestakingProgramClient},
+    };
+
+    async fn setup() -> (RestakingProgramClient, OperatorRoot) {
+        let fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let operator_root = restaking_program_client
+            .do_initialize_operator()
+            .await
+            .unwrap();
+
+        (restaking_program_client, operator_root)
+    }
+
+    #[tokio::test]
+    async fn test_update_operator_metadata_ok() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "https://jito.network",
+                "",
+                "",
+                Some([7; 32]),
+            )
+            .await
+            .unwrap();
+
+        restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito Labs",
+                "https://jito.wtf",
+                "https://jito.wtf/logo.png",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let operator_metadata = restaking_program_client
+            .get_operator_metadata(&operator_root.operator_pubkey)
+            .await
+            .unwrap();
+        assert_eq!(operator_metadata.metadata.name(), "Jito Labs");
+        assert_eq!(operator_metadata.metadata.website(), "https://jito.wtf");
+        assert_eq!(
+            operator_metadata.metadata.logo_uri(),
+            "https://jito.wtf/logo.png"
+        );
+        assert_eq!(operator_metadata.metadata.contact(), "");
+        assert_eq!(operator_metadata.metadata.description_hash(), None);
+    }
+
+    #[tokio::test]
+    async fn test_update_operator_metadata_wrong_admin_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &Keypair::new(),
+                "Jito Labs",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::OperatorMetadataAdminInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_update_operator_metadata_embedded_nul_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        restaking_program_client
+            .initialize_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await
+            .unwrap();
+
+        let result = restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito\0Labs",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_restaking_error(result, RestakingError::MetadataFieldInvalid);
+    }
+
+    #[tokio::test]
+    async fn test_update_operator_metadata_not_initialized_fails() {
+        let (mut restaking_program_client, operator_root) = setup().await;
+
+        let result = restaking_program_client
+            .update_operator_metadata(
+                &operator_root.operator_pubkey,
+                &operator_root.operator_admin,
+                "Jito",
+                "",
+                "",
+                "",
+                None,
+            )
+            .await;
+
+        assert_account_error(
+            result,
+            "operator_metadata",
+            LoaderError::InvalidAccountOwner,
+        );
+    }
+}
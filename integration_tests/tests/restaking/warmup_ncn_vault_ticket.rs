@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use jito_restaking_core::{
+        config::Config, ncn_policy::NcnPolicy, ncn_vault_ticket::NcnVaultTicket,
+    };
+    use jito_restaking_sdk::error::RestakingError;
+    use solana_sdk::signature::{Keypair, Signer};
+
+    use crate::fixtures::{
+        fixture::TestBuilder,
+        restaking_client::{assert_restaking_error, NcnRoot, RestakingProgramClient},
+        vault_client::VaultRoot,
+        TestResult,
+    };
+
+    /// Sets up an NCN with a policy and a vault with an NcnVaultTicket
+    async fn setup_with_ncn_policy() -> (TestBuilder, RestakingProgramClient, NcnRoot, VaultRoot) {
+        let mut fixture = TestBuilder::new().await;
+        let mut restaking_program_client = fixture.restaking_program_client();
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_vault_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_config()
+            .await
+            .unwrap();
+        let ncn_root = restaking_program_client.do_initialize_ncn().await.unwrap();
+        restaking_program_client
+            .initialize_ncn_policy(&ncn_root)
+            .await
+            .unwrap();
+        restaking_program_client
+            .do_initialize_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        fixture.warp_slot_incremental(1).await.unwrap();
+
+        (fixture, restaking_program_client, ncn_root, vault_root)
+    }
+
+    async fn warmup_ncn_vault_ticket_with_policy(
+        restaking_program_client: &mut RestakingProgramClient,
+        ncn_root: &NcnRoot,
+        vault_root: &VaultRoot,
+        admin: &Keypair,
+    ) -> TestResult<()> {
+        restaking_program_client
+            .warmup_ncn_vault_ticket(
+                &Config::find_program_address(&jito_restaking_program::id()).0,
+                &ncn_root.ncn_pubkey,
+                &vault_root.vault_pubkey,
+                &NcnVaultTicket::find_program_address(
+                    &jito_restaking_program::id(),
+                    &ncn_root.ncn_pubkey,
+                    &vault_root.vault_pubkey,
+                )
+                .0,
+                admin,
+                Some(
+                    &NcnPolicy::find_program_address(
+                        &jito_restaking_program::id(),
+                        &ncn_root.ncn_pubkey,
+                    )
+                    .0,
+                ),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_warmup_ncn_vault_ticket_with_ncn_policy_ok() {
+        let (fixture, mut restaking_program_client, ncn_root, vault_root) =
+            setup_with_ncn_policy().await;
+
+        let vault = fixture
+            .vault_program_client()
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[vault.supported_mint], false, 0, 0, 0)
+            .await
+            .unwrap();
+
+        warmup_ncn_vault_ticket_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &vault_root,
+            &ncn_root.ncn_admin,
+        )
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_warmup_ncn_vault_ticket_missing_ncn_policy_fails() {
+        let (_fixture, mut restaking_program_client, ncn_root, vault_root) =
+            setup_with_ncn_policy().await;
+
+        let result = restaking_program_client
+            .do_warmup_ncn_vault_ticket(&ncn_root, &vault_root.vault_pubkey)
+            .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyMissing);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_ncn_vault_ticket_vault_mint_not_allowed_fails() {
+        let (fixture, mut restaking_program_client, ncn_root, vault_root) =
+            setup_with_ncn_policy().await;
+
+        let mint = Keypair::new();
+        fixture
+            .vault_program_client()
+            .create_token_mint(&mint, &spl_token::id())
+            .await
+            .unwrap();
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[mint.pubkey()], false, 0, 0, 0)
+            .await
+            .unwrap();
+
+        let result = warmup_ncn_vault_ticket_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &vault_root,
+            &ncn_root.ncn_admin,
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnPolicyVaultMintNotAllowed);
+    }
+
+    #[tokio::test]
+    async fn test_warmup_ncn_vault_ticket_permissionless_admission_ok() {
+        let (_fixture, mut restaking_program_client, ncn_root, vault_root) =
+            setup_with_ncn_policy().await;
+
+        let result = warmup_ncn_vault_ticket_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &vault_root,
+            &Keypair::new(),
+        )
+        .await;
+        assert_restaking_error(result, RestakingError::NcnVaultAdminInvalid);
+
+        restaking_program_client
+            .ncn_set_policy(&ncn_root, &[], true, 0, 0, 0)
+            .await
+            .unwrap();
+        warmup_ncn_vault_ticket_with_policy(
+            &mut restaking_program_client,
+            &ncn_root,
+            &vault_root,
+            &Keypair::new(),
+        )
+        .await
+        .unwrap();
+    }
+}
@@ -1,16 +1,17 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_vault_core::{
         config::Config, delegation_state::DelegationState, vault::Vault,
         vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
     };
     use jito_vault_sdk::{error::VaultError, instruction::VaultAdminRole};
     use solana_program::pubkey::Pubkey;
-    use solana_sdk::{instruction::InstructionError, signature::Keypair, signer::Signer};
+    use solana_sdk::{signature::Keypair, signer::Signer};
     use spl_associated_token_account::get_associated_token_address;
 
     use crate::fixtures::{
-        assert_ix_error,
+        assert_account_error,
         fixture::{ConfiguredVault, TestBuilder},
         vault_client::{assert_vault_error, VaultStakerWithdrawalTicketRoot},
     };
@@ -1490,7 +1491,11 @@ mod tests {
             .await;
 
         // Assert that the instruction failed with the expected error
-        assert_ix_error(result, InstructionError::InvalidAccountData);
+        assert_account_error(
+            result,
+            "program_fee_token_account",
+            LoaderError::InvalidAddress,
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod tests {
+    use jito_vault_core::{
+        config::Config, vault_operator_delegation::VaultOperatorDelegation,
+        vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+        vault_update_state_tracker::VaultUpdateStateTracker,
+    };
+    use jito_vault_sdk::compute_units::{
+        BURN_WITHDRAWAL_TICKET_COMPUTE_UNITS, CRANK_VAULT_UPDATE_STATE_TRACKER_COMPUTE_UNITS,
+    };
+    use solana_sdk::{signature::Keypair, signer::Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        fixture::{ConfiguredVault, TestBuilder},
+        vault_client::VaultStakerWithdrawalTicketRoot,
+    };
+
+    /// Asserts that an instruction stayed within its budget. The budgets are for the SBF build of
+    /// the vault program (`SBF_OUT_DIR` is set), as the native builtins only meter their CPIs.
+    #[track_caller]
+    fn assert_within_budget(instruction: &str, compute_units: u64, budget: u32) {
+        if std::env::var_os("SBF_OUT_DIR").is_none() {
+            return;
+        }
+        assert!(
+            compute_units <= u64::from(budget),
+            "{instruction} consumed {compute_units} compute units, over its budget of {budget}"
+        );
+    }
+
+    /// Cranking an operator that has to be force cooled down is the most expensive path
+    #[tokio::test]
+    async fn test_crank_vault_update_state_tracker_within_budget() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        vault_program_client
+            .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+            .await
+            .unwrap();
+        vault_program_client
+            .do_add_delegation(&vault_root, &operator, MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+
+        fixture
+            .warp_slot_incremental(config.epoch_length())
+            .await
+            .unwrap();
+        let ncn_epoch = fixture.get_current_slot().await.unwrap() / config.epoch_length();
+        let vault_update_state_tracker = VaultUpdateStateTracker::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            ncn_epoch,
+        )
+        .0;
+        vault_program_client
+            .initialize_vault_update_state_tracker(
+                &vault_root.vault_pubkey,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+
+        let compute_units = vault_program_client
+            .crank_vault_update_state_tracker_compute_units(
+                &vault_root.vault_pubkey,
+                &operator,
+                &VaultOperatorDelegation::find_program_address(
+                    &jito_vault_program::id(),
+                    &vault_root.vault_pubkey,
+                    &operator,
+                )
+                .0,
+                &vault_update_state_tracker,
+            )
+            .await
+            .unwrap();
+        assert_within_budget(
+            "CrankVaultUpdateStateTracker",
+            compute_units,
+            CRANK_VAULT_UPDATE_STATE_TRACKER_COMPUTE_UNITS,
+        );
+    }
+
+    /// Burning with both fees and extra VRT in the ticket runs every CPI
+    #[tokio::test]
+    async fn test_burn_withdrawal_ticket_within_budget() {
+        const MINT_AMOUNT: u64 = 100_000;
+
+        let mut fixture = TestBuilder::new().await;
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_config_admin,
+            vault_root,
+            operator_roots,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 1_000, 0, 1, &[])
+            .await
+            .unwrap();
+        let operator = operator_roots[0].operator_pubkey;
+
+        vault_program_client
+            .set_program_fee(&vault_config_admin, 100)
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+        vault_program_client
+            .do_mint_to(&vault_root, &depositor, MINT_AMOUNT, MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let config = vault_program_client
+            .get_config(&Config::find_program_address(&jito_vault_program::id()).0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let VaultStakerWithdrawalTicketRoot { base } = vault_program_client
+            .do_enqueue_withdrawal(&vault_root, &depositor, None, MINT_AMOUNT / 2)
+            .await
+            .unwrap();
+        let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::find_program_address(
+            &jito_vault_program::id(),
+            &vault_root.vault_pubkey,
+            &base,
+        )
+        .0;
+        fixture
+            .transfer_token(
+                &spl_token::id(),
+                &depositor,
+                &vault_staker_withdrawal_ticket,
+                &vault.vrt_mint,
+                1,
+            )
+            .await
+            .unwrap();
+
+        for _ in 0..2 {
+            fixture
+                .warp_slot_incremental(config.epoch_length())
+                .await
+                .unwrap();
+            vault_program_client
+                .do_full_vault_update(&vault_root.vault_pubkey, &[operator])
+                .await
+                .unwrap();
+        }
+
+        let compute_units = vault_program_client
+            .burn_withdrawal_ticket_compute_units(
+                &Config::find_program_address(&jito_vault_program::id()).0,
+                &vault_root.vault_pubkey,
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &vault.vrt_mint,
+                &depositor.pubkey(),
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &vault_staker_withdrawal_ticket,
+                &get_associated_token_address(&vault_staker_withdrawal_ticket, &vault.vrt_mint),
+                &get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint),
+                &get_associated_token_address(&config.program_fee_wallet, &vault.vrt_mint),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_within_budget(
+            "BurnWithdrawalTicket",
+            compute_units,
+            BURN_WITHDRAWAL_TICKET_COMPUTE_UNITS,
+        );
+    }
+}
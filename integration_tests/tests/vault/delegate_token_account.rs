@@ -1,14 +1,15 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_vault_core::config::Config;
     use jito_vault_sdk::error::VaultError;
-    use solana_program::{instruction::InstructionError, program_option::COption, pubkey::Pubkey};
+    use solana_program::{program_option::COption, pubkey::Pubkey};
     use solana_sdk::signature::{Keypair, Signer};
     use spl_associated_token_account::get_associated_token_address;
     use test_case::test_case;
 
     use crate::fixtures::{
-        assert_ix_error,
+        assert_account_error,
         fixture::TestBuilder,
         vault_client::{assert_vault_error, VaultRoot},
     };
@@ -201,7 +202,7 @@ mod tests {
                 )
                 .await;
 
-            assert_ix_error(test_error, InstructionError::InvalidAccountData);
+            assert_account_error(test_error, "token_account", LoaderError::InvalidMint);
         } else {
             let test_error = vault_program_client
                 .delegate_token_account(
@@ -215,7 +216,7 @@ mod tests {
                 )
                 .await;
 
-            assert_ix_error(test_error, InstructionError::InvalidAccountData);
+            assert_account_error(test_error, "token_mint", LoaderError::InvalidAccountOwner);
         }
     }
 
@@ -247,7 +248,7 @@ mod tests {
                 )
                 .await;
 
-            assert_ix_error(test_error, InstructionError::InvalidAccountOwner);
+            assert_account_error(test_error, "token_mint", LoaderError::InvalidAccountOwner);
         } else {
             let test_error = vault_program_client
                 .delegate_token_account(
@@ -261,7 +262,7 @@ mod tests {
                 )
                 .await;
 
-            assert_ix_error(test_error, InstructionError::IncorrectProgramId);
+            assert_account_error(test_error, "token_mint", LoaderError::InvalidAccountOwner);
         }
     }
 }
@@ -1,14 +1,14 @@
 #[cfg(test)]
 mod tests {
+    use jito_jsm_core::loader::LoaderError;
     use jito_vault_core::{
         config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
     };
     use jito_vault_sdk::error::VaultError;
-    use solana_program::instruction::InstructionError;
     use solana_sdk::{signature::Keypair, signer::Signer};
 
     use crate::fixtures::{
-        assert_ix_error,
+        assert_account_error,
         fixture::{ConfiguredVault, TestBuilder},
         vault_client::assert_vault_error,
     };
@@ -182,7 +182,11 @@ mod tests {
             )
             .await;
 
-        assert_ix_error(result, InstructionError::InvalidAccountOwner);
+        assert_account_error(
+            result,
+            "vault_update_state_tracker",
+            LoaderError::InvalidAccountOwner,
+        );
     }
 
     #[tokio::test]
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use jito_bytemuck::{AccountDeserialize, ACCOUNT_HEADER_LEN};
+    use jito_vault_core::{config::Config, vault::Vault};
+    use solana_program::rent::Rent;
+
+    use crate::fixtures::fixture::TestBuilder;
+
+    #[tokio::test]
+    async fn test_migrate_account_current_layout_ok() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        vault_program_client.do_initialize_config().await.unwrap();
+        let config_pubkey = Config::find_program_address(&jito_vault_program::id()).0;
+        let account_before = fixture.get_account(&config_pubkey).await.unwrap();
+
+        vault_program_client
+            .migrate_account(&config_pubkey)
+            .await
+            .unwrap();
+
+        let account_after = fixture.get_account(&config_pubkey).await.unwrap();
+        assert_eq!(account_after, account_before);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_account_grows_account() {
+        let mut fixture = TestBuilder::new().await;
+        let mut vault_program_client = fixture.vault_program_client();
+
+        let (_config_admin, vault_root) = vault_program_client
+            .setup_config_and_vault(0, 0, 0)
+            .await
+            .unwrap();
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        // Simulate an account written with an older layout lacking trailing reserved space
+        let mut account = fixture.get_account(&vault_root.vault_pubkey).await.unwrap();
+        let old_len = account.data.len() - 64;
+        account.data.truncate(old_len);
+        account.lamports = Rent::default().minimum_balance(old_len);
+        fixture.set_account(&vault_root.vault_pubkey, account);
+
+        vault_program_client
+            .migrate_account(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+
+        let account = fixture.get_account(&vault_root.vault_pubkey).await.unwrap();
+        let space = ACCOUNT_HEADER_LEN + size_of::<Vault>();
+        assert_eq!(account.data.len(), space);
+        assert!(account.lamports >= Rent::default().minimum_balance(space));
+        assert_eq!(
+            *Vault::try_from_slice_unchecked(&account.data).unwrap(),
+            vault
+        );
+    }
+}
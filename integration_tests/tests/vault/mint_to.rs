@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use jito_jsm_core::loader::LoaderError;
+    use solana_sdk::signature::{Keypair, Signer};
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::fixtures::{
+        assert_account_error,
+        fixture::{ConfiguredVault, TestBuilder},
+    };
+
+    #[tokio::test]
+    async fn test_mint_to_wrong_vault_fee_token_account_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MINT_AMOUNT: u64 = 1000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 0, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let depositor_vrt_token_account =
+            get_associated_token_address(&depositor.pubkey(), &vault.vrt_mint);
+
+        // The depositor's VRT token account is a valid token account, but not the fee wallet's
+        let result = vault_program_client
+            .mint_to(
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor,
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &depositor_vrt_token_account,
+                &depositor_vrt_token_account,
+                None,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await;
+
+        assert_account_error(
+            result,
+            "vault_fee_token_account",
+            LoaderError::InvalidAddress,
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mint_to_wrong_depositor_vrt_token_account_fails() {
+        let mut fixture = TestBuilder::new().await;
+
+        const MINT_AMOUNT: u64 = 1000;
+
+        let ConfiguredVault {
+            mut vault_program_client,
+            vault_root,
+            ..
+        } = fixture
+            .setup_vault_with_ncn_and_operators(0, 0, 0, 0, &[])
+            .await
+            .unwrap();
+
+        let depositor = Keypair::new();
+        vault_program_client
+            .configure_depositor(&vault_root, &depositor.pubkey(), MINT_AMOUNT)
+            .await
+            .unwrap();
+
+        let vault = vault_program_client
+            .get_vault(&vault_root.vault_pubkey)
+            .await
+            .unwrap();
+        let vault_fee_token_account =
+            get_associated_token_address(&vault.fee_wallet, &vault.vrt_mint);
+
+        // The fee wallet's VRT token account is a valid token account, but not the depositor's
+        let result = vault_program_client
+            .mint_to(
+                &vault_root.vault_pubkey,
+                &vault.vrt_mint,
+                &depositor,
+                &get_associated_token_address(&depositor.pubkey(), &vault.supported_mint),
+                &get_associated_token_address(&vault_root.vault_pubkey, &vault.supported_mint),
+                &vault_fee_token_account,
+                &vault_fee_token_account,
+                None,
+                MINT_AMOUNT,
+                MINT_AMOUNT,
+            )
+            .await;
+
+        assert_account_error(
+            result,
+            "depositor_vrt_token_account",
+            LoaderError::InvalidAddress,
+        );
+    }
+}
@@ -2,6 +2,7 @@ mod add_delegation;
 mod burn_withdrawal_ticket;
 mod close_update_state_tracker;
 mod close_vault_update_state_tracker;
+mod compute_units;
 mod cooldown_delegation;
 mod cooldown_vault_ncn_ticket;
 mod crank_vault_update_state_tracker;
@@ -14,6 +15,8 @@ mod initialize_vault_ncn_slasher_ticket;
 mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
+mod migrate_account;
+mod mint_to;
 mod revoke_delegate_token_account;
 mod reward_fee;
 mod set_admin;
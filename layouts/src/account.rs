@@ -0,0 +1,233 @@
+//! The header shared by all program accounts and the traits to decode accounts.
+//!
+//! Every account starts with an [`ACCOUNT_HEADER_LEN`] byte header: the discriminator, the layout
+//! version and six reserved bytes. [`account_from_bytes`] decodes an account struct without
+//! `solana-program`. The `program` feature adds [`AccountDeserialize`] and [`MigrateLayout`],
+//! which return a `ProgramError` and are used by the on-chain programs.
+
+use bytemuck::Pod;
+#[cfg(feature = "program")]
+pub use jito_account_traits_derive::AccountDeserialize;
+#[cfg(feature = "program")]
+use solana_program::{msg, program_error::ProgramError};
+
+use crate::error::AccountDataError;
+
+/// The length of the account header preceding the account struct
+pub const ACCOUNT_HEADER_LEN: usize = 8;
+
+/// The offset of the layout version in the account header
+pub const LAYOUT_VERSION_OFFSET: usize = 1;
+
+pub trait Discriminator {
+    const DISCRIMINATOR: u8;
+
+    /// The current layout version of the account, stored in the second byte of the header.
+    /// Accounts created before layouts were versioned have version zero.
+    const LAYOUT_VERSION: u8 = 0;
+}
+
+/// Returns the layout version stored in the account header, if any
+pub fn layout_version(data: &[u8]) -> Option<u8> {
+    data.get(LAYOUT_VERSION_OFFSET).copied()
+}
+
+/// Checks the discriminator and the layout version in the account header
+///
+/// # Arguments
+/// * `data` - The account data to check
+pub fn check_account_header<T: Discriminator>(data: &[u8]) -> Result<(), AccountDataError> {
+    if data.first() != Some(&T::DISCRIMINATOR) {
+        return Err(AccountDataError::InvalidDiscriminator);
+    }
+    if layout_version(data) != Some(T::LAYOUT_VERSION) {
+        return Err(AccountDataError::InvalidLayoutVersion);
+    }
+    Ok(())
+}
+
+/// Decodes the account data into an account struct, checking the account header
+///
+/// # Arguments
+/// * `data` - The account data, including the header
+///
+/// # Returns
+/// * `Result<&T, AccountDataError>` - The account struct as a reference or an error
+pub fn account_from_bytes<T: Pod + Discriminator>(data: &[u8]) -> Result<&T, AccountDataError> {
+    check_account_header::<T>(data)?;
+    data.get(ACCOUNT_HEADER_LEN..)
+        .and_then(|data| bytemuck::try_from_bytes(data).ok())
+        .ok_or(AccountDataError::InvalidLength)
+}
+
+#[cfg(feature = "program")]
+pub trait AccountDeserialize: Sized + Pod + Discriminator {
+    /// Writes the discriminator and the current layout version to the account header.
+    ///
+    /// # Arguments
+    /// * `data` - The account data, at least [`ACCOUNT_HEADER_LEN`] bytes long
+    fn write_header(data: &mut [u8]) {
+        data[0] = Self::DISCRIMINATOR;
+        data[LAYOUT_VERSION_OFFSET] = Self::LAYOUT_VERSION;
+    }
+
+    /// Checks the discriminator and the layout version in the account header
+    ///
+    /// # Arguments
+    /// * `data` - The account data to check
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - Returns `Ok(())` if the header matches the current layout
+    fn check_header(data: &[u8]) -> Result<(), ProgramError> {
+        match check_account_header::<Self>(data) {
+            Err(AccountDataError::InvalidDiscriminator) => {
+                msg!(
+                    "Discriminator is invalid; expected {}, got {:?}",
+                    Self::DISCRIMINATOR,
+                    data.first()
+                );
+                Err(ProgramError::InvalidAccountData)
+            }
+            Err(_) => {
+                msg!(
+                    "Layout version is invalid; expected {}, got {:?}. The account may need to be migrated",
+                    Self::LAYOUT_VERSION,
+                    layout_version(data)
+                );
+                Err(ProgramError::InvalidAccountData)
+            }
+            Ok(()) => Ok(()),
+        }
+    }
+
+    /// Deserialize the account data into a struct.
+    /// It assumes the first byte is the discriminator, the second byte is the layout version and
+    /// the next six bytes are reserved. The rest of the data is deserialized into the struct.
+    ///
+    /// # Arguments
+    /// * `data` - The account data to deserialize
+    ///
+    /// # Returns
+    /// * `Result<&Self, ProgramError>` - The deserialized struct as a reference or an error
+    fn try_from_slice_unchecked(data: &[u8]) -> Result<&Self, ProgramError> {
+        Self::check_header(data)?;
+        bytemuck::try_from_bytes(&data[ACCOUNT_HEADER_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+
+    /// Deserialize the account data into a mutable struct.
+    /// It assumes the first byte is the discriminator, the second byte is the layout version and
+    /// the next six bytes are reserved. The rest of the data is deserialized into the struct.
+    ///
+    /// # Arguments
+    /// * `data` - The account data to deserialize
+    ///
+    /// # Returns
+    /// * `Result<&mut Self, ProgramError>` - The deserialized struct as a reference or an error
+    fn try_from_slice_unchecked_mut(data: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        Self::check_header(data)?;
+        bytemuck::try_from_bytes_mut(&mut data[ACCOUNT_HEADER_LEN..])
+            .map_err(|_| ProgramError::InvalidAccountData)
+    }
+}
+
+/// An account whose layout can be migrated in place from older layout versions
+#[cfg(feature = "program")]
+pub trait MigrateLayout: AccountDeserialize {
+    /// Migrates the account struct bytes from an older layout version to the current layout.
+    /// The data has already been grown to the current layout size, with any new bytes zeroed.
+    /// The default implementation suits layouts that only append fields or carve them out of
+    /// zeroed reserved space, which need no data changes.
+    ///
+    /// # Arguments
+    /// * `layout_version` - The layout version the account data was written with
+    /// * `data` - The account data following the header
+    fn migrate_from(_layout_version: u8, _data: &mut [u8]) -> Result<(), ProgramError> {
+        Ok(())
+    }
+
+    /// Migrates the account data in place to the current layout, dispatching on the layout
+    /// version in the header. The data shall already be grown to the current layout size.
+    ///
+    /// # Arguments
+    /// * `data` - The account data to migrate
+    ///
+    /// # Returns
+    /// * `Result<bool, ProgramError>` - Whether the layout version was migrated
+    fn migrate(data: &mut [u8]) -> Result<bool, ProgramError> {
+        if data.first() != Some(&Self::DISCRIMINATOR) {
+            msg!(
+                "Discriminator is invalid; expected {}, got {:?}",
+                Self::DISCRIMINATOR,
+                data.first()
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        if data.len().saturating_sub(ACCOUNT_HEADER_LEN) < core::mem::size_of::<Self>() {
+            msg!("Account data is too small for the current layout");
+            return Err(ProgramError::AccountDataTooSmall);
+        }
+        let layout_version = data[LAYOUT_VERSION_OFFSET];
+        if layout_version == Self::LAYOUT_VERSION {
+            return Ok(false);
+        }
+        if layout_version > Self::LAYOUT_VERSION {
+            msg!(
+                "Layout version {} is newer than the current layout version {}",
+                layout_version,
+                Self::LAYOUT_VERSION
+            );
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Self::migrate_from(layout_version, &mut data[ACCOUNT_HEADER_LEN..])?;
+        data[LAYOUT_VERSION_OFFSET] = Self::LAYOUT_VERSION;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{Pod, Zeroable};
+
+    use super::{account_from_bytes, Discriminator, ACCOUNT_HEADER_LEN};
+    use crate::{error::AccountDataError, types::PodU64};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+    #[repr(C)]
+    struct TestAccount {
+        value: PodU64,
+    }
+
+    impl Discriminator for TestAccount {
+        const DISCRIMINATOR: u8 = 1;
+        const LAYOUT_VERSION: u8 = 2;
+    }
+
+    #[test]
+    fn test_account_from_bytes() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        data[..2].copy_from_slice(&[1, 2]);
+        data[ACCOUNT_HEADER_LEN..].copy_from_slice(&42_u64.to_le_bytes());
+        let account = account_from_bytes::<TestAccount>(&data).unwrap();
+        assert_eq!(u64::from(account.value), 42);
+    }
+
+    #[test]
+    fn test_account_from_bytes_bad_header_fails() {
+        let mut data = [0; ACCOUNT_HEADER_LEN + 8];
+        assert_eq!(
+            account_from_bytes::<TestAccount>(&data),
+            Err(AccountDataError::InvalidDiscriminator)
+        );
+        data[0] = TestAccount::DISCRIMINATOR;
+        assert_eq!(
+            account_from_bytes::<TestAccount>(&data),
+            Err(AccountDataError::InvalidLayoutVersion)
+        );
+        data[1] = TestAccount::LAYOUT_VERSION;
+        assert_eq!(
+            account_from_bytes::<TestAccount>(&data[..ACCOUNT_HEADER_LEN + 4]),
+            Err(AccountDataError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,60 @@
+//! Errors returned by the layout math. The on-chain crates convert them into their own error
+//! types, so the error codes returned by the programs don't change.
+
+/// Errors returned when decoding account data with
+/// [`account_from_bytes`](crate::account::account_from_bytes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDataError {
+    /// The discriminator doesn't match the account type
+    InvalidDiscriminator,
+    /// The layout version isn't the current one, the account may need to be migrated
+    InvalidLayoutVersion,
+    /// The account data is not the size of the account type
+    InvalidLength,
+}
+
+/// Errors returned by [`SlotToggle`](crate::slot_toggle::SlotToggle)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotToggleError {
+    /// The epoch length is zero
+    BadEpochLength,
+    /// An epoch calculation overflowed
+    ArithmeticOverflow,
+}
+
+/// Errors returned by [`DelegationState`](crate::delegation_state::DelegationState)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DelegationStateError {
+    /// Adding to the delegated security overflowed
+    SecurityOverflow,
+    /// Removing from the delegated security underflowed
+    SecurityUnderflow,
+    /// The slash amount exceeds the total security
+    SlashUnderflow,
+    /// The slash could not be applied in full
+    SlashIncomplete,
+    /// The cooldown amount is zero
+    CooldownZero,
+    /// The delegation amount is zero
+    DelegationZero,
+}
+
+/// Errors returned by the [`vault_math`](crate::vault_math) functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MathError {
+    /// A calculation overflowed
+    Overflow,
+    /// A calculation underflowed
+    Underflow,
+}
+
+#[cfg(feature = "program")]
+impl From<SlotToggleError> for solana_program::program_error::ProgramError {
+    fn from(e: SlotToggleError) -> Self {
+        match e {
+            // Same code as jito_errors::CoreError::BadEpochLength
+            SlotToggleError::BadEpochLength => Self::Custom(4000),
+            SlotToggleError::ArithmeticOverflow => Self::ArithmeticOverflow,
+        }
+    }
+}
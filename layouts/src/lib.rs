@@ -0,0 +1,22 @@
+//! Account layouts and pure math shared by the restaking and vault programs.
+//!
+//! This crate only depends on `bytemuck` and `solana-pubkey`, so off-chain services and WASM
+//! front-ends can decode and reason about account data without pulling in `solana-program`. The
+//! account structs of both programs live in [`restaking`] and [`vault`], and the on-chain crates
+//! re-export everything here. Enable the `program` feature to load accounts and convert errors
+//! into `ProgramError`, the `shank` feature to derive the shank IDL types, and the `serde` feature
+//! to serialize accounts with base58 addresses.
+#![cfg_attr(not(any(test, feature = "program")), no_std)]
+
+extern crate alloc;
+// Lets the account derives refer to this crate by name from within it
+extern crate self as jito_layouts;
+
+pub mod account;
+pub mod delegation_state;
+pub mod error;
+pub mod restaking;
+pub mod slot_toggle;
+pub mod types;
+pub mod vault;
+pub mod vault_math;
@@ -0,0 +1,110 @@
+//! Global configuration account for the restaking program
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_clock::DEFAULT_SLOTS_PER_EPOCH;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, restaking::discriminators::RestakingDiscriminator, types::PodU64,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The global configuration account for the restaking program. Manages
+/// program-wide settings and state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"config"])
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct Config {
+    /// The configuration admin
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+
+    /// The vault program
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault_program: Pubkey,
+
+    /// The number of NCN managed by the program
+    pub ncn_count: PodU64,
+
+    /// The number of operators managed by the program
+    pub operator_count: PodU64,
+
+    /// The length of an epoch in slots
+    epoch_length: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for Config {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::Config as u8;
+}
+
+impl Config {
+    pub fn new(admin: Pubkey, vault_program: Pubkey, bump: u8) -> Self {
+        Self {
+            admin,
+            vault_program,
+            epoch_length: PodU64::from(DEFAULT_SLOTS_PER_EPOCH),
+            ncn_count: PodU64::from(0),
+            operator_count: PodU64::from(0),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn epoch_length(&self) -> u64 {
+        self.epoch_length.into()
+    }
+
+    pub fn ncn_count(&self) -> u64 {
+        self.ncn_count.into()
+    }
+
+    pub fn operator_count(&self) -> u64 {
+        self.operator_count.into()
+    }
+
+    pub fn set_admin(&mut self, new_admin: Pubkey) {
+        self.admin = new_admin;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_no_padding() {
+        let config_size = std::mem::size_of::<Config>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // admin
+            std::mem::size_of::<Pubkey>() + // vault_program
+            std::mem::size_of::<PodU64>() + // ncn_count
+            std::mem::size_of::<PodU64>() + // operator_count
+            std::mem::size_of::<PodU64>() + // epoch_length
+            std::mem::size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(config_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,52 @@
+/// Discriminators for restaking accounts
+/// Values must not change as they are written on chain to determine the type of account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestakingDiscriminator {
+    Config = 1,
+    Ncn = 2,
+    Operator = 3,
+    NcnOperatorState = 4,
+    OperatorVaultTicket = 5,
+    NcnVaultTicket = 6,
+    NcnVaultSlasherTicket = 7,
+    NcnMetadata = 8,
+    OperatorMetadata = 9,
+    OperatorBond = 10,
+    NcnPolicy = 11,
+}
+
+#[cfg(feature = "program")]
+mod migrate_layout {
+    use crate::{
+        account::MigrateLayout,
+        restaking::{
+            config::Config, ncn::Ncn, ncn_metadata::NcnMetadata,
+            ncn_operator_state::NcnOperatorState, ncn_policy::NcnPolicy,
+            ncn_vault_slasher_ticket::NcnVaultSlasherTicket, ncn_vault_ticket::NcnVaultTicket,
+            operator::Operator, operator_bond::OperatorBond, operator_metadata::OperatorMetadata,
+            operator_vault_ticket::OperatorVaultTicket,
+        },
+    };
+
+    impl MigrateLayout for Config {}
+
+    impl MigrateLayout for Ncn {}
+
+    impl MigrateLayout for Operator {}
+
+    impl MigrateLayout for NcnOperatorState {}
+
+    impl MigrateLayout for OperatorVaultTicket {}
+
+    impl MigrateLayout for NcnVaultTicket {}
+
+    impl MigrateLayout for NcnVaultSlasherTicket {}
+
+    impl MigrateLayout for NcnMetadata {}
+
+    impl MigrateLayout for OperatorMetadata {}
+
+    impl MigrateLayout for OperatorBond {}
+
+    impl MigrateLayout for NcnPolicy {}
+}
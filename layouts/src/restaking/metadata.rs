@@ -0,0 +1,102 @@
+//! Human-readable metadata shared by the [`crate::restaking::ncn_metadata::NcnMetadata`] and
+//! [`crate::restaking::operator_metadata::OperatorMetadata`] accounts.
+//!
+//! Strings are stored as fixed-size, zero-padded UTF-8 byte arrays so the account stays `Pod`.
+
+use alloc::string::String;
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankType;
+
+use crate::types::PodBool;
+
+/// Maximum length of the name in bytes
+pub const MAX_NAME_LEN: usize = 64;
+
+/// Maximum length of the website in bytes
+pub const MAX_WEBSITE_LEN: usize = 128;
+
+/// Maximum length of the logo URI in bytes
+pub const MAX_LOGO_URI_LEN: usize = 128;
+
+/// Maximum length of the contact in bytes
+pub const MAX_CONTACT_LEN: usize = 64;
+
+/// Human-readable information about an NCN or operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "shank", derive(ShankType))]
+#[repr(C)]
+pub struct Metadata {
+    /// The display name
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub name: [u8; 64],
+
+    /// The website URL
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub website: [u8; 128],
+
+    /// The URI of the logo image
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub logo_uri: [u8; 128],
+
+    /// Contact information, such as an email address or a handle
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub contact: [u8; 64],
+
+    /// Hash of an off-chain description document, only valid if `has_description_hash` is set
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub description_hash: [u8; 32],
+
+    /// Whether `description_hash` is set
+    pub has_description_hash: PodBool,
+}
+
+impl Metadata {
+    pub fn name(&self) -> String {
+        Self::unpack(&self.name)
+    }
+
+    pub fn website(&self) -> String {
+        Self::unpack(&self.website)
+    }
+
+    pub fn logo_uri(&self) -> String {
+        Self::unpack(&self.logo_uri)
+    }
+
+    pub fn contact(&self) -> String {
+        Self::unpack(&self.contact)
+    }
+
+    pub fn description_hash(&self) -> Option<[u8; 32]> {
+        if self.has_description_hash.into() {
+            Some(self.description_hash)
+        } else {
+            None
+        }
+    }
+
+    fn unpack(bytes: &[u8]) -> String {
+        let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+        String::from_utf8_lossy(&bytes[..end]).into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metadata_no_padding() {
+        let metadata_size = std::mem::size_of::<Metadata>();
+        let sum_of_fields = MAX_NAME_LEN + // name
+            MAX_WEBSITE_LEN + // website
+            MAX_LOGO_URI_LEN + // logo_uri
+            MAX_CONTACT_LEN + // contact
+            32 + // description_hash
+            std::mem::size_of::<PodBool>(); // has_description_hash
+        assert_eq!(metadata_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,14 @@
+//! The accounts of the restaking program
+pub mod config;
+pub mod discriminators;
+pub mod metadata;
+pub mod ncn;
+pub mod ncn_metadata;
+pub mod ncn_operator_state;
+pub mod ncn_policy;
+pub mod ncn_vault_slasher_ticket;
+pub mod ncn_vault_ticket;
+pub mod operator;
+pub mod operator_bond;
+pub mod operator_metadata;
+pub mod operator_vault_ticket;
@@ -0,0 +1,246 @@
+//! NCN (Node Consensus Network)
+//!
+//! - is a program-owned account that represents a network of nodes that participate in consensus.
+//! - is used to manage the operators, vaults, and slashers that are associated with the network.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    restaking::discriminators::RestakingDiscriminator,
+    slot_toggle::TransitionEpochs,
+    types::{PodBool, PodU64},
+};
+
+const RESERVED_SPACE_LEN: usize = 217;
+
+/// The maximum number of full epochs an NCN can set for warming up or cooling down
+pub const MAX_TRANSITION_EPOCHS: u16 = 32;
+
+/// The NCN manages the operators, vaults, and slashers associated with a network
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"ncn", self.base], name = "NCN")
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct Ncn {
+    /// The base account used as a PDA seed
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base: Pubkey,
+
+    /// The admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+
+    /// The operator admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_admin: Pubkey,
+
+    /// The vault admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault_admin: Pubkey,
+
+    /// The slasher admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slasher_admin: Pubkey,
+
+    /// The delegate admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub delegate_admin: Pubkey,
+
+    /// Authority to update the ncn's metadata
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub metadata_admin: Pubkey,
+
+    /// The weight table admin of the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub weight_table_admin: Pubkey,
+
+    /// Admin in charge of of any on-chain programs related to the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_program_admin: Pubkey,
+
+    /// The index of the NCN
+    pub index: PodU64,
+
+    /// Number of operator accounts associated with the NCN
+    pub operator_count: PodU64,
+
+    /// Number of vault accounts associated with the NCN
+    pub vault_count: PodU64,
+
+    /// Number of slasher accounts associated with the NCN
+    pub slasher_count: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// The mint of the tokens operators shall bond to be warmed up by the NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_bond_mint: Pubkey,
+
+    /// The minimum operator self-bond required to warm up an operator, zero if none is required
+    pub min_operator_bond: PodU64,
+
+    /// Whether the NCN has an [`crate::restaking::ncn_policy::NcnPolicy`] that shall be enforced
+    pub has_policy: PodBool,
+
+    /// The warmup and cooldown durations of the NCN's operator, vault and slasher relationships
+    pub transition_epochs: TransitionEpochs,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 217],
+}
+
+impl Discriminator for Ncn {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::Ncn as u8;
+}
+
+impl Ncn {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(base: Pubkey, admin: Pubkey, ncn_index: u64, bump: u8) -> Self {
+        Self {
+            base,
+            admin,
+            operator_admin: admin,
+            vault_admin: admin,
+            slasher_admin: admin,
+            delegate_admin: admin,
+            metadata_admin: admin,
+            weight_table_admin: admin,
+            ncn_program_admin: admin,
+            index: PodU64::from(ncn_index),
+            operator_count: PodU64::from(0),
+            vault_count: PodU64::from(0),
+            slasher_count: PodU64::from(0),
+            bump,
+            operator_bond_mint: Pubkey::default(),
+            min_operator_bond: PodU64::from(0),
+            has_policy: PodBool::from(false),
+            transition_epochs: TransitionEpochs::default(),
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn operator_count(&self) -> u64 {
+        self.operator_count.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+
+    pub fn slasher_count(&self) -> u64 {
+        self.slasher_count.into()
+    }
+
+    pub fn min_operator_bond(&self) -> u64 {
+        self.min_operator_bond.into()
+    }
+
+    /// Sets the operator self-bond requirement
+    ///
+    /// # Arguments
+    /// * `operator_bond_mint` - The mint of the tokens operators shall bond
+    /// * `min_operator_bond` - The minimum bond, zero to disable the requirement
+    pub fn set_operator_bond_requirement(
+        &mut self,
+        operator_bond_mint: Pubkey,
+        min_operator_bond: u64,
+    ) {
+        self.operator_bond_mint = operator_bond_mint;
+        self.min_operator_bond = PodU64::from(min_operator_bond);
+    }
+
+    pub fn has_policy(&self) -> bool {
+        self.has_policy.into()
+    }
+
+    pub fn set_has_policy(&mut self, has_policy: bool) {
+        self.has_policy = PodBool::from(has_policy);
+    }
+
+    pub const fn transition_epochs(&self) -> TransitionEpochs {
+        self.transition_epochs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+
+    use super::{Ncn, RESERVED_SPACE_LEN};
+    use crate::{
+        slot_toggle::TransitionEpochs,
+        types::{PodBool, PodU64},
+    };
+
+    #[test]
+    fn test_ncn_no_padding() {
+        let ncn_size = std::mem::size_of::<Ncn>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // base
+            std::mem::size_of::<Pubkey>() + // admin
+            std::mem::size_of::<Pubkey>() + // operator_admin
+            std::mem::size_of::<Pubkey>() + // vault_admin
+            std::mem::size_of::<Pubkey>() + // slasher_admin
+            std::mem::size_of::<Pubkey>() + // delegate_admin
+            std::mem::size_of::<Pubkey>() + // metadata_admin
+            std::mem::size_of::<Pubkey>() + // weight_table_admin
+            std::mem::size_of::<Pubkey>() + // ncn_program_admin
+            std::mem::size_of::<PodU64>() + // index
+            std::mem::size_of::<PodU64>() + // operator_count
+            std::mem::size_of::<PodU64>() + // vault_count
+            std::mem::size_of::<PodU64>() + // slasher_count
+            std::mem::size_of::<u8>() + // bump
+            std::mem::size_of::<Pubkey>() + // operator_bond_mint
+            std::mem::size_of::<PodU64>() + // min_operator_bond
+            std::mem::size_of::<PodBool>() + // has_policy
+            std::mem::size_of::<TransitionEpochs>() + // transition_epochs
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_size, sum_of_fields);
+    }
+}
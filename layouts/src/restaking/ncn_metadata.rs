@@ -0,0 +1,75 @@
+//! The NcnMetadata account stores human-readable information about an NCN, such as its name,
+//! website and logo. It is managed by the NCN's metadata admin.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    restaking::{discriminators::RestakingDiscriminator, metadata::Metadata},
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// Human-readable information about an NCN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"ncn_metadata", ncn])
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct NcnMetadata {
+    /// The NCN this metadata describes
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The metadata
+    pub metadata: Metadata,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for NcnMetadata {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnMetadata as u8;
+}
+
+impl NcnMetadata {
+    pub const fn new(ncn: Pubkey, metadata: Metadata, bump: u8) -> Self {
+        Self {
+            ncn,
+            metadata,
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ncn_metadata_no_padding() {
+        let ncn_metadata_size = std::mem::size_of::<NcnMetadata>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Metadata>() + // metadata
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_metadata_size, sum_of_fields);
+    }
+}
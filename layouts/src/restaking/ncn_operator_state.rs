@@ -0,0 +1,213 @@
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    error::SlotToggleError,
+    restaking::discriminators::RestakingDiscriminator,
+    slot_toggle::{get_epoch, SlotToggle},
+    types::{PodBool, PodU64},
+};
+
+const RESERVED_SPACE_LEN: usize = 254;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"ncn_operator_state", ncn, operator],
+        name = "NCNOperatorState"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct NcnOperatorState {
+    /// The NCN account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The operator account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    /// Index
+    index: PodU64,
+
+    /// State of the ncn opt-ing in to the operator
+    pub ncn_opt_in_state: SlotToggle,
+
+    /// State of the operator opt-ing in to the ncn
+    pub operator_opt_in_state: SlotToggle,
+
+    pub bump: u8,
+
+    /// Whether the operator was admitted under the NCN policy
+    policy_admitted: PodBool,
+
+    /// The operator is jailed by the NCN until the start of this epoch
+    jailed_until_epoch: PodU64,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 254],
+}
+
+impl Discriminator for NcnOperatorState {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnOperatorState as u8;
+}
+
+impl NcnOperatorState {
+    pub fn new(ncn: Pubkey, operator: Pubkey, index: u64, bump: u8, slot: u64) -> Self {
+        Self {
+            ncn,
+            operator,
+            index: PodU64::from(index),
+            ncn_opt_in_state: SlotToggle::new(slot),
+            operator_opt_in_state: SlotToggle::new(slot),
+            bump,
+            policy_admitted: PodBool::from(false),
+            jailed_until_epoch: PodU64::from(0),
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn policy_admitted(&self) -> bool {
+        self.policy_admitted.into()
+    }
+
+    pub fn set_policy_admitted(&mut self, policy_admitted: bool) {
+        self.policy_admitted = PodBool::from(policy_admitted);
+    }
+
+    pub fn jailed_until_epoch(&self) -> u64 {
+        self.jailed_until_epoch.into()
+    }
+
+    /// Jails the operator until the start of the given epoch
+    pub fn jail(&mut self, jailed_until_epoch: u64) {
+        self.jailed_until_epoch = PodU64::from(jailed_until_epoch);
+    }
+
+    /// Releases the operator from jail
+    pub fn unjail(&mut self) {
+        self.jailed_until_epoch = PodU64::from(0);
+    }
+
+    /// Whether the operator is jailed at the given slot. Jail expires on its own once the
+    /// jailed-until epoch is reached.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn is_jailed(&self, slot: u64, epoch_length: u64) -> Result<bool, SlotToggleError> {
+        Ok(get_epoch(slot, epoch_length)? < self.jailed_until_epoch())
+    }
+
+    /// Whether the NCN and the operator are both active and the operator isn't jailed. Stake
+    /// delegated to an operator shall only count towards an NCN while this is true.
+    ///
+    /// # Arguments
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    pub fn is_active(&self, slot: u64, epoch_length: u64) -> Result<bool, SlotToggleError> {
+        Ok(self.ncn_opt_in_state.is_active(slot, epoch_length)?
+            && self.operator_opt_in_state.is_active(slot, epoch_length)?
+            && !self.is_jailed(slot, epoch_length)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::slot_toggle::SlotToggleState;
+
+    #[test]
+    fn test_ncn_operator_state_no_padding() {
+        let ncn_operator_state_size = std::mem::size_of::<NcnOperatorState>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // operator
+            size_of::<PodU64>() + // index
+            size_of::<SlotToggle>() + // ncn_opt_in_state
+            size_of::<SlotToggle>() + // operator_opt_in_state
+            size_of::<u8>() + // bump
+            size_of::<PodBool>() + // policy_admitted
+            size_of::<PodU64>() + // jailed_until_epoch
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_operator_state_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_ncn_operator_state_inactive_on_creation() {
+        let slot = 1;
+        let ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, slot);
+        assert_eq!(
+            ncn_operator_state
+                .ncn_opt_in_state
+                .state(slot + 1, 100)
+                .unwrap(),
+            SlotToggleState::Inactive
+        );
+        assert_eq!(
+            ncn_operator_state
+                .operator_opt_in_state
+                .state(slot + 1, 100)
+                .unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+
+    #[test]
+    fn test_ncn_operator_state_jailed_is_inactive() {
+        const EPOCH_LENGTH: u64 = 100;
+        let mut ncn_operator_state =
+            NcnOperatorState::new(Pubkey::default(), Pubkey::default(), 0, 0, 0);
+        assert!(ncn_operator_state
+            .ncn_opt_in_state
+            .activate(1, EPOCH_LENGTH)
+            .unwrap());
+        assert!(ncn_operator_state
+            .operator_opt_in_state
+            .activate(1, EPOCH_LENGTH)
+            .unwrap());
+
+        let slot = 3 * EPOCH_LENGTH;
+        assert!(ncn_operator_state.is_active(slot, EPOCH_LENGTH).unwrap());
+
+        // Jailed until the start of epoch 5
+        ncn_operator_state.jail(5);
+        assert!(ncn_operator_state.is_jailed(slot, EPOCH_LENGTH).unwrap());
+        assert!(!ncn_operator_state.is_active(slot, EPOCH_LENGTH).unwrap());
+        assert!(ncn_operator_state
+            .is_jailed(5 * EPOCH_LENGTH - 1, EPOCH_LENGTH)
+            .unwrap());
+
+        // Jail expires on its own
+        assert!(!ncn_operator_state
+            .is_jailed(5 * EPOCH_LENGTH, EPOCH_LENGTH)
+            .unwrap());
+        assert!(ncn_operator_state
+            .is_active(5 * EPOCH_LENGTH, EPOCH_LENGTH)
+            .unwrap());
+
+        ncn_operator_state.unjail();
+        assert!(ncn_operator_state.is_active(slot, EPOCH_LENGTH).unwrap());
+    }
+}
@@ -0,0 +1,150 @@
+//! The NcnPolicy account holds the admission rules of an NCN. Once an NCN has a policy, the
+//! program enforces it when warming up operators and vaults.
+//!
+//! With permissionless admission enabled, any signer can warm up operators and vaults that satisfy
+//! the policy.
+
+use alloc::vec::Vec;
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    restaking::discriminators::RestakingDiscriminator,
+    types::{PodBool, PodU64},
+};
+
+/// The maximum number of vault supported mints a policy can allow
+pub const MAX_ALLOWED_VAULT_MINTS: usize = 8;
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The admission rules of an NCN
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"ncn_policy", ncn])
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct NcnPolicy {
+    /// The NCN account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The vault supported mints allowed to be warmed up, unused entries are the default pubkey.
+    /// Any mint is allowed if all entries are unused.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<[serde_with::DisplayFromStr; 8]>")
+    )]
+    pub allowed_vault_mints: [Pubkey; 8],
+
+    /// The minimum stake delegated to an operator to be warmed up, zero if none is required
+    pub min_operator_stake: PodU64,
+
+    /// The maximum number of operators admitted under the policy, zero if unlimited
+    pub max_operators: PodU64,
+
+    /// The number of operators currently admitted under the policy
+    pub admitted_operator_count: PodU64,
+
+    /// The minimum number of slots an operator shall have been opted in to the NCN before it can
+    /// be warmed up, zero if none is required
+    pub min_operator_ticket_age: PodU64,
+
+    /// Whether any signer can warm up operators and vaults that satisfy the policy
+    pub permissionless_admission: PodBool,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for NcnPolicy {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnPolicy as u8;
+}
+
+impl NcnPolicy {
+    pub fn new(ncn: Pubkey, bump: u8) -> Self {
+        Self {
+            ncn,
+            allowed_vault_mints: [Pubkey::default(); MAX_ALLOWED_VAULT_MINTS],
+            min_operator_stake: PodU64::from(0),
+            max_operators: PodU64::from(0),
+            admitted_operator_count: PodU64::from(0),
+            min_operator_ticket_age: PodU64::from(0),
+            permissionless_admission: PodBool::from(false),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    /// Returns the allowed vault supported mints, empty if any mint is allowed
+    pub fn allowed_vault_mints(&self) -> Vec<Pubkey> {
+        self.allowed_vault_mints
+            .iter()
+            .filter(|mint| **mint != Pubkey::default())
+            .copied()
+            .collect()
+    }
+
+    pub fn min_operator_stake(&self) -> u64 {
+        self.min_operator_stake.into()
+    }
+
+    pub fn max_operators(&self) -> u64 {
+        self.max_operators.into()
+    }
+
+    pub fn admitted_operator_count(&self) -> u64 {
+        self.admitted_operator_count.into()
+    }
+
+    pub fn min_operator_ticket_age(&self) -> u64 {
+        self.min_operator_ticket_age.into()
+    }
+
+    pub fn permissionless_admission(&self) -> bool {
+        self.permissionless_admission.into()
+    }
+
+    /// Whether vaults with the given supported mint are allowed by the policy
+    pub fn is_vault_mint_allowed(&self, mint: &Pubkey) -> bool {
+        let allowed_vault_mints = self.allowed_vault_mints();
+        allowed_vault_mints.is_empty() || allowed_vault_mints.contains(mint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ncn_policy_no_padding() {
+        let ncn_policy_size = std::mem::size_of::<NcnPolicy>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() * MAX_ALLOWED_VAULT_MINTS + // allowed_vault_mints
+            size_of::<PodU64>() + // min_operator_stake
+            size_of::<PodU64>() + // max_operators
+            size_of::<PodU64>() + // admitted_operator_count
+            size_of::<PodU64>() + // min_operator_ticket_age
+            size_of::<PodBool>() + // permissionless_admission
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_policy_size, sum_of_fields);
+    }
+}
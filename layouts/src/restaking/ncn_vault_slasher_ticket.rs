@@ -0,0 +1,156 @@
+//! The NcnVaultSlasherTicket tracks the opting-in of a slasher to a particular vault.
+//! The NcnVaultSlasherTicket can be activated and deactivated over time by the NCN slasher admin.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, restaking::discriminators::RestakingDiscriminator,
+    slot_toggle::SlotToggle, types::PodU64,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`NcnVaultSlasherTicket`] account
+///
+/// - is created by the NCN and it tracks the state of a node consensus network network opting-in to a vault slasher.
+/// - can be activated and deactivated over time.
+/// - can slash a specific operator that's receiving delegation from a vault for a maximum amount per epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"ncn_slasher_ticket", ncn, vault, slasher],
+        name = "NCN vault slasher ticket"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct NcnVaultSlasherTicket {
+    /// The NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The vault account this slasher can slash
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The slasher signer
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slasher: Pubkey,
+
+    /// The max slashable funds per epoch per operator
+    max_slashable_per_epoch: PodU64,
+
+    /// The index
+    index: PodU64,
+
+    /// State of the NCN slasher
+    pub state: SlotToggle,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for NcnVaultSlasherTicket {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::NcnVaultSlasherTicket as u8;
+}
+
+impl NcnVaultSlasherTicket {
+    /// Create a new NcnVaultSlasherTicket and immediately activates it at the given slot, transitioning
+    /// it to warming up.
+    ///
+    /// # Arguments
+    /// * `ncn` - The node consensus network
+    /// * `vault` - The vault account this slasher can slash
+    /// * `slasher` - The slasher signer
+    /// * `max_slashable_per_epoch` - The max slashable funds per epoch per operator
+    /// * `index` - The index
+    /// * `slot_added` - The slot at which the ticket was created
+    /// * `bump` - The bump seed for the PDA
+    pub fn new(
+        ncn: Pubkey,
+        vault: Pubkey,
+        slasher: Pubkey,
+        max_slashable_per_epoch: u64,
+        index: u64,
+        bump: u8,
+        slot: u64,
+    ) -> Self {
+        Self {
+            ncn,
+            vault,
+            slasher,
+            max_slashable_per_epoch: PodU64::from(max_slashable_per_epoch),
+            index: PodU64::from(index),
+            state: SlotToggle::new(slot),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn max_slashable_per_epoch(&self) -> u64 {
+        self.max_slashable_per_epoch.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slot_toggle::SlotToggleState;
+
+    #[test]
+    fn test_ncn_vault_slasher_ticket_no_padding() {
+        let ncn_vault_slasher_ticket_size = std::mem::size_of::<NcnVaultSlasherTicket>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // slasher
+            size_of::<PodU64>() + // max_slashable_per_epoch
+            size_of::<PodU64>() + // index
+            size_of::<SlotToggle>() + // state
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(ncn_vault_slasher_ticket_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_ncn_vault_slasher_ticket_inactive_on_creation() {
+        let slot = 1;
+        let ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            0,
+            slot,
+        );
+        assert_eq!(
+            ncn_vault_slasher_ticket.state.state(slot + 1, 100).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
@@ -0,0 +1,170 @@
+//! The Operator account stores global information for a particular operator
+//! including the admin, voter, and the number of NCN and vault accounts.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    restaking::discriminators::RestakingDiscriminator,
+    types::{PodU16, PodU64},
+};
+
+const RESERVED_SPACE_LEN: usize = 261;
+
+/// The Operator account stores global information for a particular operator
+/// including the admin, voter, and the number of NCN and vault accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"operator", self.base], bump = bump)
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct Operator {
+    /// The base pubkey used as a seed for the PDA
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base: Pubkey,
+
+    /// The admin pubkey
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+
+    /// The NCN admin can add and remove support for NCNs in the restaking protocol
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_admin: Pubkey,
+
+    /// The vault admin can add and remove support for vaults in the restaking protocol
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault_admin: Pubkey,
+
+    /// The delegate admin can delegate assets from the operator
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub delegate_admin: Pubkey,
+
+    /// Authority to update the operator's metadata
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub metadata_admin: Pubkey,
+
+    /// The voter pubkey can be used as the voter for signing transactions for interacting
+    /// with various NCN programs. NCNs can also opt for their own signing infrastructure.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub voter: Pubkey,
+
+    /// The operator index
+    index: PodU64,
+
+    /// The number of NcnOperatorTickets associated with the operator.
+    /// Helpful for indexing all available OperatorNcnTickets.
+    pub ncn_count: PodU64,
+
+    /// The number of OperatorVaultTickets associated with the operator.
+    /// Helpful for indexing all available OperatorVaultTickets.
+    pub vault_count: PodU64,
+
+    /// The operator fee in basis points
+    pub operator_fee_bps: PodU16,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved_space: [u8; 261],
+}
+
+impl Discriminator for Operator {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::Operator as u8;
+}
+
+impl Operator {
+    /// Create a new Operator account
+    /// # Arguments
+    /// * `base` - The base account used as a PDA seed
+    /// * `admin` - The admin of the Operator
+    /// * `index` - The index of the Operator
+    /// * `bump` - The bump seed for the PDA
+    pub fn new(base: Pubkey, admin: Pubkey, index: u64, operator_fee_bps: u16, bump: u8) -> Self {
+        Self {
+            base,
+            admin,
+            ncn_admin: admin,
+            vault_admin: admin,
+            delegate_admin: admin,
+            metadata_admin: admin,
+            voter: admin,
+            index: PodU64::from(index),
+            ncn_count: PodU64::from(0),
+            vault_count: PodU64::from(0),
+            operator_fee_bps: PodU16::from(operator_fee_bps),
+            bump,
+            reserved_space: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn ncn_count(&self) -> u64 {
+        self.ncn_count.into()
+    }
+
+    pub fn vault_count(&self) -> u64 {
+        self.vault_count.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+
+    use super::{Operator, RESERVED_SPACE_LEN};
+    use crate::types::{PodU16, PodU64};
+
+    #[test]
+    fn test_operator_no_padding() {
+        let operator_size = std::mem::size_of::<Operator>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // base
+            std::mem::size_of::<Pubkey>() + // admin
+            std::mem::size_of::<Pubkey>() + // ncn_admin
+            std::mem::size_of::<Pubkey>() + // vault_admin
+            std::mem::size_of::<Pubkey>() + // delegate_admin
+            std::mem::size_of::<Pubkey>() + // metadata_admin
+            std::mem::size_of::<Pubkey>() + // voter
+            std::mem::size_of::<PodU64>() + // index
+            std::mem::size_of::<PodU64>() + // ncn_count
+            std::mem::size_of::<PodU64>() + // vault_count
+            std::mem::size_of::<PodU16>() + // operator_fee_bps
+            std::mem::size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(operator_size, sum_of_fields);
+    }
+}
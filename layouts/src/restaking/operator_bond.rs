@@ -0,0 +1,131 @@
+//! The OperatorBond account tracks the tokens an operator has bonded to an NCN in a given mint.
+//! The tokens are held in the associated token account of the OperatorBond PDA.
+//!
+//! NCNs can require a minimum bond before warming up an operator, and only the NCN the bond is
+//! scoped to can slash it. Unbonded tokens cool down for the NCN's cooldown epochs before they can
+//! be withdrawn, and stay slashable until then.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, restaking::discriminators::RestakingDiscriminator, types::PodU64,
+};
+
+const RESERVED_SPACE_LEN: usize = 215;
+
+/// The self-bond of an operator to an NCN in a given mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"operator_bond", ncn, operator, mint: Pubkey])
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct OperatorBond {
+    /// The NCN the bond is posted to
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The operator account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    /// The mint of the bonded tokens
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+
+    /// The amount of tokens currently bonded
+    pub amount: PodU64,
+
+    /// The total amount of tokens slashed from the bond
+    pub slashed: PodU64,
+
+    /// The amount of tokens unbonded and cooling down
+    pub unbonding_amount: PodU64,
+
+    /// The epoch from which the unbonding tokens can be withdrawn
+    pub withdrawable_epoch: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space, [`RESERVED_SPACE_LEN`] bytes. The IDL generator only reads literal array
+    /// lengths, so the constant cannot be used here; `new` fails to compile if they differ.
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 215],
+}
+
+impl Discriminator for OperatorBond {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorBond as u8;
+}
+
+impl OperatorBond {
+    pub fn new(ncn: Pubkey, operator: Pubkey, mint: Pubkey, bump: u8) -> Self {
+        Self {
+            ncn,
+            operator,
+            mint,
+            amount: PodU64::from(0),
+            slashed: PodU64::from(0),
+            unbonding_amount: PodU64::from(0),
+            withdrawable_epoch: PodU64::from(0),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn amount(&self) -> u64 {
+        self.amount.into()
+    }
+
+    pub fn slashed(&self) -> u64 {
+        self.slashed.into()
+    }
+
+    pub fn unbonding_amount(&self) -> u64 {
+        self.unbonding_amount.into()
+    }
+
+    pub fn withdrawable_epoch(&self) -> u64 {
+        self.withdrawable_epoch.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_bond_no_padding() {
+        let operator_bond_size = std::mem::size_of::<OperatorBond>();
+        let sum_of_fields = size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // operator
+            size_of::<Pubkey>() + // mint
+            size_of::<PodU64>() + // amount
+            size_of::<PodU64>() + // slashed
+            size_of::<PodU64>() + // unbonding_amount
+            size_of::<PodU64>() + // withdrawable_epoch
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(operator_bond_size, sum_of_fields);
+
+        // With the 8-byte account header
+        assert_eq!(8 + operator_bond_size, 352);
+    }
+}
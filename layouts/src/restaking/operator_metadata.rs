@@ -0,0 +1,75 @@
+//! The OperatorMetadata account stores human-readable information about an operator, such as
+//! its name, website and logo. It is managed by the operator's metadata admin.
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    restaking::{discriminators::RestakingDiscriminator, metadata::Metadata},
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// Human-readable information about an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"operator_metadata", operator])
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct OperatorMetadata {
+    /// The operator this metadata describes
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    /// The metadata
+    pub metadata: Metadata,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for OperatorMetadata {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorMetadata as u8;
+}
+
+impl OperatorMetadata {
+    pub const fn new(operator: Pubkey, metadata: Metadata, bump: u8) -> Self {
+        Self {
+            operator,
+            metadata,
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operator_metadata_no_padding() {
+        let operator_metadata_size = std::mem::size_of::<OperatorMetadata>();
+        let sum_of_fields = size_of::<Pubkey>() + // operator
+            size_of::<Metadata>() + // metadata
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(operator_metadata_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,103 @@
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, restaking::discriminators::RestakingDiscriminator,
+    slot_toggle::SlotToggle, types::PodU64,
+};
+
+const RESERVED_LEN: usize = 263;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"operator_vault_ticket", operator, vault],
+        name = "Operator vault ticket"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct OperatorVaultTicket {
+    /// The operator account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    /// The vault account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The index
+    index: PodU64,
+
+    /// The slot toggle
+    pub state: SlotToggle,
+
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for OperatorVaultTicket {
+    const DISCRIMINATOR: u8 = RestakingDiscriminator::OperatorVaultTicket as u8;
+}
+
+impl OperatorVaultTicket {
+    pub fn new(operator: Pubkey, vault: Pubkey, index: u64, bump: u8, slot: u64) -> Self {
+        Self {
+            operator,
+            vault,
+            index: PodU64::from(index),
+            state: SlotToggle::new(slot),
+            bump,
+            reserved: [0; RESERVED_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slot_toggle::SlotToggleState;
+
+    #[test]
+    fn test_operator_vault_ticket_no_padding() {
+        let operator_vault_ticket_size = std::mem::size_of::<OperatorVaultTicket>();
+        let sum_of_fields = size_of::<Pubkey>() + // operator
+            size_of::<Pubkey>() + // vault
+            size_of::<PodU64>() + // index
+            size_of::<SlotToggle>() + // state
+            size_of::<u8>() + // bump
+            RESERVED_LEN; // reserved
+        assert_eq!(operator_vault_ticket_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_operator_vault_ticket_inactive_on_creation() {
+        let slot = 1;
+        let operator_vault_ticket =
+            OperatorVaultTicket::new(Pubkey::default(), Pubkey::default(), 0, 0, slot);
+        assert_eq!(
+            operator_vault_ticket.state.state(slot + 1, 100).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
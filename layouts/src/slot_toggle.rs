@@ -0,0 +1,568 @@
+//! Slot toggled state tracker, useful for activations and deactivations of certain features
+//! based on slot time.
+
+use core::{cmp::Ordering, fmt::Debug};
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankType;
+
+use crate::{
+    error::SlotToggleError,
+    types::{PodBool, PodU16, PodU64},
+};
+
+/// The number of full epochs a transition takes when no custom duration is set
+pub const DEFAULT_TRANSITION_EPOCHS: u16 = 1;
+
+/// Custom warmup and cooldown durations, in full epochs, of a [`SlotToggle`].
+///
+/// Zeroed bytes mean the durations aren't set and [`DEFAULT_TRANSITION_EPOCHS`] applies, which
+/// keeps accounts created before custom durations existed backwards compatible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "shank", derive(ShankType))]
+#[repr(C)]
+pub struct TransitionEpochs {
+    /// Whether the custom durations are set
+    is_set: PodBool,
+    /// The number of full epochs spent warming up
+    warmup_epochs: PodU16,
+    /// The number of full epochs spent cooling down
+    cooldown_epochs: PodU16,
+}
+
+impl Default for TransitionEpochs {
+    fn default() -> Self {
+        Self::zeroed()
+    }
+}
+
+impl TransitionEpochs {
+    /// Create custom transition durations
+    ///
+    /// # Arguments
+    /// * `warmup_epochs` - The number of full epochs spent warming up, zero activates at the next epoch
+    /// * `cooldown_epochs` - The number of full epochs spent cooling down, zero deactivates at the next epoch
+    pub fn new(warmup_epochs: u16, cooldown_epochs: u16) -> Self {
+        Self {
+            is_set: PodBool::from(true),
+            warmup_epochs: PodU16::from(warmup_epochs),
+            cooldown_epochs: PodU16::from(cooldown_epochs),
+        }
+    }
+
+    /// Whether custom durations are set
+    pub fn is_set(&self) -> bool {
+        self.is_set.into()
+    }
+
+    /// The number of full epochs spent warming up
+    pub fn warmup_epochs(&self) -> u16 {
+        if self.is_set() {
+            self.warmup_epochs.into()
+        } else {
+            DEFAULT_TRANSITION_EPOCHS
+        }
+    }
+
+    /// The number of full epochs spent cooling down
+    pub fn cooldown_epochs(&self) -> u16 {
+        if self.is_set() {
+            self.cooldown_epochs.into()
+        } else {
+            DEFAULT_TRANSITION_EPOCHS
+        }
+    }
+}
+
+/// Returns the epoch of the given slot
+pub(crate) fn get_epoch(slot: u64, epoch_length: u64) -> Result<u64, SlotToggleError> {
+    slot.checked_div(epoch_length)
+        .ok_or(SlotToggleError::BadEpochLength)
+}
+
+/// SlotToggle is a state tracker that allows for activation and deactivation of certain features
+/// based on slot time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "shank", derive(ShankType))]
+#[repr(C)]
+pub struct SlotToggle {
+    /// The slot at which the feature was added
+    slot_added: PodU64,
+    /// The slot at which the feature was removed
+    slot_removed: PodU64,
+    /// The warmup and cooldown durations of the last transition
+    transition_epochs: TransitionEpochs,
+
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 27],
+}
+
+/// The state of the SlotToggle
+#[derive(Debug, PartialEq, Eq)]
+pub enum SlotToggleState {
+    /// The feature is inactive
+    Inactive,
+    /// The feature is in warm-up state
+    WarmUp,
+    /// The feature is active
+    Active,
+    /// The feature is in cooldown state
+    Cooldown,
+}
+
+impl SlotToggle {
+    /// Create a new SlotToggle with the given slot
+    /// This sets slot_added and slot_removed to the same value, meaning the feature is inactive upon creation
+    pub fn new(slot: u64) -> Self {
+        Self {
+            slot_added: PodU64::from(slot),
+            slot_removed: PodU64::from(slot),
+            transition_epochs: TransitionEpochs::default(),
+            reserved: [0; 27],
+        }
+    }
+
+    /// Get the slot at which the feature was added
+    pub fn slot_added(&self) -> u64 {
+        self.slot_added.into()
+    }
+
+    /// Get the slot at which the feature was removed
+    pub fn slot_removed(&self) -> u64 {
+        self.slot_removed.into()
+    }
+
+    /// Get the warmup and cooldown durations of the last transition
+    pub const fn transition_epochs(&self) -> TransitionEpochs {
+        self.transition_epochs
+    }
+
+    /// Activate the feature at the given slot, which can only happen if the feature is inactive.
+    /// Once activated, it immediately transitions to warming up state, which takes place for
+    /// the stored number of **full** warmup epochs (one by default) before transitioning to
+    /// active state.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being activated
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully activated
+    pub fn activate(&mut self, slot: u64, epoch_length: u64) -> Result<bool, SlotToggleError> {
+        self.activate_with_transition_epochs(slot, epoch_length, self.transition_epochs)
+    }
+
+    /// Activate the feature at the given slot with custom transition durations, which can only
+    /// happen if the feature is inactive. Once activated, it immediately transitions to warming
+    /// up state, which takes place for the number of **full** warmup epochs before transitioning
+    /// to active state. The durations are kept for the following cooldown.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being activated
+    /// * `epoch_length` - The length of an epoch in slots
+    /// * `transition_epochs` - The warmup and cooldown durations
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully activated
+    pub fn activate_with_transition_epochs(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        transition_epochs: TransitionEpochs,
+    ) -> Result<bool, SlotToggleError> {
+        match self.state(slot, epoch_length)? {
+            SlotToggleState::Inactive => {
+                if self.slot_added() == slot {
+                    // this should only be possible if the feature is being activated for the first time
+                    // and the slot is the same as the slot it was created at
+                    Ok(false)
+                } else {
+                    self.slot_added = PodU64::from(slot);
+                    self.transition_epochs = transition_epochs;
+                    Ok(true)
+                }
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Deactivate the feature at the given slot, which can only happen if the feature is active.
+    /// Once deactivated, it immediately transitions to cooldown state, which takes place for
+    /// the stored number of **full** cooldown epochs (one by default) before transitioning to
+    /// inactive state.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being deactivated
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully deactivated
+    pub fn deactivate(&mut self, slot: u64, epoch_length: u64) -> Result<bool, SlotToggleError> {
+        self.deactivate_with_transition_epochs(slot, epoch_length, self.transition_epochs)
+    }
+
+    /// Deactivate the feature at the given slot with custom transition durations, which can only
+    /// happen if the feature is active. Once deactivated, it immediately transitions to cooldown
+    /// state, which takes place for the number of **full** cooldown epochs before transitioning
+    /// to inactive state.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the feature is being deactivated
+    /// * `epoch_length` - The length of an epoch in slots
+    /// * `transition_epochs` - The warmup and cooldown durations
+    ///
+    /// # Returns
+    /// * `bool` - Whether the feature was successfully deactivated
+    pub fn deactivate_with_transition_epochs(
+        &mut self,
+        slot: u64,
+        epoch_length: u64,
+        transition_epochs: TransitionEpochs,
+    ) -> Result<bool, SlotToggleError> {
+        match self.state(slot, epoch_length)? {
+            SlotToggleState::Active => {
+                self.slot_removed = PodU64::from(slot);
+                self.transition_epochs = transition_epochs;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Check if the feature is active or in cooldown state at the given slot.
+    pub fn is_active_or_cooldown(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<bool, SlotToggleError> {
+        Ok(matches!(
+            self.state(slot, epoch_length)?,
+            SlotToggleState::Active | SlotToggleState::Cooldown
+        ))
+    }
+
+    /// Check if the feature is active at the given slot.
+    pub fn is_active(&self, slot: u64, epoch_length: u64) -> Result<bool, SlotToggleError> {
+        Ok(matches!(
+            self.state(slot, epoch_length)?,
+            SlotToggleState::Active
+        ))
+    }
+
+    /// Get the state of the feature at the given slot.
+    /// The state is determined based on the slot time, the epoch length and the transition
+    /// durations of the last activation or deactivation.
+    ///
+    /// # Arguments
+    /// * `slot` - The slot at which the state is being queried
+    /// * `epoch_length` - The length of an epoch in slots
+    ///
+    /// # Returns
+    /// * `SlotToggleState` - The state of the feature at the given slot
+    pub fn state(&self, slot: u64, epoch_length: u64) -> Result<SlotToggleState, SlotToggleError> {
+        let current_epoch = get_epoch(slot, epoch_length)?;
+
+        let slot_added: u64 = self.slot_added.into();
+        let slot_removed: u64 = self.slot_removed.into();
+
+        match slot_added.cmp(&slot_removed) {
+            Ordering::Equal => Ok(SlotToggleState::Inactive),
+            Ordering::Less => {
+                let slot_removed_epoch = get_epoch(slot_removed, epoch_length)?;
+                if current_epoch
+                    > slot_removed_epoch
+                        .checked_add(self.transition_epochs.cooldown_epochs() as u64)
+                        .ok_or(SlotToggleError::ArithmeticOverflow)?
+                {
+                    Ok(SlotToggleState::Inactive)
+                } else {
+                    Ok(SlotToggleState::Cooldown)
+                }
+            }
+            Ordering::Greater => {
+                let slot_added_epoch = get_epoch(slot_added, epoch_length)?;
+                if current_epoch
+                    > slot_added_epoch
+                        .checked_add(self.transition_epochs.warmup_epochs() as u64)
+                        .ok_or(SlotToggleError::ArithmeticOverflow)?
+                {
+                    Ok(SlotToggleState::Active)
+                } else {
+                    Ok(SlotToggleState::WarmUp)
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        slot_toggle::{SlotToggle, SlotToggleState, TransitionEpochs},
+        types::{PodBool, PodU16, PodU64},
+    };
+
+    #[test]
+    fn test_slot_toggle_no_padding() {
+        let slot_toggle_size = std::mem::size_of::<SlotToggle>();
+        let sum_of_fields = size_of::<PodU64>() + // slot_added
+            size_of::<PodU64>() + // slot_removed
+            size_of::<TransitionEpochs>() + // transition_epochs
+            27; // reserved
+        assert_eq!(slot_toggle_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_transition_epochs_no_padding() {
+        let transition_epochs_size = std::mem::size_of::<TransitionEpochs>();
+        let sum_of_fields = size_of::<PodBool>() + // is_set
+            size_of::<PodU16>() + // warmup_epochs
+            size_of::<PodU16>(); // cooldown_epochs
+        assert_eq!(transition_epochs_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_transition_epochs_default() {
+        let transition_epochs = TransitionEpochs::default();
+        assert!(!transition_epochs.is_set());
+        assert_eq!(transition_epochs.warmup_epochs(), 1);
+        assert_eq!(transition_epochs.cooldown_epochs(), 1);
+
+        let transition_epochs = TransitionEpochs::new(0, 3);
+        assert!(transition_epochs.is_set());
+        assert_eq!(transition_epochs.warmup_epochs(), 0);
+        assert_eq!(transition_epochs.cooldown_epochs(), 3);
+    }
+
+    #[test]
+    fn test_slot_zero() {
+        let epoch_length = 150;
+        let toggle = SlotToggle::new(0);
+        assert_eq!(
+            toggle.state(0, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+        assert_eq!(
+            toggle.state(10, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+        assert_eq!(
+            toggle.state(epoch_length + 1, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+
+        let toggle = SlotToggle::new(creation_slot);
+        assert_eq!(toggle.slot_added(), creation_slot);
+        assert_eq!(toggle.slot_removed(), creation_slot);
+        assert!(toggle.state(creation_slot, epoch_length).unwrap() == SlotToggleState::Inactive);
+    }
+
+    #[test]
+    fn test_cant_transition_same_slot_created() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        // can't transition to activate the same slot it was created at
+        assert!(!toggle.activate(creation_slot, epoch_length).unwrap());
+        assert!(!toggle.deactivate(creation_slot, epoch_length).unwrap());
+    }
+
+    #[test]
+    fn test_activate_deactivate_cycle() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+
+        let mut current_slot = creation_slot;
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        // Assert inactive
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+
+        // Transition to warming up
+        current_slot += 1;
+        assert!(toggle.activate(current_slot, epoch_length).unwrap());
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+
+        // Assert warming up
+        current_slot += epoch_length;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+
+        // Assert active
+        current_slot += epoch_length;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Active
+        );
+
+        // Assert Deactivate
+        assert!(toggle.deactivate(current_slot, epoch_length).unwrap());
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Cooldown
+        );
+
+        current_slot += epoch_length;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Cooldown
+        );
+
+        current_slot += epoch_length;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+
+    #[test]
+    fn test_is_active_or_cooldown() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        // Initially inactive
+        assert!(!toggle
+            .is_active_or_cooldown(creation_slot, epoch_length)
+            .unwrap());
+
+        // Activate and check during warm-up
+        let activation_slot = creation_slot + 1;
+        assert!(toggle.activate(activation_slot, epoch_length).unwrap());
+        assert!(!toggle
+            .is_active_or_cooldown(activation_slot, epoch_length)
+            .unwrap());
+
+        // Check during active state
+        let active_slot = activation_slot + (epoch_length * 2);
+        assert!(toggle
+            .is_active_or_cooldown(active_slot, epoch_length)
+            .unwrap());
+
+        // Deactivate and check during cooldown
+        assert!(toggle.deactivate(active_slot, epoch_length).unwrap());
+        assert!(toggle
+            .is_active_or_cooldown(active_slot, epoch_length)
+            .unwrap());
+
+        // Check after cooldown period
+        let inactive_slot = active_slot + (epoch_length * 2);
+        assert!(!toggle
+            .is_active_or_cooldown(inactive_slot, epoch_length)
+            .unwrap());
+    }
+
+    #[test]
+    fn test_is_active() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        // Initially inactive
+        assert!(!toggle.is_active(creation_slot, epoch_length).unwrap());
+
+        // Activate and check during warm-up
+        let activation_slot = creation_slot + 1;
+        assert!(toggle.activate(activation_slot, epoch_length).unwrap());
+        assert!(!toggle.is_active(activation_slot, epoch_length).unwrap());
+
+        // Check during warm-up period
+        let warmup_slot = activation_slot + epoch_length;
+        assert!(!toggle.is_active(warmup_slot, epoch_length).unwrap());
+
+        // Check during active state
+        let active_slot = activation_slot + (epoch_length * 2);
+        assert!(toggle.is_active(active_slot, epoch_length).unwrap());
+
+        // Deactivate and check during cooldown
+        assert!(toggle.deactivate(active_slot, epoch_length).unwrap());
+        assert!(!toggle.is_active(active_slot, epoch_length).unwrap());
+
+        // Check after cooldown period
+        let inactive_slot = active_slot + (epoch_length * 2);
+        assert!(!toggle.is_active(inactive_slot, epoch_length).unwrap());
+    }
+
+    #[test]
+    fn test_custom_transition_epochs_cycle() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let transition_epochs = TransitionEpochs::new(3, 0);
+
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        // Activated in epoch 0, warms up for 3 full epochs and is active in epoch 4
+        let mut current_slot = creation_slot + 1;
+        assert!(toggle
+            .activate_with_transition_epochs(current_slot, epoch_length, transition_epochs)
+            .unwrap());
+        assert_eq!(toggle.transition_epochs(), transition_epochs);
+        current_slot = 4 * epoch_length - 1;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::WarmUp
+        );
+        current_slot += 1;
+        assert_eq!(
+            toggle.state(current_slot, epoch_length).unwrap(),
+            SlotToggleState::Active
+        );
+
+        // Without full cooldown epochs, it is inactive from the next epoch
+        assert!(toggle
+            .deactivate_with_transition_epochs(current_slot, epoch_length, transition_epochs)
+            .unwrap());
+        assert_eq!(
+            toggle.state(5 * epoch_length - 1, epoch_length).unwrap(),
+            SlotToggleState::Cooldown
+        );
+        assert_eq!(
+            toggle.state(5 * epoch_length, epoch_length).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+
+    #[test]
+    fn test_failed_transition_keeps_transition_epochs() {
+        let creation_slot = 100;
+        let epoch_length = 150;
+        let mut toggle = SlotToggle::new(creation_slot);
+
+        assert!(toggle
+            .activate_with_transition_epochs(
+                creation_slot + 1,
+                epoch_length,
+                TransitionEpochs::new(2, 2)
+            )
+            .unwrap());
+
+        // Can't activate while warming up, so the warmup duration can't be changed
+        assert!(!toggle
+            .activate_with_transition_epochs(
+                creation_slot + 2,
+                epoch_length,
+                TransitionEpochs::new(0, 0)
+            )
+            .unwrap());
+        assert_eq!(toggle.transition_epochs(), TransitionEpochs::new(2, 2));
+    }
+}
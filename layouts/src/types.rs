@@ -0,0 +1,509 @@
+use core::fmt::{Debug, Formatter};
+
+// https://github.com/solana-labs/solana-program-library/tree/master/libraries/pod
+use bytemuck::{Pod, Zeroable};
+
+/// Simple macro for implementing conversion functions between Pod* ints and
+/// standard ints.
+///
+/// The standard int types can cause alignment issues when placed in a `Pod`,
+/// so these replacements are usable in all `Pod`s.
+#[macro_export]
+macro_rules! impl_int_conversion {
+    ($P:ty, $I:ty) => {
+        impl From<$I> for $P {
+            fn from(n: $I) -> Self {
+                Self(n.to_le_bytes())
+            }
+        }
+        impl From<&$I> for $P {
+            fn from(n: &$I) -> Self {
+                Self(n.to_le_bytes())
+            }
+        }
+        impl From<$P> for $I {
+            fn from(pod: $P) -> Self {
+                Self::from_le_bytes(pod.0)
+            }
+        }
+        impl From<&$P> for $I {
+            fn from(pod: &$P) -> Self {
+                Self::from_le_bytes(pod.0)
+            }
+        }
+    };
+}
+
+/// Implements checked and saturating arithmetic and ordering for Pod* ints.
+///
+/// The operations convert to the standard int, apply the operation and convert
+/// back, so `PodU64` can be used in accounting code without round-tripping
+/// through `u64` by hand. The right-hand side accepts either a Pod* int or a
+/// standard int.
+#[macro_export]
+macro_rules! impl_int_arithmetic {
+    ($P:ty, $I:ty) => {
+        impl $P {
+            /// Checked addition. Returns `None` on overflow.
+            pub fn checked_add(self, rhs: impl Into<$I>) -> Option<Self> {
+                <$I>::from(self).checked_add(rhs.into()).map(Self::from)
+            }
+
+            /// Checked subtraction. Returns `None` on underflow.
+            pub fn checked_sub(self, rhs: impl Into<$I>) -> Option<Self> {
+                <$I>::from(self).checked_sub(rhs.into()).map(Self::from)
+            }
+
+            /// Checked multiplication. Returns `None` on overflow.
+            pub fn checked_mul(self, rhs: impl Into<$I>) -> Option<Self> {
+                <$I>::from(self).checked_mul(rhs.into()).map(Self::from)
+            }
+
+            /// Saturating addition. Clamps at the max value on overflow.
+            pub fn saturating_add(self, rhs: impl Into<$I>) -> Self {
+                Self::from(<$I>::from(self).saturating_add(rhs.into()))
+            }
+
+            /// Saturating subtraction. Clamps at zero on underflow.
+            pub fn saturating_sub(self, rhs: impl Into<$I>) -> Self {
+                Self::from(<$I>::from(self).saturating_sub(rhs.into()))
+            }
+
+            /// Saturating multiplication. Clamps at the max value on overflow.
+            pub fn saturating_mul(self, rhs: impl Into<$I>) -> Self {
+                Self::from(<$I>::from(self).saturating_mul(rhs.into()))
+            }
+        }
+
+        impl PartialOrd for $P {
+            fn partial_cmp(&self, other: &Self) -> Option<::core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        // Compare by value, the little-endian bytes don't sort numerically
+        impl Ord for $P {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                <$I>::from(self).cmp(&<$I>::from(other))
+            }
+        }
+    };
+}
+
+/// Serializes Pod* ints as their standard int so they read naturally in JSON.
+#[cfg(feature = "serde")]
+macro_rules! impl_int_serde {
+    ($P:ty, $I:ty) => {
+        impl serde::Serialize for $P {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serde::Serialize::serialize(&<$I>::from(self), serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $P {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                <$I as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+            }
+        }
+    };
+}
+
+/// Borsh-encodes Pod* ints as their standard int, which is byte-for-byte the
+/// same as the Pod layout.
+#[cfg(feature = "borsh")]
+macro_rules! impl_int_borsh {
+    ($P:ty, $I:ty) => {
+        impl borsh::BorshSerialize for $P {
+            fn serialize<W: borsh::maybestd::io::Write>(
+                &self,
+                writer: &mut W,
+            ) -> borsh::maybestd::io::Result<()> {
+                borsh::BorshSerialize::serialize(&<$I>::from(self), writer)
+            }
+        }
+
+        impl borsh::BorshDeserialize for $P {
+            fn deserialize_reader<R: borsh::maybestd::io::Read>(
+                reader: &mut R,
+            ) -> borsh::maybestd::io::Result<Self> {
+                <$I as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self::from)
+            }
+        }
+    };
+}
+
+/// Implements the conversion, arithmetic and optional encoding traits for a Pod* int.
+macro_rules! impl_pod_int {
+    ($P:ty, $I:ty) => {
+        impl_int_conversion!($P, $I);
+        impl_int_arithmetic!($P, $I);
+        #[cfg(feature = "serde")]
+        impl_int_serde!($P, $I);
+        #[cfg(feature = "borsh")]
+        impl_int_borsh!($P, $I);
+    };
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Pod, Zeroable, Eq)]
+#[repr(transparent)]
+pub struct PodU16([u8; 2]);
+impl_pod_int!(PodU16, u16);
+
+impl Debug for PodU16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let v: u16 = u16::from(self);
+        f.debug_tuple("PodU16").field(&v).finish()
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Pod, Zeroable, Eq)]
+#[repr(transparent)]
+pub struct PodU32([u8; 4]);
+impl_pod_int!(PodU32, u32);
+
+impl Debug for PodU32 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let v: u32 = u32::from(self);
+        f.debug_tuple("PodU32").field(&v).finish()
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Pod, Zeroable, Eq)]
+#[repr(transparent)]
+pub struct PodU64([u8; 8]);
+impl_pod_int!(PodU64, u64);
+
+impl Debug for PodU64 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let v: u64 = u64::from(self);
+        f.debug_tuple("PodU64").field(&v).finish()
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Pod, Zeroable, Eq)]
+#[repr(transparent)]
+pub struct PodU128([u8; 16]);
+impl_pod_int!(PodU128, u128);
+
+impl Debug for PodU128 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let v: u128 = u128::from(self);
+        f.debug_tuple("PodU128").field(&v).finish()
+    }
+}
+
+/// The standard `bool` is not a `Pod`, define a replacement that is
+#[derive(Clone, Copy, Default, PartialEq, Eq, Pod, Zeroable)]
+#[repr(transparent)]
+pub struct PodBool(pub u8);
+impl PodBool {
+    pub const fn from_bool(b: bool) -> Self {
+        Self(if b { 1 } else { 0 })
+    }
+}
+
+impl From<bool> for PodBool {
+    fn from(b: bool) -> Self {
+        Self::from_bool(b)
+    }
+}
+
+impl From<&bool> for PodBool {
+    fn from(b: &bool) -> Self {
+        Self(if *b { 1 } else { 0 })
+    }
+}
+
+impl From<&PodBool> for bool {
+    fn from(b: &PodBool) -> Self {
+        b.0 != 0
+    }
+}
+
+impl From<PodBool> for bool {
+    fn from(b: PodBool) -> Self {
+        b.0 != 0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PodBool {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde::Serialize::serialize(&bool::from(self), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PodBool {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <bool as serde::Deserialize>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for PodBool {
+    fn serialize<W: borsh::maybestd::io::Write>(
+        &self,
+        writer: &mut W,
+    ) -> borsh::maybestd::io::Result<()> {
+        borsh::BorshSerialize::serialize(&self.0, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for PodBool {
+    fn deserialize_reader<R: borsh::maybestd::io::Read>(
+        reader: &mut R,
+    ) -> borsh::maybestd::io::Result<Self> {
+        <u8 as borsh::BorshDeserialize>::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl Debug for PodBool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let v: bool = bool::from(self);
+        f.debug_tuple("PodBool").field(&v).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pod_u16() {
+        // Test zero
+        let zero = PodU16::default();
+        assert_eq!(u16::from(zero), 0);
+
+        // Test conversion from u16
+        let n: u16 = 12345;
+        let pod = PodU16::from(n);
+        assert_eq!(u16::from(pod), n);
+
+        // Test reference conversion
+        let pod_from_ref = PodU16::from(&n);
+        assert_eq!(u16::from(pod_from_ref), n);
+
+        // Test max value
+        let max = PodU16::from(u16::MAX);
+        assert_eq!(u16::from(max), u16::MAX);
+    }
+
+    #[test]
+    fn test_pod_u32() {
+        // Test zero
+        let zero = PodU32::default();
+        assert_eq!(u32::from(zero), 0);
+
+        // Test conversion from u32
+        let n: u32 = 305_419_896;
+        let pod = PodU32::from(n);
+        assert_eq!(u32::from(pod), n);
+
+        // Test reference conversion
+        let pod_from_ref = PodU32::from(&n);
+        assert_eq!(u32::from(pod_from_ref), n);
+
+        // Test max value
+        let max = PodU32::from(u32::MAX);
+        assert_eq!(u32::from(max), u32::MAX);
+    }
+
+    #[test]
+    fn test_pod_u64() {
+        // Test zero
+        let zero = PodU64::default();
+        assert_eq!(u64::from(zero), 0);
+
+        // Test conversion from u64
+        let n: u64 = 1_311_768_467_294_899_695;
+        let pod = PodU64::from(n);
+        assert_eq!(u64::from(pod), n);
+
+        // Test reference conversion
+        let pod_from_ref = PodU64::from(&n);
+        assert_eq!(u64::from(pod_from_ref), n);
+
+        // Test max value
+        let max = PodU64::from(u64::MAX);
+        assert_eq!(u64::from(max), u64::MAX);
+    }
+
+    #[test]
+    fn test_pod_u128() {
+        // Test zero
+        let zero = PodU128::default();
+        assert_eq!(u128::from(zero), 0);
+
+        // Test conversion from u128
+        let n: u128 = 170_141_183_460_469_231_731_687_303_715_884_105_727;
+        let pod = PodU128::from(n);
+        assert_eq!(u128::from(pod), n);
+
+        // Test reference conversion
+        let pod_from_ref = PodU128::from(&n);
+        assert_eq!(u128::from(pod_from_ref), n);
+
+        // Test max value
+        let max = PodU128::from(u128::MAX);
+        assert_eq!(u128::from(max), u128::MAX);
+    }
+
+    #[test]
+    fn test_pod_bool() {
+        // Test default is false
+        let default_bool = PodBool::default();
+        assert!(!bool::from(default_bool));
+
+        // Test true conversion
+        let true_bool = PodBool::from(true);
+        assert!(bool::from(true_bool));
+
+        // Test false conversion
+        let false_bool = PodBool::from(false);
+        assert!(!bool::from(false_bool));
+
+        // Test reference conversion
+        let true_ref = true;
+        let pod_from_ref = PodBool::from(&true_ref);
+        assert!(bool::from(pod_from_ref));
+
+        // Test non-zero values are true
+        let non_zero = PodBool(2);
+        assert!(bool::from(non_zero));
+    }
+
+    #[test]
+    fn test_debug_formatting() {
+        // Test debug formatting for all types
+        assert_eq!(format!("{:?}", PodU16::from(12345)), "PodU16(12345)");
+        assert_eq!(
+            format!("{:?}", PodU32::from(305419896)),
+            "PodU32(305419896)"
+        );
+        assert_eq!(
+            format!("{:?}", PodU64::from(1311768467294899695)),
+            "PodU64(1311768467294899695)"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                PodU128::from(170141183460469231731687303715884105727)
+            ),
+            "PodU128(170141183460469231731687303715884105727)"
+        );
+        assert_eq!(format!("{:?}", PodBool::from(true)), "PodBool(true)");
+        assert_eq!(format!("{:?}", PodBool::from(false)), "PodBool(false)");
+    }
+
+    #[test]
+    fn test_byte_representation() {
+        // Test that the byte representation is correct (little-endian)
+        let n: u16 = 0x1234;
+        let pod = PodU16::from(n);
+        assert_eq!(pod.0, [0x34, 0x12]);
+
+        let n: u32 = 0x12345678;
+        let pod = PodU32::from(n);
+        assert_eq!(pod.0, [0x78, 0x56, 0x34, 0x12]);
+
+        let n: u64 = 0x1234567890ABCDEF;
+        let pod = PodU64::from(n);
+        assert_eq!(pod.0, [0xEF, 0xCD, 0xAB, 0x90, 0x78, 0x56, 0x34, 0x12]);
+    }
+
+    #[test]
+    fn test_pod_properties() {
+        // Test that Pod types implement expected traits
+        fn assert_pod<T: Pod + Default + Copy + Clone + PartialEq + Eq>() {}
+
+        assert_pod::<PodU16>();
+        assert_pod::<PodU32>();
+        assert_pod::<PodU64>();
+        assert_pod::<PodU128>();
+        assert_pod::<PodBool>();
+    }
+
+    #[test]
+    fn test_pod_checked_arithmetic() {
+        let pod = PodU64::from(10u64);
+        assert_eq!(pod.checked_add(5u64), Some(PodU64::from(15u64)));
+        assert_eq!(
+            pod.checked_add(PodU64::from(5u64)),
+            Some(PodU64::from(15u64))
+        );
+        assert_eq!(pod.checked_sub(10u64), Some(PodU64::default()));
+        assert_eq!(pod.checked_sub(11u64), None);
+        assert_eq!(pod.checked_mul(3u64), Some(PodU64::from(30u64)));
+        assert_eq!(PodU64::from(u64::MAX).checked_add(1u64), None);
+        assert_eq!(PodU16::from(u16::MAX).checked_mul(2u16), None);
+    }
+
+    #[test]
+    fn test_pod_saturating_arithmetic() {
+        assert_eq!(
+            PodU32::from(u32::MAX).saturating_add(1u32),
+            PodU32::from(u32::MAX)
+        );
+        assert_eq!(PodU32::from(1u32).saturating_sub(2u32), PodU32::default());
+        assert_eq!(
+            PodU128::from(u128::MAX).saturating_mul(2u128),
+            PodU128::from(u128::MAX)
+        );
+        assert_eq!(PodU16::from(2u16).saturating_mul(3u16), PodU16::from(6u16));
+    }
+
+    #[test]
+    fn test_pod_ordering_is_numeric() {
+        // 256 is [0x00, 0x01] and 1 is [0x01, 0x00], so a bytewise comparison would be wrong
+        assert!(PodU16::from(256u16) > PodU16::from(1u16));
+        assert!(PodU64::from(1u64 << 32) > PodU64::from(u32::MAX as u64));
+        assert_eq!(
+            PodU32::from(7u32).cmp(&PodU32::from(7u32)),
+            std::cmp::Ordering::Equal
+        );
+        assert_eq!(
+            PodU64::from(3u64).max(PodU64::from(300u64)),
+            PodU64::from(300u64)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_pod_serde() {
+        assert_eq!(
+            serde_json::to_string(&PodU64::from(1_000_000u64)).unwrap(),
+            "1000000"
+        );
+        assert_eq!(
+            serde_json::from_str::<PodU16>("513").unwrap(),
+            PodU16::from(513u16)
+        );
+        assert_eq!(
+            serde_json::to_string(&PodU128::from(u128::MAX)).unwrap(),
+            u128::MAX.to_string()
+        );
+        assert_eq!(serde_json::to_string(&PodBool::from(true)).unwrap(), "true");
+        assert_eq!(
+            serde_json::from_str::<PodBool>("false").unwrap(),
+            PodBool::from(false)
+        );
+    }
+
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn test_pod_borsh_matches_pod_layout() {
+        use borsh::{BorshDeserialize, BorshSerialize};
+
+        let pod = PodU64::from(0x1234567890ABCDEFu64);
+        let bytes = pod.try_to_vec().unwrap();
+        assert_eq!(bytes, bytemuck::bytes_of(&pod));
+        assert_eq!(PodU64::try_from_slice(&bytes).unwrap(), pod);
+
+        let pod_bool = PodBool::from(true);
+        let bytes = pod_bool.try_to_vec().unwrap();
+        assert_eq!(bytes, bytemuck::bytes_of(&pod_bool));
+        assert_eq!(PodBool::try_from_slice(&bytes).unwrap(), pod_bool);
+    }
+}
@@ -0,0 +1,180 @@
+//! The vault configuration account
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_clock::DEFAULT_SLOTS_PER_EPOCH;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    types::{PodU16, PodU64},
+    vault::discriminators::VaultDiscriminator,
+};
+
+const RESERVED_SPACE_LEN: usize = 229;
+
+/// The vault configuration account for the vault program.
+/// Manages program-wide settings and state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(seeds = [b"config"], bump = bump)
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct Config {
+    /// The configuration admin
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+
+    /// The approved restaking program for this vault
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub restaking_program: Pubkey,
+
+    /// The length of an epoch in slots
+    epoch_length: PodU64,
+
+    /// The number of vaults managed by the program
+    pub num_vaults: PodU64,
+
+    /// The fee cap in basis points ( withdraw and deposit )
+    pub deposit_withdrawal_fee_cap_bps: PodU16,
+
+    /// The maximum amount a fee can increase per epoch in basis points
+    pub fee_rate_of_change_bps: PodU16,
+
+    /// The amount a fee can increase above the rate of change in basis points
+    pub fee_bump_bps: PodU16,
+
+    /// The program fee in basis points
+    pub program_fee_bps: PodU16,
+
+    /// The fee wallet
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub program_fee_wallet: Pubkey,
+
+    /// The admin for the fee account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_admin: Pubkey,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 229],
+}
+
+impl Discriminator for Config {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::Config as u8;
+}
+
+impl Config {
+    /// Maximum fee cap in basis points
+    pub const DEFAULT_FEES_CAP_BPS: u16 = 2_000; // 20%
+    /// Maximum rate of change in fee basis pointer per epoch
+    pub const DEFAULT_FEE_RATE_OF_CHANGE_BPS: u16 = 2_500; // 25%
+    /// Maximum bump in fee change above the rate of change
+    pub const DEFAULT_FEE_BUMP_BPS: u16 = 10; // 0.1%
+
+    pub fn new(
+        admin: Pubkey,
+        restaking_program: Pubkey,
+        program_fee_wallet: Pubkey,
+        program_fee_bps: u16,
+        bump: u8,
+    ) -> Self {
+        Self {
+            admin,
+            restaking_program,
+            epoch_length: PodU64::from(DEFAULT_SLOTS_PER_EPOCH),
+            num_vaults: PodU64::from(0),
+            // Cannot be higher than 100%
+            deposit_withdrawal_fee_cap_bps: PodU16::from(Self::DEFAULT_FEES_CAP_BPS),
+            fee_rate_of_change_bps: PodU16::from(Self::DEFAULT_FEE_RATE_OF_CHANGE_BPS),
+            fee_bump_bps: PodU16::from(Self::DEFAULT_FEE_BUMP_BPS),
+            program_fee_bps: PodU16::from(program_fee_bps),
+            program_fee_wallet,
+            fee_admin: admin,
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn epoch_length(&self) -> u64 {
+        self.epoch_length.into()
+    }
+
+    pub fn num_vaults(&self) -> u64 {
+        self.num_vaults.into()
+    }
+
+    pub fn deposit_withdrawal_fee_cap_bps(&self) -> u16 {
+        u16::from(self.deposit_withdrawal_fee_cap_bps)
+    }
+
+    pub fn fee_rate_of_change_bps(&self) -> u16 {
+        u16::from(self.fee_rate_of_change_bps)
+    }
+
+    pub fn program_fee_bps(&self) -> u16 {
+        self.program_fee_bps.into()
+    }
+
+    pub fn fee_bump_bps(&self) -> u16 {
+        u16::from(self.fee_bump_bps)
+    }
+
+    pub fn set_admin(&mut self, new_admin: Pubkey) {
+        self.admin = new_admin;
+        self.fee_admin = new_admin;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vault_math::MAX_BPS;
+
+    #[test]
+    fn test_config_no_padding() {
+        let config_size = std::mem::size_of::<Config>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // admin
+            std::mem::size_of::<Pubkey>() + // restaking_program
+            std::mem::size_of::<PodU64>() + // epoch_length
+            std::mem::size_of::<PodU64>() + // num_vaults
+            std::mem::size_of::<PodU16>() + // deposit_withdrawal_fee_cap_bps
+            std::mem::size_of::<PodU16>() + // fee_rate_of_change_bps
+            std::mem::size_of::<PodU16>() + // fee_bump_bps
+            std::mem::size_of::<PodU16>() + // program_fee_bps
+            std::mem::size_of::<Pubkey>() + // program_fee_wallet
+            std::mem::size_of::<Pubkey>() + // fee_admin
+            std::mem::size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(config_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_bps_are_within_bounds() {
+        assert!(Config::DEFAULT_FEES_CAP_BPS <= MAX_BPS);
+        assert!(Config::DEFAULT_FEE_RATE_OF_CHANGE_BPS <= MAX_BPS);
+        assert!(Config::DEFAULT_FEE_BUMP_BPS <= MAX_BPS);
+    }
+}
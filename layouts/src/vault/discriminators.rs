@@ -0,0 +1,44 @@
+/// Discriminators for vault accounts
+/// Values must not change as they are written on chain to determine the type of account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VaultDiscriminator {
+    Config = 1,
+    Vault = 2,
+    VaultNcnTicket = 3,
+    VaultOperatorDelegation = 4,
+    VaultNcnSlasherTicket = 5,
+    VaultNcnSlasherOperatorTicket = 6,
+    VaultStakerWithdrawalTicket = 7,
+    VaultUpdateStateTracker = 8,
+}
+
+#[cfg(feature = "program")]
+mod migrate_layout {
+    use crate::{
+        account::MigrateLayout,
+        vault::{
+            config::Config, vault::Vault,
+            vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+            vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+            vault_operator_delegation::VaultOperatorDelegation,
+            vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+            vault_update_state_tracker::VaultUpdateStateTracker,
+        },
+    };
+
+    impl MigrateLayout for Config {}
+
+    impl MigrateLayout for Vault {}
+
+    impl MigrateLayout for VaultNcnTicket {}
+
+    impl MigrateLayout for VaultOperatorDelegation {}
+
+    impl MigrateLayout for VaultNcnSlasherTicket {}
+
+    impl MigrateLayout for VaultNcnSlasherOperatorTicket {}
+
+    impl MigrateLayout for VaultStakerWithdrawalTicket {}
+
+    impl MigrateLayout for VaultUpdateStateTracker {}
+}
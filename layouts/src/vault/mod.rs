@@ -0,0 +1,11 @@
+//! The accounts of the vault program
+pub mod config;
+pub mod discriminators;
+#[allow(clippy::module_inception)]
+pub mod vault;
+pub mod vault_ncn_slasher_operator_ticket;
+pub mod vault_ncn_slasher_ticket;
+pub mod vault_ncn_ticket;
+pub mod vault_operator_delegation;
+pub mod vault_staker_withdrawal_ticket;
+pub mod vault_update_state_tracker;
@@ -0,0 +1,492 @@
+//! The vault is responsible for holding tokens and minting VRT tokens.
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "program")]
+use jito_errors::{AccountError, LoaderError};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+#[cfg(feature = "program")]
+use solana_program::account_info::AccountInfo;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator,
+    delegation_state::DelegationState,
+    types::{PodBool, PodU16, PodU64},
+    vault::discriminators::VaultDiscriminator,
+};
+
+pub const RESERVED_SPACE_LEN: usize = 251;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MintSummary {
+    pub vrt_to_depositor: u64,
+    pub vrt_to_fee_wallet: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "program", derive(AccountDeserialize))]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct Vault {
+    /// The base account of the VRT
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base: Pubkey,
+
+    // ------------------------------------------
+    // Token information and accounting
+    // ------------------------------------------
+    /// Mint of the VRT token
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vrt_mint: Pubkey,
+
+    /// Mint of the token that is supported by the VRT
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub supported_mint: Pubkey,
+
+    /// The total number of VRT in circulation
+    pub vrt_supply: PodU64,
+
+    /// The total number of tokens deposited
+    pub tokens_deposited: PodU64,
+
+    /// The maximum deposit capacity allowed in the mint_to instruction.
+    /// The deposited assets in the vault may exceed the deposit_capacity during other operations, such as vault balance updates.
+    pub deposit_capacity: PodU64,
+
+    /// Rolled-up stake state for all operators in the set
+    pub delegation_state: DelegationState,
+
+    /// The amount of additional assets that need unstaking to fulfill VRT withdrawals
+    pub additional_assets_need_unstaking: PodU64,
+
+    /// The amount of VRT tokens in VaultStakerWithdrawalTickets enqueued for cooldown
+    pub vrt_enqueued_for_cooldown_amount: PodU64,
+
+    /// The amount of VRT tokens cooling down
+    pub vrt_cooling_down_amount: PodU64,
+
+    /// The amount of VRT tokens ready to claim
+    pub vrt_ready_to_claim_amount: PodU64,
+
+    // ------------------------------------------
+    // Admins
+    // ------------------------------------------
+    /// Vault admin
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub admin: Pubkey,
+
+    /// The delegation admin responsible for adding and removing delegations from operators.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub delegation_admin: Pubkey,
+
+    /// The operator admin responsible for adding and removing operators.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator_admin: Pubkey,
+
+    /// The node consensus network admin responsible for adding and removing support for NCNs.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn_admin: Pubkey,
+
+    /// The admin responsible for adding and removing slashers.
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slasher_admin: Pubkey,
+
+    /// The admin responsible for setting the capacity
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub capacity_admin: Pubkey,
+
+    /// The admin responsible for setting the fees
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_admin: Pubkey,
+
+    /// The delegate_admin responsible for delegating assets
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub delegate_asset_admin: Pubkey,
+
+    /// Fee wallet account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub fee_wallet: Pubkey,
+
+    /// Optional mint signer
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint_burn_admin: Pubkey,
+
+    /// ( For future use ) Authority to update the vault's metadata
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub metadata_admin: Pubkey,
+
+    // ------------------------------------------
+    // Indexing and counters
+    // These are helpful when one needs to iterate through all the accounts
+    // ------------------------------------------
+    /// The index of the vault in the vault list
+    pub vault_index: PodU64,
+
+    /// Number of VaultNcnTicket accounts tracked by this vault
+    pub ncn_count: PodU64,
+
+    /// Number of VaultOperatorDelegation accounts tracked by this vault
+    pub operator_count: PodU64,
+
+    /// Number of VaultNcnSlasherTicket accounts tracked by this vault
+    pub slasher_count: PodU64,
+
+    /// The slot of the last fee change
+    pub last_fee_change_slot: PodU64,
+
+    /// The slot of the last time the delegations were updated
+    pub last_full_state_update_slot: PodU64,
+
+    /// The deposit fee in basis points
+    pub deposit_fee_bps: PodU16,
+
+    /// The withdrawal fee in basis points
+    pub withdrawal_fee_bps: PodU16,
+
+    /// The next epoch's withdrawal fee in basis points
+    pub next_withdrawal_fee_bps: PodU16,
+
+    /// Fee for each epoch
+    pub reward_fee_bps: PodU16,
+
+    /// (Copied from Config) The program fee in basis points
+    pub program_fee_bps: PodU16,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Whether the vault is paused
+    pub is_paused: PodBool,
+
+    /// last
+    pub last_start_state_update_slot: PodU64,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    pub reserved: [u8; 251],
+}
+
+impl Discriminator for Vault {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::Vault as u8;
+}
+
+impl Vault {
+    pub const MAX_REWARD_DELTA_BPS: u16 = 50; // 0.5%
+    pub const MIN_WITHDRAWAL_SLIPPAGE_BPS: u16 = 50; // 0.5%
+    pub const DEFAULT_INITIALIZATION_TOKEN_AMOUNT: u64 = 10_000;
+
+    pub fn ncn_count(&self) -> u64 {
+        self.ncn_count.into()
+    }
+
+    pub fn last_fee_change_slot(&self) -> u64 {
+        self.last_fee_change_slot.into()
+    }
+
+    pub fn deposit_capacity(&self) -> u64 {
+        self.deposit_capacity.into()
+    }
+
+    pub fn vault_index(&self) -> u64 {
+        self.vault_index.into()
+    }
+
+    pub fn set_last_fee_change_slot(&mut self, slot: u64) {
+        self.last_fee_change_slot = PodU64::from(slot);
+    }
+
+    pub fn last_full_state_update_slot(&self) -> u64 {
+        self.last_full_state_update_slot.into()
+    }
+
+    pub fn last_start_state_update_slot(&self) -> u64 {
+        self.last_start_state_update_slot.into()
+    }
+
+    pub fn vrt_supply(&self) -> u64 {
+        self.vrt_supply.into()
+    }
+
+    pub fn slasher_count(&self) -> u64 {
+        self.slasher_count.into()
+    }
+
+    pub fn tokens_deposited(&self) -> u64 {
+        self.tokens_deposited.into()
+    }
+
+    pub fn vrt_enqueued_for_cooldown_amount(&self) -> u64 {
+        self.vrt_enqueued_for_cooldown_amount.into()
+    }
+
+    pub fn vrt_cooling_down_amount(&self) -> u64 {
+        self.vrt_cooling_down_amount.into()
+    }
+
+    pub fn vrt_ready_to_claim_amount(&self) -> u64 {
+        self.vrt_ready_to_claim_amount.into()
+    }
+
+    pub fn deposit_fee_bps(&self) -> u16 {
+        u16::from(self.deposit_fee_bps)
+    }
+
+    pub fn withdrawal_fee_bps(&self) -> u16 {
+        u16::from(self.withdrawal_fee_bps)
+    }
+
+    pub fn next_withdrawal_fee_bps(&self) -> u16 {
+        u16::from(self.next_withdrawal_fee_bps)
+    }
+
+    pub fn reward_fee_bps(&self) -> u16 {
+        u16::from(self.reward_fee_bps)
+    }
+
+    pub fn program_fee_bps(&self) -> u16 {
+        u16::from(self.program_fee_bps)
+    }
+
+    pub fn operator_count(&self) -> u64 {
+        self.operator_count.into()
+    }
+
+    pub fn set_capacity(&mut self, capacity: u64) {
+        self.deposit_capacity = PodU64::from(capacity);
+    }
+
+    pub fn set_vrt_cooling_down_amount(&mut self, amount: u64) {
+        self.vrt_cooling_down_amount = PodU64::from(amount);
+    }
+
+    pub fn set_last_full_state_update_slot(&mut self, slot: u64) {
+        self.last_full_state_update_slot = PodU64::from(slot);
+    }
+
+    pub fn set_last_start_state_update_slot(&mut self, slot: u64) {
+        self.last_start_state_update_slot = PodU64::from(slot);
+    }
+
+    pub fn set_vrt_enqueued_for_cooldown_amount(&mut self, amount: u64) {
+        self.vrt_enqueued_for_cooldown_amount = PodU64::from(amount);
+    }
+
+    pub fn set_tokens_deposited(&mut self, tokens_deposited: u64) {
+        self.tokens_deposited = PodU64::from(tokens_deposited);
+    }
+
+    pub fn set_vrt_supply(&mut self, vrt_supply: u64) {
+        self.vrt_supply = PodU64::from(vrt_supply);
+    }
+
+    pub fn additional_assets_need_unstaking(&self) -> u64 {
+        self.additional_assets_need_unstaking.into()
+    }
+
+    pub fn set_additional_assets_need_unstaking(&mut self, additional_assets_need_unstaking: u64) {
+        self.additional_assets_need_unstaking = PodU64::from(additional_assets_need_unstaking);
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.is_paused.into()
+    }
+
+    pub fn set_is_paused(&mut self, is_paused: bool) {
+        self.is_paused = PodBool::from_bool(is_paused);
+    }
+
+    // Only to be used in initialize_vault
+}
+
+#[cfg(feature = "program")]
+impl Vault {
+    /// Returns the seeds for the PDA
+    ///
+    /// # Returns
+    /// * `Vec<Vec<u8>>` - containing the seed vectors
+    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
+        vec![b"vault".as_ref().to_vec(), base.to_bytes().to_vec()]
+    }
+
+    /// Returns the seeds for the PDA used for signing
+    pub fn signing_seeds(&self) -> Vec<Vec<u8>> {
+        let mut vault_seeds = Self::seeds(&self.base);
+        vault_seeds.push(vec![self.bump]);
+        vault_seeds
+    }
+
+    /// Find the program address for the Vault
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `base` - The base account used as a PDA seed
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
+    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(base);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`Vault`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `account` - The account to load
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        account: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), AccountError> {
+        if account.owner.ne(program_id) {
+            return Err(AccountError::new("vault", LoaderError::InvalidAccountOwner));
+        }
+        if account.data_is_empty() {
+            return Err(AccountError::new("vault", LoaderError::AccountDataEmpty));
+        }
+        if expect_writable && !account.is_writable {
+            return Err(AccountError::new("vault", LoaderError::AccountNotWritable));
+        }
+        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            return Err(AccountError::new(
+                "vault",
+                LoaderError::InvalidDiscriminator,
+            ));
+        }
+
+        let vault_data = &account.data.borrow();
+        let vault = Self::try_from_slice_unchecked(vault_data)
+            .map_err(|_| AccountError::new("vault", LoaderError::InvalidAccountData))?;
+        let seeds = vault.signing_seeds();
+        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seed_slices, program_id)
+            .map_err(|_| AccountError::new("vault", LoaderError::InvalidAddress))?;
+        if account.key.ne(&expected_pubkey) {
+            return Err(AccountError::new("vault", LoaderError::InvalidAddress));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_no_padding() {
+        let vault_size = std::mem::size_of::<Vault>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // base
+            std::mem::size_of::<Pubkey>() + // vrt_mint
+            std::mem::size_of::<Pubkey>() + // supported_mint
+            std::mem::size_of::<PodU64>() + // vrt_supply
+            std::mem::size_of::<PodU64>() + // tokens_deposited
+            std::mem::size_of::<PodU64>() + // capacity
+            std::mem::size_of::<DelegationState>() + // delegation_state
+            std::mem::size_of::<PodU64>() + // additional_assets_needed_to_unstake
+            std::mem::size_of::<PodU64>() + // vrt_enqueued_for_cooldown_amount
+            std::mem::size_of::<PodU64>() + // vrt_cooling_down_amount
+            std::mem::size_of::<PodU64>() + // vrt_ready_to_claim_amount
+            std::mem::size_of::<Pubkey>() + // admin
+            std::mem::size_of::<Pubkey>() + // delegation_admin
+            std::mem::size_of::<Pubkey>() + // operator_admin
+            std::mem::size_of::<Pubkey>() + // ncn_admin
+            std::mem::size_of::<Pubkey>() + // slasher_admin
+            std::mem::size_of::<Pubkey>() + // capacity_admin
+            std::mem::size_of::<Pubkey>() + // fee_admin
+            std::mem::size_of::<Pubkey>() + // delegate_asset_admin
+            std::mem::size_of::<Pubkey>() + // fee_wallet
+            std::mem::size_of::<Pubkey>() + // mint_burn_admin
+            std::mem::size_of::<Pubkey>() + // metadata_admin
+            std::mem::size_of::<PodU64>() + // vault_index
+            std::mem::size_of::<PodU64>() + // ncn_count
+            std::mem::size_of::<PodU64>() + // operator_count
+            std::mem::size_of::<PodU64>() + // slasher_count
+            std::mem::size_of::<PodU64>() + // last_fee_change_slot
+            std::mem::size_of::<PodU64>() + // last_full_state_update_slot
+            std::mem::size_of::<PodU16>() + // deposit_fee_bps
+            std::mem::size_of::<PodU16>() + // withdrawal_fee_bps
+            std::mem::size_of::<PodU16>() + // next_withdrawal_fee_bps
+            std::mem::size_of::<PodU16>() + // reward_fee_bps
+            std::mem::size_of::<PodU16>() + // program_fee_bps
+            std::mem::size_of::<PodBool>() + // is_paused
+            std::mem::size_of::<PodU64>() + // last_start_state_update_slot
+            1 + // bump
+            RESERVED_SPACE_LEN; // reserved
+
+        assert_eq!(vault_size, sum_of_fields);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vault_serde() {
+        let mut vault = Vault::zeroed();
+        vault.base = Pubkey::new_unique();
+        vault.tokens_deposited = PodU64::from(1_000);
+        vault.is_paused = PodBool::from(true);
+
+        let json = serde_json::to_value(vault).unwrap();
+        assert_eq!(json["base"], vault.base.to_string());
+        assert_eq!(json["tokens_deposited"], 1_000);
+        assert_eq!(json["is_paused"], true);
+        assert_eq!(json["delegation_state"]["staked_amount"], 0);
+        assert_eq!(
+            json["reserved"].as_array().map(Vec::len),
+            Some(RESERVED_SPACE_LEN)
+        );
+        assert_eq!(serde_json::from_value::<Vault>(json).unwrap(), vault);
+    }
+}
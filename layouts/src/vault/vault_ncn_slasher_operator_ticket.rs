@@ -0,0 +1,125 @@
+//! The [`VaultNcnSlasherOperatorTicket`] account tracks the amount an operator has been slashed
+//! by a slasher for a given node consensus network (NCN) and vault for a given epoch.
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{account::Discriminator, types::PodU64, vault::discriminators::VaultDiscriminator};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`VaultNcnSlasherOperatorTicket`] account
+///
+/// - tracks the amount an operator has been slashed by a slasher for a given node consensus network (NCN) and vault for a given epoch.
+/// - helps ensure that the operator is held accountable for their actions and that slashing conditions aren't exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"vault_ncn_slasher_operator", vault, ncn, slasher, operator, ncn_epoch: u64],
+        name = "Vault NCN slasher operator ticket"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultNcnSlasherOperatorTicket {
+    /// The vault slashed
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The node consensus network slashed
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The slasher
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slasher: Pubkey,
+
+    /// The operator
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    /// The epoch
+    epoch: PodU64,
+
+    /// The amount slashed for the given epoch
+    pub slashed: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultNcnSlasherOperatorTicket {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnSlasherOperatorTicket as u8;
+}
+
+impl VaultNcnSlasherOperatorTicket {
+    pub fn new(
+        vault: Pubkey,
+        ncn: Pubkey,
+        slasher: Pubkey,
+        operator: Pubkey,
+        epoch: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            ncn,
+            slasher,
+            operator,
+            epoch: PodU64::from(epoch),
+            slashed: PodU64::from(0),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn slashed(&self) -> u64 {
+        self.slashed.into()
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.epoch.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_ncn_slasher_operator_ticket_no_padding() {
+        let vault_ncn_slasher_operator_ticket_size =
+            std::mem::size_of::<VaultNcnSlasherOperatorTicket>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // slasher
+            size_of::<Pubkey>() + // operator
+            size_of::<PodU64>() + // epoch
+            size_of::<PodU64>() + // slashed
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_ncn_slasher_operator_ticket_size, sum_of_fields);
+    }
+}
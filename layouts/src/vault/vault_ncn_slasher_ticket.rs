@@ -0,0 +1,141 @@
+//! The [`VaultNcnSlasherTicket`] account tracks a vault's support for a node consensus network
+//! slasher. It can be enabled and disabled over time by the vault slasher admin.
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, slot_toggle::SlotToggle, types::PodU64,
+    vault::discriminators::VaultDiscriminator,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`VaultNcnSlasherTicket`] account tracks a vault's support for a node consensus network
+/// slasher. It can be enabled and disabled over time by the vault slasher admin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"vault_slasher_ticket", vault, ncn, slasher],
+        name = "Vault NCN slasher ticket"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultNcnSlasherTicket {
+    /// The vault
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The NCN
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The slasher
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub slasher: Pubkey,
+
+    /// The maximum slashable per epoch per operator
+    max_slashable_per_epoch: PodU64,
+
+    /// The index
+    index: PodU64,
+
+    /// The slot toggle
+    pub state: SlotToggle,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultNcnSlasherTicket {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnSlasherTicket as u8;
+}
+
+impl VaultNcnSlasherTicket {
+    pub fn new(
+        vault: Pubkey,
+        ncn: Pubkey,
+        slasher: Pubkey,
+        max_slashable_per_epoch: u64,
+        index: u64,
+        bump: u8,
+        slot: u64,
+    ) -> Self {
+        Self {
+            vault,
+            ncn,
+            slasher,
+            max_slashable_per_epoch: PodU64::from(max_slashable_per_epoch),
+            index: PodU64::from(index),
+            state: SlotToggle::new(slot),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+
+    pub fn max_slashable_per_epoch(&self) -> u64 {
+        self.max_slashable_per_epoch.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slot_toggle::SlotToggleState;
+
+    #[test]
+    fn test_vault_ncn_slasher_ticket_no_padding() {
+        let vault_ncn_slasher_ticket_size = std::mem::size_of::<VaultNcnSlasherTicket>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // ncn
+            size_of::<Pubkey>() + // slasher
+            size_of::<PodU64>() + // max_slashable_per_epoch
+            size_of::<PodU64>() + // index
+            size_of::<SlotToggle>() + // state
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_ncn_slasher_ticket_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_vault_ncn_slasher_ticket_inactive_on_creation() {
+        let slot = 1;
+        let vault_ncn_slasher_ticket = VaultNcnSlasherTicket::new(
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+            0,
+            slot,
+        );
+        assert_eq!(
+            vault_ncn_slasher_ticket.state.state(slot + 1, 100).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
@@ -0,0 +1,108 @@
+//! The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
+//! enabled and disabled over time by the vault NCN admin.
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, slot_toggle::SlotToggle, types::PodU64,
+    vault::discriminators::VaultDiscriminator,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`VaultNcnTicket`] account tracks a vault supporting a node consensus network. It can be
+/// enabled and disabled over time by the vault NCN admin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"vault_ncn_ticket", vault, ncn],
+        name = "Vault NCN ticket"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultNcnTicket {
+    /// The vault account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The ncn account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub ncn: Pubkey,
+
+    /// The index
+    index: PodU64,
+
+    /// The slot toggle
+    pub state: SlotToggle,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultNcnTicket {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultNcnTicket as u8;
+}
+
+impl VaultNcnTicket {
+    pub fn new(vault: Pubkey, ncn: Pubkey, index: u64, bump: u8, slot: u64) -> Self {
+        Self {
+            vault,
+            ncn,
+            index: PodU64::from(index),
+            state: SlotToggle::new(slot),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::slot_toggle::SlotToggleState;
+
+    #[test]
+    fn test_vault_ncn_ticket_no_padding() {
+        let vault_ncn_ticket_size = std::mem::size_of::<VaultNcnTicket>();
+        let sum_of_fields = std::mem::size_of::<Pubkey>() + // vault
+            std::mem::size_of::<Pubkey>() + // ncn
+            std::mem::size_of::<PodU64>() + // index
+            std::mem::size_of::<SlotToggle>() + // state
+            std::mem::size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_ncn_ticket_size, sum_of_fields);
+    }
+
+    #[test]
+    fn test_vault_ncn_ticket_inactive_on_creation() {
+        let slot = 1;
+        let vault_ncn_ticket =
+            VaultNcnTicket::new(Pubkey::default(), Pubkey::default(), slot, 0, slot);
+        assert_eq!(
+            vault_ncn_ticket.state.state(slot + 1, 100).unwrap(),
+            SlotToggleState::Inactive
+        );
+    }
+}
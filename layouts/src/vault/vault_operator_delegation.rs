@@ -0,0 +1,104 @@
+//! The [`VaultOperatorDelegation`] account tracks a vault's delegation to an operator
+
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, delegation_state::DelegationState, types::PodU64,
+    vault::discriminators::VaultDiscriminator,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`VaultOperatorDelegation`] account tracks a vault's delegation to an operator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"vault_operator_delegation", vault, operator],
+        name = "Vault operator delegation",
+        bump = bump
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultOperatorDelegation {
+    /// The vault account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The operator account
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub operator: Pubkey,
+
+    pub delegation_state: DelegationState,
+
+    /// The last slot the [`VaultOperatorDelegation::update`] method was updated
+    pub last_update_slot: PodU64,
+
+    /// The index
+    index: PodU64,
+
+    /// The bump seed for the PDA
+    pub bump: u8,
+
+    /// Reserved space
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultOperatorDelegation {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultOperatorDelegation as u8;
+}
+
+impl VaultOperatorDelegation {
+    pub fn new(vault: Pubkey, operator: Pubkey, index: u64, bump: u8, slot: u64) -> Self {
+        Self {
+            vault,
+            operator,
+            last_update_slot: PodU64::from(slot),
+            delegation_state: DelegationState::default(),
+            index: PodU64::from(index),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn last_update_slot(&self) -> u64 {
+        self.last_update_slot.into()
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_operator_delegation_no_padding() {
+        let vault_operator_delegation_size = std::mem::size_of::<VaultOperatorDelegation>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // operator
+            size_of::<DelegationState>() + // delegation_state
+            size_of::<PodU64>() + // last_update_slot
+            size_of::<PodU64>() + // index
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_operator_delegation_size, sum_of_fields);
+    }
+}
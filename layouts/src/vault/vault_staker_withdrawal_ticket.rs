@@ -0,0 +1,209 @@
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "program")]
+use jito_errors::{AccountError, LoaderError};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+#[cfg(feature = "program")]
+use solana_program::{account_info::AccountInfo, msg};
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{account::Discriminator, types::PodU64, vault::discriminators::VaultDiscriminator};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+/// The [`VaultStakerWithdrawalTicket`] account
+///
+/// - is used to represent a pending withdrawal from a vault by a staker.
+/// - for every withdrawal ticket, there's an associated token account owned by the withdrawal ticket with the staker's VRT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "program", derive(AccountDeserialize))]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultStakerWithdrawalTicket {
+    /// The vault being withdrawn from
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The staker withdrawing from the vault
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub staker: Pubkey,
+
+    /// The base account used as a PDA seed
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub base: Pubkey,
+
+    /// The amount of VRT held in the VaultStakerWithdrawalTicket token account at the time of creation.
+    /// This is used to ensure the amount redeemed is the same as the amount allocated.
+    vrt_amount: PodU64,
+
+    /// The slot the withdrawal was enqueued
+    slot_unstaked: PodU64,
+
+    /// The bump seed used to create the PDA
+    pub bump: u8,
+
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultStakerWithdrawalTicket {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultStakerWithdrawalTicket as u8;
+}
+
+impl VaultStakerWithdrawalTicket {
+    pub fn new(
+        vault: Pubkey,
+        staker: Pubkey,
+        base: Pubkey,
+        vrt_amount: u64,
+        slot_unstaked: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            vault,
+            staker,
+            base,
+            vrt_amount: PodU64::from(vrt_amount),
+            slot_unstaked: PodU64::from(slot_unstaked),
+            bump,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn vrt_amount(&self) -> u64 {
+        self.vrt_amount.into()
+    }
+
+    pub fn slot_unstaked(&self) -> u64 {
+        self.slot_unstaked.into()
+    }
+}
+
+#[cfg(feature = "program")]
+impl VaultStakerWithdrawalTicket {
+    /// Returns the seeds for the PDA
+    ///
+    /// # Arguments
+    /// * `vault` - The vault
+    /// * `base` - The base account used as a PDA seed
+    pub fn seeds(vault: &Pubkey, base: &Pubkey) -> Vec<Vec<u8>> {
+        Vec::from_iter([
+            b"vault_staker_withdrawal_ticket".to_vec(),
+            vault.to_bytes().to_vec(),
+            base.to_bytes().to_vec(),
+        ])
+    }
+
+    /// Returns the seeds for the PDA used for signing
+    ///
+    /// # Returns
+    /// * `Vec<Vec<u8>>` - containing the seed vectors
+    pub fn signing_seeds(&self, vault: &Pubkey) -> Vec<Vec<u8>> {
+        let mut vault_seeds = Self::seeds(vault, &self.base);
+        vault_seeds.push(vec![self.bump]);
+        vault_seeds
+    }
+
+    /// Find the program address for the PDA
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault` - The vault
+    /// * `staker` - The staker
+    /// * `base` - The base account used as a PDA seed
+    ///
+    /// # Returns
+    /// * [`Pubkey`] - The program address
+    /// * `u8` - The bump seed
+    /// * `Vec<Vec<u8>` - The seeds used to generate the PDA
+    pub fn find_program_address(
+        program_id: &Pubkey,
+        vault: &Pubkey,
+        base: &Pubkey,
+    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
+        let seeds = Self::seeds(vault, base);
+        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
+        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
+        (pda, bump, seeds)
+    }
+
+    /// Loads the [`VaultStakerWithdrawalTicket`] account
+    ///
+    /// # Arguments
+    /// * `program_id` - The program ID
+    /// * `vault_staker_withdrawal_ticket` - The [`VaultStakerWithdrawalTicket`] account
+    /// * `expect_writable` - Whether the account should be writable
+    ///
+    /// # Returns
+    /// * `Result<(), ProgramError>` - The result of the operation
+    pub fn load(
+        program_id: &Pubkey,
+        vault_staker_withdrawal_ticket: &AccountInfo,
+        vault: &AccountInfo,
+        expect_writable: bool,
+    ) -> Result<(), AccountError> {
+        const ROLE: &str = "vault_staker_withdrawal_ticket";
+        if vault_staker_withdrawal_ticket.owner.ne(program_id) {
+            return Err(AccountError::new(ROLE, LoaderError::InvalidAccountOwner));
+        }
+        if vault_staker_withdrawal_ticket.data_is_empty() {
+            return Err(AccountError::new(ROLE, LoaderError::AccountDataEmpty));
+        }
+        if expect_writable && !vault_staker_withdrawal_ticket.is_writable {
+            return Err(AccountError::new(ROLE, LoaderError::AccountNotWritable));
+        }
+        if vault_staker_withdrawal_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
+            return Err(AccountError::new(ROLE, LoaderError::InvalidDiscriminator));
+        }
+
+        let vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow();
+        let ticket = Self::try_from_slice_unchecked(&vault_staker_withdrawal_ticket_data)
+            .map_err(|_| AccountError::new(ROLE, LoaderError::InvalidAccountData))?;
+
+        if ticket.vault.ne(vault.key) {
+            msg!("Vault must match the vault in the withdrawal ticket");
+            return Err(AccountError::new(ROLE, LoaderError::InvalidAccountData));
+        }
+
+        let seeds = ticket.signing_seeds(vault.key);
+        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
+        let expected_pubkey = Pubkey::create_program_address(&seed_slices, program_id)
+            .map_err(|_| AccountError::new(ROLE, LoaderError::InvalidAddress))?;
+        if vault_staker_withdrawal_ticket.key.ne(&expected_pubkey) {
+            return Err(AccountError::new(ROLE, LoaderError::InvalidAddress));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_staker_withdrawal_ticket_no_padding() {
+        let vault_staker_withdrawal_ticket_size =
+            std::mem::size_of::<VaultStakerWithdrawalTicket>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<Pubkey>() + // staker
+            size_of::<Pubkey>() + // base
+            size_of::<PodU64>() + // vrt_amount
+            size_of::<PodU64>() + // slot_unstaked
+            size_of::<u8>() + // bump
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_staker_withdrawal_ticket_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,90 @@
+use bytemuck::{Pod, Zeroable};
+#[cfg(feature = "shank")]
+use shank::ShankAccount;
+use solana_pubkey::Pubkey;
+
+#[cfg(feature = "program")]
+use crate::account::AccountDeserialize;
+use crate::{
+    account::Discriminator, delegation_state::DelegationState, types::PodU64,
+    vault::discriminators::VaultDiscriminator,
+};
+
+const RESERVED_SPACE_LEN: usize = 263;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "program",
+    derive(AccountDeserialize),
+    account(
+        seeds = [b"vault_update_state_tracker", vault, ncn_epoch: u64],
+        name = "Vault update state tracker"
+    )
+)]
+#[cfg_attr(feature = "shank", derive(ShankAccount))]
+#[repr(C)]
+pub struct VaultUpdateStateTracker {
+    /// The vault associated with this update ticket
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub vault: Pubkey,
+
+    /// The NCN epoch for which the delegations are to be updated
+    ncn_epoch: PodU64,
+
+    /// The last updated index of the vault
+    pub last_updated_index: PodU64,
+
+    /// The total amount delegated across all the operators in the vault
+    pub delegation_state: DelegationState,
+
+    pub withdrawal_allocation_method: u8,
+
+    #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::Bytes>"))]
+    reserved: [u8; 263],
+}
+
+impl Discriminator for VaultUpdateStateTracker {
+    const DISCRIMINATOR: u8 = VaultDiscriminator::VaultUpdateStateTracker as u8;
+}
+
+impl VaultUpdateStateTracker {
+    pub fn new(vault: Pubkey, ncn_epoch: u64, withdrawal_allocation_method: u8) -> Self {
+        Self {
+            vault,
+            ncn_epoch: PodU64::from(ncn_epoch),
+            last_updated_index: PodU64::from(u64::MAX),
+            delegation_state: DelegationState::default(),
+            withdrawal_allocation_method,
+            reserved: [0; RESERVED_SPACE_LEN],
+        }
+    }
+
+    pub fn ncn_epoch(&self) -> u64 {
+        self.ncn_epoch.into()
+    }
+
+    pub fn last_updated_index(&self) -> u64 {
+        self.last_updated_index.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_update_state_tracker_no_padding() {
+        let vault_update_state_tracker_size = std::mem::size_of::<VaultUpdateStateTracker>();
+        let sum_of_fields = size_of::<Pubkey>() + // vault
+            size_of::<PodU64>() + // ncn_epoch
+            size_of::<PodU64>() + // last_updated_index
+            size_of::<DelegationState>() + // delegation_state
+            size_of::<u8>() + // withdrawal_allocation_method
+            RESERVED_SPACE_LEN; // reserved
+        assert_eq!(vault_update_state_tracker_size, sum_of_fields);
+    }
+}
@@ -0,0 +1,185 @@
+//! Pure VRT minting, burning and fee math used by the vault program.
+
+use crate::error::MathError;
+
+/// The number of basis points in 100%
+pub const MAX_BPS: u16 = 10_000;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct BurnSummary {
+    /// How much of the VRT shall be transferred to the vault fee account
+    pub vault_fee_amount: u64,
+    /// How much of the VRT shall be transferred to the program fee account
+    pub program_fee_amount: u64,
+    /// How much of the staker's VRT shall be burned
+    pub burn_amount: u64,
+    /// How much of the staker's tokens shall be returned
+    pub out_amount: u64,
+}
+
+/// Calculate the amount of VRT tokens to mint based on the amount of tokens deposited in the vault.
+///
+/// If no tokens have been deposited, the amount is equal to the amount passed in.
+/// Otherwise, the amount is calculated as the pro-rata share of the total VRT supply.
+///
+/// # Arguments
+/// * `tokens_deposited` - The amount of supported tokens held by the vault
+/// * `vrt_supply` - The VRT supply
+/// * `amount` - The amount of supported tokens being deposited
+pub fn calculate_vrt_mint_amount(
+    tokens_deposited: u64,
+    vrt_supply: u64,
+    amount: u64,
+) -> Result<u64, MathError> {
+    if tokens_deposited == 0 {
+        return Ok(amount);
+    }
+
+    (amount as u128)
+        .checked_mul(vrt_supply as u128)
+        .and_then(|x| x.checked_div(tokens_deposited as u128))
+        .and_then(|result| result.try_into().ok())
+        .ok_or(MathError::Overflow)
+}
+
+/// Calculate a fee in basis points of the given amount, rounded up
+///
+/// # Arguments
+/// * `fee_bps` - The fee in basis points
+/// * `amount` - The amount the fee is taken from
+pub fn calculate_fee(fee_bps: u16, amount: u64) -> Result<u64, MathError> {
+    (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .map(|x| x.div_ceil(MAX_BPS as u128))
+        .and_then(|x| x.try_into().ok())
+        .ok_or(MathError::Overflow)
+}
+
+/// Calculate the fees, the amount of VRT burned and the amount of supported tokens returned
+/// when burning VRT.
+///
+/// The program fee is prioritized over the vault fee if together they exceed
+/// the amount in, and stakers that are themselves a fee wallet don't pay that fee.
+///
+/// # Arguments
+/// * `tokens_deposited` - The amount of supported tokens held by the vault
+/// * `vrt_supply` - The VRT supply
+/// * `program_fee_bps` - The program withdrawal fee in basis points
+/// * `withdrawal_fee_bps` - The vault withdrawal fee in basis points
+/// * `is_staker_program_fee_wallet` - Whether the staker is the program fee wallet
+/// * `is_staker_vault_fee_wallet` - Whether the staker is the vault fee wallet
+/// * `amount_in` - The amount of VRT being burned
+pub fn calculate_burn_summary(
+    tokens_deposited: u64,
+    vrt_supply: u64,
+    program_fee_bps: u16,
+    withdrawal_fee_bps: u16,
+    is_staker_program_fee_wallet: bool,
+    is_staker_vault_fee_wallet: bool,
+    amount_in: u64,
+) -> Result<BurnSummary, MathError> {
+    let mut program_fee_amount = calculate_fee(program_fee_bps, amount_in)?;
+    let mut vault_fee_amount = calculate_fee(withdrawal_fee_bps, amount_in)?;
+
+    // Prioritize program fee over vault fee if together they exceed the amount in
+    if program_fee_amount
+        .checked_add(vault_fee_amount)
+        .ok_or(MathError::Overflow)?
+        > amount_in
+    {
+        vault_fee_amount = amount_in
+            .checked_sub(program_fee_amount)
+            .ok_or(MathError::Underflow)?;
+    }
+
+    if is_staker_program_fee_wallet {
+        program_fee_amount = 0;
+    }
+
+    if is_staker_vault_fee_wallet {
+        vault_fee_amount = 0;
+    }
+
+    let amount_to_burn = amount_in
+        .checked_sub(program_fee_amount)
+        .and_then(|x| x.checked_sub(vault_fee_amount))
+        .ok_or(MathError::Underflow)?;
+
+    let amount_out = (amount_to_burn as u128)
+        .checked_mul(tokens_deposited as u128)
+        .and_then(|x| x.checked_div(vrt_supply as u128))
+        .and_then(|x| x.try_into().ok())
+        .ok_or(MathError::Overflow)?;
+
+    Ok(BurnSummary {
+        program_fee_amount,
+        vault_fee_amount,
+        burn_amount: amount_to_burn,
+        out_amount: amount_out,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_vrt_mint_amount_empty_vault() {
+        assert_eq!(calculate_vrt_mint_amount(0, 0, 100), Ok(100));
+    }
+
+    #[test]
+    fn test_calculate_vrt_mint_amount_pro_rata() {
+        // 2 VRT per token
+        assert_eq!(calculate_vrt_mint_amount(1_000, 2_000, 50), Ok(100));
+        // Rounds down
+        assert_eq!(calculate_vrt_mint_amount(3, 1, 1), Ok(0));
+    }
+
+    #[test]
+    fn test_calculate_fee_rounds_up() {
+        assert_eq!(calculate_fee(0, 1_000), Ok(0));
+        assert_eq!(calculate_fee(100, 1_000), Ok(10));
+        assert_eq!(calculate_fee(1, 1), Ok(1));
+        assert_eq!(calculate_fee(MAX_BPS, u64::MAX), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_calculate_burn_summary() {
+        let summary = calculate_burn_summary(1_000, 1_000, 10, 100, false, false, 1_000).unwrap();
+        assert_eq!(
+            summary,
+            BurnSummary {
+                program_fee_amount: 1,
+                vault_fee_amount: 10,
+                burn_amount: 989,
+                out_amount: 989,
+            }
+        );
+    }
+
+    #[test]
+    fn test_calculate_burn_summary_fee_wallets_pay_no_fee() {
+        let summary = calculate_burn_summary(1_000, 1_000, 10, 100, true, true, 1_000).unwrap();
+        assert_eq!(summary.program_fee_amount, 0);
+        assert_eq!(summary.vault_fee_amount, 0);
+        assert_eq!(summary.burn_amount, 1_000);
+    }
+
+    #[test]
+    fn test_calculate_burn_summary_program_fee_prioritized() {
+        let summary =
+            calculate_burn_summary(1_000, 1_000, MAX_BPS, MAX_BPS, false, false, 100).unwrap();
+        assert_eq!(summary.program_fee_amount, 100);
+        assert_eq!(summary.vault_fee_amount, 0);
+        assert_eq!(summary.burn_amount, 0);
+    }
+
+    #[test]
+    fn test_calculate_burn_summary_no_supply_fails() {
+        assert_eq!(
+            calculate_burn_summary(1_000, 0, 0, 0, false, false, 100),
+            Err(MathError::Overflow)
+        );
+    }
+}
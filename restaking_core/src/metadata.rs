@@ -0,0 +1,166 @@
+//! Human-readable metadata shared by the [`crate::ncn_metadata::NcnMetadata`] and
+//! [`crate::operator_metadata::OperatorMetadata`] accounts.
+//!
+//! Strings are stored as fixed-size, zero-padded UTF-8 byte arrays so the account stays `Pod`.
+
+use jito_bytemuck::types::PodBool;
+pub use jito_layouts::restaking::metadata::{
+    Metadata, MAX_CONTACT_LEN, MAX_LOGO_URI_LEN, MAX_NAME_LEN, MAX_WEBSITE_LEN,
+};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::msg;
+
+/// The [`Metadata`] methods that return a [`RestakingError`]
+pub trait MetadataExt: Sized {
+    /// Creates a new [`Metadata`], returning an error if any of the fields is too long
+    ///
+    /// # Arguments
+    /// * `name` - The display name
+    /// * `website` - The website URL
+    /// * `logo_uri` - The URI of the logo image
+    /// * `contact` - Contact information
+    /// * `description_hash` - Optional hash of an off-chain description document
+    fn new(
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> Result<Self, RestakingError>;
+
+    /// Checks each string field is UTF-8 followed only by zero padding and the description hash
+    /// flag is 0 or 1, so the metadata reads back as it was written
+    fn check_valid(&self) -> Result<(), RestakingError>;
+}
+
+impl MetadataExt for Metadata {
+    fn new(
+        name: &str,
+        website: &str,
+        logo_uri: &str,
+        contact: &str,
+        description_hash: Option<[u8; 32]>,
+    ) -> Result<Self, RestakingError> {
+        Ok(Self {
+            name: pack::<MAX_NAME_LEN>("name", name)?,
+            website: pack::<MAX_WEBSITE_LEN>("website", website)?,
+            logo_uri: pack::<MAX_LOGO_URI_LEN>("logo_uri", logo_uri)?,
+            contact: pack::<MAX_CONTACT_LEN>("contact", contact)?,
+            description_hash: description_hash.unwrap_or_default(),
+            has_description_hash: PodBool::from(description_hash.is_some()),
+        })
+    }
+
+    fn check_valid(&self) -> Result<(), RestakingError> {
+        check_packed("name", &self.name)?;
+        check_packed("website", &self.website)?;
+        check_packed("logo_uri", &self.logo_uri)?;
+        check_packed("contact", &self.contact)?;
+        if self.has_description_hash.0 > 1 {
+            msg!(
+                "Metadata has_description_hash is {}, expected 0 or 1",
+                self.has_description_hash.0
+            );
+            return Err(RestakingError::MetadataFieldInvalid);
+        }
+        Ok(())
+    }
+}
+
+fn pack<const N: usize>(field: &str, value: &str) -> Result<[u8; N], RestakingError> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        msg!(
+            "Metadata {} is too long, maximum is {} bytes, received {}",
+            field,
+            N,
+            bytes.len()
+        );
+        return Err(RestakingError::MetadataFieldTooLong);
+    }
+    let mut packed = [0; N];
+    packed[..bytes.len()].copy_from_slice(bytes);
+    Ok(packed)
+}
+
+fn check_packed(field: &str, packed: &[u8]) -> Result<(), RestakingError> {
+    let end = packed.iter().position(|b| *b == 0).unwrap_or(packed.len());
+    let (value, padding) = packed.split_at(end);
+    if core::str::from_utf8(value).is_err() {
+        msg!("Metadata {} is not valid UTF-8", field);
+        return Err(RestakingError::MetadataFieldInvalid);
+    }
+    if padding.iter().any(|b| *b != 0) {
+        msg!("Metadata {} has data after its zero padding", field);
+        return Err(RestakingError::MetadataFieldInvalid);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_restaking_sdk::error::RestakingError;
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_round_trip() {
+        let metadata = Metadata::new(
+            "Jito",
+            "https://jito.network",
+            "https://jito.network/logo.png",
+            "team@jito.network",
+            Some([7; 32]),
+        )
+        .unwrap();
+
+        assert_eq!(metadata.name(), "Jito");
+        assert_eq!(metadata.website(), "https://jito.network");
+        assert_eq!(metadata.logo_uri(), "https://jito.network/logo.png");
+        assert_eq!(metadata.contact(), "team@jito.network");
+        assert_eq!(metadata.description_hash(), Some([7; 32]));
+    }
+
+    #[test]
+    fn test_metadata_max_length_ok() {
+        let name = "a".repeat(MAX_NAME_LEN);
+        let metadata = Metadata::new(&name, "", "", "", None).unwrap();
+        assert_eq!(metadata.name(), name);
+        assert_eq!(metadata.description_hash(), None);
+    }
+
+    #[test]
+    fn test_metadata_check_valid() {
+        let metadata = Metadata::new("Jito", "", "", "", Some([7; 32])).unwrap();
+        metadata.check_valid().unwrap();
+
+        let mut invalid_utf8 = metadata;
+        invalid_utf8.website[0] = 0xff;
+        assert_eq!(
+            invalid_utf8.check_valid(),
+            Err(RestakingError::MetadataFieldInvalid)
+        );
+
+        let embedded_zero = Metadata::new("Jito\0Labs", "", "", "", None).unwrap();
+        assert_eq!(
+            embedded_zero.check_valid(),
+            Err(RestakingError::MetadataFieldInvalid)
+        );
+
+        let mut invalid_flag = metadata;
+        invalid_flag.has_description_hash = PodBool(2);
+        assert_eq!(
+            invalid_flag.check_valid(),
+            Err(RestakingError::MetadataFieldInvalid)
+        );
+    }
+
+    #[test]
+    fn test_metadata_too_long_fails() {
+        let name = "a".repeat(MAX_NAME_LEN + 1);
+        assert_eq!(
+            Metadata::new(&name, "", "", "", None),
+            Err(RestakingError::MetadataFieldTooLong)
+        );
+    }
+}
@@ -3,144 +3,177 @@
 //! - is a program-owned account that represents a network of nodes that participate in consensus.
 //! - is used to manage the operators, vaults, and slashers that are associated with the network.
 
-use std::fmt::Debug;
-
-use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_jsm_core::slot_toggle::TransitionEpochs;
+pub use jito_layouts::restaking::ncn::{Ncn, MAX_TRANSITION_EPOCHS};
 use jito_restaking_sdk::error::RestakingError;
-use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
-
-const RESERVED_SPACE_LEN: usize = 263;
-
-/// The NCN manages the operators, vaults, and slashers associated with a network
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
-#[repr(C)]
-pub struct Ncn {
-    /// The base account used as a PDA seed
-    pub base: Pubkey,
-
-    /// The admin of the NCN
-    pub admin: Pubkey,
-
-    /// The operator admin of the NCN
-    pub operator_admin: Pubkey,
+use solana_program::{msg, pubkey::Pubkey};
 
-    /// The vault admin of the NCN
-    pub vault_admin: Pubkey,
+use crate::operator_bond::OperatorBond;
 
-    /// The slasher admin of the NCN
-    pub slasher_admin: Pubkey,
-
-    /// The delegate admin of the NCN
-    pub delegate_admin: Pubkey,
-
-    /// ( For future use ) Authority to update the ncn's metadata
-    pub metadata_admin: Pubkey,
+/// The [`Ncn`] methods that return a [`RestakingError`]
+pub trait NcnExt {
+    /// Checks the operator self-bond against the NCN requirement
+    ///
+    /// # Arguments
+    /// * `operator_bond` - The operator's bond in the NCN's bond mint, if any
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if no bond is required or the bond is
+    ///   at least the minimum
+    fn check_operator_bond(
+        &self,
+        operator_bond: Option<&OperatorBond>,
+    ) -> Result<(), RestakingError>;
+
+    /// Sets the warmup and cooldown durations used when the NCN's relationships transition
+    ///
+    /// # Arguments
+    /// * `warmup_epochs` - The number of full epochs spent warming up
+    /// * `cooldown_epochs` - The number of full epochs spent cooling down
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if both durations are at most
+    ///   [`MAX_TRANSITION_EPOCHS`]
+    fn set_transition_epochs(
+        &mut self,
+        warmup_epochs: u16,
+        cooldown_epochs: u16,
+    ) -> Result<(), RestakingError>;
 
-    /// The weight table admin of the NCN
-    pub weight_table_admin: Pubkey,
+    fn increment_operator_count(&mut self) -> Result<(), RestakingError>;
 
-    /// Admin in charge of of any on-chain programs related to the NCN
-    pub ncn_program_admin: Pubkey,
+    fn increment_vault_count(&mut self) -> Result<(), RestakingError>;
 
-    /// The index of the NCN
-    index: PodU64,
+    fn increment_slasher_count(&mut self) -> Result<(), RestakingError>;
 
-    /// Number of operator accounts associated with the NCN
-    operator_count: PodU64,
+    /// Validates the admin account and ensures it matches the expected admin.
+    ///
+    /// # Arguments
+    /// * `admin` - A reference to the [`Pubkey`] representing the admin Pubkey that is attempting
+    ///   to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the admin account is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnAdminInvalid`] error in the following case:
+    /// * The `admin_info` account's public key does not match the expected admin public key stored in `self`.
+    fn check_admin(&self, admin: &Pubkey) -> Result<(), RestakingError>;
 
-    /// Number of vault accounts associated with the NCN
-    vault_count: PodU64,
+    /// Validates the delegate_admin account and ensures it matches the expected delegate_admin.
+    ///
+    /// # Arguments
+    /// * `delegate_admin_info` - A reference to the [`Pubkey`] representing the delegate_admin Pubkey that is attempting
+    ///   to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the delegate_admin account is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnDelegateAdminInvalid`] error in the following case:
+    /// * The `delegate_admin_info` account's public key does not match the expected delegate_admin public key stored in `self`.
+    fn check_delegate_admin(&self, delegate_admin: &Pubkey) -> Result<(), RestakingError>;
 
-    /// Number of slasher accounts associated with the NCN
-    slasher_count: PodU64,
+    /// Validates the metadata_admin account and ensures it matches the expected metadata_admin.
+    ///
+    /// # Arguments
+    /// * `metadata_admin` - A reference to the [`Pubkey`] representing the metadata_admin Pubkey that is attempting
+    ///   to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the metadata_admin account is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnMetadataAdminInvalid`] error in the following case:
+    /// * The `metadata_admin` account's public key does not match the expected metadata_admin public key stored in `self`.
+    fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> Result<(), RestakingError>;
 
-    /// The bump seed for the PDA
-    pub bump: u8,
+    /// Validates the signer is allowed to jail operators, which is either the admin or the
+    /// slasher_admin.
+    ///
+    /// # Arguments
+    /// * `jail_admin` - A reference to the [`Pubkey`] attempting to jail or unjail an operator.
+    ///
+    /// # Returns
+    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the signer is allowed to jail operators.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnJailAdminInvalid`] error in the following case:
+    /// * The `jail_admin` public key matches neither the admin nor the slasher_admin stored in `self`.
+    fn check_jail_admin(&self, jail_admin: &Pubkey) -> Result<(), RestakingError>;
 
-    /// Reserved space
-    reserved: [u8; 263],
+    /// Replace all secondary admins that were equal to the old admin to the new admin
+    ///
+    /// # Arguments
+    /// * `old_admin` - The old admin Pubkey
+    /// * `new_admin` - The new admin Pubkey
+    fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey);
 }
 
-impl Ncn {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(base: Pubkey, admin: Pubkey, ncn_index: u64, bump: u8) -> Self {
-        Self {
-            base,
-            admin,
-            operator_admin: admin,
-            vault_admin: admin,
-            slasher_admin: admin,
-            delegate_admin: admin,
-            metadata_admin: admin,
-            weight_table_admin: admin,
-            ncn_program_admin: admin,
-            index: PodU64::from(ncn_index),
-            operator_count: PodU64::from(0),
-            vault_count: PodU64::from(0),
-            slasher_count: PodU64::from(0),
-            bump,
-            reserved: [0; RESERVED_SPACE_LEN],
+impl NcnExt for Ncn {
+    fn check_operator_bond(
+        &self,
+        operator_bond: Option<&OperatorBond>,
+    ) -> Result<(), RestakingError> {
+        let min_operator_bond = self.min_operator_bond();
+        if min_operator_bond == 0 {
+            return Ok(());
         }
+        let bonded = operator_bond.map_or(0, |operator_bond| operator_bond.amount());
+        if bonded < min_operator_bond {
+            msg!(
+                "Operator bond {} is below the NCN minimum of {}",
+                bonded,
+                min_operator_bond
+            );
+            return Err(RestakingError::NcnOperatorBondInsufficient);
+        }
+        Ok(())
     }
 
-    pub fn index(&self) -> u64 {
-        self.index.into()
-    }
-
-    pub fn operator_count(&self) -> u64 {
-        self.operator_count.into()
-    }
-
-    pub fn vault_count(&self) -> u64 {
-        self.vault_count.into()
-    }
-
-    pub fn slasher_count(&self) -> u64 {
-        self.slasher_count.into()
+    fn set_transition_epochs(
+        &mut self,
+        warmup_epochs: u16,
+        cooldown_epochs: u16,
+    ) -> Result<(), RestakingError> {
+        if warmup_epochs > MAX_TRANSITION_EPOCHS || cooldown_epochs > MAX_TRANSITION_EPOCHS {
+            msg!(
+                "Transition epochs must be at most {}, received warmup {} and cooldown {}",
+                MAX_TRANSITION_EPOCHS,
+                warmup_epochs,
+                cooldown_epochs
+            );
+            return Err(RestakingError::NcnTransitionEpochsInvalid);
+        }
+        self.transition_epochs = TransitionEpochs::new(warmup_epochs, cooldown_epochs);
+        Ok(())
     }
 
-    pub fn increment_operator_count(&mut self) -> Result<(), RestakingError> {
-        let mut operator_count: u64 = self.operator_count.into();
-        operator_count = operator_count
-            .checked_add(1)
+    fn increment_operator_count(&mut self) -> Result<(), RestakingError> {
+        self.operator_count = self
+            .operator_count
+            .checked_add(1_u64)
             .ok_or(RestakingError::OperatorOverflow)?;
-        self.operator_count = PodU64::from(operator_count);
         Ok(())
     }
 
-    pub fn increment_vault_count(&mut self) -> Result<(), RestakingError> {
-        let mut vault_count: u64 = self.vault_count.into();
-        vault_count = vault_count
-            .checked_add(1)
+    fn increment_vault_count(&mut self) -> Result<(), RestakingError> {
+        self.vault_count = self
+            .vault_count
+            .checked_add(1_u64)
             .ok_or(RestakingError::VaultOverflow)?;
-        self.vault_count = PodU64::from(vault_count);
         Ok(())
     }
 
-    pub fn increment_slasher_count(&mut self) -> Result<(), RestakingError> {
-        let mut slasher_count: u64 = self.slasher_count.into();
-        slasher_count = slasher_count
-            .checked_add(1)
+    fn increment_slasher_count(&mut self) -> Result<(), RestakingError> {
+        self.slasher_count = self
+            .slasher_count
+            .checked_add(1_u64)
             .ok_or(RestakingError::SlasherOverflow)?;
-        self.slasher_count = PodU64::from(slasher_count);
         Ok(())
     }
 
-    /// Validates the admin account and ensures it matches the expected admin.
-    ///
-    /// # Arguments
-    /// * `admin` - A reference to the [`Pubkey`] representing the admin Pubkey that is attempting
-    ///   to authorize the operation.
-    ///
-    /// # Returns
-    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the admin account is valid.
-    ///
-    /// # Errors
-    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnAdminInvalid`] error in the following case:
-    /// * The `admin_info` account's public key does not match the expected admin public key stored in `self`.
-    pub fn check_admin(&self, admin: &Pubkey) -> Result<(), RestakingError> {
+    fn check_admin(&self, admin: &Pubkey) -> Result<(), RestakingError> {
         if self.admin.ne(admin) {
             msg!(
                 "Incorrect admin provided, expected {}, received {}",
@@ -152,19 +185,7 @@ impl Ncn {
         Ok(())
     }
 
-    /// Validates the delegate_admin account and ensures it matches the expected delegate_admin.
-    ///
-    /// # Arguments
-    /// * `delegate_admin_info` - A reference to the [`Pubkey`] representing the delegate_admin Pubkey that is attempting
-    ///   to authorize the operation.
-    ///
-    /// # Returns
-    /// * `Result<(), RestakingError>` - Returns `Ok(())` if the delegate_admin account is valid.
-    ///
-    /// # Errors
-    /// This function will return a [`jito_restaking_sdk::error::RestakingError::NcnDelegateAdminInvalid`] error in the following case:
-    /// * The `delegate_admin_info` account's public key does not match the expected delegate_admin public key stored in `self`.
-    pub fn check_delegate_admin(&self, delegate_admin: &Pubkey) -> Result<(), RestakingError> {
+    fn check_delegate_admin(&self, delegate_admin: &Pubkey) -> Result<(), RestakingError> {
         if self.delegate_admin.ne(delegate_admin) {
             msg!(
                 "Incorrect delegate_admin provided, expected {}, received {}",
@@ -176,12 +197,32 @@ impl Ncn {
         Ok(())
     }
 
-    /// Replace all secondary admins that were equal to the old admin to the new admin
-    ///
-    /// # Arguments
-    /// * `old_admin` - The old admin Pubkey
-    /// * `new_admin` - The new admin Pubkey
-    pub fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
+    fn check_metadata_admin(&self, metadata_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.metadata_admin.ne(metadata_admin) {
+            msg!(
+                "Incorrect metadata_admin provided, expected {}, received {}",
+                self.metadata_admin,
+                metadata_admin
+            );
+            return Err(RestakingError::NcnMetadataAdminInvalid);
+        }
+        Ok(())
+    }
+
+    fn check_jail_admin(&self, jail_admin: &Pubkey) -> Result<(), RestakingError> {
+        if self.admin.ne(jail_admin) && self.slasher_admin.ne(jail_admin) {
+            msg!(
+                "Incorrect jail admin provided, expected {} or {}, received {}",
+                self.admin,
+                self.slasher_admin,
+                jail_admin
+            );
+            return Err(RestakingError::NcnJailAdminInvalid);
+        }
+        Ok(())
+    }
+
+    fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
         if self.operator_admin.eq(old_admin) {
             self.operator_admin = *new_admin;
             msg!("Operator admin set to {:?}", new_admin);
@@ -217,95 +258,74 @@ impl Ncn {
             msg!("Ncn program admin set to {:?}", new_admin);
         }
     }
-
-    /// Returns the seeds for the PDA
-    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
-        Vec::from_iter([b"ncn".to_vec(), base.as_ref().to_vec()])
-    }
-
-    /// Find the program address for the NCN account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    /// Attempts to load the account as [`Ncn`], returning an error if it's not valid.
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load the NCN from
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("NCN account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("NCN account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("NCN account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("NCN account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let base = Self::try_from_slice_unchecked(&account.data.borrow())?.base;
-        if account
-            .key
-            .ne(&Self::find_program_address(program_id, &base).0)
-        {
-            msg!("NCN account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
 mod tests {
-    use jito_bytemuck::types::PodU64;
+    use jito_jsm_core::slot_toggle::TransitionEpochs;
+    use jito_restaking_sdk::error::RestakingError;
     use solana_program::pubkey::Pubkey;
 
-    use super::{Ncn, RESERVED_SPACE_LEN};
+    use super::{Ncn, NcnExt, MAX_TRANSITION_EPOCHS};
+    use crate::operator_bond::{OperatorBond, OperatorBondExt};
+
+    #[test]
+    fn test_check_operator_bond() {
+        let operator = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+
+        // No requirement by default
+        assert_eq!(ncn.check_operator_bond(None), Ok(()));
+
+        ncn.set_operator_bond_requirement(mint, 100);
+        assert_eq!(
+            ncn.check_operator_bond(None),
+            Err(RestakingError::NcnOperatorBondInsufficient)
+        );
+
+        let mut operator_bond = OperatorBond::new(Pubkey::new_unique(), operator, mint, 0);
+        operator_bond.deposit(99).unwrap();
+        assert_eq!(
+            ncn.check_operator_bond(Some(&operator_bond)),
+            Err(RestakingError::NcnOperatorBondInsufficient)
+        );
+
+        operator_bond.deposit(1).unwrap();
+        assert_eq!(ncn.check_operator_bond(Some(&operator_bond)), Ok(()));
+
+        // Unbonding tokens don't count towards the requirement
+        operator_bond.unbond(1, 0, 1).unwrap();
+        assert_eq!(
+            ncn.check_operator_bond(Some(&operator_bond)),
+            Err(RestakingError::NcnOperatorBondInsufficient)
+        );
+    }
 
     #[test]
-    fn test_ncn_no_padding() {
-        let ncn_size = std::mem::size_of::<Ncn>();
-        let sum_of_fields = std::mem::size_of::<Pubkey>() + // base
-            std::mem::size_of::<Pubkey>() + // admin
-            std::mem::size_of::<Pubkey>() + // operator_admin
-            std::mem::size_of::<Pubkey>() + // vault_admin
-            std::mem::size_of::<Pubkey>() + // slasher_admin
-            std::mem::size_of::<Pubkey>() + // delegate_admin
-            std::mem::size_of::<Pubkey>() + // metadata_admin
-            std::mem::size_of::<Pubkey>() + // weight_table_admin
-            std::mem::size_of::<Pubkey>() + // ncn_program_admin
-            std::mem::size_of::<PodU64>() + // index
-            std::mem::size_of::<PodU64>() + // operator_count
-            std::mem::size_of::<PodU64>() + // vault_count
-            std::mem::size_of::<PodU64>() + // slasher_count
-            std::mem::size_of::<u8>() + // bump
-            RESERVED_SPACE_LEN; // reserved
-        assert_eq!(ncn_size, sum_of_fields);
+    fn test_set_transition_epochs() {
+        let mut ncn = Ncn::new(Pubkey::new_unique(), Pubkey::new_unique(), 0, 0);
+        assert_eq!(ncn.transition_epochs(), TransitionEpochs::default());
+
+        ncn.set_transition_epochs(0, MAX_TRANSITION_EPOCHS).unwrap();
+        assert_eq!(ncn.transition_epochs().warmup_epochs(), 0);
+        assert_eq!(
+            ncn.transition_epochs().cooldown_epochs(),
+            MAX_TRANSITION_EPOCHS
+        );
+
+        assert_eq!(
+            ncn.set_transition_epochs(MAX_TRANSITION_EPOCHS + 1, 1),
+            Err(RestakingError::NcnTransitionEpochsInvalid)
+        );
+        assert_eq!(
+            ncn.set_transition_epochs(1, MAX_TRANSITION_EPOCHS + 1),
+            Err(RestakingError::NcnTransitionEpochsInvalid)
+        );
+        assert_eq!(
+            ncn.transition_epochs(),
+            TransitionEpochs::new(0, MAX_TRANSITION_EPOCHS)
+        );
     }
 
     #[test]
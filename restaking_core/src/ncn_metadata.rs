@@ -0,0 +1,4 @@
+//! The NcnMetadata account stores human-readable information about an NCN, such as its name,
+//! website and logo. It is managed by the NCN's metadata admin.
+
+pub use jito_layouts::restaking::ncn_metadata::NcnMetadata;
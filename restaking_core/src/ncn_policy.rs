@@ -0,0 +1,246 @@
+//! The NcnPolicy account holds the admission rules of an NCN. Once an NCN has a policy, the
+//! program enforces it when warming up operators and vaults.
+//!
+//! With permissionless admission enabled, any signer can warm up operators and vaults that satisfy
+//! the policy.
+
+use jito_bytemuck::types::{PodBool, PodU64};
+use jito_jsm_core::slot_toggle::{SlotToggle, SlotToggleState};
+pub use jito_layouts::restaking::ncn_policy::{NcnPolicy, MAX_ALLOWED_VAULT_MINTS};
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::{msg, program_error::ProgramError, pubkey::Pubkey};
+
+/// The [`NcnPolicy`] methods that return a [`RestakingError`]
+pub trait NcnPolicyExt {
+    /// Sets the admission rules
+    ///
+    /// # Arguments
+    /// * `permissionless_admission` - Whether any signer can warm up operators and vaults
+    /// * `min_operator_stake` - The minimum stake delegated to an operator, zero if none
+    /// * `max_operators` - The maximum number of admitted operators, zero if unlimited
+    /// * `min_operator_ticket_age` - The minimum opt-in age of an operator in slots, zero if none
+    /// * `allowed_vault_mints` - The allowed vault supported mints, empty to allow any mint
+    fn set_policy(
+        &mut self,
+        permissionless_admission: bool,
+        min_operator_stake: u64,
+        max_operators: u64,
+        min_operator_ticket_age: u64,
+        allowed_vault_mints: &[Pubkey],
+    ) -> Result<(), RestakingError>;
+
+    /// Checks the vault supported mint is allowed by the policy
+    fn check_vault_mint(&self, mint: &Pubkey) -> Result<(), RestakingError>;
+
+    /// Checks the stake delegated to the operator meets the policy minimum
+    ///
+    /// # Arguments
+    /// * `operator_stake` - The stake delegated to the operator from allowed vaults
+    fn check_operator_stake(&self, operator_stake: u64) -> Result<(), RestakingError>;
+
+    /// Checks the operator is warming up or active in the NCN and was opted in at least the minimum
+    /// ticket age ago
+    ///
+    /// # Arguments
+    /// * `operator_opt_in_state` - The operator's opt-in state in the NcnOperatorState
+    /// * `slot` - The current slot
+    /// * `epoch_length` - The length of an epoch in slots
+    fn check_operator_ticket_age(
+        &self,
+        operator_opt_in_state: &SlotToggle,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError>;
+
+    /// Admits an operator, checking the maximum number of operators is not exceeded
+    fn admit_operator(&mut self) -> Result<(), RestakingError>;
+
+    /// Releases an operator admitted under the policy
+    fn release_operator(&mut self) -> Result<(), RestakingError>;
+}
+
+impl NcnPolicyExt for NcnPolicy {
+    fn set_policy(
+        &mut self,
+        permissionless_admission: bool,
+        min_operator_stake: u64,
+        max_operators: u64,
+        min_operator_ticket_age: u64,
+        allowed_vault_mints: &[Pubkey],
+    ) -> Result<(), RestakingError> {
+        if allowed_vault_mints.len() > MAX_ALLOWED_VAULT_MINTS {
+            msg!(
+                "At most {} vault mints can be allowed",
+                MAX_ALLOWED_VAULT_MINTS
+            );
+            return Err(RestakingError::NcnPolicyTooManyVaultMints);
+        }
+        self.allowed_vault_mints = [Pubkey::default(); MAX_ALLOWED_VAULT_MINTS];
+        self.allowed_vault_mints[..allowed_vault_mints.len()].copy_from_slice(allowed_vault_mints);
+        self.permissionless_admission = PodBool::from(permissionless_admission);
+        self.min_operator_stake = PodU64::from(min_operator_stake);
+        self.max_operators = PodU64::from(max_operators);
+        self.min_operator_ticket_age = PodU64::from(min_operator_ticket_age);
+        Ok(())
+    }
+
+    fn check_vault_mint(&self, mint: &Pubkey) -> Result<(), RestakingError> {
+        if !self.is_vault_mint_allowed(mint) {
+            msg!(
+                "Vault supported mint {} is not allowed by the NCN policy",
+                mint
+            );
+            return Err(RestakingError::NcnPolicyVaultMintNotAllowed);
+        }
+        Ok(())
+    }
+
+    fn check_operator_stake(&self, operator_stake: u64) -> Result<(), RestakingError> {
+        let min_operator_stake = self.min_operator_stake();
+        if operator_stake < min_operator_stake {
+            msg!(
+                "Operator stake {} is below the NCN policy minimum of {}",
+                operator_stake,
+                min_operator_stake
+            );
+            return Err(RestakingError::NcnPolicyOperatorStakeInsufficient);
+        }
+        Ok(())
+    }
+
+    fn check_operator_ticket_age(
+        &self,
+        operator_opt_in_state: &SlotToggle,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<(), ProgramError> {
+        let min_operator_ticket_age = self.min_operator_ticket_age();
+        if min_operator_ticket_age == 0 {
+            return Ok(());
+        }
+        // The operator shall currently be opted in to the NCN
+        let opted_in = matches!(
+            operator_opt_in_state.state(slot, epoch_length)?,
+            SlotToggleState::WarmUp | SlotToggleState::Active
+        );
+        let age = slot.saturating_sub(operator_opt_in_state.slot_added());
+        if !opted_in || age < min_operator_ticket_age {
+            msg!(
+                "Operator ticket age must be at least {} slots",
+                min_operator_ticket_age
+            );
+            return Err(RestakingError::NcnPolicyOperatorTicketTooNew.into());
+        }
+        Ok(())
+    }
+
+    fn admit_operator(&mut self) -> Result<(), RestakingError> {
+        let max_operators = self.max_operators();
+        let admitted_operator_count = self.admitted_operator_count();
+        if max_operators > 0 && admitted_operator_count >= max_operators {
+            msg!(
+                "NCN policy allows at most {} admitted operators",
+                max_operators
+            );
+            return Err(RestakingError::NcnPolicyMaxOperatorsReached);
+        }
+        self.admitted_operator_count = self
+            .admitted_operator_count
+            .checked_add(1_u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    fn release_operator(&mut self) -> Result<(), RestakingError> {
+        self.admitted_operator_count = self
+            .admitted_operator_count
+            .checked_sub(1_u64)
+            .ok_or(RestakingError::ArithmeticUnderflow)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_policy_too_many_vault_mints_fails() {
+        let mut ncn_policy = NcnPolicy::new(Pubkey::new_unique(), 0);
+        let mints: Vec<Pubkey> = (0..MAX_ALLOWED_VAULT_MINTS + 1)
+            .map(|_| Pubkey::new_unique())
+            .collect();
+        assert_eq!(
+            ncn_policy.set_policy(false, 0, 0, 0, &mints),
+            Err(RestakingError::NcnPolicyTooManyVaultMints)
+        );
+    }
+
+    #[test]
+    fn test_check_vault_mint() {
+        let mut ncn_policy = NcnPolicy::new(Pubkey::new_unique(), 0);
+        let allowed_mint = Pubkey::new_unique();
+
+        // Any mint is allowed without an allow list
+        ncn_policy.check_vault_mint(&allowed_mint).unwrap();
+
+        ncn_policy
+            .set_policy(false, 0, 0, 0, &[allowed_mint])
+            .unwrap();
+        assert_eq!(ncn_policy.allowed_vault_mints(), vec![allowed_mint]);
+        ncn_policy.check_vault_mint(&allowed_mint).unwrap();
+        assert_eq!(
+            ncn_policy.check_vault_mint(&Pubkey::new_unique()),
+            Err(RestakingError::NcnPolicyVaultMintNotAllowed)
+        );
+    }
+
+    #[test]
+    fn test_check_operator_ticket_age() {
+        let mut ncn_policy = NcnPolicy::new(Pubkey::new_unique(), 0);
+        ncn_policy.set_policy(false, 0, 0, 100, &[]).unwrap();
+
+        // Operator not opted in
+        let mut operator_opt_in_state = SlotToggle::new(0);
+        assert_eq!(
+            ncn_policy.check_operator_ticket_age(&operator_opt_in_state, 1_000, 1_000),
+            Err(RestakingError::NcnPolicyOperatorTicketTooNew.into())
+        );
+
+        assert!(operator_opt_in_state.activate(10, 1_000).unwrap());
+        assert_eq!(
+            ncn_policy.check_operator_ticket_age(&operator_opt_in_state, 109, 1_000),
+            Err(RestakingError::NcnPolicyOperatorTicketTooNew.into())
+        );
+        ncn_policy
+            .check_operator_ticket_age(&operator_opt_in_state, 110, 1_000)
+            .unwrap();
+        ncn_policy
+            .check_operator_ticket_age(&operator_opt_in_state, 2_000, 1_000)
+            .unwrap();
+
+        // Operator cooling down
+        assert!(operator_opt_in_state.deactivate(2_000, 1_000).unwrap());
+        assert_eq!(
+            ncn_policy.check_operator_ticket_age(&operator_opt_in_state, 2_000, 1_000),
+            Err(RestakingError::NcnPolicyOperatorTicketTooNew.into())
+        );
+    }
+
+    #[test]
+    fn test_admit_and_release_operator() {
+        let mut ncn_policy = NcnPolicy::new(Pubkey::new_unique(), 0);
+        ncn_policy.set_policy(false, 0, 1, 0, &[]).unwrap();
+
+        ncn_policy.admit_operator().unwrap();
+        assert_eq!(ncn_policy.admitted_operator_count(), 1);
+        assert_eq!(
+            ncn_policy.admit_operator(),
+            Err(RestakingError::NcnPolicyMaxOperatorsReached)
+        );
+
+        ncn_policy.release_operator().unwrap();
+        assert_eq!(ncn_policy.admitted_operator_count(), 0);
+        ncn_policy.admit_operator().unwrap();
+    }
+}
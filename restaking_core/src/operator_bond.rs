@@ -0,0 +1,256 @@
+//! The OperatorBond account tracks the tokens an operator has bonded to an NCN in a given mint.
+//! The tokens are held in the associated token account of the OperatorBond PDA.
+//!
+//! NCNs can require a minimum bond before warming up an operator, and only the NCN the bond is
+//! scoped to can slash it. Unbonded tokens cool down for the NCN's cooldown epochs before they can
+//! be withdrawn, and stay slashable until then.
+
+use jito_bytemuck::types::PodU64;
+pub use jito_layouts::restaking::operator_bond::OperatorBond;
+use jito_restaking_sdk::error::RestakingError;
+use solana_program::msg;
+
+/// The [`OperatorBond`] methods that return a [`RestakingError`]
+pub trait OperatorBondExt {
+    /// Adds deposited tokens to the bond
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of tokens deposited
+    fn deposit(&mut self, amount: u64) -> Result<(), RestakingError>;
+
+    /// Starts the cooldown of bonded tokens. The cooldown of tokens already unbonding restarts
+    /// with them, so all the unbonding tokens become withdrawable together.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of tokens to unbond
+    /// * `epoch` - The current epoch
+    /// * `cooldown_epochs` - The number of full epochs the tokens cool down for
+    fn unbond(
+        &mut self,
+        amount: u64,
+        epoch: u64,
+        cooldown_epochs: u16,
+    ) -> Result<(), RestakingError>;
+
+    /// Takes the unbonding tokens once their cooldown is over.
+    ///
+    /// # Arguments
+    /// * `epoch` - The current epoch
+    ///
+    /// # Returns
+    /// * `Result<u64, RestakingError>` - The amount of tokens to withdraw
+    fn withdraw(&mut self, epoch: u64) -> Result<u64, RestakingError>;
+
+    /// Slashes the bond, taking the bonded tokens first and then the tokens still cooling down.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount to slash
+    ///
+    /// # Returns
+    /// * `Result<u64, RestakingError>` - The amount taken from the bond, which is capped at the
+    ///   bonded and unbonding amounts
+    fn slash(&mut self, amount: u64) -> Result<u64, RestakingError>;
+}
+
+impl OperatorBondExt for OperatorBond {
+    fn deposit(&mut self, amount: u64) -> Result<(), RestakingError> {
+        if amount == 0 {
+            msg!("Operator bond deposit amount must be greater than zero");
+            return Err(RestakingError::OperatorBondAmountZero);
+        }
+        self.amount = self
+            .amount
+            .checked_add(amount)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    fn unbond(
+        &mut self,
+        amount: u64,
+        epoch: u64,
+        cooldown_epochs: u16,
+    ) -> Result<(), RestakingError> {
+        if amount == 0 {
+            msg!("Operator bond unbond amount must be greater than zero");
+            return Err(RestakingError::OperatorBondAmountZero);
+        }
+        let new_amount = self.amount.checked_sub(amount).ok_or_else(|| {
+            msg!(
+                "Unbond amount {} exceeds the bonded amount {}",
+                amount,
+                self.amount()
+            );
+            RestakingError::OperatorBondAmountExceeded
+        })?;
+        let new_unbonding_amount = self
+            .unbonding_amount
+            .checked_add(amount)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        let withdrawable_epoch = epoch
+            .checked_add(u64::from(cooldown_epochs))
+            .and_then(|epoch| epoch.checked_add(1))
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        self.amount = new_amount;
+        self.unbonding_amount = new_unbonding_amount;
+        self.withdrawable_epoch = PodU64::from(withdrawable_epoch);
+        Ok(())
+    }
+
+    fn withdraw(&mut self, epoch: u64) -> Result<u64, RestakingError> {
+        let unbonding_amount = self.unbonding_amount();
+        if unbonding_amount == 0 {
+            msg!("Operator bond has no unbonded tokens to withdraw");
+            return Err(RestakingError::OperatorBondAmountZero);
+        }
+        if epoch < self.withdrawable_epoch() {
+            msg!(
+                "Unbonded tokens are withdrawable from epoch {}",
+                self.withdrawable_epoch()
+            );
+            return Err(RestakingError::OperatorBondCooldownActive);
+        }
+        self.unbonding_amount = PodU64::from(0);
+        Ok(unbonding_amount)
+    }
+
+    fn slash(&mut self, amount: u64) -> Result<u64, RestakingError> {
+        if amount == 0 {
+            msg!("Operator bond slash amount must be greater than zero");
+            return Err(RestakingError::OperatorBondAmountZero);
+        }
+        let slashed_from_amount = amount.min(self.amount());
+        let slashed_from_unbonding = amount
+            .saturating_sub(slashed_from_amount)
+            .min(self.unbonding_amount());
+        let new_amount = self
+            .amount
+            .checked_sub(slashed_from_amount)
+            .ok_or(RestakingError::ArithmeticUnderflow)?;
+        let new_unbonding_amount = self
+            .unbonding_amount
+            .checked_sub(slashed_from_unbonding)
+            .ok_or(RestakingError::ArithmeticUnderflow)?;
+        let slashed_from_bond = slashed_from_amount
+            .checked_add(slashed_from_unbonding)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        let new_slashed = self
+            .slashed
+            .checked_add(slashed_from_bond)
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+        self.amount = new_amount;
+        self.unbonding_amount = new_unbonding_amount;
+        self.slashed = new_slashed;
+        Ok(slashed_from_bond)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    fn new_operator_bond() -> OperatorBond {
+        OperatorBond::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_deposit_zero_fails() {
+        let mut operator_bond = new_operator_bond();
+        assert_eq!(
+            operator_bond.deposit(0),
+            Err(RestakingError::OperatorBondAmountZero)
+        );
+    }
+
+    #[test]
+    fn test_slash_capped_at_bond() {
+        let mut operator_bond = new_operator_bond();
+        operator_bond.deposit(100).unwrap();
+
+        assert_eq!(operator_bond.slash(40), Ok(40));
+        assert_eq!(operator_bond.amount(), 60);
+        assert_eq!(operator_bond.slashed(), 40);
+
+        // Only the remaining bond is taken
+        assert_eq!(operator_bond.slash(100), Ok(60));
+        assert_eq!(operator_bond.amount(), 0);
+        assert_eq!(operator_bond.slashed(), 100);
+
+        assert_eq!(operator_bond.slash(10), Ok(0));
+    }
+
+    #[test]
+    fn test_slash_takes_unbonding_tokens() {
+        let mut operator_bond = new_operator_bond();
+        operator_bond.deposit(100).unwrap();
+        operator_bond.unbond(70, 5, 1).unwrap();
+
+        assert_eq!(operator_bond.slash(50), Ok(50));
+        assert_eq!(operator_bond.amount(), 0);
+        assert_eq!(operator_bond.unbonding_amount(), 50);
+
+        assert_eq!(operator_bond.slash(80), Ok(50));
+        assert_eq!(operator_bond.unbonding_amount(), 0);
+        assert_eq!(operator_bond.slashed(), 100);
+    }
+
+    #[test]
+    fn test_unbond_exceeding_bond_fails() {
+        let mut operator_bond = new_operator_bond();
+        operator_bond.deposit(100).unwrap();
+
+        assert_eq!(
+            operator_bond.unbond(101, 5, 1),
+            Err(RestakingError::OperatorBondAmountExceeded)
+        );
+        assert_eq!(
+            operator_bond.unbond(0, 5, 1),
+            Err(RestakingError::OperatorBondAmountZero)
+        );
+    }
+
+    #[test]
+    fn test_withdraw_after_cooldown() {
+        let mut operator_bond = new_operator_bond();
+        operator_bond.deposit(100).unwrap();
+        operator_bond.unbond(30, 5, 1).unwrap();
+        assert_eq!(operator_bond.amount(), 70);
+        assert_eq!(operator_bond.unbonding_amount(), 30);
+
+        // The cooldown lasts the full epoch after the unbonding epoch
+        assert_eq!(operator_bond.withdrawable_epoch(), 7);
+        assert_eq!(
+            operator_bond.withdraw(6),
+            Err(RestakingError::OperatorBondCooldownActive)
+        );
+        assert_eq!(operator_bond.withdraw(7), Ok(30));
+        assert_eq!(operator_bond.unbonding_amount(), 0);
+        assert_eq!(
+            operator_bond.withdraw(7),
+            Err(RestakingError::OperatorBondAmountZero)
+        );
+    }
+
+    #[test]
+    fn test_unbond_restarts_cooldown() {
+        let mut operator_bond = new_operator_bond();
+        operator_bond.deposit(100).unwrap();
+        operator_bond.unbond(30, 5, 1).unwrap();
+        operator_bond.unbond(20, 6, 1).unwrap();
+
+        assert_eq!(operator_bond.unbonding_amount(), 50);
+        assert_eq!(operator_bond.withdrawable_epoch(), 8);
+        assert_eq!(
+            operator_bond.withdraw(7),
+            Err(RestakingError::OperatorBondCooldownActive)
+        );
+        assert_eq!(operator_bond.withdraw(8), Ok(50));
+    }
+}
@@ -0,0 +1,4 @@
+//! The OperatorMetadata account stores human-readable information about an operator, such as
+//! its name, website and logo. It is managed by the operator's metadata admin.
+
+pub use jito_layouts::restaking::operator_metadata::OperatorMetadata;
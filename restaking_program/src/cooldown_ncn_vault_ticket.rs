@@ -1,10 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::CooldownNcnVaultTicketAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// [`crate::RestakingInstruction::CooldownNcnVaultTicket`]
@@ -12,14 +12,13 @@ pub fn process_cooldown_ncn_vault_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, ncn, vault, ncn_vault_ticket, ncn_vault_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn, false)?;
-    NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault, true)?;
-    load_signer(ncn_vault_admin, false)?;
+    let CooldownNcnVaultTicketAccounts {
+        config,
+        ncn,
+        vault: _,
+        ncn_vault_ticket,
+        admin: ncn_vault_admin,
+    } = CooldownNcnVaultTicketAccounts::try_from_accounts(program_id, accounts)?;
 
     // The NCN vault admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
@@ -35,10 +34,11 @@ pub fn process_cooldown_ncn_vault_ticket(
     let mut ncn_vault_ticket_data = ncn_vault_ticket.data.borrow_mut();
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
-    if !ncn_vault_ticket
-        .state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
-    {
+    if !ncn_vault_ticket.state.deactivate_with_transition_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.transition_epochs(),
+    )? {
         msg!("Vault is not ready to be deactivated");
         return Err(RestakingError::NcnVaultTicketFailedCooldown.into());
     }
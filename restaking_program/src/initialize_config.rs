@@ -1,12 +1,9 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_restaking_core::config::Config;
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeConfigAccounts};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -15,13 +12,14 @@ use solana_program::{
 /// Initializes the global configuration for the restaking program
 /// [`crate::RestakingInstruction::InitializeConfig`]
 pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, admin, vault_program, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeConfigAccounts {
+        config,
+        admin,
+        vault_program,
+        system_program,
+    } = InitializeConfigAccounts::try_from_accounts(program_id, accounts)?;
 
-    load_system_account(config, true)?;
-    load_signer(admin, true)?;
-    load_system_program(system_program)?;
+    load_system_account(config, "config", true)?;
 
     // The Config shall be at the canonical PDA
     let (config_pubkey, config_bump, mut config_seeds) = Config::find_program_address(program_id);
@@ -45,7 +43,7 @@ pub fn process_initialize_config(program_id: &Pubkey, accounts: &[AccountInfo])
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
-    config_data[0] = Config::DISCRIMINATOR;
+    Config::write_header(&mut config_data);
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(*admin.key, *vault_program.key, config_bump);
 
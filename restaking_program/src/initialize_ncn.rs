@@ -1,12 +1,12 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    config::{Config, ConfigExt},
+    ncn::Ncn,
 };
-use jito_restaking_core::{config::Config, ncn::Ncn};
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeNcnAccounts};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -15,14 +15,14 @@ use solana_program::{
 /// Initializes an NCN and associated accounts
 /// [`crate::RestakingInstruction::InitializeNcn`]
 pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, admin, base, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, true)?;
-    load_system_account(ncn, true)?;
-    load_signer(admin, true)?;
-    load_signer(base, false)?;
-    load_system_program(system_program)?;
+    let InitializeNcnAccounts {
+        config,
+        ncn,
+        admin,
+        base,
+        system_program,
+    } = InitializeNcnAccounts::try_from_accounts(program_id, accounts)?;
+    load_system_account(ncn, "ncn", true)?;
 
     // The Ncn shall be at the canonical PDA
     let (ncn_pubkey, ncn_bump, mut ncn_seeds) = Ncn::find_program_address(program_id, base.key);
@@ -49,7 +49,7 @@ pub fn process_initialize_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) ->
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
     let mut ncn_data = ncn.try_borrow_mut_data()?;
-    ncn_data[0] = Ncn::DISCRIMINATOR;
+    Ncn::write_header(&mut ncn_data);
     let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
     *ncn = Ncn::new(*base.key, *admin.key, config.ncn_count(), ncn_bump);
 
@@ -0,0 +1,71 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    metadata::{Metadata, MetadataExt},
+    ncn::{Ncn, NcnExt},
+    ncn_metadata::NcnMetadata,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeNcnMetadataAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// The NCN metadata admin can create the metadata account describing the NCN.
+///
+/// [`crate::RestakingInstruction::InitializeNcnMetadata`]
+pub fn process_initialize_ncn_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: Metadata,
+) -> ProgramResult {
+    let InitializeNcnMetadataAccounts {
+        config: _,
+        ncn: ncn_info,
+        ncn_metadata,
+        metadata_admin,
+        payer,
+        system_program,
+    } = InitializeNcnMetadataAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_system_account(ncn_metadata, "ncn_metadata", true)?;
+
+    // The NcnMetadata shall be at the canonical PDA
+    let (ncn_metadata_pubkey, ncn_metadata_bump, mut ncn_metadata_seeds) =
+        NcnMetadata::find_program_address(program_id, ncn_info.key);
+    ncn_metadata_seeds.push(vec![ncn_metadata_bump]);
+    if ncn_metadata_pubkey.ne(ncn_metadata.key) {
+        msg!("NcnMetadata is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The NCN metadata admin shall be the signer of the transaction
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_metadata_admin(metadata_admin.key)?;
+
+    // Each string field shall be UTF-8 followed only by zero padding
+    metadata.check_valid()?;
+
+    msg!("Initializing NcnMetadata at address {}", ncn_metadata.key);
+    create_account(
+        payer,
+        ncn_metadata,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<NcnMetadata>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &ncn_metadata_seeds,
+    )?;
+
+    let mut ncn_metadata_data = ncn_metadata.try_borrow_mut_data()?;
+    NcnMetadata::write_header(&mut ncn_metadata_data);
+    let ncn_metadata = NcnMetadata::try_from_slice_unchecked_mut(&mut ncn_metadata_data)?;
+    *ncn_metadata = NcnMetadata::new(*ncn_info.key, metadata, ncn_metadata_bump);
+
+    Ok(())
+}
@@ -1,14 +1,13 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+    ncn::{Ncn, NcnExt},
+    ncn_operator_state::NcnOperatorState,
+    operator::{Operator, OperatorExt},
 };
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeNcnOperatorStateAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -22,19 +21,17 @@ pub fn process_initialize_ncn_operator_state(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, ncn_info, operator, ncn_operator_state, ncn_operator_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeNcnOperatorStateAccounts {
+        config: _,
+        ncn: ncn_info,
+        operator,
+        ncn_operator_state,
+        admin: ncn_operator_admin,
+        payer,
+        system_program,
+    } = InitializeNcnOperatorStateAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn_info, true)?;
-    Operator::load(program_id, operator, true)?;
-    load_system_account(ncn_operator_state, true)?;
-    load_signer(ncn_operator_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(ncn_operator_state, "ncn_operator_state", true)?;
 
     // The NcnOperatorState shall be at the canonical PDA
     let (ncn_operator_state_pubkey, ncn_operator_state_bump, mut ncn_operator_state_seeds) =
@@ -67,7 +64,7 @@ pub fn process_initialize_ncn_operator_state(
     )?;
 
     let mut ncn_operator_state_data = ncn_operator_state.try_borrow_mut_data()?;
-    ncn_operator_state_data[0] = NcnOperatorState::DISCRIMINATOR;
+    NcnOperatorState::write_header(&mut ncn_operator_state_data);
     let ncn_operator_state =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     *ncn_operator_state = NcnOperatorState::new(
@@ -0,0 +1,69 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    ncn::{Ncn, NcnExt},
+    ncn_policy::NcnPolicy,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeNcnPolicyAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// The NCN admin can create the NCN policy. The policy starts without any rules, and once it
+/// exists the program enforces it when warming up operators and vaults.
+///
+/// [`crate::RestakingInstruction::InitializeNcnPolicy`]
+pub fn process_initialize_ncn_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let InitializeNcnPolicyAccounts {
+        config: _,
+        ncn: ncn_info,
+        ncn_policy,
+        admin,
+        payer,
+        system_program,
+    } = InitializeNcnPolicyAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_system_account(ncn_policy, "ncn_policy", true)?;
+
+    // The NcnPolicy shall be at the canonical PDA
+    let (ncn_policy_pubkey, ncn_policy_bump, mut ncn_policy_seeds) =
+        NcnPolicy::find_program_address(program_id, ncn_info.key);
+    ncn_policy_seeds.push(vec![ncn_policy_bump]);
+    if ncn_policy_pubkey.ne(ncn_policy.key) {
+        msg!("NcnPolicy is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn_info.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    msg!("Initializing NcnPolicy at address {}", ncn_policy.key);
+    create_account(
+        payer,
+        ncn_policy,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<NcnPolicy>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &ncn_policy_seeds,
+    )?;
+
+    let mut ncn_policy_data = ncn_policy.try_borrow_mut_data()?;
+    NcnPolicy::write_header(&mut ncn_policy_data);
+    let ncn_policy = NcnPolicy::try_from_slice_unchecked_mut(&mut ncn_policy_data)?;
+    *ncn_policy = NcnPolicy::new(*ncn_info.key, ncn_policy_bump);
+
+    ncn.set_has_policy(true);
+
+    Ok(())
+}
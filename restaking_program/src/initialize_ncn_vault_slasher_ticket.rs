@@ -1,16 +1,14 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
-    ncn_vault_ticket::NcnVaultTicket,
+    ncn::{Ncn, NcnExt},
+    ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+};
+use jito_restaking_sdk::{
+    error::RestakingError, instruction::InitializeNcnVaultSlasherTicketAccounts,
 };
-use jito_restaking_sdk::error::RestakingError;
-use jito_vault_core::vault::Vault;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -21,22 +19,19 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
     accounts: &[AccountInfo],
     max_slashable_per_epoch: u64,
 ) -> ProgramResult {
-    let [config, ncn_info, vault, slasher, ncn_vault_ticket, ncn_vault_slasher_ticket, ncn_slasher_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeNcnVaultSlasherTicketAccounts {
+        config: _,
+        ncn: ncn_info,
+        vault,
+        slasher,
+        ncn_vault_ticket: _,
+        ncn_vault_slasher_ticket,
+        admin: ncn_slasher_admin,
+        payer,
+        system_program,
+    } = InitializeNcnVaultSlasherTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn_info, true)?;
-    let config_data = config.data.borrow();
-    let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(&config.vault_program, vault, false)?;
-    NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn_info, vault, false)?;
-    load_system_account(ncn_vault_slasher_ticket, true)?;
-    load_signer(ncn_slasher_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(ncn_vault_slasher_ticket, "ncn_vault_slasher_ticket", true)?;
 
     // The NcnVaultSlasherTicket shall be at the canonical PDA
     let (
@@ -81,7 +76,7 @@ pub fn process_initialize_ncn_vault_slasher_ticket(
         &ncn_vault_slasher_ticket_seeds,
     )?;
     let mut ncn_vault_slasher_ticket_data = ncn_vault_slasher_ticket.try_borrow_mut_data()?;
-    ncn_vault_slasher_ticket_data[0] = NcnVaultSlasherTicket::DISCRIMINATOR;
+    NcnVaultSlasherTicket::write_header(&mut ncn_vault_slasher_ticket_data);
     let ncn_vault_slasher_ticket =
         NcnVaultSlasherTicket::try_from_slice_unchecked_mut(&mut ncn_vault_slasher_ticket_data)?;
     *ncn_vault_slasher_ticket = NcnVaultSlasherTicket::new(
@@ -1,13 +1,12 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    ncn::{Ncn, NcnExt},
+    ncn_vault_ticket::NcnVaultTicket,
 };
-use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
-use jito_restaking_sdk::error::RestakingError;
-use jito_vault_core::vault::Vault;
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeNcnVaultTicketAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -21,21 +20,17 @@ pub fn process_initialize_ncn_vault_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, ncn_info, vault, ncn_vault_ticket, ncn_vault_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeNcnVaultTicketAccounts {
+        config: _,
+        ncn: ncn_info,
+        vault,
+        ncn_vault_ticket,
+        admin: ncn_vault_admin,
+        payer,
+        system_program,
+    } = InitializeNcnVaultTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn_info, true)?;
-    let config_data = config.data.borrow();
-    let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(&config.vault_program, vault, false)?;
-    load_system_account(ncn_vault_ticket, true)?;
-    load_signer(ncn_vault_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(ncn_vault_ticket, "ncn_vault_ticket", true)?;
 
     // The NcnVaultTicket shall be at the canonical PDA
     let (ncn_vault_ticket_pubkey, ncn_vault_ticket_bump, mut ncn_vault_ticket_seeds) =
@@ -70,7 +65,7 @@ pub fn process_initialize_ncn_vault_ticket(
     )?;
 
     let mut ncn_vault_ticket_data = ncn_vault_ticket.try_borrow_mut_data()?;
-    ncn_vault_ticket_data[0] = NcnVaultTicket::DISCRIMINATOR;
+    NcnVaultTicket::write_header(&mut ncn_vault_ticket_data);
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
     *ncn_vault_ticket = NcnVaultTicket::new(
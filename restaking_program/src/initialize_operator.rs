@@ -1,12 +1,13 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    config::{Config, ConfigExt},
+    operator::Operator,
+    MAX_FEE_BPS,
 };
-use jito_restaking_core::{config::Config, operator::Operator, MAX_FEE_BPS};
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeOperatorAccounts};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -19,14 +20,14 @@ pub fn process_initialize_operator(
     accounts: &[AccountInfo],
     operator_fee_bps: u16,
 ) -> ProgramResult {
-    let [config, operator, admin, base, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, true)?;
-    load_system_account(operator, true)?;
-    load_signer(admin, true)?;
-    load_signer(base, false)?;
-    load_system_program(system_program)?;
+    let InitializeOperatorAccounts {
+        config,
+        operator,
+        admin,
+        base,
+        system_program,
+    } = InitializeOperatorAccounts::try_from_accounts(program_id, accounts)?;
+    load_system_account(operator, "operator", true)?;
 
     // The Operator shall be at the canonical PDA
     let (operator_pubkey, operator_bump, mut operator_seed) =
@@ -59,7 +60,7 @@ pub fn process_initialize_operator(
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
     let mut operator_data = operator.try_borrow_mut_data()?;
-    operator_data[0] = Operator::DISCRIMINATOR;
+    Operator::write_header(&mut operator_data);
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
 
     *operator = Operator::new(
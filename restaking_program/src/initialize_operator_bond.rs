@@ -0,0 +1,74 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{
+    accounts::AccountsContext,
+    create_account,
+    loader::{load_system_account, load_token_mint},
+};
+use jito_restaking_core::{
+    operator::{Operator, OperatorExt},
+    operator_bond::OperatorBond,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeOperatorBondAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// The operator admin can create the bond account to an NCN for a given mint. The bonded tokens
+/// are held in the associated token account of the bond, and only the NCN can slash them.
+///
+/// [`crate::RestakingInstruction::InitializeOperatorBond`]
+pub fn process_initialize_operator_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let InitializeOperatorBondAccounts {
+        config: _,
+        ncn,
+        operator: operator_info,
+        operator_bond,
+        mint,
+        admin,
+        payer,
+        system_program,
+    } = InitializeOperatorBondAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_system_account(operator_bond, "operator_bond", true)?;
+    load_token_mint(mint, "mint")?;
+
+    // The OperatorBond shall be at the canonical PDA
+    let (operator_bond_pubkey, operator_bond_bump, mut operator_bond_seeds) =
+        OperatorBond::find_program_address(program_id, ncn.key, operator_info.key, mint.key);
+    operator_bond_seeds.push(vec![operator_bond_bump]);
+    if operator_bond_pubkey.ne(operator_bond.key) {
+        msg!("OperatorBond is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The operator admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_admin(admin.key)?;
+
+    msg!("Initializing OperatorBond at address {}", operator_bond.key);
+    create_account(
+        payer,
+        operator_bond,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<OperatorBond>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &operator_bond_seeds,
+    )?;
+
+    let mut operator_bond_data = operator_bond.try_borrow_mut_data()?;
+    OperatorBond::write_header(&mut operator_bond_data);
+    let operator_bond = OperatorBond::try_from_slice_unchecked_mut(&mut operator_bond_data)?;
+    *operator_bond = OperatorBond::new(*ncn.key, *operator_info.key, *mint.key, operator_bond_bump);
+
+    Ok(())
+}
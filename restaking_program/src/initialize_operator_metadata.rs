@@ -0,0 +1,76 @@
+use std::mem::size_of;
+
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::{
+    metadata::{Metadata, MetadataExt},
+    operator::{Operator, OperatorExt},
+    operator_metadata::OperatorMetadata,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::InitializeOperatorMetadataAccounts};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// The operator metadata admin can create the metadata account describing the operator.
+///
+/// [`crate::RestakingInstruction::InitializeOperatorMetadata`]
+pub fn process_initialize_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: Metadata,
+) -> ProgramResult {
+    let InitializeOperatorMetadataAccounts {
+        config: _,
+        operator: operator_info,
+        operator_metadata,
+        metadata_admin,
+        payer,
+        system_program,
+    } = InitializeOperatorMetadataAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_system_account(operator_metadata, "operator_metadata", true)?;
+
+    // The OperatorMetadata shall be at the canonical PDA
+    let (operator_metadata_pubkey, operator_metadata_bump, mut operator_metadata_seeds) =
+        OperatorMetadata::find_program_address(program_id, operator_info.key);
+    operator_metadata_seeds.push(vec![operator_metadata_bump]);
+    if operator_metadata_pubkey.ne(operator_metadata.key) {
+        msg!("OperatorMetadata is not at the correct PDA");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // The operator metadata admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_metadata_admin(metadata_admin.key)?;
+
+    // Each string field shall be UTF-8 followed only by zero padding
+    metadata.check_valid()?;
+
+    msg!(
+        "Initializing OperatorMetadata at address {}",
+        operator_metadata.key
+    );
+    create_account(
+        payer,
+        operator_metadata,
+        system_program,
+        program_id,
+        &Rent::get()?,
+        8_u64
+            .checked_add(size_of::<OperatorMetadata>() as u64)
+            .ok_or(RestakingError::ArithmeticOverflow)?,
+        &operator_metadata_seeds,
+    )?;
+
+    let mut operator_metadata_data = operator_metadata.try_borrow_mut_data()?;
+    OperatorMetadata::write_header(&mut operator_metadata_data);
+    let operator_metadata =
+        OperatorMetadata::try_from_slice_unchecked_mut(&mut operator_metadata_data)?;
+    *operator_metadata =
+        OperatorMetadata::new(*operator_info.key, metadata, operator_metadata_bump);
+
+    Ok(())
+}
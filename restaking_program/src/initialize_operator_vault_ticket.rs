@@ -1,15 +1,14 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_restaking_core::{
-    config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
+    operator::{Operator, OperatorExt},
+    operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::{
+    error::RestakingError, instruction::InitializeOperatorVaultTicketAccounts,
 };
-use jito_restaking_sdk::error::RestakingError;
-use jito_vault_core::vault::Vault;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -24,21 +23,21 @@ pub fn process_initialize_operator_vault_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, operator_info, vault, operator_vault_ticket_account, operator_vault_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeOperatorVaultTicketAccounts {
+        config: _,
+        operator: operator_info,
+        vault,
+        operator_vault_ticket: operator_vault_ticket_account,
+        admin: operator_vault_admin,
+        payer,
+        system_program,
+    } = InitializeOperatorVaultTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Operator::load(program_id, operator_info, true)?;
-    let config_data = config.data.borrow();
-    let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(&config.vault_program, vault, false)?;
-    load_system_account(operator_vault_ticket_account, true)?;
-    load_signer(operator_vault_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(
+        operator_vault_ticket_account,
+        "operator_vault_ticket_account",
+        true,
+    )?;
 
     // The OperatorVaultTicket shall be at the canonical PDA
     let (operator_vault_ticket_pubkey, operator_vault_ticket_bump, mut operator_vault_ticket_seeds) =
@@ -76,7 +75,7 @@ pub fn process_initialize_operator_vault_ticket(
     )?;
     let mut operator_vault_ticket_account_data =
         operator_vault_ticket_account.try_borrow_mut_data()?;
-    operator_vault_ticket_account_data[0] = OperatorVaultTicket::DISCRIMINATOR;
+    OperatorVaultTicket::write_header(&mut operator_vault_ticket_account_data);
     let operator_vault_ticket =
         OperatorVaultTicket::try_from_slice_unchecked_mut(&mut operator_vault_ticket_account_data)?;
     *operator_vault_ticket = OperatorVaultTicket::new(
@@ -3,28 +3,45 @@ mod cooldown_ncn_vault_ticket;
 mod cooldown_operator_vault_ticket;
 mod initialize_config;
 mod initialize_ncn;
+mod initialize_ncn_metadata;
 mod initialize_ncn_operator_state;
+mod initialize_ncn_policy;
 mod initialize_ncn_vault_slasher_ticket;
 mod initialize_ncn_vault_ticket;
 mod initialize_operator;
+mod initialize_operator_bond;
+mod initialize_operator_metadata;
 mod initialize_operator_vault_ticket;
+mod migrate_account;
 mod ncn_cooldown_operator;
 mod ncn_delegate_token_account;
+mod ncn_jail_operator;
 mod ncn_set_admin;
+mod ncn_set_operator_bond_requirement;
+mod ncn_set_policy;
 mod ncn_set_secondary_admin;
+mod ncn_set_transition_epochs;
+mod ncn_unjail_operator;
 mod ncn_warmup_operator;
 mod operator_cooldown_ncn;
 mod operator_delegate_token_account;
+mod operator_deposit_bond;
 mod operator_set_admin;
 mod operator_set_fee;
 mod operator_set_secondary_admin;
+mod operator_unbond;
 mod operator_warmup_ncn;
+mod operator_withdraw_bond;
 mod set_config_admin;
+mod slash_operator_bond;
+mod update_ncn_metadata;
+mod update_operator_metadata;
 mod warmup_ncn_vault_slasher_ticket;
 mod warmup_ncn_vault_ticket;
 mod warmup_operator_vault_ticket;
 
 use borsh::BorshDeserialize;
+use jito_restaking_core::metadata::{Metadata, MetadataExt};
 use jito_restaking_sdk::instruction::RestakingInstruction;
 use operator_set_fee::process_operator_set_fee;
 use solana_program::{
@@ -39,20 +56,34 @@ use crate::{
     cooldown_ncn_vault_ticket::process_cooldown_ncn_vault_ticket,
     cooldown_operator_vault_ticket::process_cooldown_operator_vault_ticket,
     initialize_config::process_initialize_config, initialize_ncn::process_initialize_ncn,
+    initialize_ncn_metadata::process_initialize_ncn_metadata,
     initialize_ncn_operator_state::process_initialize_ncn_operator_state,
+    initialize_ncn_policy::process_initialize_ncn_policy,
     initialize_ncn_vault_slasher_ticket::process_initialize_ncn_vault_slasher_ticket,
     initialize_ncn_vault_ticket::process_initialize_ncn_vault_ticket,
     initialize_operator::process_initialize_operator,
+    initialize_operator_bond::process_initialize_operator_bond,
+    initialize_operator_metadata::process_initialize_operator_metadata,
     initialize_operator_vault_ticket::process_initialize_operator_vault_ticket,
-    ncn_cooldown_operator::process_ncn_cooldown_operator,
+    migrate_account::process_migrate_account, ncn_cooldown_operator::process_ncn_cooldown_operator,
     ncn_delegate_token_account::process_ncn_delegate_token_account,
-    ncn_set_admin::process_ncn_set_admin, ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_jail_operator::process_ncn_jail_operator, ncn_set_admin::process_ncn_set_admin,
+    ncn_set_operator_bond_requirement::process_ncn_set_operator_bond_requirement,
+    ncn_set_policy::process_ncn_set_policy,
+    ncn_set_secondary_admin::process_ncn_set_secondary_admin,
+    ncn_set_transition_epochs::process_ncn_set_transition_epochs,
+    ncn_unjail_operator::process_ncn_unjail_operator,
     ncn_warmup_operator::process_ncn_warmup_operator,
     operator_cooldown_ncn::process_operator_cooldown_ncn,
     operator_delegate_token_account::process_operator_delegate_token_account,
+    operator_deposit_bond::process_operator_deposit_bond,
     operator_set_admin::process_set_node_operator_admin,
     operator_set_secondary_admin::process_set_operator_secondary_admin,
-    operator_warmup_ncn::process_operator_warmup_ncn, set_config_admin::process_set_config_admin,
+    operator_unbond::process_operator_unbond, operator_warmup_ncn::process_operator_warmup_ncn,
+    operator_withdraw_bond::process_operator_withdraw_bond,
+    set_config_admin::process_set_config_admin, slash_operator_bond::process_slash_operator_bond,
+    update_ncn_metadata::process_update_ncn_metadata,
+    update_operator_metadata::process_update_operator_metadata,
     warmup_ncn_vault_slasher_ticket::process_warmup_ncn_vault_slasher_ticket,
     warmup_ncn_vault_ticket::process_warmup_ncn_vault_ticket,
     warmup_operator_vault_ticket::process_warmup_operator_vault_ticket,
@@ -193,5 +224,112 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigAdmin");
             process_set_config_admin(program_id, accounts)
         }
+        RestakingInstruction::InitializeNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        } => {
+            msg!("Instruction: InitializeNcnMetadata");
+            let metadata = Metadata::new(&name, &website, &logo_uri, &contact, description_hash)?;
+            process_initialize_ncn_metadata(program_id, accounts, metadata)
+        }
+        RestakingInstruction::UpdateNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        } => {
+            msg!("Instruction: UpdateNcnMetadata");
+            let metadata = Metadata::new(&name, &website, &logo_uri, &contact, description_hash)?;
+            process_update_ncn_metadata(program_id, accounts, metadata)
+        }
+        RestakingInstruction::InitializeOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        } => {
+            msg!("Instruction: InitializeOperatorMetadata");
+            let metadata = Metadata::new(&name, &website, &logo_uri, &contact, description_hash)?;
+            process_initialize_operator_metadata(program_id, accounts, metadata)
+        }
+        RestakingInstruction::UpdateOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        } => {
+            msg!("Instruction: UpdateOperatorMetadata");
+            let metadata = Metadata::new(&name, &website, &logo_uri, &contact, description_hash)?;
+            process_update_operator_metadata(program_id, accounts, metadata)
+        }
+        RestakingInstruction::NcnSetOperatorBondRequirement { min_operator_bond } => {
+            msg!("Instruction: NcnSetOperatorBondRequirement");
+            process_ncn_set_operator_bond_requirement(program_id, accounts, min_operator_bond)
+        }
+        RestakingInstruction::InitializeOperatorBond => {
+            msg!("Instruction: InitializeOperatorBond");
+            process_initialize_operator_bond(program_id, accounts)
+        }
+        RestakingInstruction::OperatorDepositBond { amount } => {
+            msg!("Instruction: OperatorDepositBond");
+            process_operator_deposit_bond(program_id, accounts, amount)
+        }
+        RestakingInstruction::SlashOperatorBond { amount } => {
+            msg!("Instruction: SlashOperatorBond");
+            process_slash_operator_bond(program_id, accounts, amount)
+        }
+        RestakingInstruction::InitializeNcnPolicy => {
+            msg!("Instruction: InitializeNcnPolicy");
+            process_initialize_ncn_policy(program_id, accounts)
+        }
+        RestakingInstruction::NcnSetPolicy {
+            permissionless_admission,
+            min_operator_stake,
+            max_operators,
+            min_operator_ticket_age,
+        } => {
+            msg!("Instruction: NcnSetPolicy");
+            process_ncn_set_policy(
+                program_id,
+                accounts,
+                permissionless_admission,
+                min_operator_stake,
+                max_operators,
+                min_operator_ticket_age,
+            )
+        }
+        RestakingInstruction::NcnJailOperator { jailed_until_epoch } => {
+            msg!("Instruction: NcnJailOperator");
+            process_ncn_jail_operator(program_id, accounts, jailed_until_epoch)
+        }
+        RestakingInstruction::NcnUnjailOperator => {
+            msg!("Instruction: NcnUnjailOperator");
+            process_ncn_unjail_operator(program_id, accounts)
+        }
+        RestakingInstruction::NcnSetTransitionEpochs {
+            warmup_epochs,
+            cooldown_epochs,
+        } => {
+            msg!("Instruction: NcnSetTransitionEpochs");
+            process_ncn_set_transition_epochs(program_id, accounts, warmup_epochs, cooldown_epochs)
+        }
+        RestakingInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
+        RestakingInstruction::OperatorUnbond { amount } => {
+            msg!("Instruction: OperatorUnbond");
+            process_operator_unbond(program_id, accounts, amount)
+        }
+        RestakingInstruction::OperatorWithdrawBond => {
+            msg!("Instruction: OperatorWithdrawBond");
+            process_operator_withdraw_bond(program_id, accounts)
+        }
     }
 }
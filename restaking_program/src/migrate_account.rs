@@ -0,0 +1,65 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{accounts::AccountsContext, migrate_account};
+use jito_restaking_core::{
+    config::Config, ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+    ncn_policy::NcnPolicy, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+    ncn_vault_ticket::NcnVaultTicket, operator::Operator, operator_bond::OperatorBond,
+    operator_metadata::OperatorMetadata, operator_vault_ticket::OperatorVaultTicket,
+};
+use jito_restaking_sdk::instruction::MigrateAccountAccounts;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Migrates any restaking program account to its current layout version, dispatching on the
+/// account discriminator. Accounts whose current layout is larger are grown first, with the
+/// payer funding the additional rent.
+///
+/// [`crate::RestakingInstruction::MigrateAccount`]
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let MigrateAccountAccounts {
+        account,
+        payer,
+        system_program: _,
+    } = MigrateAccountAccounts::try_from_accounts(program_id, accounts)?;
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let discriminator = account.try_borrow_data()?.first().copied();
+    let rent = Rent::get()?;
+    match discriminator {
+        Some(Config::DISCRIMINATOR) => migrate_account::<Config>(account, payer, &rent),
+        Some(Ncn::DISCRIMINATOR) => migrate_account::<Ncn>(account, payer, &rent),
+        Some(Operator::DISCRIMINATOR) => migrate_account::<Operator>(account, payer, &rent),
+        Some(NcnOperatorState::DISCRIMINATOR) => {
+            migrate_account::<NcnOperatorState>(account, payer, &rent)
+        }
+        Some(OperatorVaultTicket::DISCRIMINATOR) => {
+            migrate_account::<OperatorVaultTicket>(account, payer, &rent)
+        }
+        Some(NcnVaultTicket::DISCRIMINATOR) => {
+            migrate_account::<NcnVaultTicket>(account, payer, &rent)
+        }
+        Some(NcnVaultSlasherTicket::DISCRIMINATOR) => {
+            migrate_account::<NcnVaultSlasherTicket>(account, payer, &rent)
+        }
+        Some(NcnMetadata::DISCRIMINATOR) => migrate_account::<NcnMetadata>(account, payer, &rent),
+        Some(OperatorMetadata::DISCRIMINATOR) => {
+            migrate_account::<OperatorMetadata>(account, payer, &rent)
+        }
+        Some(OperatorBond::DISCRIMINATOR) => migrate_account::<OperatorBond>(account, payer, &rent),
+        Some(NcnPolicy::DISCRIMINATOR) => migrate_account::<NcnPolicy>(account, payer, &rent),
+        _ => {
+            msg!("Account discriminator is invalid");
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
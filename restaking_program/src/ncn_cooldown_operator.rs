@@ -1,30 +1,37 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+    config::Config,
+    ncn::Ncn,
+    ncn_operator_state::NcnOperatorState,
+    ncn_policy::{NcnPolicy, NcnPolicyExt},
 };
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::NcnCooldownOperatorAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// The NCN admin can remove a node operator from the NCN.
 /// This method is permissioned to the NCN admin.
+/// If the operator was admitted under the NCN policy, the policy shall be passed to release it.
 /// [`crate::RestakingInstruction::NcnCooldownOperator`]
 pub fn process_ncn_cooldown_operator(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let NcnCooldownOperatorAccounts {
+        config,
+        ncn,
+        operator: _,
+        ncn_operator_state,
+        admin: ncn_operator_admin,
+        ncn_policy: ncn_policy_info,
+    } = NcnCooldownOperatorAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn, false)?;
-    Operator::load(program_id, operator, false)?;
-    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
-    load_signer(ncn_operator_admin, false)?;
+    if let Some(ncn_policy_info) = ncn_policy_info {
+        NcnPolicy::load(program_id, ncn_policy_info, ncn.info(), true)?;
+    }
 
     // The NCN operator admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
@@ -42,12 +49,28 @@ pub fn process_ncn_cooldown_operator(
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_ticket
         .ncn_opt_in_state
-        .deactivate(Clock::get()?.slot, config.epoch_length())?
+        .deactivate_with_transition_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.transition_epochs(),
+        )?
     {
         msg!("NCN is not ready to deactivate operator");
         return Err(RestakingError::NcnCooldownOperatorFailed.into());
     }
 
+    // An operator admitted under the NCN policy is released from it
+    if ncn_operator_ticket.policy_admitted() {
+        let Some(ncn_policy_info) = ncn_policy_info else {
+            msg!("NCN policy is required");
+            return Err(RestakingError::NcnPolicyMissing.into());
+        };
+        let mut ncn_policy_data = ncn_policy_info.data.borrow_mut();
+        let ncn_policy = NcnPolicy::try_from_slice_unchecked_mut(&mut ncn_policy_data)?;
+        ncn_policy.release_operator()?;
+        ncn_operator_ticket.set_policy_admitted(false);
+    }
+
     msg!(
         "COOLDOWN NCN_OPERATOR_STATE: NCN {} deactivating Operator {}",
         ncn_operator_ticket.ncn,
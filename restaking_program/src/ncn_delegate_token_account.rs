@@ -1,6 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
-use jito_restaking_core::ncn::Ncn;
+use jito_jsm_core::{
+    accounts::AccountsContext,
+    loader::{load_token_account, load_token_mint},
+};
+use jito_restaking_core::ncn::{Ncn, NcnExt};
+use jito_restaking_sdk::instruction::NcnDelegateTokenAccountAccounts;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
@@ -21,23 +25,23 @@ pub fn process_ncn_delegate_token_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [ncn_info, delegate_admin, token_mint, token_account, delegate, token_program_info] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let NcnDelegateTokenAccountAccounts {
+        ncn: ncn_info,
+        delegate_admin,
+        token_mint,
+        token_account,
+        delegate,
+        token_program: token_program_info,
+    } = NcnDelegateTokenAccountAccounts::try_from_accounts(program_id, accounts)?;
 
-    Ncn::load(program_id, ncn_info, false)?;
-    load_signer(delegate_admin, false)?;
-    load_token_mint(token_mint)?;
+    load_token_mint(token_mint, "token_mint")?;
     load_token_account(
         token_account,
+        "token_account",
         ncn_info.key,
         token_mint.key,
         token_program_info,
     )?;
-    // Only the original spl token program is allowed
-    load_token_program(token_program_info)?;
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
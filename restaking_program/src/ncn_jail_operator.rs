@@ -0,0 +1,62 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, get_epoch};
+use jito_restaking_core::{
+    config::Config,
+    ncn::{Ncn, NcnExt},
+    ncn_operator_state::NcnOperatorState,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::NcnJailOperatorAccounts};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The NCN admin or slasher admin can jail a misbehaving operator until the start of a future
+/// epoch. The jail expires on its own, and jailing again overrides the jailed-until epoch.
+///
+/// [`crate::RestakingInstruction::NcnJailOperator`]
+pub fn process_ncn_jail_operator(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    jailed_until_epoch: u64,
+) -> ProgramResult {
+    let NcnJailOperatorAccounts {
+        config,
+        ncn,
+        operator: _,
+        ncn_operator_state,
+        admin,
+    } = NcnJailOperatorAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The NCN admin or slasher admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_jail_admin(admin.key)?;
+
+    // The operator shall be jailed until a future epoch
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let current_epoch = get_epoch(Clock::get()?.slot, config.epoch_length())?;
+    if jailed_until_epoch <= current_epoch {
+        msg!(
+            "Jailed until epoch {} must be after the current epoch {}",
+            jailed_until_epoch,
+            current_epoch
+        );
+        return Err(RestakingError::NcnOperatorJailEpochInvalid.into());
+    }
+
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    ncn_operator_state.jail(jailed_until_epoch);
+
+    msg!(
+        "JAIL NCN_OPERATOR_STATE: NCN {} jailed Operator {} until epoch {}",
+        ncn_operator_state.ncn,
+        ncn_operator_state.operator,
+        jailed_until_epoch
+    );
+
+    Ok(())
+}
@@ -1,20 +1,15 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::ncn::Ncn;
-use jito_restaking_sdk::error::RestakingError;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::ncn::{Ncn, NcnExt};
+use jito_restaking_sdk::{error::RestakingError, instruction::NcnSetAdminAccounts};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 pub fn process_ncn_set_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [ncn, old_admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Ncn::load(program_id, ncn, true)?;
-    load_signer(old_admin, false)?;
-    load_signer(new_admin, false)?;
+    let NcnSetAdminAccounts {
+        ncn,
+        old_admin,
+        new_admin,
+    } = NcnSetAdminAccounts::try_from_accounts(program_id, accounts)?;
 
     // The Ncn admin shall be the signer of the transaction
     let mut ncn_data = ncn.data.borrow_mut();
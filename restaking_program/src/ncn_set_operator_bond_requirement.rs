@@ -0,0 +1,40 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
+use jito_restaking_core::ncn::{Ncn, NcnExt};
+use jito_restaking_sdk::instruction::NcnSetOperatorBondRequirementAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// The NCN admin can require operators to bond a minimum amount of tokens before the NCN
+/// can warm them up. Setting the minimum to zero disables the requirement.
+///
+/// [`crate::RestakingInstruction::NcnSetOperatorBondRequirement`]
+pub fn process_ncn_set_operator_bond_requirement(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_operator_bond: u64,
+) -> ProgramResult {
+    let NcnSetOperatorBondRequirementAccounts {
+        config: _,
+        ncn: ncn_info,
+        operator_bond_mint,
+        admin,
+    } = NcnSetOperatorBondRequirementAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_token_mint(operator_bond_mint, "operator_bond_mint")?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn_info.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    ncn.set_operator_bond_requirement(*operator_bond_mint.key, min_operator_bond);
+
+    msg!(
+        "NCN {} requires an operator bond of {} in mint {}",
+        ncn_info.key,
+        min_operator_bond,
+        operator_bond_mint.key
+    );
+
+    Ok(())
+}
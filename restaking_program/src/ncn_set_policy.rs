@@ -0,0 +1,63 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
+use jito_restaking_core::{
+    ncn::{Ncn, NcnExt},
+    ncn_policy::{NcnPolicy, NcnPolicyExt},
+};
+use jito_restaking_sdk::instruction::NcnSetPolicyAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// The NCN admin can set the rules of the NCN policy. The allowed vault supported mints are
+/// passed as remaining accounts, passing none allows any mint.
+///
+/// [`crate::RestakingInstruction::NcnSetPolicy`]
+pub fn process_ncn_set_policy(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    permissionless_admission: bool,
+    min_operator_stake: u64,
+    max_operators: u64,
+    min_operator_ticket_age: u64,
+) -> ProgramResult {
+    let NcnSetPolicyAccounts {
+        config: _,
+        ncn: ncn_info,
+        ncn_policy,
+        admin,
+        allowed_vault_mints,
+    } = NcnSetPolicyAccounts::try_from_accounts(program_id, accounts)?;
+
+    for mint in allowed_vault_mints {
+        load_token_mint(mint, "mint")?;
+    }
+
+    // The NCN admin shall be the signer of the transaction
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    let allowed_vault_mints: Vec<Pubkey> =
+        allowed_vault_mints.iter().map(|mint| *mint.key).collect();
+
+    let mut ncn_policy_data = ncn_policy.data.borrow_mut();
+    let ncn_policy = NcnPolicy::try_from_slice_unchecked_mut(&mut ncn_policy_data)?;
+    ncn_policy.set_policy(
+        permissionless_admission,
+        min_operator_stake,
+        max_operators,
+        min_operator_ticket_age,
+        &allowed_vault_mints,
+    )?;
+
+    msg!(
+        "NCN {} policy set: permissionless admission {}, min operator stake {}, max operators {}, min operator ticket age {}, {} allowed vault mints",
+        ncn_info.key,
+        permissionless_admission,
+        min_operator_stake,
+        max_operators,
+        min_operator_ticket_age,
+        allowed_vault_mints.len()
+    );
+
+    Ok(())
+}
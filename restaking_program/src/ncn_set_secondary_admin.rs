@@ -1,23 +1,22 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::ncn::Ncn;
-use jito_restaking_sdk::{error::RestakingError, instruction::NcnAdminRole};
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+use jito_restaking_sdk::{
+    error::RestakingError,
+    instruction::{NcnAdminRole, NcnSetSecondaryAdminAccounts},
 };
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 pub fn process_ncn_set_secondary_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     role: NcnAdminRole,
 ) -> ProgramResult {
-    let [ncn, admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Ncn::load(program_id, ncn, true)?;
-    load_signer(admin, false)?;
+    let NcnSetSecondaryAdminAccounts {
+        ncn,
+        admin,
+        new_admin,
+    } = NcnSetSecondaryAdminAccounts::try_from_accounts(program_id, accounts)?;
 
     // The Ncn admin shall be the signer of the transaction
     let mut ncn_data = ncn.data.borrow_mut();
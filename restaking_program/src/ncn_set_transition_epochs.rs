@@ -0,0 +1,39 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::ncn::{Ncn, NcnExt};
+use jito_restaking_sdk::instruction::NcnSetTransitionEpochsAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// The NCN admin can set how many full epochs its operator, vault and slasher relationships
+/// spend warming up and cooling down. The durations are applied to transitions started after
+/// the update.
+///
+/// [`crate::RestakingInstruction::NcnSetTransitionEpochs`]
+pub fn process_ncn_set_transition_epochs(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    warmup_epochs: u16,
+    cooldown_epochs: u16,
+) -> ProgramResult {
+    let NcnSetTransitionEpochsAccounts {
+        config: _,
+        ncn: ncn_info,
+        admin,
+    } = NcnSetTransitionEpochsAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The NCN admin shall be the signer of the transaction
+    let mut ncn_data = ncn_info.data.borrow_mut();
+    let ncn = Ncn::try_from_slice_unchecked_mut(&mut ncn_data)?;
+    ncn.check_admin(admin.key)?;
+
+    ncn.set_transition_epochs(warmup_epochs, cooldown_epochs)?;
+
+    msg!(
+        "NCN {} set warmup epochs to {} and cooldown epochs to {}",
+        ncn_info.key,
+        warmup_epochs,
+        cooldown_epochs
+    );
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    config::Config,
+    ncn::{Ncn, NcnExt},
+    ncn_operator_state::NcnOperatorState,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::NcnUnjailOperatorAccounts};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The NCN admin or slasher admin can release a jailed operator before its jail expires.
+///
+/// [`crate::RestakingInstruction::NcnUnjailOperator`]
+pub fn process_ncn_unjail_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let NcnUnjailOperatorAccounts {
+        config,
+        ncn,
+        operator: _,
+        ncn_operator_state,
+        admin,
+    } = NcnUnjailOperatorAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The NCN admin or slasher admin shall be the signer of the transaction
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_jail_admin(admin.key)?;
+
+    // The operator shall be jailed
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
+    let ncn_operator_state =
+        NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+    if !ncn_operator_state.is_jailed(Clock::get()?.slot, config.epoch_length())? {
+        msg!("Operator is not jailed");
+        return Err(RestakingError::NcnOperatorNotJailed.into());
+    }
+    ncn_operator_state.unjail();
+
+    msg!(
+        "UNJAIL NCN_OPERATOR_STATE: NCN {} released Operator {}",
+        ncn_operator_state.ncn,
+        ncn_operator_state.operator,
+    );
+
+    Ok(())
+}
@@ -1,42 +1,141 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::{
-    config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
+    config::Config,
+    ncn::{Ncn, NcnExt},
+    ncn_operator_state::NcnOperatorState,
+    ncn_policy::{NcnPolicy, NcnPolicyExt},
+    ncn_vault_ticket::NcnVaultTicket,
+    operator_bond::OperatorBond,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::NcnWarmupOperatorAccounts};
+use jito_vault_core::{
+    vault::{Vault, VaultExt},
+    vault_operator_delegation::VaultOperatorDelegation,
 };
-use jito_restaking_sdk::error::RestakingError;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
 };
 
+/// A jailed operator can't be warmed up until its jail ends.
+///
+/// If the NCN has an operator bond requirement, the operator bond in the NCN's bond mint shall be
+/// passed and hold at least the minimum bond.
+///
+/// If the NCN has a policy, the policy shall be passed and the operator shall satisfy it. With
+/// permissionless admission, any signer can warm up the operator. When the policy requires a
+/// minimum operator stake, the (vault, ncn_vault_ticket, vault_operator_delegation) groups
+/// delegating to the operator are passed as remaining accounts.
+///
 /// [`crate::RestakingInstruction::NcnWarmupOperator`]
 pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, operator, ncn_operator_state, ncn_operator_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn, false)?;
-    Operator::load(program_id, operator, false)?;
-    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
-    load_signer(ncn_operator_admin, false)?;
-
-    // The NCN operator admin shall be the signer of the transaction
-    let ncn_data = ncn.data.borrow();
+    let NcnWarmupOperatorAccounts {
+        config,
+        ncn: ncn_info,
+        operator,
+        ncn_operator_state,
+        admin: ncn_operator_admin,
+        operator_bond: operator_bond_info,
+        ncn_policy: ncn_policy_info,
+        vault_operator_delegations,
+    } = NcnWarmupOperatorAccounts::try_from_accounts(program_id, accounts)?;
+
+    if let Some(ncn_policy_info) = ncn_policy_info {
+        NcnPolicy::load(program_id, ncn_policy_info, ncn_info.info(), true)?;
+    }
+
+    let ncn_data = ncn_info.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.operator_admin.ne(ncn_operator_admin.key) {
+
+    // The NCN policy shall be passed if the NCN has one
+    let mut ncn_policy_data = match ncn_policy_info {
+        Some(ncn_policy_info) if ncn.has_policy() => Some(ncn_policy_info.data.borrow_mut()),
+        None if ncn.has_policy() => {
+            msg!("NCN policy is required");
+            return Err(RestakingError::NcnPolicyMissing.into());
+        }
+        _ => None,
+    };
+    let mut ncn_policy = ncn_policy_data
+        .as_mut()
+        .map(|ncn_policy_data| NcnPolicy::try_from_slice_unchecked_mut(ncn_policy_data))
+        .transpose()?;
+
+    // The NCN operator admin shall be the signer of the transaction, unless the NCN policy allows
+    // permissionless admission
+    let permissionless_admission = ncn_policy
+        .as_ref()
+        .is_some_and(|ncn_policy| ncn_policy.permissionless_admission());
+    if ncn.operator_admin.ne(ncn_operator_admin.key) && !permissionless_admission {
         msg!("Invalid operator admin for NCN");
         return Err(RestakingError::NcnOperatorAdminInvalid.into());
     }
 
-    // The NcnOperatorTicket shall be inactive before it can warmed up
+    // The operator shall meet the NCN's operator bond requirement, if any
+    if ncn.min_operator_bond() > 0 {
+        let operator_bond = match operator_bond_info {
+            Some(operator_bond_info) => {
+                OperatorBond::load(
+                    program_id,
+                    operator_bond_info,
+                    ncn_info.info(),
+                    operator.info(),
+                    &ncn.operator_bond_mint,
+                    false,
+                )?;
+                Some(*OperatorBond::try_from_slice_unchecked(
+                    &operator_bond_info.data.borrow(),
+                )?)
+            }
+            None => None,
+        };
+        ncn.check_operator_bond(operator_bond.as_ref())?;
+    }
+
     let config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked(&config_data)?;
+    let slot = Clock::get()?.slot;
     let mut ncn_operator_state_data = ncn_operator_state.data.borrow_mut();
     let ncn_operator_state =
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
+
+    // A jailed operator shall not be warmed up until the jail ends
+    if ncn_operator_state.is_jailed(slot, config.epoch_length())? {
+        msg!(
+            "Operator is jailed until epoch {}",
+            ncn_operator_state.jailed_until_epoch()
+        );
+        return Err(RestakingError::NcnOperatorJailed.into());
+    }
+
+    // The operator shall satisfy the NCN policy, if any
+    if let Some(ncn_policy) = ncn_policy.as_mut() {
+        ncn_policy.check_operator_ticket_age(
+            &ncn_operator_state.operator_opt_in_state,
+            slot,
+            config.epoch_length(),
+        )?;
+        if ncn_policy.min_operator_stake() > 0 {
+            let operator_stake = operator_stake(
+                program_id,
+                config,
+                ncn_info.info(),
+                operator.info(),
+                ncn_policy,
+                vault_operator_delegations,
+                slot,
+            )?;
+            ncn_policy.check_operator_stake(operator_stake)?;
+        }
+        ncn_policy.admit_operator()?;
+        ncn_operator_state.set_policy_admitted(true);
+    }
+
+    // The NcnOperatorTicket shall be inactive before it can warmed up
     if !ncn_operator_state
         .ncn_opt_in_state
-        .activate(Clock::get()?.slot, config.epoch_length())?
+        .activate_with_transition_epochs(slot, config.epoch_length(), ncn.transition_epochs())?
     {
         msg!("NCN is not ready to be warmup operator");
         return Err(RestakingError::NcnWarmupOperatorFailed.into());
@@ -50,3 +149,86 @@ pub fn process_ncn_warmup_operator(program_id: &Pubkey, accounts: &[AccountInfo]
 
     Ok(())
 }
+
+/// Sums the stake delegated to the operator by vaults with a supported mint allowed by the NCN
+/// policy. Each vault is only counted once, shall have an active NcnVaultTicket with the NCN and
+/// shall be fully updated for the current epoch, so its delegations are current.
+fn operator_stake(
+    program_id: &Pubkey,
+    config: &Config,
+    ncn: &AccountInfo,
+    operator: &AccountInfo,
+    ncn_policy: &NcnPolicy,
+    vault_operator_delegations: &[AccountInfo],
+    slot: u64,
+) -> Result<u64, ProgramError> {
+    let (vault_program, epoch_length) = (&config.vault_program, config.epoch_length());
+    if vault_operator_delegations.len() % 3 != 0 {
+        msg!(
+            "Vault operator delegations shall be passed as (vault, ncn_vault_ticket, \
+             vault_operator_delegation) groups"
+        );
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let groups = vault_operator_delegations.chunks_exact(3);
+    let mut vaults = Vec::with_capacity(groups.len());
+    let mut operator_stake: u64 = 0;
+    for accounts in groups {
+        let (vault_info, ncn_vault_ticket, vault_operator_delegation) =
+            (&accounts[0], &accounts[1], &accounts[2]);
+        Vault::load(vault_program, vault_info, false)?;
+        NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault_info, false)?;
+        VaultOperatorDelegation::load(
+            vault_program,
+            vault_operator_delegation,
+            vault_info,
+            operator,
+            false,
+        )?;
+        if vaults.contains(vault_info.key) {
+            msg!("Vault {} is passed more than once", vault_info.key);
+            return Err(ProgramError::InvalidAccountData);
+        }
+        vaults.push(*vault_info.key);
+
+        let vault_data = vault_info.data.borrow();
+        let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+        if !ncn_policy.is_vault_mint_allowed(&vault.supported_mint) {
+            msg!(
+                "Vault {} supported mint is not allowed by the NCN policy, skipping",
+                vault_info.key
+            );
+            continue;
+        }
+
+        // The vault shall back the NCN
+        let ncn_vault_ticket_data = ncn_vault_ticket.data.borrow();
+        let ncn_vault_ticket = NcnVaultTicket::try_from_slice_unchecked(&ncn_vault_ticket_data)?;
+        if !ncn_vault_ticket.state.is_active(slot, epoch_length)? {
+            msg!(
+                "NCN vault ticket for vault {} is not active",
+                vault_info.key
+            );
+            return Err(RestakingError::NcnVaultTicketInactive.into());
+        }
+
+        // The vault's delegations shall be current
+        if vault.is_update_needed(slot, epoch_length)? {
+            msg!(
+                "Vault {} is not updated for the current epoch",
+                vault_info.key
+            );
+            return Err(RestakingError::NcnPolicyVaultUpdateNeeded.into());
+        }
+
+        let vault_operator_delegation_data = vault_operator_delegation.data.borrow();
+        let vault_operator_delegation =
+            VaultOperatorDelegation::try_from_slice_unchecked(&vault_operator_delegation_data)?;
+        operator_stake = operator_stake
+            .checked_add(vault_operator_delegation.delegation_state.staked_amount())
+            .ok_or(RestakingError::ArithmeticOverflow)?;
+    }
+
+    Ok(operator_stake)
+}
@@ -1,6 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
-use jito_restaking_core::operator::Operator;
+use jito_jsm_core::{
+    accounts::AccountsContext,
+    loader::{load_token_account, load_token_mint},
+};
+use jito_restaking_core::operator::{Operator, OperatorExt};
+use jito_restaking_sdk::instruction::OperatorDelegateTokenAccountAccounts;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
@@ -22,23 +26,23 @@ pub fn process_operator_delegate_token_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [operator_info, delegate_admin, token_mint, token_account, delegate, token_program_info] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let OperatorDelegateTokenAccountAccounts {
+        operator: operator_info,
+        delegate_admin,
+        token_mint,
+        token_account,
+        delegate,
+        token_program: token_program_info,
+    } = OperatorDelegateTokenAccountAccounts::try_from_accounts(program_id, accounts)?;
 
-    Operator::load(program_id, operator_info, false)?;
-    load_signer(delegate_admin, false)?;
-    load_token_mint(token_mint)?;
+    load_token_mint(token_mint, "token_mint")?;
     load_token_account(
         token_account,
+        "token_account",
         operator_info.key,
         token_mint.key,
         token_program_info,
     )?;
-    // Only the original spl token program is allowed
-    load_token_program(token_program_info)?;
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
@@ -0,0 +1,70 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
+use jito_restaking_core::{
+    operator::{Operator, OperatorExt},
+    operator_bond::{OperatorBond, OperatorBondExt},
+};
+use jito_restaking_sdk::instruction::OperatorDepositBondAccounts;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke, pubkey::Pubkey,
+};
+use spl_token::instruction::transfer;
+
+/// The operator admin deposits tokens into the operator bond. The tokens are transferred to the
+/// associated token account of the bond.
+///
+/// [`crate::RestakingInstruction::OperatorDepositBond`]
+pub fn process_operator_deposit_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let OperatorDepositBondAccounts {
+        config: _,
+        ncn,
+        operator: operator_info,
+        operator_bond: operator_bond_info,
+        mint,
+        admin,
+        admin_token_account,
+        operator_bond_token_account,
+        token_program: _,
+    } = OperatorDepositBondAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_token_mint(mint, "mint")?;
+
+    // The operator admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_admin(admin.key)?;
+
+    let mut operator_bond_data = operator_bond_info.data.borrow_mut();
+    let operator_bond = OperatorBond::try_from_slice_unchecked_mut(&mut operator_bond_data)?;
+    operator_bond.deposit(amount)?;
+
+    invoke(
+        &transfer(
+            &spl_token::id(),
+            admin_token_account.key,
+            operator_bond_token_account.key,
+            admin.key,
+            &[],
+            amount,
+        )?,
+        &[
+            admin_token_account.clone(),
+            operator_bond_token_account.clone(),
+            admin.clone(),
+        ],
+    )?;
+
+    msg!(
+        "Operator {} bonded {} tokens to NCN {}, total bond {}",
+        operator_info.key,
+        amount,
+        ncn.key,
+        operator_bond.amount()
+    );
+
+    Ok(())
+}
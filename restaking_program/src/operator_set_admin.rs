@@ -1,10 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::operator::{Operator, OperatorExt};
+use jito_restaking_sdk::instruction::OperatorSetAdminAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 /// The node operator admin can set a new admin for the node operator.
 /// This method is permissioned to the node operator admin and both the old and new admins must sign.
@@ -14,13 +12,11 @@ pub fn process_set_node_operator_admin(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [operator, old_admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Operator::load(program_id, operator, true)?;
-    load_signer(old_admin, false)?;
-    load_signer(new_admin, false)?;
+    let OperatorSetAdminAccounts {
+        operator,
+        old_admin,
+        new_admin,
+    } = OperatorSetAdminAccounts::try_from_accounts(program_id, accounts)?;
 
     // The Operator admin shall be the signer of the transaction
     let mut operator_data = operator.data.borrow_mut();
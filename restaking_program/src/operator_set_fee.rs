@@ -1,11 +1,11 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::{operator::Operator, MAX_FEE_BPS};
-use jito_restaking_sdk::error::RestakingError;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    operator::{Operator, OperatorExt},
+    MAX_FEE_BPS,
 };
+use jito_restaking_sdk::{error::RestakingError, instruction::OperatorSetFeeAccounts};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 /// The node operator admin can set a new fee. This method is permissioned to the node operator admin.
 ///
@@ -15,12 +15,11 @@ pub fn process_operator_set_fee(
     accounts: &[AccountInfo],
     new_fee_bps: u16,
 ) -> ProgramResult {
-    let [_config, operator_account, admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Operator::load(program_id, operator_account, true)?;
-    load_signer(admin, false)?;
+    let OperatorSetFeeAccounts {
+        config: _,
+        operator: operator_account,
+        admin,
+    } = OperatorSetFeeAccounts::try_from_accounts(program_id, accounts)?;
 
     let mut operator_data = operator_account.try_borrow_mut_data()?;
     let operator = Operator::try_from_slice_unchecked_mut(&mut operator_data)?;
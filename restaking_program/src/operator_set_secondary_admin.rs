@@ -1,11 +1,11 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::operator::Operator;
-use jito_restaking_sdk::{error::RestakingError, instruction::OperatorAdminRole};
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
+use jito_restaking_sdk::{
+    error::RestakingError,
+    instruction::{OperatorAdminRole, OperatorSetSecondaryAdminAccounts},
 };
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 /// The node operator admin can set a new voter for the node operator.
 /// This method is permissioned to the node operator admin.
@@ -16,11 +16,11 @@ pub fn process_set_operator_secondary_admin(
     accounts: &[AccountInfo],
     role: OperatorAdminRole,
 ) -> ProgramResult {
-    let [operator, admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Operator::load(program_id, operator, true)?;
-    load_signer(admin, false)?;
+    let OperatorSetSecondaryAdminAccounts {
+        operator,
+        admin,
+        new_admin,
+    } = OperatorSetSecondaryAdminAccounts::try_from_accounts(program_id, accounts)?;
 
     // The Operator admin shall be the signer of the transaction
     let mut operator_data = operator.data.borrow_mut();
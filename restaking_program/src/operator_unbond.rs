@@ -0,0 +1,57 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, get_epoch};
+use jito_restaking_core::{
+    config::Config,
+    ncn::Ncn,
+    operator::{Operator, OperatorExt},
+    operator_bond::{OperatorBond, OperatorBondExt},
+};
+use jito_restaking_sdk::instruction::OperatorUnbondAccounts;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+/// The operator admin unbonds tokens from the operator bond. The tokens cool down for the NCN's
+/// cooldown epochs before they can be withdrawn, and the NCN can slash them until then.
+///
+/// [`crate::RestakingInstruction::OperatorUnbond`]
+pub fn process_operator_unbond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let OperatorUnbondAccounts {
+        config,
+        ncn: ncn_info,
+        operator: operator_info,
+        operator_bond: operator_bond_info,
+        mint: _,
+        admin,
+    } = OperatorUnbondAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The operator admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_admin(admin.key)?;
+
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    let epoch = get_epoch(Clock::get()?.slot, config.epoch_length())?;
+    let mut operator_bond_data = operator_bond_info.data.borrow_mut();
+    let operator_bond = OperatorBond::try_from_slice_unchecked_mut(&mut operator_bond_data)?;
+    operator_bond.unbond(amount, epoch, ncn.transition_epochs().cooldown_epochs())?;
+
+    msg!(
+        "Operator {} unbonded {} tokens from NCN {}, {} unbonding until epoch {}",
+        operator_info.key,
+        amount,
+        ncn_info.key,
+        operator_bond.unbonding_amount(),
+        operator_bond.withdrawable_epoch()
+    );
+
+    Ok(())
+}
@@ -1,24 +1,26 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::{
     config::Config, ncn::Ncn, ncn_operator_state::NcnOperatorState, operator::Operator,
 };
-use jito_restaking_sdk::error::RestakingError;
+use jito_restaking_sdk::{error::RestakingError, instruction::OperatorWarmupNcnAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// [`crate::RestakingInstruction::OperatorWarmupNcn`]
 pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, ncn, operator, ncn_operator_state, operator_ncn_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Operator::load(program_id, operator, false)?;
-    Ncn::load(program_id, ncn, false)?;
-    NcnOperatorState::load(program_id, ncn_operator_state, ncn, operator, true)?;
-    load_signer(operator_ncn_admin, false)?;
+    let OperatorWarmupNcnAccounts {
+        config,
+        ncn,
+        operator,
+        ncn_operator_state,
+        admin: operator_ncn_admin,
+    } = OperatorWarmupNcnAccounts::try_from_accounts(program_id, accounts)?;
+
+    let ncn_data = ncn.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
 
     // The operator NCN admin shall be the signer of the transaction
     let operator_data = operator.data.borrow();
@@ -36,7 +38,11 @@ pub fn process_operator_warmup_ncn(program_id: &Pubkey, accounts: &[AccountInfo]
         NcnOperatorState::try_from_slice_unchecked_mut(&mut ncn_operator_state_data)?;
     if !ncn_operator_state
         .operator_opt_in_state
-        .activate(Clock::get()?.slot, config.epoch_length())?
+        .activate_with_transition_epochs(
+            Clock::get()?.slot,
+            config.epoch_length(),
+            ncn.transition_epochs(),
+        )?
     {
         msg!("Operator is not ready to warm up NCN");
         return Err(RestakingError::OperatorWarmupNcnFailed.into());
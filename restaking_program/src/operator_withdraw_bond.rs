@@ -0,0 +1,87 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, get_epoch, loader::load_token_mint};
+use jito_restaking_core::{
+    config::Config,
+    operator::{Operator, OperatorExt},
+    operator_bond::{OperatorBond, OperatorBondExt},
+};
+use jito_restaking_sdk::instruction::OperatorWithdrawBondAccounts;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, pubkey::Pubkey, sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// The operator admin withdraws the unbonded tokens once their cooldown is over. All the
+/// unbonding tokens are transferred from the bond's token account to the admin token account.
+///
+/// [`crate::RestakingInstruction::OperatorWithdrawBond`]
+pub fn process_operator_withdraw_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    let OperatorWithdrawBondAccounts {
+        config,
+        ncn,
+        operator: operator_info,
+        operator_bond: operator_bond_info,
+        mint,
+        admin,
+        admin_token_account,
+        operator_bond_token_account,
+        token_program: _,
+    } = OperatorWithdrawBondAccounts::try_from_accounts(program_id, accounts)?;
+
+    load_token_mint(mint, "mint")?;
+
+    // The operator admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_admin(admin.key)?;
+
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let epoch = get_epoch(Clock::get()?.slot, config.epoch_length())?;
+    let mut operator_bond_data = operator_bond_info.data.borrow_mut();
+    let operator_bond = OperatorBond::try_from_slice_unchecked_mut(&mut operator_bond_data)?;
+    let amount = operator_bond.withdraw(epoch)?;
+
+    let mut operator_bond_seeds = OperatorBond::seeds(
+        &operator_bond.ncn,
+        &operator_bond.operator,
+        &operator_bond.mint,
+    );
+    operator_bond_seeds.push(vec![operator_bond.bump]);
+    let operator_bond_seeds_slice = operator_bond_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    drop(operator_bond_data);
+
+    invoke_signed(
+        &transfer(
+            &spl_token::id(),
+            operator_bond_token_account.key,
+            admin_token_account.key,
+            operator_bond_info.key,
+            &[],
+            amount,
+        )?,
+        &[
+            operator_bond_token_account.clone(),
+            admin_token_account.clone(),
+            operator_bond_info.clone(),
+        ],
+        &[&operator_bond_seeds_slice],
+    )?;
+
+    msg!(
+        "Operator {} withdrew {} unbonded tokens from NCN {}",
+        operator_info.key,
+        amount,
+        ncn.key
+    );
+
+    Ok(())
+}
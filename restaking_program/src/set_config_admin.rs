@@ -1,21 +1,18 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::config::Config;
-use jito_restaking_sdk::error::RestakingError;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_restaking_sdk::{error::RestakingError, instruction::SetConfigAdminAccounts};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 /// Processes the set config admin instruction: [`crate::RestakingInstruction::SetConfigAdmin`]
 pub fn process_set_config_admin(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
-    let [config, old_admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, true)?;
+    let SetConfigAdminAccounts {
+        config,
+        old_admin,
+        new_admin,
+    } = SetConfigAdminAccounts::try_from_accounts(program_id, accounts)?;
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
-    load_signer(old_admin, false)?;
 
     if config.admin != *old_admin.key {
         return Err(RestakingError::ConfigAdminInvalid.into());
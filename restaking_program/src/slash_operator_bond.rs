@@ -0,0 +1,113 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    config::Config,
+    ncn::Ncn,
+    ncn_operator_state::NcnOperatorState,
+    operator_bond::{OperatorBond, OperatorBondExt},
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::SlashOperatorBondAccounts};
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
+    program::invoke_signed, pubkey::Pubkey, sysvar::Sysvar,
+};
+use spl_token::instruction::transfer;
+
+/// The NCN slasher admin slashes the operator bond to the NCN. The bonded tokens are taken first,
+/// then the tokens still cooling down after being unbonded. Vault delegations to the operator are
+/// slashed separately through the vault program.
+///
+/// Nothing on-chain ties the two slashes together, so the program cannot enforce that the bond is
+/// slashed before the vault delegations. The slasher is responsible for slashing the operator bond
+/// first and only then slashing the vaults for the remainder.
+///
+/// Specification:
+/// - The operator bond shall be posted to the NCN, in the NCN's operator bond mint
+/// - The operator shall have a [`NcnOperatorState`] with the NCN that is active on both sides
+///   and not jailed
+/// - At most the bonded amount is transferred to the destination token account
+///
+/// [`crate::RestakingInstruction::SlashOperatorBond`]
+pub fn process_slash_operator_bond(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+) -> ProgramResult {
+    let SlashOperatorBondAccounts {
+        config,
+        ncn: ncn_info,
+        operator,
+        ncn_operator_state,
+        operator_bond: operator_bond_info,
+        operator_bond_token_account,
+        slasher_admin,
+        destination_token_account,
+        token_program: _,
+    } = SlashOperatorBondAccounts::try_from_accounts(program_id, accounts)?;
+
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+
+    // The NCN slasher admin shall be the signer of the transaction
+    if ncn.slasher_admin.ne(slasher_admin.key) {
+        msg!("Invalid slasher admin for NCN");
+        return Err(RestakingError::NcnSlasherAdminInvalid.into());
+    }
+
+    // The NCN and the operator shall both be opted in to each other and the operator not jailed
+    let config_data = config.data.borrow();
+    let config = Config::try_from_slice_unchecked(&config_data)?;
+    let slot = Clock::get()?.slot;
+    let ncn_operator_state_data = ncn_operator_state.data.borrow();
+    let ncn_operator_state = NcnOperatorState::try_from_slice_unchecked(&ncn_operator_state_data)?;
+    if !ncn_operator_state.is_active(slot, config.epoch_length())? {
+        msg!("NCN operator state is not active");
+        return Err(RestakingError::NcnOperatorStateInactive.into());
+    }
+
+    let mut operator_bond_data = operator_bond_info.data.borrow_mut();
+    let operator_bond = OperatorBond::try_from_slice_unchecked_mut(&mut operator_bond_data)?;
+    let slashed_from_bond = operator_bond.slash(amount)?;
+
+    let mut operator_bond_seeds = OperatorBond::seeds(
+        &operator_bond.ncn,
+        &operator_bond.operator,
+        &operator_bond.mint,
+    );
+    operator_bond_seeds.push(vec![operator_bond.bump]);
+    let operator_bond_seeds_slice = operator_bond_seeds
+        .iter()
+        .map(|seed| seed.as_slice())
+        .collect::<Vec<&[u8]>>();
+
+    drop(operator_bond_data);
+
+    if slashed_from_bond > 0 {
+        // The token program verifies the destination token account is in the same mint
+        invoke_signed(
+            &transfer(
+                &spl_token::id(),
+                operator_bond_token_account.key,
+                destination_token_account.key,
+                operator_bond_info.key,
+                &[],
+                slashed_from_bond,
+            )?,
+            &[
+                operator_bond_token_account.clone(),
+                destination_token_account.clone(),
+                operator_bond_info.clone(),
+            ],
+            &[&operator_bond_seeds_slice],
+        )?;
+    }
+
+    msg!(
+        "Slashed {} of {} from the bond of operator {}",
+        slashed_from_bond,
+        amount,
+        operator.key
+    );
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    metadata::{Metadata, MetadataExt},
+    ncn::{Ncn, NcnExt},
+    ncn_metadata::NcnMetadata,
+};
+use jito_restaking_sdk::instruction::UpdateNcnMetadataAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// The NCN metadata admin can replace the metadata describing the NCN.
+///
+/// [`crate::RestakingInstruction::UpdateNcnMetadata`]
+pub fn process_update_ncn_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: Metadata,
+) -> ProgramResult {
+    let UpdateNcnMetadataAccounts {
+        config: _,
+        ncn: ncn_info,
+        ncn_metadata,
+        metadata_admin,
+    } = UpdateNcnMetadataAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The NCN metadata admin shall be the signer of the transaction
+    let ncn_data = ncn_info.data.borrow();
+    let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
+    ncn.check_metadata_admin(metadata_admin.key)?;
+
+    // Each string field shall be UTF-8 followed only by zero padding
+    metadata.check_valid()?;
+
+    let mut ncn_metadata_data = ncn_metadata.try_borrow_mut_data()?;
+    let ncn_metadata = NcnMetadata::try_from_slice_unchecked_mut(&mut ncn_metadata_data)?;
+    ncn_metadata.metadata = metadata;
+
+    msg!("NcnMetadata updated for NCN {}", ncn_info.key);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    metadata::{Metadata, MetadataExt},
+    operator::{Operator, OperatorExt},
+    operator_metadata::OperatorMetadata,
+};
+use jito_restaking_sdk::instruction::UpdateOperatorMetadataAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
+
+/// The operator metadata admin can replace the metadata describing the operator.
+///
+/// [`crate::RestakingInstruction::UpdateOperatorMetadata`]
+pub fn process_update_operator_metadata(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    metadata: Metadata,
+) -> ProgramResult {
+    let UpdateOperatorMetadataAccounts {
+        config: _,
+        operator: operator_info,
+        operator_metadata,
+        metadata_admin,
+    } = UpdateOperatorMetadataAccounts::try_from_accounts(program_id, accounts)?;
+
+    // The operator metadata admin shall be the signer of the transaction
+    let operator_data = operator_info.data.borrow();
+    let operator = Operator::try_from_slice_unchecked(&operator_data)?;
+    operator.check_metadata_admin(metadata_admin.key)?;
+
+    // Each string field shall be UTF-8 followed only by zero padding
+    metadata.check_valid()?;
+
+    let mut operator_metadata_data = operator_metadata.try_borrow_mut_data()?;
+    let operator_metadata =
+        OperatorMetadata::try_from_slice_unchecked_mut(&mut operator_metadata_data)?;
+    operator_metadata.metadata = metadata;
+
+    msg!(
+        "OperatorMetadata updated for operator {}",
+        operator_info.key
+    );
+
+    Ok(())
+}
@@ -1,46 +1,84 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::{config::Config, ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
-use jito_restaking_sdk::error::RestakingError;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_restaking_core::{
+    config::Config,
+    ncn::Ncn,
+    ncn_policy::{NcnPolicy, NcnPolicyExt},
+    ncn_vault_ticket::NcnVaultTicket,
+};
+use jito_restaking_sdk::{error::RestakingError, instruction::WarmupNcnVaultTicketAccounts};
 use jito_vault_core::vault::Vault;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
+/// If the NCN has a policy, the policy shall be passed and the vault supported mint shall be
+/// allowed by it. With permissionless admission, any signer can warm up the vault.
+///
 /// [`crate::RestakingInstruction::WarmupNcnVaultTicket`]
 pub fn process_warmup_ncn_vault_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, ncn, vault, ncn_vault_ticket, ncn_vault_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let WarmupNcnVaultTicketAccounts {
+        config,
+        ncn,
+        vault,
+        ncn_vault_ticket,
+        admin: ncn_vault_admin,
+        ncn_policy: ncn_policy_info,
+    } = WarmupNcnVaultTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Ncn::load(program_id, ncn, false)?;
     let config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(&config.vault_program, vault, false)?;
-    NcnVaultTicket::load(program_id, ncn_vault_ticket, ncn, vault, true)?;
-    load_signer(ncn_vault_admin, false)?;
+    if let Some(ncn_policy_info) = ncn_policy_info {
+        NcnPolicy::load(program_id, ncn_policy_info, ncn.info(), false)?;
+    }
 
-    // The NCN vault admin shall be the signer of the transaction
     let ncn_data = ncn.data.borrow();
     let ncn = Ncn::try_from_slice_unchecked(&ncn_data)?;
-    if ncn.vault_admin.ne(ncn_vault_admin.key) {
+
+    // The NCN policy shall be passed if the NCN has one
+    let ncn_policy_data = match ncn_policy_info {
+        Some(ncn_policy_info) if ncn.has_policy() => Some(ncn_policy_info.data.borrow()),
+        None if ncn.has_policy() => {
+            msg!("NCN policy is required");
+            return Err(RestakingError::NcnPolicyMissing.into());
+        }
+        _ => None,
+    };
+    let ncn_policy = ncn_policy_data
+        .as_ref()
+        .map(|ncn_policy_data| NcnPolicy::try_from_slice_unchecked(ncn_policy_data))
+        .transpose()?;
+
+    // The NCN vault admin shall be the signer of the transaction, unless the NCN policy allows
+    // permissionless admission
+    let permissionless_admission = ncn_policy
+        .as_ref()
+        .is_some_and(|ncn_policy| ncn_policy.permissionless_admission());
+    if ncn.vault_admin.ne(ncn_vault_admin.key) && !permissionless_admission {
         msg!("Invalid vault admin for NCN");
         return Err(RestakingError::NcnVaultAdminInvalid.into());
     }
 
+    // The vault supported mint shall be allowed by the NCN policy, if any
+    if let Some(ncn_policy) = ncn_policy {
+        let vault_data = vault.data.borrow();
+        let vault = Vault::try_from_slice_unchecked(&vault_data)?;
+        ncn_policy.check_vault_mint(&vault.supported_mint)?;
+    }
+
     // The NcnVaultTicket shall be inactive before it can warmed up
     let mut ncn_vault_ticket_data = ncn_vault_ticket.data.borrow_mut();
     let ncn_vault_ticket =
         NcnVaultTicket::try_from_slice_unchecked_mut(&mut ncn_vault_ticket_data)?;
-    if !ncn_vault_ticket
-        .state
-        .activate(Clock::get()?.slot, config.epoch_length())?
-    {
+    if !ncn_vault_ticket.state.activate_with_transition_epochs(
+        Clock::get()?.slot,
+        config.epoch_length(),
+        ncn.transition_epochs(),
+    )? {
         msg!("Vault is not ready to be activated");
         return Err(RestakingError::NcnVaultTicketFailedWarmup.into());
     }
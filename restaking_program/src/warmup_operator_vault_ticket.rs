@@ -1,13 +1,12 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_restaking_core::{
     config::Config, operator::Operator, operator_vault_ticket::OperatorVaultTicket,
 };
-use jito_restaking_sdk::error::RestakingError;
-use jito_vault_core::vault::Vault;
+use jito_restaking_sdk::{error::RestakingError, instruction::WarmupOperatorVaultTicketAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// [`crate::RestakingInstruction::WarmupOperatorVaultTicket`]
@@ -15,16 +14,15 @@ pub fn process_warmup_operator_vault_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, operator, vault, operator_vault_ticket, operator_vault_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Operator::load(program_id, operator, false)?;
+    let WarmupOperatorVaultTicketAccounts {
+        config,
+        operator,
+        vault: _,
+        operator_vault_ticket,
+        admin: operator_vault_admin,
+    } = WarmupOperatorVaultTicketAccounts::try_from_accounts(program_id, accounts)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(&config.vault_program, vault, false)?;
-    OperatorVaultTicket::load(program_id, operator_vault_ticket, operator, vault, true)?;
-    load_signer(operator_vault_admin, false)?;
 
     // The operator vault admin shall be the signer of the transaction
     let operator_data = operator.data.borrow();
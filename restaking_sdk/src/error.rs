@@ -1,74 +1,128 @@
-use solana_program::{decode_error::DecodeError, program_error::ProgramError};
+use std::ops::Range;
+
+pub use jito_errors::DecodedError;
+use jito_errors::ErrorCode;
+use num_derive::FromPrimitive;
+use solana_program::{decode_error::DecodeError, program_error::ProgramError, pubkey::Pubkey};
 use thiserror::Error;
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq, Eq, FromPrimitive)]
 pub enum RestakingError {
-    #[error("NcnOperatorAdminInvalid")]
-    NcnOperatorAdminInvalid = 1000,
-    #[error("NcnCooldownOperatorFailed")]
+    #[error("Signer is not the NCN's operator admin")]
+    NcnOperatorAdminInvalid = 5000,
+    #[error("NCN operator state cannot be cooled down by the NCN in its current state")]
     NcnCooldownOperatorFailed,
-    #[error("NcnSlasherAdminInvalid")]
+    #[error("Signer is not the NCN's slasher admin")]
     NcnSlasherAdminInvalid,
-    #[error("NcnVaultAdminInvalid")]
+    #[error("Signer is not the NCN's vault admin")]
     NcnVaultAdminInvalid,
-    #[error("NcnAdminInvalid")]
+    #[error("Signer is not the NCN admin")]
     NcnAdminInvalid,
-    #[error("NcnDelegateAdminInvalid")]
+    #[error("Signer is not the NCN's delegate admin")]
     NcnDelegateAdminInvalid,
-    #[error("NcnVaultSlasherTicketFailedCooldown")]
+    #[error("NCN vault slasher ticket cannot be cooled down in its current state")]
     NcnVaultSlasherTicketFailedCooldown,
-    #[error("NcnVaultTicketFailedCooldown")]
+    #[error("NCN vault ticket cannot be cooled down in its current state")]
     NcnVaultTicketFailedCooldown,
-    #[error("NcnWarmupOperatorFailed")]
+    #[error("NCN operator state cannot be warmed up by the NCN in its current state")]
     NcnWarmupOperatorFailed,
-    #[error("NcnVaultSlasherTicketFailedWarmup")]
+    #[error("NCN vault slasher ticket cannot be warmed up in its current state")]
     NcnVaultSlasherTicketFailedWarmup,
-    #[error("NcnVaultTicketFailedWarmup")]
+    #[error("NCN vault ticket cannot be warmed up in its current state")]
     NcnVaultTicketFailedWarmup,
+    #[error("Signer is not the NCN's metadata admin")]
+    NcnMetadataAdminInvalid,
+    #[error("Operator bond is below the NCN's minimum bond")]
+    NcnOperatorBondInsufficient,
+    #[error("NCN has no operator policy")]
+    NcnPolicyMissing,
+    #[error("NCN policy allows too many vault mints")]
+    NcnPolicyTooManyVaultMints,
+    #[error("Vault mint is not allowed by the NCN policy")]
+    NcnPolicyVaultMintNotAllowed,
+    #[error("Operator stake is below the NCN policy minimum")]
+    NcnPolicyOperatorStakeInsufficient,
+    #[error("Operator ticket is too new for the NCN policy")]
+    NcnPolicyOperatorTicketTooNew,
+    #[error("NCN policy maximum number of operators reached")]
+    NcnPolicyMaxOperatorsReached,
+    #[error("Signer is not the NCN's jail admin")]
+    NcnJailAdminInvalid,
+    #[error("Jail end epoch must be after the current epoch")]
+    NcnOperatorJailEpochInvalid,
+    #[error("Operator is not jailed by the NCN")]
+    NcnOperatorNotJailed,
+    #[error("Warmup and cooldown epochs are invalid")]
+    NcnTransitionEpochsInvalid,
+    #[error("Operator is jailed by the NCN")]
+    NcnOperatorJailed,
+    #[error("NCN operator state is not active")]
+    NcnOperatorStateInactive,
+    #[error("NCN vault ticket is not active")]
+    NcnVaultTicketInactive,
+    #[error("Vault is not updated for the current epoch")]
+    NcnPolicyVaultUpdateNeeded,
 
-    #[error("OperatorNcnAdminInvalid")]
-    OperatorNcnAdminInvalid = 2000,
-    #[error("OperatorVaultAdminInvalid")]
+    #[error("Signer is not the operator's NCN admin")]
+    OperatorNcnAdminInvalid = 6000,
+    #[error("Signer is not the operator's vault admin")]
     OperatorVaultAdminInvalid,
-    #[error("OperatorAdminInvalid")]
+    #[error("Signer is not the operator admin")]
     OperatorAdminInvalid,
-    #[error("OperatorDelegateAdminInvalid")]
+    #[error("Signer is not the operator's delegate admin")]
     OperatorDelegateAdminInvalid,
-    #[error("OperatorCooldownNcnFailed")]
+    #[error("NCN operator state cannot be cooled down by the operator in its current state")]
     OperatorCooldownNcnFailed,
-    #[error("OperatorVaultTicketFailedCooldown")]
+    #[error("Operator vault ticket cannot be cooled down in its current state")]
     OperatorVaultTicketFailedCooldown,
-    #[error("OperatorVaultTicketFailedWarmup")]
+    #[error("Operator vault ticket cannot be warmed up in its current state")]
     OperatorVaultTicketFailedWarmup,
-    #[error("OperatorWarmupNcnFailed")]
+    #[error("NCN operator state cannot be warmed up by the operator in its current state")]
     OperatorWarmupNcnFailed,
-    #[error("OperatorFeeCapExceeded")]
+    #[error("Operator fee exceeds the maximum allowed fee")]
     OperatorFeeCapExceeded,
-    #[error("NcnOverflow")]
+    #[error("NCN count overflowed")]
     NcnOverflow,
-    #[error("OperatorOverflow")]
+    #[error("Operator count overflowed")]
     OperatorOverflow,
-    #[error("VaultOverflow")]
+    #[error("Vault count overflowed")]
     VaultOverflow,
-    #[error("SlasherOverflow")]
+    #[error("Slasher count overflowed")]
     SlasherOverflow,
-    #[error("InvalidEpochLength")]
+    #[error("Epoch length is invalid")]
     InvalidEpochLength,
 
-    #[error("ConfigAdminInvalid")]
+    #[error("Signer is not the config admin")]
     ConfigAdminInvalid,
+    #[error("Signer is not the operator's metadata admin")]
+    OperatorMetadataAdminInvalid,
+    #[error("Metadata field is too long")]
+    MetadataFieldTooLong,
+    #[error("Bond amount must be non-zero")]
+    OperatorBondAmountZero,
+    #[error("Amount exceeds the bonded amount")]
+    OperatorBondAmountExceeded,
+    #[error("Unbonded tokens are still cooling down")]
+    OperatorBondCooldownActive,
+    #[error("Metadata field is not zero-padded UTF-8 or not a valid flag")]
+    MetadataFieldInvalid,
 
-    #[error("ArithmeticOverflow")]
-    ArithmeticOverflow = 3000,
-    #[error("ArithmeticUnderflow")]
+    #[error("Arithmetic overflow")]
+    ArithmeticOverflow = 7000,
+    #[error("Arithmetic underflow")]
     ArithmeticUnderflow,
-    #[error("DivisionByZero")]
+    #[error("Division by zero")]
     DivisionByZero,
 }
 
+impl ErrorCode for RestakingError {
+    const SOURCE: &'static str = "jito::restaking";
+    const CODES: Range<u32> = jito_errors::RESTAKING_ERROR_CODES;
+}
+
 impl<T> DecodeError<T> for RestakingError {
     fn type_of() -> &'static str {
-        "jito::restaking"
+        Self::SOURCE
     }
 }
 
@@ -83,3 +137,46 @@ impl From<RestakingError> for u64 {
         e as Self
     }
 }
+
+/// Decodes a custom error code returned by the restaking program at `program_id` into its name and
+/// description. Returns `None` for codes the restaking program doesn't define.
+pub fn decode_program_error(program_id: &Pubkey, code: u32) -> Option<DecodedError> {
+    jito_errors::decode_program_error::<RestakingError>(program_id, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_errors::{CoreError, CORE_ERROR_CODES, RESTAKING_ERROR_CODES};
+
+    use super::*;
+
+    #[test]
+    fn test_decoded_codes_are_registered() {
+        let program_id = Pubkey::new_unique();
+        for code in 0..u16::MAX.into() {
+            let Some(decoded) = decode_program_error(&program_id, code) else {
+                continue;
+            };
+            match decoded.source {
+                "jito::restaking" => assert!(RESTAKING_ERROR_CODES.contains(&code)),
+                "jito::core" | "jito::loader" => assert!(CORE_ERROR_CODES.contains(&code)),
+                source => panic!("unexpected source {source}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_program_error() {
+        let program_id = Pubkey::new_unique();
+        let error = RestakingError::ArithmeticOverflow;
+        let decoded = decode_program_error(&program_id, error as u32).unwrap();
+        assert_eq!(decoded.program_id, program_id);
+        assert_eq!(decoded.name, "ArithmeticOverflow");
+        assert_eq!(decoded.description, "Arithmetic overflow");
+
+        let decoded = decode_program_error(&program_id, CoreError::BadEpochLength as u32).unwrap();
+        assert_eq!(decoded.name, "BadEpochLength");
+
+        assert_eq!(decode_program_error(&program_id, 0), None);
+    }
+}
@@ -1,7 +1,17 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_layouts::{
+    restaking::{
+        config::Config, ncn::Ncn, ncn_metadata::NcnMetadata, ncn_operator_state::NcnOperatorState,
+        ncn_policy::NcnPolicy, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+        ncn_vault_ticket::NcnVaultTicket, operator::Operator, operator_bond::OperatorBond,
+        operator_metadata::OperatorMetadata, operator_vault_ticket::OperatorVaultTicket,
+    },
+    vault::vault::Vault,
+};
 use shank::ShankInstruction;
 
-#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
+#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction, AccountsContext)]
 pub enum RestakingInstruction {
     /// Initializes the global configuration
     #[account(0, writable, name = "config")]
@@ -16,6 +26,7 @@ pub enum RestakingInstruction {
     #[account(2, writable, signer, name = "admin")]
     #[account(3, signer, name = "base")]
     #[account(4, name = "system_program")]
+    #[accounts(pda(config = Config))]
     InitializeNcn,
 
     /// Initializes a operator
@@ -24,6 +35,7 @@ pub enum RestakingInstruction {
     #[account(2, writable, signer, name = "admin")]
     #[account(3, signer, name = "base")]
     #[account(4, name = "system_program")]
+    #[accounts(pda(config = Config))]
     InitializeOperator { operator_fee_bps: u16 },
 
     /// The NCN adds support for a vault slasher
@@ -39,6 +51,12 @@ pub enum RestakingInstruction {
     #[account(6, signer, name = "admin")]
     #[account(7, writable, signer, name = "payer")]
     #[account(8, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(vault = Vault, program = config.vault_program),
+        pda(ncn_vault_ticket = NcnVaultTicket(ncn, vault)),
+    )]
     InitializeNcnVaultSlasherTicket { max_slashable_per_epoch: u64 },
 
     /// NCN adds support for receiving delegation from a vault
@@ -49,6 +67,11 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     #[account(5, writable, signer, name = "payer")]
     #[account(6, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(vault = Vault, program = config.vault_program),
+    )]
     InitializeNcnVaultTicket,
 
     /// Operator adds support for receiving delegation from a vault
@@ -59,6 +82,11 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     #[account(5, writable, signer, name = "payer")]
     #[account(6, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(vault = Vault, program = config.vault_program),
+    )]
     InitializeOperatorVaultTicket,
 
     /// After the operator has signaled they are ready to join the network,
@@ -70,6 +98,7 @@ pub enum RestakingInstruction {
     #[account(4, signer, name = "admin")]
     #[account(5, writable, signer, name = "payer")]
     #[account(6, name = "system_program")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn), pda(operator = Operator))]
     InitializeNcnOperatorState,
 
     #[account(0, name = "config")]
@@ -77,6 +106,18 @@ pub enum RestakingInstruction {
     #[account(2, name = "vault")]
     #[account(3, writable, name = "ncn_vault_ticket")]
     #[account(4, signer, name = "admin")]
+    #[account(
+        5,
+        optional,
+        name = "ncn_policy",
+        description = "Required if the NCN has a policy"
+    )]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(vault = Vault, program = config.vault_program),
+        pda(ncn_vault_ticket = NcnVaultTicket(ncn, vault)),
+    )]
     WarmupNcnVaultTicket,
 
     /// NCN removes support for receiving delegation from a vault
@@ -85,13 +126,41 @@ pub enum RestakingInstruction {
     #[account(2, name = "vault")]
     #[account(3, writable, name = "ncn_vault_ticket")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(ncn_vault_ticket = NcnVaultTicket(ncn, vault)),
+    )]
     CooldownNcnVaultTicket,
 
+    /// NCN warms up an operator. If the NCN policy requires a minimum operator stake, the
+    /// (vault, ncn_vault_ticket, vault_operator_delegation) groups delegating to the operator are
+    /// passed as remaining accounts.
     #[account(0, name = "config")]
     #[account(1, name = "ncn")]
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[account(
+        5,
+        optional,
+        name = "operator_bond",
+        description = "Required if the NCN has an operator bond requirement"
+    )]
+    #[account(
+        6,
+        writable,
+        optional,
+        name = "ncn_policy",
+        description = "Required if the NCN has a policy"
+    )]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+        remaining = vault_operator_delegations,
+    )]
     NcnWarmupOperator,
 
     #[account(0, name = "config")]
@@ -99,6 +168,19 @@ pub enum RestakingInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[account(
+        5,
+        writable,
+        optional,
+        name = "ncn_policy",
+        description = "Required if the operator was admitted under the NCN policy"
+    )]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+    )]
     NcnCooldownOperator,
 
     #[account(0, name = "config")]
@@ -106,6 +188,12 @@ pub enum RestakingInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(ncn = Ncn),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+    )]
     OperatorWarmupNcn,
 
     #[account(0, name = "config")]
@@ -113,6 +201,12 @@ pub enum RestakingInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "ncn_operator_state")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(ncn = Ncn),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+    )]
     OperatorCooldownNcn,
 
     #[account(0, name = "config")]
@@ -122,6 +216,13 @@ pub enum RestakingInstruction {
     #[account(4, name = "ncn_vault_ticket")]
     #[account(5, writable, name = "ncn_vault_slasher_ticket")]
     #[account(6, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(vault = Vault, program = config.vault_program),
+        pda(ncn_vault_ticket = NcnVaultTicket(ncn, vault)),
+        pda(ncn_vault_slasher_ticket = NcnVaultSlasherTicket(ncn, vault, slasher)),
+    )]
     WarmupNcnVaultSlasherTicket,
 
     /// NCN removes support for a slasher
@@ -131,6 +232,12 @@ pub enum RestakingInstruction {
     #[account(3, name = "slasher")]
     #[account(4, writable, name = "ncn_vault_slasher_ticket")]
     #[account(5, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(vault = Vault, program = config.vault_program),
+        pda(ncn_vault_slasher_ticket = NcnVaultSlasherTicket(ncn, vault, slasher)),
+    )]
     CooldownNcnVaultSlasherTicket,
 
     #[account(0, name = "config")]
@@ -138,6 +245,12 @@ pub enum RestakingInstruction {
     #[account(2, name = "vault")]
     #[account(3, writable, name = "operator_vault_ticket")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(vault = Vault, program = config.vault_program),
+        pda(operator_vault_ticket = OperatorVaultTicket(operator, vault)),
+    )]
     WarmupOperatorVaultTicket,
 
     /// Node operator removes support for receiving delegation from a vault
@@ -146,34 +259,45 @@ pub enum RestakingInstruction {
     #[account(2, name = "vault")]
     #[account(3, writable, name = "operator_vault_ticket")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(vault = Vault, program = config.vault_program),
+        pda(operator_vault_ticket = OperatorVaultTicket(operator, vault)),
+    )]
     CooldownOperatorVaultTicket,
 
     #[account(0, writable, name = "ncn")]
     #[account(1, signer, name = "old_admin")]
     #[account(2, signer, name = "new_admin")]
+    #[accounts(pda(ncn = Ncn))]
     NcnSetAdmin,
 
     #[account(0, writable, name = "ncn")]
     #[account(1, signer, name = "admin")]
     #[account(2, name = "new_admin")]
+    #[accounts(pda(ncn = Ncn))]
     NcnSetSecondaryAdmin(NcnAdminRole),
 
     /// Sets the admin for a node operator
     #[account(0, writable, name = "operator")]
     #[account(1, signer, name = "old_admin")]
     #[account(2, signer, name = "new_admin")]
+    #[accounts(pda(operator = Operator))]
     OperatorSetAdmin,
 
     /// Sets the voter for a node operator
     #[account(0, writable, name = "operator")]
     #[account(1, signer, name = "admin")]
     #[account(2, name = "new_admin")]
+    #[accounts(pda(operator = Operator))]
     OperatorSetSecondaryAdmin(OperatorAdminRole),
 
     /// Sets the fee for a node operator
     #[account(0, name = "config")]
     #[account(1, writable, name = "operator")]
     #[account(2, signer, name = "admin")]
+    #[accounts(pda(operator = Operator))]
     OperatorSetFee { new_fee_bps: u16 },
 
     #[account(0, name = "ncn")]
@@ -182,6 +306,7 @@ pub enum RestakingInstruction {
     #[account(3, writable, name = "token_account")]
     #[account(4, name = "delegate")]
     #[account(5, name = "token_program")]
+    #[accounts(pda(ncn = Ncn))]
     NcnDelegateTokenAccount,
 
     #[account(0, name = "operator")]
@@ -190,13 +315,251 @@ pub enum RestakingInstruction {
     #[account(3, writable, name = "token_account")]
     #[account(4, name = "delegate")]
     #[account(5, name = "token_program")]
+    #[accounts(pda(operator = Operator))]
     OperatorDelegateTokenAccount,
 
     /// Changes the admin for the config
     #[account(0, writable, name = "config")]
     #[account(1, signer, name = "old_admin")]
     #[account(2, name = "new_admin")]
+    #[accounts(pda(config = Config))]
     SetConfigAdmin,
+
+    /// Initializes the metadata for an NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_metadata")]
+    #[account(3, signer, name = "metadata_admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn))]
+    InitializeNcnMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<[u8; 32]>,
+    },
+
+    /// Updates the metadata for an NCN
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_metadata")]
+    #[account(3, signer, name = "metadata_admin")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn), pda(ncn_metadata = NcnMetadata(ncn)))]
+    UpdateNcnMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<[u8; 32]>,
+    },
+
+    /// Initializes the metadata for an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, writable, name = "operator_metadata")]
+    #[account(3, signer, name = "metadata_admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    #[accounts(pda(config = Config), pda(operator = Operator))]
+    InitializeOperatorMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<[u8; 32]>,
+    },
+
+    /// Updates the metadata for an operator
+    #[account(0, name = "config")]
+    #[account(1, name = "operator")]
+    #[account(2, writable, name = "operator_metadata")]
+    #[account(3, signer, name = "metadata_admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(operator = Operator),
+        pda(operator_metadata = OperatorMetadata(operator)),
+    )]
+    UpdateOperatorMetadata {
+        name: String,
+        website: String,
+        logo_uri: String,
+        contact: String,
+        description_hash: Option<[u8; 32]>,
+    },
+
+    /// Sets the mint and minimum amount operators shall bond before the NCN can warm them up
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn")]
+    #[account(2, name = "operator_bond_mint")]
+    #[account(3, signer, name = "admin")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn))]
+    NcnSetOperatorBondRequirement { min_operator_bond: u64 },
+
+    /// Initializes the operator bond to an NCN for a given mint
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "operator_bond")]
+    #[account(4, name = "mint")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, writable, signer, name = "payer")]
+    #[account(7, name = "system_program")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn), pda(operator = Operator))]
+    InitializeOperatorBond,
+
+    /// The operator admin deposits tokens into the operator bond
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "operator_bond")]
+    #[account(4, name = "mint")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, writable, name = "admin_token_account")]
+    #[account(7, writable, name = "operator_bond_token_account")]
+    #[account(8, name = "token_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(operator_bond = OperatorBond(ncn, operator, mint.key)),
+        associated_token_account(operator_bond_token_account, owner = operator_bond, mint = mint),
+    )]
+    OperatorDepositBond { amount: u64 },
+
+    /// The NCN slasher admin slashes the operator bond to the NCN, taking the bonded tokens first
+    /// and then the tokens still cooling down. The program does not check that the bond is
+    /// slashed before the vault delegations; that ordering is up to the slasher.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, name = "ncn_operator_state")]
+    #[account(4, writable, name = "operator_bond")]
+    #[account(5, writable, name = "operator_bond_token_account")]
+    #[account(6, signer, name = "slasher_admin")]
+    #[account(7, writable, name = "destination_token_account")]
+    #[account(8, name = "token_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+        pda(operator_bond = OperatorBond(ncn, operator, ncn.operator_bond_mint)),
+        associated_token_account(operator_bond_token_account, owner = operator_bond, mint = ncn.operator_bond_mint),
+    )]
+    SlashOperatorBond { amount: u64 },
+
+    /// Initializes the NCN policy, after which the program enforces it when warming up operators
+    /// and vaults
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn")]
+    #[account(2, writable, name = "ncn_policy")]
+    #[account(3, signer, name = "admin")]
+    #[account(4, writable, signer, name = "payer")]
+    #[account(5, name = "system_program")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn))]
+    InitializeNcnPolicy,
+
+    /// Sets the NCN policy. The allowed vault supported mints are passed as remaining accounts.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, writable, name = "ncn_policy")]
+    #[account(3, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(ncn_policy = NcnPolicy(ncn)),
+        remaining = allowed_vault_mints,
+    )]
+    NcnSetPolicy {
+        permissionless_admission: bool,
+        min_operator_stake: u64,
+        max_operators: u64,
+        min_operator_ticket_age: u64,
+    },
+
+    /// The NCN admin or slasher admin jails an operator until the start of an epoch. A jailed
+    /// operator is treated as inactive without going through a cooldown.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+    )]
+    NcnJailOperator { jailed_until_epoch: u64 },
+
+    /// The NCN admin or slasher admin releases a jailed operator before its jail expires
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "ncn_operator_state")]
+    #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(ncn_operator_state = NcnOperatorState(ncn, operator)),
+    )]
+    NcnUnjailOperator,
+
+    /// The NCN admin sets the number of full epochs its operator, vault and slasher relationships
+    /// spend warming up and cooling down. Transitions already in progress keep their durations.
+    #[account(0, name = "config")]
+    #[account(1, writable, name = "ncn")]
+    #[account(2, signer, name = "admin")]
+    #[accounts(pda(config = Config), pda(ncn = Ncn))]
+    NcnSetTransitionEpochs {
+        warmup_epochs: u16,
+        cooldown_epochs: u16,
+    },
+
+    /// Migrates a program account in place to its current layout version, growing it if needed.
+    /// Anyone can migrate an account, the payer funds any additional rent.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+    /// The operator admin unbonds tokens from the operator bond. The tokens cool down for the
+    /// NCN's cooldown epochs, during which they can still be slashed.
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "operator_bond")]
+    #[account(4, name = "mint")]
+    #[account(5, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(operator_bond = OperatorBond(ncn, operator, mint.key)),
+    )]
+    OperatorUnbond { amount: u64 },
+
+    /// The operator admin withdraws the unbonded tokens once their cooldown is over
+    #[account(0, name = "config")]
+    #[account(1, name = "ncn")]
+    #[account(2, name = "operator")]
+    #[account(3, writable, name = "operator_bond")]
+    #[account(4, name = "mint")]
+    #[account(5, signer, name = "admin")]
+    #[account(6, writable, name = "admin_token_account")]
+    #[account(7, writable, name = "operator_bond_token_account")]
+    #[account(8, name = "token_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(ncn = Ncn),
+        pda(operator = Operator),
+        pda(operator_bond = OperatorBond(ncn, operator, mint.key)),
+        associated_token_account(operator_bond_token_account, owner = operator_bond, mint = mint),
+    )]
+    OperatorWithdrawBond,
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize, PartialEq, Eq)]
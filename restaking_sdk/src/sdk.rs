@@ -485,6 +485,7 @@ pub fn operator_cooldown_ncn(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn ncn_warmup_operator(
     program_id: &Pubkey,
     config: &Pubkey,
@@ -492,14 +493,28 @@ pub fn ncn_warmup_operator(
     operator: &Pubkey,
     ncn_operator_state: &Pubkey,
     admin: &Pubkey,
+    operator_bond: Option<&Pubkey>,
+    ncn_policy: Option<&Pubkey>,
+    vault_operator_delegations: &[(Pubkey, Pubkey, Pubkey)],
 ) -> Instruction {
-    let accounts = vec![
+    // Omitted optional accounts are replaced by the program id
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new(*ncn_operator_state, false),
         AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new_readonly(*operator_bond.unwrap_or(program_id), false),
+        ncn_policy.map_or_else(
+            || AccountMeta::new_readonly(*program_id, false),
+            |ncn_policy| AccountMeta::new(*ncn_policy, false),
+        ),
     ];
+    for (vault, ncn_vault_ticket, vault_operator_delegation) in vault_operator_delegations {
+        accounts.push(AccountMeta::new_readonly(*vault, false));
+        accounts.push(AccountMeta::new_readonly(*ncn_vault_ticket, false));
+        accounts.push(AccountMeta::new_readonly(*vault_operator_delegation, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -516,14 +531,18 @@ pub fn ncn_cooldown_operator(
     operator: &Pubkey,
     ncn_operator_state: &Pubkey,
     admin: &Pubkey,
+    ncn_policy: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*operator, false),
         AccountMeta::new(*ncn_operator_state, false),
         AccountMeta::new_readonly(*admin, true),
     ];
+    if let Some(ncn_policy) = ncn_policy {
+        accounts.push(AccountMeta::new(*ncn_policy, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -540,14 +559,18 @@ pub fn warmup_ncn_vault_ticket(
     vault: &Pubkey,
     ncn_vault_ticket: &Pubkey,
     admin: &Pubkey,
+    ncn_policy: Option<&Pubkey>,
 ) -> Instruction {
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new_readonly(*config, false),
         AccountMeta::new_readonly(*ncn, false),
         AccountMeta::new_readonly(*vault, false),
         AccountMeta::new(*ncn_vault_ticket, false),
         AccountMeta::new_readonly(*admin, true),
     ];
+    if let Some(ncn_policy) = ncn_policy {
+        accounts.push(AccountMeta::new_readonly(*ncn_policy, false));
+    }
     Instruction {
         program_id: *program_id,
         accounts,
@@ -627,3 +650,477 @@ pub fn set_config_admin(
         data: RestakingInstruction::SetConfigAdmin.try_to_vec().unwrap(),
     }
 }
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_ncn_metadata(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+    description_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_ncn_metadata(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+    description_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateNcnMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_operator_metadata(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    operator_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    payer: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+    description_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn update_operator_metadata(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    operator: &Pubkey,
+    operator_metadata: &Pubkey,
+    metadata_admin: &Pubkey,
+    name: String,
+    website: String,
+    logo_uri: String,
+    contact: String,
+    description_hash: Option<[u8; 32]>,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_metadata, false),
+        AccountMeta::new_readonly(*metadata_admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::UpdateOperatorMetadata {
+            name,
+            website,
+            logo_uri,
+            contact,
+            description_hash,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_set_operator_bond_requirement(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator_bond_mint: &Pubkey,
+    admin: &Pubkey,
+    min_operator_bond: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*operator_bond_mint, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetOperatorBondRequirement { min_operator_bond }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn initialize_operator_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_bond: &Pubkey,
+    mint: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeOperatorBond
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn operator_deposit_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_bond: &Pubkey,
+    mint: &Pubkey,
+    admin: &Pubkey,
+    admin_token_account: &Pubkey,
+    operator_bond_token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*admin_token_account, false),
+        AccountMeta::new(*operator_bond_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorDepositBond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn slash_operator_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    operator_bond: &Pubkey,
+    operator_bond_token_account: &Pubkey,
+    slasher_admin: &Pubkey,
+    destination_token_account: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new_readonly(*ncn_operator_state, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new(*operator_bond_token_account, false),
+        AccountMeta::new_readonly(*slasher_admin, true),
+        AccountMeta::new(*destination_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::SlashOperatorBond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn initialize_ncn_policy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_policy: &Pubkey,
+    admin: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new(*ncn_policy, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::InitializeNcnPolicy
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn ncn_set_policy(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    ncn_policy: &Pubkey,
+    admin: &Pubkey,
+    allowed_vault_mints: &[Pubkey],
+    permissionless_admission: bool,
+    min_operator_stake: u64,
+    max_operators: u64,
+    min_operator_ticket_age: u64,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new(*ncn_policy, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    accounts.extend(
+        allowed_vault_mints
+            .iter()
+            .map(|mint| AccountMeta::new_readonly(*mint, false)),
+    );
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetPolicy {
+            permissionless_admission,
+            min_operator_stake,
+            max_operators,
+            min_operator_ticket_age,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn ncn_jail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+    jailed_until_epoch: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnJailOperator { jailed_until_epoch }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_unjail_operator(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    ncn_operator_state: &Pubkey,
+    admin: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*ncn_operator_state, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnUnjailOperator
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+pub fn ncn_set_transition_epochs(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    admin: &Pubkey,
+    warmup_epochs: u16,
+    cooldown_epochs: u16,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new(*ncn, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::NcnSetTransitionEpochs {
+            warmup_epochs,
+            cooldown_epochs,
+        }
+        .try_to_vec()
+        .unwrap(),
+    }
+}
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn operator_unbond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_bond: &Pubkey,
+    mint: &Pubkey,
+    admin: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*admin, true),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorUnbond { amount }
+            .try_to_vec()
+            .unwrap(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn operator_withdraw_bond(
+    program_id: &Pubkey,
+    config: &Pubkey,
+    ncn: &Pubkey,
+    operator: &Pubkey,
+    operator_bond: &Pubkey,
+    mint: &Pubkey,
+    admin: &Pubkey,
+    admin_token_account: &Pubkey,
+    operator_bond_token_account: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new_readonly(*config, false),
+        AccountMeta::new_readonly(*ncn, false),
+        AccountMeta::new_readonly(*operator, false),
+        AccountMeta::new(*operator_bond, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new_readonly(*admin, true),
+        AccountMeta::new(*admin_token_account, false),
+        AccountMeta::new(*operator_bond_token_account, false),
+        AccountMeta::new_readonly(*token_program, false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: RestakingInstruction::OperatorWithdrawBond
+            .try_to_vec()
+            .unwrap(),
+    }
+}
@@ -81,6 +81,22 @@ restakingKinobi.update(kinobi.bottomUpTransformerVisitor([
             };
         },
     },
+    {
+        // PodBool -> bool
+        select: (node) => {
+            return (
+                kinobi.isNode(node, "structFieldTypeNode") &&
+                node.type.name === "podBool"
+            );
+        },
+        transform: (node) => {
+            kinobi.assertIsNode(node, "structFieldTypeNode");
+            return {
+                ...node,
+                type: kinobi.booleanTypeNode(),
+            };
+        },
+    },
     // add 8 byte discriminator to accountNode
     {
         select: (node) => {
@@ -1,4 +1,8 @@
-use std::{fs::File, io::Write, path::PathBuf};
+use std::{
+    fs::{self, File},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{anyhow, Result};
 use clap::{Args, Parser, Subcommand};
@@ -37,11 +41,14 @@ struct GenerateArgs {
     #[arg(long)]
     idl_name: String,
 
-    /// Module paths (core, program, sdk...)
+    /// Module paths (core, program, sdk...). `crate::module` only parses that module of the crate
     #[arg(long)]
     module_paths: Vec<String>,
 }
 
+/// The prefix of the attributes only applied with the `shank` feature
+const SHANK_CFG_ATTR: &str = "#[cfg_attr(feature = \"shank\", ";
+
 struct IdlConfiguration {
     program_id: String,
     name: String,
@@ -68,7 +75,11 @@ fn main() -> Result<()> {
 
             let crate_root = std::env::current_dir().unwrap();
             let mut idls = Vec::new();
-            for path in idl_config.paths.iter() {
+            for module_path in idl_config.paths.iter() {
+                let (path, module) = match module_path.split_once("::") {
+                    Some((path, module)) => (path, Some(module)),
+                    None => (module_path.as_str(), None),
+                };
                 let cargo_toml = crate_root.join(path).join("Cargo.toml");
                 if !cargo_toml.exists() {
                     return Err(anyhow!(
@@ -83,7 +94,15 @@ fn main() -> Result<()> {
                     .ok_or_else(|| anyhow!("Program needs to be a lib"))?;
 
                 debug!("lib_rel_path: {:?}", lib_rel_path);
-                let lib_full_path_str = crate_root.join(path).join(lib_rel_path);
+                // shank_idl only sees plain derives, so parse a copy of the crate where the
+                // derives behind the `shank` feature are unconditional. The copy stays in the
+                // workspace so its manifest can inherit from the workspace manifest.
+                let crate_copy = crate_root.join("target").join("jito-shank-cli").join(path);
+                copy_unwrapping_shank_attrs(&crate_root.join(path), &crate_copy)?;
+                let lib_full_path_str = match module {
+                    Some(module) => module_root(&crate_copy.join(lib_rel_path), module)?,
+                    None => crate_copy.join(lib_rel_path),
+                };
                 let lib_full_path = lib_full_path_str
                     .to_str()
                     .ok_or_else(|| anyhow!("Invalid Path"))?;
@@ -134,3 +153,59 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+/// Returns the file of the top level `module` of the crate rooted at `lib_path`
+fn module_root(lib_path: &Path, module: &str) -> Result<PathBuf> {
+    let src_dir = lib_path.parent().ok_or_else(|| anyhow!("Invalid Path"))?;
+    [
+        src_dir.join(format!("{module}.rs")),
+        src_dir.join(module).join("mod.rs"),
+    ]
+    .into_iter()
+    .find(|path| path.exists())
+    .ok_or_else(|| anyhow!("Did not find module {} in {}", module, src_dir.display()))
+}
+
+/// Copies the manifest and the sources of the crate at `crate_dir` to `copy_dir`, turning the
+/// attributes behind the `shank` feature into plain attributes.
+fn copy_unwrapping_shank_attrs(crate_dir: &Path, copy_dir: &Path) -> Result<()> {
+    if copy_dir.exists() {
+        fs::remove_dir_all(copy_dir)?;
+    }
+    fs::create_dir_all(copy_dir)?;
+    fs::copy(crate_dir.join("Cargo.toml"), copy_dir.join("Cargo.toml"))?;
+    copy_sources(&crate_dir.join("src"), &copy_dir.join("src"))
+}
+
+fn copy_sources(src_dir: &Path, copy_dir: &Path) -> Result<()> {
+    fs::create_dir_all(copy_dir)?;
+    for entry in fs::read_dir(src_dir)? {
+        let path = entry?.path();
+        let copy_path = copy_dir.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_sources(&path, &copy_path)?;
+        } else {
+            let source = fs::read_to_string(&path)?;
+            fs::write(copy_path, unwrap_shank_attrs(&source))?;
+        }
+    }
+    Ok(())
+}
+
+/// Turns `#[cfg_attr(feature = "shank", derive(ShankType))]` into `#[derive(ShankType)]`
+fn unwrap_shank_attrs(source: &str) -> String {
+    let mut unwrapped = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some((before, after)) = rest.split_once(SHANK_CFG_ATTR) {
+        let Some((attr, after)) = after.split_once(")]") else {
+            break;
+        };
+        unwrapped.push_str(before);
+        unwrapped.push_str("#[");
+        unwrapped.push_str(attr);
+        unwrapped.push(']');
+        rest = after;
+    }
+    unwrapped.push_str(rest);
+    unwrapped
+}
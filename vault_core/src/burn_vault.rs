@@ -1,4 +1,5 @@
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
+use jito_jsm_core::loader::{AccountError, LoaderError};
+use solana_program::{account_info::AccountInfo, pubkey::Pubkey};
 
 // Empty PDA to send tokens to "burn"
 pub struct BurnVault {}
@@ -38,23 +39,26 @@ impl BurnVault {
         base: &Pubkey,
         account: &AccountInfo,
         expect_writable: bool,
-    ) -> Result<(), ProgramError> {
+    ) -> Result<(), AccountError> {
         if account.owner.ne(&solana_program::system_program::ID) {
-            msg!("Burn Vault account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(AccountError::new(
+                "burn_vault",
+                LoaderError::InvalidAccountOwner,
+            ));
         }
 
         if expect_writable && !account.is_writable {
-            msg!("Burn Vault account is not writable");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(AccountError::new(
+                "burn_vault",
+                LoaderError::AccountNotWritable,
+            ));
         }
 
         if account
             .key
             .ne(&Self::find_program_address(program_id, base).0)
         {
-            msg!("Burn Vault account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
+            return Err(AccountError::new("burn_vault", LoaderError::InvalidAddress));
         }
         Ok(())
     }
@@ -146,7 +150,10 @@ mod tests {
         );
 
         let result = BurnVault::load(&program_id, &base, &account, false);
-        assert_eq!(result.unwrap_err(), ProgramError::InvalidAccountOwner);
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::new("burn_vault", LoaderError::InvalidAccountOwner)
+        );
     }
 
     #[test]
@@ -170,7 +177,10 @@ mod tests {
         );
 
         let result = BurnVault::load(&program_id, &base, &account, false);
-        assert_eq!(result.unwrap_err(), ProgramError::InvalidAccountData);
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::new("burn_vault", LoaderError::InvalidAddress)
+        );
     }
 
     #[test]
@@ -194,6 +204,9 @@ mod tests {
         );
 
         let result = BurnVault::load(&program_id, &base, &account, true);
-        assert_eq!(result.unwrap_err(), ProgramError::InvalidAccountData);
+        assert_eq!(
+            result.unwrap_err(),
+            AccountError::new("burn_vault", LoaderError::AccountNotWritable)
+        );
     }
 }
@@ -11,4 +11,4 @@ pub mod vault_operator_delegation;
 pub mod vault_staker_withdrawal_ticket;
 pub mod vault_update_state_tracker;
 
-pub const MAX_BPS: u16 = 10_000;
+pub use jito_layouts::vault_math::MAX_BPS;
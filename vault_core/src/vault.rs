@@ -1,169 +1,193 @@
 //! The vault is responsible for holding tokens and minting VRT tokens.
-use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{
-    types::{PodBool, PodU16, PodU64},
-    AccountDeserialize, Discriminator,
-};
+use jito_bytemuck::types::{PodBool, PodU16, PodU64};
 use jito_jsm_core::{get_epoch, loader::load_signer};
+use jito_layouts::{vault::vault::RESERVED_SPACE_LEN, vault_math};
+pub use jito_layouts::{
+    vault::vault::{MintSummary, Vault},
+    vault_math::BurnSummary,
+};
 use jito_vault_sdk::error::VaultError;
-use shank::ShankAccount;
 use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{config::Config, delegation_state::DelegationState, MAX_BPS};
+use crate::{delegation_state::DelegationState, MAX_BPS};
 
-const RESERVED_SPACE_LEN: usize = 251;
+/// The [`Vault`] methods that return an error
+pub trait VaultExt: Sized {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        vrt_mint: Pubkey,
+        supported_mint: Pubkey,
+        admin: Pubkey,
+        vault_index: u64,
+        base: Pubkey,
+        deposit_fee_bps: u16,
+        withdrawal_fee_bps: u16,
+        reward_fee_bps: u16,
+        program_fee_bps_from_config: u16,
+        bump: u8,
+        current_slot: u64,
+    ) -> Result<Self, VaultError>;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct BurnSummary {
-    /// How much of the VRT shall be transferred to the vault fee account
-    pub vault_fee_amount: u64,
-    /// How much of the VRT shall be transferred to the program fee account
-    pub program_fee_amount: u64,
-    /// How much of the staker's VRT shall be burned
-    pub burn_amount: u64,
-    /// How much of the staker's tokens shall be returned
-    pub out_amount: u64,
-}
+    fn increment_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError>;
 
-#[derive(Debug, PartialEq, Eq)]
-pub struct MintSummary {
-    pub vrt_to_depositor: u64,
-    pub vrt_to_fee_wallet: u64,
-}
+    fn decrement_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError>;
+
+    fn increment_slasher_count(&mut self) -> Result<(), VaultError>;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
-#[repr(C)]
-pub struct Vault {
-    /// The base account of the VRT
-    pub base: Pubkey,
+    fn increment_ncn_count(&mut self) -> Result<(), VaultError>;
 
-    // ------------------------------------------
-    // Token information and accounting
-    // ------------------------------------------
-    /// Mint of the VRT token
-    pub vrt_mint: Pubkey,
+    fn increment_operator_count(&mut self) -> Result<(), VaultError>;
 
-    /// Mint of the token that is supported by the VRT
-    pub supported_mint: Pubkey,
+    fn set_program_fee_bps(&mut self, program_fee_bps: u16) -> Result<(), ProgramError>;
 
-    /// The total number of VRT in circulation
-    vrt_supply: PodU64,
+    fn increment_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError>;
 
-    /// The total number of tokens deposited
-    tokens_deposited: PodU64,
+    fn decrement_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError>;
 
-    /// The maximum deposit capacity allowed in the mint_to instruction.
-    /// The deposited assets in the vault may exceed the deposit_capacity during other operations, such as vault balance updates.
-    deposit_capacity: PodU64,
+    fn decrement_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError>;
 
-    /// Rolled-up stake state for all operators in the set
-    pub delegation_state: DelegationState,
+    fn increment_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError>;
 
-    /// The amount of additional assets that need unstaking to fulfill VRT withdrawals
-    additional_assets_need_unstaking: PodU64,
+    fn increment_vrt_enqueued_for_cooldown_amount(&mut self, amount: u64)
+        -> Result<(), VaultError>;
 
-    /// The amount of VRT tokens in VaultStakerWithdrawalTickets enqueued for cooldown
-    vrt_enqueued_for_cooldown_amount: PodU64,
+    fn decrement_additional_assets_need_unstaking(&mut self, amount: u64)
+        -> Result<(), VaultError>;
 
-    /// The amount of VRT tokens cooling down
-    vrt_cooling_down_amount: PodU64,
+    fn initialize_vault_override_deposit_fee_bps(
+        &mut self,
+        deposit_fee_bps: u16,
+        base: &AccountInfo,
+    ) -> Result<(), ProgramError>;
 
-    /// The amount of VRT tokens ready to claim
-    vrt_ready_to_claim_amount: PodU64,
+    /// Checks whether the vault is currently paused.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the vault is not paused.
+    ///
+    /// # Errors
+    /// * [`VaultError::VaultIsPaused`] - If the vault is currently paused.
+    fn check_is_paused(&self) -> Result<(), VaultError>;
 
-    // ------------------------------------------
-    // Admins
-    // ------------------------------------------
-    /// Vault admin
-    pub admin: Pubkey,
+    fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError>;
+
+    /// Check admin validity and signature
+    fn check_admin(&self, admin: &Pubkey) -> Result<(), ProgramError>;
 
-    /// The delegation admin responsible for adding and removing delegations from operators.
-    pub delegation_admin: Pubkey,
+    fn check_delegation_admin(&self, delegation_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The operator admin responsible for adding and removing operators.
-    pub operator_admin: Pubkey,
+    fn check_operator_admin(&self, operator_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The node consensus network admin responsible for adding and removing support for NCNs.
-    pub ncn_admin: Pubkey,
+    fn check_ncn_admin(&self, ncn_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The admin responsible for adding and removing slashers.
-    pub slasher_admin: Pubkey,
+    fn check_slasher_admin(&self, slasher_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The admin responsible for setting the capacity
-    pub capacity_admin: Pubkey,
+    fn check_capacity_admin(&self, capacity_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The admin responsible for setting the fees
-    pub fee_admin: Pubkey,
+    fn check_fee_admin(&self, fee_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// The delegate_admin responsible for delegating assets
-    pub delegate_asset_admin: Pubkey,
+    /// Validates the delegate_asset_admin account and ensures it matches the expected delegate_asset_admin.
+    ///
+    /// # Arguments
+    /// * `delegate_asset_admin` - A reference to the [`Pubkey`] representing the delegate_asset_admin Pubkey that is attempting
+    ///   to authorize the operation.
+    ///
+    /// # Returns
+    /// * `Result<(), VaultError>` - Returns `Ok(())` if the delegate_asset_admin Pubkey is valid.
+    ///
+    /// # Errors
+    /// This function will return a [`jito_vault_sdk::error::VaultError::VaultDelegateAssetAdminInvalid`] error in the following case:
+    /// * The `delegate_asset_admin` 's public key does not match the expected delegate_asset_admin public key stored in `self`.
+    fn check_delegate_asset_admin(&self, delegate_asset_admin: &Pubkey) -> Result<(), VaultError>;
 
-    /// Fee wallet account
-    pub fee_wallet: Pubkey,
+    /// Replace all secondary admins that were equal to the old admin to the new admin
+    fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey);
 
-    /// Optional mint signer
-    pub mint_burn_admin: Pubkey,
+    fn is_update_needed(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError>;
 
-    /// ( For future use ) Authority to update the vault's metadata
-    pub metadata_admin: Pubkey,
+    fn check_update_state_ok(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError>;
 
-    // ------------------------------------------
-    // Indexing and counters
-    // These are helpful when one needs to iterate through all the accounts
-    // ------------------------------------------
-    /// The index of the vault in the vault list
-    vault_index: PodU64,
+    fn check_mint_burn_admin(
+        &self,
+        mint_burn_admin: Option<&AccountInfo>,
+    ) -> Result<(), VaultError>;
 
-    /// Number of VaultNcnTicket accounts tracked by this vault
-    ncn_count: PodU64,
+    /// Fees can be changed at most one per epoch, and a **full** epoch must pass before a fee can be changed again.
+    fn check_can_modify_fees(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError>;
 
-    /// Number of VaultOperatorDelegation accounts tracked by this vault
-    operator_count: PodU64,
+    fn set_withdrawal_fee_bps(&mut self, withdrawal_fee_bps: u16) -> Result<(), VaultError>;
 
-    /// Number of VaultNcnSlasherTicket accounts tracked by this vault
-    slasher_count: PodU64,
+    fn set_next_withdrawal_fee_bps(
+        &mut self,
+        withdrawal_fee_bps: u16,
+        deposit_withdrawal_fee_cap_bps: u16,
+        fee_bump_bps: u16,
+        fee_rate_of_change_bps: u16,
+    ) -> Result<(), VaultError>;
 
-    /// The slot of the last fee change
-    last_fee_change_slot: PodU64,
+    fn set_deposit_fee_bps(
+        &mut self,
+        deposit_fee_bps: u16,
+        deposit_withdrawal_fee_cap_bps: u16,
+        fee_bump_bps: u16,
+        fee_rate_of_change_bps: u16,
+    ) -> Result<(), VaultError>;
 
-    /// The slot of the last time the delegations were updated
-    last_full_state_update_slot: PodU64,
+    fn set_reward_fee_bps(&mut self, reward_fee_bps: u16) -> Result<(), VaultError>;
 
-    /// The deposit fee in basis points
-    deposit_fee_bps: PodU16,
+    /// Calculate the reward fee in terms of ST. The VRT minted as a result is further calculated
+    /// in update_vault_balance
+    fn calculate_st_reward_fee(&self, new_st_supply: u64) -> Result<u64, VaultError>;
 
-    /// The withdrawal fee in basis points
-    withdrawal_fee_bps: PodU16,
+    /// Checks that reward fee's actual rate is within the expected rate
+    fn check_reward_fee_effective_rate(
+        &self,
+        st_rewards: u64,
+        vrt_reward_fee: u64,
+        max_delta_bps: u16,
+    ) -> Result<(), VaultError>;
 
-    /// The next epoch's withdrawal fee in basis points
-    next_withdrawal_fee_bps: PodU16,
+    /// Calculate the amount of VRT tokens to mint based on the amount of tokens deposited in the vault.
+    /// If no tokens have been deposited, the amount is equal to the amount passed in.
+    /// Otherwise, the amount is calculated as the pro-rata share of the total VRT supply.
+    fn calculate_vrt_mint_amount(&self, amount: u64) -> Result<u64, VaultError>;
 
-    /// Fee for each epoch
-    reward_fee_bps: PodU16,
+    fn mint_with_fee(
+        &mut self,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<MintSummary, VaultError>;
 
-    /// (Copied from Config) The program fee in basis points
-    program_fee_bps: PodU16,
+    fn calculate_burn_summary(
+        &self,
+        is_staker_program_fee_wallet: bool,
+        is_staker_vault_fee_wallet: bool,
+        amount_in: u64,
+    ) -> Result<BurnSummary, VaultError>;
 
-    /// The bump seed for the PDA
-    pub bump: u8,
+    fn burn_with_fee(
+        &mut self,
+        is_staker_program_fee_wallet: bool,
+        is_staker_vault_fee_wallet: bool,
+        amount_in: u64,
+    ) -> Result<BurnSummary, VaultError>;
 
-    /// Whether the vault is paused
-    is_paused: PodBool,
+    /// Calculates the amount of tokens, denominated in the supported_mint asset,
+    /// that should be reserved for the VRTs in the vault
+    fn calculate_supported_assets_requested_for_withdrawal(&self) -> Result<u64, VaultError>;
 
-    /// last
-    last_start_state_update_slot: PodU64,
+    fn calculate_additional_supported_assets_needed_to_unstake(
+        &self,
+        slot: u64,
+        epoch_length: u64,
+    ) -> Result<u64, ProgramError>;
 
-    /// Reserved space
-    reserved: [u8; 251],
+    fn delegate(&mut self, amount: u64) -> Result<(), VaultError>;
 }
 
-impl Vault {
-    pub const MAX_REWARD_DELTA_BPS: u16 = 50; // 0.5%
-    pub const MIN_WITHDRAWAL_SLIPPAGE_BPS: u16 = 50; // 0.5%
-    pub const DEFAULT_INITIALIZATION_TOKEN_AMOUNT: u64 = 10_000;
-
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
+impl VaultExt for Vault {
+    fn new(
         vrt_mint: Pubkey,
         supported_mint: Pubkey,
         admin: Pubkey,
@@ -230,122 +254,47 @@ impl Vault {
         })
     }
 
-    pub fn ncn_count(&self) -> u64 {
-        self.ncn_count.into()
-    }
-
-    pub fn last_fee_change_slot(&self) -> u64 {
-        self.last_fee_change_slot.into()
-    }
-
-    pub fn deposit_capacity(&self) -> u64 {
-        self.deposit_capacity.into()
-    }
-
-    pub fn vault_index(&self) -> u64 {
-        self.vault_index.into()
-    }
-
-    pub fn set_last_fee_change_slot(&mut self, slot: u64) {
-        self.last_fee_change_slot = PodU64::from(slot);
-    }
-
-    pub fn last_full_state_update_slot(&self) -> u64 {
-        self.last_full_state_update_slot.into()
-    }
-
-    pub fn last_start_state_update_slot(&self) -> u64 {
-        self.last_start_state_update_slot.into()
-    }
-
-    pub fn vrt_supply(&self) -> u64 {
-        self.vrt_supply.into()
-    }
-
-    pub fn slasher_count(&self) -> u64 {
-        self.slasher_count.into()
-    }
-
-    pub fn tokens_deposited(&self) -> u64 {
-        self.tokens_deposited.into()
-    }
-
-    pub fn increment_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut tokens_deposited: u64 = self.tokens_deposited.into();
-        tokens_deposited = tokens_deposited
+    fn increment_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.tokens_deposited = self
+            .tokens_deposited
             .checked_add(amount)
             .ok_or(VaultError::VaultOverflow)?;
-        self.tokens_deposited = PodU64::from(tokens_deposited);
         Ok(())
     }
 
-    pub fn decrement_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut tokens_deposited: u64 = self.tokens_deposited.into();
-        tokens_deposited = tokens_deposited
+    fn decrement_tokens_deposited(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.tokens_deposited = self
+            .tokens_deposited
             .checked_sub(amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.tokens_deposited = PodU64::from(tokens_deposited);
         Ok(())
     }
 
-    pub fn increment_slasher_count(&mut self) -> Result<(), VaultError> {
-        let mut slasher_count: u64 = self.slasher_count.into();
-        slasher_count = slasher_count
-            .checked_add(1)
+    fn increment_slasher_count(&mut self) -> Result<(), VaultError> {
+        self.slasher_count = self
+            .slasher_count
+            .checked_add(1_u64)
             .ok_or(VaultError::SlasherOverflow)?;
-        self.slasher_count = PodU64::from(slasher_count);
         Ok(())
     }
 
-    pub fn increment_ncn_count(&mut self) -> Result<(), VaultError> {
-        let mut ncn_count: u64 = self.ncn_count.into();
-        ncn_count = ncn_count.checked_add(1).ok_or(VaultError::NcnOverflow)?;
-        self.ncn_count = PodU64::from(ncn_count);
+    fn increment_ncn_count(&mut self) -> Result<(), VaultError> {
+        self.ncn_count = self
+            .ncn_count
+            .checked_add(1_u64)
+            .ok_or(VaultError::NcnOverflow)?;
         Ok(())
     }
 
-    pub fn increment_operator_count(&mut self) -> Result<(), VaultError> {
-        let mut operator_count: u64 = self.operator_count.into();
-        operator_count = operator_count
-            .checked_add(1)
+    fn increment_operator_count(&mut self) -> Result<(), VaultError> {
+        self.operator_count = self
+            .operator_count
+            .checked_add(1_u64)
             .ok_or(VaultError::OperatorOverflow)?;
-        self.operator_count = PodU64::from(operator_count);
         Ok(())
     }
 
-    pub fn vrt_enqueued_for_cooldown_amount(&self) -> u64 {
-        self.vrt_enqueued_for_cooldown_amount.into()
-    }
-
-    pub fn vrt_cooling_down_amount(&self) -> u64 {
-        self.vrt_cooling_down_amount.into()
-    }
-
-    pub fn vrt_ready_to_claim_amount(&self) -> u64 {
-        self.vrt_ready_to_claim_amount.into()
-    }
-
-    pub fn deposit_fee_bps(&self) -> u16 {
-        u16::from(self.deposit_fee_bps)
-    }
-
-    pub fn withdrawal_fee_bps(&self) -> u16 {
-        u16::from(self.withdrawal_fee_bps)
-    }
-
-    pub fn next_withdrawal_fee_bps(&self) -> u16 {
-        u16::from(self.next_withdrawal_fee_bps)
-    }
-
-    pub fn reward_fee_bps(&self) -> u16 {
-        u16::from(self.reward_fee_bps)
-    }
-
-    pub fn program_fee_bps(&self) -> u16 {
-        u16::from(self.program_fee_bps)
-    }
-
-    pub fn set_program_fee_bps(&mut self, program_fee_bps: u16) -> Result<(), ProgramError> {
+    fn set_program_fee_bps(&mut self, program_fee_bps: u16) -> Result<(), ProgramError> {
         if program_fee_bps > MAX_BPS {
             msg!("New fee exceeds maximum allowed fee");
             return Err(ProgramError::InvalidInstructionData);
@@ -354,117 +303,61 @@ impl Vault {
         Ok(())
     }
 
-    pub fn operator_count(&self) -> u64 {
-        self.operator_count.into()
-    }
-
-    pub fn set_capacity(&mut self, capacity: u64) {
-        self.deposit_capacity = PodU64::from(capacity);
-    }
-
-    pub fn set_vrt_cooling_down_amount(&mut self, amount: u64) {
-        self.vrt_cooling_down_amount = PodU64::from(amount);
-    }
-
-    pub fn increment_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut vrt_supply: u64 = self.vrt_supply.into();
-        vrt_supply = vrt_supply
+    fn increment_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.vrt_supply = self
+            .vrt_supply
             .checked_add(amount)
             .ok_or(VaultError::VaultOverflow)?;
-        self.vrt_supply = PodU64::from(vrt_supply);
         Ok(())
     }
 
-    pub fn decrement_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut vrt_supply: u64 = self.vrt_supply.into();
-        vrt_supply = vrt_supply
+    fn decrement_vrt_supply(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.vrt_supply = self
+            .vrt_supply
             .checked_sub(amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.vrt_supply = PodU64::from(vrt_supply);
         Ok(())
     }
 
-    pub fn set_last_full_state_update_slot(&mut self, slot: u64) {
-        self.last_full_state_update_slot = PodU64::from(slot);
-    }
-
-    pub fn set_last_start_state_update_slot(&mut self, slot: u64) {
-        self.last_start_state_update_slot = PodU64::from(slot);
-    }
-
-    pub fn decrement_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut vrt_ready_to_claim_amount: u64 = self.vrt_ready_to_claim_amount.into();
-        vrt_ready_to_claim_amount = vrt_ready_to_claim_amount
+    fn decrement_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.vrt_ready_to_claim_amount = self
+            .vrt_ready_to_claim_amount
             .checked_sub(amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.vrt_ready_to_claim_amount = PodU64::from(vrt_ready_to_claim_amount);
         Ok(())
     }
 
-    pub fn increment_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError> {
-        let mut vrt_ready_to_claim_amount: u64 = self.vrt_ready_to_claim_amount.into();
-        vrt_ready_to_claim_amount = vrt_ready_to_claim_amount
+    fn increment_vrt_ready_to_claim_amount(&mut self, amount: u64) -> Result<(), VaultError> {
+        self.vrt_ready_to_claim_amount = self
+            .vrt_ready_to_claim_amount
             .checked_add(amount)
             .ok_or(VaultError::VaultOverflow)?;
-        self.vrt_ready_to_claim_amount = PodU64::from(vrt_ready_to_claim_amount);
         Ok(())
     }
 
-    pub fn increment_vrt_enqueued_for_cooldown_amount(
+    fn increment_vrt_enqueued_for_cooldown_amount(
         &mut self,
         amount: u64,
     ) -> Result<(), VaultError> {
-        let mut vrt_enqueued_for_cooldown_amount: u64 =
-            self.vrt_enqueued_for_cooldown_amount.into();
-        vrt_enqueued_for_cooldown_amount = vrt_enqueued_for_cooldown_amount
+        self.vrt_enqueued_for_cooldown_amount = self
+            .vrt_enqueued_for_cooldown_amount
             .checked_add(amount)
             .ok_or(VaultError::VaultOverflow)?;
-        self.vrt_enqueued_for_cooldown_amount = PodU64::from(vrt_enqueued_for_cooldown_amount);
         Ok(())
     }
 
-    pub fn set_vrt_enqueued_for_cooldown_amount(&mut self, amount: u64) {
-        self.vrt_enqueued_for_cooldown_amount = PodU64::from(amount);
-    }
-
-    pub fn set_tokens_deposited(&mut self, tokens_deposited: u64) {
-        self.tokens_deposited = PodU64::from(tokens_deposited);
-    }
-
-    pub fn set_vrt_supply(&mut self, vrt_supply: u64) {
-        self.vrt_supply = PodU64::from(vrt_supply);
-    }
-
-    pub fn additional_assets_need_unstaking(&self) -> u64 {
-        self.additional_assets_need_unstaking.into()
-    }
-
-    pub fn set_additional_assets_need_unstaking(&mut self, additional_assets_need_unstaking: u64) {
-        self.additional_assets_need_unstaking = PodU64::from(additional_assets_need_unstaking);
-    }
-
-    pub fn decrement_additional_assets_need_unstaking(
+    fn decrement_additional_assets_need_unstaking(
         &mut self,
         amount: u64,
     ) -> Result<(), VaultError> {
-        let new_amount = self
-            .additional_assets_need_unstaking()
+        self.additional_assets_need_unstaking = self
+            .additional_assets_need_unstaking
             .checked_sub(amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.additional_assets_need_unstaking = PodU64::from(new_amount);
         Ok(())
     }
 
-    pub fn is_paused(&self) -> bool {
-        self.is_paused.into()
-    }
-
-    pub fn set_is_paused(&mut self, is_paused: bool) {
-        self.is_paused = PodBool::from_bool(is_paused);
-    }
-
-    // Only to be used in initialize_vault
-    pub fn initialize_vault_override_deposit_fee_bps(
+    fn initialize_vault_override_deposit_fee_bps(
         &mut self,
         deposit_fee_bps: u16,
         base: &AccountInfo,
@@ -484,15 +377,7 @@ impl Vault {
         Ok(())
     }
 
-    /// Checks whether the vault is currently paused.
-    ///
-    /// # Returns
-    ///
-    /// - `Ok(())` if the vault is not paused.
-    ///
-    /// # Errors
-    /// * [`VaultError::VaultIsPaused`] - If the vault is currently paused.
-    pub fn check_is_paused(&self) -> Result<(), VaultError> {
+    fn check_is_paused(&self) -> Result<(), VaultError> {
         if self.is_paused.into() {
             msg!("Vault is currently paused.");
             return Err(VaultError::VaultIsPaused);
@@ -501,7 +386,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError> {
+    fn check_vrt_mint(&self, vrt_mint: &Pubkey) -> Result<(), ProgramError> {
         if self.vrt_mint.ne(vrt_mint) {
             msg!("Vault VRT mint does not match the provided VRT mint");
             return Err(ProgramError::InvalidAccountData);
@@ -509,9 +394,8 @@ impl Vault {
         Ok(())
     }
 
-    /// Check admin validity and signature
     #[inline(always)]
-    pub fn check_admin(&self, admin: &Pubkey) -> Result<(), ProgramError> {
+    fn check_admin(&self, admin: &Pubkey) -> Result<(), ProgramError> {
         if self.admin.ne(admin) {
             msg!("Vault admin does not match the provided admin");
             return Err(VaultError::VaultAdminInvalid.into());
@@ -520,7 +404,7 @@ impl Vault {
     }
 
     #[inline(always)]
-    pub fn check_delegation_admin(&self, delegation_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_delegation_admin(&self, delegation_admin: &Pubkey) -> Result<(), VaultError> {
         if self.delegation_admin.ne(delegation_admin) {
             msg!("Vault delegation admin does not match the provided delegation admin");
             return Err(VaultError::VaultDelegationAdminInvalid);
@@ -528,7 +412,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_operator_admin(&self, operator_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_operator_admin(&self, operator_admin: &Pubkey) -> Result<(), VaultError> {
         if self.operator_admin.ne(operator_admin) {
             msg!("Vault operator admin does not match the provided operator admin");
             return Err(VaultError::VaultOperatorAdminInvalid);
@@ -536,7 +420,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_ncn_admin(&self, ncn_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_ncn_admin(&self, ncn_admin: &Pubkey) -> Result<(), VaultError> {
         if self.ncn_admin.ne(ncn_admin) {
             msg!("Vault NCN admin does not match the provided NCN admin");
             return Err(VaultError::VaultNcnAdminInvalid);
@@ -544,7 +428,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_slasher_admin(&self, slasher_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_slasher_admin(&self, slasher_admin: &Pubkey) -> Result<(), VaultError> {
         if self.slasher_admin.ne(slasher_admin) {
             msg!("Vault slasher admin does not match the provided slasher admin");
             return Err(VaultError::VaultSlasherAdminInvalid);
@@ -552,7 +436,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_capacity_admin(&self, capacity_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_capacity_admin(&self, capacity_admin: &Pubkey) -> Result<(), VaultError> {
         if self.capacity_admin.ne(capacity_admin) {
             msg!("Vault capacity admin does not match the provided capacity admin");
             return Err(VaultError::VaultCapacityAdminInvalid);
@@ -560,7 +444,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn check_fee_admin(&self, fee_admin: &Pubkey) -> Result<(), VaultError> {
+    fn check_fee_admin(&self, fee_admin: &Pubkey) -> Result<(), VaultError> {
         if self.fee_admin.ne(fee_admin) {
             msg!("Vault fee admin does not match the provided fee admin");
             return Err(VaultError::VaultFeeAdminInvalid);
@@ -568,22 +452,7 @@ impl Vault {
         Ok(())
     }
 
-    /// Validates the delegate_asset_admin account and ensures it matches the expected delegate_asset_admin.
-    ///
-    /// # Arguments
-    /// * `delegate_asset_admin` - A reference to the [`Pubkey`] representing the delegate_asset_admin Pubkey that is attempting
-    ///   to authorize the operation.
-    ///
-    /// # Returns
-    /// * `Result<(), VaultError>` - Returns `Ok(())` if the delegate_asset_admin Pubkey is valid.
-    ///
-    /// # Errors
-    /// This function will return a [`jito_vault_sdk::error::VaultError::VaultDelegateAssetAdminInvalid`] error in the following case:
-    /// * The `delegate_asset_admin` 's public key does not match the expected delegate_asset_admin public key stored in `self`.
-    pub fn check_delegate_asset_admin(
-        &self,
-        delegate_asset_admin: &Pubkey,
-    ) -> Result<(), VaultError> {
+    fn check_delegate_asset_admin(&self, delegate_asset_admin: &Pubkey) -> Result<(), VaultError> {
         if self.delegate_asset_admin.ne(delegate_asset_admin) {
             msg!("Vault delegate asset admin does not match the provided delegate asset admin");
             return Err(VaultError::VaultDelegateAssetAdminInvalid);
@@ -591,8 +460,7 @@ impl Vault {
         Ok(())
     }
 
-    /// Replace all secondary admins that were equal to the old admin to the new admin
-    pub fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
+    fn update_secondary_admin(&mut self, old_admin: &Pubkey, new_admin: &Pubkey) {
         if self.delegation_admin.eq(old_admin) {
             self.delegation_admin = *new_admin;
             msg!("Delegation admin set to {:?}", new_admin);
@@ -644,12 +512,8 @@ impl Vault {
         }
     }
 
-    // ------------------------------------------
-    // Asset accounting and tracking
-    // ------------------------------------------
-
     #[inline(always)]
-    pub fn is_update_needed(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
+    fn is_update_needed(&self, slot: u64, epoch_length: u64) -> Result<bool, ProgramError> {
         let last_updated_epoch = get_epoch(self.last_full_state_update_slot(), epoch_length)?;
         let current_epoch = get_epoch(slot, epoch_length)?;
 
@@ -657,7 +521,7 @@ impl Vault {
     }
 
     #[inline(always)]
-    pub fn check_update_state_ok(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError> {
+    fn check_update_state_ok(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError> {
         if self.is_update_needed(slot, epoch_length)? {
             msg!("Vault update is needed");
             return Err(VaultError::VaultUpdateNeeded.into());
@@ -666,13 +530,13 @@ impl Vault {
     }
 
     #[inline(always)]
-    pub fn check_mint_burn_admin(
+    fn check_mint_burn_admin(
         &self,
         mint_burn_admin: Option<&AccountInfo>,
     ) -> Result<(), VaultError> {
         if self.mint_burn_admin.ne(&Pubkey::default()) {
             if let Some(burn_signer) = mint_burn_admin {
-                load_signer(burn_signer, false)
+                load_signer(burn_signer, "mint_burn_admin", false)
                     .map_err(|_| VaultError::VaultMintBurnAdminInvalid)?;
                 if burn_signer.key.ne(&self.mint_burn_admin) {
                     msg!("Burn signer does not match vault burn signer");
@@ -686,13 +550,8 @@ impl Vault {
         Ok(())
     }
 
-    // ------------------------------------------
-    // Fees
-    // ------------------------------------------
-
-    /// Fees can be changed at most one per epoch, and a **full** epoch must pass before a fee can be changed again.
     #[inline(always)]
-    pub fn check_can_modify_fees(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError> {
+    fn check_can_modify_fees(&self, slot: u64, epoch_length: u64) -> Result<(), ProgramError> {
         let current_epoch = get_epoch(slot, epoch_length)?;
         let last_fee_change_epoch = get_epoch(self.last_fee_change_slot(), epoch_length)?;
 
@@ -708,7 +567,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn set_withdrawal_fee_bps(&mut self, withdrawal_fee_bps: u16) -> Result<(), VaultError> {
+    fn set_withdrawal_fee_bps(&mut self, withdrawal_fee_bps: u16) -> Result<(), VaultError> {
         if withdrawal_fee_bps > MAX_BPS {
             msg!("Withdrawal fee exceeds maximum allowed of {}", MAX_BPS);
             return Err(VaultError::VaultFeeCapExceeded);
@@ -717,7 +576,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn set_next_withdrawal_fee_bps(
+    fn set_next_withdrawal_fee_bps(
         &mut self,
         withdrawal_fee_bps: u16,
         deposit_withdrawal_fee_cap_bps: u16,
@@ -734,7 +593,7 @@ impl Vault {
             );
             return Err(VaultError::VaultFeeCapExceeded);
         }
-        Self::check_fee_change_ok(
+        check_fee_change_ok(
             self.withdrawal_fee_bps(),
             withdrawal_fee_bps,
             deposit_withdrawal_fee_cap_bps,
@@ -745,7 +604,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn set_deposit_fee_bps(
+    fn set_deposit_fee_bps(
         &mut self,
         deposit_fee_bps: u16,
         deposit_withdrawal_fee_cap_bps: u16,
@@ -763,7 +622,7 @@ impl Vault {
             return Err(VaultError::VaultFeeCapExceeded);
         }
 
-        Self::check_fee_change_ok(
+        check_fee_change_ok(
             self.deposit_fee_bps(),
             deposit_fee_bps,
             deposit_withdrawal_fee_cap_bps,
@@ -775,7 +634,7 @@ impl Vault {
         Ok(())
     }
 
-    pub fn set_reward_fee_bps(&mut self, reward_fee_bps: u16) -> Result<(), VaultError> {
+    fn set_reward_fee_bps(&mut self, reward_fee_bps: u16) -> Result<(), VaultError> {
         if reward_fee_bps > MAX_BPS {
             msg!("Reward fee exceeds maximum allowed of {}", MAX_BPS);
             return Err(VaultError::VaultFeeCapExceeded);
@@ -784,59 +643,7 @@ impl Vault {
         Ok(())
     }
 
-    fn check_fee_change_ok(
-        current_fee_bps: u16,
-        new_fee_bps: u16,
-        fee_cap_bps: u16,
-        fee_bump_bps: u16,
-        fee_rate_of_change_bps: u16,
-    ) -> Result<(), VaultError> {
-        if current_fee_bps > MAX_BPS
-            || new_fee_bps > MAX_BPS
-            || fee_cap_bps > MAX_BPS
-            || fee_bump_bps > MAX_BPS
-            || fee_rate_of_change_bps > MAX_BPS
-        {
-            // This is always false
-            msg!("BPS cannot be above {}", MAX_BPS);
-            return Err(VaultError::VaultFeeCapExceeded);
-        }
-
-        let fee_delta = new_fee_bps.saturating_sub(current_fee_bps);
-        let fee_cap_bps = fee_cap_bps.min(MAX_BPS);
-
-        if new_fee_bps > fee_cap_bps {
-            msg!("Fee exceeds maximum allowed of {}", fee_cap_bps);
-            return Err(VaultError::VaultFeeCapExceeded);
-        }
-
-        if fee_delta > fee_bump_bps {
-            let deposit_percentage_increase_bps: u64 = (fee_delta as u128)
-                .checked_mul(MAX_BPS as u128)
-                .and_then(|product| product.checked_div(current_fee_bps as u128))
-                .and_then(|result| result.try_into().ok())
-                .unwrap_or(u64::MAX); // Divide by zero should result in max value
-
-            if deposit_percentage_increase_bps > fee_rate_of_change_bps as u64 {
-                msg!(
-                    "Fee increase exceeds maximum rate of change {} bps or flat bump of {} bps",
-                    fee_rate_of_change_bps,
-                    fee_bump_bps
-                );
-                return Err(VaultError::VaultFeeBumpTooLarge);
-            }
-        }
-
-        Ok(())
-    }
-
-    // ------------------------------------------
-    // Minting and burning
-    // ------------------------------------------
-
-    /// Calculate the reward fee in terms of ST. The VRT minted as a result is further calculated
-    /// in update_vault_balance
-    pub fn calculate_st_reward_fee(&self, new_st_supply: u64) -> Result<u64, VaultError> {
+    fn calculate_st_reward_fee(&self, new_st_supply: u64) -> Result<u64, VaultError> {
         let st_rewards = new_st_supply.saturating_sub(self.tokens_deposited());
 
         if st_rewards == 0 {
@@ -852,8 +659,7 @@ impl Vault {
         Ok(st_reward_fee)
     }
 
-    /// Checks that reward fee's actual rate is within the expected rate
-    pub fn check_reward_fee_effective_rate(
+    fn check_reward_fee_effective_rate(
         &self,
         st_rewards: u64,
         vrt_reward_fee: u64,
@@ -927,42 +733,12 @@ impl Vault {
         Ok(())
     }
 
-    /// Calculate the amount of VRT tokens to mint based on the amount of tokens deposited in the vault.
-    /// If no tokens have been deposited, the amount is equal to the amount passed in.
-    /// Otherwise, the amount is calculated as the pro-rata share of the total VRT supply.
-    pub fn calculate_vrt_mint_amount(&self, amount: u64) -> Result<u64, VaultError> {
-        if self.tokens_deposited() == 0 {
-            return Ok(amount);
-        }
-
-        (amount as u128)
-            .checked_mul(self.vrt_supply() as u128)
-            .and_then(|x| x.checked_div(self.tokens_deposited() as u128))
-            .and_then(|result| result.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)
-    }
-
-    /// Calculate the amount of tokens collected as a fee for depositing tokens in the vault.
-    fn calculate_deposit_fee(&self, vrt_amount: u64) -> Result<u64, VaultError> {
-        let fee = (vrt_amount as u128)
-            .checked_mul(self.deposit_fee_bps() as u128)
-            .map(|x| x.div_ceil(MAX_BPS as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-        Ok(fee)
+    fn calculate_vrt_mint_amount(&self, amount: u64) -> Result<u64, VaultError> {
+        vault_math::calculate_vrt_mint_amount(self.tokens_deposited(), self.vrt_supply(), amount)
+            .map_err(VaultError::from)
     }
 
-    /// Calculate the amount of tokens collected as a fee for withdrawing tokens from the vault.
-    fn calculate_withdrawal_fee(&self, vrt_amount: u64) -> Result<u64, VaultError> {
-        let fee = (vrt_amount as u128)
-            .checked_mul(self.withdrawal_fee_bps() as u128)
-            .map(|x| x.div_ceil(MAX_BPS as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-        Ok(fee)
-    }
-
-    pub fn mint_with_fee(
+    fn mint_with_fee(
         &mut self,
         amount_in: u64,
         min_amount_out: u64,
@@ -973,16 +749,16 @@ impl Vault {
         }
 
         let vault_token_amount_after_deposit = self
-            .tokens_deposited()
+            .tokens_deposited
             .checked_add(amount_in)
             .ok_or(VaultError::VaultOverflow)?;
-        if vault_token_amount_after_deposit > self.deposit_capacity() {
+        if vault_token_amount_after_deposit > self.deposit_capacity {
             msg!("Amount exceeds vault capacity");
             return Err(VaultError::VaultCapacityExceeded);
         }
 
         let vrt_mint_amount = self.calculate_vrt_mint_amount(amount_in)?;
-        let vrt_to_fee_wallet = self.calculate_deposit_fee(vrt_mint_amount)?;
+        let vrt_to_fee_wallet = calculate_deposit_fee(self, vrt_mint_amount)?;
         let vrt_to_depositor = vrt_mint_amount
             .checked_sub(vrt_to_fee_wallet)
             .ok_or(VaultError::VaultUnderflow)?;
@@ -996,12 +772,11 @@ impl Vault {
             return Err(VaultError::SlippageError);
         }
 
-        let vrt_supply = self
-            .vrt_supply()
+        self.vrt_supply = self
+            .vrt_supply
             .checked_add(vrt_mint_amount)
             .ok_or(VaultError::VaultOverflow)?;
-        self.vrt_supply = PodU64::from(vrt_supply);
-        self.tokens_deposited = PodU64::from(vault_token_amount_after_deposit);
+        self.tokens_deposited = vault_token_amount_after_deposit;
 
         Ok(MintSummary {
             vrt_to_depositor,
@@ -1009,55 +784,25 @@ impl Vault {
         })
     }
 
-    pub fn calculate_burn_summary(
+    fn calculate_burn_summary(
         &self,
         is_staker_program_fee_wallet: bool,
         is_staker_vault_fee_wallet: bool,
         amount_in: u64,
     ) -> Result<BurnSummary, VaultError> {
-        let mut program_fee_amount =
-            Config::calculate_program_fee(self.program_fee_bps(), amount_in)?;
-        let mut vault_fee_amount = self.calculate_withdrawal_fee(amount_in)?;
-
-        // Prioritize program fee over vault fee if together they exceed the amount in
-        if program_fee_amount
-            .checked_add(vault_fee_amount)
-            .ok_or(VaultError::VaultOverflow)?
-            > amount_in
-        {
-            vault_fee_amount = amount_in
-                .checked_sub(program_fee_amount)
-                .ok_or(VaultError::VaultUnderflow)?;
-        }
-
-        if is_staker_program_fee_wallet {
-            program_fee_amount = 0;
-        }
-
-        if is_staker_vault_fee_wallet {
-            vault_fee_amount = 0;
-        }
-
-        let amount_to_burn = amount_in
-            .checked_sub(program_fee_amount)
-            .and_then(|x| x.checked_sub(vault_fee_amount))
-            .ok_or(VaultError::VaultUnderflow)?;
-
-        let amount_out = (amount_to_burn as u128)
-            .checked_mul(self.tokens_deposited() as u128)
-            .and_then(|x| x.checked_div(self.vrt_supply() as u128))
-            .and_then(|x| x.try_into().ok())
-            .ok_or(VaultError::VaultOverflow)?;
-
-        Ok(BurnSummary {
-            program_fee_amount,
-            vault_fee_amount,
-            burn_amount: amount_to_burn,
-            out_amount: amount_out,
-        })
+        vault_math::calculate_burn_summary(
+            self.tokens_deposited(),
+            self.vrt_supply(),
+            self.program_fee_bps(),
+            self.withdrawal_fee_bps(),
+            is_staker_program_fee_wallet,
+            is_staker_vault_fee_wallet,
+            amount_in,
+        )
+        .map_err(VaultError::from)
     }
 
-    pub fn burn_with_fee(
+    fn burn_with_fee(
         &mut self,
         is_staker_program_fee_wallet: bool,
         is_staker_vault_fee_wallet: bool,
@@ -1092,17 +837,14 @@ impl Vault {
             return Err(VaultError::VaultUnderflow);
         }
 
-        let vrt_supply = self
-            .vrt_supply()
+        self.vrt_supply = self
+            .vrt_supply
             .checked_sub(burn_amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.vrt_supply = PodU64::from(vrt_supply);
-
-        let tokens_deposited = self
-            .tokens_deposited()
+        self.tokens_deposited = self
+            .tokens_deposited
             .checked_sub(out_amount)
             .ok_or(VaultError::VaultUnderflow)?;
-        self.tokens_deposited = PodU64::from(tokens_deposited);
 
         Ok(BurnSummary {
             program_fee_amount,
@@ -1112,9 +854,7 @@ impl Vault {
         })
     }
 
-    /// Calculates the amount of tokens, denominated in the supported_mint asset,
-    /// that should be reserved for the VRTs in the vault
-    pub fn calculate_supported_assets_requested_for_withdrawal(&self) -> Result<u64, VaultError> {
+    fn calculate_supported_assets_requested_for_withdrawal(&self) -> Result<u64, VaultError> {
         if self.vrt_supply() == 0 {
             return Ok(0);
         }
@@ -1132,7 +872,7 @@ impl Vault {
         Ok(amount_to_reserve_for_vrts)
     }
 
-    pub fn calculate_additional_supported_assets_needed_to_unstake(
+    fn calculate_additional_supported_assets_needed_to_unstake(
         &self,
         slot: u64,
         epoch_length: u64,
@@ -1168,7 +908,9 @@ impl Vault {
         }
 
         // Calculate the total amount of assets delegated after the simulated update
-        let total_delegated_after_update = delegation_state_after_update.total_security()?;
+        let total_delegated_after_update = delegation_state_after_update
+            .total_security()
+            .map_err(VaultError::from)?;
 
         // Calculate the amount of assets that are not delegated after the simulated update
         let undelegated_after_update = self
@@ -1197,7 +939,7 @@ impl Vault {
         Ok(additional_assets_need_undelegating)
     }
 
-    pub fn delegate(&mut self, amount: u64) -> Result<(), VaultError> {
+    fn delegate(&mut self, amount: u64) -> Result<(), VaultError> {
         if amount == 0 {
             msg!("Delegation amount is zero");
             return Err(VaultError::VaultDelegationZero);
@@ -1226,99 +968,70 @@ impl Vault {
 
         Ok(())
     }
+}
 
-    // ------------------------------------------
-    // Serialization & Deserialization
-    // ------------------------------------------
-
-    /// Returns the seeds for the PDA
-    ///
-    /// # Returns
-    /// * `Vec<Vec<u8>>` - containing the seed vectors
-    pub fn seeds(base: &Pubkey) -> Vec<Vec<u8>> {
-        vec![b"vault".as_ref().to_vec(), base.to_bytes().to_vec()]
-    }
-
-    /// Returns the seeds for the PDA used for signing
-    pub fn signing_seeds(&self) -> Vec<Vec<u8>> {
-        let mut vault_seeds = Self::seeds(&self.base);
-        vault_seeds.push(vec![self.bump]);
-        vault_seeds
-    }
-
-    /// Find the program address for the Vault
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `base` - The base account used as a PDA seed
-    ///
-    /// # Returns
-    /// * [`Pubkey`] - The program address
-    /// * `u8` - The bump seed
-    /// * `Vec<Vec<u8>>` - The seeds used to generate the PDA
-    pub fn find_program_address(program_id: &Pubkey, base: &Pubkey) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(base);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
+fn check_fee_change_ok(
+    current_fee_bps: u16,
+    new_fee_bps: u16,
+    fee_cap_bps: u16,
+    fee_bump_bps: u16,
+    fee_rate_of_change_bps: u16,
+) -> Result<(), VaultError> {
+    if current_fee_bps > MAX_BPS
+        || new_fee_bps > MAX_BPS
+        || fee_cap_bps > MAX_BPS
+        || fee_bump_bps > MAX_BPS
+        || fee_rate_of_change_bps > MAX_BPS
+    {
+        // This is always false
+        msg!("BPS cannot be above {}", MAX_BPS);
+        return Err(VaultError::VaultFeeCapExceeded);
+    }
+
+    let fee_delta = new_fee_bps.saturating_sub(current_fee_bps);
+    let fee_cap_bps = fee_cap_bps.min(MAX_BPS);
+
+    if new_fee_bps > fee_cap_bps {
+        msg!("Fee exceeds maximum allowed of {}", fee_cap_bps);
+        return Err(VaultError::VaultFeeCapExceeded);
+    }
+
+    if fee_delta > fee_bump_bps {
+        let deposit_percentage_increase_bps: u64 = (fee_delta as u128)
+            .checked_mul(MAX_BPS as u128)
+            .and_then(|product| product.checked_div(current_fee_bps as u128))
+            .and_then(|result| result.try_into().ok())
+            .unwrap_or(u64::MAX); // Divide by zero should result in max value
 
-    /// Loads the [`Vault`] account
-    ///
-    /// # Arguments
-    /// * `program_id` - The program ID
-    /// * `account` - The account to load
-    /// * `expect_writable` - Whether the account should be writable
-    ///
-    /// # Returns
-    /// * `Result<(), ProgramError>` - The result of the operation
-    pub fn load(
-        program_id: &Pubkey,
-        account: &AccountInfo,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if account.owner.ne(program_id) {
-            msg!("Vault account has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if account.data_is_empty() {
-            msg!("Vault account data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !account.is_writable {
-            msg!("Vault account is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if account.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault account discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
+        if deposit_percentage_increase_bps > fee_rate_of_change_bps as u64 {
+            msg!(
+                "Fee increase exceeds maximum rate of change {} bps or flat bump of {} bps",
+                fee_rate_of_change_bps,
+                fee_bump_bps
+            );
+            return Err(VaultError::VaultFeeBumpTooLarge);
         }
+    }
 
-        let vault_data = &account.data.borrow();
-        let vault = Self::try_from_slice_unchecked(vault_data)?;
-        let seeds = vault.signing_seeds();
-        let seed_slices: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
-        let expected_pubkey = Pubkey::create_program_address(&seed_slices, program_id)?;
-        if account.key.ne(&expected_pubkey) {
-            msg!("Vault account is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
+    Ok(())
+}
 
-        Ok(())
-    }
+/// Calculate the amount of tokens collected as a fee for depositing tokens in the vault.
+fn calculate_deposit_fee(vault: &Vault, vrt_amount: u64) -> Result<u64, VaultError> {
+    vault_math::calculate_fee(vault.deposit_fee_bps(), vrt_amount).map_err(VaultError::from)
 }
 
 #[cfg(test)]
 mod tests {
     use std::{cell::RefCell, rc::Rc};
 
-    use jito_bytemuck::types::{PodBool, PodU16, PodU64};
+    use jito_bytemuck::types::PodU64;
     use jito_vault_sdk::error::VaultError;
     use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
     use crate::{
         delegation_state::DelegationState,
-        vault::{BurnSummary, MintSummary, Vault, RESERVED_SPACE_LEN},
+        vault::{check_fee_change_ok, BurnSummary, MintSummary, Vault, VaultExt},
         MAX_BPS,
     };
 
@@ -1351,50 +1064,6 @@ mod tests {
         vault
     }
 
-    #[test]
-    fn test_vault_no_padding() {
-        let vault_size = std::mem::size_of::<Vault>();
-        let sum_of_fields = std::mem::size_of::<Pubkey>() + // base
-            std::mem::size_of::<Pubkey>() + // vrt_mint
-            std::mem::size_of::<Pubkey>() + // supported_mint
-            std::mem::size_of::<PodU64>() + // vrt_supply
-            std::mem::size_of::<PodU64>() + // tokens_deposited
-            std::mem::size_of::<PodU64>() + // capacity
-            std::mem::size_of::<DelegationState>() + // delegation_state
-            std::mem::size_of::<PodU64>() + // additional_assets_needed_to_unstake
-            std::mem::size_of::<PodU64>() + // vrt_enqueued_for_cooldown_amount
-            std::mem::size_of::<PodU64>() + // vrt_cooling_down_amount
-            std::mem::size_of::<PodU64>() + // vrt_ready_to_claim_amount
-            std::mem::size_of::<Pubkey>() + // admin
-            std::mem::size_of::<Pubkey>() + // delegation_admin
-            std::mem::size_of::<Pubkey>() + // operator_admin
-            std::mem::size_of::<Pubkey>() + // ncn_admin
-            std::mem::size_of::<Pubkey>() + // slasher_admin
-            std::mem::size_of::<Pubkey>() + // capacity_admin
-            std::mem::size_of::<Pubkey>() + // fee_admin
-            std::mem::size_of::<Pubkey>() + // delegate_asset_admin
-            std::mem::size_of::<Pubkey>() + // fee_wallet
-            std::mem::size_of::<Pubkey>() + // mint_burn_admin
-            std::mem::size_of::<Pubkey>() + // metadata_admin
-            std::mem::size_of::<PodU64>() + // vault_index
-            std::mem::size_of::<PodU64>() + // ncn_count
-            std::mem::size_of::<PodU64>() + // operator_count
-            std::mem::size_of::<PodU64>() + // slasher_count
-            std::mem::size_of::<PodU64>() + // last_fee_change_slot
-            std::mem::size_of::<PodU64>() + // last_full_state_update_slot
-            std::mem::size_of::<PodU16>() + // deposit_fee_bps
-            std::mem::size_of::<PodU16>() + // withdrawal_fee_bps
-            std::mem::size_of::<PodU16>() + // next_withdrawal_fee_bps
-            std::mem::size_of::<PodU16>() + // reward_fee_bps
-            std::mem::size_of::<PodU16>() + // program_fee_bps
-            std::mem::size_of::<PodBool>() + // is_paused
-            std::mem::size_of::<PodU64>() + // last_start_state_update_slot
-            1 + // bump
-            RESERVED_SPACE_LEN; // reserved
-
-        assert_eq!(vault_size, sum_of_fields);
-    }
-
     #[test]
     fn test_update_secondary_admin_ok() {
         let old_admin = Pubkey::new_unique();
@@ -2168,7 +1837,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // OK: 25% increase <= 25% limit
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2187,7 +1856,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // ERROR: 26% increase > 25% limit
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2206,7 +1875,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // OK: Δ <= bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2225,7 +1894,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // ERROR: Δ > bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2244,7 +1913,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // OK: Δ <= bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2263,7 +1932,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // Error: Δ > bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2282,7 +1951,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // OK: Δ <= bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2301,7 +1970,7 @@ mod tests {
         let fee_rate_of_change_bps = 2500;
 
         // OK: Δ <= bump
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2321,7 +1990,7 @@ mod tests {
         let fee_bump_bps = 10;
         let fee_rate_of_change_bps = 2500;
 
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2339,7 +2008,7 @@ mod tests {
         let fee_bump_bps = 10;
         let fee_rate_of_change_bps = 2500;
 
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2357,7 +2026,7 @@ mod tests {
         let fee_bump_bps = 10;
         let fee_rate_of_change_bps = 2500;
 
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2375,7 +2044,7 @@ mod tests {
         let fee_bump_bps = 10;
         let fee_rate_of_change_bps = 2500;
 
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -2393,7 +2062,7 @@ mod tests {
         let fee_bump_bps = 10;
         let fee_rate_of_change_bps = 2500;
 
-        assert!(Vault::check_fee_change_ok(
+        assert!(check_fee_change_ok(
             current_fee_bps,
             new_fee_bps,
             fee_cap_bps,
@@ -1,61 +1,20 @@
-use bytemuck::{Pod, Zeroable};
-use jito_bytemuck::{types::PodU64, AccountDeserialize, Discriminator};
+use jito_bytemuck::types::PodU64;
+pub use jito_layouts::vault::vault_update_state_tracker::VaultUpdateStateTracker;
 use jito_vault_sdk::error::VaultError;
-use shank::ShankAccount;
-use solana_program::{account_info::AccountInfo, msg, program_error::ProgramError, pubkey::Pubkey};
-
-use crate::delegation_state::DelegationState;
-
-const RESERVED_SPACE_LEN: usize = 263;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Pod, Zeroable, AccountDeserialize, ShankAccount)]
-#[repr(C)]
-pub struct VaultUpdateStateTracker {
-    /// The vault associated with this update ticket
-    pub vault: Pubkey,
-
-    /// The NCN epoch for which the delegations are to be updated
-    ncn_epoch: PodU64,
-
-    /// The last updated index of the vault
-    last_updated_index: PodU64,
-
-    /// The total amount delegated across all the operators in the vault
-    pub delegation_state: DelegationState,
-
-    pub withdrawal_allocation_method: u8,
-
-    reserved: [u8; 263],
-}
-
-impl VaultUpdateStateTracker {
-    pub fn new(vault: Pubkey, ncn_epoch: u64, withdrawal_allocation_method: u8) -> Self {
-        Self {
-            vault,
-            ncn_epoch: PodU64::from(ncn_epoch),
-            last_updated_index: PodU64::from(u64::MAX),
-            delegation_state: DelegationState::default(),
-            withdrawal_allocation_method,
-            reserved: [0; RESERVED_SPACE_LEN],
-        }
-    }
-
-    pub fn ncn_epoch(&self) -> u64 {
-        self.ncn_epoch.into()
-    }
-
-    pub fn last_updated_index(&self) -> u64 {
-        self.last_updated_index.into()
-    }
+use solana_program::msg;
 
+/// The [`VaultUpdateStateTracker`] methods that return a [`VaultError`]
+pub trait VaultUpdateStateTrackerExt {
     /// Checks and updates the index of the vault update state tracker
     /// Index starts at different values depending on the NCN epoch to prevent
     /// any single operator from getting starved
-    pub fn check_and_update_index(
-        &mut self,
-        index: u64,
-        num_operators: u64,
-    ) -> Result<(), VaultError> {
+    fn check_and_update_index(&mut self, index: u64, num_operators: u64) -> Result<(), VaultError>;
+
+    fn all_operators_updated(&self, num_operators: u64) -> Result<bool, VaultError>;
+}
+
+impl VaultUpdateStateTrackerExt for VaultUpdateStateTracker {
+    fn check_and_update_index(&mut self, index: u64, num_operators: u64) -> Result<(), VaultError> {
         if self.last_updated_index() == u64::MAX {
             let start_index = self
                 .ncn_epoch()
@@ -81,7 +40,7 @@ impl VaultUpdateStateTracker {
         Ok(())
     }
 
-    pub fn all_operators_updated(&self, num_operators: u64) -> Result<bool, VaultError> {
+    fn all_operators_updated(&self, num_operators: u64) -> Result<bool, VaultError> {
         if self.last_updated_index() == u64::MAX {
             return Ok(false);
         }
@@ -103,62 +62,6 @@ impl VaultUpdateStateTracker {
                 .checked_sub(1)
                 .ok_or(VaultError::ArithmeticUnderflow)?)
     }
-
-    /// Returns the seeds for the PDA
-    ///
-    /// # Arguments
-    /// * `vault` - The vault
-    /// * `ncn_epoch` - The NCN epoch
-    pub fn seeds(vault: &Pubkey, ncn_epoch: u64) -> Vec<Vec<u8>> {
-        Vec::from_iter([
-            b"vault_update_state_tracker".to_vec(),
-            vault.to_bytes().to_vec(),
-            ncn_epoch.to_le_bytes().to_vec(),
-        ])
-    }
-
-    /// Find the program address for the PDA
-    pub fn find_program_address(
-        program_id: &Pubkey,
-        vault: &Pubkey,
-        ncn_epoch: u64,
-    ) -> (Pubkey, u8, Vec<Vec<u8>>) {
-        let seeds = Self::seeds(vault, ncn_epoch);
-        let seeds_iter: Vec<_> = seeds.iter().map(|s| s.as_slice()).collect();
-        let (pda, bump) = Pubkey::find_program_address(&seeds_iter, program_id);
-        (pda, bump, seeds)
-    }
-
-    pub fn load(
-        program_id: &Pubkey,
-        vault_update_delegation_ticket: &AccountInfo,
-        vault: &AccountInfo,
-        ncn_epoch: u64,
-        expect_writable: bool,
-    ) -> Result<(), ProgramError> {
-        if vault_update_delegation_ticket.owner.ne(program_id) {
-            msg!("Vault update delegations ticket has an invalid owner");
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-        if vault_update_delegation_ticket.data_is_empty() {
-            msg!("Vault update delegations ticket data is empty");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if expect_writable && !vault_update_delegation_ticket.is_writable {
-            msg!("Vault update delegations ticket is not writable");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        if vault_update_delegation_ticket.data.borrow()[0].ne(&Self::DISCRIMINATOR) {
-            msg!("Vault update delegations ticket discriminator is invalid");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        let expected_pubkey = Self::find_program_address(program_id, vault.key, ncn_epoch).0;
-        if vault_update_delegation_ticket.key.ne(&expected_pubkey) {
-            msg!("Vault update delegations ticket is not at the correct PDA");
-            return Err(ProgramError::InvalidAccountData);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -167,22 +70,7 @@ mod tests {
     use jito_vault_sdk::error::VaultError;
     use solana_program::pubkey::Pubkey;
 
-    use crate::{
-        delegation_state::DelegationState,
-        vault_update_state_tracker::{VaultUpdateStateTracker, RESERVED_SPACE_LEN},
-    };
-
-    #[test]
-    fn test_vault_update_state_tracker_no_padding() {
-        let vault_update_state_tracker_size = std::mem::size_of::<VaultUpdateStateTracker>();
-        let sum_of_fields = size_of::<Pubkey>() + // vault
-            size_of::<PodU64>() + // ncn_epoch
-            size_of::<PodU64>() + // last_updated_index
-            size_of::<DelegationState>() + // delegation_state
-            size_of::<u8>() + // withdrawal_allocation_method
-            RESERVED_SPACE_LEN; // reserved
-        assert_eq!(vault_update_state_tracker_size, sum_of_fields);
-    }
+    use crate::vault_update_state_tracker::{VaultUpdateStateTracker, VaultUpdateStateTrackerExt};
 
     #[test]
     fn test_update_index_zero_ok() {
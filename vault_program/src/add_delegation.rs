@@ -1,12 +1,14 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::operator::Operator;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_operator_delegation::VaultOperatorDelegation,
 };
+use jito_vault_sdk::{error::VaultError, instruction::AddDelegationAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// Process the addition of a delegation: [`jito_vault_sdk::instruction::VaultInstruction::AddDelegation`]
@@ -24,32 +26,23 @@ pub fn process_add_delegation(
     accounts: &[AccountInfo],
     amount: u64,
 ) -> ProgramResult {
-    let [config, vault_info, operator, vault_operator_delegation, vault_delegation_admin] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let AddDelegationAccounts {
+        config,
+        vault: vault_info,
+        operator: _,
+        vault_operator_delegation,
+        admin: vault_delegation_admin,
+    } = AddDelegationAccounts::try_from_accounts(program_id, accounts)?;
 
     let clock = Clock::get()?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    Operator::load(&config.restaking_program, operator, false)?;
-    VaultOperatorDelegation::load(
-        program_id,
-        vault_operator_delegation,
-        vault_info,
-        operator,
-        true,
-    )?;
     let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
-    load_signer(vault_delegation_admin, false)?;
 
     // The Vault delegation admin shall be the signer of the transaction
     vault.check_delegation_admin(vault_delegation_admin.key)?;
@@ -59,11 +52,12 @@ pub fn process_add_delegation(
 
     vault.check_is_paused()?;
 
-    vault.delegate(amount)?;
+    vault.delegate(amount).map_err(VaultError::from)?;
 
     vault_operator_delegation
         .delegation_state
-        .delegate(amount)?;
+        .delegate(amount)
+        .map_err(VaultError::from)?;
 
     // msg!(
     //     "ADD DELEGATION: Vault {} delegating {} tokens to Operator {}",
@@ -1,36 +1,29 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
+use jito_jsm_core::accounts::AccountsContext;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
-};
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
+    vault::{Vault, VaultExt},
+    vault_staker_withdrawal_ticket::{VaultStakerWithdrawalTicket, VaultStakerWithdrawalTicketExt},
 };
+use jito_vault_sdk::instruction::ChangeWithdrawalTicketOwnerAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 pub fn process_change_withdrawal_ticket_owner(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, vault_staker_withdrawal_ticket, old_owner, new_owner] = accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault_info, false)?;
+    let ChangeWithdrawalTicketOwnerAccounts {
+        config: _,
+        vault: vault_info,
+        vault_staker_withdrawal_ticket,
+        old_owner,
+        new_owner,
+    } = ChangeWithdrawalTicketOwnerAccounts::try_from_accounts(program_id, accounts)?;
     let vault_data = vault_info.data.borrow();
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
-    VaultStakerWithdrawalTicket::load(
-        program_id,
-        vault_staker_withdrawal_ticket,
-        vault_info,
-        true,
-    )?;
     let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
     let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
         &mut vault_staker_withdrawal_ticket_data,
     )?;
-    load_signer(old_owner, false)?;
 
     vault.check_is_paused()?;
 
@@ -1,12 +1,14 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::{close_program_account, get_epoch, loader::load_signer};
+use jito_jsm_core::{accounts::AccountsContext, close_program_account, get_epoch};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_update_state_tracker::{VaultUpdateStateTracker, VaultUpdateStateTrackerExt},
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::CloseVaultUpdateStateTrackerAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// Close the VaultUpdateStateTracker
@@ -16,29 +18,29 @@ pub fn process_close_vault_update_state_tracker(
     accounts: &[AccountInfo],
     ncn_epoch: u64,
 ) -> ProgramResult {
-    let [config, vault_info, vault_update_state_tracker_info, payer] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let CloseVaultUpdateStateTrackerAccounts {
+        config,
+        vault: vault_info,
+        vault_update_state_tracker: vault_update_state_tracker_info,
+        payer,
+    } = CloseVaultUpdateStateTrackerAccounts::try_from_accounts(program_id, accounts)?;
 
     let slot = Clock::get()?.slot;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data: std::cell::RefMut<'_, &mut [u8]> = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
     VaultUpdateStateTracker::load(
         program_id,
         vault_update_state_tracker_info,
-        vault_info,
+        vault_info.info(),
         ncn_epoch,
         true,
     )?;
     let vault_update_state_tracker_data = vault_update_state_tracker_info.data.borrow();
     let vault_update_state_tracker =
         VaultUpdateStateTracker::try_from_slice_unchecked(&vault_update_state_tracker_data)?;
-    load_signer(payer, true)?;
 
     vault.check_is_paused()?;
 
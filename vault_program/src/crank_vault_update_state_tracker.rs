@@ -1,44 +1,45 @@
 use std::cmp::min;
 
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::get_epoch;
-use jito_restaking_core::operator::Operator;
+use jito_jsm_core::{accounts::AccountsContext, get_epoch};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
-    vault_update_state_tracker::VaultUpdateStateTracker,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_operator_delegation::{VaultOperatorDelegation, VaultOperatorDelegationExt},
+    vault_update_state_tracker::{VaultUpdateStateTracker, VaultUpdateStateTrackerExt},
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    instruction::{CrankVaultUpdateStateTrackerAccounts, WithdrawalAllocationMethod},
 };
-use jito_vault_sdk::instruction::WithdrawalAllocationMethod;
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
+/// Updates an operator delegation for the current epoch and accumulates it into the vault update
+/// state tracker.
+///
+/// This runs for every operator of every vault each epoch, so it must stay within
+/// [`jito_vault_sdk::compute_units::CRANK_VAULT_UPDATE_STATE_TRACKER_COMPUTE_UNITS`].
 pub fn process_crank_vault_update_state_tracker(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, operator, vault_operator_delegation, vault_update_state_tracker] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let CrankVaultUpdateStateTrackerAccounts {
+        config,
+        vault: vault_info,
+        operator: _,
+        vault_operator_delegation,
+        vault_update_state_tracker,
+    } = CrankVaultUpdateStateTrackerAccounts::try_from_accounts(program_id, accounts)?;
 
     let slot = Clock::get()?.slot;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    Operator::load(&config.restaking_program, operator, false)?;
-    VaultOperatorDelegation::load(
-        program_id,
-        vault_operator_delegation,
-        vault_info,
-        operator,
-        true,
-    )?;
     let mut vault_operator_delegation_data = vault_operator_delegation.data.borrow_mut();
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
@@ -63,7 +64,7 @@ pub fn process_crank_vault_update_state_tracker(
     VaultUpdateStateTracker::load(
         program_id,
         vault_update_state_tracker,
-        vault_info,
+        vault_info.info(),
         ncn_epoch,
         true,
     )?;
@@ -74,7 +75,7 @@ pub fn process_crank_vault_update_state_tracker(
 
     vault.check_is_paused()?;
 
-    vault_operator_delegation.check_is_already_updated(slot, config.epoch_length())?;
+    vault_operator_delegation.check_is_already_updated(slot, epoch_length)?;
     vault_update_state_tracker
         .check_and_update_index(vault_operator_delegation.index(), vault.operator_count())?;
 
@@ -103,7 +104,8 @@ pub fn process_crank_vault_update_state_tracker(
 
                 vault_operator_delegation
                     .delegation_state
-                    .cooldown(max_cooldown)?;
+                    .cooldown(max_cooldown)
+                    .map_err(VaultError::from)?;
                 vault.decrement_additional_assets_need_unstaking(max_cooldown)?;
             }
         }
@@ -116,10 +118,11 @@ pub fn process_crank_vault_update_state_tracker(
         }
     }
 
-    vault_operator_delegation.update(slot, config.epoch_length())?;
+    vault_operator_delegation.update(slot, epoch_length)?;
     vault_update_state_tracker
         .delegation_state
-        .accumulate(&vault_operator_delegation.delegation_state)?;
+        .accumulate(&vault_operator_delegation.delegation_state)
+        .map_err(VaultError::from)?;
 
     Ok(())
 }
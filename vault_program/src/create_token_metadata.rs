@@ -1,10 +1,17 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_signer, load_system_account, load_system_program, load_token_mint,
+use jito_jsm_core::{
+    accounts::AccountsContext,
+    loader::{load_system_account, load_token_mint},
 };
-use jito_vault_core::{loader::load_mpl_metadata_program, vault::Vault};
-use jito_vault_sdk::inline_mpl_token_metadata::{
-    instruction::create_metadata_accounts_v3, pda::find_metadata_account,
+use jito_vault_core::{
+    loader::load_mpl_metadata_program,
+    vault::{Vault, VaultExt},
+};
+use jito_vault_sdk::{
+    inline_mpl_token_metadata::{
+        instruction::create_metadata_accounts_v3, pda::find_metadata_account,
+    },
+    instruction::CreateTokenMetadataAccounts,
 };
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
@@ -18,21 +25,21 @@ pub fn process_create_token_metadata(
     symbol: String,
     uri: String,
 ) -> ProgramResult {
-    let [vault_info, admin, vrt_mint, payer, metadata, mpl_token_metadata_program, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let CreateTokenMetadataAccounts {
+        vault: vault_info,
+        admin,
+        vrt_mint,
+        payer,
+        metadata,
+        mpl_token_metadata_program,
+        system_program,
+    } = CreateTokenMetadataAccounts::try_from_accounts(program_id, accounts)?;
 
-    Vault::load(program_id, vault_info, false)?;
     let vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
-    load_signer(admin, false)?;
-    load_token_mint(vrt_mint)?;
-    load_signer(payer, true)?;
-    load_system_account(metadata, true)?;
+    load_token_mint(vrt_mint, "vrt_mint")?;
+    load_system_account(metadata, "metadata", true)?;
     load_mpl_metadata_program(mpl_token_metadata_program)?;
-    load_system_program(system_program)?;
 
     vault.check_admin(admin.key)?;
     vault.check_vrt_mint(vrt_mint.key)?;
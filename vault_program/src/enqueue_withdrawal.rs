@@ -1,17 +1,13 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{
-        load_associated_token_account, load_signer, load_system_account, load_system_program,
-        load_token_program,
-    },
-};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::EnqueueWithdrawalAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, program::invoke,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -33,34 +29,30 @@ pub fn process_enqueue_withdrawal(
     accounts: &[AccountInfo],
     vrt_amount: u64,
 ) -> ProgramResult {
-    let (required_accounts, optional_accounts) = accounts.split_at(9);
-
-    let [config, vault_info, vault_staker_withdrawal_ticket, vault_staker_withdrawal_ticket_token_account, staker, staker_vrt_token_account, base, token_program, system_program] =
-        required_accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let EnqueueWithdrawalAccounts {
+        config,
+        vault: vault_info,
+        vault_staker_withdrawal_ticket,
+        vault_staker_withdrawal_ticket_token_account,
+        staker,
+        staker_vrt_token_account,
+        base,
+        token_program: _,
+        system_program,
+        burn_signer,
+    } = EnqueueWithdrawalAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_system_account(vault_staker_withdrawal_ticket, true)?;
-    load_associated_token_account(
-        vault_staker_withdrawal_ticket_token_account,
-        vault_staker_withdrawal_ticket.key,
-        &vault.vrt_mint,
+    load_system_account(
+        vault_staker_withdrawal_ticket,
+        "vault_staker_withdrawal_ticket",
+        true,
     )?;
-    load_signer(staker, false)?;
-    load_associated_token_account(staker_vrt_token_account, staker.key, &vault.vrt_mint)?;
-    load_token_program(token_program)?;
-
-    load_signer(base, false)?;
-    load_system_program(system_program)?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    vault.check_mint_burn_admin(burn_signer)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
 
@@ -101,7 +93,7 @@ pub fn process_enqueue_withdrawal(
         &vault_staker_withdrawal_ticket_seeds,
     )?;
     let mut vault_staker_withdrawal_ticket_data = vault_staker_withdrawal_ticket.data.borrow_mut();
-    vault_staker_withdrawal_ticket_data[0] = VaultStakerWithdrawalTicket::DISCRIMINATOR;
+    VaultStakerWithdrawalTicket::write_header(&mut vault_staker_withdrawal_ticket_data);
     let vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket::try_from_slice_unchecked_mut(
         &mut vault_staker_withdrawal_ticket_data,
     )?;
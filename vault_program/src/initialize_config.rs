@@ -1,12 +1,9 @@
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_vault_sdk::{error::VaultError, instruction::InitializeConfigAccounts};
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
+use jito_bytemuck::AccountDeserialize;
 use jito_vault_core::{config::Config, MAX_BPS};
-use jito_vault_sdk::error::VaultError;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
     pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -18,13 +15,15 @@ pub fn process_initialize_config(
     accounts: &[AccountInfo],
     program_fee_bps: u16,
 ) -> ProgramResult {
-    let [config, admin, restaking_program, program_fee_wallet, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeConfigAccounts {
+        config,
+        admin,
+        restaking_program,
+        program_fee_wallet,
+        system_program,
+    } = InitializeConfigAccounts::try_from_accounts(program_id, accounts)?;
 
-    load_system_account(config, true)?;
-    load_signer(admin, true)?;
-    load_system_program(system_program)?;
+    load_system_account(config, "config", true)?;
 
     // The config account shall be at the canonical PDA
     let (config_pubkey, config_bump, mut config_seeds) = Config::find_program_address(program_id);
@@ -53,7 +52,7 @@ pub fn process_initialize_config(
     )?;
 
     let mut config_data = config.try_borrow_mut_data()?;
-    config_data[0] = Config::DISCRIMINATOR;
+    Config::write_header(&mut config_data);
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
     *config = Config::new(
         *admin.key,
@@ -1,15 +1,18 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
+    accounts::AccountsContext,
     create_account,
-    loader::{
-        load_associated_token_account_program, load_signer, load_system_account,
-        load_system_program, load_token_account, load_token_mint, load_token_program,
-    },
+    loader::{load_system_account, load_token_account, load_token_mint},
 };
-use jito_vault_core::{burn_vault::BurnVault, config::Config, vault::Vault, MAX_BPS};
-use jito_vault_sdk::error::VaultError;
+use jito_vault_core::{
+    burn_vault::BurnVault,
+    config::{Config, ConfigExt},
+    vault::{Vault, VaultExt},
+    MAX_BPS,
+};
+use jito_vault_sdk::{error::VaultError, instruction::InitializeVaultAccounts};
 use solana_program::{
     account_info::AccountInfo,
     clock::Clock,
@@ -39,38 +42,47 @@ pub fn process_initialize_vault(
     decimals: u8,
     initialize_token_amount: u64,
 ) -> ProgramResult {
-    let [config, vault, vrt_mint, st_mint, admin_st_token_account, vault_st_token_account, burn_vault, burn_vault_vrt_token_account, admin, base, system_program, token_program, associated_token_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, true)?;
+    let InitializeVaultAccounts {
+        config,
+        vault,
+        vrt_mint,
+        st_mint,
+        admin_st_token_account,
+        vault_st_token_account,
+        burn_vault,
+        burn_vault_vrt_token_account,
+        admin,
+        base,
+        system_program,
+        token_program,
+        associated_token_program,
+    } = InitializeVaultAccounts::try_from_accounts(program_id, accounts)?;
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
 
-    load_system_account(vault, true)?;
-    load_system_account(vrt_mint, true)?;
-    load_signer(vrt_mint, true)?;
-    load_token_mint(st_mint)?;
+    load_system_account(vault, "vault", true)?;
+    load_system_account(vrt_mint, "vrt_mint", true)?;
+    load_token_mint(st_mint, "st_mint")?;
     load_token_account(
         admin_st_token_account,
+        "admin_st_token_account",
         admin.key,
         st_mint.key,
         token_program,
     )?;
     load_token_account(
         vault_st_token_account,
+        "vault_st_token_account",
         vault.key,
         st_mint.key,
         token_program,
     )?;
     BurnVault::load(program_id, base.key, burn_vault, false)?;
-    load_system_account(burn_vault_vrt_token_account, true)?;
-    load_signer(admin, true)?;
-    load_signer(base, false)?;
-    load_system_program(system_program)?;
-    load_token_program(token_program)?;
-    load_associated_token_account_program(associated_token_program)?;
+    load_system_account(
+        burn_vault_vrt_token_account,
+        "burn_vault_vrt_token_account",
+        true,
+    )?;
 
     if initialize_token_amount == 0 {
         msg!("Initialize token amount must be greater than zero");
@@ -147,7 +159,7 @@ pub fn process_initialize_vault(
         )?;
 
         let mut vault_data = vault.try_borrow_mut_data()?;
-        vault_data[0] = Vault::DISCRIMINATOR;
+        Vault::write_header(&mut vault_data);
         let vault_account = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
         *vault_account = Vault::new(
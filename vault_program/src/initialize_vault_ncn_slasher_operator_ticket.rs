@@ -1,16 +1,17 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
-    create_account, get_epoch,
-    loader::{load_signer, load_system_account, load_system_program},
+    accounts::AccountsContext, create_account, get_epoch, loader::load_system_account,
 };
-use jito_restaking_core::{ncn::Ncn, operator::Operator};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
-    vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+};
+use jito_vault_sdk::{
+    error::VaultError, instruction::InitializeVaultNcnSlasherOperatorTicketAccounts,
 };
-use jito_vault_sdk::error::VaultError;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -21,31 +22,27 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, ncn, slasher, operator, vault_ncn_slasher_ticket, vault_ncn_slasher_operator_ticket, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeVaultNcnSlasherOperatorTicketAccounts {
+        config,
+        vault: vault_info,
+        ncn,
+        slasher,
+        operator,
+        vault_ncn_slasher_ticket: _,
+        vault_ncn_slasher_operator_ticket,
+        payer,
+        system_program,
+    } = InitializeVaultNcnSlasherOperatorTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, false)?;
     let vault_data = vault_info.data.borrow();
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
-    Ncn::load(&config.restaking_program, ncn, false)?;
-    Operator::load(&config.restaking_program, operator, false)?;
-    VaultNcnSlasherTicket::load(
-        program_id,
-        vault_ncn_slasher_ticket,
-        vault_info,
-        ncn,
-        slasher,
-        false,
+    load_system_account(
+        vault_ncn_slasher_operator_ticket,
+        "vault_ncn_slasher_operator_ticket",
+        true,
     )?;
-    load_system_account(vault_ncn_slasher_operator_ticket, true)?;
-    load_signer(payer, false)?;
-    load_system_program(system_program)?;
 
     let slot = Clock::get()?.slot;
     let ncn_epoch = get_epoch(slot, config.epoch_length())?;
@@ -94,7 +91,7 @@ pub fn process_initialize_vault_ncn_slasher_operator_ticket(
 
     let mut vault_ncn_slasher_operator_ticket_data =
         vault_ncn_slasher_operator_ticket.try_borrow_mut_data()?;
-    vault_ncn_slasher_operator_ticket_data[0] = VaultNcnSlasherOperatorTicket::DISCRIMINATOR;
+    VaultNcnSlasherOperatorTicket::write_header(&mut vault_ncn_slasher_operator_ticket_data);
     let vault_ncn_slasher_operator_ticket =
         VaultNcnSlasherOperatorTicket::try_from_slice_unchecked_mut(
             &mut vault_ncn_slasher_operator_ticket_data,
@@ -1,15 +1,14 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
-use jito_restaking_core::{ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_restaking_core::ncn_vault_slasher_ticket::NcnVaultSlasherTicket;
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::InitializeVaultNcnSlasherTicketAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -20,31 +19,23 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, ncn, slasher, ncn_slasher_ticket, vault_ncn_slasher_ticket, vault_slasher_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeVaultNcnSlasherTicketAccounts {
+        config,
+        vault: vault_info,
+        ncn,
+        slasher,
+        ncn_slasher_ticket,
+        vault_slasher_ticket: vault_ncn_slasher_ticket,
+        admin: vault_slasher_admin,
+        payer,
+        system_program,
+    } = InitializeVaultNcnSlasherTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, false)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    Ncn::load(&config.restaking_program, ncn, false)?;
-    NcnVaultSlasherTicket::load(
-        &config.restaking_program,
-        ncn_slasher_ticket,
-        ncn,
-        vault_info,
-        slasher,
-        false,
-    )?;
-    load_system_account(vault_ncn_slasher_ticket, true)?;
-    load_signer(vault_slasher_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(vault_ncn_slasher_ticket, "vault_ncn_slasher_ticket", true)?;
 
     vault.check_is_paused()?;
 
@@ -91,7 +82,7 @@ pub fn process_initialize_vault_ncn_slasher_ticket(
         NcnVaultSlasherTicket::try_from_slice_unchecked(&ncn_vault_slasher_ticket_data)?;
 
     let mut vault_ncn_slasher_ticket_data = vault_ncn_slasher_ticket.try_borrow_mut_data()?;
-    vault_ncn_slasher_ticket_data[0] = VaultNcnSlasherTicket::DISCRIMINATOR;
+    VaultNcnSlasherTicket::write_header(&mut vault_ncn_slasher_ticket_data);
     let vault_ncn_slasher_ticket =
         VaultNcnSlasherTicket::try_from_slice_unchecked_mut(&mut vault_ncn_slasher_ticket_data)?;
     *vault_ncn_slasher_ticket = VaultNcnSlasherTicket::new(
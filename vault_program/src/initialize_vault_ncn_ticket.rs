@@ -1,13 +1,13 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
+use jito_vault_core::{
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_ncn_ticket::VaultNcnTicket,
 };
-use jito_restaking_core::{ncn::Ncn, ncn_vault_ticket::NcnVaultTicket};
-use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::InitializeVaultNcnTicketAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -25,30 +25,22 @@ pub fn process_initialize_vault_ncn_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, ncn, ncn_vault_ticket, vault_ncn_ticket, vault_ncn_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeVaultNcnTicketAccounts {
+        config,
+        vault: vault_info,
+        ncn,
+        ncn_vault_ticket: _,
+        vault_ncn_ticket,
+        admin: vault_ncn_admin,
+        payer,
+        system_program,
+    } = InitializeVaultNcnTicketAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    Ncn::load(&config.restaking_program, ncn, false)?;
-    NcnVaultTicket::load(
-        &config.restaking_program,
-        ncn_vault_ticket,
-        ncn,
-        vault_info,
-        false,
-    )?;
-    load_system_account(vault_ncn_ticket, false)?;
-    load_signer(vault_ncn_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(vault_ncn_ticket, "vault_ncn_ticket", false)?;
 
     // The VaultNcnTicket shall be at the canonical PDA
     let (vault_ncn_ticket_pubkey, vault_ncn_ticket_bump, mut vault_ncn_ticket_seeds) =
@@ -82,7 +74,7 @@ pub fn process_initialize_vault_ncn_ticket(
         &vault_ncn_ticket_seeds,
     )?;
     let mut vault_ncn_ticket_data = vault_ncn_ticket.try_borrow_mut_data()?;
-    vault_ncn_ticket_data[0] = VaultNcnTicket::DISCRIMINATOR;
+    VaultNcnTicket::write_header(&mut vault_ncn_ticket_data);
     let vault_ncn_ticket =
         VaultNcnTicket::try_from_slice_unchecked_mut(&mut vault_ncn_ticket_data)?;
     *vault_ncn_ticket = VaultNcnTicket::new(
@@ -1,15 +1,13 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
-use jito_jsm_core::{
-    create_account,
-    loader::{load_signer, load_system_account, load_system_program},
-};
-use jito_restaking_core::{operator::Operator, operator_vault_ticket::OperatorVaultTicket};
+use jito_bytemuck::AccountDeserialize;
+use jito_jsm_core::{accounts::AccountsContext, create_account, loader::load_system_account};
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_operator_delegation::VaultOperatorDelegation,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_operator_delegation::VaultOperatorDelegation,
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::InitializeVaultOperatorDelegationAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -20,30 +18,22 @@ pub fn process_initialize_vault_operator_delegation(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, operator, operator_vault_ticket, vault_operator_delegation, vault_operator_admin, payer, system_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let InitializeVaultOperatorDelegationAccounts {
+        config,
+        vault: vault_info,
+        operator,
+        operator_vault_ticket: _,
+        vault_operator_delegation,
+        admin: vault_operator_admin,
+        payer,
+        system_program,
+    } = InitializeVaultOperatorDelegationAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    Operator::load(&config.restaking_program, operator, false)?;
-    OperatorVaultTicket::load(
-        &config.restaking_program,
-        operator_vault_ticket,
-        operator,
-        vault_info,
-        false,
-    )?;
-    load_system_account(vault_operator_delegation, true)?;
-    load_signer(vault_operator_admin, false)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(vault_operator_delegation, "vault_operator_delegation", true)?;
 
     // The VaultOperatorDelegation shall be at the canonical PDA
     let (
@@ -80,7 +70,7 @@ pub fn process_initialize_vault_operator_delegation(
     )?;
 
     let mut vault_operator_delegation_data = vault_operator_delegation.try_borrow_mut_data()?;
-    vault_operator_delegation_data[0] = VaultOperatorDelegation::DISCRIMINATOR;
+    VaultOperatorDelegation::write_header(&mut vault_operator_delegation_data);
     let vault_operator_delegation =
         VaultOperatorDelegation::try_from_slice_unchecked_mut(&mut vault_operator_delegation_data)?;
     *vault_operator_delegation = VaultOperatorDelegation::new(
@@ -1,14 +1,18 @@
 use std::mem::size_of;
 
-use jito_bytemuck::{AccountDeserialize, Discriminator};
+use jito_bytemuck::AccountDeserialize;
 use jito_jsm_core::{
-    create_account, get_epoch,
-    loader::{load_signer, load_system_account, load_system_program},
+    accounts::AccountsContext, create_account, get_epoch, loader::load_system_account,
 };
 use jito_vault_core::{
-    config::Config, vault::Vault, vault_update_state_tracker::VaultUpdateStateTracker,
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::{
+    error::VaultError,
+    instruction::{InitializeVaultUpdateStateTrackerAccounts, WithdrawalAllocationMethod},
 };
-use jito_vault_sdk::{error::VaultError, instruction::WithdrawalAllocationMethod};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
@@ -22,18 +26,22 @@ pub fn process_initialize_vault_update_state_tracker(
     accounts: &[AccountInfo],
     withdrawal_allocation_method: WithdrawalAllocationMethod,
 ) -> ProgramResult {
-    let [config, vault_info, vault_update_state_tracker, payer, system_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
+    let InitializeVaultUpdateStateTrackerAccounts {
+        config,
+        vault: vault_info,
+        vault_update_state_tracker,
+        payer,
+        system_program,
+    } = InitializeVaultUpdateStateTrackerAccounts::try_from_accounts(program_id, accounts)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_system_account(vault_update_state_tracker, true)?;
-    load_signer(payer, true)?;
-    load_system_program(system_program)?;
+    load_system_account(
+        vault_update_state_tracker,
+        "vault_update_state_tracker",
+        true,
+    )?;
 
     // The VaultUpdateStateTracker shall be at the canonical PDA
     let slot = Clock::get()?.slot;
@@ -114,7 +122,7 @@ pub fn process_initialize_vault_update_state_tracker(
     vault.set_last_start_state_update_slot(slot);
 
     let mut vault_update_state_tracker_data = vault_update_state_tracker.try_borrow_mut_data()?;
-    vault_update_state_tracker_data[0] = VaultUpdateStateTracker::DISCRIMINATOR;
+    VaultUpdateStateTracker::write_header(&mut vault_update_state_tracker_data);
     let vault_update_state_tracker = VaultUpdateStateTracker::try_from_slice_unchecked_mut(
         &mut vault_update_state_tracker_data,
     )?;
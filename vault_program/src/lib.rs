@@ -17,6 +17,7 @@ mod initialize_vault_ncn_ticket;
 mod initialize_vault_operator_delegation;
 mod initialize_vault_update_state_tracker;
 mod initialize_vault_with_mint;
+mod migrate_account;
 mod mint_to;
 mod revoke_delegate_token_account;
 mod set_admin;
@@ -61,7 +62,8 @@ use crate::{
     initialize_vault_ncn_ticket::process_initialize_vault_ncn_ticket,
     initialize_vault_operator_delegation::process_initialize_vault_operator_delegation,
     initialize_vault_update_state_tracker::process_initialize_vault_update_state_tracker,
-    initialize_vault_with_mint::process_initialize_vault_with_mint, mint_to::process_mint,
+    initialize_vault_with_mint::process_initialize_vault_with_mint,
+    migrate_account::process_migrate_account, mint_to::process_mint,
     revoke_delegate_token_account::process_revoke_delegate_token_account,
     set_admin::process_set_admin, set_capacity::process_set_deposit_capacity,
     set_config_admin::process_set_config_admin, set_fees::process_set_fees,
@@ -99,6 +101,20 @@ pub fn process_instruction(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    // The hot crank instructions carry no arguments, so they're dispatched on their tag without
+    // decoding the instruction
+    match instruction_data {
+        [VaultInstruction::CRANK_VAULT_UPDATE_STATE_TRACKER_TAG] => {
+            msg!("Instruction: CrankVaultUpdateStateTracker");
+            return process_crank_vault_update_state_tracker(program_id, accounts);
+        }
+        [VaultInstruction::BURN_WITHDRAWAL_TICKET_TAG] => {
+            msg!("Instruction: BurnWithdrawalTicket");
+            return process_burn_withdrawal_ticket(program_id, accounts);
+        }
+        _ => {}
+    }
+
     let instruction = VaultInstruction::try_from_slice(instruction_data)?;
 
     match instruction {
@@ -292,5 +308,9 @@ pub fn process_instruction(
             msg!("Instruction: SetConfigSecondaryAdmin");
             process_set_config_secondary_admin(program_id, accounts, role)
         }
+        VaultInstruction::MigrateAccount => {
+            msg!("Instruction: MigrateAccount");
+            process_migrate_account(program_id, accounts)
+        }
     }
 }
@@ -0,0 +1,65 @@
+use jito_bytemuck::Discriminator;
+use jito_jsm_core::{accounts::AccountsContext, migrate_account};
+use jito_vault_core::{
+    config::Config, vault::Vault, vault_ncn_slasher_operator_ticket::VaultNcnSlasherOperatorTicket,
+    vault_ncn_slasher_ticket::VaultNcnSlasherTicket, vault_ncn_ticket::VaultNcnTicket,
+    vault_operator_delegation::VaultOperatorDelegation,
+    vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    vault_update_state_tracker::VaultUpdateStateTracker,
+};
+use jito_vault_sdk::instruction::MigrateAccountAccounts;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey, rent::Rent, sysvar::Sysvar,
+};
+
+/// Processes the migrate account instruction: [`crate::VaultInstruction::MigrateAccount`]
+///
+/// Migrates any vault program account to its current layout version, dispatching on the account
+/// discriminator. Accounts whose current layout is larger are grown first, with the payer funding
+/// the additional rent.
+pub fn process_migrate_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let MigrateAccountAccounts {
+        account,
+        payer,
+        system_program: _,
+    } = MigrateAccountAccounts::try_from_accounts(program_id, accounts)?;
+
+    if account.owner.ne(program_id) {
+        msg!("Account has an invalid owner");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    if !account.is_writable {
+        msg!("Account is not writable");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let discriminator = account.try_borrow_data()?.first().copied();
+    let rent = Rent::get()?;
+    match discriminator {
+        Some(Config::DISCRIMINATOR) => migrate_account::<Config>(account, payer, &rent),
+        Some(Vault::DISCRIMINATOR) => migrate_account::<Vault>(account, payer, &rent),
+        Some(VaultNcnTicket::DISCRIMINATOR) => {
+            migrate_account::<VaultNcnTicket>(account, payer, &rent)
+        }
+        Some(VaultOperatorDelegation::DISCRIMINATOR) => {
+            migrate_account::<VaultOperatorDelegation>(account, payer, &rent)
+        }
+        Some(VaultNcnSlasherTicket::DISCRIMINATOR) => {
+            migrate_account::<VaultNcnSlasherTicket>(account, payer, &rent)
+        }
+        Some(VaultNcnSlasherOperatorTicket::DISCRIMINATOR) => {
+            migrate_account::<VaultNcnSlasherOperatorTicket>(account, payer, &rent)
+        }
+        Some(VaultStakerWithdrawalTicket::DISCRIMINATOR) => {
+            migrate_account::<VaultStakerWithdrawalTicket>(account, payer, &rent)
+        }
+        Some(VaultUpdateStateTracker::DISCRIMINATOR) => {
+            migrate_account::<VaultUpdateStateTracker>(account, payer, &rent)
+        }
+        _ => {
+            msg!("Account discriminator is invalid");
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -1,19 +1,16 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{
-    load_associated_token_account, load_signer, load_token_mint, load_token_program,
-};
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
 use jito_vault_core::{
     config::Config,
-    vault::{MintSummary, Vault},
+    vault::{MintSummary, Vault, VaultExt},
 };
-use jito_vault_sdk::error::VaultError;
+use jito_vault_sdk::{error::VaultError, instruction::MintToAccounts};
 use solana_program::{
     account_info::AccountInfo,
     clock::Clock,
     entrypoint::ProgramResult,
     msg,
     program::{invoke, invoke_signed},
-    program_error::ProgramError,
     pubkey::Pubkey,
     sysvar::Sysvar,
 };
@@ -40,36 +37,27 @@ pub fn process_mint(
     amount_in: u64,
     min_amount_out: u64,
 ) -> ProgramResult {
-    let (required_accounts, optional_accounts) = accounts.split_at(9);
-
-    let [config, vault_info, vrt_mint, depositor, depositor_token_account, vault_token_account, depositor_vrt_token_account, vault_fee_token_account, token_program] =
-        required_accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let MintToAccounts {
+        config,
+        vault: vault_info,
+        vrt_mint,
+        depositor,
+        depositor_token_account,
+        vault_token_account,
+        depositor_vrt_token_account,
+        vault_fee_token_account,
+        token_program: _,
+        mint_signer,
+    } = MintToAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault_info, true)?;
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
-    load_token_mint(vrt_mint)?;
-    load_signer(depositor, false)?;
-    load_associated_token_account(
-        depositor_token_account,
-        depositor.key,
-        &vault.supported_mint,
-    )?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
-    load_associated_token_account(depositor_vrt_token_account, depositor.key, vrt_mint.key)?;
-    load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, vrt_mint.key)?;
-
-    // Only the original spl token program is allowed
-    load_token_program(token_program)?;
+    load_token_mint(vrt_mint, "vrt_mint")?;
 
-    vault.check_mint_burn_admin(optional_accounts.first())?;
+    vault.check_mint_burn_admin(mint_signer)?;
     vault.check_vrt_mint(vrt_mint.key)?;
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_is_paused()?;
@@ -1,6 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_account, load_token_mint, load_token_program};
-use jito_vault_core::{config::Config, vault::Vault};
+use jito_jsm_core::{
+    accounts::AccountsContext,
+    loader::{load_token_account, load_token_mint},
+};
+use jito_vault_core::vault::{Vault, VaultExt};
+use jito_vault_sdk::instruction::RevokeDelegateTokenAccountAccounts;
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
     program_error::ProgramError, pubkey::Pubkey,
@@ -20,24 +24,23 @@ pub fn process_revoke_delegate_token_account(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, delegate_asset_admin, token_mint, token_account, token_program_info] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let RevokeDelegateTokenAccountAccounts {
+        config: _,
+        vault: vault_info,
+        delegate_asset_admin,
+        token_mint,
+        token_account,
+        token_program: token_program_info,
+    } = RevokeDelegateTokenAccountAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault_info, false)?;
-    load_signer(delegate_asset_admin, false)?;
-    load_token_mint(token_mint)?;
+    load_token_mint(token_mint, "token_mint")?;
     load_token_account(
         token_account,
+        "token_account",
         vault_info.key,
         token_mint.key,
         token_program_info,
     )?;
-    // Only the original spl token program is allowed
-    load_token_program(token_program_info)?;
 
     // The owner of token mint and token account must match
     if token_mint.owner.ne(token_account.owner) {
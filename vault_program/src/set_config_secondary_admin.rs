@@ -1,11 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_vault_core::config::Config;
-use jito_vault_sdk::instruction::ConfigAdminRole;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::config::{Config, ConfigExt};
+use jito_vault_sdk::instruction::{ConfigAdminRole, SetConfigSecondaryAdminAccounts};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 /// Sets a secondary admin for a specific role in the configuration.
 ///
@@ -18,14 +15,14 @@ pub fn process_set_config_secondary_admin(
     accounts: &[AccountInfo],
     role: ConfigAdminRole,
 ) -> ProgramResult {
-    let [config, admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let SetConfigSecondaryAdminAccounts {
+        config,
+        admin,
+        new_admin,
+    } = SetConfigSecondaryAdminAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, true)?;
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
-    load_signer(admin, false)?;
 
     config.check_admin(admin.key)?;
 
@@ -1,6 +1,10 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_vault_core::{config::Config, vault::Vault};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::{
+    config::Config,
+    vault::{Vault, VaultExt},
+};
+use jito_vault_sdk::instruction::SetFeesAccounts;
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
     program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
@@ -22,16 +26,15 @@ pub fn process_set_fees(
     withdrawal_fee_bps: Option<u16>,
     reward_fee_bps: Option<u16>,
 ) -> ProgramResult {
-    let [config, vault, vault_fee_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
+    let SetFeesAccounts {
+        config,
+        vault,
+        admin: vault_fee_admin,
+    } = SetFeesAccounts::try_from_accounts(program_id, accounts)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Vault::load(program_id, vault, true)?;
     let mut vault_data = vault.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_signer(vault_fee_admin, false)?;
 
     vault.check_fee_admin(vault_fee_admin.key)?;
     vault.check_can_modify_fees(Clock::get()?.slot, config.epoch_length())?;
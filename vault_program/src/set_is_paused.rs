@@ -1,10 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_vault_core::{config::Config, vault::Vault};
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::vault::{Vault, VaultExt};
+use jito_vault_sdk::instruction::SetIsPausedAccounts;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 
 /// Processes the set `is_paused` instruction: [`crate::VaultInstruction::SetIsPaused`]
 pub fn process_set_is_paused(
@@ -12,14 +10,13 @@ pub fn process_set_is_paused(
     accounts: &[AccountInfo],
     is_paused: bool,
 ) -> ProgramResult {
-    let [config, vault, admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault, true)?;
+    let SetIsPausedAccounts {
+        config: _,
+        vault,
+        admin,
+    } = SetIsPausedAccounts::try_from_accounts(program_id, accounts)?;
     let mut vault_data = vault.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_signer(admin, false)?;
 
     vault.check_admin(admin.key)?;
 
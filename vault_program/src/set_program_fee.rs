@@ -1,11 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_vault_core::config::Config;
-use jito_vault_sdk::error::VaultError;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::config::{Config, ConfigExt};
+use jito_vault_sdk::{error::VaultError, instruction::SetProgramFeeAccounts};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 /// Sets the program fee for the vault program.
 ///
@@ -18,13 +15,12 @@ pub fn process_set_program_fee(
     accounts: &[AccountInfo],
     new_fee_bps: u16,
 ) -> ProgramResult {
-    let [config, config_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, true)?;
+    let SetProgramFeeAccounts {
+        config,
+        admin: config_admin,
+    } = SetProgramFeeAccounts::try_from_accounts(program_id, accounts)?;
     let mut config_data = config.data.borrow_mut();
     let config = Config::try_from_slice_unchecked_mut(&mut config_data)?;
-    load_signer(config_admin, false)?;
 
     if config_admin.key != &config.admin {
         msg!("Config admin does not match");
@@ -1,11 +1,8 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_vault_core::{config::Config, vault::Vault};
-use jito_vault_sdk::instruction::VaultAdminRole;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-    pubkey::Pubkey,
-};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::vault::{Vault, VaultExt};
+use jito_vault_sdk::instruction::{SetSecondaryAdminAccounts, VaultAdminRole};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
 /// Processes the set delegation admin instruction: [`crate::VaultInstruction::SetSecondaryAdmin`]
 pub fn process_set_secondary_admin(
@@ -13,15 +10,15 @@ pub fn process_set_secondary_admin(
     accounts: &[AccountInfo],
     role: VaultAdminRole,
 ) -> ProgramResult {
-    let [config, vault, admin, new_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let SetSecondaryAdminAccounts {
+        config: _,
+        vault,
+        admin,
+        new_admin,
+    } = SetSecondaryAdminAccounts::try_from_accounts(program_id, accounts)?;
 
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault, true)?;
     let mut vault_data = vault.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
-    load_signer(admin, false)?;
 
     vault.check_admin(admin.key)?;
 
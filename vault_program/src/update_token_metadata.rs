@@ -1,15 +1,15 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_signer, load_token_mint};
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
 use jito_vault_core::{
     loader::{load_mpl_metadata, load_mpl_metadata_program},
-    vault::Vault,
+    vault::{Vault, VaultExt},
 };
-use jito_vault_sdk::inline_mpl_token_metadata::{
-    instruction::update_metadata_accounts_v2, state::DataV2,
+use jito_vault_sdk::{
+    inline_mpl_token_metadata::{instruction::update_metadata_accounts_v2, state::DataV2},
+    instruction::UpdateTokenMetadataAccounts,
 };
 use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed,
-    program_error::ProgramError, pubkey::Pubkey,
+    account_info::AccountInfo, entrypoint::ProgramResult, program::invoke_signed, pubkey::Pubkey,
 };
 
 pub fn process_update_token_metadata(
@@ -19,15 +19,17 @@ pub fn process_update_token_metadata(
     symbol: String,
     uri: String,
 ) -> ProgramResult {
-    let [vault_info, admin, vrt_mint, metadata, mpl_token_metadata_program] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
+    let UpdateTokenMetadataAccounts {
+        vault: vault_info,
+        admin,
+        vrt_mint,
+        metadata,
+        mpl_token_metadata_program,
+    } = UpdateTokenMetadataAccounts::try_from_accounts(program_id, accounts)?;
 
-    Vault::load(program_id, vault_info, false)?;
     let vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked(&vault_data)?;
-    load_signer(admin, false)?;
-    load_token_mint(vrt_mint)?;
+    load_token_mint(vrt_mint, "vrt_mint")?;
     load_mpl_metadata(metadata, vrt_mint.key)?;
     load_mpl_metadata_program(mpl_token_metadata_program)?;
 
@@ -1,11 +1,13 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::{load_associated_token_account, load_token_mint, load_token_program};
-use jito_vault_core::{config::Config, vault::Vault};
-use jito_vault_sdk::error::VaultError;
+use jito_jsm_core::{accounts::AccountsContext, loader::load_token_mint};
+use jito_vault_core::{
+    config::Config,
+    vault::{Vault, VaultExt},
+};
+use jito_vault_sdk::{error::VaultError, instruction::UpdateVaultBalanceAccounts};
 use solana_program::{
     account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program::invoke_signed, program_error::ProgramError, program_pack::Pack, pubkey::Pubkey,
-    sysvar::Sysvar,
+    program::invoke_signed, program_pack::Pack, pubkey::Pubkey, sysvar::Sysvar,
 };
 use spl_token::{instruction::mint_to, state::Account};
 
@@ -13,14 +15,14 @@ pub fn process_update_vault_balance(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault_info, vault_token_account, vrt_mint, vault_fee_token_account, token_program] =
-        accounts
-    else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault_info, true)?;
+    let UpdateVaultBalanceAccounts {
+        config,
+        vault: vault_info,
+        vault_token_account,
+        vrt_mint,
+        vault_fee_token_account,
+        token_program: _,
+    } = UpdateVaultBalanceAccounts::try_from_accounts(program_id, accounts)?;
 
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
@@ -28,10 +30,7 @@ pub fn process_update_vault_balance(
     let mut vault_data = vault_info.data.borrow_mut();
     let vault = Vault::try_from_slice_unchecked_mut(&mut vault_data)?;
 
-    load_token_mint(vrt_mint)?;
-    load_associated_token_account(vault_fee_token_account, &vault.fee_wallet, vrt_mint.key)?;
-    load_associated_token_account(vault_token_account, vault_info.key, &vault.supported_mint)?;
-    load_token_program(token_program)?;
+    load_token_mint(vrt_mint, "vrt_mint")?;
 
     vault.check_update_state_ok(Clock::get()?.slot, config.epoch_length())?;
     vault.check_vrt_mint(vrt_mint.key)?;
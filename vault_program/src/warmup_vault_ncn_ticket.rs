@@ -1,11 +1,14 @@
 use jito_bytemuck::AccountDeserialize;
-use jito_jsm_core::loader::load_signer;
-use jito_restaking_core::ncn::Ncn;
-use jito_vault_core::{config::Config, vault::Vault, vault_ncn_ticket::VaultNcnTicket};
-use jito_vault_sdk::error::VaultError;
+use jito_jsm_core::accounts::AccountsContext;
+use jito_vault_core::{
+    config::Config,
+    vault::{Vault, VaultExt},
+    vault_ncn_ticket::VaultNcnTicket,
+};
+use jito_vault_sdk::{error::VaultError, instruction::WarmupVaultNcnTicketAccounts};
 use solana_program::{
-    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg,
-    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, msg, pubkey::Pubkey,
+    sysvar::Sysvar,
 };
 
 /// Instruction: [`crate::VaultInstruction::WarmupVaultNcnTicket`]
@@ -13,16 +16,15 @@ pub fn process_warmup_vault_ncn_ticket(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
-    let [config, vault, ncn, vault_ncn_ticket, vault_ncn_admin] = accounts else {
-        return Err(ProgramError::NotEnoughAccountKeys);
-    };
-    Config::load(program_id, config, false)?;
-    Vault::load(program_id, vault, false)?;
+    let WarmupVaultNcnTicketAccounts {
+        config,
+        vault,
+        ncn: _,
+        vault_ncn_ticket,
+        admin: vault_ncn_admin,
+    } = WarmupVaultNcnTicketAccounts::try_from_accounts(program_id, accounts)?;
     let config_data = config.data.borrow();
     let config = Config::try_from_slice_unchecked(&config_data)?;
-    Ncn::load(&config.restaking_program, ncn, false)?;
-    VaultNcnTicket::load(program_id, vault_ncn_ticket, vault, ncn, true)?;
-    load_signer(vault_ncn_admin, false)?;
 
     // The Vault NCN admin shall be the signer of the transaction
     let vault_data = vault.data.borrow();
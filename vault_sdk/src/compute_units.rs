@@ -0,0 +1,15 @@
+//! Compute unit budgets for the instructions that are cranked for every vault, every epoch.
+//!
+//! Each budget is what the SBF build of the vault program consumes on the most expensive path of
+//! the instruction, plus a 20% margin, and is enforced by the integration tests when they run
+//! against the SBF build (`SBF_OUT_DIR` is set). The failing assertion reports the measured
+//! units. The current values are estimates that have not been measured against the SBF build yet.
+//! Crankers can use them to size their compute unit limits.
+
+/// The compute units [`crate::instruction::VaultInstruction::CrankVaultUpdateStateTracker`]
+/// may consume
+pub const CRANK_VAULT_UPDATE_STATE_TRACKER_COMPUTE_UNITS: u32 = 30_000;
+
+/// The compute units [`crate::instruction::VaultInstruction::BurnWithdrawalTicket`] may consume,
+/// including its SPL token CPIs
+pub const BURN_WITHDRAWAL_TICKET_COMPUTE_UNITS: u32 = 80_000;
@@ -1,139 +1,150 @@
-use solana_program::{decode_error::DecodeError, program_error::ProgramError};
+use std::ops::Range;
+
+pub use jito_errors::DecodedError;
+use jito_errors::ErrorCode;
+use jito_layouts::error::{DelegationStateError, MathError};
+use num_derive::FromPrimitive;
+use solana_program::{decode_error::DecodeError, program_error::ProgramError, pubkey::Pubkey};
 use thiserror::Error;
 
-#[derive(Debug, Error, PartialEq, Eq)]
+#[derive(Debug, Error, PartialEq, Eq, FromPrimitive)]
 pub enum VaultError {
-    #[error("VaultSlashUnderflow")]
+    #[error("Slash amount exceeds the security delegated to the operator")]
     VaultSlashUnderflow = 1000,
-    #[error("VaultInitialAmountFailed")]
+    #[error("Initial deposit must be non-zero and mint VRT one to one")]
     VaultInitialAmountFailed,
-    #[error("VaultInsufficientFunds")]
+    #[error("Vault does not hold enough assets for this amount")]
     VaultInsufficientFunds,
-    #[error("VaultOverflow")]
+    #[error("Vault calculation overflowed")]
     VaultOverflow,
-    #[error("VaultOperatorAdminInvalid")]
+    #[error("Signer is not the vault's operator admin")]
     VaultOperatorAdminInvalid,
-    #[error("VaultAdminInvalid")]
+    #[error("Signer is not the vault admin")]
     VaultAdminInvalid,
-    #[error("VaultCapacityAdminInvalid")]
+    #[error("Signer is not the vault's capacity admin")]
     VaultCapacityAdminInvalid,
-    #[error("VaultMintBurnAdminInvalid")]
+    #[error("Signer is not the vault's mint burn admin")]
     VaultMintBurnAdminInvalid,
-    #[error("VaultDelegationAdminInvalid")]
+    #[error("Signer is not the vault's delegation admin")]
     VaultDelegationAdminInvalid,
-    #[error("VaultDelegateAssetAdminInvalid")]
+    #[error("Signer is not the vault's delegate asset admin")]
     VaultDelegateAssetAdminInvalid,
-    #[error("VaultCapacityExceeded")]
+    #[error("Deposit would exceed the vault's deposit capacity")]
     VaultCapacityExceeded,
-    #[error("VaultSlasherAdminInvalid")]
+    #[error("Signer is not the vault's slasher admin")]
     VaultSlasherAdminInvalid,
-    #[error("VaultNcnAdminInvalid")]
+    #[error("Signer is not the vault's NCN admin")]
     VaultNcnAdminInvalid,
-    #[error("VaultFeeAdminInvalid")]
+    #[error("Signer is not the vault's fee admin")]
     VaultFeeAdminInvalid,
-    #[error("ConfigAdminInvalid")]
+    #[error("Signer is not the config admin")]
     ConfigAdminInvalid,
-    #[error("ConfigFeeAdminInvalid")]
+    #[error("Signer is not the config's fee admin")]
     ConfigFeeAdminInvalid,
-    #[error("VaultFeeCapExceeded")]
+    #[error("Fee exceeds the maximum allowed fee")]
     VaultFeeCapExceeded,
-    #[error("VaultFeeChangeTooSoon")]
+    #[error("Fees can only be changed once per epoch")]
     VaultFeeChangeTooSoon,
-    #[error("VaultFeeBumpTooLarge")]
+    #[error("Fee increase exceeds the maximum allowed per change")]
     VaultFeeBumpTooLarge,
-    #[error("VaultUnderflow")]
+    #[error("Vault calculation underflowed")]
     VaultUnderflow,
-    #[error("VaultUpdateNeeded")]
+    #[error("Vault must be updated for the current epoch first")]
     VaultUpdateNeeded,
-    #[error("VaultIsUpdated")]
+    #[error("Vault is already updated for the current epoch")]
     VaultIsUpdated,
-    #[error("VaultOperatorDelegationUpdateNeeded")]
+    #[error("Vault operator delegation must be updated for the current epoch first")]
     VaultOperatorDelegationUpdateNeeded,
-    #[error("VaultOperatorDelegationIsUpdated")]
+    #[error("Vault operator delegation is already updated for the current epoch")]
     VaultOperatorDelegationIsUpdated,
-    #[error("VaultUpdateIncorrectIndex")]
+    #[error("Operators must be updated in index order")]
     VaultUpdateIncorrectIndex,
-    #[error("VaultUpdateStateNotFinishedUpdating")]
+    #[error("Not all operators have been updated yet")]
     VaultUpdateStateNotFinishedUpdating,
-    #[error("VaultSecurityOverflow")]
+    #[error("Delegated security overflowed")]
     VaultSecurityOverflow,
-    #[error("VaultSlashIncomplete")]
+    #[error("Slash could not be applied in full")]
     VaultSlashIncomplete,
-    #[error("VaultSecurityUnderflow")]
+    #[error("Delegated security underflowed")]
     VaultSecurityUnderflow,
-    #[error("SlippageError")]
+    #[error("Amount out is less than the minimum amount out")]
     SlippageError,
-    #[error("VaultStakerWithdrawalTicketNotWithdrawable")]
+    #[error("Withdrawal ticket is still cooling down")]
     VaultStakerWithdrawalTicketNotWithdrawable,
-    #[error("VaultNcnSlasherTicketFailedCooldown")]
+    #[error("Vault NCN slasher ticket cannot be cooled down in its current state")]
     VaultNcnSlasherTicketFailedCooldown,
-    #[error("VaultNcnSlasherTicketFailedWarmup")]
+    #[error("Vault NCN slasher ticket cannot be warmed up in its current state")]
     VaultNcnSlasherTicketFailedWarmup,
-    #[error("VaultNcnTicketFailedCooldown")]
+    #[error("Vault NCN ticket cannot be cooled down in its current state")]
     VaultNcnTicketFailedCooldown,
-    #[error("VaultNcnTicketFailedWarmup")]
+    #[error("Vault NCN ticket cannot be warmed up in its current state")]
     VaultNcnTicketFailedWarmup,
-    #[error("VaultNcnTicketUnslashable")]
+    #[error("Vault NCN ticket is not active, so the NCN cannot slash")]
     VaultNcnTicketUnslashable,
-    #[error("OperatorVaultTicketUnslashable")]
+    #[error("Operator vault ticket is not active, so the operator cannot be slashed")]
     OperatorVaultTicketUnslashable,
-    #[error("NcnOperatorStateUnslashable")]
+    #[error("NCN operator state is not active, so the operator cannot be slashed")]
     NcnOperatorStateUnslashable,
-    #[error("VaultNcnSlasherTicketUnslashable")]
+    #[error("Vault NCN slasher ticket is not active, so the slasher cannot slash")]
     VaultNcnSlasherTicketUnslashable,
-    #[error("NcnVaultTicketUnslashable")]
+    #[error("NCN vault ticket is not active, so the NCN cannot slash")]
     NcnVaultTicketUnslashable,
-    #[error("NcnVaultSlasherTicketUnslashable")]
+    #[error("NCN vault slasher ticket is not active, so the slasher cannot slash")]
     NcnVaultSlasherTicketUnslashable,
-    #[error("VaultMaxSlashedPerOperatorExceeded")]
+    #[error("Slash exceeds the maximum slashable per operator this epoch")]
     VaultMaxSlashedPerOperatorExceeded,
-    #[error("VaultStakerWithdrawalTicketInvalidStaker")]
+    #[error("Signer is not the staker of the withdrawal ticket")]
     VaultStakerWithdrawalTicketInvalidStaker,
-    #[error("SlasherOverflow")]
+    #[error("Vault slasher count overflowed")]
     SlasherOverflow,
-    #[error("NcnOverflow")]
+    #[error("Vault NCN count overflowed")]
     NcnOverflow,
-    #[error("OperatorOverflow")]
+    #[error("Vault operator count overflowed")]
     OperatorOverflow,
-    #[error("VaultDelegationZero")]
+    #[error("Delegation amount must be non-zero")]
     VaultDelegationZero,
-    #[error("VaultCooldownZero")]
+    #[error("Cooldown amount must be non-zero")]
     VaultCooldownZero,
-    #[error("VaultBurnZero")]
+    #[error("Burn amount must be non-zero")]
     VaultBurnZero,
-    #[error("VaultEnqueueWithdrawalAmountZero")]
+    #[error("Withdrawal amount must be non-zero")]
     VaultEnqueueWithdrawalAmountZero,
-    #[error("VaultMintZero")]
+    #[error("Mint amount must be non-zero")]
     VaultMintZero,
-    #[error("VaultIsPaused")]
+    #[error("Vault is paused")]
     VaultIsPaused,
-    #[error("InvalidDepositor")]
+    #[error("Depositor cannot be the vault")]
     InvalidDepositor,
-    #[error("InvalidDepositTokenAccount")]
+    #[error("Depositor token account cannot be the vault token account")]
     InvalidDepositTokenAccount,
-    #[error("NoSupportedMintBalanceChange")]
+    #[error("Supported mint balance did not change")]
     NoSupportedMintBalanceChange,
-    #[error("InvalidEpochLength")]
+    #[error("Epoch length is invalid")]
     InvalidEpochLength,
-    #[error("VaultRewardFeeDeltaTooLarge")]
+    #[error("Reward fee taken differs too much from the configured reward fee")]
     VaultRewardFeeDeltaTooLarge,
-    #[error("VaultRewardFeeIsZero")]
+    #[error("Reward fee rounded down to zero VRT")]
     VaultRewardFeeIsZero,
-    #[error("VrtOutCannotBeZero")]
+    #[error("Deposit is too small to mint any VRT")]
     VrtOutCannotBeZero,
-    #[error("NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate")]
+    #[error("Not enough assets were cooled down to cover pending withdrawals")]
     NonZeroAdditionalAssetsNeededForWithdrawalAtEndOfUpdate,
-    #[error("ArithmeticOverflow")]
+    #[error("Arithmetic overflow")]
     ArithmeticOverflow = 3000,
-    #[error("ArithmeticUnderflow")]
+    #[error("Arithmetic underflow")]
     ArithmeticUnderflow,
-    #[error("DivisionByZero")]
+    #[error("Division by zero")]
     DivisionByZero,
 }
 
+impl ErrorCode for VaultError {
+    const SOURCE: &'static str = "jito::vault";
+    const CODES: Range<u32> = jito_errors::VAULT_ERROR_CODES;
+}
+
 impl<T> DecodeError<T> for VaultError {
     fn type_of() -> &'static str {
-        "jito::vault"
+        Self::SOURCE
     }
 }
 
@@ -143,6 +154,28 @@ impl From<VaultError> for ProgramError {
     }
 }
 
+impl From<DelegationStateError> for VaultError {
+    fn from(e: DelegationStateError) -> Self {
+        match e {
+            DelegationStateError::SecurityOverflow => Self::VaultSecurityOverflow,
+            DelegationStateError::SecurityUnderflow => Self::VaultSecurityUnderflow,
+            DelegationStateError::SlashUnderflow => Self::VaultSlashUnderflow,
+            DelegationStateError::SlashIncomplete => Self::VaultSlashIncomplete,
+            DelegationStateError::CooldownZero => Self::VaultCooldownZero,
+            DelegationStateError::DelegationZero => Self::VaultDelegationZero,
+        }
+    }
+}
+
+impl From<MathError> for VaultError {
+    fn from(e: MathError) -> Self {
+        match e {
+            MathError::Overflow => Self::VaultOverflow,
+            MathError::Underflow => Self::VaultUnderflow,
+        }
+    }
+}
+
 impl From<VaultError> for u64 {
     fn from(e: VaultError) -> Self {
         e as Self
@@ -154,3 +187,46 @@ impl From<VaultError> for u32 {
         e as Self
     }
 }
+
+/// Decodes a custom error code returned by the vault program at `program_id` into its name and
+/// description. Returns `None` for codes the vault program doesn't define.
+pub fn decode_program_error(program_id: &Pubkey, code: u32) -> Option<DecodedError> {
+    jito_errors::decode_program_error::<VaultError>(program_id, code)
+}
+
+#[cfg(test)]
+mod tests {
+    use jito_errors::{CoreError, CORE_ERROR_CODES, VAULT_ERROR_CODES};
+
+    use super::*;
+
+    #[test]
+    fn test_decoded_codes_are_registered() {
+        let program_id = Pubkey::new_unique();
+        for code in 0..u16::MAX.into() {
+            let Some(decoded) = decode_program_error(&program_id, code) else {
+                continue;
+            };
+            match decoded.source {
+                "jito::vault" => assert!(VAULT_ERROR_CODES.contains(&code)),
+                "jito::core" | "jito::loader" => assert!(CORE_ERROR_CODES.contains(&code)),
+                source => panic!("unexpected source {source}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_decode_program_error() {
+        let program_id = Pubkey::new_unique();
+        let error = VaultError::ArithmeticOverflow;
+        let decoded = decode_program_error(&program_id, error as u32).unwrap();
+        assert_eq!(decoded.program_id, program_id);
+        assert_eq!(decoded.name, "ArithmeticOverflow");
+        assert_eq!(decoded.description, "Arithmetic overflow");
+
+        let decoded = decode_program_error(&program_id, CoreError::BadEpochLength as u32).unwrap();
+        assert_eq!(decoded.name, "BadEpochLength");
+
+        assert_eq!(decode_program_error(&program_id, 0), None);
+    }
+}
@@ -1,9 +1,22 @@
 use borsh::{BorshDeserialize, BorshSerialize};
+use jito_jsm_core::accounts::AccountsContext;
+use jito_layouts::{
+    restaking::{
+        ncn::Ncn, ncn_vault_slasher_ticket::NcnVaultSlasherTicket,
+        ncn_vault_ticket::NcnVaultTicket, operator::Operator,
+        operator_vault_ticket::OperatorVaultTicket,
+    },
+    vault::{
+        config::Config, vault::Vault, vault_ncn_slasher_ticket::VaultNcnSlasherTicket,
+        vault_ncn_ticket::VaultNcnTicket, vault_operator_delegation::VaultOperatorDelegation,
+        vault_staker_withdrawal_ticket::VaultStakerWithdrawalTicket,
+    },
+};
 use shank::ShankInstruction;
 use solana_program::program_error::ProgramError;
 
 #[rustfmt::skip]
-#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction)]
+#[derive(Debug, BorshSerialize, BorshDeserialize, ShankInstruction, AccountsContext)]
 pub enum VaultInstruction {
     /// Initializes global configuration
     #[account(0, writable, name = "config")]
@@ -29,6 +42,7 @@ pub enum VaultInstruction {
     #[account(10, name = "system_program")]
     #[account(11, name = "token_program")]
     #[account(12, name = "associated_token_program")]
+    #[accounts(pda(config = Config))]
     InitializeVault {
         deposit_fee_bps: u16,
         withdrawal_fee_bps: u16,
@@ -49,6 +63,12 @@ pub enum VaultInstruction {
     #[account(5, signer, name = "admin")]
     #[account(6, writable, signer, name = "payer")]
     #[account(7, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(operator = Operator, program = config.restaking_program),
+        pda(operator_vault_ticket = OperatorVaultTicket(operator, vault), program = config.restaking_program),
+    )]
     InitializeVaultOperatorDelegation,
 
     /// Vault adds support for the NCN
@@ -60,6 +80,12 @@ pub enum VaultInstruction {
     #[account(5, signer, name = "admin")]
     #[account(6, writable, signer, name = "payer")]
     #[account(7, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(ncn_vault_ticket = NcnVaultTicket(ncn, vault), program = config.restaking_program),
+    )]
     InitializeVaultNcnTicket,
 
     /// Initializes the account which keeps track of how much an operator has been slashed
@@ -73,6 +99,13 @@ pub enum VaultInstruction {
     #[account(6, writable, name = "vault_ncn_slasher_operator_ticket")]
     #[account(7, writable, signer, name = "payer")]
     #[account(8, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(operator = Operator, program = config.restaking_program),
+        pda(vault_ncn_slasher_ticket = VaultNcnSlasherTicket(vault, ncn, slasher)),
+    )]
     InitializeVaultNcnSlasherOperatorTicket,
 
     /// Registers a slasher with the vault
@@ -85,6 +118,12 @@ pub enum VaultInstruction {
     #[account(6, signer, name = "admin")]
     #[account(7, signer, writable, name = "payer")]
     #[account(8, name = "system_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(ncn_slasher_ticket = NcnVaultSlasherTicket(ncn, vault, slasher), program = config.restaking_program),
+    )]
     InitializeVaultNcnSlasherTicket,
 
     #[account(0, name = "config")]
@@ -92,6 +131,12 @@ pub enum VaultInstruction {
     #[account(2, name = "ncn")]
     #[account(3, writable, name = "vault_ncn_ticket")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(vault_ncn_ticket = VaultNcnTicket(vault, ncn)),
+    )]
     WarmupVaultNcnTicket,
 
     /// Vault removes support for an NCN
@@ -100,6 +145,12 @@ pub enum VaultInstruction {
     #[account(2, name = "ncn")]
     #[account(3, writable, name = "vault_ncn_ticket")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(vault_ncn_ticket = VaultNcnTicket(vault, ncn)),
+    )]
     CooldownVaultNcnTicket,
 
     #[account(0, name = "config")]
@@ -108,6 +159,12 @@ pub enum VaultInstruction {
     #[account(3, name = "slasher")]
     #[account(4, writable, name = "vault_slasher_ticket")]
     #[account(5, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(vault_slasher_ticket = VaultNcnSlasherTicket(vault, ncn, slasher)),
+    )]
     WarmupVaultNcnSlasherTicket,
 
     #[account(0, name = "config")]
@@ -116,6 +173,12 @@ pub enum VaultInstruction {
     #[account(3, name = "slasher")]
     #[account(4, writable, name = "vault_ncn_slasher_ticket")]
     #[account(5, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(ncn = Ncn, program = config.restaking_program),
+        pda(vault_ncn_slasher_ticket = VaultNcnSlasherTicket(vault, ncn, slasher)),
+    )]
     CooldownVaultNcnSlasherTicket,
 
     /// Mints VRT by depositing tokens into the vault
@@ -129,6 +192,14 @@ pub enum VaultInstruction {
     #[account(7, writable, name = "vault_fee_token_account")]
     #[account(8, name = "token_program")]
     #[account(9, signer, optional, name = "mint_signer", description = "Signer for minting")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        associated_token_account(depositor_vrt_token_account, owner = depositor, mint = vrt_mint),
+        associated_token_account(depositor_token_account, owner = depositor, mint = vault.supported_mint),
+        associated_token_account(vault_token_account, owner = vault, mint = vault.supported_mint),
+        associated_token_account(vault_fee_token_account, owner = vault.fee_wallet, mint = vrt_mint),
+    )]
     MintTo {
         amount_in: u64,
         min_amount_out: u64,
@@ -146,6 +217,12 @@ pub enum VaultInstruction {
     #[account(7, name = "token_program")]
     #[account(8, name = "system_program")]
     #[account(9, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        associated_token_account(vault_staker_withdrawal_ticket_token_account, owner = vault_staker_withdrawal_ticket, mint = vault.vrt_mint),
+        associated_token_account(staker_vrt_token_account, owner = staker, mint = vault.vrt_mint),
+    )]
     EnqueueWithdrawal {
         amount: u64,
     },
@@ -155,6 +232,11 @@ pub enum VaultInstruction {
     #[account(2, writable, name = "vault_staker_withdrawal_ticket")]
     #[account(3, signer, name = "old_owner")]
     #[account(4, name = "new_owner")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket(vault)),
+    )]
     ChangeWithdrawalTicketOwner,
 
     /// Burns the withdrawal ticket, returning funds to the staker. Withdraw tickets can be burned
@@ -172,12 +254,23 @@ pub enum VaultInstruction {
     #[account(10, name = "token_program")]
     #[account(11, name = "system_program")]
     #[account(12, signer, optional, name = "burn_signer", description = "Signer for burning")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(vault_staker_withdrawal_ticket = VaultStakerWithdrawalTicket(vault)),
+        associated_token_account(vault_token_account, owner = vault, mint = vault.supported_mint),
+        associated_token_account(staker_token_account, owner = staker, mint = vault.supported_mint),
+        associated_token_account(vault_staker_withdrawal_ticket_token_account, owner = vault_staker_withdrawal_ticket, mint = vault.vrt_mint),
+        associated_token_account(vault_fee_token_account, owner = vault.fee_wallet, mint = vault.vrt_mint),
+        associated_token_account(program_fee_token_account, owner = config.program_fee_wallet, mint = vault.vrt_mint),
+    )]
     BurnWithdrawalTicket,
 
     /// Sets the max tokens that can be deposited into the VRT
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, signer, name = "admin")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     SetDepositCapacity {
         amount: u64
     },
@@ -186,6 +279,7 @@ pub enum VaultInstruction {
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, signer, name = "admin")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     SetFees {
         deposit_fee_bps: Option<u16>,
         withdrawal_fee_bps: Option<u16>,
@@ -195,6 +289,7 @@ pub enum VaultInstruction {
     /// Sets the program fee for the vault program
     #[account(0, writable, name = "config")]
     #[account(1, signer, name = "admin")]
+    #[accounts(pda(config = Config))]
     SetProgramFee {
         new_fee_bps: u16
     },
@@ -203,12 +298,14 @@ pub enum VaultInstruction {
     #[account(0, writable, name = "config")]
     #[account(1, signer, name = "program_fee_admin")]
     #[account(2, name = "new_fee_wallet")]
+    #[accounts(pda(config = Config))]
     SetProgramFeeWallet,
 
     /// Sets `is_paused`
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, signer, name = "admin")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     SetIsPaused {
         is_paused: bool,
     },
@@ -221,6 +318,7 @@ pub enum VaultInstruction {
     #[account(4, writable, name = "token_account")]
     #[account(5, name = "delegate")]
     #[account(6, name = "token_program")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     DelegateTokenAccount,
 
     /// Revoke Delegate of the token account
@@ -230,6 +328,7 @@ pub enum VaultInstruction {
     #[account(3, name = "token_mint")]
     #[account(4, writable, name = "token_account")]
     #[account(5, name = "token_program")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     RevokeDelegateTokenAccount,
 
     /// Changes the signer for vault admin
@@ -237,6 +336,7 @@ pub enum VaultInstruction {
     #[account(1, writable, name = "vault")]
     #[account(2, signer, name = "old_admin")]
     #[account(3, signer, name = "new_admin")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     SetAdmin,
 
     /// Changes the signer for vault delegation
@@ -244,6 +344,7 @@ pub enum VaultInstruction {
     #[account(1, writable, name = "vault")]
     #[account(2, signer, name = "admin")]
     #[account(3, name = "new_admin")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     SetSecondaryAdmin(VaultAdminRole),
 
     /// Delegates a token amount to a specific node operator
@@ -252,6 +353,12 @@ pub enum VaultInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "vault_operator_delegation")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(operator = Operator, program = config.restaking_program),
+        pda(vault_operator_delegation = VaultOperatorDelegation(vault, operator)),
+    )]
     AddDelegation {
         amount: u64,
     },
@@ -261,6 +368,12 @@ pub enum VaultInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "vault_operator_delegation")]
     #[account(4, signer, name = "admin")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(operator = Operator, program = config.restaking_program),
+        pda(vault_operator_delegation = VaultOperatorDelegation(vault, operator)),
+    )]
     CooldownDelegation {
         amount: u64,
     },
@@ -271,14 +384,21 @@ pub enum VaultInstruction {
     #[account(3, writable, name = "vrt_mint")]
     #[account(4, writable, name = "vault_fee_token_account")]
     #[account(5, name = "token_program")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        associated_token_account(vault_fee_token_account, owner = vault.fee_wallet, mint = vrt_mint),
+        associated_token_account(vault_token_account, owner = vault, mint = vault.supported_mint),
+    )]
     UpdateVaultBalance,
 
     /// Starts updating the vault
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_update_state_tracker")]
-    #[account(3, writable, name = "payer")]
+    #[account(3, writable, signer, name = "payer")]
     #[account(4, name = "system_program")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     InitializeVaultUpdateStateTracker { withdrawal_allocation_method: WithdrawalAllocationMethod },
 
     /// Shall be called on every vault_operator_delegation
@@ -287,12 +407,19 @@ pub enum VaultInstruction {
     #[account(2, name = "operator")]
     #[account(3, writable, name = "vault_operator_delegation")]
     #[account(4, writable, name = "vault_update_state_tracker")]
+    #[accounts(
+        pda(config = Config),
+        pda(vault = Vault),
+        pda(operator = Operator, program = config.restaking_program),
+        pda(vault_operator_delegation = VaultOperatorDelegation(vault, operator)),
+    )]
     CrankVaultUpdateStateTracker,
 
     #[account(0, name = "config")]
     #[account(1, writable, name = "vault")]
     #[account(2, writable, name = "vault_update_state_tracker")]
     #[account(3, writable, signer, name = "payer")]
+    #[accounts(pda(config = Config), pda(vault = Vault))]
     CloseVaultUpdateStateTracker {
         ncn_epoch: u64
     },
@@ -305,6 +432,7 @@ pub enum VaultInstruction {
     #[account(4, writable, name = "metadata")]
     #[account(5, name = "mpl_token_metadata_program")]
     #[account(6, name = "system_program")]
+    #[accounts(pda(vault = Vault))]
     CreateTokenMetadata {
         name: String,
         symbol: String,
@@ -317,6 +445,7 @@ pub enum VaultInstruction {
     #[account(2, name = "vrt_mint")]
     #[account(3, writable, name = "metadata")]
     #[account(4, name = "mpl_token_metadata_program")]
+    #[accounts(pda(vault = Vault))]
     UpdateTokenMetadata {
         name: String,
         symbol: String,
@@ -327,14 +456,31 @@ pub enum VaultInstruction {
     #[account(0, writable, name = "config")]
     #[account(1, signer, name = "old_admin")]
     #[account(2, name = "new_admin")]
+    #[accounts(pda(config = Config))]
     SetConfigAdmin,
 
     /// Changes the secondary admin for the config
-    #[account(0, name = "config")]
+    #[account(0, writable, name = "config")]
     #[account(1, signer, name = "admin")]
     #[account(2, name = "new_admin")]
+    #[accounts(pda(config = Config))]
     SetConfigSecondaryAdmin (ConfigAdminRole),
 
+    /// Migrates a program account in place to its current layout version, growing it if needed.
+    /// Anyone can migrate an account, the payer funds any additional rent.
+    #[account(0, writable, name = "account")]
+    #[account(1, writable, signer, name = "payer")]
+    #[account(2, name = "system_program")]
+    MigrateAccount,
+
+}
+
+impl VaultInstruction {
+    /// The Borsh tag of [`VaultInstruction::BurnWithdrawalTicket`]
+    pub const BURN_WITHDRAWAL_TICKET_TAG: u8 = 14;
+
+    /// The Borsh tag of [`VaultInstruction::CrankVaultUpdateStateTracker`]
+    pub const CRANK_VAULT_UPDATE_STATE_TRACKER_TAG: u8 = 28;
 }
 
 #[derive(Debug, BorshSerialize, BorshDeserialize)]
@@ -375,3 +521,20 @@ impl TryFrom<u8> for WithdrawalAllocationMethod {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::VaultInstruction;
+
+    #[test]
+    fn test_instruction_tags() {
+        assert_eq!(
+            borsh::to_vec(&VaultInstruction::BurnWithdrawalTicket).unwrap(),
+            vec![VaultInstruction::BURN_WITHDRAWAL_TICKET_TAG]
+        );
+        assert_eq!(
+            borsh::to_vec(&VaultInstruction::CrankVaultUpdateStateTracker).unwrap(),
+            vec![VaultInstruction::CRANK_VAULT_UPDATE_STATE_TRACKER_TAG]
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod compute_units;
 pub mod error;
 pub mod inline_mpl_token_metadata;
 pub mod instruction;
@@ -867,3 +867,16 @@ pub fn set_config_secondary_admin(
             .unwrap(),
     }
 }
+
+pub fn migrate_account(program_id: &Pubkey, account: &Pubkey, payer: &Pubkey) -> Instruction {
+    let accounts = vec![
+        AccountMeta::new(*account, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        data: VaultInstruction::MigrateAccount.try_to_vec().unwrap(),
+    }
+}